@@ -0,0 +1,132 @@
+/* Checks the C bindings against known results, run with `make test` */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "unicode_security.h"
+
+static int failures = 0;
+
+#define CHECK(cond)                                                   \
+    do {                                                              \
+        if (!(cond)) {                                                \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__,    \
+                    __LINE__, #cond);                                 \
+            failures++;                                               \
+        }                                                             \
+    } while (0)
+
+#define STR(s) (const uint8_t *)(s), strlen(s)
+
+static void test_identifier(void) {
+    CHECK(unicode_security_identifier_allowed('A'));
+    CHECK(unicode_security_identifier_allowed('_'));
+    CHECK(!unicode_security_identifier_allowed(0));
+    /* U+2160 ROMAN NUMERAL ONE */
+    CHECK(!unicode_security_identifier_allowed(0x2160));
+    /* Surrogates and values past U+10FFFF are not code points */
+    CHECK(!unicode_security_identifier_allowed(0xD800));
+    CHECK(!unicode_security_identifier_allowed(0x110000));
+
+    CHECK(unicode_security_identifier_type('A') ==
+          UNICODE_SECURITY_IDENTIFIER_TYPE_RECOMMENDED);
+    CHECK(unicode_security_identifier_type('\t') ==
+          UNICODE_SECURITY_IDENTIFIER_TYPE_NOT_XID);
+    CHECK(unicode_security_identifier_type(0xD800) ==
+          UNICODE_SECURITY_IDENTIFIER_TYPE_NONE);
+}
+
+static void test_restriction_level(void) {
+    UnicodeSecurityRestrictionLevel level;
+
+    CHECK(unicode_security_restriction_level(STR("paypal"), &level) ==
+          UNICODE_SECURITY_STATUS_OK);
+    CHECK(level == UNICODE_SECURITY_RESTRICTION_LEVEL_ASCII_ONLY);
+    CHECK(unicode_security_restriction_level(STR("p\xd0\xb0ypal"), &level) ==
+          UNICODE_SECURITY_STATUS_OK);
+    CHECK(level == UNICODE_SECURITY_RESTRICTION_LEVEL_MINIMALLY_RESTRICTIVE);
+    CHECK(unicode_security_restriction_level(NULL, 0, &level) ==
+          UNICODE_SECURITY_STATUS_OK);
+    CHECK(level == UNICODE_SECURITY_RESTRICTION_LEVEL_ASCII_ONLY);
+
+    CHECK(unicode_security_restriction_level(STR("\xff"), &level) ==
+          UNICODE_SECURITY_STATUS_INVALID_UTF8);
+    CHECK(unicode_security_restriction_level(NULL, 1, &level) ==
+          UNICODE_SECURITY_STATUS_NULL_POINTER);
+    CHECK(unicode_security_restriction_level(STR("a"), NULL) ==
+          UNICODE_SECURITY_STATUS_NULL_POINTER);
+}
+
+static void test_script_sets(void) {
+    bool single_script;
+    uint8_t buf[64];
+    size_t written;
+
+    CHECK(unicode_security_is_single_script(STR("paypal"), &single_script) ==
+          UNICODE_SECURITY_STATUS_OK);
+    CHECK(single_script);
+    CHECK(unicode_security_is_single_script(STR("p\xd0\xb0ypal"),
+                                            &single_script) ==
+          UNICODE_SECURITY_STATUS_OK);
+    CHECK(!single_script);
+
+    CHECK(unicode_security_resolve_script_set(STR("paypal"), buf, sizeof buf,
+                                              &written) ==
+          UNICODE_SECURITY_STATUS_OK);
+    CHECK(written == 4 && memcmp(buf, "Latn", 4) == 0);
+    CHECK(unicode_security_resolve_script_set(STR("p\xd0\xb0ypal"), buf,
+                                              sizeof buf, &written) ==
+          UNICODE_SECURITY_STATUS_OK);
+    CHECK(written == 0);
+    CHECK(unicode_security_resolve_script_set(STR("123"), buf, sizeof buf,
+                                              &written) ==
+          UNICODE_SECURITY_STATUS_OK);
+    CHECK(written == 4 && memcmp(buf, "Zyyy", 4) == 0);
+    /* U+5B57 CJK UNIFIED IDEOGRAPH-5B57 */
+    CHECK(unicode_security_resolve_script_set(STR("\xe5\xad\x97"), buf,
+                                              sizeof buf, &written) ==
+          UNICODE_SECURITY_STATUS_OK);
+    CHECK(written == 19 && memcmp(buf, "Hanb Jpan Kore Hani", 19) == 0);
+}
+
+static void test_skeleton(void) {
+    uint8_t buf[64];
+    size_t written = 0;
+
+    /* With a Cyrillic 'а' and a capital 'I' */
+    CHECK(unicode_security_skeleton(STR("p\xd0\xb0yPaI"), buf, sizeof buf,
+                                    &written) == UNICODE_SECURITY_STATUS_OK);
+    CHECK(written == 6 && memcmp(buf, "payPal", 6) == 0);
+
+    /* The length is reported even if the buffer is too small */
+    CHECK(unicode_security_skeleton(STR("paypal"), NULL, 0, &written) ==
+          UNICODE_SECURITY_STATUS_BUFFER_TOO_SMALL);
+    CHECK(written == 6);
+    CHECK(unicode_security_skeleton(STR("paypal"), buf, 5, &written) ==
+          UNICODE_SECURITY_STATUS_BUFFER_TOO_SMALL);
+    CHECK(written == 6);
+    CHECK(unicode_security_skeleton(STR(""), NULL, 0, &written) ==
+          UNICODE_SECURITY_STATUS_OK);
+    CHECK(written == 0);
+
+    CHECK(unicode_security_skeleton(STR("paypal"), NULL, 6, &written) ==
+          UNICODE_SECURITY_STATUS_NULL_POINTER);
+    CHECK(unicode_security_skeleton(STR("paypal"), buf, sizeof buf, NULL) ==
+          UNICODE_SECURITY_STATUS_NULL_POINTER);
+    CHECK(unicode_security_skeleton(STR("\xc0\x80"), buf, sizeof buf,
+                                    &written) ==
+          UNICODE_SECURITY_STATUS_INVALID_UTF8);
+}
+
+int main(void) {
+    test_identifier();
+    test_restriction_level();
+    test_script_sets();
+    test_skeleton();
+    if (failures != 0) {
+        fprintf(stderr, "%d checks failed\n", failures);
+        return 1;
+    }
+    printf("all checks passed\n");
+    return 0;
+}
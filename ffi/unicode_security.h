@@ -0,0 +1,145 @@
+/* Generated with cbindgen from src/ffi.rs, do not edit directly */
+
+#ifndef UNICODE_SECURITY_H
+#define UNICODE_SECURITY_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// The identifier type of a code point, as returned by
+// `GeneralSecurityProfile::identifier_type`, or `None` if it has none
+typedef enum UnicodeSecurityIdentifierType {
+  // No identifier type, or not a valid code point
+  UNICODE_SECURITY_IDENTIFIER_TYPE_NONE = 0,
+  // Not_Character
+  UNICODE_SECURITY_IDENTIFIER_TYPE_NOT_CHARACTER,
+  // Deprecated
+  UNICODE_SECURITY_IDENTIFIER_TYPE_DEPRECATED,
+  // Default_Ignorable
+  UNICODE_SECURITY_IDENTIFIER_TYPE_DEFAULT_IGNORABLE,
+  // Not_NFKC
+  UNICODE_SECURITY_IDENTIFIER_TYPE_NOT_NFKC,
+  // Not_XID
+  UNICODE_SECURITY_IDENTIFIER_TYPE_NOT_XID,
+  // Exclusion
+  UNICODE_SECURITY_IDENTIFIER_TYPE_EXCLUSION,
+  // Obsolete
+  UNICODE_SECURITY_IDENTIFIER_TYPE_OBSOLETE,
+  // Technical
+  UNICODE_SECURITY_IDENTIFIER_TYPE_TECHNICAL,
+  // Uncommon_Use
+  UNICODE_SECURITY_IDENTIFIER_TYPE_UNCOMMON_USE,
+  // Limited_Use
+  UNICODE_SECURITY_IDENTIFIER_TYPE_LIMITED_USE,
+  // Inclusion
+  UNICODE_SECURITY_IDENTIFIER_TYPE_INCLUSION,
+  // Recommended
+  UNICODE_SECURITY_IDENTIFIER_TYPE_RECOMMENDED,
+} UnicodeSecurityIdentifierType;
+
+// The outcome of a call taking a string
+typedef enum UnicodeSecurityStatus {
+  // The call succeeded
+  UNICODE_SECURITY_STATUS_OK = 0,
+  // A pointer argument was null
+  UNICODE_SECURITY_STATUS_NULL_POINTER = 1,
+  // The string is not valid UTF-8
+  UNICODE_SECURITY_STATUS_INVALID_UTF8 = 2,
+  // The output buffer is too small for the result, whose length was still
+  // written
+  UNICODE_SECURITY_STATUS_BUFFER_TOO_SMALL = 3,
+} UnicodeSecurityStatus;
+
+// A restriction level, as returned by `RestrictionLevelDetection::detect_restriction_level`
+typedef enum UnicodeSecurityRestrictionLevel {
+  // ASCII-Only
+  UNICODE_SECURITY_RESTRICTION_LEVEL_ASCII_ONLY = 0,
+  // Single Script
+  UNICODE_SECURITY_RESTRICTION_LEVEL_SINGLE_SCRIPT,
+  // Highly Restrictive
+  UNICODE_SECURITY_RESTRICTION_LEVEL_HIGHLY_RESTRICTIVE,
+  // Moderately Restrictive
+  UNICODE_SECURITY_RESTRICTION_LEVEL_MODERATELY_RESTRICTIVE,
+  // Minimally Restrictive
+  UNICODE_SECURITY_RESTRICTION_LEVEL_MINIMALLY_RESTRICTIVE,
+  // Unrestricted
+  UNICODE_SECURITY_RESTRICTION_LEVEL_UNRESTRICTED,
+} UnicodeSecurityRestrictionLevel;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Check whether a code point is allowed in identifiers
+//
+// Values that are not code points, such as surrogates, are not allowed.
+bool unicode_security_identifier_allowed(uint32_t c);
+
+// Find the identifier type of a code point
+enum UnicodeSecurityIdentifierType unicode_security_identifier_type(uint32_t c);
+
+// Detect the restriction level of a string
+//
+// # Safety
+//
+// `s` must point to `len` readable bytes, or be null if `len` is 0. `level`
+// must point to writable memory.
+enum UnicodeSecurityStatus unicode_security_restriction_level(const uint8_t *s,
+                                                              size_t len,
+                                                              enum UnicodeSecurityRestrictionLevel *level);
+
+// Check whether a string is single-script
+//
+// # Safety
+//
+// `s` must point to `len` readable bytes, or be null if `len` is 0.
+// `single_script` must point to writable memory.
+enum UnicodeSecurityStatus unicode_security_is_single_script(const uint8_t *s,
+                                                             size_t len,
+                                                             bool *single_script);
+
+// Write the resolved script set of a string to a buffer
+//
+// The set is written as the ISO 15924 codes of its scripts separated by
+// spaces, with the augmented `Hanb`, `Jpan` and `Kore` first, as in `"Latn"` or
+// `"Hanb Jpan Kore Hani"`. The empty set, of a mixed-script string, is written
+// as an empty string, and the set of all scripts, of a string with only Common
+// and Inherited characters, as `"Zyyy"`. See
+// `AugmentedScriptSet::script_codes`. Otherwise this works like
+// [`unicode_security_skeleton`].
+//
+// # Safety
+//
+// As for [`unicode_security_skeleton`].
+enum UnicodeSecurityStatus unicode_security_resolve_script_set(const uint8_t *s,
+                                                               size_t len,
+                                                               uint8_t *buf,
+                                                               size_t buf_len,
+                                                               size_t *written);
+
+// Write the skeleton of a string to a buffer, as UTF-8
+//
+// The length of the skeleton in bytes is written to `written`. If it does not
+// fit into the `buf_len` bytes of `buf`, [`UnicodeSecurityStatus::BufferTooSmall`]
+// is returned, and the call can be repeated with a buffer of that length. The
+// result is not NUL-terminated.
+//
+// # Safety
+//
+// `s` must point to `len` readable bytes, or be null if `len` is 0. `buf` must
+// point to `buf_len` writable bytes, or be null if `buf_len` is 0. `written`
+// must point to writable memory.
+enum UnicodeSecurityStatus unicode_security_skeleton(const uint8_t *s,
+                                                     size_t len,
+                                                     uint8_t *buf,
+                                                     size_t buf_len,
+                                                     size_t *written);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* UNICODE_SECURITY_H */
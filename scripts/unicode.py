@@ -15,15 +15,26 @@
 # - IdentifierType.txt
 # - PropertyValueAliases.txt
 # - confusables.txt
+# - intentional.txt
 # - ReadMe.txt
+# This script also uses the following data of its own, from this directory:
+# - confusable_sequences.txt
 # This script also uses the following Unicode UCD data:
+# - CaseFolding.txt
 # - DerivedCoreProperties.txt
 # - Scripts.txt
 #
 # Since this should not require frequent updates, we just store this
 # out-of-line and check the tables.rs file into git.
+#
+# Run with --fixtures to instead copy the files checked by the conformance
+# tests in src/conformance.rs into tests/fixtures:
+# - confusablesSummary.txt
+# - intentional.txt
+# - IdentifierStatus.txt
+# - IdentifierType.txt
 
-import fileinput, re, os, sys, operator
+import fileinput, re, os, sys, operator, shutil, unicodedata
 
 preamble = '''// Copyright 2012-2015 The Rust Project Developers. See the COPYRIGHT
 // file at the top-level directory of this distribution and at
@@ -125,6 +136,61 @@ def load_confusables(f):
 
     return confusables
 
+# Loads intentional confusables from intentional.txt, as a sorted list of
+# (code point, sorted list of code points it is intentionally confusable with)
+def load_intentional(f):
+    fetch(f)
+    partners = {}
+    re1 = re.compile(r"^([0-9A-F]+)[ \t]*;[ \t]*([0-9A-F]+)[ \t]*#")
+
+    for line in fileinput.input(os.path.basename(f), openhook=fileinput.hook_encoded("utf-8")):
+        m = re1.match(line)
+        if not m:
+            continue
+        a = int(m.group(1), 16)
+        b = int(m.group(2), 16)
+        partners.setdefault(a, set()).add(b)
+        partners.setdefault(b, set()).add(a)
+
+    if not partners:
+        sys.stderr.write("no intentional confusables in %s\n" % f)
+        exit(1)
+
+    return sorted((c, sorted(p)) for (c, p) in partners.items())
+
+# Loads confusable sequences from confusable_sequences.txt in this directory, as a
+# list of (source code points, target code points)
+def load_confusable_sequences(f):
+    sequences = []
+    re1 = re.compile(r"^((?:[0-9A-F]+ )+);\t((?:[0-9A-F]+ )+)")
+    path = os.path.join(os.path.dirname(os.path.abspath(__file__)), f)
+
+    for line in fileinput.input(path, openhook=fileinput.hook_encoded("utf-8")):
+        m = re1.match(line)
+        if not m:
+            continue
+        source = [int(c, 16) for c in m.group(1).split()]
+        target = [int(c, 16) for c in m.group(2).split()]
+        sequences.append((source, target))
+
+    return sequences
+
+# Loads full case folding data (statuses C and F) from CaseFolding.txt
+def load_case_folding(f):
+    fetch_unidata(f)
+    case_folding = []
+    re1 = re.compile(r"^ *([0-9A-F]+); [CF]; ((?:[0-9A-F]+ ?)+);")
+
+    for line in fileinput.input(os.path.basename(f), openhook=fileinput.hook_encoded("utf-8")):
+        m = re1.match(line)
+        if not m:
+            continue
+        d_input = int(m.group(1), 16)
+        d_outputs = [int(d_output, 16) for d_output in m.group(2).split()]
+        case_folding.append((d_input, d_outputs))
+
+    return case_folding
+
 # Loads Unicode script name correspondence from PropertyValueAliases.txt
 def aliases():
     # This function is taken from the `unicode-script` crate. If significant
@@ -453,7 +519,7 @@ def escape_char_list(l):
     line += "]"
     return line
 
-def emit_table(f, name, t_data, t_type = "&'static [(char, char)]", is_pub=True,
+def emit_table(f, name, t_data, t_type = "&[(char, char)]", is_pub=True,
         pfun=lambda x: "(%s,%s)" % (escape_char(x[0]), escape_char(x[1])), is_const=True):
     pub_string = "const"
     if not is_const:
@@ -471,9 +537,98 @@ def emit_table(f, name, t_data, t_type = "&'static [(char, char)]", is_pub=True,
     format_table_content(f, data, 8)
     f.write("\n    ];\n\n")
 
+# Values of code points below this are also stored in a flat array, so that
+# lookups of ASCII and Latin-1 characters skip the trie
+TRIE_LATIN1_LEN = 0x100
+
+def trie_values(ranges, default=0):
+    values = [default] * 0x110000
+    for (lo, hi, value) in ranges:
+        values[lo:hi + 1] = [value] * (hi - lo + 1)
+    return values
+
+def trie_bitset_words(ranges):
+    words = [0] * (0x110000 >> 6)
+    for (lo, hi) in ranges:
+        for c in range(lo, hi + 1):
+            words[c >> 6] |= 1 << (c & 63)
+    return words
+
+# Split values into deduplicated leaves of 2**leaf_bits values, and the leaf
+# numbers into deduplicated blocks of 2**block_bits leaf numbers, indexed by
+# the top bits. Trailing blocks only containing zeros are dropped from the index,
+# lookups past its end return zero.
+def compress_trie(values, block_bits, leaf_bits):
+    def dedup(items, chunk):
+        chunks = []
+        ids = {}
+        numbers = []
+        for i in range(0, len(items), chunk):
+            key = tuple(items[i:i + chunk])
+            if key not in ids:
+                ids[key] = len(chunks)
+                chunks.append(key)
+            numbers.append(ids[key])
+        return (chunks, ids, numbers)
+
+    (leaves, leaf_ids, leaf_numbers) = dedup(values, 1 << leaf_bits)
+    (blocks, block_ids, index) = dedup(leaf_numbers, 1 << block_bits)
+    empty_leaf = leaf_ids.get((0,) * (1 << leaf_bits))
+    empty_block = block_ids.get((empty_leaf,) * (1 << block_bits))
+    while index and index[-1] == empty_block:
+        index.pop()
+    return (index, blocks, leaves)
+
+# Find the smallest trie for the values, given the size of a value in bytes
+def best_trie(values, value_size, leaf_bits_choices):
+    best = None
+    for leaf_bits in leaf_bits_choices:
+        for block_bits in range(1, 9):
+            (index, blocks, leaves) = compress_trie(values, block_bits, leaf_bits)
+            if len(blocks) > 0x100 or len(leaves) > 0x10000:
+                continue
+            size = len(index) + 2 * len(blocks) * (1 << block_bits) \
+                + value_size * len(leaves) * (1 << leaf_bits)
+            if best is None or size < best[0]:
+                best = (size, index, blocks, leaves, block_bits, leaf_bits)
+    if best is None:
+        raise Exception("no trie layout fits the index types")
+    return best[1:]
+
+def emit_trie(f, name, trie, latin1, t_type, pfun=lambda x: "%d" % x):
+    (index, blocks, leaves, block_bits, leaf_bits) = trie
+    f.write("    const %s: Trie<%s> = Trie {\n" % (name, t_type))
+    for (field, data, fmt) in [("latin1", latin1, pfun),
+                               ("index", index, lambda x: "%d" % x),
+                               ("blocks", [n for b in blocks for n in b], lambda x: "%d" % x),
+                               ("leaves", [v for l in leaves for v in l], pfun)]:
+        f.write("        %s: &[\n" % field)
+        format_table_content(f, ",".join(fmt(x) for x in data), 12)
+        f.write("\n        ],\n")
+    f.write("        block_bits: %d,\n" % block_bits)
+    f.write("        leaf_bits: %d,\n" % leaf_bits)
+    f.write("    };\n\n")
+
+# Emit a trie holding one bit per code point
+def emit_bitset_trie(f, name, ranges):
+    words = trie_bitset_words(ranges)
+    trie = best_trie(words, 8, [0])
+    emit_trie(f, name, trie, words[:TRIE_LATIN1_LEN >> 6], "u64", pfun=lambda x: "0x%x" % x)
+
+# Emit a trie holding a small integer per code point
+def emit_value_trie(f, name, ranges, t_type, value_size):
+    values = trie_values(ranges)
+    trie = best_trie(values, value_size, range(2, 9))
+    emit_trie(f, name, trie, values[:TRIE_LATIN1_LEN], t_type)
+
+IDENTIFIER_TYPES = ["Not_Character", "Deprecated", "Default_Ignorable", "Not_NFKC",
+    "Not_XID", "Exclusion", "Obsolete", "Technical", "Uncommon_Use", "Limited_Use",
+    "Inclusion", "Recommended"]
+
 def emit_identifier_module(f):
-    f.write("pub mod identifier {")
+    f.write("#[cfg(feature = \"identifier\")]\npub mod identifier {")
     f.write("""
+    use super::util::Trie;
 
     #[derive(Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Debug)]
     #[allow(non_camel_case_types)]
@@ -497,65 +652,59 @@ def emit_identifier_module(f):
     }
     #[inline]
     pub fn identifier_status_allowed(c: char) -> bool {
-        // FIXME: do we want to special case ASCII here?
-        match c as usize {
-            _ => super::util::bsearch_range_table(c, IDENTIFIER_STATUS)
-        }
+        super::util::trie_bitset_contains(c, &IDENTIFIER_STATUS)
     }
 
     #[inline]
     pub fn identifier_type(c: char) -> Option<IdentifierType> {
-        // FIXME: do we want to special case ASCII here?
-        match c as usize {
-            _ => super::util::bsearch_range_value_table(c, IDENTIFIER_TYPE)
-        }
+        IDENTIFIER_TYPES[IDENTIFIER_TYPE.lookup(c as u32) as usize]
     }
 """)
 
     f.write("    // Identifier status table:\n")
     identifier_status_table = load_properties(fetch("IdentifierStatus.txt"))
-    emit_table(f, "IDENTIFIER_STATUS", identifier_status_table['Allowed'], "&'static [(char, char)]", is_pub=False,
-            pfun=lambda x: "(%s,%s)" % (escape_char(x[0]), escape_char(x[1])))
+    emit_bitset_trie(f, "IDENTIFIER_STATUS", identifier_status_table['Allowed'])
     identifier_type = load_properties(fetch("IdentifierType.txt"))
     type_table = []
     for ty in identifier_type:
-        type_table.extend([(x, y, ty) for (x, y) in identifier_type[ty]])
-    
-    type_table.sort(key=lambda w: w[0])
+        type_table.extend([(x, y, IDENTIFIER_TYPES.index(ty) + 1) for (x, y) in identifier_type[ty]])
 
-    emit_table(f, "IDENTIFIER_TYPE", type_table, "&'static [(char, char, IdentifierType)]", is_pub=False,
-            pfun=lambda x: "(%s,%s, IdentifierType::%s)" % (escape_char(x[0]), escape_char(x[1]), x[2]))
+    f.write("    // Identifier type table, values are indices into IDENTIFIER_TYPES:\n")
+    emit_value_trie(f, "IDENTIFIER_TYPE", type_table, "u8", 1)
+    emit_table(f, "IDENTIFIER_TYPES", [None] + IDENTIFIER_TYPES, "&[Option<IdentifierType>]", is_pub=False,
+            pfun=lambda x: "None" if x is None else "Some(IdentifierType::%s)" % x)
     f.write("}\n\n")
 
 def emit_default_ignorable_detection_module(f):
     f.write("pub mod default_ignorable_code_point {")
     f.write("""
 
+    use super::util::Trie;
+
     #[inline]
     pub fn default_ignorable_code_point(c: char) -> bool {
-        match c as usize {
-            _ => super::util::bsearch_range_table(c, DEFAULT_IGNORABLE)
-        }
+        super::util::trie_bitset_contains(c, &DEFAULT_IGNORABLE)
     }
 
 """)
 
     f.write("    // Default ignorable code point table:\n")
     default_ignorable_table = load_properties(fetch_unidata("DerivedCoreProperties.txt"), ["Default_Ignorable_Code_Point"])
-    emit_table(f, "DEFAULT_IGNORABLE", default_ignorable_table["Default_Ignorable_Code_Point"], "&'static [(char, char)]", is_pub=False,
-            pfun=lambda x: "(%s,%s)" % (escape_char(x[0]), escape_char(x[1])))
+    emit_bitset_trie(f, "DEFAULT_IGNORABLE", default_ignorable_table["Default_Ignorable_Code_Point"])
 
     f.write("}\n\n")
 
 def emit_confusable_detection_module(f):
-    f.write("pub mod confusable_detection {")
+    f.write("#[cfg(feature = \"confusables\")]\npub mod confusable_detection {")
     f.write("""
 
+    use super::util::Trie;
+
     #[inline]
     pub fn char_confusable_prototype(c: char) -> Option<&'static [char]> {
-        // FIXME: do we want to special case ASCII here?
-        match c as usize {
-            _ => super::util::bsearch_value_table(c, CONFUSABLES)
+        match CONFUSABLES.lookup(c as u32) {
+            0 => None,
+            i => Some(PROTOTYPES[i as usize - 1]),
         }
     }
 
@@ -571,7 +720,85 @@ def emit_confusable_detection_module(f):
             raise Exception("duplicate keys in confusables table: %s" % k)
         last_key = k
 
-    emit_table(f, "CONFUSABLES", confusable_table, "&'static [(char, &'static [char])]", is_pub=False,
+    prototypes = []
+    prototype_ids = {}
+    ranges = []
+    for (k, prototype) in confusable_table:
+        prototype = tuple(prototype)
+        if prototype not in prototype_ids:
+            prototype_ids[prototype] = len(prototypes) + 1
+            prototypes.append(prototype)
+        ranges.append((k, k, prototype_ids[prototype]))
+
+    f.write("    // Values are one more than the index of the prototype in PROTOTYPES, or 0\n")
+    emit_value_trie(f, "CONFUSABLES", ranges, "u16", 2)
+    emit_table(f, "PROTOTYPES", prototypes, "&[&[char]]", is_pub=False,
+            pfun=lambda x: "&%s" % escape_char_list(x))
+    f.write("}\n\n")
+
+def emit_confusable_sequences_module(f):
+    f.write("#[cfg(feature = \"confusables\")]\npub mod confusable_sequences {")
+    f.write("""
+
+    #[inline]
+    pub fn confusable_sequence_target(source: &[char]) -> Option<&'static [char]> {
+        CONFUSABLE_SEQUENCES
+            .binary_search_by_key(&source, |&(k, _)| k)
+            .ok()
+            .map(|idx| CONFUSABLE_SEQUENCES[idx].1)
+    }
+
+""")
+
+    sequences = load_confusable_sequences("confusable_sequences.txt")
+    sequences.sort(key=lambda w: w[0])
+    prototypes = set(k for (k, _) in load_confusables("confusables.txt"))
+    for (source, target) in sequences:
+        if len(source) < 2:
+            raise Exception("confusable sequence of a single code point: %s" % source)
+        for c in source + target:
+            if c in prototypes or unicodedata.normalize("NFD", chr(c)) != chr(c) \
+                    or unicodedata.combining(chr(c)) != 0:
+                raise Exception("confusable sequence not in skeleton form: %s" % source)
+
+    f.write("    pub const MAX_SEQUENCE_LEN: usize = %d;\n\n" % max(len(s) for (s, _) in sequences))
+    f.write("    // Confusable sequence table:\n")
+    emit_table(f, "CONFUSABLE_SEQUENCES", sequences, "&[(&[char], &[char])]", is_pub=False,
+            pfun=lambda x: "(&%s, &%s)" % (escape_char_list(x[0]), escape_char_list(x[1])))
+    f.write("}\n\n")
+
+def emit_intentional_confusables_module(f):
+    f.write("#[cfg(feature = \"confusables\")]\npub mod intentional_confusables {")
+    f.write("""
+
+    #[inline]
+    pub fn intentional_confusables(c: char) -> &'static [char] {
+        super::util::bsearch_value_table(c, INTENTIONAL_CONFUSABLES).unwrap_or(&[])
+    }
+
+""")
+
+    f.write("    // Intentional confusables table:\n")
+    intentional_table = load_intentional("intentional.txt")
+    emit_table(f, "INTENTIONAL_CONFUSABLES", intentional_table, "&[(char, &[char])]", is_pub=False,
+            pfun=lambda x: "(%s, &%s)" % (escape_char(x[0]), escape_char_list(x[1])))
+    f.write("}\n\n")
+
+def emit_case_folding_module(f):
+    f.write("#[cfg(feature = \"confusables\")]\npub mod case_folding {")
+    f.write("""
+
+    #[inline]
+    pub fn case_fold(c: char) -> Option<&'static [char]> {
+        super::util::bsearch_value_table(c, CASE_FOLDING)
+    }
+
+""")
+
+    f.write("    // Full case folding table:\n")
+    case_folding_table = load_case_folding("CaseFolding.txt")
+    case_folding_table.sort(key=lambda w: w[0])
+    emit_table(f, "CASE_FOLDING", case_folding_table, "&[(char, &[char])]", is_pub=False,
             pfun=lambda x: "(%s, &%s)" % (escape_char(x[0]), escape_char_list(x[1])))
     f.write("}\n\n")
 
@@ -579,14 +806,15 @@ def escape_script_constant(name, longforms):
     return "Script::" + longforms[name].strip()
 
 def emit_potiential_mixed_script_confusable(f):
-    f.write("pub mod potential_mixed_script_confusable {")
+    f.write("#[cfg(feature = \"mixed-script\")]\npub mod potential_mixed_script_confusable {")
     f.write("""
+    use super::util::Trie;
+
     #[inline]
     pub fn potential_mixed_script_confusable(c: char) -> bool {
-        match c as usize {
-            _ => super::util::bsearch_table(c, CONFUSABLES)
-        }
+        super::util::trie_bitset_contains(c, &CONFUSABLES)
     }
+
 """)
     identifier_status_table = load_properties(fetch("IdentifierStatus.txt"))
     _, scripts = load_scripts("Scripts.txt")
@@ -601,63 +829,78 @@ def emit_potiential_mixed_script_confusable(f):
         for _, pair in lst.items():
             source = pair[0]
             confusable_table.append((source, script))
-    confusable_table.sort(key=lambda w: w[0])
-    emit_table(f, "CONFUSABLES", confusable_table, "&'static [char]", is_pub=False,
-            pfun=lambda x: "%s" % escape_char(x[0]))
+    emit_bitset_trie(f, "CONFUSABLES", [(source, source) for (source, _) in confusable_table])
     f.write("}\n\n")
 
 
 def emit_util_mod(f):
     f.write("""
 pub mod util {
-    use core::result::Result::{Ok, Err};
-
-    #[inline]
-    pub fn bsearch_table(c: char, r: &'static [char]) -> bool {
-        r.binary_search(&c).is_ok()
-    }
-
+    #[cfg(feature = "confusables")]
     #[inline]
     pub fn bsearch_value_table<T: Copy>(c: char, r: &'static [(char, T)]) -> Option<T> {
-        match r.binary_search_by_key(&c, |&(k, _)| k) {
-            Ok(idx) => {
-                let (_, v) = r[idx];
-                Some(v)
-            }
-            Err(_) => None
-        }
+        r.binary_search_by_key(&c, |&(k, _)| k).ok().map(|idx| r[idx].1)
     }
 
-    #[inline]
-    pub fn bsearch_range_table(c: char, r: &'static [(char,char)]) -> bool {
-        use core::cmp::Ordering::{Equal, Less, Greater};
-        r.binary_search_by(|&(lo,hi)| {
-            if lo <= c && c <= hi { Equal }
-            else if hi < c { Less }
-            else { Greater }
-        }).is_ok()
+    /// A three-level lookup table over all code points
+    ///
+    /// Values are stored in deduplicated leaves of `1 << leaf_bits` values, which
+    /// are numbered by deduplicated blocks of `1 << block_bits` leaves, which are
+    /// numbered by `index`. Positions past the end of `index` have the value 0.
+    /// The values of the first positions are also stored in `latin1`, so that
+    /// ASCII and Latin-1 lookups take a single load.
+    pub struct Trie<T: 'static> {
+        pub latin1: &'static [T],
+        pub index: &'static [u8],
+        pub blocks: &'static [u16],
+        pub leaves: &'static [T],
+        pub block_bits: u32,
+        pub leaf_bits: u32,
     }
 
-    pub fn bsearch_range_value_table<T: Copy>(c: char, r: &'static [(char, char, T)]) -> Option<T> {
-        use core::cmp::Ordering::{Equal, Less, Greater};
-        match r.binary_search_by(|&(lo, hi, _)| {
-            if lo <= c && c <= hi { Equal }
-            else if hi < c { Less }
-            else { Greater }
-        }) {
-            Ok(idx) => {
-                let (_, _, cat) = r[idx];
-                Some(cat)
+    impl<T: Copy + Default> Trie<T> {
+        #[inline]
+        pub fn lookup(&self, i: u32) -> T {
+            let i = i as usize;
+            if let Some(&v) = self.latin1.get(i) {
+                return v;
             }
-            Err(_) => None
+            let block = match self.index.get(i >> (self.block_bits + self.leaf_bits)) {
+                Some(&block) => block as usize,
+                None => return T::default(),
+            };
+            let block_mask = (1 << self.block_bits) - 1;
+            let leaf = self.blocks[(block << self.block_bits) | ((i >> self.leaf_bits) & block_mask)];
+            let leaf_mask = (1 << self.leaf_bits) - 1;
+            self.leaves[((leaf as usize) << self.leaf_bits) | (i & leaf_mask)]
         }
     }
 
+    /// Look up a character in a trie holding one bit per code point
+    #[inline]
+    pub fn trie_bitset_contains(c: char, trie: &Trie<u64>) -> bool {
+        let c = c as u32;
+        (trie.lookup(c >> 6) >> (c & 63)) & 1 != 0
+    }
+
 }
 
 """)
 
+FIXTURES = ["confusablesSummary.txt", "intentional.txt", "IdentifierStatus.txt",
+    "IdentifierType.txt"]
+
+# Copy the conformance test data into tests/fixtures
+def copy_fixtures():
+    fixtures_dir = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..", "tests", "fixtures")
+    for f in FIXTURES:
+        shutil.copy(fetch(f), os.path.join(fixtures_dir, f))
+
 if __name__ == "__main__":
+    if "--fixtures" in sys.argv[1:]:
+        copy_fixtures()
+        exit(0)
+
     r = "tables.rs"
     if os.path.exists(r):
         os.remove(r)
@@ -679,5 +922,11 @@ pub const UNICODE_VERSION: (u64, u64, u64) = (%s, %s, %s);
         emit_default_ignorable_detection_module(rf)
         ### confusable_detection module
         emit_confusable_detection_module(rf)
+        ### confusable_sequences module
+        emit_confusable_sequences_module(rf)
+        ### intentional_confusables module
+        emit_intentional_confusables_module(rf)
         ### mixed_script_confusable_detection module
         emit_potiential_mixed_script_confusable(rf)
+        ### case_folding module
+        emit_case_folding_module(rf)
@@ -0,0 +1,170 @@
+//! Checking many strings at once
+//!
+//! Re-validating a whole table of identifiers runs the same checks on every row.
+//! [`check_all`] returns the [restriction level](crate::RestrictionLevelDetection), the
+//! [single-script](crate::MixedScript::is_single_script) status and the [skeleton](crate::skeleton) of
+//! every string, and [`skeleton_collisions`] finds the strings of a batch that
+//! are confusable with each other. Results are always in the order of the input.
+//!
+//! With the `rayon` feature, the functions taking a slice process it in parallel.
+//! Their `_with` variants then need a [`DataProvider`] that is `Sync`.
+//!
+//! ```rust
+//! use unicode_security::batch::{check_all, skeleton_collisions};
+//! use unicode_security::RestrictionLevel;
+//!
+//! let names = ["paypal", "pаypal", "PayPal", "paypal"]; // with a Cyrillic 'а'
+//! let results = check_all(&names);
+//! assert_eq!(results[0].restriction_level, RestrictionLevel::ASCIIOnly);
+//! assert!(!results[1].single_script);
+//!
+//! let collisions = skeleton_collisions(&names);
+//! assert_eq!(collisions.len(), 1);
+//! assert_eq!(collisions[0].indices, [0, 1, 3]);
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::confusable_detection::skeleton_with;
+use crate::mixed_script::is_single_script_with;
+use crate::provider::{BakedData, DataProvider};
+use crate::restriction_level::detect_restriction_level_with;
+use crate::RestrictionLevel;
+
+/// The results of the checks of [`check_all`] for one string
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct BatchResult {
+    /// The restriction level of the string
+    pub restriction_level: RestrictionLevel,
+    /// Whether the string is single-script
+    pub single_script: bool,
+    /// The skeleton of the string
+    pub skeleton: String,
+}
+
+impl BatchResult {
+    fn new<D: DataProvider + ?Sized>(s: &str, data: &D) -> Self {
+        BatchResult {
+            restriction_level: detect_restriction_level_with(s, data),
+            single_script: is_single_script_with(s, data),
+            skeleton: skeleton_with(s, data).collect(),
+        }
+    }
+}
+
+/// Strings of a batch with the same skeleton
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct SkeletonCollision {
+    /// The skeleton shared by the strings
+    pub skeleton: String,
+    /// The indices of the strings in the batch, in ascending order
+    pub indices: Vec<usize>,
+}
+
+/// Check every string of a slice
+pub fn check_all<S: AsRef<str> + Sync>(strings: &[S]) -> Vec<BatchResult> {
+    check_all_with(strings, &BakedData)
+}
+
+/// Check every string of a slice, with the data of a [`DataProvider`]
+pub fn check_all_with<S, D>(strings: &[S], data: &D) -> Vec<BatchResult>
+where
+    S: AsRef<str> + Sync,
+    D: DataProvider + Sync + ?Sized,
+{
+    map_slice(strings, |s| BatchResult::new(s, data))
+}
+
+/// Check every string of an iterator, lazily and in order
+pub fn check_iter<I>(strings: I) -> impl Iterator<Item = BatchResult>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    strings
+        .into_iter()
+        .map(|s| BatchResult::new(s.as_ref(), &BakedData))
+}
+
+/// Check every string of an iterator, lazily and in order, with the data of a
+/// [`DataProvider`]
+pub fn check_iter_with<'a, I, D>(strings: I, data: &'a D) -> impl Iterator<Item = BatchResult> + 'a
+where
+    I: IntoIterator,
+    I::IntoIter: 'a,
+    I::Item: AsRef<str>,
+    D: DataProvider + ?Sized,
+{
+    strings
+        .into_iter()
+        .map(move |s| BatchResult::new(s.as_ref(), data))
+}
+
+/// Find the groups of different strings of a slice that have the same skeleton
+///
+/// Equal strings are not collisions with each other, so a batch can contain
+/// duplicates: a group is reported once at least two different strings share a
+/// skeleton, with the indices of all their occurrences. Groups are in the order
+/// of their first index.
+pub fn skeleton_collisions<S: AsRef<str> + Sync>(strings: &[S]) -> Vec<SkeletonCollision> {
+    skeleton_collisions_with(strings, &BakedData)
+}
+
+/// Find the groups of different strings of a slice that have the same skeleton,
+/// with the data of a [`DataProvider`]
+pub fn skeleton_collisions_with<S, D>(strings: &[S], data: &D) -> Vec<SkeletonCollision>
+where
+    S: AsRef<str> + Sync,
+    D: DataProvider + Sync + ?Sized,
+{
+    let skeletons = map_slice(strings, |s| skeleton_with(s, data).collect::<String>());
+    let mut order: Vec<usize> = (0..strings.len()).collect();
+    let key = |&idx: &usize| (&skeletons[idx], strings[idx].as_ref(), idx);
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::slice::ParallelSliceMut;
+        order.par_sort_unstable_by(|a, b| key(a).cmp(&key(b)));
+    }
+    #[cfg(not(feature = "rayon"))]
+    order.sort_unstable_by(|a, b| key(a).cmp(&key(b)));
+
+    let mut collisions = Vec::new();
+    let mut start = 0;
+    for end in 1..=order.len() {
+        if end < order.len() && skeletons[order[end]] == skeletons[order[start]] {
+            continue;
+        }
+        let group = &order[start..end];
+        let first = strings[group[0]].as_ref();
+        if group.iter().any(|&idx| strings[idx].as_ref() != first) {
+            let mut indices = group.to_vec();
+            indices.sort_unstable();
+            collisions.push(SkeletonCollision {
+                skeleton: skeletons[group[0]].clone(),
+                indices,
+            });
+        }
+        start = end;
+    }
+    collisions.sort_unstable_by_key(|collision| collision.indices[0]);
+    collisions
+}
+
+/// Apply a function to every string of a slice, in parallel with the `rayon` feature
+fn map_slice<S, T, F>(strings: &[S], f: F) -> Vec<T>
+where
+    S: AsRef<str> + Sync,
+    T: Send,
+    F: Fn(&str) -> T + Sync + Send,
+{
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+        strings.par_iter().map(|s| f(s.as_ref())).collect()
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        strings.iter().map(|s| f(s.as_ref())).collect()
+    }
+}
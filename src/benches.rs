@@ -0,0 +1,216 @@
+// Copyright 2012-2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Per-character property lookups, as done by a lexer on every character
+//!
+//! Run with `cargo +nightly bench --features bench`. Each lookup is also timed
+//! with binary search over sorted range tables built from the same data, as
+//! `tables.rs` did before it stored tries, in the `_baseline` benchmarks.
+
+use core::cmp::Ordering;
+use std::vec::Vec;
+use test::{black_box, Bencher};
+
+use crate::tables::confusable_detection::char_confusable_prototype;
+use crate::{
+    is_default_ignorable_code_point, is_potential_mixed_script_confusable_char,
+    GeneralSecurityProfile,
+};
+
+const ASCII_SOURCE: &str = "fn main() {\n    let mut total_count = 0;\n    for value in \
+    values.iter() {\n        if value.is_valid() { total_count += value.weight; }\n    }\n    \
+    println!(\"{}\", total_count);\n}\n";
+
+const MIXED_SOURCE: &str = "let größe = 1; let café = 2; let число = 3; let λόγος = 4;\n\
+    let 変数名 = 5; let 한국어 = 6; let שלום = 7; let مرحبا = 8; let ऊर्जा = 9;\n\
+    let 𝑥 = 10; let 🦀 = 11; let zero\u{200B}width = 12; let ﬁle = 13;\n";
+
+fn bench_lookup<T>(b: &mut Bencher, text: &str, lookup: impl Fn(char) -> T) {
+    b.bytes = text.len() as u64;
+    b.iter(|| {
+        for c in black_box(text).chars() {
+            black_box(lookup(c));
+        }
+    });
+}
+
+#[bench]
+fn identifier_allowed_ascii(b: &mut Bencher) {
+    bench_lookup(b, ASCII_SOURCE, |c| c.identifier_allowed());
+}
+
+#[bench]
+fn identifier_allowed_mixed(b: &mut Bencher) {
+    bench_lookup(b, MIXED_SOURCE, |c| c.identifier_allowed());
+}
+
+#[bench]
+fn identifier_type_ascii(b: &mut Bencher) {
+    bench_lookup(b, ASCII_SOURCE, |c| c.identifier_type());
+}
+
+#[bench]
+fn identifier_type_mixed(b: &mut Bencher) {
+    bench_lookup(b, MIXED_SOURCE, |c| c.identifier_type());
+}
+
+#[bench]
+fn default_ignorable_ascii(b: &mut Bencher) {
+    bench_lookup(b, ASCII_SOURCE, is_default_ignorable_code_point);
+}
+
+#[bench]
+fn default_ignorable_mixed(b: &mut Bencher) {
+    bench_lookup(b, MIXED_SOURCE, is_default_ignorable_code_point);
+}
+
+#[bench]
+fn confusable_prototype_ascii(b: &mut Bencher) {
+    bench_lookup(b, ASCII_SOURCE, char_confusable_prototype);
+}
+
+#[bench]
+fn confusable_prototype_mixed(b: &mut Bencher) {
+    bench_lookup(b, MIXED_SOURCE, char_confusable_prototype);
+}
+
+#[bench]
+fn mixed_script_confusable_ascii(b: &mut Bencher) {
+    bench_lookup(b, ASCII_SOURCE, is_potential_mixed_script_confusable_char);
+}
+
+#[bench]
+fn mixed_script_confusable_mixed(b: &mut Bencher) {
+    bench_lookup(b, MIXED_SOURCE, is_potential_mixed_script_confusable_char);
+}
+
+/// The ranges of code points with the same value, as `(first, last, value)`
+fn range_value_table<T: Copy + PartialEq>(
+    lookup: impl Fn(char) -> Option<T>,
+) -> Vec<(char, char, T)> {
+    let mut table: Vec<(char, char, T)> = Vec::new();
+    for c in (0..=0x10FFFF).filter_map(char::from_u32) {
+        let value = match lookup(c) {
+            Some(value) => value,
+            None => continue,
+        };
+        match table.last_mut() {
+            Some((_, last, v)) if *last as u32 + 1 == c as u32 && *v == value => *last = c,
+            _ => table.push((c, c, value)),
+        }
+    }
+    table
+}
+
+fn range_table(lookup: impl Fn(char) -> bool) -> Vec<(char, char, ())> {
+    range_value_table(|c| lookup(c).then_some(()))
+}
+
+fn bsearch_range_value_table<T: Copy>(c: char, r: &[(char, char, T)]) -> Option<T> {
+    r.binary_search_by(|&(lo, hi, _)| {
+        if hi < c {
+            Ordering::Less
+        } else if c < lo {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    })
+    .ok()
+    .map(|idx| r[idx].2)
+}
+
+fn bsearch_value_table<T: Copy>(c: char, r: &[(char, T)]) -> Option<T> {
+    r.binary_search_by_key(&c, |&(k, _)| k)
+        .ok()
+        .map(|idx| r[idx].1)
+}
+
+#[bench]
+fn identifier_allowed_ascii_baseline(b: &mut Bencher) {
+    let table = range_table(|c| c.identifier_allowed());
+    bench_lookup(b, ASCII_SOURCE, |c| {
+        bsearch_range_value_table(c, &table).is_some()
+    });
+}
+
+#[bench]
+fn identifier_allowed_mixed_baseline(b: &mut Bencher) {
+    let table = range_table(|c| c.identifier_allowed());
+    bench_lookup(b, MIXED_SOURCE, |c| {
+        bsearch_range_value_table(c, &table).is_some()
+    });
+}
+
+#[bench]
+fn identifier_type_ascii_baseline(b: &mut Bencher) {
+    let table = range_value_table(|c| c.identifier_type());
+    bench_lookup(b, ASCII_SOURCE, |c| bsearch_range_value_table(c, &table));
+}
+
+#[bench]
+fn identifier_type_mixed_baseline(b: &mut Bencher) {
+    let table = range_value_table(|c| c.identifier_type());
+    bench_lookup(b, MIXED_SOURCE, |c| bsearch_range_value_table(c, &table));
+}
+
+#[bench]
+fn default_ignorable_ascii_baseline(b: &mut Bencher) {
+    let table = range_table(is_default_ignorable_code_point);
+    bench_lookup(b, ASCII_SOURCE, |c| {
+        bsearch_range_value_table(c, &table).is_some()
+    });
+}
+
+#[bench]
+fn default_ignorable_mixed_baseline(b: &mut Bencher) {
+    let table = range_table(is_default_ignorable_code_point);
+    bench_lookup(b, MIXED_SOURCE, |c| {
+        bsearch_range_value_table(c, &table).is_some()
+    });
+}
+
+fn confusables_table() -> Vec<(char, &'static [char])> {
+    (0..=0x10FFFF)
+        .filter_map(char::from_u32)
+        .filter_map(|c| Some((c, char_confusable_prototype(c)?)))
+        .collect()
+}
+
+#[bench]
+fn confusable_prototype_ascii_baseline(b: &mut Bencher) {
+    let table = confusables_table();
+    bench_lookup(b, ASCII_SOURCE, |c| bsearch_value_table(c, &table));
+}
+
+#[bench]
+fn confusable_prototype_mixed_baseline(b: &mut Bencher) {
+    let table = confusables_table();
+    bench_lookup(b, MIXED_SOURCE, |c| bsearch_value_table(c, &table));
+}
+
+fn mixed_script_confusables_table() -> Vec<char> {
+    (0..=0x10FFFF)
+        .filter_map(char::from_u32)
+        .filter(|&c| is_potential_mixed_script_confusable_char(c))
+        .collect()
+}
+
+#[bench]
+fn mixed_script_confusable_ascii_baseline(b: &mut Bencher) {
+    let table = mixed_script_confusables_table();
+    bench_lookup(b, ASCII_SOURCE, |c| table.binary_search(&c).is_ok());
+}
+
+#[bench]
+fn mixed_script_confusable_mixed_baseline(b: &mut Bencher) {
+    let table = mixed_script_confusables_table();
+    bench_lookup(b, MIXED_SOURCE, |c| table.binary_search(&c).is_ok());
+}
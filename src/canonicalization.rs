@@ -0,0 +1,120 @@
+//! A canonicalization pipeline for identifiers such as user names
+//!
+//! Account systems need a canonical key for every identifier, a
+//! [skeleton](crate::skeleton) to detect collisions between keys, and a
+//! [restriction level](https://www.unicode.org/reports/tr39/#Restriction_Level_Detection)
+//! gate. [`canonicalize_identifier`] computes all three in a fixed order, so
+//! that every caller using the same [`CanonicalizationOptions`] gets the same answer.
+
+use alloc::string::String;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::confusable_detection::{map_char, skeleton_with};
+use crate::provider::{BakedData, DataProvider};
+use crate::restriction_level::detect_restriction_level_with;
+use crate::RestrictionLevel;
+
+/// Options for [`canonicalize_identifier`]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct CanonicalizationOptions {
+    casefold: bool,
+    remove_default_ignorables: bool,
+    max_restriction_level: RestrictionLevel,
+}
+
+impl Default for CanonicalizationOptions {
+    fn default() -> Self {
+        CanonicalizationOptions {
+            casefold: true,
+            remove_default_ignorables: true,
+            max_restriction_level: RestrictionLevel::HighlyRestrictive,
+        }
+    }
+}
+
+impl CanonicalizationOptions {
+    /// Create the default options: case folding and removal of default ignorable
+    /// code points are enabled, and identifiers up to
+    /// [`RestrictionLevel::HighlyRestrictive`] are accepted
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether to apply full case folding, giving NFKC_Casefold instead of NFKC
+    pub fn casefold(mut self, casefold: bool) -> Self {
+        self.casefold = casefold;
+        self
+    }
+
+    /// Set whether to remove [default ignorable code points](https://www.unicode.org/reports/tr44/#Default_Ignorable_Code_Point)
+    pub fn remove_default_ignorables(mut self, remove: bool) -> Self {
+        self.remove_default_ignorables = remove;
+        self
+    }
+
+    /// Set the least restrictive level an identifier may have to be accepted
+    pub fn max_restriction_level(mut self, level: RestrictionLevel) -> Self {
+        self.max_restriction_level = level;
+        self
+    }
+}
+
+/// The result of [`canonicalize_identifier`]
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct CanonicalIdentifier {
+    /// The normalized form, to be used as the canonical key
+    pub normalized: String,
+    /// The [skeleton](crate::skeleton) of the normalized form, to be used for collision checks
+    pub skeleton: String,
+    /// The [restriction level](https://www.unicode.org/reports/tr39/#Restriction_Level_Detection)
+    /// of the normalized form
+    pub restriction_level: RestrictionLevel,
+    /// Whether the restriction level is acceptable under the options used
+    pub accepted: bool,
+}
+
+/// Canonicalize an identifier
+///
+/// The pipeline runs in this order:
+///
+/// 1. Normalize to NFKC, or to NFKC_Casefold (NFKC of the full case folding of
+///    NFKD) if case folding is enabled
+/// 2. Remove default ignorable code points, if enabled
+/// 3. Compute the skeleton and the restriction level of the result
+///
+/// All steps depend on the Unicode data of this crate, see [`UNICODE_VERSION`](crate::UNICODE_VERSION).
+pub fn canonicalize_identifier(s: &str, options: &CanonicalizationOptions) -> CanonicalIdentifier {
+    canonicalize_identifier_with(s, options, &BakedData)
+}
+
+/// Canonicalize an identifier with the data of a [`DataProvider`]
+///
+/// Normalization to NFKC is not provided by a `DataProvider`, and always uses
+/// the data of the `unicode-normalization` crate.
+pub fn canonicalize_identifier_with<D: DataProvider + ?Sized>(
+    s: &str,
+    options: &CanonicalizationOptions,
+    data: &D,
+) -> CanonicalIdentifier {
+    let remove_default_ignorables = options.remove_default_ignorables;
+    let keep = move |c: &char| !remove_default_ignorables || !data.default_ignorable(*c);
+    let normalized: String = if options.casefold {
+        s.chars()
+            .nfkd()
+            .flat_map(|c| map_char(c, data.case_fold(c)))
+            .filter(keep)
+            .nfkc()
+            .collect()
+    } else {
+        s.chars().filter(keep).nfkc().collect()
+    };
+
+    let skeleton = skeleton_with(&normalized, data).collect();
+    let restriction_level = detect_restriction_level_with(&normalized, data);
+    CanonicalIdentifier {
+        accepted: restriction_level <= options.max_restriction_level,
+        normalized,
+        skeleton,
+        restriction_level,
+    }
+}
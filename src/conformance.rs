@@ -0,0 +1,310 @@
+// Copyright 2012-2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Conformance tests against the data files published with UTS #39
+//!
+//! Each test reads one file from `tests/fixtures` and checks every entry in it
+//! against the crate. The files are fetched with `python3 scripts/unicode.py
+//! --fixtures`, for the same Unicode version as `tables.rs`, and are checked in.
+//! A test whose file is missing fails.
+
+use std::format;
+use std::fs;
+use std::path::PathBuf;
+use std::string::String;
+#[cfg(feature = "confusables")]
+use std::vec;
+use std::vec::Vec;
+
+/// One data line of a fixture, with its 1-based line number
+struct Entry<'a> {
+    line: usize,
+    fields: Vec<&'a str>,
+}
+
+/// Read a fixture, which must be present
+fn read_fixture(file: &str) -> String {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "tests", "fixtures", file]
+        .iter()
+        .collect();
+    let contents = fs::read_to_string(&path).unwrap_or_else(|err| {
+        panic!(
+            "cannot read {}: {} (fetch it with `python3 scripts/unicode.py --fixtures`)",
+            path.display(),
+            err
+        )
+    });
+    check_version(file, &contents);
+    contents
+}
+
+/// Check that a fixture is for the Unicode version of the tables
+fn check_version(file: &str, contents: &str) {
+    let (major, minor, micro) = crate::UNICODE_VERSION;
+    let expected = format!("{}.{}.{}", major, minor, micro);
+    for line in contents.lines().take_while(|line| line.starts_with('#')) {
+        if let Some(version) = line.strip_prefix("# Version:") {
+            assert_eq!(
+                version.trim(),
+                expected,
+                "{} is for a different Unicode version than the tables",
+                file
+            );
+        }
+    }
+}
+
+/// The `;`-separated fields of the data lines of a UCD-style file
+fn entries(contents: &str) -> impl Iterator<Item = Entry<'_>> {
+    contents.lines().enumerate().filter_map(|(idx, line)| {
+        let data = line.split('#').next().unwrap_or("").trim();
+        if data.is_empty() {
+            return None;
+        }
+        Some(Entry {
+            line: idx + 1,
+            fields: data.split(';').map(str::trim).collect(),
+        })
+    })
+}
+
+fn parse_code_point(s: &str) -> char {
+    u32::from_str_radix(s, 16)
+        .ok()
+        .and_then(char::from_u32)
+        .unwrap_or_else(|| panic!("invalid code point {:?}", s))
+}
+
+#[cfg(feature = "confusables")]
+fn parse_code_points(s: &str) -> String {
+    s.split_whitespace().map(parse_code_point).collect()
+}
+
+#[cfg(feature = "identifier")]
+fn parse_range(s: &str) -> (char, char) {
+    match s.split_once("..") {
+        Some((lo, hi)) => (parse_code_point(lo), parse_code_point(hi)),
+        None => (parse_code_point(s), parse_code_point(s)),
+    }
+}
+
+#[cfg(feature = "confusables")]
+/// The groups of confusablesSummary.txt, as strings with their line numbers
+///
+/// A group starts with a line `#\t<string>\t...` and continues with lines
+/// `←\t<string>\t...`. The code points of each string are in the next to last
+/// field, as the string itself may contain a tab.
+fn confusable_groups(contents: &str) -> Vec<Vec<(usize, String)>> {
+    let mut groups: Vec<Vec<(usize, String)>> = Vec::new();
+    for (idx, line) in contents.lines().enumerate() {
+        let (marker, rest) = match line.split_once('\t') {
+            Some((marker, rest)) if marker == "#" || marker == "←" => (marker, rest),
+            _ => continue,
+        };
+        let member = match rest.rsplit('\t').nth(1) {
+            Some(code_points) => (idx + 1, parse_code_points(code_points)),
+            None => panic!("line {}: expected code points", idx + 1),
+        };
+        match groups.last_mut() {
+            Some(group) if marker == "←" => group.push(member),
+            _ if marker == "#" => groups.push(vec![member]),
+            _ => panic!("line {} continues a group before the first one", idx + 1),
+        }
+    }
+    groups
+}
+
+#[cfg(feature = "confusables")]
+fn skeleton(s: &str) -> String {
+    crate::skeleton(s).collect()
+}
+
+#[cfg(feature = "confusables")]
+#[test]
+fn test_parse_confusable_groups() {
+    let contents = "# confusablesSummary.txt\n\
+                    # Version: 17.0.0\n\
+                    \n\
+                    #\tl\t(\u{200e} l \u{200e})\t006C\t LATIN SMALL LETTER L\n\
+                    ←\tI\t(\u{200e} I \u{200e})\t0049\t LATIN CAPITAL LETTER I\n\
+                    ←\tǀ\t(\u{200e} ǀ \u{200e})\t01C0\t LATIN LETTER DENTAL CLICK\n\
+                    \n\
+                    #\trn\t(\u{200e} rn \u{200e})\t0072 006E\t LATIN SMALL LETTER R, LATIN SMALL LETTER N\n\
+                    ←\tm\t(\u{200e} m \u{200e})\t006D\t LATIN SMALL LETTER M\n";
+    let groups = confusable_groups(contents);
+    assert_eq!(
+        groups,
+        [
+            vec![(4, "l".into()), (5, "I".into()), (6, "ǀ".into())],
+            vec![(8, "rn".into()), (9, "m".into())],
+        ]
+    );
+}
+
+/// Every group of confusablesSummary.txt has one skeleton, shared by no other group
+#[cfg(feature = "confusables")]
+#[test]
+fn test_confusables_summary() {
+    use std::collections::HashMap;
+
+    let contents = read_fixture("confusablesSummary.txt");
+    let groups = confusable_groups(&contents);
+    assert!(!groups.is_empty(), "confusablesSummary.txt has no groups");
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    for group in &groups {
+        let (first_line, first) = &group[0];
+        let expected = skeleton(first);
+        for (line, member) in &group[1..] {
+            assert_eq!(
+                skeleton(member),
+                expected,
+                "line {}: {:?} is not confusable with {:?} on line {}",
+                line,
+                member,
+                first,
+                first_line
+            );
+        }
+        if let Some(other_line) = seen.insert(expected, *first_line) {
+            panic!(
+                "the groups on lines {} and {} have the same skeleton",
+                other_line, first_line
+            );
+        }
+    }
+}
+
+/// Every pair of intentional.txt is intentionally confusable, and has the same skeleton
+#[cfg(feature = "confusables")]
+#[test]
+fn test_intentional() {
+    let contents = read_fixture("intentional.txt");
+    for entry in entries(&contents) {
+        let (a, b) = match entry.fields[..] {
+            [a, b, ..] => (parse_code_points(a), parse_code_points(b)),
+            _ => panic!("line {}: expected two fields", entry.line),
+        };
+        assert_eq!(
+            skeleton(&a),
+            skeleton(&b),
+            "line {}: {:?} and {:?} have different skeletons",
+            entry.line,
+            a,
+            b
+        );
+        let (a, b) = (single_char(&a, entry.line), single_char(&b, entry.line));
+        assert!(
+            crate::is_intentional_confusable(a, b) && crate::is_intentional_confusable(b, a),
+            "line {}: {:?} and {:?} are not intentionally confusable",
+            entry.line,
+            a,
+            b
+        );
+    }
+}
+
+#[cfg(feature = "confusables")]
+fn single_char(s: &str, line: usize) -> char {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => c,
+        _ => panic!("line {}: expected a single code point", line),
+    }
+}
+
+/// Exactly the code points listed as Allowed in IdentifierStatus.txt are allowed
+#[cfg(feature = "identifier")]
+#[test]
+fn test_identifier_status() {
+    use crate::GeneralSecurityProfile;
+
+    let contents = read_fixture("IdentifierStatus.txt");
+    let mut allowed = Vec::new();
+    for entry in entries(&contents) {
+        match entry.fields[..] {
+            [range, "Allowed", ..] => allowed.push(parse_range(range)),
+            [_, "Restricted", ..] => {}
+            _ => panic!("line {}: expected an identifier status", entry.line),
+        }
+    }
+    allowed.sort_unstable();
+    let mut ranges = allowed.iter().peekable();
+    for c in (0..=0x10FFFF).filter_map(char::from_u32) {
+        while ranges.next_if(|&&(_, hi)| hi < c).is_some() {}
+        let expected = matches!(ranges.peek(), Some(&&(lo, _)) if lo <= c);
+        assert_eq!(
+            c.identifier_allowed(),
+            expected,
+            "U+{:04X} has the wrong identifier status",
+            c as u32
+        );
+    }
+}
+
+/// Every code point listed in IdentifierType.txt has the listed type
+///
+/// Code points with several types are only checked to have one of them, or
+/// none, as `tables.rs` keeps a single type per code point.
+#[cfg(feature = "identifier")]
+#[test]
+fn test_identifier_type() {
+    use crate::general_security_profile::IdentifierType;
+    use crate::GeneralSecurityProfile;
+
+    let contents = read_fixture("IdentifierType.txt");
+    for entry in entries(&contents) {
+        let (range, types) = match entry.fields[..] {
+            [range, types, ..] => (parse_range(range), types),
+            _ => panic!("line {}: expected two fields", entry.line),
+        };
+        let types: Vec<IdentifierType> = types
+            .split_whitespace()
+            .map(|ty| {
+                parse_identifier_type(ty).unwrap_or_else(|| {
+                    panic!("line {}: unknown identifier type {}", entry.line, ty)
+                })
+            })
+            .collect();
+        for c in range.0..=range.1 {
+            let actual = c.identifier_type();
+            let ok = match (&types[..], actual) {
+                ([ty], actual) => actual == Some(*ty),
+                (_, Some(actual)) => types.contains(&actual),
+                (_, None) => true,
+            };
+            assert!(
+                ok,
+                "line {}: U+{:04X} has identifier type {:?}, expected {:?}",
+                entry.line, c as u32, actual, types
+            );
+        }
+    }
+}
+
+#[cfg(feature = "identifier")]
+fn parse_identifier_type(s: &str) -> Option<crate::general_security_profile::IdentifierType> {
+    use crate::general_security_profile::IdentifierType::*;
+
+    Some(match s {
+        "Not_Character" => Not_Character,
+        "Deprecated" => Deprecated,
+        "Default_Ignorable" => Default_Ignorable,
+        "Not_NFKC" => Not_NFKC,
+        "Not_XID" => Not_XID,
+        "Exclusion" => Exclusion,
+        "Obsolete" => Obsolete,
+        "Technical" => Technical,
+        "Uncommon_Use" => Uncommon_Use,
+        "Limited_Use" => Limited_Use,
+        "Inclusion" => Inclusion,
+        "Recommended" => Recommended,
+        _ => return None,
+    })
+}
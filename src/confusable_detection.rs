@@ -1,8 +1,17 @@
 //! [Confusable detection](https://www.unicode.org/reports/tr39/#Confusable_Detection)
 
-use core::iter;
+#[cfg(feature = "alloc")]
+use alloc::collections::{BTreeMap, BTreeSet};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{iter, slice};
 
-enum OnceOrMore<T, I> {
+use crate::provider::{BakedData, DataProvider};
+use crate::tables::confusable_sequences::MAX_SEQUENCE_LEN;
+
+pub(crate) enum OnceOrMore<T, I> {
     Once(iter::Once<T>),
     More(I),
 }
@@ -22,24 +31,389 @@ where
     }
 }
 
-type StaticSliceIterCloned = core::iter::Cloned<core::slice::Iter<'static, char>>;
+type SliceIterCloned<'a> = iter::Cloned<slice::Iter<'a, char>>;
 
-fn char_prototype(c: char) -> OnceOrMore<char, StaticSliceIterCloned> {
-    use crate::tables::confusable_detection::char_confusable_prototype;
-    match char_confusable_prototype(c) {
+/// Replace a character with its mapping, if it has one
+pub(crate) fn map_char(c: char, mapping: Option<&[char]>) -> OnceOrMore<char, SliceIterCloned<'_>> {
+    match mapping {
         None => OnceOrMore::Once(iter::once(c)),
         Some(l) => OnceOrMore::More(l.iter().cloned()),
     }
 }
 
+fn char_prototype(c: char) -> OnceOrMore<char, SliceIterCloned<'static>> {
+    use crate::tables::confusable_detection::char_confusable_prototype;
+    map_char(c, char_confusable_prototype(c))
+}
+
+/// Apply [full case folding](https://www.unicode.org/versions/latest/core-spec/chapter-3/#G53253)
+/// to a character
+fn case_fold(c: char) -> OnceOrMore<char, SliceIterCloned<'static>> {
+    use crate::tables::case_folding;
+    map_char(c, case_folding::case_fold(c))
+}
+
 /// Calculate skeleton for string, as defined by UTS 39
 pub fn skeleton(s: &str) -> impl Iterator<Item = char> + '_ {
+    skeleton_chars(s.chars())
+}
+
+/// Calculate [`skeleton`] for a string given as characters
+pub fn skeleton_chars<I: IntoIterator<Item = char>>(chars: I) -> impl Iterator<Item = char> {
     use crate::tables::default_ignorable_code_point::default_ignorable_code_point;
+
+    skeleton_by(chars, default_ignorable_code_point, char_prototype)
+}
+
+/// Calculate [`skeleton`] with the data of a [`DataProvider`]
+pub fn skeleton_with<'a, D: DataProvider + ?Sized>(
+    s: &'a str,
+    data: &'a D,
+) -> impl Iterator<Item = char> + 'a {
+    skeleton_by(
+        s.chars(),
+        move |c| data.default_ignorable(c),
+        move |c| map_char(c, data.confusable_prototype(c)),
+    )
+}
+
+fn skeleton_by<I, G, F, P>(
+    chars: I,
+    default_ignorable: G,
+    prototype: F,
+) -> impl Iterator<Item = char>
+where
+    I: IntoIterator<Item = char>,
+    G: Fn(char) -> bool,
+    F: FnMut(char) -> P,
+    P: Iterator<Item = char>,
+{
     use unicode_normalization::UnicodeNormalization;
 
-    s.chars()
+    chars
+        .into_iter()
+        .nfd()
+        .filter(move |c| !default_ignorable(*c))
+        .flat_map(prototype)
+        .nfd()
+}
+
+/// Calculate an extended skeleton for string, which also maps confusable sequences
+///
+/// This is [`skeleton`] with sequences of characters that look like another
+/// character, such as "cl" for "d" and "vv" for "w", replaced by it. At each
+/// position the longest matching sequence is replaced. The sequences are
+/// matched in the standard skeleton, so "cI" also becomes "d". They are not
+/// Unicode data, but maintained with this crate, and may change between its
+/// versions; "rn" and "m" already have the same standard skeleton.
+///
+/// ```rust
+/// use unicode_security::confusable_detection::skeleton_extended;
+///
+/// assert!(skeleton_extended("cIoud").eq(skeleton_extended("doud")));
+/// ```
+pub fn skeleton_extended(s: &str) -> impl Iterator<Item = char> + '_ {
+    skeleton_extended_chars(s.chars())
+}
+
+/// Calculate [`skeleton_extended`] for a string given as characters
+pub fn skeleton_extended_chars<I: IntoIterator<Item = char>>(
+    chars: I,
+) -> impl Iterator<Item = char> {
+    ConfusableSequences::new(skeleton_chars(chars), |_| false)
+}
+
+/// Calculate [`skeleton_extended`] with the data of a [`DataProvider`]
+///
+/// The confusable sequences are maintained with this crate, so they are not
+/// taken from the provider.
+pub fn skeleton_extended_with<'a, D: DataProvider + ?Sized>(
+    s: &'a str,
+    data: &'a D,
+) -> impl Iterator<Item = char> + 'a {
+    ConfusableSequences::new(skeleton_with(s, data), |_| false)
+}
+
+/// Replaces the [confusable sequences](skeleton_extended) of a skeleton, other
+/// than the suppressed ones
+struct ConfusableSequences<I, S> {
+    inner: I,
+    suppressed: S,
+    lookahead: [char; MAX_SEQUENCE_LEN],
+    lookahead_len: usize,
+    target: SliceIterCloned<'static>,
+}
+
+impl<I, S> ConfusableSequences<I, S>
+where
+    I: Iterator<Item = char>,
+    S: Fn(&[char]) -> bool,
+{
+    fn new(inner: I, suppressed: S) -> Self {
+        ConfusableSequences {
+            inner,
+            suppressed,
+            lookahead: ['\0'; MAX_SEQUENCE_LEN],
+            lookahead_len: 0,
+            target: [].iter().cloned(),
+        }
+    }
+}
+
+impl<I, S> Iterator for ConfusableSequences<I, S>
+where
+    I: Iterator<Item = char>,
+    S: Fn(&[char]) -> bool,
+{
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        use crate::tables::confusable_sequences::confusable_sequence_target;
+
+        if let Some(c) = self.target.next() {
+            return Some(c);
+        }
+        while self.lookahead_len < MAX_SEQUENCE_LEN {
+            match self.inner.next() {
+                Some(c) => {
+                    self.lookahead[self.lookahead_len] = c;
+                    self.lookahead_len += 1;
+                }
+                None => break,
+            }
+        }
+        let pending = &self.lookahead[..self.lookahead_len];
+        let first = *pending.first()?;
+        let (len, target) = (2..=pending.len())
+            .rev()
+            .filter(|&len| !(self.suppressed)(&pending[..len]))
+            .find_map(|len| confusable_sequence_target(&pending[..len]).map(|t| (len, t)))
+            .unwrap_or((1, &[]));
+        self.lookahead.copy_within(len..self.lookahead_len, 0);
+        self.lookahead_len -= len;
+        if len == 1 {
+            return Some(first);
+        }
+        self.target = target.iter().cloned();
+        self.target.next()
+    }
+}
+
+/// Calculates skeletons with custom confusable mappings
+///
+/// Mappings added with [`add_mapping`](Self::add_mapping) are applied on top of
+/// the prototypes of confusables.txt, and may have sources of several characters,
+/// such as "vv" for "w". At each position of the string the longest matching
+/// source is replaced, and the characters of its target are then mapped to their
+/// prototypes like any other character. With
+/// [`confusable_sequences`](Self::confusable_sequences), the sequences of
+/// [`skeleton_extended`] are replaced afterwards. Mappings removed with
+/// [`suppress_mapping`](Self::suppress_mapping) are not applied, whether they are
+/// from confusables.txt, confusable sequences or added. Without any changes, the
+/// result is the standard [`skeleton`].
+///
+/// ```rust
+/// use unicode_security::confusable_detection::SkeletonBuilder;
+///
+/// let builder = SkeletonBuilder::new()
+///     .add_mapping("vv", "w")
+///     .suppress_mapping("0");
+/// assert_eq!(builder.skeleton("vvolf"), builder.skeleton("wolf"));
+/// assert_ne!(builder.skeleton("g00gle"), builder.skeleton("google"));
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct SkeletonBuilder {
+    mappings: BTreeMap<Vec<char>, Vec<char>>,
+    suppressed: BTreeSet<Vec<char>>,
+    max_source_len: usize,
+    confusable_sequences: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl SkeletonBuilder {
+    /// Create a builder calculating the standard [`skeleton`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Map a source string to a target string, replacing any earlier mapping or
+    /// suppression of the same source
+    ///
+    /// Both strings are compared in NFD, and default ignorable code points are
+    /// removed from the source. An empty source is ignored.
+    pub fn add_mapping(mut self, source: &str, target: &str) -> Self {
+        use unicode_normalization::UnicodeNormalization;
+
+        let source = normalize_source(source);
+        if source.is_empty() {
+            return self;
+        }
+        self.suppressed.remove(&source);
+        self.max_source_len = self.max_source_len.max(source.len());
+        self.mappings.insert(source, target.nfd().collect());
+        self
+    }
+
+    /// Stop mapping a source string, whether it is a character with a prototype
+    /// in confusables.txt or the source of an added mapping
+    pub fn suppress_mapping(mut self, source: &str) -> Self {
+        let source = normalize_source(source);
+        self.mappings.remove(&source);
+        self.suppressed.insert(source);
+        self
+    }
+
+    /// Enable or disable replacing the confusable sequences of [`skeleton_extended`]
+    pub fn confusable_sequences(mut self, enabled: bool) -> Self {
+        self.confusable_sequences = enabled;
+        self
+    }
+
+    /// Calculate the skeleton of a string
+    pub fn skeleton(&self, s: &str) -> String {
+        self.skeleton_with(s, &BakedData)
+    }
+
+    /// Calculate the skeleton of a string with the data of a [`DataProvider`]
+    pub fn skeleton_with<D: DataProvider + ?Sized>(&self, s: &str, data: &D) -> String {
+        use unicode_normalization::UnicodeNormalization;
+
+        let chars: Vec<char> = s.nfd().filter(|&c| !data.default_ignorable(c)).collect();
+        let mut mapped = Vec::with_capacity(chars.len());
+        let mut rest = &chars[..];
+        while let Some(c) = rest.first() {
+            let (len, target) = self
+                .longest_mapping(rest)
+                .unwrap_or((1, slice::from_ref(c)));
+            for &c in target {
+                if self.suppressed.contains(slice::from_ref(&c)) {
+                    mapped.push(c);
+                } else {
+                    mapped.extend(map_char(c, data.confusable_prototype(c)));
+                }
+            }
+            rest = &rest[len..];
+        }
+        let mapped = mapped.into_iter().nfd();
+        if self.confusable_sequences {
+            ConfusableSequences::new(mapped, |seq| self.suppressed.contains(seq)).collect()
+        } else {
+            mapped.collect()
+        }
+    }
+
+    /// The length and target of the longest added mapping whose source starts `chars`
+    fn longest_mapping(&self, chars: &[char]) -> Option<(usize, &[char])> {
+        if self.mappings.is_empty() {
+            return None;
+        }
+        (1..=self.max_source_len.min(chars.len()))
+            .rev()
+            .find_map(|len| {
+                self.mappings
+                    .get(&chars[..len])
+                    .map(|target| (len, &target[..]))
+            })
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn normalize_source(source: &str) -> Vec<char> {
+    use crate::tables::default_ignorable_code_point::default_ignorable_code_point;
+    use unicode_normalization::UnicodeNormalization;
+
+    source
+        .nfd()
+        .filter(|&c| !default_ignorable_code_point(c))
+        .collect()
+}
+
+/// Check whether two characters are
+/// [intentionally confusable](https://www.unicode.org/reports/tr39/#Confusable_Detection),
+/// as listed in intentional.txt
+///
+/// These pairs are designed to look identical in harmonized fonts, such as Latin
+/// "A" and Greek "Α". They have the same [`skeleton`] like other confusables, but
+/// registries usually handle them separately: a label mixing them is almost
+/// certainly a spoof, while their lookalikes in other scripts may be legitimate.
+/// A character is not intentionally confusable with itself.
+pub fn is_intentional_confusable(a: char, b: char) -> bool {
+    is_intentional_confusable_with(a, b, &BakedData)
+}
+
+/// Check [`is_intentional_confusable`] with the data of a [`DataProvider`]
+pub fn is_intentional_confusable_with<D: DataProvider + ?Sized>(
+    a: char,
+    b: char,
+    data: &D,
+) -> bool {
+    data.intentional_confusables(a).binary_search(&b).is_ok()
+}
+
+/// The characters a character is [intentionally confusable](is_intentional_confusable)
+/// with, in code point order
+pub fn intentional_confusables(c: char) -> impl Iterator<Item = char> {
+    intentional_confusables_with(c, &BakedData)
+}
+
+/// Find [`intentional_confusables`] with the data of a [`DataProvider`]
+pub fn intentional_confusables_with<D: DataProvider + ?Sized>(
+    c: char,
+    data: &D,
+) -> impl Iterator<Item = char> + '_ {
+    data.intentional_confusables(c).iter().cloned()
+}
+
+/// Calculate a case-insensitive skeleton for string
+///
+/// This is [`skeleton`] with full case folding applied after the prototype mapping,
+/// and the prototype mapping and case folding applied once more to the folded
+/// characters. Strings which only differ in case, or whose prototypes only differ
+/// in case, have the same skeleton: "PayPaI" and "paypal" collide here, but not
+/// under [`skeleton`].
+///
+/// Case folding is not applied before the prototype mapping, as that would map "I"
+/// to "i" and lose its confusability with "l". As a consequence, the few characters
+/// whose case pairs have unrelated prototypes, such as Greek "Β" and "β", still
+/// have different skeletons.
+///
+/// # Stability
+///
+/// The result depends on the confusables and case folding data of the Unicode
+/// version this crate is built with, see [`UNICODE_VERSION`](crate::UNICODE_VERSION).
+/// For a given version it is deterministic and in NFD, so it can be stored in a
+/// database index, but the index has to be rebuilt when that version changes.
+pub fn skeleton_casefold(s: &str) -> impl Iterator<Item = char> + '_ {
+    skeleton_casefold_chars(s.chars())
+}
+
+/// Calculate [`skeleton_casefold`] for a string given as characters
+pub fn skeleton_casefold_chars<I: IntoIterator<Item = char>>(
+    chars: I,
+) -> impl Iterator<Item = char> {
+    use unicode_normalization::UnicodeNormalization;
+
+    skeleton_chars(chars)
+        .flat_map(case_fold)
         .nfd()
-        .filter(|c| !default_ignorable_code_point(*c))
         .flat_map(char_prototype)
+        .flat_map(case_fold)
+        .nfd()
+}
+
+/// Calculate [`skeleton_casefold`] with the data of a [`DataProvider`]
+pub fn skeleton_casefold_with<'a, D: DataProvider + ?Sized>(
+    s: &'a str,
+    data: &'a D,
+) -> impl Iterator<Item = char> + 'a {
+    use unicode_normalization::UnicodeNormalization;
+
+    let case_fold = move |c| map_char(c, data.case_fold(c));
+    skeleton_with(s, data)
+        .flat_map(case_fold)
+        .nfd()
+        .flat_map(move |c| map_char(c, data.confusable_prototype(c)))
+        .flat_map(case_fold)
         .nfd()
 }
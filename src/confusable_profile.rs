@@ -0,0 +1,182 @@
+//! Confusable profiles for specific fonts and renderers
+//!
+//! Whether two characters are confusable depends on how they are drawn. The data
+//! of confusables.txt is meant for the fonts in common use, and is both too
+//! strict and too loose for a specific one: a monospace font draws "l", "I" and
+//! "1" distinctly, but box drawing characters like ASCII. A [`ConfusableProfile`]
+//! is a named set of extra and removed equivalences on top of confusables.txt,
+//! to be loaded and selected at runtime.
+//!
+//! Profiles are text files with one change per line, and comments starting with
+//! `#`. `add ; <source> ; <target>` makes the source confusable with the
+//! target, and `remove ; <source>` removes the mapping of the source, where both
+//! are code points in hexadecimal separated by spaces. See
+//! [`ConfusableProfile::dejavu_sans_mono`] for an example.
+//!
+//! ```rust
+//! use unicode_security::confusable_profile::ConfusableProfile;
+//!
+//! let profile = ConfusableProfile::dejavu_sans_mono();
+//! assert!(!profile.is_confusable("l1st", "list"));
+//! assert!(profile.is_confusable("a│b", "a|b"));
+//! ```
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::confusable_detection::SkeletonBuilder;
+use crate::provider::{BakedData, DataProvider};
+
+/// The names of the profiles shipped with this crate, for [`ConfusableProfile::builtin`]
+pub const BUILTIN_PROFILES: &[&str] = &[DEJAVU_SANS_MONO];
+
+const DEJAVU_SANS_MONO: &str = "dejavu-sans-mono";
+
+/// An error encountered while parsing a profile
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct ProfileParseError {
+    /// The 1-based number of the offending line
+    pub line: usize,
+}
+
+impl fmt::Display for ProfileParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid confusable profile entry on line {}", self.line)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ProfileParseError {}
+
+/// A named set of changes to the confusables data, for a font or renderer
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ConfusableProfile {
+    name: String,
+    builder: SkeletonBuilder,
+}
+
+impl ConfusableProfile {
+    /// Create a profile from the mappings of a [`SkeletonBuilder`]
+    pub fn new(name: &str, builder: SkeletonBuilder) -> Self {
+        ConfusableProfile {
+            name: name.to_string(),
+            builder,
+        }
+    }
+
+    /// Parse a profile from its text format
+    pub fn parse(name: &str, contents: &str) -> Result<Self, ProfileParseError> {
+        let mut builder = SkeletonBuilder::new();
+        for (idx, line) in contents.lines().enumerate() {
+            let data = line.split('#').next().unwrap_or("").trim();
+            if data.is_empty() {
+                continue;
+            }
+            let fields: Vec<&str> = data.split(';').map(str::trim).collect();
+            let parsed = match fields[..] {
+                ["add", source, target] => parse_code_points(source)
+                    .zip(parse_code_points(target))
+                    .map(|(source, target)| builder.add_mapping(&source, &target)),
+                ["remove", source] => {
+                    parse_code_points(source).map(|source| builder.suppress_mapping(&source))
+                }
+                _ => None,
+            };
+            builder = parsed.ok_or(ProfileParseError { line: idx + 1 })?;
+        }
+        Ok(ConfusableProfile::new(name, builder))
+    }
+
+    /// Look up a profile shipped with this crate by name, see [`BUILTIN_PROFILES`]
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            DEJAVU_SANS_MONO => Some(Self::dejavu_sans_mono()),
+            _ => None,
+        }
+    }
+
+    /// A sample profile for the DejaVu Sans Mono font, named `dejavu-sans-mono`
+    ///
+    /// In this font "0", "1", "I", "|" and "m" are not confusable with "O", "l"
+    /// and "rn", but some box drawing and math symbols are confusable with ASCII.
+    /// Its source is in `src/profiles/dejavu-sans-mono.txt`.
+    pub fn dejavu_sans_mono() -> Self {
+        Self::parse(
+            DEJAVU_SANS_MONO,
+            include_str!("profiles/dejavu-sans-mono.txt"),
+        )
+        .expect("invalid built-in profile")
+    }
+
+    /// The name of the profile
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Calculate the skeleton of a string under this profile
+    pub fn skeleton(&self, s: &str) -> String {
+        self.skeleton_with(s, &BakedData)
+    }
+
+    /// Calculate the skeleton of a string under this profile, with the data of a
+    /// [`DataProvider`] in place of confusables.txt
+    pub fn skeleton_with<D: DataProvider + ?Sized>(&self, s: &str, data: &D) -> String {
+        self.builder.skeleton_with(s, data)
+    }
+
+    /// Check whether two strings are confusable under this profile
+    pub fn is_confusable(&self, a: &str, b: &str) -> bool {
+        self.is_confusable_with(a, b, &BakedData)
+    }
+
+    /// Check [`is_confusable`](Self::is_confusable) with the data of a [`DataProvider`]
+    pub fn is_confusable_with<D: DataProvider + ?Sized>(&self, a: &str, b: &str, data: &D) -> bool {
+        self.skeleton_with(a, data) == self.skeleton_with(b, data)
+    }
+
+    /// Group strings that are confusable with each other under this profile
+    ///
+    /// Only groups of at least two strings are returned, in order of their first
+    /// string, and the strings of each group keep the order they were given in.
+    pub fn collisions<'a, I: IntoIterator<Item = &'a str>>(&self, strings: I) -> Vec<Vec<&'a str>> {
+        self.collisions_with(strings, &BakedData)
+    }
+
+    /// Find the [`collisions`](Self::collisions) of strings with the data of a
+    /// [`DataProvider`]
+    pub fn collisions_with<'a, I, D>(&self, strings: I, data: &D) -> Vec<Vec<&'a str>>
+    where
+        I: IntoIterator<Item = &'a str>,
+        D: DataProvider + ?Sized,
+    {
+        let mut skeletons: Vec<(String, usize, &'a str)> = strings
+            .into_iter()
+            .enumerate()
+            .map(|(idx, s)| (self.skeleton_with(s, data), idx, s))
+            .collect();
+        skeletons.sort_unstable();
+        let mut groups: Vec<(usize, Vec<&'a str>)> = Vec::new();
+        let mut start = 0;
+        for end in 1..=skeletons.len() {
+            if end < skeletons.len() && skeletons[end].0 == skeletons[start].0 {
+                continue;
+            }
+            if end - start > 1 {
+                let run = &skeletons[start..end];
+                groups.push((run[0].1, run.iter().map(|&(_, _, s)| s).collect()));
+            }
+            start = end;
+        }
+        groups.sort_unstable_by_key(|&(first, _)| first);
+        groups.into_iter().map(|(_, group)| group).collect()
+    }
+}
+
+fn parse_code_points(s: &str) -> Option<String> {
+    let mut chars = String::new();
+    for c in s.split_whitespace() {
+        chars.push(u32::from_str_radix(c, 16).ok().and_then(char::from_u32)?);
+    }
+    (!chars.is_empty()).then_some(chars)
+}
@@ -0,0 +1,195 @@
+//! Similarity scores between strings based on their [skeletons](crate::skeleton)
+//!
+//! Exact skeleton equality only catches strings that are confusable as a whole.
+//! The distance here is an edit distance over skeleton characters, so names that
+//! are one visual edit away from each other, such as "rnicrosofts" and
+//! "microsoft", can be detected as well. With the default [`UnitWeights`], a
+//! distance of zero means the skeletons are equal, so the scores agree with exact
+//! collisions.
+
+use alloc::vec::Vec;
+
+use crate::confusable_detection::skeleton_with;
+use crate::provider::{BakedData, DataProvider};
+
+/// Costs of the edit operations used by [`skeleton_distance_weighted`]
+///
+/// All operations work on skeleton characters, so they are between
+/// [prototypes](https://www.unicode.org/reports/tr39/#def-prototype). Implement this
+/// trait to make edits between some prototype classes cheaper than others.
+pub trait SkeletonWeights {
+    /// The cost of inserting a character
+    fn insertion(&self, c: char) -> u32 {
+        let _ = c;
+        1
+    }
+
+    /// The cost of deleting a character
+    fn deletion(&self, c: char) -> u32 {
+        let _ = c;
+        1
+    }
+
+    /// The cost of replacing a character with a different one
+    fn substitution(&self, from: char, to: char) -> u32 {
+        let _ = (from, to);
+        1
+    }
+}
+
+/// Unit costs for all edit operations, giving the Levenshtein distance
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+pub struct UnitWeights;
+
+impl SkeletonWeights for UnitWeights {}
+
+/// The Levenshtein distance between the skeletons of two strings
+pub fn skeleton_distance(a: &str, b: &str) -> u32 {
+    skeleton_distance_weighted(a, b, &UnitWeights)
+}
+
+/// Calculate [`skeleton_distance`] with the data of a [`DataProvider`]
+pub fn skeleton_distance_with<D: DataProvider + ?Sized>(a: &str, b: &str, data: &D) -> u32 {
+    skeleton_distance_weighted_with(a, b, &UnitWeights, data)
+}
+
+/// The weighted edit distance between the skeletons of two strings
+pub fn skeleton_distance_weighted<W: SkeletonWeights + ?Sized>(
+    a: &str,
+    b: &str,
+    weights: &W,
+) -> u32 {
+    skeleton_distance_weighted_with(a, b, weights, &BakedData)
+}
+
+/// Calculate [`skeleton_distance_weighted`] with the data of a [`DataProvider`]
+pub fn skeleton_distance_weighted_with<W, D>(a: &str, b: &str, weights: &W, data: &D) -> u32
+where
+    W: SkeletonWeights + ?Sized,
+    D: DataProvider + ?Sized,
+{
+    let a: Vec<char> = skeleton_with(a, data).collect();
+    let b: Vec<char> = skeleton_with(b, data).collect();
+    edit_distance(&a, &b, weights)
+}
+
+fn edit_distance<W: SkeletonWeights + ?Sized>(a: &[char], b: &[char], weights: &W) -> u32 {
+    let mut previous: Vec<u32> = Vec::with_capacity(b.len() + 1);
+    previous.push(0);
+    for &cb in b {
+        let last = previous[previous.len() - 1];
+        previous.push(last + weights.insertion(cb));
+    }
+    let mut current = Vec::with_capacity(b.len() + 1);
+    for &ca in a {
+        current.clear();
+        current.push(previous[0] + weights.deletion(ca));
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = if ca == cb {
+                previous[j]
+            } else {
+                previous[j] + weights.substitution(ca, cb)
+            };
+            let deletion = previous[j + 1] + weights.deletion(ca);
+            let insertion = current[j] + weights.insertion(cb);
+            current.push(substitution.min(deletion).min(insertion));
+        }
+        core::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// A protected name found by [`ProtectedNames::closest`]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct NameMatch<'a> {
+    /// The protected name
+    pub name: &'a str,
+    /// The distance between the skeletons of the protected name and the candidate
+    pub distance: u32,
+}
+
+/// A list of protected names, such as brands, with their skeletons precomputed
+#[derive(Clone, Debug)]
+pub struct ProtectedNames<'a> {
+    names: Vec<(&'a str, Vec<char>)>,
+}
+
+impl<'a> ProtectedNames<'a> {
+    /// Compute the skeletons of a list of protected names
+    pub fn new<I: IntoIterator<Item = &'a str>>(names: I) -> Self {
+        Self::new_with(names, &BakedData)
+    }
+
+    /// Compute the skeletons of a list of protected names with the data of a
+    /// [`DataProvider`]
+    ///
+    /// Candidates must then be looked up with the `_with` methods and the same data.
+    pub fn new_with<I, D>(names: I, data: &D) -> Self
+    where
+        I: IntoIterator<Item = &'a str>,
+        D: DataProvider + ?Sized,
+    {
+        ProtectedNames {
+            names: names
+                .into_iter()
+                .map(|name| (name, skeleton_with(name, data).collect()))
+                .collect(),
+        }
+    }
+
+    /// Find the `k` protected names closest to a candidate by [`skeleton_distance`]
+    ///
+    /// The result is sorted by distance; names at the same distance keep the
+    /// order they were given in.
+    pub fn closest(&self, candidate: &str, k: usize) -> Vec<NameMatch<'a>> {
+        self.closest_weighted(candidate, k, &UnitWeights)
+    }
+
+    /// Find the [`closest`](Self::closest) protected names with the data of a
+    /// [`DataProvider`]
+    pub fn closest_with<D: DataProvider + ?Sized>(
+        &self,
+        candidate: &str,
+        k: usize,
+        data: &D,
+    ) -> Vec<NameMatch<'a>> {
+        self.closest_weighted_with(candidate, k, &UnitWeights, data)
+    }
+
+    /// Find the `k` protected names closest to a candidate by [`skeleton_distance_weighted`]
+    pub fn closest_weighted<W: SkeletonWeights + ?Sized>(
+        &self,
+        candidate: &str,
+        k: usize,
+        weights: &W,
+    ) -> Vec<NameMatch<'a>> {
+        self.closest_weighted_with(candidate, k, weights, &BakedData)
+    }
+
+    /// Find the [`closest_weighted`](Self::closest_weighted) protected names with
+    /// the data of a [`DataProvider`]
+    pub fn closest_weighted_with<W, D>(
+        &self,
+        candidate: &str,
+        k: usize,
+        weights: &W,
+        data: &D,
+    ) -> Vec<NameMatch<'a>>
+    where
+        W: SkeletonWeights + ?Sized,
+        D: DataProvider + ?Sized,
+    {
+        let candidate: Vec<char> = skeleton_with(candidate, data).collect();
+        let mut matches: Vec<NameMatch<'a>> = self
+            .names
+            .iter()
+            .map(|(name, skeleton)| NameMatch {
+                name,
+                distance: edit_distance(&candidate, skeleton, weights),
+            })
+            .collect();
+        matches.sort_by_key(|m| m.distance);
+        matches.truncate(k);
+        matches
+    }
+}
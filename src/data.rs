@@ -0,0 +1,413 @@
+//! Security data loaded at runtime
+//!
+//! The tables compiled into this crate are for the Unicode version in
+//! [`UNICODE_VERSION`](crate::UNICODE_VERSION). [`UnicodeData`] can replace
+//! them with newer or patched versions of confusables.txt, intentional.txt,
+//! IdentifierStatus.txt and IdentifierType.txt, parsed from local files or byte buffers, without
+//! rebuilding the crate. It is a [`DataProvider`], so it can be passed to the
+//! checks with a `_with` suffix, such as
+//! [`skeleton_with`](crate::confusable_detection::skeleton_with).
+//!
+//! ```rust,no_run
+//! use unicode_security::confusable_detection::skeleton_with;
+//! use unicode_security::data::UnicodeData;
+//!
+//! let data = UnicodeData::from_dir("/usr/share/unicode/security")?;
+//! let skeleton: String = skeleton_with("ｓｓ", &data).collect();
+//! # Ok::<(), std::io::Error>(())
+//! ```
+//!
+//! Data that is not loaded, and the data of other files such as
+//! DerivedCoreProperties.txt and Scripts.txt, is still taken from the
+//! compiled-in tables. The [potentially mixed-script confusable](crate::is_potential_mixed_script_confusable_char)
+//! characters are derived from the loaded confusables, the way the compiled-in
+//! table is generated from confusables.txt.
+
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str;
+use std::io;
+use std::path::Path;
+use unicode_script::{Script, UnicodeScript};
+
+use crate::general_security_profile::IdentifierType;
+use crate::provider::{BakedData, DataProvider};
+
+/// The file name of the confusables data
+pub const CONFUSABLES_FILE: &str = "confusables.txt";
+/// The file name of the intentional confusables data
+pub const INTENTIONAL_FILE: &str = "intentional.txt";
+/// The file name of the identifier status data
+pub const IDENTIFIER_STATUS_FILE: &str = "IdentifierStatus.txt";
+/// The file name of the identifier type data
+pub const IDENTIFIER_TYPE_FILE: &str = "IdentifierType.txt";
+
+/// An error encountered while parsing a data file
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct ParseError {
+    /// The name of the file being parsed
+    pub file: &'static str,
+    /// The 1-based number of the offending line
+    pub line: usize,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid data on line {} of {}", self.line, self.file)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<ParseError> for io::Error {
+    fn from(err: ParseError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, err)
+    }
+}
+
+type Loader = fn(&mut UnicodeData, &[u8]) -> Result<(), ParseError>;
+
+/// Security data to be used in place of the compiled-in tables
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct UnicodeData {
+    confusables: Option<Vec<(char, Vec<char>)>>,
+    intentional: Option<Vec<(char, Vec<char>)>>,
+    identifier_status: Option<Vec<(char, char)>>,
+    identifier_type: Option<Vec<(char, char, IdentifierType)>>,
+    /// Derived from `confusables` and `identifier_status` whenever either is loaded
+    mixed_script_confusables: Option<Vec<char>>,
+}
+
+impl UnicodeData {
+    /// Create a provider returning the compiled-in data
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load the data files found in a directory
+    ///
+    /// Each of [`CONFUSABLES_FILE`], [`INTENTIONAL_FILE`], [`IDENTIFIER_STATUS_FILE`]
+    /// and [`IDENTIFIER_TYPE_FILE`] is loaded if it exists. Parse errors are reported
+    /// as [`io::ErrorKind::InvalidData`].
+    pub fn from_dir<P: AsRef<Path>>(dir: P) -> io::Result<Self> {
+        let dir = dir.as_ref();
+        let mut data = UnicodeData::new();
+        let loaders: [(&str, Loader); 4] = [
+            (CONFUSABLES_FILE, Self::load_confusables),
+            (INTENTIONAL_FILE, Self::load_intentional),
+            (IDENTIFIER_STATUS_FILE, Self::load_identifier_status),
+            (IDENTIFIER_TYPE_FILE, Self::load_identifier_type),
+        ];
+        for (file, load) in loaders {
+            match std::fs::read(dir.join(file)) {
+                Ok(contents) => load(&mut data, &contents)?,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(data)
+    }
+
+    /// Replace the confusables data with the contents of a confusables.txt file
+    pub fn load_confusables(&mut self, contents: &[u8]) -> Result<(), ParseError> {
+        let mut confusables = Vec::new();
+        for_each_entry(CONFUSABLES_FILE, contents, |fields| {
+            let (source, prototype) = match fields {
+                [source, prototype, ..] => (source, prototype),
+                _ => return None,
+            };
+            match parse_code_points(source)?[..] {
+                [source] => {
+                    confusables.push((source, parse_code_points(prototype)?));
+                    Some(())
+                }
+                _ => None,
+            }
+        })?;
+        confusables.sort_unstable_by_key(|&(source, _)| source);
+        self.confusables = Some(confusables);
+        self.derive_mixed_script_confusables();
+        Ok(())
+    }
+
+    /// Replace the intentional confusables data with the contents of an intentional.txt file
+    pub fn load_intentional(&mut self, contents: &[u8]) -> Result<(), ParseError> {
+        let mut pairs = Vec::new();
+        for_each_entry(INTENTIONAL_FILE, contents, |fields| match fields {
+            [a, b, ..] => {
+                let (a, b) = (parse_code_point(a)?, parse_code_point(b)?);
+                pairs.extend([(a, b), (b, a)]);
+                Some(())
+            }
+            _ => None,
+        })?;
+        pairs.sort_unstable();
+        pairs.dedup();
+        let mut intentional: Vec<(char, Vec<char>)> = Vec::new();
+        for (a, b) in pairs {
+            match intentional.last_mut() {
+                Some((last, partners)) if *last == a => partners.push(b),
+                _ => intentional.push((a, vec![b])),
+            }
+        }
+        self.intentional = Some(intentional);
+        Ok(())
+    }
+
+    /// Replace the identifier status data with the contents of an IdentifierStatus.txt file
+    pub fn load_identifier_status(&mut self, contents: &[u8]) -> Result<(), ParseError> {
+        let mut allowed = Vec::new();
+        for_each_entry(IDENTIFIER_STATUS_FILE, contents, |fields| match fields {
+            [range, status, ..] => {
+                let range = parse_range(range)?;
+                match *status {
+                    "Allowed" => allowed.push(range),
+                    "Restricted" => {}
+                    _ => return None,
+                }
+                Some(())
+            }
+            _ => None,
+        })?;
+        allowed.sort_unstable();
+        self.identifier_status = Some(allowed);
+        self.derive_mixed_script_confusables();
+        Ok(())
+    }
+
+    /// Replace the identifier type data with the contents of an IdentifierType.txt file
+    ///
+    /// If a line lists several identifier types, the first one is used.
+    pub fn load_identifier_type(&mut self, contents: &[u8]) -> Result<(), ParseError> {
+        let mut types = Vec::new();
+        for_each_entry(IDENTIFIER_TYPE_FILE, contents, |fields| match fields {
+            [range, ty, ..] => {
+                let (lo, hi) = parse_range(range)?;
+                let ty = parse_identifier_type(ty.split_whitespace().next()?)?;
+                types.push((lo, hi, ty));
+                Some(())
+            }
+            _ => None,
+        })?;
+        types.sort_unstable_by_key(|&(lo, _, _)| lo);
+        self.identifier_type = Some(types);
+        Ok(())
+    }
+
+    /// Find the characters allowed in identifiers that are confusable with an
+    /// allowed character of another script, as `scripts/unicode.py` does for the
+    /// compiled-in table
+    ///
+    /// Characters with the same prototype are confusable with each other, and with
+    /// the prototype itself if it is a single allowed character. A character is
+    /// also confusable with a prototype of several allowed characters if mapping
+    /// it to the prototype drops its script and adds at most one other script.
+    fn derive_mixed_script_confusables(&mut self) {
+        let confusables = match self.confusables {
+            Some(ref confusables) => confusables,
+            None => return,
+        };
+        let mut classes: BTreeMap<&[char], Vec<char>> = BTreeMap::new();
+        for (source, prototype) in confusables {
+            if !self.identifier_allowed(*source) {
+                continue;
+            }
+            classes
+                .entry(&prototype[..])
+                .or_insert_with(|| match prototype[..] {
+                    [c] if self.identifier_allowed(c) => vec![c],
+                    _ => Vec::new(),
+                })
+                .push(*source);
+        }
+
+        let mut mixed_script_confusables = Vec::new();
+        for (prototype, class) in classes {
+            for (idx, &a) in class.iter().enumerate() {
+                for &b in &class[idx + 1..] {
+                    let (script_a, script_b) = (a.script(), b.script());
+                    if script_a == script_b {
+                        continue;
+                    }
+                    if !is_ignored_script(script_a) {
+                        mixed_script_confusables.push(a);
+                    }
+                    if !is_ignored_script(script_b) {
+                        mixed_script_confusables.push(b);
+                    }
+                }
+            }
+            if prototype.len() < 2 || !prototype.iter().all(|&c| self.identifier_allowed(c)) {
+                continue;
+            }
+            let mut prototype_scripts: Vec<Script> = Vec::new();
+            for c in prototype {
+                if !prototype_scripts.contains(&c.script()) {
+                    prototype_scripts.push(c.script());
+                }
+            }
+            let other_scripts = prototype_scripts
+                .iter()
+                .filter(|&&script| !is_ignored_script(script))
+                .count();
+            for c in class {
+                let script = c.script();
+                if !is_ignored_script(script)
+                    && !prototype_scripts.contains(&script)
+                    && prototype_scripts.len() <= 2
+                    && other_scripts <= 1
+                {
+                    mixed_script_confusables.push(c);
+                }
+            }
+        }
+        mixed_script_confusables.sort_unstable();
+        mixed_script_confusables.dedup();
+        self.mixed_script_confusables = Some(mixed_script_confusables);
+    }
+}
+
+/// Whether a script is left out of mixed-script confusable detection, since
+/// its characters are used with all scripts
+fn is_ignored_script(script: Script) -> bool {
+    matches!(script, Script::Common | Script::Inherited | Script::Unknown)
+}
+
+impl DataProvider for UnicodeData {
+    fn identifier_allowed(&self, c: char) -> bool {
+        match self.identifier_status {
+            Some(ref allowed) => find_range(allowed, c, |&(lo, hi)| (lo, hi)).is_some(),
+            None => BakedData.identifier_allowed(c),
+        }
+    }
+
+    fn identifier_type(&self, c: char) -> Option<IdentifierType> {
+        match self.identifier_type {
+            Some(ref types) => find_range(types, c, |&(lo, hi, _)| (lo, hi)).map(|&(_, _, ty)| ty),
+            None => BakedData.identifier_type(c),
+        }
+    }
+
+    fn confusable_prototype(&self, c: char) -> Option<&[char]> {
+        match self.confusables {
+            Some(ref confusables) => confusables
+                .binary_search_by_key(&c, |&(source, _)| source)
+                .ok()
+                .map(|idx| &confusables[idx].1[..]),
+            None => BakedData.confusable_prototype(c),
+        }
+    }
+
+    fn intentional_confusables(&self, c: char) -> &[char] {
+        match self.intentional {
+            Some(ref intentional) => intentional
+                .binary_search_by_key(&c, |&(a, _)| a)
+                .map_or(&[], |idx| &intentional[idx].1[..]),
+            None => BakedData.intentional_confusables(c),
+        }
+    }
+
+    fn potential_mixed_script_confusable(&self, c: char) -> bool {
+        match self.mixed_script_confusables {
+            Some(ref confusables) => confusables.binary_search(&c).is_ok(),
+            None => BakedData.potential_mixed_script_confusable(c),
+        }
+    }
+}
+
+/// Call `parse_entry` with the fields of every data line, stopping at the first
+/// line it fails to parse
+fn for_each_entry<F>(
+    file: &'static str,
+    contents: &[u8],
+    mut parse_entry: F,
+) -> Result<(), ParseError>
+where
+    F: FnMut(&[&str]) -> Option<()>,
+{
+    let contents = str::from_utf8(contents).map_err(|err| {
+        let line = contents[..err.valid_up_to()]
+            .iter()
+            .filter(|&&b| b == b'\n')
+            .count();
+        ParseError {
+            file,
+            line: line + 1,
+        }
+    })?;
+    let contents = contents.strip_prefix('\u{feff}').unwrap_or(contents);
+    let mut fields = Vec::new();
+    for (idx, line) in contents.lines().enumerate() {
+        let data = line.split('#').next().unwrap_or("").trim();
+        if data.is_empty() {
+            continue;
+        }
+        fields.clear();
+        fields.extend(data.split(';').map(str::trim));
+        if parse_entry(&fields).is_none() {
+            return Err(ParseError {
+                file,
+                line: idx + 1,
+            });
+        }
+    }
+    Ok(())
+}
+
+fn parse_code_point(s: &str) -> Option<char> {
+    u32::from_str_radix(s, 16).ok().and_then(char::from_u32)
+}
+
+fn parse_code_points(s: &str) -> Option<Vec<char>> {
+    s.split_whitespace().map(parse_code_point).collect()
+}
+
+fn parse_range(s: &str) -> Option<(char, char)> {
+    match s.split_once("..") {
+        Some((lo, hi)) => Some((parse_code_point(lo)?, parse_code_point(hi)?)),
+        None => parse_code_point(s).map(|c| (c, c)),
+    }
+}
+
+fn parse_identifier_type(s: &str) -> Option<IdentifierType> {
+    use IdentifierType::*;
+
+    Some(match s {
+        "Not_Character" => Not_Character,
+        "Deprecated" => Deprecated,
+        "Default_Ignorable" => Default_Ignorable,
+        "Not_NFKC" => Not_NFKC,
+        "Not_XID" => Not_XID,
+        "Exclusion" => Exclusion,
+        "Obsolete" => Obsolete,
+        "Technical" => Technical,
+        "Uncommon_Use" => Uncommon_Use,
+        "Limited_Use" => Limited_Use,
+        "Inclusion" => Inclusion,
+        "Recommended" => Recommended,
+        _ => return None,
+    })
+}
+
+fn find_range<T, F>(ranges: &[T], c: char, bounds: F) -> Option<&T>
+where
+    F: Fn(&T) -> (char, char),
+{
+    use core::cmp::Ordering::{Equal, Greater, Less};
+
+    ranges
+        .binary_search_by(|entry| {
+            let (lo, hi) = bounds(entry);
+            if hi < c {
+                Less
+            } else if lo > c {
+                Greater
+            } else {
+                Equal
+            }
+        })
+        .ok()
+        .map(|idx| &ranges[idx])
+}
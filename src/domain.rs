@@ -0,0 +1,275 @@
+//! Spoof checking for [internationalized domain names](https://www.unicode.org/reports/tr39/#Restriction_Level_Detection)
+//!
+//! Browsers decide per label whether a domain name can be shown in its Unicode
+//! form or has to fall back to Punycode. This module combines the restriction
+//! level, mixed-script and confusable checks of this crate into such a verdict.
+//!
+//! The input is expected to already be mapped according to IDNA (lowercased and
+//! normalized); this module does not implement UTS #46 processing.
+
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
+use crate::findings::{Finding, SecurityChecker};
+use crate::provider::{BakedData, DataProvider};
+use crate::restriction_level::detect_restriction_level_with;
+use crate::RestrictionLevel;
+
+/// The characters that separate domain name labels: FULL STOP, IDEOGRAPHIC FULL STOP,
+/// FULLWIDTH FULL STOP and HALFWIDTH IDEOGRAPHIC FULL STOP
+const LABEL_SEPARATORS: &[char] = &['.', '\u{3002}', '\u{ff0e}', '\u{ff61}'];
+
+/// The ACE prefix marking a Punycode encoded label
+const ACE_PREFIX: &str = "xn--";
+
+/// Split a domain name into its labels
+///
+/// All label separators recognized by IDNA are accepted. The empty label of the
+/// root, as in `example.com.`, is not returned, but other empty labels, as in
+/// `example..com`, are.
+pub fn labels(domain: &str) -> impl Iterator<Item = &str> {
+    let domain = domain
+        .strip_suffix(|c| LABEL_SEPARATORS.contains(&c))
+        .unwrap_or(domain);
+    domain.split(LABEL_SEPARATORS)
+}
+
+/// An error encountered while decoding a label
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum LabelError {
+    /// The label is empty, as between two consecutive separators
+    EmptyLabel,
+    /// The label starts with `xn--` but is not valid Punycode
+    InvalidPunycode,
+    /// The label starts with `xn--` but decodes to ASCII only
+    ///
+    /// Registries never produce such labels, they only show up in spoofing attempts.
+    AsciiPunycode,
+}
+
+/// Decode a label to its Unicode form
+///
+/// Labels with the `xn--` prefix are decoded from Punycode, all other labels are
+/// returned unchanged.
+pub fn decode_label(label: &str) -> Result<Cow<'_, str>, LabelError> {
+    if label.is_empty() {
+        return Err(LabelError::EmptyLabel);
+    }
+    match strip_ace_prefix(label) {
+        None => Ok(Cow::Borrowed(label)),
+        Some(encoded) => {
+            let decoded = punycode::decode(encoded).ok_or(LabelError::InvalidPunycode)?;
+            if decoded.is_ascii() {
+                return Err(LabelError::AsciiPunycode);
+            }
+            Ok(Cow::Owned(decoded))
+        }
+    }
+}
+
+fn strip_ace_prefix(label: &str) -> Option<&str> {
+    let prefix = label.get(..ACE_PREFIX.len())?;
+    if prefix.eq_ignore_ascii_case(ACE_PREFIX) {
+        Some(&label[ACE_PREFIX.len()..])
+    } else {
+        None
+    }
+}
+
+/// The verdict for a single domain name label
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct LabelVerdict<'a> {
+    /// The label as it appears in the domain name
+    pub label: &'a str,
+    /// The Unicode form of the label
+    pub unicode: Result<Cow<'a, str>, LabelError>,
+    /// The [restriction level](https://www.unicode.org/reports/tr39/#Restriction_Level_Detection)
+    /// of the Unicode form
+    pub restriction_level: RestrictionLevel,
+    /// The problems found in the Unicode form: a less restrictive restriction
+    /// level than allowed, and mixed-script or whole-script confusables
+    ///
+    /// Mixed-script confusables are only reported for non-ASCII characters, so
+    /// that a Latin label mixed with another script, as in `sony日本`, is not
+    /// rejected for its Latin letters alone.
+    pub findings: Vec<Finding>,
+    /// Whether the label is safe to display in its Unicode form
+    pub safe: bool,
+}
+
+/// Checks domain name labels against a display policy
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct DomainChecker {
+    max_restriction_level: RestrictionLevel,
+}
+
+impl Default for DomainChecker {
+    fn default() -> Self {
+        DomainChecker {
+            max_restriction_level: RestrictionLevel::HighlyRestrictive,
+        }
+    }
+}
+
+impl DomainChecker {
+    /// Create a checker with the default policy, which allows labels up to
+    /// [`RestrictionLevel::HighlyRestrictive`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the least restrictive level a label may have to be displayed in Unicode form
+    pub fn max_restriction_level(mut self, level: RestrictionLevel) -> Self {
+        self.max_restriction_level = level;
+        self
+    }
+
+    /// Check every label of a domain name
+    pub fn check_domain<'a>(&self, domain: &'a str) -> Vec<LabelVerdict<'a>> {
+        self.check_domain_with(domain, &BakedData)
+    }
+
+    /// Check every label of a domain name, with the data of a [`DataProvider`]
+    pub fn check_domain_with<'a, D: DataProvider + ?Sized>(
+        &self,
+        domain: &'a str,
+        data: &D,
+    ) -> Vec<LabelVerdict<'a>> {
+        labels(domain)
+            .map(|label| self.check_label_with(label, data))
+            .collect()
+    }
+
+    /// Check a single domain name label
+    pub fn check_label<'a>(&self, label: &'a str) -> LabelVerdict<'a> {
+        self.check_label_with(label, &BakedData)
+    }
+
+    /// Check a single domain name label, with the data of a [`DataProvider`]
+    pub fn check_label_with<'a, D: DataProvider + ?Sized>(
+        &self,
+        label: &'a str,
+        data: &D,
+    ) -> LabelVerdict<'a> {
+        let unicode = decode_label(label);
+        let text = match unicode {
+            Ok(ref text) => text,
+            Err(_) => {
+                return LabelVerdict {
+                    label,
+                    unicode,
+                    restriction_level: RestrictionLevel::Unrestricted,
+                    findings: Vec::new(),
+                    safe: false,
+                }
+            }
+        };
+
+        let restriction_level = detect_restriction_level_with(text, data);
+        let findings = SecurityChecker::new()
+            .restricted_chars(false)
+            .default_ignorables(false)
+            .mixed_script(false)
+            .max_restriction_level(Some(self.max_restriction_level))
+            .check_with(text, data);
+        let safe = findings.is_empty();
+
+        LabelVerdict {
+            label,
+            unicode,
+            restriction_level,
+            findings,
+            safe,
+        }
+    }
+}
+
+/// Check every label of a domain name with the default [`DomainChecker`] policy
+pub fn check_domain(domain: &str) -> Vec<LabelVerdict<'_>> {
+    DomainChecker::default().check_domain(domain)
+}
+
+/// [Punycode](https://www.rfc-editor.org/rfc/rfc3492) decoding
+mod punycode {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    const BASE: u32 = 36;
+    const T_MIN: u32 = 1;
+    const T_MAX: u32 = 26;
+    const SKEW: u32 = 38;
+    const DAMP: u32 = 700;
+    const INITIAL_BIAS: u32 = 72;
+    const INITIAL_N: u32 = 128;
+    const DELIMITER: char = '-';
+
+    fn adapt(mut delta: u32, num_points: u32, first_time: bool) -> u32 {
+        delta /= if first_time { DAMP } else { 2 };
+        delta += delta / num_points;
+        let mut k = 0;
+        while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+            delta /= BASE - T_MIN;
+            k += BASE;
+        }
+        k + (((BASE - T_MIN + 1) * delta) / (delta + SKEW))
+    }
+
+    fn digit_value(byte: u8) -> Option<u32> {
+        match byte {
+            b'0'..=b'9' => Some(u32::from(byte - b'0') + 26),
+            b'A'..=b'Z' => Some(u32::from(byte - b'A')),
+            b'a'..=b'z' => Some(u32::from(byte - b'a')),
+            _ => None,
+        }
+    }
+
+    /// Decode the part of a label following the ACE prefix
+    ///
+    /// Returns `None` if the input is not valid Punycode.
+    pub(super) fn decode(input: &str) -> Option<String> {
+        if !input.is_ascii() {
+            return None;
+        }
+        let (basic, extended) = match input.rfind(DELIMITER) {
+            Some(idx) => (&input[..idx], &input[idx + 1..]),
+            None => ("", input),
+        };
+
+        let mut output: Vec<char> = basic.chars().collect();
+        let mut n = INITIAL_N;
+        let mut i: u32 = 0;
+        let mut bias = INITIAL_BIAS;
+        let mut bytes = extended.bytes().peekable();
+
+        while bytes.peek().is_some() {
+            let old_i = i;
+            let mut weight: u32 = 1;
+            let mut k = BASE;
+            loop {
+                let digit = digit_value(bytes.next()?)?;
+                i = i.checked_add(digit.checked_mul(weight)?)?;
+                let t = if k <= bias {
+                    T_MIN
+                } else if k >= bias + T_MAX {
+                    T_MAX
+                } else {
+                    k - bias
+                };
+                if digit < t {
+                    break;
+                }
+                weight = weight.checked_mul(BASE - t)?;
+                k += BASE;
+            }
+
+            let len = output.len() as u32 + 1;
+            bias = adapt(i - old_i, len, old_i == 0);
+            n = n.checked_add(i / len)?;
+            i %= len;
+            output.insert(i as usize, core::char::from_u32(n)?);
+            i += 1;
+        }
+
+        Some(output.into_iter().collect())
+    }
+}
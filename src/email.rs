@@ -0,0 +1,172 @@
+//! Checks for email addresses following the
+//! [Email Security Profiles for Identifiers](https://www.unicode.org/reports/tr39/#Email_Security_Profiles)
+//!
+//! The local part is checked against the email profile, which extends the
+//! `dot-atom-text` syntax of [RFC 5322](https://www.rfc-editor.org/rfc/rfc5322#section-3.2.3)
+//! with characters allowed in identifiers. The domain is checked label by label
+//! with the [`domain`](crate::domain) checks. Problems in the local part are
+//! reported as [`Finding`]s.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::domain::{DomainChecker, LabelVerdict};
+use crate::findings::{Finding, FindingKind};
+use crate::provider::{BakedData, DataProvider};
+use crate::restriction_level::detect_restriction_level_with;
+use crate::RestrictionLevel;
+
+/// The ASCII characters other than letters and digits allowed in `atext`
+const ATEXT_SPECIALS: &str = "!#$%&'*+-/=?^_`{|}~";
+
+/// The least restrictive level the local part may have
+const MAX_LOCAL_PART_RESTRICTION_LEVEL: RestrictionLevel = RestrictionLevel::HighlyRestrictive;
+
+/// An error preventing an email address from being checked at all
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum EmailError {
+    /// The address does not contain an `@`
+    MissingAt,
+    /// The domain following the `@` is empty
+    EmptyDomain,
+}
+
+/// The findings for an email address
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct EmailReport<'a> {
+    /// The part before the last `@`
+    pub local_part: &'a str,
+    /// The part after the last `@`
+    pub domain: &'a str,
+    /// Problems found in the local part, as returned by [`check_local_part`]
+    pub local_part_findings: Vec<Finding>,
+    /// The verdict for each label of the domain
+    pub domain_labels: Vec<LabelVerdict<'a>>,
+}
+
+impl EmailReport<'_> {
+    /// Whether the local part satisfies the email profile and every domain label is safe
+    pub fn is_safe(&self) -> bool {
+        self.local_part_findings.is_empty() && self.domain_labels.iter().all(|v| v.safe)
+    }
+}
+
+/// Check an email address, using the default [`DomainChecker`] for the domain
+pub fn check_email(address: &str) -> Result<EmailReport<'_>, EmailError> {
+    check_email_with_checker(address, &DomainChecker::default())
+}
+
+/// Check an email address with the data of a [`DataProvider`], using the default
+/// [`DomainChecker`] for the domain
+pub fn check_email_with<'a, D: DataProvider + ?Sized>(
+    address: &'a str,
+    data: &D,
+) -> Result<EmailReport<'a>, EmailError> {
+    check_email_with_checker_and_data(address, &DomainChecker::default(), data)
+}
+
+/// Check an email address, using the given [`DomainChecker`] for the domain
+pub fn check_email_with_checker<'a>(
+    address: &'a str,
+    domain_checker: &DomainChecker,
+) -> Result<EmailReport<'a>, EmailError> {
+    check_email_with_checker_and_data(address, domain_checker, &BakedData)
+}
+
+/// Check an email address with the data of a [`DataProvider`], using the given
+/// [`DomainChecker`] for the domain
+pub fn check_email_with_checker_and_data<'a, D: DataProvider + ?Sized>(
+    address: &'a str,
+    domain_checker: &DomainChecker,
+    data: &D,
+) -> Result<EmailReport<'a>, EmailError> {
+    let at = address.rfind('@').ok_or(EmailError::MissingAt)?;
+    let (local_part, domain) = (&address[..at], &address[at + 1..]);
+    if domain.is_empty() {
+        return Err(EmailError::EmptyDomain);
+    }
+    Ok(EmailReport {
+        local_part,
+        domain,
+        local_part_findings: check_local_part_with(local_part, data),
+        domain_labels: domain_checker.check_domain_with(domain, data),
+    })
+}
+
+/// Check the local part of an email address against the email profile
+///
+/// The findings are, in this order:
+///
+/// - [`FindingKind::NotNfkc`] if the local part is not in NFKC
+/// - [`FindingKind::InvalidSyntax`] for each dot that starts or ends the local
+///   part or follows another dot, and [`FindingKind::RestrictedChar`] for each
+///   character that is neither ASCII `atext` nor allowed in identifiers, in order
+///   of appearance
+/// - [`FindingKind::RestrictionLevel`] if the local part is less restrictive
+///   than [`RestrictionLevel::HighlyRestrictive`]
+///
+/// An empty local part is reported as a single [`FindingKind::InvalidSyntax`].
+pub fn check_local_part(local_part: &str) -> Vec<Finding> {
+    check_local_part_with(local_part, &BakedData)
+}
+
+/// Check the local part of an email address against the email profile, with
+/// the data of a [`DataProvider`]
+///
+/// NFKC is checked with the data of the `unicode-normalization` crate, which
+/// is not provided by a `DataProvider`.
+pub fn check_local_part_with<D: DataProvider + ?Sized>(local_part: &str, data: &D) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    if local_part.is_empty() {
+        findings.push(Finding::new(FindingKind::InvalidSyntax, 0..0));
+        return findings;
+    }
+    if !unicode_normalization::is_nfkc(local_part) {
+        findings.push(Finding::new(FindingKind::NotNfkc, 0..local_part.len()));
+    }
+
+    let mut identifier_chars = String::new();
+    let mut previous_dot = true;
+    for (offset, ch) in local_part.char_indices() {
+        if ch == '.' {
+            if previous_dot {
+                findings.push(Finding::for_char_with(
+                    FindingKind::InvalidSyntax,
+                    offset,
+                    ch,
+                    data,
+                ));
+            }
+            previous_dot = true;
+            continue;
+        }
+        previous_dot = false;
+        if ATEXT_SPECIALS.contains(ch) {
+            continue;
+        }
+        if !ch.is_ascii_alphanumeric() && !data.identifier_allowed(ch) {
+            findings.push(Finding::for_char_with(
+                FindingKind::RestrictedChar,
+                offset,
+                ch,
+                data,
+            ));
+            continue;
+        }
+        identifier_chars.push(ch);
+    }
+    let trailing_dot =
+        Finding::for_char_with(FindingKind::InvalidSyntax, local_part.len() - 1, '.', data);
+    if previous_dot && findings.last() != Some(&trailing_dot) {
+        findings.push(trailing_dot);
+    }
+
+    let level = detect_restriction_level_with(&identifier_chars, data);
+    if level > MAX_LOCAL_PART_RESTRICTION_LEVEL {
+        findings.push(Finding::new(
+            FindingKind::RestrictionLevel(level),
+            0..local_part.len(),
+        ));
+    }
+    findings
+}
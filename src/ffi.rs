@@ -0,0 +1,309 @@
+//! C bindings
+//!
+//! These functions give C and C++ code, and languages calling C such as Go
+//! through cgo, the same answers as the Rust API. The declarations are in
+//! `ffi/unicode_security.h`, generated with `cbindgen`; `ffi/Makefile` builds a
+//! static library and runs the C test program in `ffi/test.c` against it.
+//!
+//! Strings are passed as a pointer to UTF-8 bytes and a length, and need not be
+//! NUL-terminated. Results that are strings are written to a caller-provided
+//! buffer, see [`unicode_security_skeleton`].
+
+#![allow(unsafe_code)]
+
+use core::fmt::{self, Write};
+use core::{slice, str};
+
+use crate::general_security_profile::IdentifierType;
+use crate::mixed_script::AugmentedScriptSet;
+use crate::{skeleton, GeneralSecurityProfile, MixedScript};
+use crate::{RestrictionLevel, RestrictionLevelDetection};
+
+/// The outcome of a call taking a string
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum UnicodeSecurityStatus {
+    /// The call succeeded
+    Ok = 0,
+    /// A pointer argument was null
+    NullPointer = 1,
+    /// The string is not valid UTF-8
+    InvalidUtf8 = 2,
+    /// The output buffer is too small for the result, whose length was still
+    /// written
+    BufferTooSmall = 3,
+}
+
+/// The identifier type of a code point, as returned by
+/// `GeneralSecurityProfile::identifier_type`, or `None` if it has none
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum UnicodeSecurityIdentifierType {
+    /// No identifier type, or not a valid code point
+    None = 0,
+    /// Not_Character
+    NotCharacter,
+    /// Deprecated
+    Deprecated,
+    /// Default_Ignorable
+    DefaultIgnorable,
+    /// Not_NFKC
+    NotNfkc,
+    /// Not_XID
+    NotXid,
+    /// Exclusion
+    Exclusion,
+    /// Obsolete
+    Obsolete,
+    /// Technical
+    Technical,
+    /// Uncommon_Use
+    UncommonUse,
+    /// Limited_Use
+    LimitedUse,
+    /// Inclusion
+    Inclusion,
+    /// Recommended
+    Recommended,
+}
+
+impl From<Option<IdentifierType>> for UnicodeSecurityIdentifierType {
+    fn from(ty: Option<IdentifierType>) -> Self {
+        let ty = match ty {
+            Some(ty) => ty,
+            None => return Self::None,
+        };
+        match ty {
+            IdentifierType::Not_Character => Self::NotCharacter,
+            IdentifierType::Deprecated => Self::Deprecated,
+            IdentifierType::Default_Ignorable => Self::DefaultIgnorable,
+            IdentifierType::Not_NFKC => Self::NotNfkc,
+            IdentifierType::Not_XID => Self::NotXid,
+            IdentifierType::Exclusion => Self::Exclusion,
+            IdentifierType::Obsolete => Self::Obsolete,
+            IdentifierType::Technical => Self::Technical,
+            IdentifierType::Uncommon_Use => Self::UncommonUse,
+            IdentifierType::Limited_Use => Self::LimitedUse,
+            IdentifierType::Inclusion => Self::Inclusion,
+            IdentifierType::Recommended => Self::Recommended,
+        }
+    }
+}
+
+/// A restriction level, as returned by `RestrictionLevelDetection::detect_restriction_level`
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum UnicodeSecurityRestrictionLevel {
+    /// ASCII-Only
+    AsciiOnly = 0,
+    /// Single Script
+    SingleScript,
+    /// Highly Restrictive
+    HighlyRestrictive,
+    /// Moderately Restrictive
+    ModeratelyRestrictive,
+    /// Minimally Restrictive
+    MinimallyRestrictive,
+    /// Unrestricted
+    Unrestricted,
+}
+
+impl From<RestrictionLevel> for UnicodeSecurityRestrictionLevel {
+    fn from(level: RestrictionLevel) -> Self {
+        use UnicodeSecurityRestrictionLevel::*;
+
+        match level {
+            RestrictionLevel::ASCIIOnly => AsciiOnly,
+            RestrictionLevel::SingleScript => SingleScript,
+            RestrictionLevel::HighlyRestrictive => HighlyRestrictive,
+            RestrictionLevel::ModeratelyRestrictive => ModeratelyRestrictive,
+            RestrictionLevel::MinimallyRestrictive => MinimallyRestrictive,
+            RestrictionLevel::Unrestricted => Unrestricted,
+        }
+    }
+}
+
+/// Check whether a code point is allowed in identifiers
+///
+/// Values that are not code points, such as surrogates, are not allowed.
+#[no_mangle]
+pub extern "C" fn unicode_security_identifier_allowed(c: u32) -> bool {
+    char::from_u32(c).is_some_and(|c| c.identifier_allowed())
+}
+
+/// Find the identifier type of a code point
+#[no_mangle]
+pub extern "C" fn unicode_security_identifier_type(c: u32) -> UnicodeSecurityIdentifierType {
+    char::from_u32(c).and_then(|c| c.identifier_type()).into()
+}
+
+/// Detect the restriction level of a string
+///
+/// # Safety
+///
+/// `s` must point to `len` readable bytes, or be null if `len` is 0. `level`
+/// must point to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn unicode_security_restriction_level(
+    s: *const u8,
+    len: usize,
+    level: *mut UnicodeSecurityRestrictionLevel,
+) -> UnicodeSecurityStatus {
+    match str_arg(s, len) {
+        Ok(s) => write_out(level, s.detect_restriction_level().into()),
+        Err(status) => status,
+    }
+}
+
+/// Check whether a string is single-script
+///
+/// # Safety
+///
+/// `s` must point to `len` readable bytes, or be null if `len` is 0.
+/// `single_script` must point to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn unicode_security_is_single_script(
+    s: *const u8,
+    len: usize,
+    single_script: *mut bool,
+) -> UnicodeSecurityStatus {
+    match str_arg(s, len) {
+        Ok(s) => write_out(single_script, s.is_single_script()),
+        Err(status) => status,
+    }
+}
+
+/// Write the resolved script set of a string to a buffer
+///
+/// The set is written as the ISO 15924 codes of its scripts separated by
+/// spaces, with the augmented `Hanb`, `Jpan` and `Kore` first, as in `"Latn"` or
+/// `"Hanb Jpan Kore Hani"`. The empty set, of a mixed-script string, is written
+/// as an empty string, and the set of all scripts, of a string with only Common
+/// and Inherited characters, as `"Zyyy"`. See
+/// `AugmentedScriptSet::script_codes`. Otherwise this works like
+/// [`unicode_security_skeleton`].
+///
+/// # Safety
+///
+/// As for [`unicode_security_skeleton`].
+#[no_mangle]
+pub unsafe extern "C" fn unicode_security_resolve_script_set(
+    s: *const u8,
+    len: usize,
+    buf: *mut u8,
+    buf_len: usize,
+    written: *mut usize,
+) -> UnicodeSecurityStatus {
+    match str_arg(s, len) {
+        Ok(s) => write_str(buf, buf_len, written, |w| {
+            write_script_set(w, s.resolve_script_set())
+        }),
+        Err(status) => status,
+    }
+}
+
+/// Write the skeleton of a string to a buffer, as UTF-8
+///
+/// The length of the skeleton in bytes is written to `written`. If it does not
+/// fit into the `buf_len` bytes of `buf`, [`UnicodeSecurityStatus::BufferTooSmall`]
+/// is returned, and the call can be repeated with a buffer of that length. The
+/// result is not NUL-terminated.
+///
+/// # Safety
+///
+/// `s` must point to `len` readable bytes, or be null if `len` is 0. `buf` must
+/// point to `buf_len` writable bytes, or be null if `buf_len` is 0. `written`
+/// must point to writable memory.
+#[no_mangle]
+pub unsafe extern "C" fn unicode_security_skeleton(
+    s: *const u8,
+    len: usize,
+    buf: *mut u8,
+    buf_len: usize,
+    written: *mut usize,
+) -> UnicodeSecurityStatus {
+    match str_arg(s, len) {
+        Ok(s) => write_str(buf, buf_len, written, |w| {
+            skeleton(s).try_for_each(|c| w.write_char(c))
+        }),
+        Err(status) => status,
+    }
+}
+
+unsafe fn str_arg<'a>(s: *const u8, len: usize) -> Result<&'a str, UnicodeSecurityStatus> {
+    if len == 0 {
+        return Ok("");
+    }
+    if s.is_null() {
+        return Err(UnicodeSecurityStatus::NullPointer);
+    }
+    str::from_utf8(slice::from_raw_parts(s, len)).map_err(|_| UnicodeSecurityStatus::InvalidUtf8)
+}
+
+unsafe fn write_out<T>(out: *mut T, value: T) -> UnicodeSecurityStatus {
+    if out.is_null() {
+        return UnicodeSecurityStatus::NullPointer;
+    }
+    out.write(value);
+    UnicodeSecurityStatus::Ok
+}
+
+/// Write the output of `f` to a caller-provided buffer, or only count its
+/// length if it does not fit
+unsafe fn write_str<F>(
+    buf: *mut u8,
+    buf_len: usize,
+    written: *mut usize,
+    f: F,
+) -> UnicodeSecurityStatus
+where
+    F: Fn(&mut dyn Write) -> fmt::Result,
+{
+    if written.is_null() || (buf.is_null() && buf_len != 0) {
+        return UnicodeSecurityStatus::NullPointer;
+    }
+    let mut counter = Counter(0);
+    // Neither writer fails
+    let _ = f(&mut counter);
+    written.write(counter.0);
+    if counter.0 > buf_len {
+        return UnicodeSecurityStatus::BufferTooSmall;
+    }
+    if counter.0 != 0 {
+        let mut out = Buffer(slice::from_raw_parts_mut(buf, counter.0));
+        let _ = f(&mut out);
+    }
+    UnicodeSecurityStatus::Ok
+}
+
+fn write_script_set(w: &mut dyn Write, set: AugmentedScriptSet) -> fmt::Result {
+    for (idx, code) in set.script_codes().enumerate() {
+        if idx != 0 {
+            w.write_char(' ')?;
+        }
+        w.write_str(code)?;
+    }
+    Ok(())
+}
+
+/// Counts the bytes written to it
+struct Counter(usize);
+
+impl Write for Counter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+/// Writes to a byte slice of the exact length of the output
+struct Buffer<'a>(&'a mut [u8]);
+
+impl Write for Buffer<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let (head, tail) = core::mem::take(&mut self.0).split_at_mut(s.len());
+        head.copy_from_slice(s.as_bytes());
+        self.0 = tail;
+        Ok(())
+    }
+}
@@ -0,0 +1,311 @@
+//! A common report format for the checks of this crate
+//!
+//! Every check answers a different question: whether a character is allowed, which
+//! restriction level a string has, whether it mixes scripts. [`SecurityChecker`]
+//! runs a configurable set of them over a string and reports each problem as a
+//! [`Finding`], so consumers can combine the results without per-check glue. The
+//! [`domain`](crate::domain) and [`email`](crate::email) checks report their
+//! problems as `Finding`s too.
+//!
+//! With the `serde` feature, findings implement `serde::Serialize`.
+//!
+//! ```rust
+//! use unicode_security::findings::{FindingKind, SecurityChecker};
+//!
+//! let findings = SecurityChecker::new().check("pаypal"); // with a Cyrillic 'а'
+//! assert!(findings
+//!     .iter()
+//!     .any(|f| f.kind == FindingKind::MixedScriptConfusable));
+//! ```
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::confusable_detection::skeleton_with;
+use crate::general_security_profile::is_bidi_control;
+use crate::mixed_script::{is_single_script_with, resolve_script_set_with, AugmentedScriptSet};
+use crate::provider::{BakedData, DataProvider};
+use crate::restriction_level::detect_restriction_level_with;
+use crate::RestrictionLevel;
+
+/// How serious a finding is
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Severity {
+    /// Worth knowing about, but common in legitimate text
+    Info,
+    /// Unusual in legitimate text
+    Warning,
+    /// Likely to be a spoofing attempt
+    Error,
+}
+
+/// The problem reported by a [`Finding`]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum FindingKind {
+    /// A character [restricted](crate::GeneralSecurityProfile::identifier_allowed)
+    /// from use in identifiers, or otherwise not allowed where it occurs
+    RestrictedChar,
+    /// A [default ignorable code point](crate::is_default_ignorable_code_point),
+    /// which is usually invisible
+    DefaultIgnorable,
+    /// A [bidirectional control](crate::is_bidi_control), which reorders the
+    /// text around it
+    BidiControl,
+    /// The string is not [single-script](crate::MixedScript::is_single_script)
+    MixedScript,
+    /// The string has a less restrictive
+    /// [restriction level](https://www.unicode.org/reports/tr39/#Restriction_Level_Detection)
+    /// than allowed
+    RestrictionLevel(RestrictionLevel),
+    /// The string mixes scripts and contains characters that are
+    /// [confusable](crate::is_potential_mixed_script_confusable_char) with characters
+    /// of another script
+    MixedScriptConfusable,
+    /// The string is written in a single script other than ASCII, but its
+    /// [skeleton](crate::skeleton) is entirely ASCII
+    WholeScriptConfusable,
+    /// The string has the same [skeleton](crate::skeleton) as another string
+    /// it is used together with
+    SkeletonCollision,
+    /// The string is not in NFKC
+    NotNfkc,
+    /// The string does not follow the syntax required where it is used, such as
+    /// an email address with two dots in a row
+    InvalidSyntax,
+}
+
+impl FindingKind {
+    /// The severity [`SecurityChecker`] reports findings of this kind with
+    pub fn severity(self) -> Severity {
+        match self {
+            FindingKind::MixedScript => Severity::Info,
+            FindingKind::RestrictedChar
+            | FindingKind::DefaultIgnorable
+            | FindingKind::RestrictionLevel(_)
+            | FindingKind::NotNfkc => Severity::Warning,
+            FindingKind::BidiControl
+            | FindingKind::MixedScriptConfusable
+            | FindingKind::WholeScriptConfusable
+            | FindingKind::SkeletonCollision
+            | FindingKind::InvalidSyntax => Severity::Error,
+        }
+    }
+}
+
+/// A problem found by a check
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Finding {
+    /// What the problem is
+    pub kind: FindingKind,
+    /// How serious the problem is
+    pub severity: Severity,
+    /// The byte range of the checked string the problem applies to
+    pub range: Range<usize>,
+    /// The characters responsible for the problem, in order of appearance
+    ///
+    /// This is empty if the problem is with the string as a whole.
+    pub chars: Vec<char>,
+    /// The distinct script sets involved, in order of appearance
+    pub scripts: Vec<AugmentedScriptSet>,
+}
+
+impl Finding {
+    /// Create a finding about a byte range of a string, with the
+    /// [severity](FindingKind::severity) of its kind and no characters or scripts
+    pub fn new(kind: FindingKind, range: Range<usize>) -> Self {
+        Finding {
+            kind,
+            severity: kind.severity(),
+            range,
+            chars: Vec::new(),
+            scripts: Vec::new(),
+        }
+    }
+
+    /// Create a finding about the character at a byte offset of a string
+    pub fn for_char(kind: FindingKind, offset: usize, c: char) -> Self {
+        Finding::for_char_with(kind, offset, c, &BakedData)
+    }
+
+    pub(crate) fn for_char_with<D: DataProvider + ?Sized>(
+        kind: FindingKind,
+        offset: usize,
+        c: char,
+        data: &D,
+    ) -> Self {
+        let mut finding = Finding::new(kind, offset..offset + c.len_utf8());
+        finding.chars.push(c);
+        finding
+            .scripts
+            .push(AugmentedScriptSet::for_char_with(c, data));
+        finding
+    }
+}
+
+/// Runs a configurable set of checks over strings and collects [`Finding`]s
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct SecurityChecker {
+    restricted_chars: bool,
+    default_ignorables: bool,
+    mixed_script: bool,
+    max_restriction_level: Option<RestrictionLevel>,
+    confusables: bool,
+}
+
+impl Default for SecurityChecker {
+    fn default() -> Self {
+        SecurityChecker {
+            restricted_chars: true,
+            default_ignorables: true,
+            mixed_script: true,
+            max_restriction_level: Some(RestrictionLevel::HighlyRestrictive),
+            confusables: true,
+        }
+    }
+}
+
+impl SecurityChecker {
+    /// Create a checker with all checks enabled, which allows strings up to
+    /// [`RestrictionLevel::HighlyRestrictive`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enable or disable reporting characters restricted from use in identifiers
+    pub fn restricted_chars(mut self, enabled: bool) -> Self {
+        self.restricted_chars = enabled;
+        self
+    }
+
+    /// Enable or disable reporting default ignorable code points
+    ///
+    /// Bidirectional controls are reported as [`FindingKind::BidiControl`].
+    pub fn default_ignorables(mut self, enabled: bool) -> Self {
+        self.default_ignorables = enabled;
+        self
+    }
+
+    /// Enable or disable reporting strings that are not single-script
+    pub fn mixed_script(mut self, enabled: bool) -> Self {
+        self.mixed_script = enabled;
+        self
+    }
+
+    /// Set the least restrictive level a string may have, or `None` to disable
+    /// the restriction level check
+    pub fn max_restriction_level(mut self, level: Option<RestrictionLevel>) -> Self {
+        self.max_restriction_level = level;
+        self
+    }
+
+    /// Enable or disable reporting mixed-script and whole-script confusables
+    pub fn confusables(mut self, enabled: bool) -> Self {
+        self.confusables = enabled;
+        self
+    }
+
+    /// Run the enabled checks over a string
+    ///
+    /// Findings about single characters come first, in order of appearance,
+    /// followed by findings about the string as a whole.
+    pub fn check(&self, s: &str) -> Vec<Finding> {
+        self.check_with(s, &BakedData)
+    }
+
+    /// Run the enabled checks over a string, with the data of a [`DataProvider`]
+    pub fn check_with<D: DataProvider + ?Sized>(&self, s: &str, data: &D) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        for (offset, c) in s.char_indices() {
+            if self.restricted_chars && !data.identifier_allowed(c) {
+                findings.push(Finding::for_char_with(
+                    FindingKind::RestrictedChar,
+                    offset,
+                    c,
+                    data,
+                ));
+            }
+            if self.default_ignorables && data.default_ignorable(c) {
+                let kind = if is_bidi_control(c) {
+                    FindingKind::BidiControl
+                } else {
+                    FindingKind::DefaultIgnorable
+                };
+                findings.push(Finding::for_char_with(kind, offset, c, data));
+            }
+        }
+
+        let single_script = is_single_script_with(s, data);
+        if self.mixed_script && !single_script {
+            let mut finding = Finding::new(FindingKind::MixedScript, 0..s.len());
+            finding.scripts = script_sets(s, data);
+            findings.push(finding);
+        }
+        if let Some(max) = self.max_restriction_level {
+            let level = detect_restriction_level_with(s, data);
+            if level > max {
+                let mut finding = Finding::new(FindingKind::RestrictionLevel(level), 0..s.len());
+                finding.scripts = script_sets(s, data);
+                findings.push(finding);
+            }
+        }
+        if self.confusables {
+            if !single_script {
+                self.check_mixed_script_confusable(s, data, &mut findings);
+            } else if !s.is_ascii() && skeleton_with(s, data).all(|c| c.is_ascii()) {
+                let mut finding = Finding::new(FindingKind::WholeScriptConfusable, 0..s.len());
+                finding.chars = s.chars().filter(|c| !c.is_ascii()).collect();
+                finding.scripts.push(resolve_script_set_with(s, data));
+                findings.push(finding);
+            }
+        }
+        findings
+    }
+
+    fn check_mixed_script_confusable<D: DataProvider + ?Sized>(
+        &self,
+        s: &str,
+        data: &D,
+        findings: &mut Vec<Finding>,
+    ) {
+        let mut confusables = s
+            .char_indices()
+            .filter(|&(_, c)| !c.is_ascii() && data.potential_mixed_script_confusable(c));
+        let (start, first) = match confusables.next() {
+            Some(confusable) => confusable,
+            None => return,
+        };
+        let mut finding = Finding::new(
+            FindingKind::MixedScriptConfusable,
+            start..start + first.len_utf8(),
+        );
+        for (offset, c) in core::iter::once((start, first)).chain(confusables) {
+            finding.range.end = offset + c.len_utf8();
+            finding.chars.push(c);
+            push_script_set(
+                &mut finding.scripts,
+                AugmentedScriptSet::for_char_with(c, data),
+            );
+        }
+        findings.push(finding);
+    }
+}
+
+/// The distinct script sets of the characters of a string, other than Common and Inherited
+fn script_sets<D: DataProvider + ?Sized>(s: &str, data: &D) -> Vec<AugmentedScriptSet> {
+    let mut sets = Vec::new();
+    for c in s.chars() {
+        push_script_set(&mut sets, AugmentedScriptSet::for_char_with(c, data));
+    }
+    sets
+}
+
+fn push_script_set(sets: &mut Vec<AugmentedScriptSet>, set: AugmentedScriptSet) {
+    if !set.is_all() && !sets.contains(&set) {
+        sets.push(set);
+    }
+}
@@ -1,11 +1,14 @@
 //! Utilities for working with the [General Security Profile](https://www.unicode.org/reports/tr39/#General_Security_Profile)
 //! for identifiers
 
+#[cfg(feature = "identifier")]
 use crate::tables::identifier;
 
+#[cfg(feature = "identifier")]
 pub use identifier::IdentifierType;
 
 /// Methods for determining characters not restricted from use for identifiers.
+#[cfg(feature = "identifier")]
 pub trait GeneralSecurityProfile {
     /// Returns whether the character is not restricted from use for identifiers.
     fn identifier_allowed(self) -> bool;
@@ -14,6 +17,7 @@ pub trait GeneralSecurityProfile {
     fn identifier_type(self) -> Option<IdentifierType>;
 }
 
+#[cfg(feature = "identifier")]
 impl GeneralSecurityProfile for char {
     #[inline]
     fn identifier_allowed(self) -> bool {
@@ -24,3 +28,48 @@ impl GeneralSecurityProfile for char {
         identifier::identifier_type(self)
     }
 }
+
+#[cfg(all(feature = "identifier", feature = "serde"))]
+impl serde::Serialize for IdentifierType {
+    /// Serialize as the name of the type in IdentifierType.txt, such as `"Not_NFKC"`
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use IdentifierType::*;
+
+        let name = match self {
+            Not_Character => "Not_Character",
+            Deprecated => "Deprecated",
+            Default_Ignorable => "Default_Ignorable",
+            Not_NFKC => "Not_NFKC",
+            Not_XID => "Not_XID",
+            Exclusion => "Exclusion",
+            Obsolete => "Obsolete",
+            Technical => "Technical",
+            Uncommon_Use => "Uncommon_Use",
+            Limited_Use => "Limited_Use",
+            Inclusion => "Inclusion",
+            Recommended => "Recommended",
+        };
+        serializer.serialize_unit_variant("IdentifierType", *self as u32, name)
+    }
+}
+
+/// Check if a character is a [default ignorable code point](https://www.unicode.org/reports/tr44/#Default_Ignorable_Code_Point)
+///
+/// Such characters are invisible in normal rendering, and are removed when computing
+/// a [skeleton](crate::skeleton).
+pub fn is_default_ignorable_code_point(c: char) -> bool {
+    use crate::tables::default_ignorable_code_point::default_ignorable_code_point;
+
+    default_ignorable_code_point(c)
+}
+
+/// Check if a character is a [bidirectional control](https://www.unicode.org/reports/tr9/#Directional_Formatting_Characters)
+///
+/// Such characters are default ignorable, but reorder the text around them, so
+/// that code can be displayed differently from how it is compiled.
+pub fn is_bidi_control(c: char) -> bool {
+    matches!(
+        c,
+        '\u{61C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+    )
+}
@@ -0,0 +1,266 @@
+//! Checks reporting their findings per extended grapheme cluster
+//!
+//! The other checks of this crate look at `char`s, but users see
+//! [extended grapheme clusters](https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries):
+//! a base character together with the marks and joiners that attach to it.
+//! [`GraphemeChecker`] reports each problem at the cluster it occurs in, with
+//! the index and byte range of the cluster and the script of its base character,
+//! so that it can be pointed out in a user interface. It also judges each
+//! combining sequence as a whole, which finds scripts mixed within a cluster and
+//! marks hidden by being drawn over each other.
+//!
+//! ```rust
+//! use unicode_security::graphemes::{GraphemeChecker, GraphemeFindingKind};
+//!
+//! let findings = GraphemeChecker::new().check("pаypal"); // with a Cyrillic 'а'
+//! assert_eq!(findings[0].kind, GraphemeFindingKind::MixedScript);
+//! assert_eq!(findings[0].index, 1);
+//! assert_eq!(findings[0].range, 1..3);
+//! ```
+
+use alloc::vec::Vec;
+use core::ops::Range;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+use unicode_script::{Script, UnicodeScript};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::findings::Severity;
+use crate::mixed_script::AugmentedScriptSet;
+use crate::provider::{BakedData, DataProvider};
+use crate::restriction_level::RestrictionLevelDetector;
+use crate::RestrictionLevel;
+
+/// The most combining marks in a row a cluster may have, after NFD, before
+/// [`GraphemeFindingKind::TooManyMarks`] is reported
+///
+/// This is the limit suggested by
+/// [UTS #39](https://www.unicode.org/reports/tr39/#Optional_Detection).
+pub const MAX_MARKS: usize = 4;
+
+/// An extended grapheme cluster of a string, as returned by [`graphemes`]
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Grapheme<'a> {
+    /// The index of the cluster among the clusters of the string
+    pub index: usize,
+    /// The byte range of the cluster in the string
+    pub range: Range<usize>,
+    /// The text of the cluster
+    pub text: &'a str,
+    /// The first character of the cluster, to which the others attach
+    pub base: char,
+    /// The [Script](https://www.unicode.org/reports/tr24/) of the base character
+    pub script: Script,
+}
+
+/// Split a string into its extended grapheme clusters
+pub fn graphemes(s: &str) -> impl Iterator<Item = Grapheme<'_>> {
+    s.grapheme_indices(true)
+        .enumerate()
+        .map(|(index, (offset, text))| {
+            // Clusters are never empty
+            let base = text.chars().next().unwrap();
+            Grapheme {
+                index,
+                range: offset..offset + text.len(),
+                text,
+                base,
+                script: base.script(),
+            }
+        })
+}
+
+/// The problem reported by a [`GraphemeFinding`]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum GraphemeFindingKind {
+    /// The cluster has no script in common with the clusters before it, since
+    /// the previous such finding
+    MixedScript,
+    /// The characters of the cluster itself have no script in common, such as
+    /// a Latin letter with a Devanagari vowel sign
+    MixedScriptCluster,
+    /// The string up to and including the cluster has a less restrictive
+    /// [restriction level](https://www.unicode.org/reports/tr39/#Restriction_Level_Detection)
+    /// than allowed, and a less restrictive one than before the cluster
+    RestrictionLevel(RestrictionLevel),
+    /// The same combining mark occurs twice in a row in the cluster, after NFD,
+    /// and is drawn over itself
+    RepeatedMark,
+    /// The cluster has more than [`MAX_MARKS`] combining marks in a row, after NFD
+    TooManyMarks,
+}
+
+impl GraphemeFindingKind {
+    /// The severity [`GraphemeChecker`] reports findings of this kind with
+    pub fn severity(self) -> Severity {
+        match self {
+            GraphemeFindingKind::MixedScript => Severity::Info,
+            GraphemeFindingKind::RestrictionLevel(_) | GraphemeFindingKind::TooManyMarks => {
+                Severity::Warning
+            }
+            GraphemeFindingKind::MixedScriptCluster | GraphemeFindingKind::RepeatedMark => {
+                Severity::Error
+            }
+        }
+    }
+}
+
+/// A problem found in an extended grapheme cluster
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct GraphemeFinding {
+    /// What the problem is
+    pub kind: GraphemeFindingKind,
+    /// How serious the problem is
+    pub severity: Severity,
+    /// The index of the cluster among the clusters of the string
+    pub index: usize,
+    /// The byte range of the cluster in the string
+    pub range: Range<usize>,
+    /// The base character of the cluster
+    pub base: char,
+    /// The [Script](https://www.unicode.org/reports/tr24/) of the base character
+    pub script: Script,
+}
+
+impl GraphemeFinding {
+    fn new(kind: GraphemeFindingKind, grapheme: &Grapheme<'_>) -> Self {
+        GraphemeFinding {
+            kind,
+            severity: kind.severity(),
+            index: grapheme.index,
+            range: grapheme.range.clone(),
+            base: grapheme.base,
+            script: grapheme.script,
+        }
+    }
+}
+
+/// Runs a configurable set of checks over the extended grapheme clusters of
+/// strings and collects [`GraphemeFinding`]s
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct GraphemeChecker {
+    mixed_script: bool,
+    max_restriction_level: Option<RestrictionLevel>,
+    hidden_marks: bool,
+}
+
+impl Default for GraphemeChecker {
+    fn default() -> Self {
+        GraphemeChecker {
+            mixed_script: true,
+            max_restriction_level: Some(RestrictionLevel::HighlyRestrictive),
+            hidden_marks: true,
+        }
+    }
+}
+
+impl GraphemeChecker {
+    /// Create a checker with all checks enabled, which allows strings up to
+    /// [`RestrictionLevel::HighlyRestrictive`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enable or disable reporting clusters that mix scripts, within themselves
+    /// or with the clusters before them
+    pub fn mixed_script(mut self, enabled: bool) -> Self {
+        self.mixed_script = enabled;
+        self
+    }
+
+    /// Set the least restrictive level a string may have, or `None` to disable
+    /// the restriction level check
+    pub fn max_restriction_level(mut self, level: Option<RestrictionLevel>) -> Self {
+        self.max_restriction_level = level;
+        self
+    }
+
+    /// Enable or disable reporting repeated combining marks, and more than
+    /// [`MAX_MARKS`] of them in a row
+    pub fn hidden_marks(mut self, enabled: bool) -> Self {
+        self.hidden_marks = enabled;
+        self
+    }
+
+    /// Run the enabled checks over the clusters of a string
+    ///
+    /// Findings are in the order of their clusters.
+    pub fn check(&self, s: &str) -> Vec<GraphemeFinding> {
+        self.check_with(s, &BakedData)
+    }
+
+    /// Run the enabled checks over the clusters of a string, with the data of
+    /// a [`DataProvider`]
+    pub fn check_with<D: DataProvider + ?Sized>(&self, s: &str, data: &D) -> Vec<GraphemeFinding> {
+        let mut findings = Vec::new();
+        let mut scripts = AugmentedScriptSet::default();
+        let mut detector = RestrictionLevelDetector::new();
+        let mut level = detector.finish();
+        for grapheme in graphemes(s) {
+            if self.mixed_script {
+                let set = AugmentedScriptSet::for_str_with(grapheme.text, data);
+                if set.is_empty() {
+                    findings.push(GraphemeFinding::new(
+                        GraphemeFindingKind::MixedScriptCluster,
+                        &grapheme,
+                    ));
+                } else {
+                    scripts.intersect_with(set);
+                    if scripts.is_empty() {
+                        findings.push(GraphemeFinding::new(
+                            GraphemeFindingKind::MixedScript,
+                            &grapheme,
+                        ));
+                        scripts = set;
+                    }
+                }
+            }
+            if let Some(max) = self.max_restriction_level {
+                for c in grapheme.text.chars() {
+                    detector.push_with(c, data);
+                }
+                let previous = level;
+                level = detector.finish();
+                if level > max && level > previous {
+                    findings.push(GraphemeFinding::new(
+                        GraphemeFindingKind::RestrictionLevel(level),
+                        &grapheme,
+                    ));
+                }
+            }
+            if self.hidden_marks {
+                check_marks(&grapheme, &mut findings);
+            }
+        }
+        findings
+    }
+}
+
+fn check_marks(grapheme: &Grapheme<'_>, findings: &mut Vec<GraphemeFinding>) {
+    let (mut repeated, mut too_many) = (false, false);
+    let mut previous = None;
+    let mut run = 0;
+    for c in grapheme.text.nfd() {
+        if !is_combining_mark(c) {
+            previous = None;
+            run = 0;
+            continue;
+        }
+        repeated |= previous == Some(c);
+        run += 1;
+        too_many |= run > MAX_MARKS;
+        previous = Some(c);
+    }
+    if repeated {
+        findings.push(GraphemeFinding::new(
+            GraphemeFindingKind::RepeatedMark,
+            grapheme,
+        ));
+    }
+    if too_many {
+        findings.push(GraphemeFinding::new(
+            GraphemeFindingKind::TooManyMarks,
+            grapheme,
+        ));
+    }
+}
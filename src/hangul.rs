@@ -0,0 +1,170 @@
+//! Checks for Korean text spoofed with Hangul jamo
+//!
+//! Hangul can be written with precomposed syllables, with sequences of
+//! conjoining jamo that render the same as a syllable, and with the Hangul
+//! Compatibility Jamo and their halfwidth forms, which look like the jamo of a
+//! syllable. The mixed-script checks treat all of these as Korean, and
+//! [`skeleton`](crate::skeleton) only maps some of them to each other.
+//! [`check_hangul`] reports each occurrence of the forms that can make two
+//! different strings look the same, and of conjoining jamo that do not compose
+//! into a syllable at all.
+//!
+//! These checks use no data tables, and only need the `alloc` feature.
+//!
+//! ```rust
+//! use unicode_security::hangul::{check_hangul, HangulFindingKind};
+//!
+//! // "한" written as the conjoining jamo ᄒ, ᅡ and ᆫ
+//! let findings = check_hangul("\u{1112}\u{1161}\u{11AB}국");
+//! assert_eq!(findings.len(), 1);
+//! assert_eq!(findings[0].kind, HangulFindingKind::DecomposedSyllable('한'));
+//! assert_eq!(findings[0].range, 0..9);
+//! ```
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+// The constants of the Hangul syllable composition algorithm, from section 3.12
+// of the Unicode Standard
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const S_COUNT: u32 = L_COUNT * V_COUNT * T_COUNT;
+
+/// The problem reported by a [`HangulFinding`]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum HangulFindingKind {
+    /// Conjoining jamo, possibly following a syllable, that compose to the
+    /// given precomposed syllable and render the same as it
+    DecomposedSyllable(char),
+    /// A Hangul Compatibility Jamo, or its halfwidth form, in a string that also
+    /// has syllables or conjoining jamo
+    CompatibilityJamo,
+    /// A Hangul filler, which renders as blank space or not at all: U+115F
+    /// CHOSEONG FILLER, U+1160 JUNGSEONG FILLER, U+3164 HANGUL FILLER or U+FFA0
+    /// HALFWIDTH HANGUL FILLER
+    Filler,
+    /// A conjoining jamo that is not part of a sequence composing to a
+    /// precomposed syllable, such as a vowel or trailing consonant on its own,
+    /// a leading consonant followed by another one, or an old jamo, including
+    /// those of Hangul Jamo Extended-A and Extended-B
+    ///
+    /// Fonts render these as a syllable block of their own, with parts missing,
+    /// or joined to the jamo next to them, so that they can hide in or alter
+    /// the look of the text around them.
+    NonComposingJamo,
+}
+
+/// A problem found in Hangul text
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct HangulFinding {
+    /// What the problem is
+    pub kind: HangulFindingKind,
+    /// The byte range of the string the problem applies to
+    pub range: Range<usize>,
+}
+
+/// Check a string for conjoining jamo sequences that render as a syllable,
+/// conjoining jamo that do not compose, compatibility jamo mixed with other
+/// Hangul, and Hangul fillers
+///
+/// Every occurrence is reported, in order of appearance. Each jamo that does
+/// not compose is reported on its own, so a leading consonant doubled before a
+/// vowel, as in U+1100 U+1100 U+1161, gives a
+/// [`NonComposingJamo`](HangulFindingKind::NonComposingJamo) for the first
+/// consonant and a [`DecomposedSyllable`](HangulFindingKind::DecomposedSyllable)
+/// for the rest.
+pub fn check_hangul(s: &str) -> Vec<HangulFinding> {
+    let mut findings = Vec::new();
+    let has_hangul = s
+        .chars()
+        .any(|c| is_syllable(c) || (is_conjoining_jamo(c) && !is_filler(c)));
+    // The start and composed character of the syllable being built, and whether
+    // any jamo were composed into it
+    let mut pending: Option<(usize, char, bool)> = None;
+    for (offset, c) in s.char_indices() {
+        if let Some((start, composed, _)) = pending {
+            if let Some(syllable) = compose(composed, c) {
+                pending = Some((start, syllable, true));
+                continue;
+            }
+        }
+        flush(&mut pending, offset, &mut findings);
+
+        let kind = if is_filler(c) {
+            Some(HangulFindingKind::Filler)
+        } else if is_conjoining_jamo(c) && !is_leading_jamo(c) {
+            Some(HangulFindingKind::NonComposingJamo)
+        } else if has_hangul && is_compatibility_jamo(c) {
+            Some(HangulFindingKind::CompatibilityJamo)
+        } else {
+            None
+        };
+        if let Some(kind) = kind {
+            findings.push(HangulFinding {
+                kind,
+                range: offset..offset + c.len_utf8(),
+            });
+        }
+        if is_leading_jamo(c) || is_syllable(c) {
+            pending = Some((offset, c, false));
+        }
+    }
+    flush(&mut pending, s.len(), &mut findings);
+    findings
+}
+
+fn flush(pending: &mut Option<(usize, char, bool)>, end: usize, findings: &mut Vec<HangulFinding>) {
+    let (start, kind) = match pending.take() {
+        Some((start, syllable, true)) => (start, HangulFindingKind::DecomposedSyllable(syllable)),
+        // A leading consonant that nothing composed with
+        Some((start, c, false)) if is_leading_jamo(c) => {
+            (start, HangulFindingKind::NonComposingJamo)
+        }
+        _ => return,
+    };
+    findings.push(HangulFinding {
+        kind,
+        range: start..end,
+    });
+}
+
+/// Compose a leading consonant with a vowel, or an LV syllable with a trailing
+/// consonant, as canonical composition does
+fn compose(a: char, b: char) -> Option<char> {
+    let leading = is_leading_jamo(a);
+    let (a, b) = (a as u32, b as u32);
+    if leading && (V_BASE..V_BASE + V_COUNT).contains(&b) {
+        let lv = (a - L_BASE) * V_COUNT + (b - V_BASE);
+        return char::from_u32(S_BASE + lv * T_COUNT);
+    }
+    let s_index = a.wrapping_sub(S_BASE);
+    if s_index < S_COUNT && s_index % T_COUNT == 0 && (T_BASE + 1..T_BASE + T_COUNT).contains(&b) {
+        return char::from_u32(a + (b - T_BASE));
+    }
+    None
+}
+
+fn is_leading_jamo(c: char) -> bool {
+    (L_BASE..L_BASE + L_COUNT).contains(&(c as u32))
+}
+
+fn is_syllable(c: char) -> bool {
+    (S_BASE..S_BASE + S_COUNT).contains(&(c as u32))
+}
+
+fn is_conjoining_jamo(c: char) -> bool {
+    matches!(c, '\u{1100}'..='\u{11FF}' | '\u{A960}'..='\u{A97F}' | '\u{D7B0}'..='\u{D7FF}')
+}
+
+fn is_compatibility_jamo(c: char) -> bool {
+    matches!(c, '\u{3131}'..='\u{318E}' | '\u{FFA1}'..='\u{FFDC}') && !is_filler(c)
+}
+
+fn is_filler(c: char) -> bool {
+    matches!(c, '\u{115F}' | '\u{1160}' | '\u{3164}' | '\u{FFA0}')
+}
@@ -0,0 +1,342 @@
+//! Lints over the whole set of identifiers of a program
+//!
+//! This is the logic behind rustc's `non_ascii_idents`, `uncommon_codepoints`,
+//! `confusable_idents` and `mixed_script_confusables` lints, for use by other
+//! language tools. Identifiers carry a caller-defined span type, which is
+//! returned in the diagnostics.
+//!
+//! ```rust
+//! use unicode_security::identifier_lints::{Diagnostic, Identifier, IdentifierLints};
+//!
+//! let identifiers = vec![
+//!     Identifier::new("apple", 1),
+//!     Identifier::new("аpple", 2), // with a Cyrillic 'а'
+//! ];
+//! let diagnostics = IdentifierLints::new().check(identifiers);
+//! assert!(diagnostics
+//!     .iter()
+//!     .any(|d| matches!(d, Diagnostic::ConfusableIdents { .. })));
+//! ```
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::confusable_detection::skeleton_with;
+use crate::general_security_profile::IdentifierType;
+use crate::mixed_script::AugmentedScriptSet;
+use crate::provider::{BakedData, DataProvider};
+
+/// An identifier and where it occurs
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Identifier<'a, S> {
+    /// The identifier
+    pub name: &'a str,
+    /// Where the identifier occurs, in terms defined by the caller
+    pub span: S,
+}
+
+impl<'a, S> Identifier<'a, S> {
+    /// Create an identifier occurring at `span`
+    pub fn new(name: &'a str, span: S) -> Self {
+        Identifier { name, span }
+    }
+}
+
+/// A diagnostic emitted by [`IdentifierLints::check`]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Diagnostic<'a, S> {
+    /// `non_ascii_idents`: the identifier contains non-ASCII characters
+    NonAsciiIdent {
+        /// The identifier
+        name: &'a str,
+        /// Where the identifier first occurs
+        span: S,
+    },
+    /// `uncommon_codepoints`: the identifier contains characters that are
+    /// [restricted](crate::GeneralSecurityProfile::identifier_allowed) from use in identifiers
+    ///
+    /// One diagnostic is emitted per identifier type of the offending characters.
+    UncommonCodepoints {
+        /// The identifier
+        name: &'a str,
+        /// Where the identifier first occurs
+        span: S,
+        /// The identifier type of the characters, if they have one
+        identifier_type: Option<IdentifierType>,
+        /// The characters, in order of appearance
+        chars: Vec<char>,
+    },
+    /// `confusable_idents`: the identifier has the same [skeleton](crate::skeleton)
+    /// as another identifier, and at least one of them is not ASCII
+    ConfusableIdents {
+        /// The identifier
+        name: &'a str,
+        /// Where the identifier first occurs
+        span: S,
+        /// The identifier it is confusable with
+        existing_name: &'a str,
+        /// Where the identifier it is confusable with first occurs
+        existing_span: S,
+    },
+    /// `mixed_script_confusables`: all characters used from a script set are
+    /// [confusable](crate::is_potential_mixed_script_confusable_char) with characters
+    /// from other scripts
+    MixedScriptConfusables {
+        /// Where the first of the characters occurs
+        span: S,
+        /// The script set
+        script_set: AugmentedScriptSet,
+        /// The characters, sorted and deduplicated
+        chars: Vec<char>,
+    },
+}
+
+/// Runs the identifier lints over a set of identifiers
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct IdentifierLints {
+    non_ascii_idents: bool,
+    uncommon_codepoints: bool,
+    confusable_idents: bool,
+    mixed_script_confusables: bool,
+}
+
+impl Default for IdentifierLints {
+    fn default() -> Self {
+        IdentifierLints {
+            non_ascii_idents: true,
+            uncommon_codepoints: true,
+            confusable_idents: true,
+            mixed_script_confusables: true,
+        }
+    }
+}
+
+enum ScriptSetUsage<S> {
+    Suspicious(Vec<char>, S),
+    Verified,
+}
+
+impl IdentifierLints {
+    /// Create a lint runner with all lints enabled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enable or disable the `non_ascii_idents` lint
+    pub fn non_ascii_idents(mut self, enabled: bool) -> Self {
+        self.non_ascii_idents = enabled;
+        self
+    }
+
+    /// Enable or disable the `uncommon_codepoints` lint
+    pub fn uncommon_codepoints(mut self, enabled: bool) -> Self {
+        self.uncommon_codepoints = enabled;
+        self
+    }
+
+    /// Enable or disable the `confusable_idents` lint
+    pub fn confusable_idents(mut self, enabled: bool) -> Self {
+        self.confusable_idents = enabled;
+        self
+    }
+
+    /// Enable or disable the `mixed_script_confusables` lint
+    pub fn mixed_script_confusables(mut self, enabled: bool) -> Self {
+        self.mixed_script_confusables = enabled;
+        self
+    }
+
+    /// Run the enabled lints over all identifiers of a program
+    ///
+    /// Each distinct identifier is checked once, at its smallest span. If all
+    /// identifiers are ASCII, no diagnostics are emitted.
+    pub fn check<'a, S, I>(&self, identifiers: I) -> Vec<Diagnostic<'a, S>>
+    where
+        S: Ord + Clone,
+        I: IntoIterator<Item = Identifier<'a, S>>,
+    {
+        self.check_with(identifiers, &BakedData)
+    }
+
+    /// Run the enabled lints over all identifiers of a program, with the data of
+    /// a [`DataProvider`]
+    pub fn check_with<'a, S, I, D>(&self, identifiers: I, data: &D) -> Vec<Diagnostic<'a, S>>
+    where
+        S: Ord + Clone,
+        I: IntoIterator<Item = Identifier<'a, S>>,
+        D: DataProvider + ?Sized,
+    {
+        let mut first_spans: BTreeMap<&'a str, S> = BTreeMap::new();
+        for Identifier { name, span } in identifiers {
+            match first_spans.get_mut(name) {
+                Some(existing) if *existing <= span => {}
+                Some(existing) => *existing = span,
+                None => {
+                    first_spans.insert(name, span);
+                }
+            }
+        }
+        let mut symbols: Vec<(&'a str, S)> = first_spans.into_iter().collect();
+        symbols.sort_by(|a, b| a.1.cmp(&b.1));
+
+        let mut diagnostics = Vec::new();
+        if symbols.iter().all(|(name, _)| name.is_ascii()) {
+            return diagnostics;
+        }
+        for &(name, ref span) in &symbols {
+            if name.is_ascii() {
+                continue;
+            }
+            if self.non_ascii_idents {
+                diagnostics.push(Diagnostic::NonAsciiIdent {
+                    name,
+                    span: span.clone(),
+                });
+            }
+            if self.uncommon_codepoints {
+                self.check_uncommon_codepoints(name, span, data, &mut diagnostics);
+            }
+        }
+        if self.confusable_idents {
+            self.check_confusable_idents(&symbols, data, &mut diagnostics);
+        }
+        if self.mixed_script_confusables {
+            self.check_mixed_script_confusables(&symbols, data, &mut diagnostics);
+        }
+        diagnostics
+    }
+
+    fn check_uncommon_codepoints<'a, S: Clone, D: DataProvider + ?Sized>(
+        &self,
+        name: &'a str,
+        span: &S,
+        data: &D,
+        diagnostics: &mut Vec<Diagnostic<'a, S>>,
+    ) {
+        let mut by_type: BTreeMap<Option<IdentifierType>, Vec<char>> = BTreeMap::new();
+        for ch in name.chars().filter(|&c| !data.identifier_allowed(c)) {
+            by_type
+                .entry(data.identifier_type(ch))
+                .or_default()
+                .push(ch);
+        }
+        for (identifier_type, chars) in by_type {
+            diagnostics.push(Diagnostic::UncommonCodepoints {
+                name,
+                span: span.clone(),
+                identifier_type,
+                chars,
+            });
+        }
+    }
+
+    fn check_confusable_idents<'a, S: Clone, D: DataProvider + ?Sized>(
+        &self,
+        symbols: &[(&'a str, S)],
+        data: &D,
+        diagnostics: &mut Vec<Diagnostic<'a, S>>,
+    ) {
+        let mut skeletons: BTreeMap<String, (&'a str, S, bool)> = BTreeMap::new();
+        for &(name, ref span) in symbols {
+            let is_ascii = name.is_ascii();
+            let existing = skeletons
+                .entry(skeleton_with(name, data).collect())
+                .or_insert_with(|| (name, span.clone(), is_ascii));
+            if existing.0 == name {
+                continue;
+            }
+            if !existing.2 || !is_ascii {
+                diagnostics.push(Diagnostic::ConfusableIdents {
+                    name,
+                    span: span.clone(),
+                    existing_name: existing.0,
+                    existing_span: existing.1.clone(),
+                });
+            }
+            // Prefer reporting later collisions against a non-ASCII identifier
+            if existing.2 && !is_ascii {
+                *existing = (name, span.clone(), is_ascii);
+            }
+        }
+    }
+
+    fn check_mixed_script_confusables<'a, S: Ord + Clone, D: DataProvider + ?Sized>(
+        &self,
+        symbols: &[(&'a str, S)],
+        data: &D,
+        diagnostics: &mut Vec<Diagnostic<'a, S>>,
+    ) {
+        // Latin is always considered in use, ASCII letters are not confusables
+        // in their own script.
+        let mut script_states =
+            alloc::vec![(AugmentedScriptSet::for_char('A'), ScriptSetUsage::Verified,)];
+        let mut has_suspicious = false;
+        for (name, span) in symbols {
+            // ASCII characters are covered by the exception above, and restricted
+            // characters by `uncommon_codepoints`.
+            for ch in name
+                .chars()
+                .filter(|&c| !c.is_ascii() && data.identifier_allowed(c))
+            {
+                let script_set = AugmentedScriptSet::for_char_with(ch, data);
+                let confusable = data.potential_mixed_script_confusable(ch);
+                match script_states.iter_mut().find(|(set, _)| *set == script_set) {
+                    Some((_, usage)) => {
+                        if let ScriptSetUsage::Suspicious(chars, _) = usage {
+                            if confusable {
+                                chars.push(ch);
+                            } else {
+                                *usage = ScriptSetUsage::Verified;
+                            }
+                        }
+                    }
+                    None if confusable => {
+                        has_suspicious = true;
+                        let usage = ScriptSetUsage::Suspicious(alloc::vec![ch], span.clone());
+                        script_states.push((script_set, usage));
+                    }
+                    None => script_states.push((script_set, ScriptSetUsage::Verified)),
+                }
+            }
+        }
+        if !has_suspicious {
+            return;
+        }
+
+        let verified: Vec<AugmentedScriptSet> = script_states
+            .iter()
+            .filter(|(set, usage)| matches!(usage, ScriptSetUsage::Verified) && !set.is_all())
+            .map(|(set, _)| *set)
+            .collect();
+        let mut reports = Vec::new();
+        'outer: for (script_set, usage) in script_states {
+            let (mut chars, span) = match usage {
+                ScriptSetUsage::Suspicious(chars, span) => (chars, span),
+                ScriptSetUsage::Verified => continue,
+            };
+            if script_set.is_all() {
+                continue;
+            }
+            // The script set is also in use through a verified set overlapping it
+            for existing in &verified {
+                let mut intersection = *existing;
+                intersection.intersect_with(script_set);
+                if !intersection.is_empty() && !intersection.is_all() {
+                    continue 'outer;
+                }
+            }
+            chars.sort_unstable();
+            chars.dedup();
+            reports.push((span, chars, script_set));
+        }
+        reports.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+        diagnostics.extend(reports.into_iter().map(|(span, chars, script_set)| {
+            Diagnostic::MixedScriptConfusables {
+                span,
+                script_set,
+                chars,
+            }
+        }));
+    }
+}
@@ -0,0 +1,137 @@
+//! Adapters for checking strings that are not stored as `&str`
+//!
+//! [`MixedScript`] and [`RestrictionLevelDetection`] are implemented for `&str`,
+//! [`Chars`](core::str::Chars) and `&[char]` directly, and `String` and `Cow<str>`
+//! values can be checked through the `&str` they dereference to. The types here
+//! extend them to any iterator of chars and to UTF-16 buffers, so these can be
+//! checked without first collecting them into a `String`. For [skeletons](crate::skeleton), use
+//! [`skeleton_chars`](crate::skeleton_chars) with any char iterator.
+//!
+//! ```rust
+//! # #[cfg(all(feature = "confusables", feature = "mixed-script"))]
+//! # {
+//! use unicode_security::input::Utf16;
+//! use unicode_security::{skeleton_chars, MixedScript};
+//!
+//! let units: Vec<u16> = "pаypal".encode_utf16().collect(); // with a Cyrillic 'а'
+//! let text = Utf16::new(&units);
+//! assert!(!text.is_single_script());
+//! assert!(skeleton_chars(text.chars()).eq("paypal".chars()));
+//! # }
+//! ```
+
+use core::char::{decode_utf16, DecodeUtf16, REPLACEMENT_CHARACTER};
+use core::iter::Copied;
+use core::slice;
+
+#[cfg(feature = "mixed-script")]
+use crate::mixed_script::{AugmentedScriptSet, MixedScript};
+#[cfg(all(feature = "identifier", feature = "mixed-script"))]
+use crate::restriction_level::{
+    RestrictionLevel, RestrictionLevelDetection, RestrictionLevelDetector,
+};
+
+/// Any iterator of chars, to be checked as a string
+///
+/// ```rust
+/// # #[cfg(feature = "mixed-script")]
+/// # {
+/// use unicode_security::input::CharIter;
+/// use unicode_security::MixedScript;
+///
+/// let segments = ["Σ", "x"];
+/// let chars = segments.iter().flat_map(|segment| segment.chars());
+/// assert!(!CharIter(chars).is_single_script());
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct CharIter<I>(pub I);
+
+#[cfg(feature = "mixed-script")]
+impl<I: Iterator<Item = char>> MixedScript for CharIter<I> {
+    fn is_single_script(self) -> bool {
+        !self.resolve_script_set().is_empty()
+    }
+
+    fn resolve_script_set(self) -> AugmentedScriptSet {
+        self.0.collect()
+    }
+}
+
+#[cfg(all(feature = "identifier", feature = "mixed-script"))]
+impl<I: Iterator<Item = char>> RestrictionLevelDetection for CharIter<I> {
+    fn detect_restriction_level(self) -> RestrictionLevel {
+        self.0.collect::<RestrictionLevelDetector>().finish()
+    }
+}
+
+/// A UTF-16 buffer, to be checked as a string
+///
+/// The buffer may contain unpaired surrogates, as the strings of JavaScript
+/// engines can. These are checked as U+FFFD REPLACEMENT CHARACTER, which is
+/// [restricted](crate::GeneralSecurityProfile::identifier_allowed) and in the
+/// Common script: a buffer with unpaired surrogates is
+/// [`RestrictionLevel::Unrestricted`], and they do not make it mixed-script. Use
+/// [`has_unpaired_surrogates`](Utf16::has_unpaired_surrogates) to reject such
+/// buffers outright.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Utf16<'a> {
+    units: &'a [u16],
+}
+
+impl<'a> Utf16<'a> {
+    /// Wrap a buffer of UTF-16 code units
+    pub fn new(units: &'a [u16]) -> Self {
+        Utf16 { units }
+    }
+
+    /// The characters of the buffer, with unpaired surrogates replaced by U+FFFD
+    pub fn chars(&self) -> Utf16Chars<'a> {
+        Utf16Chars {
+            inner: decode_utf16(self.units.iter().copied()),
+        }
+    }
+
+    /// Check whether the buffer contains unpaired surrogates, and so is not valid UTF-16
+    pub fn has_unpaired_surrogates(&self) -> bool {
+        decode_utf16(self.units.iter().copied()).any(|c| c.is_err())
+    }
+}
+
+/// The characters of a [`Utf16`] buffer
+#[derive(Clone, Debug)]
+pub struct Utf16Chars<'a> {
+    inner: DecodeUtf16<Copied<slice::Iter<'a, u16>>>,
+}
+
+impl Iterator for Utf16Chars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        self.inner
+            .next()
+            .map(|c| c.unwrap_or(REPLACEMENT_CHARACTER))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(feature = "mixed-script")]
+impl MixedScript for Utf16<'_> {
+    fn is_single_script(self) -> bool {
+        CharIter(self.chars()).is_single_script()
+    }
+
+    fn resolve_script_set(self) -> AugmentedScriptSet {
+        CharIter(self.chars()).resolve_script_set()
+    }
+}
+
+#[cfg(all(feature = "identifier", feature = "mixed-script"))]
+impl RestrictionLevelDetection for Utf16<'_> {
+    fn detect_restriction_level(self) -> RestrictionLevel {
+        CharIter(self.chars()).detect_restriction_level()
+    }
+}
@@ -0,0 +1,55 @@
+//! Look up all properties this crate knows about a character at once
+
+use unicode_script::ScriptExtension;
+
+use crate::general_security_profile::IdentifierType;
+use crate::mixed_script::AugmentedScriptSet;
+use crate::provider::{BakedData, DataProvider};
+
+/// The security related properties of a character, as returned by [`inspect`]
+///
+/// The confusable prototype borrows from the data it was looked up in, which is
+/// `'static` for the compiled-in tables.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct CharInfo<'a> {
+    /// The character
+    pub ch: char,
+    /// Whether the character is [allowed](crate::GeneralSecurityProfile::identifier_allowed)
+    /// in identifiers
+    pub identifier_allowed: bool,
+    /// The [identifier type](crate::GeneralSecurityProfile::identifier_type)
+    pub identifier_type: Option<IdentifierType>,
+    /// The [Script_Extensions](https://www.unicode.org/reports/tr24/#Script_Extensions) property
+    pub script_extension: ScriptExtension,
+    /// The [augmented script set](https://www.unicode.org/reports/tr39/#def-augmented-script-set)
+    pub augmented_script_set: AugmentedScriptSet,
+    /// The [prototype](https://www.unicode.org/reports/tr39/#def-prototype) of the
+    /// character, if it is mapped in confusables.txt
+    pub confusable_prototype: Option<&'a [char]>,
+    /// Whether the character is a [default ignorable code point](crate::is_default_ignorable_code_point)
+    pub default_ignorable: bool,
+    /// Whether the character is
+    /// [potentially mixed-script confusable](crate::is_potential_mixed_script_confusable_char)
+    pub potential_mixed_script_confusable: bool,
+}
+
+/// Look up the security related properties of a character
+pub fn inspect(c: char) -> CharInfo<'static> {
+    inspect_with(c, &BakedData)
+}
+
+/// Look up the security related properties of a character in the data of a
+/// [`DataProvider`]
+pub fn inspect_with<D: DataProvider + ?Sized>(c: char, data: &D) -> CharInfo<'_> {
+    let script_extension = data.script_extension(c);
+    CharInfo {
+        ch: c,
+        identifier_allowed: data.identifier_allowed(c),
+        identifier_type: data.identifier_type(c),
+        script_extension,
+        augmented_script_set: script_extension.into(),
+        confusable_prototype: data.confusable_prototype(c),
+        default_ignorable: data.default_ignorable(c),
+        potential_mixed_script_confusable: data.potential_mixed_script_confusable(c),
+    }
+}
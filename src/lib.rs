@@ -15,8 +15,12 @@
 //! ```rust
 //! extern crate unicode_security;
 //!
+//! # #[cfg(feature = "identifier")]
 //! use unicode_security::GeneralSecurityProfile;
 //!
+//! # #[cfg(not(feature = "identifier"))]
+//! # fn main() {}
+//! # #[cfg(feature = "identifier")]
 //! fn main() {
 //!     let ch = 'µ'; // U+00B5 MICRO SIGN
 //!     let allowed = 'µ'.identifier_allowed();
@@ -31,6 +35,44 @@
 //! unicode-security supports a `no_std` feature. This eliminates dependence
 //! on std, and instead uses equivalent functions from core.
 //!
+//! The data tables are split by feature, all enabled by default, so that
+//! applications only link the ones they use:
+//!
+//! - `identifier`: identifier status and type, for [`GeneralSecurityProfile`]
+//! - `confusables`: confusable prototypes and case folding, for [`skeleton`]
+//! - `mixed-script`: script sets and mixed-script confusables, for [`MixedScript`]
+//!
+//! [`RestrictionLevelDetection`] needs both `identifier` and `mixed-script`; the
+//! checks combining several properties, such as [`inspect()`] and the `alloc`
+//! modules below, need all three.
+//!
+//! The `alloc` feature, enabled by default, provides the checks that need to
+//! allocate, such as [`canonicalize_identifier`], [`SecurityChecker`] and the
+//! [`domain`] and [`email`] modules.
+//!
+//! The checks with a `_with` suffix look up character properties through a
+//! [`provider::DataProvider`] instead of the compiled-in tables. The `std` feature
+//! provides [`data::UnicodeData`], which loads newer or patched security data
+//! files at runtime for use with them.
+//!
+//! The `rayon` feature checks the slices passed to the [`batch`] module in
+//! parallel.
+//!
+//! The `ffi` feature provides C bindings in the [`ffi`] module.
+//!
+//! The `serde` feature implements `Serialize` for [`RestrictionLevel`],
+//! [`IdentifierType`](general_security_profile::IdentifierType) and
+//! [`AugmentedScriptSet`](mixed_script::AugmentedScriptSet) and [`Finding`], and
+//! adds the serializable reports of the `report` module.
+//!
+//! The `graphemes` feature adds the `graphemes` module, whose checks report
+//! their findings per extended grapheme cluster rather than per `char`.
+//!
+//! The `cli` feature builds the `unicode-security` binary, which scans files for
+//! restricted, mixed-script, confusable and invisible characters, and shows the
+//! properties of characters with `unicode-security inspect`. With `--json`, it
+//! prints each problem as a serialized [`Finding`].
+//!
 //! # crates.io
 //!
 //! You can use this package in your project by adding the following
@@ -49,24 +91,136 @@
 #![cfg_attr(feature = "bench", feature(test))]
 #![no_std]
 
-#[cfg(test)]
-#[macro_use]
+#[cfg(any(test, feature = "std"))]
+#[cfg_attr(test, macro_use, allow(unused_imports))]
 extern crate std;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[cfg(feature = "bench")]
 extern crate test;
 
 pub use tables::UNICODE_VERSION;
 
+#[cfg(all(
+    feature = "alloc",
+    feature = "identifier",
+    feature = "confusables",
+    feature = "mixed-script"
+))]
+pub mod batch;
+#[cfg(all(
+    feature = "alloc",
+    feature = "identifier",
+    feature = "confusables",
+    feature = "mixed-script"
+))]
+pub mod canonicalization;
+#[cfg(feature = "confusables")]
 pub mod confusable_detection;
+#[cfg(all(feature = "alloc", feature = "confusables"))]
+pub mod confusable_profile;
+#[cfg(all(feature = "alloc", feature = "confusables"))]
+pub mod confusable_similarity;
+#[cfg(feature = "std")]
+pub mod data;
+#[cfg(all(
+    feature = "alloc",
+    feature = "identifier",
+    feature = "confusables",
+    feature = "mixed-script"
+))]
+pub mod domain;
+#[cfg(all(
+    feature = "alloc",
+    feature = "identifier",
+    feature = "confusables",
+    feature = "mixed-script"
+))]
+pub mod email;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(all(
+    feature = "alloc",
+    feature = "identifier",
+    feature = "confusables",
+    feature = "mixed-script"
+))]
+pub mod findings;
 pub mod general_security_profile;
+#[cfg(all(
+    feature = "graphemes",
+    feature = "alloc",
+    feature = "identifier",
+    feature = "confusables",
+    feature = "mixed-script"
+))]
+pub mod graphemes;
+#[cfg(feature = "alloc")]
+pub mod hangul;
+#[cfg(all(
+    feature = "alloc",
+    feature = "identifier",
+    feature = "confusables",
+    feature = "mixed-script"
+))]
+pub mod identifier_lints;
+pub mod input;
+#[cfg(all(
+    feature = "identifier",
+    feature = "confusables",
+    feature = "mixed-script"
+))]
+pub mod inspect;
+#[cfg(feature = "mixed-script")]
 pub mod mixed_script;
+pub mod provider;
+#[cfg(all(
+    feature = "serde",
+    feature = "identifier",
+    feature = "confusables",
+    feature = "mixed-script"
+))]
+pub mod report;
+#[cfg(all(feature = "identifier", feature = "mixed-script"))]
 pub mod restriction_level;
 
-pub use confusable_detection::skeleton;
+#[cfg(all(
+    feature = "alloc",
+    feature = "identifier",
+    feature = "confusables",
+    feature = "mixed-script"
+))]
+pub use canonicalization::canonicalize_identifier;
+#[cfg(all(feature = "alloc", feature = "confusables"))]
+pub use confusable_detection::SkeletonBuilder;
+#[cfg(feature = "confusables")]
+pub use confusable_detection::{
+    intentional_confusables, is_intentional_confusable, skeleton, skeleton_casefold,
+    skeleton_casefold_chars, skeleton_chars, skeleton_extended, skeleton_extended_chars,
+};
+#[cfg(all(
+    feature = "alloc",
+    feature = "identifier",
+    feature = "confusables",
+    feature = "mixed-script"
+))]
+pub use findings::{Finding, SecurityChecker};
+#[cfg(feature = "identifier")]
 pub use general_security_profile::GeneralSecurityProfile;
+pub use general_security_profile::{is_bidi_control, is_default_ignorable_code_point};
+#[cfg(all(
+    feature = "identifier",
+    feature = "confusables",
+    feature = "mixed-script"
+))]
+pub use inspect::inspect;
+#[cfg(feature = "mixed-script")]
 pub use mixed_script::is_potential_mixed_script_confusable_char;
+#[cfg(feature = "mixed-script")]
 pub use mixed_script::MixedScript;
+#[cfg(all(feature = "identifier", feature = "mixed-script"))]
 pub use restriction_level::{RestrictionLevel, RestrictionLevelDetection};
 
 #[rustfmt::skip]
@@ -74,3 +228,15 @@ pub(crate) mod tables;
 
 #[cfg(test)]
 mod tests;
+
+#[cfg(all(test, any(feature = "identifier", feature = "confusables")))]
+mod conformance;
+
+#[cfg(all(
+    test,
+    feature = "bench",
+    feature = "identifier",
+    feature = "confusables",
+    feature = "mixed-script"
+))]
+mod benches;
@@ -0,0 +1,454 @@
+//! Command-line scanner for Unicode security problems in source files
+//!
+//! Reports characters restricted from use in identifiers, mixed-script tokens,
+//! confusable collisions between tokens, and invisible or bidirectional control
+//! characters. Exits with status 1 if anything was found, so it can be used in
+//! pre-commit hooks.
+
+use std::collections::btree_map::{BTreeMap, Entry};
+use std::collections::BTreeSet;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::ops::Range;
+use std::process;
+
+use serde::Serialize;
+use serde_json::ser::{Formatter, Serializer};
+use unicode_security::findings::{Finding, FindingKind, SecurityChecker};
+use unicode_security::general_security_profile::IdentifierType;
+use unicode_security::inspect::CharInfo;
+use unicode_security::{
+    inspect, is_bidi_control, is_default_ignorable_code_point, skeleton, GeneralSecurityProfile,
+    MixedScript, RestrictionLevelDetection,
+};
+
+const USAGE: &str = "\
+Usage: unicode-security [OPTIONS] [FILE]...
+       unicode-security inspect [OPTIONS] [STRING]...
+
+Scan files, or standard input if no file or `-` is given, for Unicode security problems.
+With `inspect`, show the properties of each character of the strings, or of standard input.
+
+Options:
+    --json      Print findings as JSON
+    -h, --help  Print this help
+
+Exit status is 0 if nothing was found, 1 if there were findings, and 2 on errors.";
+
+/// A finding in a line of a file
+///
+/// The range of the finding is a byte range of the line.
+#[derive(Clone, Debug, Serialize)]
+struct Report {
+    path: String,
+    line: usize,
+    column: usize,
+    #[serde(flatten)]
+    finding: Finding,
+    message: String,
+}
+
+#[derive(Clone, Debug)]
+struct Location {
+    path: String,
+    line: usize,
+    column: usize,
+}
+
+/// The first occurrence of a token with a given skeleton
+struct SkeletonEntry {
+    token: String,
+    location: Location,
+    is_ascii: bool,
+}
+
+#[derive(Default)]
+struct Scanner {
+    reports: Vec<Report>,
+    skeletons: BTreeMap<String, SkeletonEntry>,
+    seen_tokens: BTreeSet<String>,
+}
+
+fn is_token_char(c: char) -> bool {
+    c == '_' || c.is_alphanumeric() || (!c.is_ascii() && c.identifier_allowed())
+}
+
+fn describe(c: char) -> String {
+    format!("U+{:04X}", c as u32)
+}
+
+fn kind_name(kind: FindingKind) -> &'static str {
+    match kind {
+        FindingKind::RestrictedChar => "restricted-char",
+        FindingKind::DefaultIgnorable => "default-ignorable",
+        FindingKind::BidiControl => "bidi-control",
+        FindingKind::MixedScript => "mixed-script",
+        FindingKind::RestrictionLevel(_) => "restriction-level",
+        FindingKind::MixedScriptConfusable => "mixed-script-confusable",
+        FindingKind::WholeScriptConfusable => "whole-script-confusable",
+        FindingKind::SkeletonCollision => "skeleton-collision",
+        FindingKind::NotNfkc => "not-nfkc",
+        FindingKind::InvalidSyntax => "invalid-syntax",
+    }
+}
+
+impl Scanner {
+    fn report(
+        &mut self,
+        path: &str,
+        line_number: usize,
+        line: &str,
+        finding: Finding,
+        message: String,
+    ) {
+        self.reports.push(Report {
+            path: path.to_owned(),
+            line: line_number,
+            column: line[..finding.range.start].chars().count() + 1,
+            finding,
+            message,
+        });
+    }
+
+    fn scan(&mut self, path: &str, text: &str) {
+        let invisible_chars = SecurityChecker::new()
+            .restricted_chars(false)
+            .mixed_script(false)
+            .max_restriction_level(None)
+            .confusables(false);
+        for (line_idx, line) in text.lines().enumerate() {
+            let line_number = line_idx + 1;
+            let first_report = self.reports.len();
+            for finding in invisible_chars.check(line) {
+                let c = finding.chars[0];
+                let message = if finding.kind == FindingKind::BidiControl {
+                    format!("bidirectional control character {}", describe(c))
+                } else {
+                    format!("invisible character {}", describe(c))
+                };
+                self.report(path, line_number, line, finding, message);
+            }
+
+            let mut token_start = None;
+            for (offset, c) in line.char_indices() {
+                if is_token_char(c) {
+                    token_start.get_or_insert(offset);
+                } else if let Some(start) = token_start.take() {
+                    self.scan_token(path, line_number, line, start..offset);
+                }
+            }
+            if let Some(start) = token_start {
+                self.scan_token(path, line_number, line, start..line.len());
+            }
+            self.reports[first_report..].sort_by_key(|report| report.column);
+        }
+    }
+
+    fn scan_token(&mut self, path: &str, line_number: usize, line: &str, range: Range<usize>) {
+        let token = &line[range.clone()];
+        let checker = SecurityChecker::new()
+            .default_ignorables(false)
+            .max_restriction_level(None)
+            .confusables(false);
+        for mut finding in checker.check(token) {
+            finding.range = finding.range.start + range.start..finding.range.end + range.start;
+            let message = match finding.kind {
+                FindingKind::RestrictedChar => {
+                    let c = finding.chars[0];
+                    match c.identifier_type() {
+                        Some(ty) => {
+                            format!("{} in `{}` is restricted ({:?})", describe(c), token, ty)
+                        }
+                        None => format!("{} in `{}` is restricted", describe(c), token),
+                    }
+                }
+                _ => format!(
+                    "`{}` mixes scripts ({:?}, {:?})",
+                    token,
+                    token.detect_restriction_level(),
+                    token.resolve_script_set()
+                ),
+            };
+            self.report(path, line_number, line, finding, message);
+        }
+
+        if !self.seen_tokens.insert(token.to_owned()) {
+            return;
+        }
+        let location = Location {
+            path: path.to_owned(),
+            line: line_number,
+            column: line[..range.start].chars().count() + 1,
+        };
+        let is_ascii = token.is_ascii();
+        match self.skeletons.entry(skeleton(token).collect()) {
+            Entry::Vacant(entry) => {
+                entry.insert(SkeletonEntry {
+                    token: token.to_owned(),
+                    location,
+                    is_ascii,
+                });
+            }
+            Entry::Occupied(entry) => {
+                let existing = entry.get();
+                if existing.is_ascii && is_ascii {
+                    return;
+                }
+                let message = format!(
+                    "`{}` is confusable with `{}` at {}:{}:{}",
+                    token,
+                    existing.token,
+                    existing.location.path,
+                    existing.location.line,
+                    existing.location.column
+                );
+                let finding = Finding::new(FindingKind::SkeletonCollision, range);
+                self.report(path, line_number, line, finding, message);
+            }
+        }
+    }
+}
+
+/// Writes JSON like the compact formatter, but also escapes invisible and
+/// bidirectional control characters, so that they cannot hide in the output
+struct EscapingFormatter;
+
+impl Formatter for EscapingFormatter {
+    fn write_string_fragment<W: ?Sized + io::Write>(
+        &mut self,
+        writer: &mut W,
+        fragment: &str,
+    ) -> io::Result<()> {
+        for c in fragment.chars() {
+            if is_bidi_control(c) || is_default_ignorable_code_point(c) {
+                let mut buf = [0; 2];
+                for unit in c.encode_utf16(&mut buf) {
+                    write!(writer, "\\u{:04x}", unit)?;
+                }
+            } else {
+                writer.write_all(c.encode_utf8(&mut [0; 4]).as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Print a JSON array with one element per line
+fn print_json<T: Serialize>(out: &mut impl io::Write, items: &[T]) -> io::Result<()> {
+    writeln!(out, "[")?;
+    for (idx, item) in items.iter().enumerate() {
+        write!(out, "  ")?;
+        item.serialize(&mut Serializer::with_formatter(
+            &mut *out,
+            EscapingFormatter,
+        ))?;
+        writeln!(out, "{}", if idx + 1 < items.len() { "," } else { "" })?;
+    }
+    writeln!(out, "]")
+}
+
+fn print_human(out: &mut impl io::Write, reports: &[Report]) -> io::Result<()> {
+    for report in reports {
+        writeln!(
+            out,
+            "{}:{}:{}: {}: {}",
+            report.path,
+            report.line,
+            report.column,
+            kind_name(report.finding.kind),
+            report.message.escape_debug()
+        )?;
+    }
+    Ok(())
+}
+
+fn char_list(chars: &[char]) -> String {
+    let mut out = String::new();
+    for (idx, &c) in chars.iter().enumerate() {
+        if idx > 0 {
+            out.push(' ');
+        }
+        out.push_str(&describe(c));
+    }
+    out
+}
+
+/// The JSON form of a [`CharInfo`]
+#[derive(Serialize)]
+struct CharInfoJson {
+    code_point: String,
+    #[serde(rename = "char")]
+    ch: char,
+    identifier_allowed: bool,
+    identifier_type: Option<IdentifierType>,
+    script_extension: String,
+    augmented_script_set: String,
+    confusable_prototype: Option<String>,
+    default_ignorable: bool,
+    potential_mixed_script_confusable: bool,
+}
+
+impl From<&CharInfo<'_>> for CharInfoJson {
+    fn from(info: &CharInfo<'_>) -> Self {
+        CharInfoJson {
+            code_point: describe(info.ch),
+            ch: info.ch,
+            identifier_allowed: info.identifier_allowed,
+            identifier_type: info.identifier_type,
+            script_extension: info.script_extension.to_string(),
+            augmented_script_set: info.augmented_script_set.to_string(),
+            confusable_prototype: info
+                .confusable_prototype
+                .map(|chars| chars.iter().collect()),
+            default_ignorable: info.default_ignorable,
+            potential_mixed_script_confusable: info.potential_mixed_script_confusable,
+        }
+    }
+}
+
+fn print_char_info_json(out: &mut impl io::Write, infos: &[CharInfo<'_>]) -> io::Result<()> {
+    let infos: Vec<CharInfoJson> = infos.iter().map(CharInfoJson::from).collect();
+    print_json(out, &infos)
+}
+
+fn print_char_info_human(out: &mut impl io::Write, infos: &[CharInfo<'_>]) -> io::Result<()> {
+    let yes_no = |b: bool| if b { "yes" } else { "no" };
+    for info in infos {
+        writeln!(out, "{} {}", describe(info.ch), info.ch.escape_debug())?;
+        let status = if info.identifier_allowed {
+            "allowed"
+        } else {
+            "restricted"
+        };
+        writeln!(out, "  identifier status:      {}", status)?;
+        match info.identifier_type {
+            Some(ty) => writeln!(out, "  identifier type:        {:?}", ty)?,
+            None => writeln!(out, "  identifier type:        none")?,
+        }
+        writeln!(out, "  script extensions:      {}", info.script_extension)?;
+        writeln!(
+            out,
+            "  augmented script set:   {}",
+            info.augmented_script_set
+        )?;
+        match info.confusable_prototype {
+            Some(chars) => writeln!(out, "  confusable prototype:   {}", char_list(chars))?,
+            None => writeln!(out, "  confusable prototype:   none")?,
+        }
+        writeln!(
+            out,
+            "  default ignorable:      {}",
+            yes_no(info.default_ignorable)
+        )?;
+        writeln!(
+            out,
+            "  mixed-script confusable: {}",
+            yes_no(info.potential_mixed_script_confusable)
+        )?;
+    }
+    Ok(())
+}
+
+fn read_input(path: &str) -> io::Result<String> {
+    if path == "-" {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text)?;
+        Ok(text)
+    } else {
+        fs::read_to_string(path)
+    }
+}
+
+fn main() {
+    let mut args = env::args().skip(1).peekable();
+    let inspect_mode = args.peek().map(String::as_str) == Some("inspect");
+    if inspect_mode {
+        args.next();
+    }
+
+    let mut json = false;
+    let mut paths = Vec::new();
+    let mut options_done = false;
+    for arg in args {
+        match arg.as_str() {
+            "--" if !options_done => options_done = true,
+            "--json" if !options_done => json = true,
+            "-h" | "--help" if !options_done => {
+                println!("{}", USAGE);
+                return;
+            }
+            option if !options_done && option.starts_with('-') && option != "-" => {
+                eprintln!("unicode-security: unknown option `{}`\n\n{}", option, USAGE);
+                process::exit(2);
+            }
+            _ => paths.push(arg),
+        }
+    }
+    if inspect_mode {
+        run_inspect(json, &paths);
+        return;
+    }
+    if paths.is_empty() {
+        paths.push("-".to_owned());
+    }
+
+    let mut scanner = Scanner::default();
+    for path in &paths {
+        match read_input(path) {
+            Ok(text) => {
+                let display_path = if path == "-" { "<stdin>" } else { path };
+                scanner.scan(display_path, &text);
+            }
+            Err(err) => {
+                eprintln!("unicode-security: {}: {}", path, err);
+                process::exit(2);
+            }
+        }
+    }
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    check_output(if json {
+        print_json(&mut out, &scanner.reports)
+    } else {
+        print_human(&mut out, &scanner.reports)
+    });
+    if !scanner.reports.is_empty() {
+        process::exit(1);
+    }
+}
+
+fn run_inspect(json: bool, strings: &[String]) {
+    let mut text = strings.join("");
+    if strings.is_empty() {
+        text = match read_input("-") {
+            Ok(text) => text,
+            Err(err) => {
+                eprintln!("unicode-security: <stdin>: {}", err);
+                process::exit(2);
+            }
+        };
+        let trimmed_len = text.trim_end_matches(&['\r', '\n'][..]).len();
+        text.truncate(trimmed_len);
+    }
+
+    let infos: Vec<CharInfo> = text.chars().map(inspect).collect();
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    check_output(if json {
+        print_char_info_json(&mut out, &infos)
+    } else {
+        print_char_info_human(&mut out, &infos)
+    });
+}
+
+fn check_output(result: io::Result<()>) {
+    // A closed pipe, as with `| head`, is not an error worth reporting
+    if let Err(err) = result {
+        if err.kind() != io::ErrorKind::BrokenPipe {
+            eprintln!("unicode-security: {}", err);
+            process::exit(2);
+        }
+    }
+}
@@ -1,8 +1,12 @@
 //! [Mixed-script detection](https://www.unicode.org/reports/tr39/#Mixed_Script_Detection)
 
 use core::fmt::{self, Debug};
+use core::iter::{self, FromIterator};
+use core::str::Chars;
 use unicode_script::{Script, ScriptExtension};
 
+use crate::provider::{BakedData, DataProvider};
+
 /// An Augmented script set, as defined by UTS 39
 ///
 /// https://www.unicode.org/reports/tr39/#def-augmented-script-set
@@ -62,6 +66,16 @@ impl From<&'_ str> for AugmentedScriptSet {
     }
 }
 
+impl FromIterator<char> for AugmentedScriptSet {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut set = AugmentedScriptSet::default();
+        for ch in iter {
+            set.intersect_with(ch.into())
+        }
+        set
+    }
+}
+
 impl Default for AugmentedScriptSet {
     fn default() -> Self {
         AugmentedScriptSet {
@@ -163,14 +177,62 @@ impl AugmentedScriptSet {
         ScriptExtension::from(c).into()
     }
 
+    /// Construct an AugmentedScriptSet for a given character, with the script
+    /// data of a [`DataProvider`]
+    pub fn for_char_with<D: DataProvider + ?Sized>(c: char, data: &D) -> Self {
+        data.script_extension(c).into()
+    }
+
     /// Find the [resolved script set](https://www.unicode.org/reports/tr39/#def-resolved-script-set) of a given string
     pub fn for_str(s: &str) -> Self {
+        s.chars().collect()
+    }
+
+    /// Find the [resolved script set](https://www.unicode.org/reports/tr39/#def-resolved-script-set)
+    /// of a given string, with the script data of a [`DataProvider`]
+    pub fn for_str_with<D: DataProvider + ?Sized>(s: &str, data: &D) -> Self {
         let mut set = AugmentedScriptSet::default();
         for ch in s.chars() {
-            set.intersect_with(ch.into())
+            set.intersect_with(Self::for_char_with(ch, data));
         }
         set
     }
+
+    /// The [ISO 15924](https://www.unicode.org/iso15924/) codes of the scripts in the set
+    ///
+    /// The augmented `Hanb`, `Jpan` and `Kore` come first, followed by the scripts
+    /// of the base set, as in `["Hanb", "Jpan", "Kore", "Hani"]`. The set of all
+    /// scripts, of strings with only Common and Inherited characters, is `["Zyyy"]`,
+    /// and the empty set has no codes.
+    pub fn script_codes(&self) -> impl Iterator<Item = &'static str> {
+        let all = self.is_all();
+        let augmented = [
+            (self.hanb, "Hanb"),
+            (self.jpan, "Jpan"),
+            (self.kore, "Kore"),
+        ];
+        let base = self.base;
+        iter::once("Zyyy")
+            .filter(move |_| all)
+            .chain(
+                IntoIterator::into_iter(augmented)
+                    .filter(move |&(member, _)| member && !all)
+                    .map(|(_, code)| code),
+            )
+            .chain(
+                base.iter()
+                    .filter(move |_| !all)
+                    .map(|script| script.short_name()),
+            )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for AugmentedScriptSet {
+    /// Serialize as the sequence of [`script_codes`](Self::script_codes)
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.script_codes())
+    }
 }
 
 /// Extension trait for [mixed-script detection](https://www.unicode.org/reports/tr39/#Mixed_Script_Detection)
@@ -178,6 +240,7 @@ pub trait MixedScript {
     /// Check if a string is [single-script](https://www.unicode.org/reports/tr39/#def-single-script)
     ///
     /// Note that a single-script string may still contain multiple Script properties!
+    #[allow(clippy::wrong_self_convention)]
     fn is_single_script(self) -> bool;
 
     /// Find the [resolved script set](https://www.unicode.org/reports/tr39/#def-resolved-script-set) of a given string
@@ -194,6 +257,38 @@ impl MixedScript for &'_ str {
     }
 }
 
+impl MixedScript for Chars<'_> {
+    fn is_single_script(self) -> bool {
+        !self.resolve_script_set().is_empty()
+    }
+
+    fn resolve_script_set(self) -> AugmentedScriptSet {
+        self.collect()
+    }
+}
+
+impl MixedScript for &'_ [char] {
+    fn is_single_script(self) -> bool {
+        !self.resolve_script_set().is_empty()
+    }
+
+    fn resolve_script_set(self) -> AugmentedScriptSet {
+        self.iter().copied().collect()
+    }
+}
+
+/// Check if a string is [single-script](MixedScript::is_single_script), with the
+/// script data of a [`DataProvider`]
+pub fn is_single_script_with<D: DataProvider + ?Sized>(s: &str, data: &D) -> bool {
+    !AugmentedScriptSet::for_str_with(s, data).is_empty()
+}
+
+/// Find the [resolved script set](MixedScript::resolve_script_set) of a string,
+/// with the script data of a [`DataProvider`]
+pub fn resolve_script_set_with<D: DataProvider + ?Sized>(s: &str, data: &D) -> AugmentedScriptSet {
+    AugmentedScriptSet::for_str_with(s, data)
+}
+
 /// Check if a character is considered potential mixed script confusable.
 ///
 /// If the specified character is not restricted from use for identifiers,
@@ -203,7 +298,15 @@ impl MixedScript for &'_ str {
 /// If the specified character is restricted from use for identifiers,
 /// the return value is unspecified.
 pub fn is_potential_mixed_script_confusable_char(c: char) -> bool {
-    use crate::tables::potential_mixed_script_confusable::potential_mixed_script_confusable;
+    is_potential_mixed_script_confusable_char_with(c, &BakedData)
+}
 
-    potential_mixed_script_confusable(c)
+/// Check if a character is considered
+/// [potential mixed script confusable](is_potential_mixed_script_confusable_char),
+/// with the data of a [`DataProvider`]
+pub fn is_potential_mixed_script_confusable_char_with<D: DataProvider + ?Sized>(
+    c: char,
+    data: &D,
+) -> bool {
+    data.potential_mixed_script_confusable(c)
 }
@@ -0,0 +1,108 @@
+//! Access to the character properties the checks of this crate are based on
+//!
+//! The checks with a `_with` suffix, such as
+//! [`skeleton_with`](crate::confusable_detection::skeleton_with), look up
+//! character properties through a [`DataProvider`] instead of the tables
+//! compiled into this crate. This allows running them against other data: an
+//! older Unicode version for reproducibility, patched confusables, or test
+//! fixtures.
+//!
+//! ```rust
+//! # #[cfg(not(feature = "confusables"))]
+//! # fn main() {}
+//! # #[cfg(feature = "confusables")]
+//! # fn main() {
+//! use unicode_security::confusable_detection::skeleton_with;
+//! use unicode_security::provider::DataProvider;
+//!
+//! /// The compiled-in data, with "ℓ" no longer confusable with "l"
+//! struct Patched;
+//!
+//! impl DataProvider for Patched {
+//!     fn confusable_prototype(&self, c: char) -> Option<&[char]> {
+//!         match c {
+//!             'ℓ' => None,
+//!             _ => unicode_security::provider::BakedData.confusable_prototype(c),
+//!         }
+//!     }
+//! }
+//!
+//! assert!(skeleton_with("ℓ", &Patched).eq("ℓ".chars()));
+//! # }
+//! ```
+
+#[cfg(feature = "mixed-script")]
+use unicode_script::ScriptExtension;
+
+#[cfg(feature = "identifier")]
+use crate::general_security_profile::IdentifierType;
+
+/// The character property lookups needed by the checks of this crate
+///
+/// Every method defaults to the tables compiled into this crate, so an
+/// implementation only overrides the properties it provides.
+pub trait DataProvider {
+    /// Whether a character is not
+    /// [restricted](crate::GeneralSecurityProfile::identifier_allowed) from use
+    /// for identifiers
+    #[cfg(feature = "identifier")]
+    fn identifier_allowed(&self, c: char) -> bool {
+        crate::tables::identifier::identifier_status_allowed(c)
+    }
+
+    /// The [identifier type](crate::GeneralSecurityProfile::identifier_type) of a character
+    #[cfg(feature = "identifier")]
+    fn identifier_type(&self, c: char) -> Option<IdentifierType> {
+        crate::tables::identifier::identifier_type(c)
+    }
+
+    /// Whether a character is a
+    /// [default ignorable code point](crate::is_default_ignorable_code_point)
+    fn default_ignorable(&self, c: char) -> bool {
+        crate::tables::default_ignorable_code_point::default_ignorable_code_point(c)
+    }
+
+    /// The [prototype](https://www.unicode.org/reports/tr39/#def-prototype) of a
+    /// character, if it is mapped in confusables.txt
+    #[cfg(feature = "confusables")]
+    fn confusable_prototype(&self, c: char) -> Option<&[char]> {
+        crate::tables::confusable_detection::char_confusable_prototype(c)
+    }
+
+    /// The characters a character is
+    /// [intentionally confusable](crate::confusable_detection::is_intentional_confusable)
+    /// with, in code point order
+    #[cfg(feature = "confusables")]
+    fn intentional_confusables(&self, c: char) -> &[char] {
+        crate::tables::intentional_confusables::intentional_confusables(c)
+    }
+
+    /// The full case folding of a character, if it is not folded to itself
+    #[cfg(feature = "confusables")]
+    fn case_fold(&self, c: char) -> Option<&[char]> {
+        crate::tables::case_folding::case_fold(c)
+    }
+
+    /// The [Script_Extensions](https://www.unicode.org/reports/tr24/#Script_Extensions)
+    /// property of a character
+    #[cfg(feature = "mixed-script")]
+    fn script_extension(&self, c: char) -> ScriptExtension {
+        ScriptExtension::from(c)
+    }
+
+    /// Whether a character is
+    /// [potentially mixed-script confusable](crate::is_potential_mixed_script_confusable_char)
+    #[cfg(feature = "mixed-script")]
+    fn potential_mixed_script_confusable(&self, c: char) -> bool {
+        crate::tables::potential_mixed_script_confusable::potential_mixed_script_confusable(c)
+    }
+}
+
+/// The tables compiled into this crate, for the Unicode version in
+/// [`UNICODE_VERSION`](crate::UNICODE_VERSION)
+///
+/// The checks without a `_with` suffix use this data.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+pub struct BakedData;
+
+impl DataProvider for BakedData {}
@@ -0,0 +1,170 @@
+//! Serializable reports of the checks of this crate
+//!
+//! A log of rejected strings is most useful with the reasoning behind each
+//! rejection. Every report here records the outcome of one check, and
+//! [`SecurityReport`] records all of them. They implement `serde::Serialize`,
+//! with restriction levels, identifier types and scripts written as their names
+//! in UTS #39, so that logs stay comparable across versions of this crate.
+//!
+//! ```rust
+//! use unicode_security::report::SecurityReport;
+//!
+//! let report = SecurityReport::new("pаypal"); // with a Cyrillic 'а'
+//! assert!(!report.mixed_script.single_script);
+//! assert_eq!(report.skeleton.skeleton, "paypal");
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::Serialize;
+
+use crate::confusable_detection::skeleton_with;
+use crate::general_security_profile::IdentifierType;
+use crate::mixed_script::{resolve_script_set_with, AugmentedScriptSet};
+use crate::provider::{BakedData, DataProvider};
+use crate::restriction_level::detect_restriction_level_with;
+use crate::RestrictionLevel;
+
+/// A character [restricted](crate::GeneralSecurityProfile::identifier_allowed) from use
+/// in identifiers
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Serialize)]
+pub struct RestrictedChar {
+    /// The byte offset of the character in the string
+    pub offset: usize,
+    /// The character
+    pub character: char,
+    /// The code point of the character
+    pub code_point: u32,
+    /// The [identifier type](crate::GeneralSecurityProfile::identifier_type) of the character
+    pub identifier_type: Option<IdentifierType>,
+}
+
+/// The restricted characters of a string
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Serialize)]
+pub struct IdentifierReport {
+    /// The restricted characters, in the order of the string
+    pub restricted_chars: Vec<RestrictedChar>,
+}
+
+impl IdentifierReport {
+    /// Check a string for restricted characters
+    pub fn new(s: &str) -> Self {
+        Self::new_with(s, &BakedData)
+    }
+
+    /// Check a string for restricted characters, with the data of a [`DataProvider`]
+    pub fn new_with<D: DataProvider + ?Sized>(s: &str, data: &D) -> Self {
+        let restricted_chars = s
+            .char_indices()
+            .filter(|&(_, c)| !data.identifier_allowed(c))
+            .map(|(offset, c)| RestrictedChar {
+                offset,
+                character: c,
+                code_point: c as u32,
+                identifier_type: data.identifier_type(c),
+            })
+            .collect();
+        IdentifierReport { restricted_chars }
+    }
+}
+
+/// The scripts of a string
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Serialize)]
+pub struct MixedScriptReport {
+    /// Whether the string is [single-script](crate::MixedScript::is_single_script)
+    pub single_script: bool,
+    /// The [resolved script set](crate::MixedScript::resolve_script_set) of the string,
+    /// serialized as its [script codes](AugmentedScriptSet::script_codes)
+    pub resolved_script_set: AugmentedScriptSet,
+}
+
+impl MixedScriptReport {
+    /// Resolve the scripts of a string
+    pub fn new(s: &str) -> Self {
+        Self::new_with(s, &BakedData)
+    }
+
+    /// Resolve the scripts of a string, with the data of a [`DataProvider`]
+    pub fn new_with<D: DataProvider + ?Sized>(s: &str, data: &D) -> Self {
+        let resolved_script_set = resolve_script_set_with(s, data);
+        MixedScriptReport {
+            single_script: !resolved_script_set.is_empty(),
+            resolved_script_set,
+        }
+    }
+}
+
+/// The restriction level of a string
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Serialize)]
+pub struct RestrictionLevelReport {
+    /// The [restriction level](crate::RestrictionLevelDetection::detect_restriction_level)
+    pub level: RestrictionLevel,
+}
+
+impl RestrictionLevelReport {
+    /// Detect the restriction level of a string
+    pub fn new(s: &str) -> Self {
+        Self::new_with(s, &BakedData)
+    }
+
+    /// Detect the restriction level of a string, with the data of a [`DataProvider`]
+    pub fn new_with<D: DataProvider + ?Sized>(s: &str, data: &D) -> Self {
+        RestrictionLevelReport {
+            level: detect_restriction_level_with(s, data),
+        }
+    }
+}
+
+/// The skeleton of a string
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Serialize)]
+pub struct SkeletonReport {
+    /// The [skeleton](crate::skeleton) of the string
+    pub skeleton: String,
+}
+
+impl SkeletonReport {
+    /// Compute the skeleton of a string
+    pub fn new(s: &str) -> Self {
+        Self::new_with(s, &BakedData)
+    }
+
+    /// Compute the skeleton of a string, with the data of a [`DataProvider`]
+    pub fn new_with<D: DataProvider + ?Sized>(s: &str, data: &D) -> Self {
+        SkeletonReport {
+            skeleton: skeleton_with(s, data).collect(),
+        }
+    }
+}
+
+/// The reports of all checks for a string
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Serialize)]
+pub struct SecurityReport {
+    /// The checked string
+    pub input: String,
+    /// The restricted characters of the string
+    pub identifier: IdentifierReport,
+    /// The scripts of the string
+    pub mixed_script: MixedScriptReport,
+    /// The restriction level of the string
+    pub restriction_level: RestrictionLevelReport,
+    /// The skeleton of the string
+    pub skeleton: SkeletonReport,
+}
+
+impl SecurityReport {
+    /// Run all checks on a string
+    pub fn new(s: &str) -> Self {
+        Self::new_with(s, &BakedData)
+    }
+
+    /// Run all checks on a string, with the data of a [`DataProvider`]
+    pub fn new_with<D: DataProvider + ?Sized>(s: &str, data: &D) -> Self {
+        SecurityReport {
+            input: s.into(),
+            identifier: IdentifierReport::new_with(s, data),
+            mixed_script: MixedScriptReport::new_with(s, data),
+            restriction_level: RestrictionLevelReport::new_with(s, data),
+            skeleton: SkeletonReport::new_with(s, data),
+        }
+    }
+}
@@ -2,30 +2,39 @@
 //! a string conforms to
 
 use crate::mixed_script::AugmentedScriptSet;
-use crate::GeneralSecurityProfile;
+use crate::provider::{BakedData, DataProvider};
+use core::iter::FromIterator;
+use core::str::Chars;
 use unicode_script::Script;
 
 #[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 /// The [Restriction level](https://www.unicode.org/reports/tr39/#Restriction_Level_Detection)
 /// a string conforms to
 pub enum RestrictionLevel {
     /// https://www.unicode.org/reports/tr39/#ascii_only
+    #[cfg_attr(feature = "serde", serde(rename = "ASCII-Only"))]
     ASCIIOnly,
     /// https://www.unicode.org/reports/tr39/#single_script
+    #[cfg_attr(feature = "serde", serde(rename = "Single Script"))]
     SingleScript,
     /// https://www.unicode.org/reports/tr39/#highly_restrictive
+    #[cfg_attr(feature = "serde", serde(rename = "Highly Restrictive"))]
     HighlyRestrictive,
     /// https://www.unicode.org/reports/tr39/#moderately_restrictive
+    #[cfg_attr(feature = "serde", serde(rename = "Moderately Restrictive"))]
     ModeratelyRestrictive,
     /// https://www.unicode.org/reports/tr39/#minimally_restrictive
+    #[cfg_attr(feature = "serde", serde(rename = "Minimally Restrictive"))]
     MinimallyRestrictive,
     /// https://www.unicode.org/reports/tr39/#unrestricted
+    #[cfg_attr(feature = "serde", serde(rename = "Unrestricted"))]
     Unrestricted,
 }
 
 /// Utilities for determining which [restriction level](https://www.unicode.org/reports/tr39/#Restriction_Level_Detection)
 /// a string satisfies
-pub trait RestrictionLevelDetection: Sized {
+pub trait RestrictionLevelDetection {
     /// Detect the [restriction level](https://www.unicode.org/reports/tr39/#Restriction_Level_Detection)
     ///
     /// This will _not_ check identifier well-formedness, as different applications may have different notions of well-formedness
@@ -34,33 +43,93 @@ pub trait RestrictionLevelDetection: Sized {
     /// Check if a string satisfies the supplied [restriction level](https://www.unicode.org/reports/tr39/#Restriction_Level_Detection)
     ///
     /// This will _not_ check identifier well-formedness, as different applications may have different notions of well-formedness
-    fn check_restriction_level(self, level: RestrictionLevel) -> bool {
+    fn check_restriction_level(self, level: RestrictionLevel) -> bool
+    where
+        Self: Sized,
+    {
         self.detect_restriction_level() <= level
     }
 }
 
-impl RestrictionLevelDetection for &'_ str {
-    fn detect_restriction_level(self) -> RestrictionLevel {
-        let mut ascii_only = true;
-        let mut set = AugmentedScriptSet::default();
-        let mut exclude_latin_set = AugmentedScriptSet::default();
-        for ch in self.chars() {
-            if !GeneralSecurityProfile::identifier_allowed(ch) {
-                return RestrictionLevel::Unrestricted;
-            }
-            if !ch.is_ascii() {
-                ascii_only = false;
-            }
-            let ch_set = ch.into();
-            set.intersect_with(ch_set);
-            if !ch_set.base.contains_script(Script::Latin) {
-                exclude_latin_set.intersect_with(ch_set);
-            }
+/// Detects the [restriction level](https://www.unicode.org/reports/tr39/#Restriction_Level_Detection)
+/// of a string fed to it piece by piece
+///
+/// This is useful when the string is not available as one contiguous `&str`,
+/// for example when it is produced by a lexer one character at a time or is
+/// stored in several buffers. Any iterator of chars can also be
+/// [collected](core::iter::FromIterator) into a detector.
+///
+/// ```rust
+/// use unicode_security::restriction_level::{RestrictionLevel, RestrictionLevelDetector};
+///
+/// let mut detector = RestrictionLevelDetector::new();
+/// detector.push_str("Σ");
+/// detector.push('x');
+/// assert_eq!(detector.finish(), RestrictionLevel::MinimallyRestrictive);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct RestrictionLevelDetector {
+    restricted: bool,
+    ascii_only: bool,
+    set: AugmentedScriptSet,
+    exclude_latin_set: AugmentedScriptSet,
+}
+
+impl Default for RestrictionLevelDetector {
+    fn default() -> Self {
+        RestrictionLevelDetector {
+            restricted: false,
+            ascii_only: true,
+            set: AugmentedScriptSet::default(),
+            exclude_latin_set: AugmentedScriptSet::default(),
+        }
+    }
+}
+
+impl RestrictionLevelDetector {
+    /// Create a detector for the empty string
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a character to the string
+    pub fn push(&mut self, ch: char) {
+        self.push_with(ch, &BakedData)
+    }
+
+    /// Append a character to the string, with the identifier status and script
+    /// data of a [`DataProvider`]
+    pub fn push_with<D: DataProvider + ?Sized>(&mut self, ch: char, data: &D) {
+        if self.restricted {
+            return;
+        }
+        if !data.identifier_allowed(ch) {
+            self.restricted = true;
+            return;
+        }
+        if !ch.is_ascii() {
+            self.ascii_only = false;
         }
+        let ch_set = AugmentedScriptSet::for_char_with(ch, data);
+        self.set.intersect_with(ch_set);
+        if !ch_set.base.contains_script(Script::Latin) {
+            self.exclude_latin_set.intersect_with(ch_set);
+        }
+    }
 
-        if ascii_only {
+    /// Append a string to the string
+    pub fn push_str(&mut self, s: &str) {
+        self.extend(s.chars())
+    }
+
+    /// Get the restriction level of the string pushed so far
+    pub fn finish(&self) -> RestrictionLevel {
+        let exclude_latin_set = self.exclude_latin_set;
+        if self.restricted {
+            return RestrictionLevel::Unrestricted;
+        } else if self.ascii_only {
             return RestrictionLevel::ASCIIOnly;
-        } else if !set.is_empty() {
+        } else if !self.set.is_empty() {
             return RestrictionLevel::SingleScript;
         } else if exclude_latin_set.kore || exclude_latin_set.hanb || exclude_latin_set.jpan {
             return RestrictionLevel::HighlyRestrictive;
@@ -70,6 +139,56 @@ impl RestrictionLevelDetection for &'_ str {
                 return RestrictionLevel::ModeratelyRestrictive;
             }
         }
-        return RestrictionLevel::MinimallyRestrictive;
+        RestrictionLevel::MinimallyRestrictive
+    }
+}
+
+impl Extend<char> for RestrictionLevelDetector {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        for ch in iter {
+            self.push(ch);
+        }
+    }
+}
+
+impl FromIterator<char> for RestrictionLevelDetector {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut detector = RestrictionLevelDetector::new();
+        detector.extend(iter);
+        detector
+    }
+}
+
+impl RestrictionLevelDetection for &'_ str {
+    fn detect_restriction_level(self) -> RestrictionLevel {
+        self.chars().detect_restriction_level()
+    }
+}
+
+impl RestrictionLevelDetection for Chars<'_> {
+    fn detect_restriction_level(self) -> RestrictionLevel {
+        self.collect::<RestrictionLevelDetector>().finish()
+    }
+}
+
+impl RestrictionLevelDetection for &'_ [char] {
+    fn detect_restriction_level(self) -> RestrictionLevel {
+        self.iter()
+            .copied()
+            .collect::<RestrictionLevelDetector>()
+            .finish()
+    }
+}
+
+/// Detect the [restriction level](https://www.unicode.org/reports/tr39/#Restriction_Level_Detection)
+/// of a string, with the identifier status and script data of a [`DataProvider`]
+pub fn detect_restriction_level_with<D: DataProvider + ?Sized>(
+    s: &str,
+    data: &D,
+) -> RestrictionLevel {
+    let mut detector = RestrictionLevelDetector::new();
+    for ch in s.chars() {
+        detector.push_with(ch, data);
     }
+    detector.finish()
 }
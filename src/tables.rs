@@ -17,53 +17,60 @@
 pub const UNICODE_VERSION: (u64, u64, u64) = (17, 0, 0);
 
 
-pub mod util {
-    use core::result::Result::{Ok, Err};
-
-    #[inline]
-    pub fn bsearch_table(c: char, r: &'static [char]) -> bool {
-        r.binary_search(&c).is_ok()
-    }
 
+pub mod util {
+    #[cfg(feature = "confusables")]
     #[inline]
     pub fn bsearch_value_table<T: Copy>(c: char, r: &'static [(char, T)]) -> Option<T> {
-        match r.binary_search_by_key(&c, |&(k, _)| k) {
-            Ok(idx) => {
-                let (_, v) = r[idx];
-                Some(v)
-            }
-            Err(_) => None
-        }
+        r.binary_search_by_key(&c, |&(k, _)| k).ok().map(|idx| r[idx].1)
     }
 
-    #[inline]
-    pub fn bsearch_range_table(c: char, r: &'static [(char,char)]) -> bool {
-        use core::cmp::Ordering::{Equal, Less, Greater};
-        r.binary_search_by(|&(lo,hi)| {
-            if lo <= c && c <= hi { Equal }
-            else if hi < c { Less }
-            else { Greater }
-        }).is_ok()
+    /// A three-level lookup table over all code points
+    ///
+    /// Values are stored in deduplicated leaves of `1 << leaf_bits` values, which
+    /// are numbered by deduplicated blocks of `1 << block_bits` leaves, which are
+    /// numbered by `index`. Positions past the end of `index` have the value 0.
+    /// The values of the first positions are also stored in `latin1`, so that
+    /// ASCII and Latin-1 lookups take a single load.
+    pub struct Trie<T: 'static> {
+        pub latin1: &'static [T],
+        pub index: &'static [u8],
+        pub blocks: &'static [u16],
+        pub leaves: &'static [T],
+        pub block_bits: u32,
+        pub leaf_bits: u32,
     }
 
-    pub fn bsearch_range_value_table<T: Copy>(c: char, r: &'static [(char, char, T)]) -> Option<T> {
-        use core::cmp::Ordering::{Equal, Less, Greater};
-        match r.binary_search_by(|&(lo, hi, _)| {
-            if lo <= c && c <= hi { Equal }
-            else if hi < c { Less }
-            else { Greater }
-        }) {
-            Ok(idx) => {
-                let (_, _, cat) = r[idx];
-                Some(cat)
+    impl<T: Copy + Default> Trie<T> {
+        #[inline]
+        pub fn lookup(&self, i: u32) -> T {
+            let i = i as usize;
+            if let Some(&v) = self.latin1.get(i) {
+                return v;
             }
-            Err(_) => None
+            let block = match self.index.get(i >> (self.block_bits + self.leaf_bits)) {
+                Some(&block) => block as usize,
+                None => return T::default(),
+            };
+            let block_mask = (1 << self.block_bits) - 1;
+            let leaf = self.blocks[(block << self.block_bits) | ((i >> self.leaf_bits) & block_mask)];
+            let leaf_mask = (1 << self.leaf_bits) - 1;
+            self.leaves[((leaf as usize) << self.leaf_bits) | (i & leaf_mask)]
         }
     }
 
+    /// Look up a character in a trie holding one bit per code point
+    #[inline]
+    pub fn trie_bitset_contains(c: char, trie: &Trie<u64>) -> bool {
+        let c = c as u32;
+        (trie.lookup(c >> 6) >> (c & 63)) & 1 != 0
+    }
+
 }
 
+#[cfg(feature = "identifier")]
 pub mod identifier {
+    use super::util::Trie;
 
     #[derive(Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Debug)]
     #[allow(non_camel_case_types)]
@@ -87,6405 +94,3502 @@ pub mod identifier {
     }
     #[inline]
     pub fn identifier_status_allowed(c: char) -> bool {
-        // FIXME: do we want to special case ASCII here?
-        match c as usize {
-            _ => super::util::bsearch_range_table(c, IDENTIFIER_STATUS)
-        }
+        super::util::trie_bitset_contains(c, &IDENTIFIER_STATUS)
     }
 
     #[inline]
     pub fn identifier_type(c: char) -> Option<IdentifierType> {
-        // FIXME: do we want to special case ASCII here?
-        match c as usize {
-            _ => super::util::bsearch_range_value_table(c, IDENTIFIER_TYPE)
-        }
+        IDENTIFIER_TYPES[IDENTIFIER_TYPE.lookup(c as u32) as usize]
     }
     // Identifier status table:
-    const IDENTIFIER_STATUS: &'static [(char, char)] = &[
-        ('\u{27}', '\u{27}'), ('\u{2d}', '\u{2e}'), ('\u{30}', '\u{3a}'), ('\u{41}', '\u{5a}'),
-        ('\u{5f}', '\u{5f}'), ('\u{61}', '\u{7a}'), ('\u{b7}', '\u{b7}'), ('\u{c0}', '\u{d6}'),
-        ('\u{d8}', '\u{f6}'), ('\u{f8}', '\u{113}'), ('\u{116}', '\u{12b}'), ('\u{12e}', '\u{131}'),
-        ('\u{134}', '\u{137}'), ('\u{139}', '\u{13e}'), ('\u{141}', '\u{148}'), ('\u{14a}',
-        '\u{14d}'), ('\u{150}', '\u{155}'), ('\u{158}', '\u{161}'), ('\u{164}', '\u{17e}'),
-        ('\u{181}', '\u{181}'), ('\u{186}', '\u{186}'), ('\u{189}', '\u{18a}'), ('\u{18e}',
-        '\u{192}'), ('\u{194}', '\u{194}'), ('\u{196}', '\u{199}'), ('\u{19d}', '\u{19d}'),
-        ('\u{1a0}', '\u{1a1}'), ('\u{1af}', '\u{1b0}'), ('\u{1b2}', '\u{1b4}'), ('\u{1b7}',
-        '\u{1b7}'), ('\u{1cd}', '\u{1d4}'), ('\u{1dd}', '\u{1dd}'), ('\u{1e6}', '\u{1e9}'),
-        ('\u{1ee}', '\u{1ef}'), ('\u{1f8}', '\u{1f9}'), ('\u{218}', '\u{21b}'), ('\u{244}',
-        '\u{244}'), ('\u{24c}', '\u{24d}'), ('\u{253}', '\u{254}'), ('\u{256}', '\u{257}'),
-        ('\u{259}', '\u{259}'), ('\u{25b}', '\u{25b}'), ('\u{263}', '\u{263}'), ('\u{268}',
-        '\u{269}'), ('\u{272}', '\u{272}'), ('\u{289}', '\u{289}'), ('\u{28b}', '\u{28b}'),
-        ('\u{292}', '\u{292}'), ('\u{2bb}', '\u{2bc}'), ('\u{300}', '\u{304}'), ('\u{306}',
-        '\u{30c}'), ('\u{31b}', '\u{31b}'), ('\u{323}', '\u{323}'), ('\u{326}', '\u{328}'),
-        ('\u{331}', '\u{331}'), ('\u{386}', '\u{386}'), ('\u{388}', '\u{38a}'), ('\u{38c}',
-        '\u{38c}'), ('\u{38e}', '\u{3a1}'), ('\u{3a3}', '\u{3ce}'), ('\u{401}', '\u{40c}'),
-        ('\u{40e}', '\u{44f}'), ('\u{451}', '\u{45c}'), ('\u{45e}', '\u{45f}'), ('\u{490}',
-        '\u{49b}'), ('\u{49e}', '\u{4a5}'), ('\u{4a8}', '\u{4b7}'), ('\u{4ba}', '\u{4c0}'),
-        ('\u{4cf}', '\u{4cf}'), ('\u{4d0}', '\u{4d9}'), ('\u{4dc}', '\u{4e9}'), ('\u{4ee}',
-        '\u{4f5}'), ('\u{4f8}', '\u{4f9}'), ('\u{524}', '\u{525}'), ('\u{531}', '\u{556}'),
-        ('\u{561}', '\u{586}'), ('\u{58a}', '\u{58a}'), ('\u{5d0}', '\u{5ea}'), ('\u{5f3}',
-        '\u{5f4}'), ('\u{620}', '\u{620}'), ('\u{621}', '\u{63a}'), ('\u{63d}', '\u{63d}'),
-        ('\u{641}', '\u{652}'), ('\u{654}', '\u{655}'), ('\u{660}', '\u{669}'), ('\u{670}',
-        '\u{670}'), ('\u{672}', '\u{672}'), ('\u{674}', '\u{674}'), ('\u{679}', '\u{68f}'),
-        ('\u{691}', '\u{69a}'), ('\u{69f}', '\u{6a0}'), ('\u{6a2}', '\u{6a2}'), ('\u{6a4}',
-        '\u{6ab}'), ('\u{6ad}', '\u{6b1}'), ('\u{6b3}', '\u{6b3}'), ('\u{6b5}', '\u{6b7}'),
-        ('\u{6ba}', '\u{6be}'), ('\u{6c0}', '\u{6ce}'), ('\u{6cf}', '\u{6cf}'), ('\u{6d0}',
-        '\u{6d3}'), ('\u{6d5}', '\u{6d5}'), ('\u{6ee}', '\u{6ef}'), ('\u{6f0}', '\u{6f9}'),
-        ('\u{6fd}', '\u{6fe}'), ('\u{6ff}', '\u{6ff}'), ('\u{751}', '\u{752}'), ('\u{756}',
-        '\u{756}'), ('\u{760}', '\u{760}'), ('\u{762}', '\u{763}'), ('\u{766}', '\u{768}'),
-        ('\u{76a}', '\u{76a}'), ('\u{76e}', '\u{771}'), ('\u{780}', '\u{7b0}'), ('\u{7b1}',
-        '\u{7b1}'), ('\u{88f}', '\u{88f}'), ('\u{8a0}', '\u{8a0}'), ('\u{8a2}', '\u{8a9}'),
-        ('\u{8bb}', '\u{8bd}'), ('\u{8be}', '\u{8c2}'), ('\u{8c7}', '\u{8c7}'), ('\u{901}',
-        '\u{903}'), ('\u{905}', '\u{90b}'), ('\u{90d}', '\u{928}'), ('\u{92a}', '\u{933}'),
-        ('\u{935}', '\u{939}'), ('\u{93a}', '\u{93b}'), ('\u{93c}', '\u{93c}'), ('\u{93e}',
-        '\u{943}'), ('\u{945}', '\u{94d}'), ('\u{94f}', '\u{94f}'), ('\u{956}', '\u{957}'),
-        ('\u{966}', '\u{96f}'), ('\u{972}', '\u{972}'), ('\u{973}', '\u{977}'), ('\u{97b}',
-        '\u{97c}'), ('\u{97e}', '\u{97f}'), ('\u{981}', '\u{983}'), ('\u{985}', '\u{98b}'),
-        ('\u{98f}', '\u{990}'), ('\u{993}', '\u{9a8}'), ('\u{9aa}', '\u{9b0}'), ('\u{9b2}',
-        '\u{9b2}'), ('\u{9b6}', '\u{9b9}'), ('\u{9bc}', '\u{9bc}'), ('\u{9be}', '\u{9c4}'),
-        ('\u{9c7}', '\u{9c8}'), ('\u{9cb}', '\u{9cd}'), ('\u{9ce}', '\u{9ce}'), ('\u{9e6}',
-        '\u{9f1}'), ('\u{a02}', '\u{a02}'), ('\u{a05}', '\u{a0a}'), ('\u{a0f}', '\u{a10}'),
-        ('\u{a13}', '\u{a28}'), ('\u{a2a}', '\u{a30}'), ('\u{a32}', '\u{a32}'), ('\u{a35}',
-        '\u{a35}'), ('\u{a38}', '\u{a39}'), ('\u{a3c}', '\u{a3c}'), ('\u{a3e}', '\u{a42}'),
-        ('\u{a47}', '\u{a48}'), ('\u{a4b}', '\u{a4d}'), ('\u{a5c}', '\u{a5c}'), ('\u{a70}',
-        '\u{a71}'), ('\u{a82}', '\u{a83}'), ('\u{a85}', '\u{a8b}'), ('\u{a8c}', '\u{a8c}'),
-        ('\u{a8d}', '\u{a8d}'), ('\u{a8f}', '\u{a91}'), ('\u{a93}', '\u{aa8}'), ('\u{aaa}',
-        '\u{ab0}'), ('\u{ab2}', '\u{ab3}'), ('\u{ab5}', '\u{ab9}'), ('\u{abc}', '\u{abc}'),
-        ('\u{abe}', '\u{ac5}'), ('\u{ac7}', '\u{ac9}'), ('\u{acb}', '\u{acd}'), ('\u{ae6}',
-        '\u{aef}'), ('\u{b01}', '\u{b03}'), ('\u{b05}', '\u{b0b}'), ('\u{b0f}', '\u{b10}'),
-        ('\u{b13}', '\u{b28}'), ('\u{b2a}', '\u{b30}'), ('\u{b32}', '\u{b33}'), ('\u{b36}',
-        '\u{b39}'), ('\u{b3c}', '\u{b3c}'), ('\u{b3e}', '\u{b43}'), ('\u{b47}', '\u{b48}'),
-        ('\u{b4b}', '\u{b4d}'), ('\u{b56}', '\u{b56}'), ('\u{b5f}', '\u{b5f}'), ('\u{b71}',
-        '\u{b71}'), ('\u{b83}', '\u{b83}'), ('\u{b85}', '\u{b8a}'), ('\u{b8e}', '\u{b90}'),
-        ('\u{b92}', '\u{b95}'), ('\u{b99}', '\u{b9a}'), ('\u{b9c}', '\u{b9c}'), ('\u{b9e}',
-        '\u{b9f}'), ('\u{ba3}', '\u{ba4}'), ('\u{ba8}', '\u{baa}'), ('\u{bae}', '\u{bb5}'),
-        ('\u{bb6}', '\u{bb6}'), ('\u{bb7}', '\u{bb9}'), ('\u{bbe}', '\u{bc2}'), ('\u{bc6}',
-        '\u{bc8}'), ('\u{bca}', '\u{bcd}'), ('\u{c02}', '\u{c03}'), ('\u{c05}', '\u{c0b}'),
-        ('\u{c0e}', '\u{c10}'), ('\u{c12}', '\u{c28}'), ('\u{c2a}', '\u{c30}'), ('\u{c32}',
-        '\u{c33}'), ('\u{c35}', '\u{c39}'), ('\u{c3e}', '\u{c44}'), ('\u{c46}', '\u{c48}'),
-        ('\u{c4a}', '\u{c4d}'), ('\u{c82}', '\u{c83}'), ('\u{c85}', '\u{c8b}'), ('\u{c8e}',
-        '\u{c90}'), ('\u{c92}', '\u{ca8}'), ('\u{caa}', '\u{cb0}'), ('\u{cb2}', '\u{cb3}'),
-        ('\u{cb5}', '\u{cb9}'), ('\u{cbe}', '\u{cc3}'), ('\u{cc6}', '\u{cc8}'), ('\u{cca}',
-        '\u{ccd}'), ('\u{ce6}', '\u{cef}'), ('\u{d02}', '\u{d03}'), ('\u{d05}', '\u{d0b}'),
-        ('\u{d0e}', '\u{d10}'), ('\u{d12}', '\u{d28}'), ('\u{d2a}', '\u{d39}'), ('\u{d3e}',
-        '\u{d43}'), ('\u{d46}', '\u{d48}'), ('\u{d4a}', '\u{d4b}'), ('\u{d4d}', '\u{d4d}'),
-        ('\u{d57}', '\u{d57}'), ('\u{d7a}', '\u{d7f}'), ('\u{d82}', '\u{d83}'), ('\u{d85}',
-        '\u{d8d}'), ('\u{d91}', '\u{d96}'), ('\u{d9a}', '\u{d9d}'), ('\u{d9f}', '\u{db1}'),
-        ('\u{db3}', '\u{dbb}'), ('\u{dbd}', '\u{dbd}'), ('\u{dc0}', '\u{dc6}'), ('\u{dca}',
-        '\u{dca}'), ('\u{dcf}', '\u{dd4}'), ('\u{dd6}', '\u{dd6}'), ('\u{dd8}', '\u{dde}'),
-        ('\u{df2}', '\u{df2}'), ('\u{e01}', '\u{e32}'), ('\u{e34}', '\u{e3a}'), ('\u{e40}',
-        '\u{e4d}'), ('\u{e50}', '\u{e59}'), ('\u{e81}', '\u{e82}'), ('\u{e84}', '\u{e84}'),
-        ('\u{e87}', '\u{e88}'), ('\u{e8a}', '\u{e8a}'), ('\u{e8d}', '\u{e8d}'), ('\u{e94}',
-        '\u{e97}'), ('\u{e99}', '\u{e9f}'), ('\u{ea1}', '\u{ea3}'), ('\u{ea5}', '\u{ea5}'),
-        ('\u{ea7}', '\u{ea7}'), ('\u{eaa}', '\u{eab}'), ('\u{ead}', '\u{eae}'), ('\u{eb0}',
-        '\u{eb2}'), ('\u{eb4}', '\u{eb9}'), ('\u{ebb}', '\u{ebd}'), ('\u{ec0}', '\u{ec4}'),
-        ('\u{ec6}', '\u{ec6}'), ('\u{ec8}', '\u{ecd}'), ('\u{ed0}', '\u{ed9}'), ('\u{f0b}',
-        '\u{f0b}'), ('\u{f20}', '\u{f29}'), ('\u{f40}', '\u{f42}'), ('\u{f44}', '\u{f47}'),
-        ('\u{f49}', '\u{f4c}'), ('\u{f4e}', '\u{f51}'), ('\u{f53}', '\u{f56}'), ('\u{f58}',
-        '\u{f5b}'), ('\u{f5d}', '\u{f68}'), ('\u{f71}', '\u{f72}'), ('\u{f74}', '\u{f74}'),
-        ('\u{f7a}', '\u{f80}'), ('\u{f84}', '\u{f84}'), ('\u{f90}', '\u{f92}'), ('\u{f94}',
-        '\u{f95}'), ('\u{f96}', '\u{f96}'), ('\u{f97}', '\u{f97}'), ('\u{f99}', '\u{f9c}'),
-        ('\u{f9e}', '\u{fa1}'), ('\u{fa3}', '\u{fa6}'), ('\u{fa8}', '\u{fab}'), ('\u{fad}',
-        '\u{fad}'), ('\u{fb1}', '\u{fb7}'), ('\u{fb8}', '\u{fb8}'), ('\u{fba}', '\u{fbc}'),
-        ('\u{1000}', '\u{1021}'), ('\u{1022}', '\u{1022}'), ('\u{1023}', '\u{1027}'), ('\u{1028}',
-        '\u{1028}'), ('\u{1029}', '\u{102a}'), ('\u{102b}', '\u{102b}'), ('\u{102c}', '\u{1032}'),
-        ('\u{1033}', '\u{1035}'), ('\u{1036}', '\u{1039}'), ('\u{103a}', '\u{103f}'), ('\u{1040}',
-        '\u{1049}'), ('\u{105a}', '\u{1064}'), ('\u{1075}', '\u{108a}'), ('\u{108f}', '\u{108f}'),
-        ('\u{10c7}', '\u{10c7}'), ('\u{10cd}', '\u{10cd}'), ('\u{10d0}', '\u{10f0}'), ('\u{1200}',
-        '\u{1206}'), ('\u{1208}', '\u{1246}'), ('\u{1247}', '\u{1247}'), ('\u{1248}', '\u{1248}'),
-        ('\u{124a}', '\u{124d}'), ('\u{1250}', '\u{1256}'), ('\u{1258}', '\u{1258}'), ('\u{125a}',
-        '\u{125d}'), ('\u{1260}', '\u{1286}'), ('\u{1288}', '\u{1288}'), ('\u{128a}', '\u{128d}'),
-        ('\u{1290}', '\u{12ae}'), ('\u{12b0}', '\u{12b0}'), ('\u{12b2}', '\u{12b5}'), ('\u{12b8}',
-        '\u{12be}'), ('\u{12c0}', '\u{12c0}'), ('\u{12c2}', '\u{12c5}'), ('\u{12c8}', '\u{12ce}'),
-        ('\u{12cf}', '\u{12cf}'), ('\u{12d0}', '\u{12d6}'), ('\u{12d8}', '\u{12ee}'), ('\u{12ef}',
-        '\u{12ef}'), ('\u{12f0}', '\u{12f7}'), ('\u{1300}', '\u{130e}'), ('\u{1310}', '\u{1310}'),
-        ('\u{1312}', '\u{1315}'), ('\u{1318}', '\u{131e}'), ('\u{1320}', '\u{1346}'), ('\u{1348}',
-        '\u{1359}'), ('\u{1780}', '\u{179c}'), ('\u{179f}', '\u{17a2}'), ('\u{17a5}', '\u{17a7}'),
-        ('\u{17aa}', '\u{17b3}'), ('\u{17b6}', '\u{17cd}'), ('\u{17d0}', '\u{17d0}'), ('\u{17d2}',
-        '\u{17d2}'), ('\u{17e0}', '\u{17e9}'), ('\u{1c90}', '\u{1cba}'), ('\u{1cbd}', '\u{1cbf}'),
-        ('\u{1e0c}', '\u{1e0d}'), ('\u{1e12}', '\u{1e13}'), ('\u{1e20}', '\u{1e21}'), ('\u{1e24}',
-        '\u{1e25}'), ('\u{1e36}', '\u{1e37}'), ('\u{1e3c}', '\u{1e3f}'), ('\u{1e42}', '\u{1e4b}'),
-        ('\u{1e5a}', '\u{1e5b}'), ('\u{1e62}', '\u{1e63}'), ('\u{1e6c}', '\u{1e6d}'), ('\u{1e70}',
-        '\u{1e71}'), ('\u{1e8c}', '\u{1e8d}'), ('\u{1e92}', '\u{1e93}'), ('\u{1e9e}', '\u{1e9e}'),
-        ('\u{1ea0}', '\u{1ef9}'), ('\u{1fa0}', '\u{1faf}'), ('\u{1fb2}', '\u{1fb4}'), ('\u{1fec}',
-        '\u{1fec}'), ('\u{2010}', '\u{2010}'), ('\u{2019}', '\u{2019}'), ('\u{2027}', '\u{2027}'),
-        ('\u{3005}', '\u{3007}'), ('\u{3041}', '\u{3094}'), ('\u{3095}', '\u{3096}'), ('\u{309d}',
-        '\u{309e}'), ('\u{30a0}', '\u{30a0}'), ('\u{30a1}', '\u{30fe}'), ('\u{3447}', '\u{3447}'),
-        ('\u{3473}', '\u{3473}'), ('\u{34e4}', '\u{34e4}'), ('\u{3577}', '\u{3577}'), ('\u{359e}',
-        '\u{359e}'), ('\u{35a1}', '\u{35a1}'), ('\u{35ad}', '\u{35ad}'), ('\u{35bf}', '\u{35bf}'),
-        ('\u{35ce}', '\u{35ce}'), ('\u{35f3}', '\u{35f3}'), ('\u{35fe}', '\u{35fe}'), ('\u{360e}',
-        '\u{360e}'), ('\u{361a}', '\u{361a}'), ('\u{3918}', '\u{3918}'), ('\u{3960}', '\u{3960}'),
-        ('\u{396e}', '\u{396e}'), ('\u{39cf}', '\u{39d0}'), ('\u{39db}', '\u{39db}'), ('\u{39df}',
-        '\u{39df}'), ('\u{39f8}', '\u{39f8}'), ('\u{39fe}', '\u{39fe}'), ('\u{3a18}', '\u{3a18}'),
-        ('\u{3a52}', '\u{3a52}'), ('\u{3a5c}', '\u{3a5c}'), ('\u{3a67}', '\u{3a67}'), ('\u{3a73}',
-        '\u{3a73}'), ('\u{3b39}', '\u{3b39}'), ('\u{3b4e}', '\u{3b4e}'), ('\u{3ba3}', '\u{3ba3}'),
-        ('\u{3c6e}', '\u{3c6e}'), ('\u{3ce0}', '\u{3ce0}'), ('\u{3de7}', '\u{3de7}'), ('\u{3deb}',
-        '\u{3deb}'), ('\u{3e74}', '\u{3e74}'), ('\u{3ed0}', '\u{3ed0}'), ('\u{4056}', '\u{4056}'),
-        ('\u{4065}', '\u{4065}'), ('\u{406a}', '\u{406a}'), ('\u{40bb}', '\u{40bb}'), ('\u{40df}',
-        '\u{40df}'), ('\u{4137}', '\u{4137}'), ('\u{415f}', '\u{415f}'), ('\u{4337}', '\u{4337}'),
-        ('\u{43ac}', '\u{43ac}'), ('\u{43b1}', '\u{43b1}'), ('\u{43d3}', '\u{43d3}'), ('\u{43dd}',
-        '\u{43dd}'), ('\u{4443}', '\u{4443}'), ('\u{44d6}', '\u{44d6}'), ('\u{44ea}', '\u{44ea}'),
-        ('\u{4606}', '\u{4606}'), ('\u{464c}', '\u{464c}'), ('\u{4661}', '\u{4661}'), ('\u{4723}',
-        '\u{4723}'), ('\u{4729}', '\u{4729}'), ('\u{477c}', '\u{477c}'), ('\u{478d}', '\u{478d}'),
-        ('\u{47f4}', '\u{47f4}'), ('\u{4882}', '\u{4882}'), ('\u{4947}', '\u{4947}'), ('\u{497a}',
-        '\u{497a}'), ('\u{497d}', '\u{497d}'), ('\u{4982}', '\u{4983}'), ('\u{4985}', '\u{4986}'),
-        ('\u{499b}', '\u{499b}'), ('\u{499f}', '\u{499f}'), ('\u{49b6}', '\u{49b7}'), ('\u{4a12}',
-        '\u{4a12}'), ('\u{4ab8}', '\u{4ab8}'), ('\u{4c77}', '\u{4c77}'), ('\u{4c7d}', '\u{4c7d}'),
-        ('\u{4c81}', '\u{4c81}'), ('\u{4c85}', '\u{4c85}'), ('\u{4c9d}', '\u{4ca3}'), ('\u{4d13}',
-        '\u{4d19}'), ('\u{4dae}', '\u{4dae}'), ('\u{4e00}', '\u{4e11}'), ('\u{4e13}', '\u{4e28}'),
-        ('\u{4e2a}', '\u{4e67}'), ('\u{4e69}', '\u{4e78}'), ('\u{4e7a}', '\u{4e95}'), ('\u{4e97}',
-        '\u{4ea2}'), ('\u{4ea4}', '\u{4ebb}'), ('\u{4ebd}', '\u{4ecb}'), ('\u{4ecd}', '\u{4ee6}'),
-        ('\u{4ee8}', '\u{4ef7}'), ('\u{4efb}', '\u{4efb}'), ('\u{4efd}', '\u{4efd}'), ('\u{4eff}',
-        '\u{4f06}'), ('\u{4f08}', '\u{4f15}'), ('\u{4f17}', '\u{4f27}'), ('\u{4f29}', '\u{4f30}'),
-        ('\u{4f32}', '\u{4f34}'), ('\u{4f36}', '\u{4f36}'), ('\u{4f38}', '\u{4f3f}'), ('\u{4f41}',
-        '\u{4f43}'), ('\u{4f45}', '\u{4f70}'), ('\u{4f72}', '\u{4f8b}'), ('\u{4f8d}', '\u{4f8d}'),
-        ('\u{4f8f}', '\u{4fa1}'), ('\u{4fa3}', '\u{4fbc}'), ('\u{4fbe}', '\u{4fc5}'), ('\u{4fc7}',
-        '\u{4fc7}'), ('\u{4fc9}', '\u{4fcb}'), ('\u{4fcd}', '\u{4fe1}'), ('\u{4fe3}', '\u{4ffb}'),
-        ('\u{4ffe}', '\u{500f}'), ('\u{5011}', '\u{5033}'), ('\u{5035}', '\u{5037}'), ('\u{5039}',
-        '\u{503c}'), ('\u{503e}', '\u{5041}'), ('\u{5043}', '\u{5051}'), ('\u{5053}', '\u{5057}'),
-        ('\u{5059}', '\u{507b}'), ('\u{507d}', '\u{5080}'), ('\u{5082}', '\u{5092}'), ('\u{5094}',
-        '\u{5096}'), ('\u{5098}', '\u{509e}'), ('\u{50a2}', '\u{50b8}'), ('\u{50ba}', '\u{50c2}'),
-        ('\u{50c4}', '\u{50d7}'), ('\u{50d9}', '\u{50de}'), ('\u{50e0}', '\u{50e0}'), ('\u{50e3}',
-        '\u{50ea}'), ('\u{50ec}', '\u{50f3}'), ('\u{50f5}', '\u{50f6}'), ('\u{50f8}', '\u{511a}'),
-        ('\u{511c}', '\u{5127}'), ('\u{5129}', '\u{512a}'), ('\u{512c}', '\u{5141}'), ('\u{5143}',
-        '\u{5149}'), ('\u{514b}', '\u{514e}'), ('\u{5150}', '\u{5152}'), ('\u{5154}', '\u{5157}'),
-        ('\u{5159}', '\u{515f}'), ('\u{5161}', '\u{5163}'), ('\u{5165}', '\u{5171}'), ('\u{5173}',
-        '\u{517d}'), ('\u{517f}', '\u{5182}'), ('\u{5185}', '\u{518d}'), ('\u{518f}', '\u{51a0}'),
-        ('\u{51a2}', '\u{51a2}'), ('\u{51a4}', '\u{51ac}'), ('\u{51ae}', '\u{51b7}'), ('\u{51b9}',
-        '\u{51b9}'), ('\u{51bb}', '\u{51c1}'), ('\u{51c3}', '\u{51d1}'), ('\u{51d4}', '\u{51de}'),
-        ('\u{51e0}', '\u{51eb}'), ('\u{51ed}', '\u{51ed}'), ('\u{51ef}', '\u{51f1}'), ('\u{51f3}',
-        '\u{5252}'), ('\u{5254}', '\u{5265}'), ('\u{5267}', '\u{5278}'), ('\u{527a}', '\u{5284}'),
-        ('\u{5286}', '\u{528d}'), ('\u{528f}', '\u{52c3}'), ('\u{52c5}', '\u{52c7}'), ('\u{52c9}',
-        '\u{52cb}'), ('\u{52cd}', '\u{52cd}'), ('\u{52cf}', '\u{52d0}'), ('\u{52d2}', '\u{52d3}'),
-        ('\u{52d5}', '\u{52e0}'), ('\u{52e2}', '\u{52e4}'), ('\u{52e6}', '\u{52ed}'), ('\u{52ef}',
-        '\u{5302}'), ('\u{5305}', '\u{5317}'), ('\u{5319}', '\u{531a}'), ('\u{531c}', '\u{531d}'),
-        ('\u{531f}', '\u{5326}'), ('\u{5328}', '\u{5328}'), ('\u{532a}', '\u{5331}'), ('\u{5333}',
-        '\u{5334}'), ('\u{5337}', '\u{5341}'), ('\u{5343}', '\u{535a}'), ('\u{535c}', '\u{535c}'),
-        ('\u{535e}', '\u{5369}'), ('\u{536b}', '\u{536c}'), ('\u{536e}', '\u{537f}'), ('\u{5381}',
-        '\u{53a0}'), ('\u{53a2}', '\u{53a9}'), ('\u{53ac}', '\u{53ae}'), ('\u{53b0}', '\u{53b9}'),
-        ('\u{53bb}', '\u{53c4}'), ('\u{53c6}', '\u{53ce}'), ('\u{53d0}', '\u{53dc}'), ('\u{53df}',
-        '\u{53e6}'), ('\u{53e8}', '\u{53fe}'), ('\u{5401}', '\u{5419}'), ('\u{541b}', '\u{5421}'),
-        ('\u{5423}', '\u{544b}'), ('\u{544d}', '\u{545c}'), ('\u{545e}', '\u{5468}'), ('\u{546a}',
-        '\u{5489}'), ('\u{548b}', '\u{54b4}'), ('\u{54b6}', '\u{54f5}'), ('\u{54f7}', '\u{5514}'),
-        ('\u{5516}', '\u{5517}'), ('\u{551a}', '\u{5546}'), ('\u{5548}', '\u{555f}'), ('\u{5561}',
-        '\u{5579}'), ('\u{557b}', '\u{55df}'), ('\u{55e1}', '\u{55f7}'), ('\u{55f9}', '\u{5609}'),
-        ('\u{560c}', '\u{561f}'), ('\u{5621}', '\u{562a}'), ('\u{562c}', '\u{5636}'), ('\u{5638}',
-        '\u{563b}'), ('\u{563d}', '\u{5643}'), ('\u{5645}', '\u{564a}'), ('\u{564c}', '\u{5650}'),
-        ('\u{5652}', '\u{5655}'), ('\u{5657}', '\u{565e}'), ('\u{5660}', '\u{5660}'), ('\u{5662}',
-        '\u{5674}'), ('\u{5676}', '\u{567c}'), ('\u{567e}', '\u{5687}'), ('\u{5689}', '\u{568a}'),
-        ('\u{568c}', '\u{5695}'), ('\u{5697}', '\u{569d}'), ('\u{569f}', '\u{56b9}'), ('\u{56bb}',
-        '\u{56ce}'), ('\u{56d0}', '\u{56d8}'), ('\u{56da}', '\u{56e5}'), ('\u{56e7}', '\u{56f5}'),
-        ('\u{56f7}', '\u{56f7}'), ('\u{56f9}', '\u{56fa}'), ('\u{56fd}', '\u{5704}'), ('\u{5706}',
-        '\u{5710}'), ('\u{5712}', '\u{5716}'), ('\u{5718}', '\u{5720}'), ('\u{5722}', '\u{5723}'),
-        ('\u{5725}', '\u{573c}'), ('\u{573e}', '\u{5742}'), ('\u{5744}', '\u{5747}'), ('\u{5749}',
-        '\u{5754}'), ('\u{5757}', '\u{5757}'), ('\u{5759}', '\u{5762}'), ('\u{5764}', '\u{5777}'),
-        ('\u{5779}', '\u{5780}'), ('\u{5782}', '\u{5786}'), ('\u{5788}', '\u{5795}'), ('\u{5797}',
-        '\u{57a7}'), ('\u{57a9}', '\u{57c9}'), ('\u{57cb}', '\u{57d0}'), ('\u{57d2}', '\u{57da}'),
-        ('\u{57dc}', '\u{5816}'), ('\u{5819}', '\u{584f}'), ('\u{5851}', '\u{5855}'), ('\u{5857}',
-        '\u{585f}'), ('\u{5861}', '\u{5865}'), ('\u{5868}', '\u{5876}'), ('\u{5878}', '\u{5894}'),
-        ('\u{5896}', '\u{58a9}'), ('\u{58ab}', '\u{58b5}'), ('\u{58b7}', '\u{58bf}'), ('\u{58c1}',
-        '\u{58c2}'), ('\u{58c5}', '\u{58cc}'), ('\u{58ce}', '\u{58cf}'), ('\u{58d1}', '\u{58e0}'),
-        ('\u{58e2}', '\u{58e5}'), ('\u{58e7}', '\u{58f4}'), ('\u{58f6}', '\u{5900}'), ('\u{5902}',
-        '\u{5904}'), ('\u{5906}', '\u{5907}'), ('\u{5909}', '\u{5910}'), ('\u{5912}', '\u{5912}'),
-        ('\u{5914}', '\u{5922}'), ('\u{5924}', '\u{5932}'), ('\u{5934}', '\u{5935}'), ('\u{5937}',
-        '\u{5958}'), ('\u{595a}', '\u{595a}'), ('\u{595c}', '\u{59b6}'), ('\u{59b8}', '\u{59e6}'),
-        ('\u{59e8}', '\u{5a23}'), ('\u{5a25}', '\u{5a25}'), ('\u{5a27}', '\u{5a2b}'), ('\u{5a2d}',
-        '\u{5a2f}'), ('\u{5a31}', '\u{5a53}'), ('\u{5a55}', '\u{5a58}'), ('\u{5a5a}', '\u{5a6e}'),
-        ('\u{5a70}', '\u{5a70}'), ('\u{5a72}', '\u{5a86}'), ('\u{5a88}', '\u{5a8c}'), ('\u{5a8e}',
-        '\u{5aaa}'), ('\u{5aac}', '\u{5ad2}'), ('\u{5ad4}', '\u{5aee}'), ('\u{5af1}', '\u{5b09}'),
-        ('\u{5b0b}', '\u{5b0c}'), ('\u{5b0e}', '\u{5b38}'), ('\u{5b3a}', '\u{5b45}'), ('\u{5b47}',
-        '\u{5b4e}'), ('\u{5b50}', '\u{5b51}'), ('\u{5b53}', '\u{5b5f}'), ('\u{5b62}', '\u{5b6e}'),
-        ('\u{5b70}', '\u{5b78}'), ('\u{5b7a}', '\u{5b7d}'), ('\u{5b7f}', '\u{5b85}'), ('\u{5b87}',
-        '\u{5b8f}'), ('\u{5b91}', '\u{5ba8}'), ('\u{5baa}', '\u{5bb1}'), ('\u{5bb3}', '\u{5bb6}'),
-        ('\u{5bb8}', '\u{5bbb}'), ('\u{5bbd}', '\u{5bc7}'), ('\u{5bc9}', '\u{5bd9}'), ('\u{5bdb}',
-        '\u{5bff}'), ('\u{5c01}', '\u{5c1a}'), ('\u{5c1c}', '\u{5c22}'), ('\u{5c24}', '\u{5c25}'),
-        ('\u{5c27}', '\u{5c28}'), ('\u{5c2a}', '\u{5c35}'), ('\u{5c37}', '\u{5c59}'), ('\u{5c5b}',
-        '\u{5c84}'), ('\u{5c86}', '\u{5cb3}'), ('\u{5cb5}', '\u{5cb8}'), ('\u{5cba}', '\u{5cd4}'),
-        ('\u{5cd6}', '\u{5cdc}'), ('\u{5cde}', '\u{5cf4}'), ('\u{5cf6}', '\u{5d2a}'), ('\u{5d2c}',
-        '\u{5d2e}'), ('\u{5d30}', '\u{5d3a}'), ('\u{5d3c}', '\u{5d52}'), ('\u{5d54}', '\u{5d56}'),
-        ('\u{5d58}', '\u{5d5f}'), ('\u{5d61}', '\u{5d82}'), ('\u{5d84}', '\u{5d95}'), ('\u{5d97}',
-        '\u{5da2}'), ('\u{5da5}', '\u{5daa}'), ('\u{5dac}', '\u{5db2}'), ('\u{5db4}', '\u{5db8}'),
-        ('\u{5dba}', '\u{5dc3}'), ('\u{5dc5}', '\u{5dd6}'), ('\u{5dd8}', '\u{5dd9}'), ('\u{5ddb}',
-        '\u{5ddb}'), ('\u{5ddd}', '\u{5df5}'), ('\u{5df7}', '\u{5e11}'), ('\u{5e13}', '\u{5e47}'),
-        ('\u{5e49}', '\u{5e50}'), ('\u{5e52}', '\u{5e91}'), ('\u{5e93}', '\u{5eb9}'), ('\u{5ebb}',
-        '\u{5ebf}'), ('\u{5ec1}', '\u{5eea}'), ('\u{5eec}', '\u{5ef8}'), ('\u{5efa}', '\u{5f0d}'),
-        ('\u{5f0f}', '\u{5f3a}'), ('\u{5f3c}', '\u{5f3c}'), ('\u{5f3e}', '\u{5f8e}'), ('\u{5f90}',
-        '\u{5f99}'), ('\u{5f9b}', '\u{5fa2}'), ('\u{5fa5}', '\u{5faf}'), ('\u{5fb1}', '\u{5fc1}'),
-        ('\u{5fc3}', '\u{5fcd}'), ('\u{5fcf}', '\u{5fda}'), ('\u{5fdc}', '\u{5fe1}'), ('\u{5fe3}',
-        '\u{5feb}'), ('\u{5fed}', '\u{5ffb}'), ('\u{5ffd}', '\u{6022}'), ('\u{6024}', '\u{6055}'),
-        ('\u{6057}', '\u{6060}'), ('\u{6062}', '\u{6070}'), ('\u{6072}', '\u{6073}'), ('\u{6075}',
-        '\u{6090}'), ('\u{6092}', '\u{6092}'), ('\u{6094}', '\u{60a4}'), ('\u{60a6}', '\u{60d1}'),
-        ('\u{60d3}', '\u{60d5}'), ('\u{60d7}', '\u{60dd}'), ('\u{60df}', '\u{60e4}'), ('\u{60e6}',
-        '\u{60fc}'), ('\u{60fe}', '\u{6101}'), ('\u{6103}', '\u{6106}'), ('\u{6108}', '\u{6110}'),
-        ('\u{6112}', '\u{611d}'), ('\u{611f}', '\u{6130}'), ('\u{6132}', '\u{6132}'), ('\u{6134}',
-        '\u{6134}'), ('\u{6136}', '\u{6137}'), ('\u{613a}', '\u{615f}'), ('\u{6161}', '\u{617a}'),
-        ('\u{617c}', '\u{617e}'), ('\u{6180}', '\u{6185}'), ('\u{6187}', '\u{6196}'), ('\u{6198}',
-        '\u{619b}'), ('\u{619d}', '\u{61b8}'), ('\u{61ba}', '\u{61ba}'), ('\u{61bc}', '\u{61d2}'),
-        ('\u{61d4}', '\u{61d4}'), ('\u{61d6}', '\u{61eb}'), ('\u{61ed}', '\u{61ee}'), ('\u{61f0}',
-        '\u{6204}'), ('\u{6206}', '\u{6234}'), ('\u{6236}', '\u{6238}'), ('\u{623a}', '\u{6256}'),
-        ('\u{6258}', '\u{628c}'), ('\u{628e}', '\u{629c}'), ('\u{629e}', '\u{62dd}'), ('\u{62df}',
-        '\u{62e9}'), ('\u{62eb}', '\u{6309}'), ('\u{630b}', '\u{6316}'), ('\u{6318}', '\u{6330}'),
-        ('\u{6332}', '\u{6336}'), ('\u{6338}', '\u{635a}'), ('\u{635c}', '\u{638a}'), ('\u{638c}',
-        '\u{6392}'), ('\u{6394}', '\u{63d0}'), ('\u{63d2}', '\u{643a}'), ('\u{643d}', '\u{6448}'),
-        ('\u{644a}', '\u{6459}'), ('\u{645b}', '\u{647d}'), ('\u{647f}', '\u{6485}'), ('\u{6487}',
-        '\u{64a0}'), ('\u{64a2}', '\u{64ae}'), ('\u{64b0}', '\u{64b5}'), ('\u{64b7}', '\u{64c7}'),
-        ('\u{64c9}', '\u{64d4}'), ('\u{64d6}', '\u{64ed}'), ('\u{64ef}', '\u{64f4}'), ('\u{64f6}',
-        '\u{64f8}'), ('\u{64fa}', '\u{6501}'), ('\u{6503}', '\u{6509}'), ('\u{650b}', '\u{651e}'),
-        ('\u{6520}', '\u{6527}'), ('\u{6529}', '\u{653f}'), ('\u{6541}', '\u{6541}'), ('\u{6543}',
-        '\u{6559}'), ('\u{655b}', '\u{655e}'), ('\u{6560}', '\u{657c}'), ('\u{657e}', '\u{6589}'),
-        ('\u{658b}', '\u{6599}'), ('\u{659b}', '\u{65b4}'), ('\u{65b6}', '\u{65bd}'), ('\u{65bf}',
-        '\u{65c7}'), ('\u{65ca}', '\u{65d0}'), ('\u{65d2}', '\u{65d7}'), ('\u{65d9}', '\u{65db}'),
-        ('\u{65dd}', '\u{65e3}'), ('\u{65e5}', '\u{65e9}'), ('\u{65eb}', '\u{65f8}'), ('\u{65fa}',
-        '\u{65fd}'), ('\u{65ff}', '\u{6616}'), ('\u{6618}', '\u{662b}'), ('\u{662d}', '\u{6636}'),
-        ('\u{6639}', '\u{6647}'), ('\u{6649}', '\u{664c}'), ('\u{664e}', '\u{665f}'), ('\u{6661}',
-        '\u{6662}'), ('\u{6664}', '\u{6691}'), ('\u{6693}', '\u{669b}'), ('\u{669d}', '\u{669d}'),
-        ('\u{669f}', '\u{66ab}'), ('\u{66ae}', '\u{66cf}'), ('\u{66d1}', '\u{66d2}'), ('\u{66d4}',
-        '\u{66d6}'), ('\u{66d8}', '\u{66de}'), ('\u{66e0}', '\u{66ee}'), ('\u{66f0}', '\u{6701}'),
-        ('\u{6703}', '\u{6706}'), ('\u{6708}', '\u{6718}'), ('\u{671a}', '\u{6723}'), ('\u{6725}',
-        '\u{6728}'), ('\u{672a}', '\u{6766}'), ('\u{6768}', '\u{6787}'), ('\u{6789}', '\u{6795}'),
-        ('\u{6797}', '\u{67bc}'), ('\u{67be}', '\u{67be}'), ('\u{67c0}', '\u{67d4}'), ('\u{67d6}',
-        '\u{67d6}'), ('\u{67d8}', '\u{67f8}'), ('\u{67fa}', '\u{6800}'), ('\u{6802}', '\u{6814}'),
-        ('\u{6816}', '\u{6826}'), ('\u{6828}', '\u{682f}'), ('\u{6831}', '\u{6857}'), ('\u{6859}',
-        '\u{6859}'), ('\u{685b}', '\u{685d}'), ('\u{685f}', '\u{6879}'), ('\u{687b}', '\u{6894}'),
-        ('\u{6896}', '\u{6898}'), ('\u{689a}', '\u{68a4}'), ('\u{68a6}', '\u{68b7}'), ('\u{68b9}',
-        '\u{68c2}'), ('\u{68c4}', '\u{68d8}'), ('\u{68da}', '\u{68e1}'), ('\u{68e3}', '\u{68e4}'),
-        ('\u{68e6}', '\u{6908}'), ('\u{690a}', '\u{693d}'), ('\u{693f}', '\u{694c}'), ('\u{694e}',
-        '\u{699e}'), ('\u{69a0}', '\u{69a1}'), ('\u{69a3}', '\u{69bf}'), ('\u{69c1}', '\u{69d0}'),
-        ('\u{69d3}', '\u{69d4}'), ('\u{69d8}', '\u{6a02}'), ('\u{6a04}', '\u{6a1b}'), ('\u{6a1d}',
-        '\u{6a23}'), ('\u{6a25}', '\u{6a36}'), ('\u{6a38}', '\u{6a49}'), ('\u{6a4b}', '\u{6a5b}'),
-        ('\u{6a5d}', '\u{6a6d}'), ('\u{6a6f}', '\u{6a6f}'), ('\u{6a71}', '\u{6a85}'), ('\u{6a87}',
-        '\u{6a89}'), ('\u{6a8b}', '\u{6a8e}'), ('\u{6a90}', '\u{6a98}'), ('\u{6a9a}', '\u{6a9c}'),
-        ('\u{6a9e}', '\u{6ab0}'), ('\u{6ab2}', '\u{6abd}'), ('\u{6abf}', '\u{6abf}'), ('\u{6ac1}',
-        '\u{6ac3}'), ('\u{6ac5}', '\u{6ac8}'), ('\u{6aca}', '\u{6ad7}'), ('\u{6ad9}', '\u{6ae8}'),
-        ('\u{6aea}', '\u{6b0d}'), ('\u{6b0f}', '\u{6b1a}'), ('\u{6b1c}', '\u{6b2d}'), ('\u{6b2f}',
-        '\u{6b34}'), ('\u{6b36}', '\u{6b3f}'), ('\u{6b41}', '\u{6b56}'), ('\u{6b59}', '\u{6b5c}'),
-        ('\u{6b5e}', '\u{6b67}'), ('\u{6b69}', '\u{6b6b}'), ('\u{6b6d}', '\u{6b6d}'), ('\u{6b6f}',
-        '\u{6b70}'), ('\u{6b72}', '\u{6b74}'), ('\u{6b76}', '\u{6b7c}'), ('\u{6b7e}', '\u{6bb7}'),
-        ('\u{6bb9}', '\u{6be8}'), ('\u{6bea}', '\u{6bf0}'), ('\u{6bf2}', '\u{6bf3}'), ('\u{6bf5}',
-        '\u{6bf9}'), ('\u{6bfb}', '\u{6c09}'), ('\u{6c0b}', '\u{6c1b}'), ('\u{6c1d}', '\u{6c2c}'),
-        ('\u{6c2e}', '\u{6c3b}'), ('\u{6c3d}', '\u{6c44}'), ('\u{6c46}', '\u{6c6b}'), ('\u{6c6d}',
-        '\u{6c6d}'), ('\u{6c6f}', '\u{6c9f}'), ('\u{6ca1}', '\u{6cd7}'), ('\u{6cd9}', '\u{6cf3}'),
-        ('\u{6cf5}', '\u{6d01}'), ('\u{6d03}', '\u{6d1b}'), ('\u{6d1d}', '\u{6d23}'), ('\u{6d25}',
-        '\u{6d70}'), ('\u{6d72}', '\u{6d80}'), ('\u{6d82}', '\u{6d95}'), ('\u{6d97}', '\u{6daf}'),
-        ('\u{6db2}', '\u{6db5}'), ('\u{6db7}', '\u{6dfd}'), ('\u{6e00}', '\u{6e00}'), ('\u{6e03}',
-        '\u{6e05}'), ('\u{6e07}', '\u{6e11}'), ('\u{6e13}', '\u{6e17}'), ('\u{6e19}', '\u{6e29}'),
-        ('\u{6e2b}', '\u{6e4b}'), ('\u{6e4d}', '\u{6e6b}'), ('\u{6e6d}', '\u{6e7a}'), ('\u{6e7e}',
-        '\u{6e8a}'), ('\u{6e8c}', '\u{6e94}'), ('\u{6e96}', '\u{6eda}'), ('\u{6edc}', '\u{6ee2}'),
-        ('\u{6ee4}', '\u{6f03}'), ('\u{6f05}', '\u{6f0a}'), ('\u{6f0c}', '\u{6f41}'), ('\u{6f43}',
-        '\u{6f47}'), ('\u{6f49}', '\u{6f49}'), ('\u{6f4b}', '\u{6f78}'), ('\u{6f7a}', '\u{6f97}'),
-        ('\u{6f99}', '\u{6f99}'), ('\u{6f9b}', '\u{6f9e}'), ('\u{6fa0}', '\u{6fb6}'), ('\u{6fb8}',
-        '\u{6fc4}'), ('\u{6fc6}', '\u{6fcf}'), ('\u{6fd1}', '\u{6fd2}'), ('\u{6fd4}', '\u{6ff4}'),
-        ('\u{6ff6}', '\u{6ffc}'), ('\u{6ffe}', '\u{700f}'), ('\u{7011}', '\u{7012}'), ('\u{7014}',
-        '\u{7046}'), ('\u{7048}', '\u{704a}'), ('\u{704c}', '\u{704d}'), ('\u{704f}', '\u{7071}'),
-        ('\u{7074}', '\u{707a}'), ('\u{707c}', '\u{7080}'), ('\u{7082}', '\u{708c}'), ('\u{708e}',
-        '\u{7096}'), ('\u{7098}', '\u{709a}'), ('\u{709c}', '\u{70a9}'), ('\u{70ab}', '\u{70b1}'),
-        ('\u{70b3}', '\u{70b5}'), ('\u{70b7}', '\u{70d4}'), ('\u{70d6}', '\u{70fd}'), ('\u{70ff}',
-        '\u{7107}'), ('\u{7109}', '\u{7123}'), ('\u{7125}', '\u{7132}'), ('\u{7135}', '\u{7156}'),
-        ('\u{7158}', '\u{716a}'), ('\u{716c}', '\u{716c}'), ('\u{716e}', '\u{718c}'), ('\u{718e}',
-        '\u{7195}'), ('\u{7197}', '\u{71a5}'), ('\u{71a7}', '\u{71aa}'), ('\u{71ac}', '\u{71b5}'),
-        ('\u{71b7}', '\u{71cb}'), ('\u{71cd}', '\u{71d2}'), ('\u{71d4}', '\u{71f2}'), ('\u{71f4}',
-        '\u{71f9}'), ('\u{71fb}', '\u{720a}'), ('\u{720c}', '\u{7210}'), ('\u{7212}', '\u{7214}'),
-        ('\u{7216}', '\u{7216}'), ('\u{7218}', '\u{721f}'), ('\u{7221}', '\u{7223}'), ('\u{7226}',
-        '\u{722e}'), ('\u{7230}', '\u{7233}'), ('\u{7235}', '\u{7244}'), ('\u{7246}', '\u{724d}'),
-        ('\u{724f}', '\u{724f}'), ('\u{7251}', '\u{7254}'), ('\u{7256}', '\u{72aa}'), ('\u{72ac}',
-        '\u{72bd}'), ('\u{72bf}', '\u{7301}'), ('\u{7303}', '\u{730f}'), ('\u{7311}', '\u{7327}'),
-        ('\u{7329}', '\u{7352}'), ('\u{7354}', '\u{739b}'), ('\u{739d}', '\u{73c0}'), ('\u{73c2}',
-        '\u{73f2}'), ('\u{73f4}', '\u{73fa}'), ('\u{73fc}', '\u{7417}'), ('\u{7419}', '\u{7438}'),
-        ('\u{743a}', '\u{743d}'), ('\u{743f}', '\u{7446}'), ('\u{7448}', '\u{7448}'), ('\u{744a}',
-        '\u{7457}'), ('\u{7459}', '\u{747a}'), ('\u{747c}', '\u{7483}'), ('\u{7485}', '\u{7495}'),
-        ('\u{7497}', '\u{749c}'), ('\u{749e}', '\u{74c6}'), ('\u{74c8}', '\u{74c8}'), ('\u{74ca}',
-        '\u{74cb}'), ('\u{74cd}', '\u{74ea}'), ('\u{74ec}', '\u{751f}'), ('\u{7521}', '\u{7540}'),
-        ('\u{7542}', '\u{7551}'), ('\u{7553}', '\u{7554}'), ('\u{7556}', '\u{755d}'), ('\u{755f}',
-        '\u{7560}'), ('\u{7562}', '\u{7570}'), ('\u{7572}', '\u{757a}'), ('\u{757c}', '\u{7584}'),
-        ('\u{7586}', '\u{75a8}'), ('\u{75aa}', '\u{75b6}'), ('\u{75b8}', '\u{75db}'), ('\u{75dd}',
-        '\u{75ed}'), ('\u{75ef}', '\u{762b}'), ('\u{762d}', '\u{7643}'), ('\u{7646}', '\u{7650}'),
-        ('\u{7652}', '\u{7654}'), ('\u{7656}', '\u{7672}'), ('\u{7674}', '\u{768c}'), ('\u{768e}',
-        '\u{76a0}'), ('\u{76a3}', '\u{76a4}'), ('\u{76a6}', '\u{76a7}'), ('\u{76a9}', '\u{76b2}'),
-        ('\u{76b4}', '\u{76b5}'), ('\u{76b7}', '\u{76c0}'), ('\u{76c2}', '\u{76ca}'), ('\u{76cc}',
-        '\u{76d8}'), ('\u{76da}', '\u{76ea}'), ('\u{76ec}', '\u{76ff}'), ('\u{7701}', '\u{7701}'),
-        ('\u{7703}', '\u{770d}'), ('\u{770f}', '\u{7720}'), ('\u{7722}', '\u{772a}'), ('\u{772c}',
-        '\u{773e}'), ('\u{7740}', '\u{7741}'), ('\u{7743}', '\u{7763}'), ('\u{7765}', '\u{7795}'),
-        ('\u{7797}', '\u{77a3}'), ('\u{77a5}', '\u{77bd}'), ('\u{77bf}', '\u{77c0}'), ('\u{77c2}',
-        '\u{77d1}'), ('\u{77d3}', '\u{77dc}'), ('\u{77de}', '\u{77e3}'), ('\u{77e5}', '\u{77e5}'),
-        ('\u{77e7}', '\u{77f3}'), ('\u{77f6}', '\u{7823}'), ('\u{7825}', '\u{7835}'), ('\u{7837}',
-        '\u{7841}'), ('\u{7843}', '\u{7845}'), ('\u{7847}', '\u{784a}'), ('\u{784c}', '\u{7875}'),
-        ('\u{7877}', '\u{7887}'), ('\u{7889}', '\u{78c1}'), ('\u{78c3}', '\u{78c6}'), ('\u{78c8}',
-        '\u{78d1}'), ('\u{78d3}', '\u{78ef}'), ('\u{78f1}', '\u{78f7}'), ('\u{78f9}', '\u{78ff}'),
-        ('\u{7901}', '\u{7907}'), ('\u{7909}', '\u{790c}'), ('\u{790e}', '\u{7914}'), ('\u{7916}',
-        '\u{791e}'), ('\u{7921}', '\u{7931}'), ('\u{7933}', '\u{7935}'), ('\u{7937}', '\u{7958}'),
-        ('\u{795a}', '\u{796b}'), ('\u{796d}', '\u{796d}'), ('\u{796f}', '\u{7974}'), ('\u{7977}',
-        '\u{7985}'), ('\u{7988}', '\u{799d}'), ('\u{799f}', '\u{79a8}'), ('\u{79aa}', '\u{79bb}'),
-        ('\u{79bd}', '\u{79c3}'), ('\u{79c5}', '\u{79c6}'), ('\u{79c8}', '\u{79cb}'), ('\u{79cd}',
-        '\u{79d3}'), ('\u{79d5}', '\u{79d6}'), ('\u{79d8}', '\u{7a00}'), ('\u{7a02}', '\u{7a06}'),
-        ('\u{7a08}', '\u{7a08}'), ('\u{7a0a}', '\u{7a2b}'), ('\u{7a2d}', '\u{7a37}'), ('\u{7a39}',
-        '\u{7a39}'), ('\u{7a3b}', '\u{7a63}'), ('\u{7a65}', '\u{7a69}'), ('\u{7a6b}', '\u{7a6e}'),
-        ('\u{7a70}', '\u{7a81}'), ('\u{7a83}', '\u{7a99}'), ('\u{7a9c}', '\u{7ab8}'), ('\u{7aba}',
-        '\u{7aba}'), ('\u{7abe}', '\u{7ac1}'), ('\u{7ac3}', '\u{7ac5}'), ('\u{7ac7}', '\u{7ae8}'),
-        ('\u{7aea}', '\u{7af4}'), ('\u{7af6}', '\u{7afb}'), ('\u{7afd}', '\u{7b06}'), ('\u{7b08}',
-        '\u{7b1e}'), ('\u{7b20}', '\u{7b26}'), ('\u{7b28}', '\u{7b28}'), ('\u{7b2a}', '\u{7b41}'),
-        ('\u{7b43}', '\u{7b52}'), ('\u{7b54}', '\u{7ba2}'), ('\u{7ba4}', '\u{7ba4}'), ('\u{7ba6}',
-        '\u{7baf}'), ('\u{7bb1}', '\u{7bb1}'), ('\u{7bb3}', '\u{7bf9}'), ('\u{7bfb}', '\u{7c1a}'),
-        ('\u{7c1c}', '\u{7c2d}'), ('\u{7c30}', '\u{7c51}'), ('\u{7c53}', '\u{7c54}'), ('\u{7c56}',
-        '\u{7c5c}'), ('\u{7c5e}', '\u{7c75}'), ('\u{7c77}', '\u{7c86}'), ('\u{7c88}', '\u{7c92}'),
-        ('\u{7c94}', '\u{7c99}'), ('\u{7c9b}', '\u{7cab}'), ('\u{7cad}', '\u{7cd2}'), ('\u{7cd4}',
-        '\u{7cd9}'), ('\u{7cdc}', '\u{7ce0}'), ('\u{7ce2}', '\u{7ce2}'), ('\u{7ce4}', '\u{7ce4}'),
-        ('\u{7ce7}', '\u{7cfb}'), ('\u{7cfd}', '\u{7cfe}'), ('\u{7d00}', '\u{7d22}'), ('\u{7d24}',
-        '\u{7d29}'), ('\u{7d2b}', '\u{7d2c}'), ('\u{7d2e}', '\u{7d47}'), ('\u{7d49}', '\u{7d4c}'),
-        ('\u{7d4e}', '\u{7d59}'), ('\u{7d5b}', '\u{7d63}'), ('\u{7d65}', '\u{7d77}'), ('\u{7d79}',
-        '\u{7d81}'), ('\u{7d83}', '\u{7d94}'), ('\u{7d96}', '\u{7d97}'), ('\u{7d99}', '\u{7da3}'),
-        ('\u{7da5}', '\u{7da7}'), ('\u{7da9}', '\u{7dcc}'), ('\u{7dce}', '\u{7dd2}'), ('\u{7dd4}',
-        '\u{7de4}'), ('\u{7de6}', '\u{7dea}'), ('\u{7dec}', '\u{7dfc}'), ('\u{7e00}', '\u{7e17}'),
-        ('\u{7e19}', '\u{7e5a}'), ('\u{7e5c}', '\u{7e63}'), ('\u{7e65}', '\u{7e9c}'), ('\u{7e9e}',
-        '\u{7f3a}'), ('\u{7f3d}', '\u{7f40}'), ('\u{7f42}', '\u{7f45}'), ('\u{7f47}', '\u{7f58}'),
-        ('\u{7f5a}', '\u{7f83}'), ('\u{7f85}', '\u{7f8f}'), ('\u{7f91}', '\u{7f96}'), ('\u{7f98}',
-        '\u{7f98}'), ('\u{7f9a}', '\u{7fb3}'), ('\u{7fb5}', '\u{7fd5}'), ('\u{7fd7}', '\u{7fdc}'),
-        ('\u{7fde}', '\u{7fe3}'), ('\u{7fe5}', '\u{8009}'), ('\u{800b}', '\u{802e}'), ('\u{8030}',
-        '\u{803b}'), ('\u{803d}', '\u{803f}'), ('\u{8041}', '\u{8065}'), ('\u{8067}', '\u{8087}'),
-        ('\u{8089}', '\u{808d}'), ('\u{808f}', '\u{8093}'), ('\u{8095}', '\u{80a5}'), ('\u{80a9}',
-        '\u{80b2}'), ('\u{80b4}', '\u{80b8}'), ('\u{80ba}', '\u{80de}'), ('\u{80e0}', '\u{8102}'),
-        ('\u{8105}', '\u{8133}'), ('\u{8136}', '\u{8183}'), ('\u{8185}', '\u{818f}'), ('\u{8191}',
-        '\u{8195}'), ('\u{8197}', '\u{81ca}'), ('\u{81cc}', '\u{81e3}'), ('\u{81e5}', '\u{81ee}'),
-        ('\u{81f1}', '\u{8212}'), ('\u{8214}', '\u{8223}'), ('\u{8225}', '\u{8240}'), ('\u{8242}',
-        '\u{8264}'), ('\u{8266}', '\u{828b}'), ('\u{828d}', '\u{82b1}'), ('\u{82b3}', '\u{82e1}'),
-        ('\u{82e3}', '\u{82fb}'), ('\u{82fd}', '\u{8309}'), ('\u{830b}', '\u{830f}'), ('\u{8311}',
-        '\u{832f}'), ('\u{8331}', '\u{8354}'), ('\u{8356}', '\u{83bd}'), ('\u{83bf}', '\u{83e5}'),
-        ('\u{83e7}', '\u{83ec}'), ('\u{83ee}', '\u{8413}'), ('\u{8415}', '\u{8415}'), ('\u{8418}',
-        '\u{841e}'), ('\u{8420}', '\u{8457}'), ('\u{8459}', '\u{8482}'), ('\u{8484}', '\u{8494}'),
-        ('\u{8496}', '\u{84b6}'), ('\u{84b8}', '\u{84c2}'), ('\u{84c4}', '\u{84ec}'), ('\u{84ee}',
-        '\u{8504}'), ('\u{8506}', '\u{850f}'), ('\u{8511}', '\u{8531}'), ('\u{8534}', '\u{854b}'),
-        ('\u{854d}', '\u{854f}'), ('\u{8551}', '\u{857e}'), ('\u{8580}', '\u{8592}'), ('\u{8594}',
-        '\u{85b1}'), ('\u{85b3}', '\u{85ba}'), ('\u{85bc}', '\u{85cb}'), ('\u{85cd}', '\u{85ed}'),
-        ('\u{85ef}', '\u{85f2}'), ('\u{85f4}', '\u{85fb}'), ('\u{85fd}', '\u{8602}'), ('\u{8604}',
-        '\u{860c}'), ('\u{860f}', '\u{860f}'), ('\u{8611}', '\u{8614}'), ('\u{8616}', '\u{861c}'),
-        ('\u{861e}', '\u{8636}'), ('\u{8638}', '\u{8656}'), ('\u{8658}', '\u{8674}'), ('\u{8676}',
-        '\u{8688}'), ('\u{868a}', '\u{8691}'), ('\u{8693}', '\u{869f}'), ('\u{86a1}', '\u{86a5}'),
-        ('\u{86a7}', '\u{86d4}'), ('\u{86d6}', '\u{86df}'), ('\u{86e1}', '\u{86e6}'), ('\u{86e8}',
-        '\u{86fc}'), ('\u{86fe}', '\u{871c}'), ('\u{871e}', '\u{872e}'), ('\u{8730}', '\u{873c}'),
-        ('\u{873e}', '\u{8744}'), ('\u{8746}', '\u{8770}'), ('\u{8772}', '\u{878d}'), ('\u{878f}',
-        '\u{8798}'), ('\u{879a}', '\u{87d9}'), ('\u{87db}', '\u{87ef}'), ('\u{87f1}', '\u{8806}'),
-        ('\u{8808}', '\u{8811}'), ('\u{8813}', '\u{882c}'), ('\u{882e}', '\u{8839}'), ('\u{883b}',
-        '\u{8846}'), ('\u{8848}', '\u{8857}'), ('\u{8859}', '\u{885b}'), ('\u{885d}', '\u{885e}'),
-        ('\u{8860}', '\u{8879}'), ('\u{887b}', '\u{88e5}'), ('\u{88e7}', '\u{88e8}'), ('\u{88ea}',
-        '\u{88ec}'), ('\u{88ee}', '\u{8902}'), ('\u{8904}', '\u{890e}'), ('\u{8910}', '\u{8923}'),
-        ('\u{8925}', '\u{8964}'), ('\u{8966}', '\u{8974}'), ('\u{8976}', '\u{897c}'), ('\u{897e}',
-        '\u{898c}'), ('\u{898e}', '\u{898f}'), ('\u{8991}', '\u{8993}'), ('\u{8995}', '\u{8998}'),
-        ('\u{899a}', '\u{89af}'), ('\u{89b1}', '\u{89b3}'), ('\u{89b5}', '\u{89ba}'), ('\u{89bd}',
-        '\u{89ed}'), ('\u{89ef}', '\u{89f4}'), ('\u{89f6}', '\u{89f8}'), ('\u{89fa}', '\u{89fc}'),
-        ('\u{89fe}', '\u{8a04}'), ('\u{8a07}', '\u{8a13}'), ('\u{8a15}', '\u{8a18}'), ('\u{8a1a}',
-        '\u{8a1f}'), ('\u{8a22}', '\u{8a2a}'), ('\u{8a2c}', '\u{8a3c}'), ('\u{8a3e}', '\u{8a4a}'),
-        ('\u{8a4c}', '\u{8a63}'), ('\u{8a65}', '\u{8a77}'), ('\u{8a79}', '\u{8a7c}'), ('\u{8a7e}',
-        '\u{8a87}'), ('\u{8a89}', '\u{8a9e}'), ('\u{8aa0}', '\u{8aae}'), ('\u{8ab0}', '\u{8ab6}'),
-        ('\u{8ab8}', '\u{8acf}'), ('\u{8ad1}', '\u{8aeb}'), ('\u{8aed}', '\u{8b28}'), ('\u{8b2a}',
-        '\u{8b31}'), ('\u{8b33}', '\u{8b37}'), ('\u{8b39}', '\u{8b3e}'), ('\u{8b40}', '\u{8b60}'),
-        ('\u{8b63}', '\u{8b68}'), ('\u{8b6a}', '\u{8b74}'), ('\u{8b76}', '\u{8b7b}'), ('\u{8b7d}',
-        '\u{8b80}'), ('\u{8b82}', '\u{8b86}'), ('\u{8b88}', '\u{8b8c}'), ('\u{8b8e}', '\u{8b8e}'),
-        ('\u{8b90}', '\u{8b9a}'), ('\u{8b9c}', '\u{8c37}'), ('\u{8c39}', '\u{8c3f}'), ('\u{8c41}',
-        '\u{8c43}'), ('\u{8c45}', '\u{8c50}'), ('\u{8c54}', '\u{8c57}'), ('\u{8c59}', '\u{8c73}'),
-        ('\u{8c75}', '\u{8c7e}'), ('\u{8c80}', '\u{8c82}'), ('\u{8c84}', '\u{8c86}'), ('\u{8c88}',
-        '\u{8c8a}'), ('\u{8c8c}', '\u{8c9a}'), ('\u{8c9c}', '\u{8ca5}'), ('\u{8ca7}', '\u{8cca}'),
-        ('\u{8ccc}', '\u{8cd5}'), ('\u{8cd7}', '\u{8cd7}'), ('\u{8cd9}', '\u{8ce8}'), ('\u{8cea}',
-        '\u{8cf6}'), ('\u{8cf8}', '\u{8d00}'), ('\u{8d02}', '\u{8d10}'), ('\u{8d13}', '\u{8d7b}'),
-        ('\u{8d7d}', '\u{8da5}'), ('\u{8da7}', '\u{8dbf}'), ('\u{8dc1}', '\u{8de4}'), ('\u{8de6}',
-        '\u{8e00}'), ('\u{8e02}', '\u{8e0a}'), ('\u{8e0c}', '\u{8e31}'), ('\u{8e33}', '\u{8e45}'),
-        ('\u{8e47}', '\u{8e4e}'), ('\u{8e50}', '\u{8e6d}'), ('\u{8e6f}', '\u{8e74}'), ('\u{8e76}',
-        '\u{8e76}'), ('\u{8e78}', '\u{8e78}'), ('\u{8e7a}', '\u{8e9a}'), ('\u{8e9c}', '\u{8ea1}'),
-        ('\u{8ea3}', '\u{8eb2}'), ('\u{8eb4}', '\u{8eb5}'), ('\u{8eb8}', '\u{8ec0}'), ('\u{8ec2}',
-        '\u{8ec3}'), ('\u{8ec5}', '\u{8ed8}'), ('\u{8eda}', '\u{8eef}'), ('\u{8ef1}', '\u{8f0e}'),
-        ('\u{8f10}', '\u{8f2c}'), ('\u{8f2e}', '\u{8f39}'), ('\u{8f3b}', '\u{8f40}'), ('\u{8f42}',
-        '\u{8f9c}'), ('\u{8f9e}', '\u{8fa3}'), ('\u{8fa5}', '\u{8fb2}'), ('\u{8fb4}', '\u{8fc2}'),
-        ('\u{8fc4}', '\u{8fc9}'), ('\u{8fcb}', '\u{8fe6}'), ('\u{8fe8}', '\u{9029}'), ('\u{902b}',
-        '\u{902b}'), ('\u{902d}', '\u{9036}'), ('\u{9038}', '\u{903f}'), ('\u{9041}', '\u{9045}'),
-        ('\u{9047}', '\u{90aa}'), ('\u{90ac}', '\u{90cb}'), ('\u{90ce}', '\u{90d1}'), ('\u{90d3}',
-        '\u{90f5}'), ('\u{90f7}', '\u{9109}'), ('\u{910b}', '\u{913b}'), ('\u{913e}', '\u{9158}'),
-        ('\u{915a}', '\u{917a}'), ('\u{917c}', '\u{9194}'), ('\u{9196}', '\u{9197}'), ('\u{9199}',
-        '\u{91a8}'), ('\u{91aa}', '\u{91be}'), ('\u{91c0}', '\u{91c3}'), ('\u{91c5}', '\u{91df}'),
-        ('\u{91e1}', '\u{91ee}'), ('\u{91f0}', '\u{9212}'), ('\u{9214}', '\u{921e}'), ('\u{9220}',
-        '\u{9221}'), ('\u{9223}', '\u{9242}'), ('\u{9244}', '\u{9268}'), ('\u{926b}', '\u{9280}'),
-        ('\u{9282}', '\u{9283}'), ('\u{9285}', '\u{929d}'), ('\u{929f}', '\u{92bc}'), ('\u{92be}',
-        '\u{92d3}'), ('\u{92d5}', '\u{92da}'), ('\u{92dc}', '\u{92e1}'), ('\u{92e3}', '\u{931b}'),
-        ('\u{931d}', '\u{932f}'), ('\u{9332}', '\u{9361}'), ('\u{9363}', '\u{9367}'), ('\u{9369}',
-        '\u{936a}'), ('\u{936c}', '\u{936e}'), ('\u{9370}', '\u{9372}'), ('\u{9374}', '\u{9377}'),
-        ('\u{9379}', '\u{937e}'), ('\u{9380}', '\u{9380}'), ('\u{9382}', '\u{938a}'), ('\u{938c}',
-        '\u{939b}'), ('\u{939d}', '\u{939f}'), ('\u{93a1}', '\u{93aa}'), ('\u{93ac}', '\u{93ba}'),
-        ('\u{93bc}', '\u{93df}'), ('\u{93e1}', '\u{93f2}'), ('\u{93f4}', '\u{9401}'), ('\u{9403}',
-        '\u{9416}'), ('\u{9418}', '\u{941b}'), ('\u{941d}', '\u{941d}'), ('\u{9420}', '\u{9423}'),
-        ('\u{9425}', '\u{9442}'), ('\u{9444}', '\u{944d}'), ('\u{944f}', '\u{946b}'), ('\u{946d}',
-        '\u{947a}'), ('\u{947c}', '\u{9577}'), ('\u{957a}', '\u{957d}'), ('\u{957f}', '\u{9584}'),
-        ('\u{9586}', '\u{9596}'), ('\u{9598}', '\u{95b2}'), ('\u{95b5}', '\u{95b7}'), ('\u{95b9}',
-        '\u{95c0}'), ('\u{95c2}', '\u{95d8}'), ('\u{95da}', '\u{95dc}'), ('\u{95de}', '\u{9624}'),
-        ('\u{9627}', '\u{9628}'), ('\u{962a}', '\u{963d}'), ('\u{963f}', '\u{9655}'), ('\u{9658}',
-        '\u{9678}'), ('\u{967a}', '\u{967a}'), ('\u{967c}', '\u{967e}'), ('\u{9680}', '\u{9680}'),
-        ('\u{9683}', '\u{968b}'), ('\u{968d}', '\u{9695}'), ('\u{9697}', '\u{9699}'), ('\u{969b}',
-        '\u{969c}'), ('\u{969e}', '\u{969e}'), ('\u{96a0}', '\u{96aa}'), ('\u{96ac}', '\u{96ae}'),
-        ('\u{96b0}', '\u{96b4}'), ('\u{96b6}', '\u{96e3}'), ('\u{96e5}', '\u{96e5}'), ('\u{96e8}',
-        '\u{96fb}'), ('\u{96fd}', '\u{9713}'), ('\u{9715}', '\u{9716}'), ('\u{9718}', '\u{9719}'),
-        ('\u{971c}', '\u{9732}'), ('\u{9735}', '\u{9736}'), ('\u{9738}', '\u{973f}'), ('\u{9742}',
-        '\u{974c}'), ('\u{974e}', '\u{9756}'), ('\u{9758}', '\u{9762}'), ('\u{9764}', '\u{9774}'),
-        ('\u{9776}', '\u{9786}'), ('\u{9788}', '\u{9788}'), ('\u{978a}', '\u{979a}'), ('\u{979c}',
-        '\u{97a8}'), ('\u{97aa}', '\u{97af}'), ('\u{97b2}', '\u{97b4}'), ('\u{97b6}', '\u{97bd}'),
-        ('\u{97bf}', '\u{97bf}'), ('\u{97c1}', '\u{97d1}'), ('\u{97d3}', '\u{97fb}'), ('\u{97fd}',
-        '\u{981e}'), ('\u{9820}', '\u{9824}'), ('\u{9826}', '\u{9829}'), ('\u{982b}', '\u{9832}'),
-        ('\u{9834}', '\u{9839}'), ('\u{983b}', '\u{983d}'), ('\u{983f}', '\u{9841}'), ('\u{9843}',
-        '\u{9846}'), ('\u{9848}', '\u{9855}'), ('\u{9857}', '\u{9865}'), ('\u{9867}', '\u{9867}'),
-        ('\u{9869}', '\u{98b6}'), ('\u{98b8}', '\u{98c9}'), ('\u{98cb}', '\u{98e3}'), ('\u{98e5}',
-        '\u{98eb}'), ('\u{98ed}', '\u{98f0}'), ('\u{98f2}', '\u{98f7}'), ('\u{98f9}', '\u{98fa}'),
-        ('\u{98fc}', '\u{9918}'), ('\u{991a}', '\u{993a}'), ('\u{993c}', '\u{9943}'), ('\u{9945}',
-        '\u{9959}'), ('\u{995b}', '\u{995c}'), ('\u{995e}', '\u{99be}'), ('\u{99c0}', '\u{99df}'),
-        ('\u{99e1}', '\u{99e5}'), ('\u{99e7}', '\u{99ea}'), ('\u{99ec}', '\u{99f4}'), ('\u{99f6}',
-        '\u{9a0f}'), ('\u{9a11}', '\u{9a16}'), ('\u{9a19}', '\u{9a3a}'), ('\u{9a3c}', '\u{9a50}'),
-        ('\u{9a52}', '\u{9a57}'), ('\u{9a59}', '\u{9a5c}'), ('\u{9a5e}', '\u{9a62}'), ('\u{9a64}',
-        '\u{9aa8}'), ('\u{9aaa}', '\u{9abc}'), ('\u{9abe}', '\u{9ac7}'), ('\u{9ac9}', '\u{9ad6}'),
-        ('\u{9ad8}', '\u{9adf}'), ('\u{9ae1}', '\u{9ae3}'), ('\u{9ae5}', '\u{9ae7}'), ('\u{9aea}',
-        '\u{9aef}'), ('\u{9af1}', '\u{9aff}'), ('\u{9b01}', '\u{9b01}'), ('\u{9b03}', '\u{9b08}'),
-        ('\u{9b0a}', '\u{9b13}'), ('\u{9b15}', '\u{9b1a}'), ('\u{9b1c}', '\u{9b33}'), ('\u{9b35}',
-        '\u{9b3c}'), ('\u{9b3e}', '\u{9b3f}'), ('\u{9b41}', '\u{9b4f}'), ('\u{9b51}', '\u{9b56}'),
-        ('\u{9b58}', '\u{9b61}'), ('\u{9b63}', '\u{9b71}'), ('\u{9b73}', '\u{9b88}'), ('\u{9b8a}',
-        '\u{9b8b}'), ('\u{9b8d}', '\u{9b98}'), ('\u{9b9a}', '\u{9bc1}'), ('\u{9bc3}', '\u{9bf5}'),
-        ('\u{9bf7}', '\u{9bff}'), ('\u{9c02}', '\u{9c02}'), ('\u{9c04}', '\u{9c41}'), ('\u{9c43}',
-        '\u{9c4e}'), ('\u{9c50}', '\u{9c50}'), ('\u{9c52}', '\u{9c60}'), ('\u{9c62}', '\u{9c63}'),
-        ('\u{9c65}', '\u{9c7a}'), ('\u{9c7c}', '\u{9d0b}'), ('\u{9d0e}', '\u{9d10}'), ('\u{9d12}',
-        '\u{9d26}'), ('\u{9d28}', '\u{9d34}'), ('\u{9d36}', '\u{9d3b}'), ('\u{9d3d}', '\u{9d6c}'),
-        ('\u{9d6e}', '\u{9d94}'), ('\u{9d96}', '\u{9dad}'), ('\u{9daf}', '\u{9dbc}'), ('\u{9dbe}',
-        '\u{9dbf}'), ('\u{9dc1}', '\u{9de9}'), ('\u{9deb}', '\u{9dfb}'), ('\u{9dfd}', '\u{9e0d}'),
-        ('\u{9e0f}', '\u{9e15}'), ('\u{9e17}', '\u{9e1b}'), ('\u{9e1d}', '\u{9e7a}'), ('\u{9e7c}',
-        '\u{9e8e}'), ('\u{9e91}', '\u{9e97}'), ('\u{9e99}', '\u{9e9d}'), ('\u{9e9f}', '\u{9ea1}'),
-        ('\u{9ea3}', '\u{9eaa}'), ('\u{9ead}', '\u{9eb0}'), ('\u{9eb2}', '\u{9eeb}'), ('\u{9eed}',
-        '\u{9ef0}'), ('\u{9ef2}', '\u{9f02}'), ('\u{9f04}', '\u{9f10}'), ('\u{9f12}', '\u{9f13}'),
-        ('\u{9f15}', '\u{9f25}'), ('\u{9f27}', '\u{9f44}'), ('\u{9f46}', '\u{9f52}'), ('\u{9f54}',
-        '\u{9f6c}'), ('\u{9f6e}', '\u{9fa0}'), ('\u{9fa2}', '\u{9fa2}'), ('\u{9fa4}', '\u{9fa5}'),
-        ('\u{a78d}', '\u{a78d}'), ('\u{a7aa}', '\u{a7aa}'), ('\u{aa7b}', '\u{aa7b}'), ('\u{ac00}',
-        '\u{d7a3}'), ('\u{11301}', '\u{11301}'), ('\u{11303}', '\u{11303}'), ('\u{1133c}',
-        '\u{1133c}'), ('\u{1e7e0}', '\u{1e7e6}'), ('\u{1e7e8}', '\u{1e7eb}'), ('\u{1e7ed}',
-        '\u{1e7ee}'), ('\u{1e7f0}', '\u{1e7fe}'), ('\u{2070e}', '\u{2070e}'), ('\u{20731}',
-        '\u{20731}'), ('\u{20779}', '\u{20779}'), ('\u{20c53}', '\u{20c53}'), ('\u{20c78}',
-        '\u{20c78}'), ('\u{20c96}', '\u{20c96}'), ('\u{20ccf}', '\u{20ccf}'), ('\u{20cd5}',
-        '\u{20cd5}'), ('\u{20d15}', '\u{20d15}'), ('\u{20d7c}', '\u{20d7c}'), ('\u{20d7f}',
-        '\u{20d7f}'), ('\u{20e0e}', '\u{20e0f}'), ('\u{20e77}', '\u{20e77}'), ('\u{20e9d}',
-        '\u{20e9d}'), ('\u{20ea2}', '\u{20ea2}'), ('\u{20ed7}', '\u{20ed7}'), ('\u{20ef9}',
-        '\u{20efa}'), ('\u{20f2d}', '\u{20f2e}'), ('\u{20f4c}', '\u{20f4c}'), ('\u{20fb4}',
-        '\u{20fb4}'), ('\u{20fbc}', '\u{20fbc}'), ('\u{20fea}', '\u{20fea}'), ('\u{2105c}',
-        '\u{2105c}'), ('\u{2106f}', '\u{2106f}'), ('\u{21075}', '\u{21076}'), ('\u{2107b}',
-        '\u{2107b}'), ('\u{210c1}', '\u{210c1}'), ('\u{210c9}', '\u{210c9}'), ('\u{211d9}',
-        '\u{211d9}'), ('\u{220c7}', '\u{220c7}'), ('\u{227b5}', '\u{227b5}'), ('\u{22ad5}',
-        '\u{22ad5}'), ('\u{22b43}', '\u{22b43}'), ('\u{22bca}', '\u{22bca}'), ('\u{22c51}',
-        '\u{22c51}'), ('\u{22c55}', '\u{22c55}'), ('\u{22cc2}', '\u{22cc2}'), ('\u{22d08}',
-        '\u{22d08}'), ('\u{22d4c}', '\u{22d4c}'), ('\u{22d67}', '\u{22d67}'), ('\u{22eb3}',
-        '\u{22eb3}'), ('\u{23cb7}', '\u{23cb7}'), ('\u{244d3}', '\u{244d3}'), ('\u{24db8}',
-        '\u{24db8}'), ('\u{24dea}', '\u{24dea}'), ('\u{2512b}', '\u{2512b}'), ('\u{26258}',
-        '\u{26258}'), ('\u{267cc}', '\u{267cc}'), ('\u{269f2}', '\u{269f2}'), ('\u{269fa}',
-        '\u{269fa}'), ('\u{27a3e}', '\u{27a3e}'), ('\u{2815d}', '\u{2815d}'), ('\u{28207}',
-        '\u{28207}'), ('\u{282e2}', '\u{282e2}'), ('\u{28cca}', '\u{28cca}'), ('\u{28ccd}',
-        '\u{28ccd}'), ('\u{28cd2}', '\u{28cd2}'), ('\u{29d98}', '\u{29d98}')
-    ];
+    const IDENTIFIER_STATUS: Trie<u64> = Trie {
+        latin1: &[
+            0x7ff608000000000, 0x7fffffe87fffffe, 0x80000000000000, 0xff7fffffff7fffff
+        ],
+        index: &[
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 10, 10, 12, 13, 14, 10, 10, 10, 10, 10, 10, 10,
+            15, 10, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35,
+            36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57,
+            58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 10, 10, 10, 70, 10, 71, 72, 72, 72, 72,
+            72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 72, 73, 10, 10, 10, 10,
+            10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+            10, 10, 10, 74, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+            10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+            10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+            10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+            10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 75,
+            10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 76, 10, 10, 77, 78, 79, 10,
+            10, 10, 10, 10, 10, 10, 80, 10, 10, 81, 10, 82, 83, 84, 10, 10, 10, 10, 10, 10, 85, 10,
+            10, 10, 86, 10, 10, 10, 87, 10, 88, 10, 10, 10, 10, 10, 10, 10, 10, 89, 10, 90, 91, 10,
+            10, 10, 10, 10, 10, 10, 10, 92, 10, 10, 93, 94, 10, 10, 10, 10, 95, 10, 10, 10, 10, 10,
+            10, 10, 96
+        ],
+        blocks: &[
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 11, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22,
+            23, 24, 25, 26, 11, 27, 28, 11, 11, 11, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40,
+            41, 42, 43, 44, 43, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 11, 57, 58, 59, 60,
+            11, 11, 11, 11, 61, 62, 63, 64, 65, 66, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+            11, 11, 11, 11, 67, 68, 11, 11, 69, 11, 11, 11, 11, 11, 70, 71, 72, 73, 11, 11, 74, 75,
+            76, 11, 11, 11, 11, 11, 11, 11, 77, 78, 79, 80, 11, 11, 11, 11, 11, 81, 11, 82, 11, 2,
+            83, 84, 85, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 86, 87, 11, 88, 86, 89, 11, 11,
+            90, 91, 92, 11, 11, 93, 11, 94, 11, 11, 11, 95, 11, 96, 11, 97, 11, 11, 11, 11, 11, 98,
+            99, 100, 2, 100, 11, 11, 11, 11, 11, 11, 2, 11, 101, 102, 11, 103, 11, 104, 11, 11, 11,
+            11, 105, 106, 11, 11, 107, 108, 109, 96, 11, 11, 110, 11, 11, 111, 112, 11, 113, 11,
+            114, 11, 11, 11, 11, 11, 11, 115, 116, 11, 117, 11, 93, 11, 118, 119, 120, 121, 122,
+            123, 124, 125, 126, 127, 128, 129, 130, 131, 132, 133, 57, 134, 135, 136, 137, 138, 139,
+            140, 141, 142, 143, 144, 145, 146, 57, 147, 148, 149, 150, 151, 152, 153, 154, 155, 156,
+            157, 158, 159, 160, 161, 162, 163, 164, 165, 166, 167, 168, 169, 170, 171, 172, 173,
+            174, 175, 176, 177, 178, 179, 180, 181, 182, 183, 184, 57, 185, 186, 187, 188, 189, 190,
+            191, 192, 193, 194, 195, 196, 197, 198, 199, 200, 201, 202, 203, 204, 205, 206, 207,
+            208, 209, 210, 211, 212, 213, 214, 215, 163, 216, 217, 218, 219, 220, 221, 222, 223,
+            224, 225, 226, 227, 228, 229, 230, 231, 232, 233, 234, 235, 236, 237, 238, 239, 240,
+            241, 242, 243, 244, 245, 246, 247, 248, 249, 250, 251, 252, 253, 254, 255, 256, 257,
+            258, 259, 260, 57, 261, 262, 263, 264, 265, 266, 267, 268, 236, 269, 270, 271, 272, 273,
+            274, 275, 276, 277, 278, 279, 280, 281, 282, 283, 284, 285, 286, 287, 288, 289, 290,
+            291, 292, 293, 294, 295, 296, 297, 298, 299, 300, 301, 302, 303, 304, 305, 306, 57, 203,
+            307, 308, 309, 310, 311, 312, 313, 314, 57, 315, 316, 317, 318, 319, 320, 321, 322, 323,
+            324, 325, 304, 326, 327, 328, 329, 330, 331, 332, 333, 334, 335, 336, 337, 338, 339,
+            340, 341, 57, 342, 343, 344, 345, 346, 347, 348, 349, 350, 351, 352, 353, 57, 232, 354,
+            355, 356, 357, 358, 359, 360, 361, 362, 363, 364, 365, 366, 367, 368, 369, 370, 371,
+            372, 373, 374, 375, 376, 377, 378, 379, 380, 381, 382, 383, 384, 385, 386, 57, 57, 57,
+            387, 388, 389, 390, 391, 392, 393, 394, 395, 396, 397, 398, 399, 162, 400, 374, 401, 80,
+            402, 403, 404, 405, 406, 407, 408, 409, 410, 411, 412, 57, 57, 413, 414, 415, 416, 417,
+            418, 419, 420, 421, 422, 423, 11, 11, 11, 11, 11, 11, 11, 424, 11, 11, 99, 11, 11, 11,
+            11, 11, 11, 57, 57, 57, 57, 57, 57, 57, 57, 57, 57, 57, 57, 57, 57, 425, 11, 11, 11, 11,
+            11, 426, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 427, 11, 11, 11, 11, 428, 90, 11, 11,
+            11, 429, 430, 431, 432, 433, 11, 11, 434, 2, 435, 436, 437, 438, 439, 440, 11, 441, 11,
+            442, 11, 11, 11, 443, 11, 11, 11, 444, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 445, 11,
+            11, 11, 11, 432, 11, 103, 11, 446, 11, 447, 11, 110, 448, 449, 11, 11, 11, 11, 450, 11,
+            11, 11, 11, 11, 11, 11, 2, 11, 11, 11, 11, 11, 11, 11, 11, 451, 11, 11, 11, 11, 11, 11,
+            11, 11, 11, 11, 114, 440, 11, 11, 11, 11, 452, 11, 11, 11, 11, 86, 11, 11, 11, 11, 11,
+            11, 11, 11, 11, 11, 11, 11, 11, 438, 11, 11, 11, 11, 11, 11, 11, 453, 454, 11, 11, 11,
+            11, 11, 11, 11, 11, 11, 11, 11, 11, 455, 11, 11, 444, 11, 11, 456, 11, 11, 11, 11, 11,
+            11, 11, 457, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 86, 11
+        ],
+        leaves: &[
+            0x7ff608000000000, 0x7fffffe87fffffe, 0x80000000000000, 0xff7fffffff7fffff,
+            0x7ef3cfffffcfffff, 0x7ffffff3ff3f3dfe, 0x9d800323d7c642, 0x300c3c0201fe000, 0xf000000,
+            0x403080ad83010, 0x1800000000040a00, 0x0, 0x201c808001fdf, 0xfffffffbffffd740, 0x7fff,
+            0xffffffffffffdffe, 0xdffeffff, 0xfcffff3fcfff0000, 0x33fc3fff3ff8001,
+            0xfffe003000000000, 0xfffffffe007fffff, 0x47f, 0x1807ffffff0000, 0x27ffffff00000000,
+            0xfe1503ff0037fffe, 0x7cebeff587feffff, 0xe3ffc000002fffff, 0x3c5cd00460000,
+            0x3ffffffffffff, 0xf80003fd00008000, 0x87, 0xdfeffdffffffefee, 0xd8fcffc000c0bfef,
+            0xd3c5fdfffff98fee, 0x3ffc00000799f, 0xd325fdfffff987e4, 0x3000010003987,
+            0xd3edfdfffffbbfec, 0xffc000003bbf, 0xd3cdfdfffff98fee, 0x200008040398f,
+            0xc3ffc718d63dc7e8, 0x3dc7, 0xc3edfdfffffdcfec, 0x3ddf, 0xffc000003dcf,
+            0xc3fffdfffffdcfec, 0xfc00000000802dcf, 0x2ffbffffbc7e3fec, 0x400007f5f847f,
+            0x7f7fffffffffffe, 0x3ff3fff, 0x3bf76caefef02596, 0x3ff3f5f, 0x3ff00000800,
+            0xfc1601ffef7bdef7, 0x1dfe2f7bdef70011, 0xffffffffffffffff, 0xffe0001ffc0003ff, 0x87ff,
+            0x1ffffffff2080, 0xffffffffffffff7f, 0xffffffff3d7f3dff, 0x7f3d7fffffff3d7f,
+            0xffffffff7fff3d, 0xffffffff7f3d7fff, 0x3ffff7f, 0xffcffce79fffffff, 0x3ff00053fff,
+            0xe7ffffffffff0000, 0xf0c00033000c3000, 0x3300c0c000ffc, 0xffffffff400c3000,
+            0x3ffffffffffffff, 0x1cffff00000000, 0x100000000000, 0x8002010000, 0xe0,
+            0xfffffffffffffffe, 0xffffffff607fffff, 0x7fffffffffffffff, 0x8000000000080,
+            0x1000000000, 0x8000200240000000, 0x4008000000004000, 0x4004000, 0x1000000,
+            0x400100000000, 0x4100000088018000, 0x8008010040000, 0x200000000000000, 0x4000,
+            0x800000000, 0x400000000000, 0x100000000, 0x88000000000, 0x10000000000000, 0x10000,
+            0x42000400000, 0x800000000000000, 0x80000000, 0x2100000000000, 0x20080000, 0x8,
+            0x40000400000, 0x40, 0x200001000, 0x20800000000, 0x1000000000000000, 0x2000, 0x4,
+            0x2400000000000080, 0xc000008800006c, 0x40000, 0x100000000000000, 0x2080000000000000,
+            0xfe0000022, 0x3f80000, 0xfffffdfffffbffff, 0xfdfffeffffffffff, 0xeffffff7ffbfffff,
+            0xa8ffff7fffffefff, 0xff5dfeffffbfff7f, 0xfffdffffffffffee, 0xdffffffbffffafff,
+            0xcffffffbffffeebf, 0xdeeffffffffeffff, 0xeffffffffefbfffb, 0xfdfffffc7f77fffd,
+            0xff6ff7f97efffff7, 0xfffff6fff7ffffff, 0xbffbffeefef77bfb, 0xfaffdff5ffffbfe7,
+            0xfffbafff7ff3fffb, 0xfdffffbffff7ffff, 0xffffffffffffbfdf, 0xffffbfddffedaeef,
+            0xff9bfd7fb6ffffe7, 0xffffdbffd7fffffb, 0xfbff73fdfffffffe, 0x7fffff7f9fff7fdf,
+            0xfffffffbfbfffffe, 0xfffffdffdfffefff, 0xffdffffffffffbff, 0xffbfffffffffffff,
+            0xfffffffffcdfffff, 0xfbfffffeffffff7f, 0xfefffffeffffffff, 0xef7ff7fefffff3ff,
+            0xdfdffffd7fbdf7ef, 0xfbffffffbfbff6ff, 0xe6bfffbffdff7fff, 0xdfffffedff7dffdf,
+            0xfefffff7fe9ffef7, 0xfffffeffffbfff7d, 0xfffffffff7fdfbff, 0xfffffffffe7fffff,
+            0xff7fff3effbeffff, 0xffbffbffffdfffff, 0xffdfffbdfffedfe6, 0xffb7fff7fff5fedd,
+            0xfffffffff5ffffff, 0xff7fffffffffffff, 0xffffff7fffffffff, 0xfffeefafffffffff,
+            0xfffd7ffffdefffff, 0xfffff7ffffffdf7f, 0xfffe7ffffff7ffff, 0xfdffffffffffdbff,
+            0xbdff7ffcfffb7fbf, 0xef7bfdfffffeffbf, 0xfffffffffbfffeff, 0xffbffdb7f7fffffe,
+            0xfffffffffbffffff, 0xfdefffffffffffdf, 0xffdfffffdfdfffff, 0xf7ff77ffffffffff,
+            0xfffffffeff77ffff, 0xfdf7f7e7ffbffff7, 0xffbfffffeb7fffef, 0xfffffffffffbffff,
+            0xfffffffffffdfeff, 0xfbfffffffffbffff, 0xfdfff7fffffffffe, 0xd7ffffffffffbfff,
+            0xfffeffe7fbff7fff, 0xefffeffbf7ffbffb, 0xfffffff7ffffffff, 0xffedfffdffbfffff,
+            0xffffffdffff5ffff, 0xdfffffdfbfbbffff, 0xfcd5ffffbffdff7b, 0x77fffffeffffffff,
+            0xf5ffffffef7fffbf, 0xffff6fffffd7ffff, 0xfddfffffffffffdf, 0xffffffffff7fffff,
+            0xffffffffdfffdfff, 0xfffffbffbfffffff, 0xff7dffffff7ffbff, 0xfffffffff7ffffff,
+            0xfffffffffff7f7ff, 0xfffffffffffdffff, 0xe7ffffffffffffff, 0xbffffffffbfffdff,
+            0xffbf7ffdffffffbf, 0xfddfbfffffdffeff, 0xfffffeff7ffffbfb, 0xdfffffff7bfffffa,
+            0xbfdffffffbfffbff, 0xbdfffbefeefdfcff, 0xfe7fefffff7fffff, 0xfffffff6ffffdeff,
+            0xffffcfffaffbffff, 0xffff7fff7f76ffff, 0xfffffdeffdffff7b, 0x5fffffffffbffeff,
+            0xfdffffffff5fffff, 0xfffeff7fffdffffd, 0xfbffffffbaffffff, 0xfeffffdffddfffff,
+            0xffffffdbfdfffff7, 0xbffffffffffffdff, 0xffffffffffffdfff, 0xfffffffb7fffffff,
+            0xffffffffff19fffe, 0xff7fffefeffffff7, 0xfffebfffeffffbff, 0xbffdffffddff7bbf,
+            0xfffffdfffefffdee, 0xffdfbffff7ffbfff, 0xdfddaeffde7ffffe, 0xfeffffffffffffff,
+            0xfbedfdffffffffff, 0xefffdfffeffffbff, 0xffffafffffffffdf, 0xfffffffeffffffff,
+            0xffeffffffeffffff, 0xffffffefeffffffb, 0xfffdffffffffffff, 0xffbcffffffbffffd,
+            0x3fffffffffffffff, 0xfffffbfffefbffb9, 0xc7ffefffffffefff, 0xffffffffffdff7ff,
+            0xfffffff7f7ffffff, 0xfffffffffffff7ef, 0xfdfffffffffffafb, 0xff7fffff7affffff,
+            0xdfdffffffff6ffdf, 0xfffffffffff6ffff, 0xf7f3ffffffffb77f, 0xffbbfbfff77fdffd,
+            0xbfffffffffdfffff, 0xffe7ffeffffffeff, 0xffffd7ffff7fffff, 0xffbff7bfffbfdfff,
+            0xfbf7fffffff7efff, 0xffef7fceff5df7ff, 0xffffffffffdebfdf, 0xbffff7ffffffffff,
+            0xfffffefffffefffb, 0xfffffffffff7ffff, 0xffffffffefffffff, 0xf7f7fffffffffffd,
+            0xbdfffffffeffffff, 0xf7fffffffefffd7f, 0xffffffffdfbfffef, 0xfffff7ffffffed7f,
+            0xf7fdfffdbfdbfffd, 0xff7ffdffffffffdf, 0xffffbfffefffffff, 0xffffefffffffffff,
+            0xfff7ffffffddffcf, 0xffb7fed9ffffdfff, 0xfffff7fffdfff7fd, 0x7ffff7fdffffbffa,
+            0xffffffeffffffffb, 0xbfffffefffbfffff, 0xffcfffafdffbfffd, 0xffbfffefffffffff,
+            0xffbffffffffff7bb, 0xfffffffffffffeff, 0xfefefffffffbff7b, 0xffbbfffe7fdfdefe,
+            0xff9faffffdffffff, 0xeffffdffbfffff3f, 0xffffffffff6fef6f, 0xfaffeffffffffd7d,
+            0xffff7befffffffff, 0xc5fffffff3fffffb, 0xefdffdffffffffbb, 0xfffffd7f7fffff7f,
+            0xfffffffffff7fffb, 0xfffaffd7ffffffff, 0xfbffffffffffffff, 0xffff3ffff7ffffff,
+            0xffbfffffdfdbffff, 0xffffeffffbf7ff7f, 0x6fffff95f3f7ffff, 0xffffdbf7ffffffff,
+            0xfeffffeffbffdeff, 0xfffffeeffedffffb, 0x1ffff7dffff7dfff, 0xfffffffffeffffff,
+            0xffffffeff7ffffff, 0xffffffffdfffffff, 0xfffffffffdffffbd, 0xffeffffffd7effef,
+            0xffffffefdfbfffff, 0xefff7ffffffffbff, 0xffffffbffffffffe, 0xfdf7fe3fffefbeff,
+            0xffffffff7fffffff, 0xffcfffffffffffe7, 0xffffffffffbeffef, 0xfffe7feffffff7ff,
+            0xffffffeffff7ffff, 0xffffffdffffffffd, 0xfffbffffffffefff, 0xeffffffbffffffff,
+            0xfffefffffffefbff, 0xffffffffffdfffff, 0xbfffffffffffffff, 0xffffdfbfffffffff,
+            0xffffffff7f2fffff, 0xff7fffffffdffff7, 0xffffdffffffffff7, 0xfff3fffffffeffdf,
+            0x7ffffffffffeefff, 0xf7fbfffffff7ffff, 0xeff7bfffffffefff, 0xff7fffffdfde9ff7,
+            0xffdfffffff7fffff, 0xffffffbefffbfdff, 0xdfffff7effdfffff, 0xdfff7fffdfffffff,
+            0xfffdffffffffffdf, 0xfffffffffdffbfff, 0xfffefffffbffffff, 0xfbffdffffffbff7f,
+            0xfbffffff6effff7f, 0xffffddbfffffffff, 0xffffffefffff7ff7, 0xdfdfffdfffffffff,
+            0xe7eefffffdeedfff, 0xdddfbfffffffffff, 0xdffff7fcfdefff9f, 0xdeffffeffffff7ff,
+            0xff7f7fff7ffffeff, 0xffffeffffffeffff, 0x7efbfdffffffffff, 0xefdffdf9ffffffff,
+            0xfffffffff7ff5f7d, 0x7feffffffef1ffee, 0xffffffbff7fff777, 0xff7ffdfffebff7ff,
+            0xfffffffffff9fffd, 0xefffffffffffffff, 0xffffffbfffffffff, 0xffffffdffffffffe,
+            0xfffbfffffffff7fd, 0xfd5fbfffffff7fbf, 0xff37fffbf7ffffff, 0xfffefffffdffffed,
+            0xfbffdfffffff7fff, 0xfffffffffffffffd, 0xfff7ffefdfffffff, 0xffffff7ffffffbf7,
+            0xff7febffffffffff, 0xffffffffffffffbe, 0xfffff7ffffffffff, 0xffbffffffffbcfff,
+            0xcffffffffffffbff, 0xf7fffffffdffffff, 0x7ffffdfffedfffff, 0xffff7ffeffffffef,
+            0xfffffffb7ff7ffff, 0xfffff9fffffffff7, 0xdfffffffbfffffed, 0xfffffffbf7efffff,
+            0xfffcffffefffffff, 0x7ef776fbffffffff, 0xf7fff7feeffff7fd, 0xfff7fffeffffffff,
+            0xffffffef2f7ffffb, 0xf7ffefffffffbff7, 0xbcffffffffffffff, 0xfee7ffffff7fffdf,
+            0xffffffffddfffffd, 0xbffffd9fffffffff, 0x75ffffffff3fffff, 0xffdf77ff5bbfeff9,
+            0xefffff2fffffffff, 0xff67fffff36fffff, 0xffdffff7ff7fdffc, 0xbfdcfdfff7fffd7f,
+            0xeffffffffffbfffe, 0xbbf7fbdf7fffffff, 0xfffffebfffbfff7b, 0xf6fdefeffffffbff,
+            0xffffffffdbffffef, 0xffdff7beffffffff, 0xf7fffffffe7effff, 0xfffffff7defdffff,
+            0xdffffdffffffffff, 0xfffefceeff7ffeff, 0xdfeffffff7effdfa, 0xfffbfffbff7efffe,
+            0xfffffffffdffedff, 0xffbffffffffffffb, 0xfffffffffffffff4, 0xf7ffffedfffd7ffb,
+            0xefdfff7ffffdcfff, 0xffffdfffffffffff, 0xdfffbfffffdfffff, 0xeffffbfffffffffe,
+            0xffffffffefbfbfff, 0xf7ffffffffffffff, 0xfffde7fbbefe7fff, 0xfffdefffffffffff,
+            0xffffffbfffedfff7, 0xffffdffffff7ffdf, 0x35ffffffff, 0x40000002000, 0xfffffffff,
+            0x100000000000000a, 0x7fff6f7f00000000, 0x2000000004000, 0x100000000080000, 0x400000,
+            0x208000, 0x200000, 0x9000000000000000, 0xc000, 0x420000000, 0x600000000800000,
+            0x600000000000, 0x1000, 0x1010000000000000, 0x40000000000, 0x860800010000000, 0x202,
+            0x2000000, 0x80, 0x20000000000000, 0x400, 0x220000, 0x100, 0x8000001000,
+            0x8000000000000, 0x80000, 0x80000000000, 0x404000000000000, 0x4000000000000000,
+            0x20000000, 0x400000000, 0x42400
+        ],
+        block_bits: 3,
+        leaf_bits: 0,
+    };
+
+    // Identifier type table, values are indices into IDENTIFIER_TYPES:
+    const IDENTIFIER_TYPE: Trie<u8> = Trie {
+        latin1: &[
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 5, 5, 5, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 5, 5, 5, 5, 5, 5, 5, 11, 5, 5, 5, 5, 5, 11, 11, 5, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 11, 5, 5, 5, 5, 5, 5, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 5, 5, 5, 5, 12, 5, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            5, 5, 5, 5, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 4, 5, 5, 5, 5, 5, 5, 5, 4, 5, 4, 5, 5, 3, 5, 4, 5, 5, 4, 4, 4,
+            4, 5, 11, 4, 4, 4, 5, 4, 4, 4, 5, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 5, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 5, 12,
+            12, 12, 12, 12, 12, 12, 12
+        ],
+        index: &[
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45,
+            46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67,
+            68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 80, 81, 82, 83, 84, 85, 85, 85, 85,
+            85, 85, 85, 85, 85, 85, 85, 85, 85, 85, 85, 85, 85, 85, 85, 85, 85, 86, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97,
+            98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 87, 87, 87, 87, 111, 108,
+            108, 112, 108, 108, 108, 108, 108, 108, 113, 108, 114, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 115, 87, 87, 87, 80, 116, 117, 118, 108, 108, 108, 108, 108, 108, 108,
+            108, 108, 108, 108, 108, 108, 108, 119, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 120, 121, 122, 87, 87, 87, 87, 123, 87, 87, 87, 87, 87, 87, 87, 124,
+            125, 126, 127, 128, 129, 87, 130, 87, 131, 132, 133, 134, 135, 136, 87, 137, 138, 139,
+            140, 141, 142, 143, 144, 87, 87, 145, 145, 145, 146, 145, 145, 147, 148, 149, 145, 145,
+            145, 145, 145, 145, 145, 150, 145, 145, 151, 145, 152, 153, 154, 145, 145, 145, 145,
+            145, 145, 155, 145, 145, 145, 156, 145, 145, 145, 157, 145, 158, 145, 145, 145, 145,
+            145, 145, 145, 145, 159, 145, 160, 161, 145, 145, 145, 145, 145, 145, 145, 145, 162,
+            145, 145, 163, 164, 145, 145, 145, 145, 165, 145, 145, 145, 145, 145, 145, 145, 166,
+            145, 145, 145, 145, 167, 145, 145, 145, 145, 145, 145, 145, 145, 168, 145, 145, 145,
+            145, 145, 145, 145, 145, 145, 145, 169, 145, 145, 145, 145, 145, 145, 145, 145, 145,
+            145, 145, 145, 145, 170, 145, 171, 87, 87, 87, 87, 172, 173, 87, 87, 145, 145, 145, 145,
+            145, 145, 145, 145, 145, 174, 145, 145, 145, 145, 145, 145, 145, 145, 145, 145, 145,
+            145, 145, 145, 145, 145, 175, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 176
+        ],
+        blocks: &[
+            0, 1, 2, 3, 4, 5, 4, 6, 7, 1, 8, 9, 10, 11, 10, 11, 10, 12, 13, 14, 15, 16, 17, 18, 19,
+            20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 27, 37, 38, 39, 40,
+            41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 10, 51, 10, 52, 53, 54, 55, 56, 10, 10, 10, 10,
+            56, 57, 57, 58, 59, 60, 61, 62, 63, 64, 65, 57, 66, 67, 68, 10, 69, 70, 10, 71, 72, 73,
+            74, 75, 10, 76, 77, 41, 78, 10, 79, 80, 81, 82, 83, 10, 84, 85, 86, 10, 87, 88, 89, 1,
+            90, 90, 90, 91, 92, 93, 94, 10, 10, 10, 95, 90, 90, 96, 97, 98, 98, 99, 1, 90, 100, 101,
+            27, 102, 103, 104, 105, 106, 29, 107, 29, 108, 10, 109, 110, 111, 112, 113, 114, 115,
+            116, 117, 118, 119, 120, 121, 122, 123, 116, 117, 124, 125, 126, 127, 128, 129, 51, 117,
+            130, 131, 132, 121, 133, 134, 116, 117, 135, 136, 137, 138, 139, 140, 141, 142, 143,
+            144, 145, 127, 146, 147, 148, 117, 149, 150, 151, 138, 152, 153, 148, 117, 154, 155,
+            156, 121, 157, 158, 148, 109, 159, 160, 161, 138, 162, 163, 164, 10, 165, 166, 167, 127,
+            168, 68, 10, 10, 169, 170, 171, 1, 1, 172, 173, 174, 175, 176, 177, 1, 1, 178, 179, 180,
+            181, 182, 183, 184, 185, 186, 182, 187, 188, 189, 146, 1, 1, 10, 10, 10, 10, 180, 190,
+            191, 192, 193, 194, 57, 57, 195, 10, 10, 196, 57, 57, 57, 57, 57, 197, 198, 57, 57, 57,
+            57, 57, 57, 57, 57, 57, 199, 10, 10, 10, 200, 201, 10, 10, 202, 10, 203, 204, 205, 206,
+            10, 207, 203, 208, 10, 10, 199, 209, 210, 211, 29, 212, 90, 90, 90, 90, 90, 213, 214,
+            90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90,
+            90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 215, 90, 216, 217, 98, 98,
+            98, 98, 218, 219, 98, 220, 98, 221, 98, 222, 223, 224, 10, 225, 226, 227, 228, 229, 230,
+            212, 231, 232, 98, 98, 98, 98, 98, 219, 98, 98, 233, 90, 90, 90, 90, 234, 90, 235, 100,
+            100, 236, 90, 237, 238, 90, 90, 100, 90, 239, 101, 41, 41, 98, 240, 90, 90, 90, 235, 90,
+            215, 239, 239, 241, 242, 243, 244, 245, 1, 90, 90, 90, 90, 246, 239, 1, 1, 90, 90, 90,
+            90, 90, 90, 90, 247, 90, 90, 90, 248, 249, 90, 90, 237, 250, 10, 10, 251, 1, 252, 57,
+            253, 27, 27, 254, 255, 256, 257, 258, 259, 27, 260, 257, 257, 261, 132, 262, 263, 264,
+            265, 266, 267, 268, 269, 270, 271, 272, 273, 10, 10, 10, 10, 10, 274, 57, 275, 57, 57,
+            275, 276, 57, 277, 57, 57, 10, 278, 279, 280, 281, 282, 283, 284, 285, 286, 287, 288,
+            289, 290, 291, 292, 293, 41, 294, 295, 296, 132, 297, 298, 299, 300, 301, 257, 257, 257,
+            302, 41, 41, 41, 41, 41, 41, 41, 41, 41, 303, 304, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 305, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            212, 1, 146, 1, 257, 257, 257, 257, 257, 257, 257, 257, 306, 1, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 307, 41, 41, 41, 41, 41, 41, 308, 41, 41, 41, 41, 41, 307, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 309, 41, 41, 41, 41, 41, 41, 310, 41, 98, 98, 98, 98, 98, 98,
+            311, 312, 98, 98, 98, 98, 98, 98, 313, 314, 57, 57, 315, 90, 90, 90, 316, 317, 29, 318,
+            319, 319, 319, 319, 57, 57, 1, 152, 212, 320, 41, 321, 1, 1, 41, 322, 41, 41, 41, 41,
+            41, 323, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 324, 1, 41,
+            325, 326, 327, 328, 68, 10, 10, 10, 10, 329, 330, 10, 10, 10, 10, 331, 332, 90, 333,
+            334, 257, 257, 335, 257, 291, 336, 90, 90, 41, 41, 337, 57, 257, 291, 257, 257, 338,
+            257, 257, 291, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257,
+            257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 29, 29, 29, 29, 339, 29, 29, 340, 29,
+            29, 29, 29, 29, 29, 341, 29, 29, 29, 29, 29, 29, 29, 29, 339, 29, 342, 343, 344, 342,
+            29, 29, 345, 342, 346, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 347, 29, 29, 29, 29, 348, 29, 29, 29, 29, 29, 344,
+            349, 29, 350, 29, 347, 29, 29, 29, 351, 339, 340, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 352, 342, 29, 29, 29, 29, 29, 340, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            342, 29, 29, 29, 29, 29, 29, 29, 353, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 354, 29, 29, 29, 29, 29, 29, 29, 29, 341, 29, 29, 29, 29, 29, 353, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 355,
+            356, 29, 29, 29, 29, 357, 29, 344, 29, 29, 29, 29, 29, 339, 29, 344, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 339, 29, 29, 29, 29, 29, 29, 358, 359, 29, 360, 29, 29, 29, 29, 29, 29, 340, 29,
+            29, 29, 29, 29, 29, 29, 29, 355, 346, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 355, 29, 29, 29, 358, 29, 359, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 361, 29, 29, 29, 29, 358, 362, 29, 29, 29, 29, 29, 29, 341, 29, 29, 29, 29, 29,
+            29, 29, 29, 363, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 339, 29, 29, 364, 365, 366,
+            29, 367, 29, 29, 29, 29, 29, 363, 29, 29, 29, 29, 29, 29, 29, 29, 29, 347, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 368, 369, 370, 371, 29, 29, 29, 29, 29, 29, 372, 29, 29, 29, 29, 29, 29, 29, 29,
+            342, 29, 1, 1, 1, 1, 10, 373, 109, 10, 10, 10, 374, 109, 10, 375, 376, 377, 377, 10,
+            199, 378, 199, 375, 374, 379, 380, 10, 10, 381, 382, 10, 373, 383, 384, 10, 373, 13, 10,
+            385, 10, 386, 373, 387, 10, 377, 381, 388, 389, 109, 376, 390, 391, 392, 10, 393, 394,
+            10, 395, 396, 380, 397, 398, 10, 399, 400, 373, 401, 382, 373, 10, 10, 10, 10, 10, 376,
+            375, 109, 402, 10, 10, 10, 403, 404, 405, 10, 406, 407, 408, 409, 373, 410, 411, 10,
+            385, 10, 412, 413, 414, 225, 199, 203, 385, 413, 373, 10, 377, 383, 109, 10, 413, 10,
+            10, 415, 10, 10, 10, 375, 10, 416, 10, 10, 199, 10, 385, 413, 10, 10, 10, 10, 10, 10,
+            385, 374, 63, 10, 417, 418, 419, 420, 381, 421, 394, 422, 10, 413, 203, 109, 375, 423,
+            415, 424, 404, 383, 396, 425, 376, 374, 424, 375, 374, 10, 413, 426, 10, 10, 10, 427,
+            10, 10, 10, 428, 429, 199, 10, 415, 413, 375, 430, 385, 431, 415, 432, 433, 376, 434,
+            10, 435, 10, 10, 10, 10, 10, 199, 10, 10, 199, 10, 10, 10, 436, 385, 10, 437, 203, 381,
+            438, 10, 393, 10, 10, 376, 203, 385, 411, 10, 10, 109, 439, 373, 440, 441, 375, 385,
+            109, 442, 374, 413, 10, 10, 385, 393, 443, 375, 10, 413, 10, 10, 415, 10, 10, 437, 10,
+            421, 10, 415, 10, 10, 444, 393, 10, 445, 385, 10, 376, 375, 446, 447, 448, 449, 10, 375,
+            10, 373, 10, 10, 374, 381, 10, 10, 10, 373, 10, 413, 385, 10, 393, 109, 450, 10, 10,
+            410, 10, 10, 10, 10, 203, 413, 406, 385, 397, 393, 451, 377, 10, 10, 376, 10, 10, 375,
+            381, 404, 10, 433, 415, 10, 10, 452, 415, 383, 453, 454, 10, 455, 10, 10, 385, 444, 375,
+            418, 10, 435, 10, 456, 457, 10, 415, 10, 10, 458, 10, 199, 10, 10, 383, 383, 10, 10, 10,
+            450, 413, 10, 413, 199, 10, 424, 10, 393, 10, 10, 393, 376, 10, 10, 10, 381, 10, 10, 10,
+            10, 10, 459, 109, 413, 10, 450, 375, 10, 460, 375, 374, 415, 450, 458, 461, 203, 374,
+            10, 462, 463, 10, 383, 413, 413, 10, 402, 464, 465, 466, 441, 10, 199, 377, 427, 467,
+            10, 468, 10, 10, 469, 13, 10, 10, 470, 203, 10, 453, 109, 437, 10, 10, 10, 199, 10, 374,
+            375, 10, 471, 10, 472, 10, 109, 381, 415, 199, 385, 10, 473, 10, 413, 10, 458, 415, 374,
+            376, 109, 474, 10, 109, 10, 10, 450, 383, 10, 10, 10, 10, 203, 373, 10, 385, 475, 10,
+            10, 376, 377, 448, 199, 413, 377, 450, 385, 476, 477, 10, 454, 478, 374, 109, 10, 450,
+            393, 450, 415, 385, 479, 480, 481, 10, 10, 10, 374, 10, 10, 109, 482, 413, 377, 383,
+            377, 415, 10, 382, 10, 10, 10, 385, 10, 10, 374, 10, 448, 373, 377, 448, 10, 10, 10, 10,
+            381, 381, 375, 10, 483, 10, 10, 10, 484, 485, 387, 413, 10, 377, 10, 377, 486, 393, 415,
+            10, 10, 10, 393, 376, 10, 419, 10, 10, 10, 487, 10, 10, 109, 10, 488, 10, 199, 415, 468,
+            10, 421, 10, 468, 10, 10, 489, 10, 10, 490, 491, 492, 413, 493, 10, 415, 10, 450, 374,
+            10, 448, 406, 10, 199, 410, 10, 383, 375, 494, 375, 377, 376, 10, 495, 393, 379, 496,
+            448, 402, 497, 10, 10, 10, 10, 393, 450, 10, 10, 10, 10, 373, 385, 374, 10, 10, 376, 10,
+            10, 10, 377, 10, 10, 381, 10, 10, 498, 10, 374, 10, 441, 408, 374, 10, 393, 448, 499,
+            10, 10, 500, 10, 393, 10, 10, 10, 385, 10, 381, 501, 381, 426, 415, 10, 109, 199, 10,
+            377, 450, 10, 10, 10, 377, 10, 12, 502, 10, 376, 383, 10, 503, 434, 426, 109, 393, 10,
+            504, 10, 426, 203, 373, 10, 448, 10, 10, 375, 448, 450, 381, 505, 506, 12, 10, 10, 448,
+            375, 507, 10, 10, 375, 374, 10, 10, 10, 453, 373, 10, 508, 509, 414, 385, 493, 10, 109,
+            382, 510, 16, 450, 109, 377, 511, 392, 10, 10, 512, 10, 377, 400, 10, 10, 513, 10, 373,
+            63, 10, 514, 493, 10, 109, 515, 199, 203, 408, 10, 373, 376, 10, 10, 10, 10, 456, 462,
+            10, 10, 10, 413, 10, 393, 484, 10, 10, 516, 10, 375, 199, 495, 377, 10, 10, 517, 518,
+            457, 10, 10, 519, 10, 467, 413, 448, 374, 373, 520, 521, 10, 383, 376, 522, 523, 10,
+            374, 10, 10, 10, 393, 448, 10, 10, 383, 10, 10, 10, 10, 10, 10, 10, 10, 10, 459, 431,
+            109, 10, 10, 448, 524, 10, 448, 10, 499, 448, 10, 413, 10, 203, 377, 385, 10, 375, 10,
+            525, 448, 526, 447, 10, 203, 10, 10, 406, 10, 10, 12, 10, 10, 10, 10, 448, 428, 10, 10,
+            393, 10, 527, 385, 10, 376, 448, 10, 381, 10, 415, 10, 377, 10, 10, 373, 10, 10, 373,
+            377, 413, 385, 10, 385, 10, 415, 10, 10, 10, 10, 10, 450, 10, 10, 499, 10, 10, 528, 10,
+            10, 10, 374, 10, 10, 376, 415, 10, 199, 376, 10, 383, 10, 415, 385, 10, 17, 377, 385,
+            10, 203, 10, 376, 10, 529, 377, 10, 450, 530, 531, 532, 10, 199, 10, 199, 10, 415, 109,
+            373, 428, 10, 10, 415, 533, 383, 10, 383, 203, 383, 415, 10, 10, 381, 450, 109, 10, 10,
+            10, 413, 10, 385, 199, 373, 383, 413, 199, 534, 10, 413, 10, 10, 10, 10, 10, 10, 535,
+            10, 536, 10, 448, 10, 10, 10, 415, 421, 383, 478, 10, 537, 10, 10, 450, 538, 510, 437,
+            539, 383, 393, 10, 448, 467, 374, 203, 203, 199, 10, 385, 377, 10, 10, 10, 109, 540, 10,
+            10, 541, 515, 542, 393, 10, 10, 10, 10, 10, 10, 10, 10, 10, 374, 380, 543, 10, 527, 544,
+            393, 375, 10, 393, 545, 109, 199, 381, 546, 10, 10, 10, 10, 10, 377, 10, 10, 375, 10,
+            385, 10, 415, 10, 426, 10, 10, 373, 439, 10, 450, 547, 10, 393, 373, 548, 404, 109, 10,
+            385, 203, 10, 383, 413, 381, 10, 10, 10, 10, 383, 448, 376, 495, 10, 199, 10, 10, 10,
+            549, 199, 428, 10, 10, 10, 10, 10, 393, 10, 13, 373, 10, 375, 413, 10, 10, 13, 10, 109,
+            10, 393, 10, 520, 109, 203, 448, 10, 550, 10, 10, 536, 373, 10, 376, 10, 551, 10, 404,
+            450, 10, 383, 10, 419, 373, 10, 10, 377, 10, 389, 10, 10, 552, 553, 426, 377, 417, 393,
+            10, 10, 385, 376, 373, 554, 448, 10, 555, 10, 377, 393, 10, 10, 10, 10, 10, 10, 10, 10,
+            10, 10, 10, 10, 10, 10, 10, 556, 415, 199, 10, 557, 381, 477, 10, 10, 10, 10, 558, 450,
+            10, 16, 10, 559, 560, 561, 444, 415, 10, 10, 562, 377, 10, 563, 10, 564, 565, 199, 376,
+            415, 408, 393, 109, 566, 385, 373, 10, 377, 10, 203, 567, 568, 453, 375, 545, 10, 10,
+            10, 10, 199, 413, 10, 569, 570, 10, 109, 10, 393, 448, 411, 10, 10, 10, 10, 10, 203, 10,
+            10, 571, 415, 10, 572, 10, 393, 10, 573, 376, 10, 10, 10, 109, 383, 374, 199, 574, 385,
+            575, 419, 10, 576, 385, 533, 373, 373, 577, 109, 10, 10, 373, 10, 10, 375, 578, 10, 10,
+            10, 579, 381, 404, 393, 10, 10, 10, 10, 10, 10, 10, 10, 13, 381, 199, 515, 10, 10, 383,
+            10, 10, 415, 450, 383, 385, 10, 413, 377, 450, 580, 10, 10, 10, 10, 10, 393, 203, 581,
+            582, 381, 10, 10, 377, 381, 376, 404, 375, 10, 415, 376, 383, 10, 10, 10, 583, 29, 29,
+            29, 29, 29, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90,
+            90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 246,
+            1, 1, 1, 1, 90, 90, 237, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90,
+            246, 584, 239, 1, 57, 57, 585, 586, 57, 587, 90, 90, 90, 90, 90, 588, 1, 262, 294, 57,
+            57, 57, 57, 589, 590, 591, 592, 593, 594, 595, 1, 596, 90, 90, 597, 212, 98, 98, 98,
+            222, 90, 90, 90, 90, 234, 239, 57, 598, 90, 90, 599, 98, 98, 222, 57, 600, 90, 90, 90,
+            90, 601, 239, 602, 603, 90, 90, 90, 604, 237, 239, 29, 605, 90, 90, 90, 90, 606, 607,
+            90, 608, 609, 610, 319, 57, 57, 611, 612, 90, 90, 90, 90, 90, 90, 90, 613, 239, 10, 10,
+            10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+            10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+            10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 614, 57, 615, 57, 57, 616, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 257, 257, 257, 257, 257, 257, 257, 257,
+            257, 257, 257, 257, 257, 257, 257, 257, 617, 618, 619, 257, 257, 257, 620, 257, 257,
+            257, 257, 257, 257, 621, 1, 1, 622, 623, 257, 624, 625, 257, 257, 257, 257, 257, 257,
+            626, 1, 627, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257,
+            257, 257, 257, 257, 257, 257, 620, 1, 257, 257, 257, 257, 628, 257, 257, 629, 1, 1, 292,
+            630, 621, 244, 257, 631, 632, 633, 634, 257, 257, 257, 257, 257, 257, 257, 635, 334,
+            257, 257, 257, 257, 257, 257, 257, 257, 257, 636, 291, 637, 638, 639, 0, 640, 98, 641,
+            642, 99, 99, 1, 1, 98, 98, 98, 98, 98, 98, 98, 217, 1, 1, 1, 1, 57, 57, 57, 643, 644,
+            645, 646, 1, 1, 1, 1, 647, 1, 1, 1, 1, 1, 1, 1, 1, 98, 648, 98, 98, 98, 649, 650, 1, 98,
+            98, 651, 98, 217, 98, 98, 217, 98, 99, 98, 98, 652, 653, 1, 1, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 99, 232, 90, 90, 654, 90, 100, 98, 98, 655, 98, 98, 98, 222, 656, 656, 657,
+            658, 659, 98, 98, 98, 222, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 660, 98, 661, 655, 1, 662, 257, 257, 663, 1, 1, 1, 1, 664, 98, 98, 665,
+            98, 661, 98, 660, 98, 666, 1, 1, 1, 1, 98, 667, 98, 661, 98, 232, 98, 232, 1, 1, 98, 98,
+            98, 668, 1, 1, 1, 1, 669, 670, 98, 671, 1, 1, 98, 648, 98, 648, 1, 1, 672, 98, 660, 1,
+            98, 98, 98, 661, 98, 661, 98, 673, 98, 674, 1, 1, 1, 1, 1, 1, 98, 98, 98, 98, 219, 1, 1,
+            1, 98, 98, 98, 673, 98, 98, 98, 673, 90, 90, 248, 239, 98, 98, 675, 98, 661, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 41, 644, 98, 98, 676, 674, 677, 678, 1, 679, 98, 648, 680,
+            98, 98, 649, 1, 98, 661, 1, 1, 98, 221, 1, 98, 660, 98, 98, 98, 98, 660, 1, 681, 220,
+            98, 98, 98, 217, 682, 98, 219, 232, 90, 90, 90, 683, 684, 98, 98, 685, 98, 98, 98, 98,
+            686, 687, 688, 689, 98, 658, 98, 668, 674, 1, 1, 1, 690, 691, 219, 98, 98, 98, 217, 232,
+            692, 693, 694, 695, 696, 697, 698, 221, 699, 98, 98, 700, 701, 222, 702, 1, 90, 90, 90,
+            90, 101, 703, 588, 1, 98, 98, 98, 98, 704, 232, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 98, 98,
+            98, 705, 649, 706, 1, 1, 98, 98, 98, 98, 707, 232, 1, 1, 98, 98, 98, 219, 232, 29, 708,
+            1, 98, 709, 240, 232, 660, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 98, 98, 98, 217, 1, 1, 1, 1,
+            1, 1, 98, 98, 98, 98, 232, 710, 711, 712, 98, 713, 222, 232, 1, 1, 1, 1, 714, 98, 98,
+            714, 715, 1, 98, 98, 98, 666, 680, 98, 98, 98, 98, 716, 1, 90, 98, 98, 98, 219, 212, 1,
+            1, 1, 1, 1, 655, 1, 1, 1, 1, 1, 98, 98, 649, 232, 694, 98, 98, 641, 649, 232, 1, 717,
+            98, 717, 672, 660, 1, 1, 1, 1, 718, 98, 98, 719, 655, 232, 720, 98, 666, 721, 232, 98,
+            98, 240, 232, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 98, 660, 98, 722, 98, 218,
+            673, 217, 1, 1, 1, 1, 1, 601, 41, 41, 41, 723, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 232, 1, 1, 1, 1, 1, 1, 98, 98, 98, 98, 98, 98, 666, 1, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 222, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 98, 98, 98, 98, 98, 98, 649, 98,
+            98, 98, 1, 98, 661, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 217, 98, 98, 98,
+            98, 660, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 98, 98, 98, 232, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 90, 90, 90, 724, 1, 1, 1, 98, 98, 98, 98, 666, 232, 98, 99, 221, 98, 98,
+            98, 660, 222, 232, 725, 726, 98, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 98, 98, 648, 232, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 98, 98,
+            98, 98, 1, 1, 98, 727, 98, 222, 1, 1, 90, 90, 90, 90, 728, 90, 90, 90, 729, 90, 1, 1, 1,
+            1, 730, 731, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 661, 1, 710, 98, 666,
+            1, 1, 1, 1, 1, 1, 98, 98, 98, 98, 98, 98, 98, 673, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 732, 57, 57, 57, 57, 57, 57,
+            57, 57, 57, 57, 57, 57, 57, 57, 57, 57, 57, 57, 733, 734, 1, 735, 736, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 240, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 98, 98, 98, 98, 98, 98, 217, 648, 219, 737,
+            738, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 739, 257, 740, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 741, 41, 646, 41,
+            646, 27, 27, 244, 27, 742, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 743, 744, 745, 746, 1, 747, 748, 749, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 41, 750, 41, 750, 1, 1, 1, 1, 1, 1, 41, 678, 1, 1, 1, 1,
+            1, 1, 1, 1, 257, 257, 257, 257, 257, 751, 257, 257, 257, 752, 753, 754, 755, 257, 257,
+            257, 756, 757, 257, 758, 759, 760, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257,
+            257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 761, 257, 257, 257, 257, 257, 257,
+            257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 762, 257, 257, 257, 98, 98, 98,
+            763, 98, 98, 648, 764, 765, 766, 216, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 27, 767, 768, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 641, 727, 769, 257, 257, 257, 620, 1, 770, 1, 1, 1, 1, 1, 1, 1,
+            90, 90, 246, 237, 771, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 98,
+            666, 1, 90, 90, 90, 239, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 98, 98, 232, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 98, 98, 217,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 98, 666, 98, 772, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 773, 774, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 221, 660, 1, 1, 90,
+            90, 90, 90, 100, 239, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 688, 41, 41,
+            41, 689, 1, 1, 1, 1, 688, 41, 41, 321, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 755, 257,
+            775, 776, 777, 778, 779, 780, 781, 782, 783, 782, 1, 1, 1, 294, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 41, 41, 310, 41, 41, 41, 41, 41, 41, 750, 644, 688, 688, 688,
+            41, 784, 785, 257, 786, 257, 257, 41, 787, 41, 41, 304, 321, 1, 1, 1, 788, 41, 789, 257,
+            257, 782, 790, 626, 784, 1, 1, 1, 1, 1, 1, 1, 1, 1, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 644, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 791, 645, 645, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 212, 310, 646, 310, 41, 41, 41, 792, 212, 41, 41, 792, 41, 321, 310, 294,
+            678, 1, 1, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 792, 321, 645, 793, 41, 41, 41, 794, 795, 796, 678, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 797, 41, 41, 41, 41, 41, 798, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 342, 29, 29, 359, 29, 29, 29,
+            352, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 340, 29, 347, 29, 355, 29, 29,
+            344, 799, 29, 29, 29, 799, 29, 29, 29, 29, 29, 800, 29, 29, 29, 29, 29, 29, 29, 29, 801,
+            29, 29, 29, 29, 29, 29, 339, 29, 362, 363, 29, 29, 339, 29, 802, 29, 29, 803, 29, 358,
+            29, 29, 29, 29, 29, 29, 804, 29, 29, 346, 29, 29, 29, 29, 29, 29, 358, 344, 805, 29, 29,
+            29, 29, 806, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 352, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 339, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 799, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 799, 29, 29, 29, 29, 29, 29, 340, 29,
+            29, 29, 29, 29, 29, 29, 346, 29, 29, 29, 29, 29, 29, 29, 29, 369, 29, 29, 29, 29, 29,
+            29, 363, 29, 29, 29, 347, 29, 29, 29, 358, 29, 339, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 340, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 339,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 340, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 347, 29, 29, 346, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 357, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 347, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 358, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 807, 29, 29, 29, 342, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 362, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 339, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 363, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 364, 363, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 347, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 1, 1, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 808, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 808, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 809, 29, 29, 29,
+            29, 29, 29, 808, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257,
+            257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257,
+            620, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            810, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 811, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 812, 1, 630, 630, 630,
+            630, 630, 630, 1, 1, 1, 1, 1, 1, 1, 1, 630, 630, 630, 630, 630, 630, 630, 630, 630, 630,
+            630, 630, 630, 630, 630, 1
+        ],
+        leaves: &[
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 5, 5, 5, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 5, 5, 5, 5, 5, 5, 5, 11, 5, 5, 5, 5, 5, 11, 11, 5, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 11, 5, 5, 5, 5, 5, 5, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 5, 5, 5, 5, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 5, 5, 5, 5, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            4, 5, 5, 5, 5, 5, 5, 5, 4, 5, 4, 5, 5, 3, 5, 4, 5, 5, 4, 4, 4, 4, 5, 11, 4, 4, 4, 5, 4,
+            4, 4, 5, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 5, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 9, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 9, 12, 12, 12,
+            12, 4, 4, 12, 12, 12, 12, 7, 12, 12, 12, 12, 12, 12, 4, 4, 12, 12, 12, 12, 12, 12, 12,
+            12, 2, 12, 12, 12, 12, 9, 9, 12, 12, 12, 12, 12, 12, 9, 9, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 9, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 4, 8, 12, 9, 9, 9, 9, 12, 9, 9, 12, 12, 9, 9, 0,
+            12, 12, 12, 12, 12, 9, 12, 9, 12, 12, 12, 12, 9, 9, 9, 12, 9, 9, 12, 12, 9, 9, 9, 9, 9,
+            9, 9, 9, 0, 0, 9, 9, 9, 12, 12, 9, 12, 12, 12, 9, 9, 12, 9, 7, 0, 0, 9, 9, 0, 7, 8, 8,
+            8, 8, 4, 4, 4, 4, 4, 4, 4, 4, 4, 12, 12, 12, 12, 12, 12, 12, 12, 9, 9, 9, 9, 9, 9, 9, 9,
+            12, 9, 9, 9, 9, 9, 9, 9, 9, 12, 12, 12, 12, 9, 9, 9, 9, 12, 12, 9, 4, 4, 4, 9, 9, 7, 7,
+            12, 12, 9, 9, 9, 9, 9, 9, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+            8, 8, 8, 12, 12, 12, 12, 7, 7, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+            9, 9, 9, 8, 8, 8, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 9, 9, 9, 9, 9, 9, 9, 12,
+            12, 9, 9, 8, 8, 8, 12, 12, 8, 12, 12, 8, 12, 8, 12, 8, 8, 8, 8, 8, 8, 8, 12, 8, 8, 8, 8,
+            12, 12, 8, 8, 8, 8, 8, 8, 8, 8, 12, 8, 8, 8, 8, 0, 8, 8, 8, 8, 0, 8, 8, 8, 8, 8, 8, 8,
+            8, 8, 8, 8, 8, 12, 8, 12, 8, 8, 8, 8, 8, 8, 12, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 0, 8,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 8, 8, 11, 11, 8, 8, 8, 8, 8, 5, 5, 5, 5, 8, 8, 8, 8, 8, 8, 8,
+            8, 8, 8, 8, 8, 5, 5, 5, 5, 5, 5, 4, 4, 4, 4, 4, 4, 5, 5, 4, 4, 4, 4, 4, 5, 5, 5, 5, 5,
+            0, 0, 8, 5, 8, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 12, 12, 12, 12, 12, 9,
+            12, 12, 12, 12, 12, 12, 12, 9, 8, 8, 8, 8, 8, 8, 8, 8, 9, 8, 8, 8, 8, 12, 8, 8, 8, 8, 8,
+            9, 9, 12, 8, 8, 12, 12, 12, 8, 8, 8, 8, 8, 8, 8, 8, 12, 9, 8, 9, 8, 9, 8, 8, 8, 8, 8, 8,
+            8, 8, 8, 4, 4, 8, 4, 4, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 3, 8, 8, 8, 8, 8, 8, 8, 8, 9, 8,
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 4, 0, 7,
+            7, 0, 0, 4, 7, 7, 7, 4, 7, 0, 0, 0, 0, 4, 4, 12, 4, 12, 12, 12, 0, 12, 0, 12, 12, 12,
+            12, 0, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 8, 4, 4, 4, 4, 4, 4, 4, 8, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 4, 4, 4, 0, 4, 4, 5, 7, 7, 4, 7, 7, 0, 7, 7,
+            7, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 7, 7, 7, 7, 7, 7, 7, 7,
+            7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 0, 7, 0, 0, 0, 0, 0, 0, 9, 9, 9, 9, 9, 9, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 7, 7, 12, 12, 12, 12, 12, 12, 12, 12, 7, 7, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 7, 7, 12, 12, 12, 12, 12, 12, 12, 9,
+            9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 9,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 9, 9, 9, 12, 12, 12, 12, 12,
+            12, 12, 12, 9, 9, 12, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+            7, 7, 7, 7, 7, 12, 12, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 0, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 0, 0, 8, 5, 5, 5, 5, 5, 5, 8,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            4, 8, 5, 11, 0, 0, 5, 5, 5, 0, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 0, 9,
+            9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 5, 9, 5,
+            9, 9, 5, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            0, 0, 0, 0, 9, 9, 9, 9, 11, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 9, 9, 9, 9, 9, 9, 9,
+            9, 9, 9, 5, 3, 5, 5, 5, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 7, 7, 12, 7, 7, 7,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 0, 12, 12, 9, 9,
+            9, 9, 9, 9, 9, 9, 9, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 5, 5, 5, 5, 7, 7, 12, 8,
+            12, 2, 12, 4, 4, 4, 4, 12, 12, 12, 12, 12, 12, 12, 7, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 9, 9, 9, 9, 12, 12, 9, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 7, 12, 12, 12, 12,
+            12, 9, 12, 9, 12, 12, 12, 9, 9, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 5, 12, 9, 9, 9,
+            9, 9, 9, 9, 5, 5, 9, 9, 9, 9, 9, 9, 8, 8, 9, 9, 5, 9, 9, 9, 9, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 9, 9, 9, 11, 11, 12, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+            10, 10, 10, 10, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 10, 10, 9, 12, 12, 9, 9,
+            9, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 9, 12, 12, 9, 9, 12, 12, 12, 9, 12, 9, 9, 9, 12,
+            12, 12, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 7, 7, 12, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 10, 10, 10, 10, 10, 10, 10, 10, 0, 0, 0, 10, 10, 10, 10, 10, 10, 10, 10,
+            10, 10, 10, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 0, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+            10, 10, 10, 0, 0, 0, 0, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 0, 0, 0, 0, 0, 8, 8,
+            8, 8, 8, 8, 8, 8, 0, 9, 9, 9, 9, 9, 7, 12, 5, 5, 0, 0, 0, 0, 0, 9, 9, 9, 9, 9, 9, 9, 9,
+            9, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 9, 9, 9, 7, 7, 7, 7, 7, 9, 9, 9, 7, 9, 9, 9,
+            9, 9, 12, 12, 12, 12, 12, 12, 12, 12, 9, 9, 9, 9, 12, 9, 8, 9, 9, 9, 9, 9, 9, 9, 9, 5,
+            9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 7,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 7, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 7, 12, 8, 7, 7, 8, 8, 9, 12, 12, 4, 4, 4, 4, 4, 4, 4, 4, 7, 7, 7, 7, 5,
+            5, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 5, 7, 12, 12, 12, 12, 12, 12, 7, 9, 9, 12,
+            12, 8, 12, 12, 7, 12, 12, 12, 0, 12, 12, 12, 12, 12, 12, 12, 9, 0, 0, 12, 12, 0, 0, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 0,
+            12, 12, 12, 12, 12, 12, 12, 0, 12, 0, 0, 0, 12, 12, 12, 12, 0, 0, 12, 7, 12, 12, 12, 12,
+            12, 12, 12, 0, 0, 12, 12, 0, 0, 12, 12, 12, 12, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0,
+            4, 4, 0, 4, 7, 7, 7, 7, 0, 0, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 5, 5, 5,
+            5, 5, 5, 5, 5, 5, 5, 7, 5, 9, 0, 0, 9, 12, 9, 0, 12, 12, 12, 12, 12, 12, 0, 0, 0, 0, 12,
+            12, 0, 12, 4, 0, 12, 4, 0, 12, 12, 0, 0, 12, 0, 12, 12, 12, 12, 12, 0, 0, 0, 0, 12, 12,
+            0, 0, 12, 12, 12, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 4, 4, 4, 12, 0, 4, 0, 0, 0, 0, 0, 0,
+            0, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 12, 9, 9, 8, 9, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9,
+            12, 12, 0, 12, 12, 12, 12, 12, 12, 12, 12, 12, 0, 12, 12, 0, 12, 12, 0, 12, 12, 12, 12,
+            12, 0, 0, 12, 7, 12, 12, 12, 12, 12, 12, 12, 12, 0, 12, 12, 12, 0, 12, 12, 12, 0, 0, 8,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 5, 0, 0, 0, 0, 0, 0, 0, 9, 9, 9, 9, 9,
+            9, 9, 0, 12, 12, 12, 0, 12, 12, 12, 12, 12, 12, 12, 9, 0, 0, 12, 12, 0, 12, 12, 0, 9,
+            12, 12, 12, 12, 0, 0, 12, 7, 12, 12, 12, 12, 12, 12, 9, 0, 0, 12, 12, 0, 0, 12, 12, 12,
+            0, 0, 0, 0, 0, 0, 0, 9, 12, 9, 0, 0, 0, 0, 4, 4, 0, 12, 7, 7, 9, 9, 0, 0, 9, 9, 9, 9, 9,
+            9, 9, 9, 9, 9, 5, 12, 5, 5, 5, 5, 5, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 12, 0, 12, 12,
+            12, 12, 12, 12, 0, 0, 0, 12, 12, 12, 0, 12, 12, 12, 12, 0, 0, 0, 12, 12, 0, 12, 0, 12,
+            12, 0, 0, 0, 12, 12, 0, 0, 0, 12, 12, 12, 0, 0, 0, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 0, 0, 0, 0, 12, 12, 12, 12, 12, 0, 0, 0, 12, 12, 12, 0, 12, 12, 12, 12, 0,
+            0, 8, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 0,
+            0, 0, 0, 0, 7, 9, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 9, 0, 12, 12, 12, 0, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 7, 12, 12, 12, 12, 12, 0,
+            0, 9, 7, 12, 12, 12, 12, 12, 12, 12, 0, 12, 12, 12, 0, 12, 12, 12, 12, 0, 0, 0, 0, 0, 0,
+            0, 9, 9, 0, 7, 7, 9, 0, 7, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 5, 5, 5, 5, 5, 5, 5, 5, 9,
+            7, 12, 12, 5, 12, 12, 12, 12, 12, 12, 12, 7, 0, 12, 12, 12, 7, 12, 12, 0, 12, 12, 12,
+            12, 12, 0, 0, 9, 7, 12, 12, 12, 12, 12, 12, 9, 0, 12, 12, 12, 0, 12, 12, 12, 12, 0, 0,
+            0, 0, 0, 0, 0, 9, 9, 0, 0, 0, 0, 0, 7, 9, 7, 0, 0, 7, 7, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 9, 7, 12, 12, 0, 12, 12, 12, 12, 12, 12, 12, 9, 0, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 7, 7, 7, 7, 12, 12, 12, 12, 12, 12, 9, 0, 12, 12, 12, 0, 12, 12, 7,
+            12, 7, 5, 0, 0, 0, 0, 9, 9, 9, 12, 5, 5, 5, 5, 5, 5, 5, 7, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+            12, 12, 12, 12, 12, 12, 0, 8, 12, 12, 0, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 0, 0,
+            12, 12, 12, 12, 12, 12, 0, 0, 0, 12, 12, 12, 12, 7, 12, 12, 12, 0, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 0, 12, 0, 0, 12, 12, 12, 12, 12, 12, 12, 0, 0, 0, 12, 0, 0, 0, 0, 12,
+            12, 12, 12, 12, 12, 0, 12, 0, 12, 12, 12, 12, 12, 12, 12, 0, 0, 0, 12, 0, 5, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 12, 12, 12, 4, 12, 12, 12, 12, 12, 12, 12, 0, 0, 0, 0, 5, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 5, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 5, 5, 0, 0, 0, 0, 0, 12, 12, 0, 12, 0, 9, 12, 12, 9, 12, 0, 9, 12, 9, 9, 9, 9,
+            9, 9, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 0, 12, 0, 12, 9, 9,
+            12, 12, 9, 12, 12, 8, 12, 12, 12, 4, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 0, 0, 12,
+            12, 12, 12, 12, 0, 12, 0, 12, 12, 12, 12, 12, 12, 9, 0, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 0, 0, 4, 4, 9, 9, 8, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 11, 4, 5, 5, 5, 5, 5, 5, 5,
+            5, 5, 5, 5, 8, 8, 5, 5, 5, 5, 5, 5, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 5, 5, 5, 5,
+            5, 5, 5, 5, 5, 5, 5, 8, 5, 8, 5, 9, 5, 5, 5, 5, 8, 8, 12, 12, 12, 4, 12, 12, 12, 12, 0,
+            12, 12, 12, 12, 4, 12, 12, 12, 12, 4, 12, 12, 12, 12, 4, 12, 12, 12, 12, 4, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 4, 0, 9, 9, 0, 0, 0, 0, 12, 12, 4, 12, 4, 4, 2, 4,
+            2, 12, 12, 12, 12, 12, 12, 12, 4, 0, 0, 12, 5, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 12, 12, 4,
+            12, 12, 12, 12, 4, 12, 12, 12, 12, 4, 12, 9, 9, 9, 12, 12, 12, 12, 12, 12, 12, 12, 4,
+            12, 12, 12, 0, 5, 5, 5, 5, 5, 5, 5, 5, 0, 5, 5, 5, 5, 5, 5, 0, 5, 5, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+            9, 9, 9, 9, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 9, 9, 9, 9, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 5, 5, 7, 7, 7,
+            7, 7, 7, 0, 12, 0, 0, 0, 0, 0, 12, 0, 0, 12, 7, 7, 7, 7, 7, 7, 9, 9, 0, 0, 5, 4, 9, 9,
+            9, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 3, 3, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+            7, 7, 7, 7, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 0, 12, 12, 12, 12, 0, 0, 12, 12, 12, 12, 12, 12, 12, 0, 12, 0,
+            12, 12, 12, 12, 0, 0, 12, 12, 12, 12, 12, 12, 12, 9, 12, 0, 12, 12, 12, 12, 0, 0, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 0, 12, 12, 12, 12, 0, 0,
+            12, 12, 12, 12, 12, 12, 12, 0, 12, 0, 12, 12, 12, 12, 0, 0, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 0, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 9, 9, 9, 9, 9, 9, 9, 9, 12, 0, 12, 12, 12, 12, 0, 0, 12, 12, 12, 12, 12, 12,
+            12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 0, 0, 9, 9, 9, 5, 5, 5, 5, 5, 5, 5, 5,
+            5, 7, 7, 7, 7, 7, 7, 7, 7, 7, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 0, 0, 0, 5, 5, 5, 5, 5,
+            5, 5, 5, 5, 5, 0, 0, 0, 0, 0, 0, 10, 10, 10, 10, 10, 10, 0, 0, 10, 10, 10, 10, 10, 10,
+            0, 0, 0, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+            10, 10, 10, 10, 10, 10, 10, 10, 0, 0, 10, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 0, 0, 0, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0,
+            0, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 0, 0, 0, 0, 0, 0, 6, 6, 6, 6, 6, 6, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 6, 6, 6, 6, 6, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 6, 6, 6, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 6, 6, 6, 0, 6,
+            6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 9, 9, 12, 12, 12, 12, 2, 2, 12, 12, 12, 7, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 3, 3, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 8, 8, 12, 0, 12, 7, 5, 5, 5, 9, 0, 5, 5, 5, 7, 0, 0, 0, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3,
+            3, 3, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 0, 0, 0, 0, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 6,
+            0, 0, 0, 0, 0, 10, 10, 10, 10, 10, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 10, 10, 10, 10,
+            10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 0, 0, 0, 0, 0, 0, 0, 10, 10, 10, 10, 10, 10, 10,
+            10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 0, 0, 10, 10, 10,
+            10, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 0, 0,
+            0, 0, 0, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0,
+            0, 0, 0, 0, 0, 0, 0, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 5, 8, 8, 9, 9, 9, 9, 9,
+            9, 9, 9, 9, 9, 9, 9, 9, 9, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 0, 0, 8, 8, 8,
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 0, 0, 0, 0, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+            10, 0, 0, 0, 10, 10, 10, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 10, 10, 10, 10, 10,
+            10, 10, 0, 0, 0, 0, 0, 0, 0, 0, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 0, 0, 0, 10, 10,
+            10, 7, 7, 7, 7, 7, 7, 7, 7, 7, 9, 9, 0, 0, 0, 0, 0, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 0, 0, 12, 12, 12, 7, 7, 7, 0, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+            7, 7, 7, 7, 7, 6, 0, 0, 0, 0, 0, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 4, 4, 4, 8, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 8, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 8,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 8,
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 4, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+            8, 8, 4, 4, 4, 4, 4, 0, 0, 0, 0, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 0, 8, 0, 0, 0, 0, 0, 0,
+            0, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 0, 8, 8, 8, 8, 8, 8, 8, 9,
+            9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 12, 9, 9, 9, 9, 12, 12, 9, 9, 9, 9, 8, 8, 8, 8, 9, 9, 9,
+            9, 12, 12, 9, 9, 12, 12, 9, 9, 9, 9, 8, 8, 8, 8, 9, 9, 9, 9, 9, 9, 9, 9, 12, 12, 9, 9,
+            9, 9, 12, 12, 12, 12, 9, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 9, 9, 9, 9, 9, 9,
+            9, 9, 9, 9, 9, 9, 9, 12, 12, 9, 9, 9, 9, 9, 9, 12, 12, 9, 9, 9, 9, 9, 9, 9, 9, 12, 12,
+            9, 9, 12, 12, 8, 8, 8, 8, 8, 8, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+            9, 12, 12, 9, 9, 9, 9, 12, 12, 9, 9, 9, 9, 9, 9, 4, 4, 8, 8, 12, 8, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 8, 8, 8, 8, 8, 8, 7, 7, 7, 7, 7, 7, 0, 0, 7, 7, 7, 7, 7, 7, 0, 0, 7,
+            7, 7, 7, 7, 7, 7, 7, 0, 7, 0, 7, 0, 7, 0, 7, 7, 4, 7, 4, 7, 4, 7, 4, 7, 4, 7, 4, 7, 4,
+            0, 0, 0, 0, 12, 12, 12, 0, 7, 7, 7, 7, 7, 4, 7, 4, 4, 4, 4, 4, 7, 7, 7, 0, 7, 7, 7, 4,
+            7, 4, 7, 4, 4, 4, 7, 7, 7, 4, 0, 0, 7, 7, 7, 7, 7, 4, 0, 4, 4, 4, 7, 7, 7, 4, 7, 7, 7,
+            7, 7, 7, 7, 4, 12, 4, 4, 4, 0, 0, 7, 7, 7, 0, 7, 7, 7, 4, 7, 4, 7, 4, 4, 0, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 3, 3, 3, 3, 3, 11, 4, 5, 5, 5, 5, 5, 4, 5, 11, 5, 5, 5, 5, 5, 5, 5,
+            5, 5, 5, 4, 4, 4, 11, 5, 5, 3, 3, 3, 3, 3, 4, 5, 5, 5, 4, 4, 5, 4, 4, 5, 5, 5, 5, 4, 5,
+            4, 8, 8, 5, 5, 5, 5, 5, 5, 4, 4, 4, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 9, 5, 0, 4, 0, 0, 0,
+            0, 0, 0, 0, 4, 3, 3, 3, 3, 3, 0, 3, 3, 3, 3, 2, 2, 2, 2, 2, 2, 4, 4, 0, 0, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 0, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 0, 0, 0, 5, 5, 5, 5, 5, 5, 5, 5, 4, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 0, 0,
+            0, 0, 8, 0, 0, 0, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 4, 4, 4, 4, 5, 4, 4, 4, 5, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 5, 4, 4, 5, 8, 4, 4, 4, 4, 4, 5, 5, 4, 4, 4, 5, 4, 5, 4, 0, 4,
+            5, 4, 4, 4, 4, 8, 4, 4, 4, 7, 4, 4, 4, 4, 4, 4, 4, 5, 4, 4, 4, 4, 4, 4, 5, 5, 5, 5, 4,
+            4, 4, 4, 4, 5, 5, 5, 5, 7, 0, 0, 0, 0, 0, 7, 7, 7, 7, 7, 4, 0, 0, 0, 0, 0, 0, 5, 5, 5,
+            5, 5, 5, 5, 5, 5, 5, 5, 5, 4, 4, 5, 4, 4, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+            5, 5, 5, 5, 5, 5, 5, 5, 5, 2, 2, 5, 5, 5, 5, 5, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 0, 0,
+            0, 0, 0, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 4, 5, 5, 5, 5, 5, 5, 5, 4, 4, 4, 5, 5, 5,
+            5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 0, 0, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+            5, 5, 5, 5, 5, 0, 0, 0, 0, 8, 8, 8, 8, 8, 8, 8, 8, 9, 9, 9, 9, 9, 7, 7, 7, 7, 7, 7, 7,
+            7, 7, 7, 8, 8, 8, 8, 8, 4, 4, 7, 7, 6, 6, 6, 6, 6, 0, 0, 0, 0, 0, 0, 6, 6, 6, 6, 6, 0,
+            0, 6, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 7, 7, 7, 7, 7, 0, 8, 0, 0, 0, 0, 0, 8,
+            0, 0, 10, 10, 10, 10, 10, 10, 10, 10, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 10, 9, 9, 9, 9, 9, 9, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 9, 9, 9, 9, 9,
+            9, 0, 9, 9, 9, 9, 9, 9, 9, 0, 0, 0, 0, 5, 5, 0, 5, 5, 5, 0, 5, 5, 0, 5, 5, 5, 5, 5, 5,
+            5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 0, 0, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 0, 5, 5, 5, 5, 4,
+            5, 5, 5, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 4, 4, 4, 4, 4, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 4, 5, 5, 5, 5, 12, 12, 12, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+            5, 5, 5, 5, 5, 0, 5, 5, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 0, 0, 5, 8, 8, 8, 8, 8,
+            4, 5, 4, 4, 4, 8, 8, 5, 5, 5, 12, 12, 12, 12, 12, 12, 12, 0, 0, 9, 9, 4, 4, 12, 12, 4,
+            11, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 11, 12, 12, 12, 4, 0, 0, 0, 0, 0, 10, 10, 10, 10, 10, 10, 10, 10,
+            10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 0, 10, 0, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5,
+            5, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 5, 5, 5, 5, 5, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 5, 4, 4, 4, 4, 4, 4, 4, 4, 5, 5, 5, 5, 5, 5, 5, 5, 9, 9, 9, 9, 9, 9, 9, 12, 9, 9, 9,
+            9, 9, 9, 9, 9, 9, 9, 9, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 9, 9, 9,
+            9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 9, 9, 12, 9, 9, 9,
+            9, 9, 9, 9, 9, 9, 9, 9, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 9, 9,
+            9, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 9, 9, 9,
+            9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 9, 9, 9, 9, 9, 9, 9, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+            9, 9, 9, 12, 9, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 9, 9, 9, 12, 9, 9, 9, 9, 9, 9, 9,
+            9, 12, 9, 9, 9, 9, 9, 12, 9, 9, 9, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 9, 9, 9, 9, 9, 9,
+            9, 9, 9, 9, 9, 9, 12, 9, 9, 9, 9, 9, 9, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+            9, 9, 9, 9, 9, 9, 9, 12, 9, 9, 9, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 9, 9, 9, 9, 9,
+            9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 9, 9, 9, 9, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+            9, 9, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 9, 9, 9, 9, 12, 9, 9, 9,
+            9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 9, 9, 9, 9,
+            9, 12, 9, 9, 9, 9, 9, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 12,
+            9, 9, 9, 9, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 12,
+            9, 9, 12, 9, 9, 9, 9, 12, 12, 9, 12, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+            9, 9, 9, 9, 12, 9, 9, 9, 12, 9, 9, 9, 9, 9, 9, 12, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+            9, 9, 9, 9, 12, 9, 9, 9, 9, 9, 12, 9, 9, 9, 12, 9, 9, 9, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+            9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 12, 12, 12, 12, 12, 12, 9, 9, 9, 9, 9, 9,
+            9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 12, 12, 12, 12, 12, 12, 9, 9, 9, 9, 9, 9, 12, 12, 9, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 9, 9, 12, 9,
+            12, 9, 12, 12, 9, 12, 12, 12, 9, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12,
+            9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 9, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12,
+            9, 12, 9, 12, 12, 12, 9, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 9, 12, 12, 12, 12, 9, 12, 12, 12, 12, 9, 12,
+            12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 9, 12, 12, 12,
+            12, 12, 12, 12, 9, 9, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 9, 12, 9, 9, 12, 12, 12, 12, 12, 12,
+            12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 9, 12, 12, 9, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12,
+            12, 12, 9, 12, 12, 12, 9, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 9, 9, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 9, 12, 12, 9, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 9, 12, 9, 12, 12, 12, 12, 12, 12, 12, 9, 9, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12,
+            12, 12, 12, 12, 9, 12, 12, 12, 9, 12, 12, 12, 9, 12, 9, 12, 12, 9, 12, 12, 9, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12,
+            12, 9, 12, 12, 12, 12, 9, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 9, 12, 12, 9, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 9, 12,
+            12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 9, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 9, 12, 12, 9, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 9, 9, 12, 12, 12,
+            9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 9, 9, 12, 12, 12, 12, 12, 12, 9, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12,
+            12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12,
+            12, 9, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 9, 12,
+            12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12,
+            12, 9, 12, 12, 12, 12, 12, 12, 12, 9, 12, 9, 12, 12, 9, 9, 12, 12, 12, 12, 9, 12, 12,
+            12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 9, 12, 9, 12, 12,
+            12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 9, 9, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 9, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 9, 9, 12, 12, 12, 12, 12, 12, 12,
+            12, 9, 12, 12, 9, 9, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 9, 12, 12, 12, 12,
+            9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 9, 12, 12, 9, 12, 12, 12, 12,
+            12, 12, 12, 12, 9, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9,
+            12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9,
+            12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 9, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12,
+            12, 12, 12, 9, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9,
+            12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12,
+            12, 9, 9, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 9, 12, 12, 12, 12, 9, 12, 12, 12, 9, 12, 12, 12, 12, 9, 12, 12, 12, 12,
+            9, 12, 12, 12, 12, 12, 12, 9, 12, 12, 9, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 9, 12, 12, 12, 9, 12, 12, 12, 9, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 9, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12,
+            12, 12, 9, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 9, 12, 9, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 9, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 9, 12, 12, 12, 9, 12, 12, 12, 12, 12,
+            12, 12, 9, 12, 12, 12, 9, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 9, 12, 9, 12, 9, 12, 12, 9, 9,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 9, 12, 12, 12, 12, 12, 9, 12,
+            12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 9, 12,
+            12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 9, 12, 12,
+            12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 9, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 9, 12, 12,
+            12, 12, 12, 12, 12, 12, 9, 9, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 9,
+            12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 9, 12, 12, 9, 12, 12, 9, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9,
+            12, 9, 12, 9, 12, 12, 9, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 9, 12, 12, 12, 12, 12, 9, 12, 9, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 9, 12, 12, 12, 9, 12, 12, 12,
+            9, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 9, 12, 12, 9, 9, 9, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 9, 12, 12, 12, 12, 9, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 9, 12, 12, 9, 12, 12, 12, 9, 12, 12, 12,
+            12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 9, 12, 12, 12, 12, 9, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 9, 12, 9, 12, 12, 9, 12, 12, 12, 9,
+            12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 9, 12, 12, 9, 12, 12, 12, 12, 12, 9, 12, 12,
+            12, 12, 12, 9, 9, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 9, 12, 9, 9, 12, 12, 12, 9, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 9, 9, 12, 12, 12, 12,
+            9, 12, 12, 12, 12, 12, 9, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9,
+            12, 9, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 9, 12, 12, 9, 12,
+            12, 12, 9, 9, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 9, 12, 12,
+            12, 12, 12, 12, 9, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 9, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 9,
+            12, 12, 12, 12, 12, 9, 12, 12, 12, 9, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 9, 12,
+            12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12,
+            12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 9, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 9, 12, 12, 9, 12, 12, 12, 12, 12, 9, 12, 12, 9,
+            12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 9, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 9, 9, 12, 12, 9, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 9, 12, 9, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 9, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 9, 12, 12, 12, 9, 12, 12, 12, 12, 9, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12,
+            9, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 9, 12,
+            12, 12, 12, 12, 12, 12, 9, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12,
+            12, 9, 12, 12, 12, 12, 9, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 9, 12, 9, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 9, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 9, 12, 9, 9, 9, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12,
+            12, 9, 12, 12, 12, 12, 12, 9, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12,
+            12, 9, 12, 12, 12, 12, 12, 12, 9, 9, 12, 12, 12, 12, 12, 9, 12, 9, 12, 9, 9, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 9, 12, 12, 9, 12, 12, 12,
+            12, 12, 12, 12, 9, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12,
+            9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 9, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 9, 9, 9, 12, 12, 12, 12, 12,
+            12, 9, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12,
+            12, 9, 12, 12, 12, 12, 12, 12, 12, 9, 9, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 9, 12, 9, 9, 12, 12, 12,
+            12, 12, 12, 12, 9, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12,
+            12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 9, 9, 12, 9, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12,
+            9, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 9, 12, 12, 12, 9, 12, 12, 9, 12, 12, 12, 12, 12, 12, 9, 12, 12, 9, 12, 12, 12,
+            9, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 9, 12, 12, 12,
+            9, 12, 12, 12, 12, 12, 12, 9, 9, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 9, 12,
+            12, 12, 9, 12, 12, 9, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12,
+            9, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 9, 12, 9, 9, 12, 12, 12, 12, 12, 12,
+            9, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 9, 12, 9,
+            12, 9, 9, 9, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12,
+            9, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 9, 12, 12, 12, 12, 12,
+            12, 12, 12, 9, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 9, 12, 9, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 9, 9, 12, 12, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 9, 12, 12, 12, 9, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 9, 9, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 9, 12, 12, 9, 12, 12, 12,
+            9, 12, 12, 12, 9, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12,
+            12, 9, 12, 12, 12, 12, 9, 12, 9, 9, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 9, 12, 12, 12, 12, 9, 12, 12, 12, 12, 9,
+            9, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 9, 12, 12, 9, 12,
+            12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 9, 12, 12, 12, 9, 12, 9,
+            9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12,
+            12, 9, 12, 12, 9, 12, 9, 12, 12, 12, 12, 9, 12, 9, 9, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 9, 12, 12, 9, 12, 12, 9, 9, 12, 12, 12, 12, 12, 12, 12, 9, 9, 12, 12, 9,
+            12, 12, 12, 12, 12, 12, 12, 12, 9, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12,
+            12, 9, 9, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 9,
+            12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 9, 12, 12,
+            12, 9, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 9, 12, 12,
+            12, 12, 12, 12, 9, 12, 12, 9, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12,
+            9, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 9, 9, 12, 12, 12, 12, 12, 12, 12, 12, 9,
+            12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 9, 12, 12, 9, 12, 12, 12, 9, 12, 12, 12, 9,
+            9, 12, 12, 12, 12, 12, 12, 9, 12, 9, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 9, 12, 12, 9, 12, 12, 12, 9, 9, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12,
+            12, 12, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 9, 12, 12, 12,
+            12, 12, 12, 9, 12, 12, 12, 12, 12, 9, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 9, 12,
+            12, 12, 12, 12, 9, 12, 12, 12, 9, 12, 12, 12, 12, 12, 12, 12, 12, 9, 9, 12, 12, 12, 12,
+            9, 12, 9, 12, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 0, 0, 0, 10, 10, 10, 10, 10, 10, 10, 10,
+            10, 10, 10, 10, 10, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 9, 0, 0, 0, 0, 7, 7, 7,
+            7, 7, 7, 7, 7, 9, 9, 5, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 4, 4, 0, 7, 10, 10, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+            7, 7, 7, 7, 7, 7, 7, 8, 5, 5, 9, 9, 12, 8, 9, 7, 7, 9, 9, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+            7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 12, 7, 7, 7, 8, 8, 7, 7, 9, 9, 9, 9, 9, 9, 9, 9, 8,
+            8, 8, 8, 8, 8, 7, 7, 9, 9, 7, 8, 8, 7, 7, 7, 7, 9, 9, 9, 9, 9, 7, 7, 7, 7, 7, 7, 7, 7,
+            7, 7, 9, 9, 9, 0, 0, 0, 0, 4, 4, 4, 4, 7, 7, 7, 4, 4, 8, 7, 7, 7, 7, 7, 10, 10, 10, 10,
+            10, 10, 10, 10, 0, 0, 0, 0, 10, 0, 0, 0, 7, 7, 7, 7, 7, 7, 7, 7, 0, 0, 0, 7, 0, 0, 7, 7,
+            10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 5, 0, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+            7, 7, 7, 7, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 7, 7, 7, 7, 7,
+            7, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 0, 10, 10,
+            10, 10, 10, 10, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 9, 9, 9, 9, 9, 9, 5, 5, 5, 9, 12, 9,
+            9, 9, 9, 10, 10, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 10, 10, 10, 0, 0, 0, 0, 10, 10, 10, 10, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 9, 9,
+            9, 9, 9, 0, 0, 9, 9, 9, 9, 9, 9, 0, 0, 9, 9, 9, 9, 9, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7,
+            7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 5, 4, 4, 4, 4, 9, 9, 9, 9, 7, 7, 9, 9, 8, 4, 5, 5, 0, 0,
+            0, 0, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 0, 10, 10, 0, 0, 12, 12, 12, 12, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 7, 7, 7, 7, 7, 7, 0, 0, 0, 0, 7, 7, 7, 7, 7, 7, 7, 7,
+            7, 7, 7, 7, 7, 7, 7, 7, 7, 0, 0, 0, 0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 9, 9,
+            4, 9, 4, 9, 9, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 9, 4, 9, 4, 9, 9, 4, 4, 9, 9, 9, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 0, 0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            0, 0, 0, 0, 0, 0, 4, 4, 4, 4, 4, 4, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 4, 4, 4,
+            4, 0, 0, 0, 0, 0, 4, 0, 4, 4, 4, 4, 4, 4, 4, 4, 0, 4, 4, 4, 4, 4, 0, 4, 0, 4, 4, 0, 4,
+            4, 0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 0, 0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 4, 4, 4, 4, 4, 0, 0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 0, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 0, 4, 4, 4, 4, 0, 0, 0, 0, 4, 4, 4, 8, 4,
+            0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 0, 0, 3, 3, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 0, 0, 4, 4, 4, 4, 4, 4, 0, 0, 4, 4, 4, 4, 4,
+            4, 0, 0, 4, 4, 4, 4, 4, 4, 0, 0, 4, 4, 4, 0, 0, 0, 4, 4, 4, 4, 4, 4, 4, 0, 4, 4, 4, 4,
+            4, 4, 4, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 6,
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 6, 6, 0, 6, 7, 7, 7, 7, 7, 5,
+            5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 0, 5, 5, 5,
+            5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 6, 6, 6, 6, 6, 6, 0, 0, 0,
+            0, 6, 6, 6, 6, 6, 6, 6, 6, 0, 6, 6, 6, 6, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 10, 10,
+            10, 0, 0, 0, 0, 10, 10, 10, 10, 10, 10, 10, 10, 6, 6, 6, 6, 6, 6, 6, 6, 0, 0, 0, 0, 0,
+            0, 0, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 6, 6, 6, 6, 6, 6, 6, 0, 6, 6, 0, 6, 6, 6,
+            6, 6, 6, 6, 6, 6, 6, 6, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 6, 6, 6, 6,
+            6, 6, 6, 0, 6, 6, 0, 0, 0, 6, 6, 6, 6, 6, 6, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 6, 6, 6,
+            6, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 4, 4, 4, 4, 4, 0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 0, 0, 0, 0, 0, 6, 6, 6, 6, 6, 6, 0, 0, 6, 0, 6, 6, 6, 6,
+            6, 6, 6, 6, 6, 6, 6, 6, 0, 6, 6, 0, 0, 0, 6, 0, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 6, 6, 0, 6, 6, 6, 0, 6, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 6, 6, 6, 6, 6, 6, 6,
+            0, 0, 0, 0, 0, 0, 6, 6, 6, 6, 6, 6, 0, 6, 6, 0, 0, 0, 0, 0, 6, 6, 6, 6, 6, 6, 6, 6, 0,
+            6, 6, 6, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 0, 6, 6, 6, 0, 0, 0, 0, 6, 6, 6,
+            6, 6, 6, 6, 6, 6, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 6, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 6, 6, 6, 6, 6, 0, 0, 0, 6, 6, 6,
+            6, 6, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 6, 6, 0, 0, 0, 0, 0, 9, 9, 9, 8, 8, 9, 0,
+            0, 0, 0, 0, 0, 0, 0, 5, 5, 5, 5, 5, 5, 5, 5, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 9, 8, 9, 9, 9, 9, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            10, 10, 10, 10, 10, 0, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 0, 0, 0, 0, 10, 10, 10,
+            10, 0, 0, 0, 0, 0, 0, 0, 0, 6, 6, 6, 6, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 6, 6, 6,
+            6, 0, 0, 0, 0, 6, 6, 6, 6, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 6, 0, 0, 0, 0,
+            5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 6, 6, 6, 6, 6, 6, 6, 0, 6, 0, 6, 6, 6, 6, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 6, 0, 6, 6, 12, 6, 12, 0, 6, 6, 6, 6, 6, 6, 6, 6, 0, 0, 6, 6, 0, 0, 6, 6, 6, 6, 6,
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 6, 6, 6, 6, 6, 6, 6, 0, 6, 6, 0,
+            6, 6, 6, 6, 6, 0, 9, 12, 6, 6, 6, 6, 6, 6, 6, 6, 0, 0, 6, 6, 0, 0, 6, 6, 6, 0, 0, 6, 0,
+            0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 6, 6, 6, 6, 6, 6, 6, 0, 0, 6, 6, 6, 6, 6, 6, 6, 0, 0,
+            0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 6, 0, 0, 6, 0, 6, 6, 6, 6, 6, 6, 0, 6, 6, 6, 6, 6,
+            6, 6, 6, 6, 6, 0, 6, 0, 0, 6, 0, 6, 6, 6, 6, 0, 6, 6, 6, 6, 0, 6, 6, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 0, 0, 0, 0, 10, 10, 6, 6,
+            6, 6, 6, 6, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 6, 6, 6, 6, 6, 6, 0, 0, 6, 6, 6, 6, 6, 6, 6,
+            6, 0, 0, 0, 0, 0, 0, 0, 0, 6, 6, 6, 6, 6, 6, 0, 0, 6, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 9, 9, 9, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 0, 0, 6, 6, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 6, 6, 6, 6, 6, 6,
+            6, 0, 0, 6, 0, 0, 6, 6, 6, 6, 6, 6, 6, 6, 0, 6, 6, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 6, 0, 6, 6, 0, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 0, 6, 6, 6, 6, 6, 6,
+            6, 6, 0, 6, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 0, 0,
+            6, 0, 0, 0, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 6, 6,
+            0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 0, 0, 6, 0, 6, 6, 0, 6, 6, 6, 6, 6, 6, 6, 0,
+            6, 6, 0, 6, 6, 6, 6, 6, 6, 6, 6, 0, 6, 6, 6, 6, 6, 6, 0, 0, 0, 0, 0, 0, 0, 6, 0, 6, 6,
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 5, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 10,
+            10, 10, 10, 10, 10, 10, 10, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 0, 0, 0, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            0, 0, 6, 6, 6, 6, 6, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 0, 0, 0, 0, 10, 10, 10,
+            10, 10, 10, 10, 10, 10, 0, 0, 0, 0, 0, 0, 0, 10, 6, 6, 5, 7, 6, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 7, 7, 8, 8, 8, 8, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 9, 9, 9, 0, 9, 9, 9, 9, 9,
+            9, 9, 0, 9, 9, 0, 7, 7, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 7, 7, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            7, 7, 7, 7, 0, 0, 0, 0, 0, 0, 0, 0, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 0, 0, 6, 6, 0, 3,
+            3, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 5, 5, 5, 5, 5, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 5, 5, 0, 0, 0, 5, 5, 5, 5, 0, 0, 0, 0, 0, 0, 5,
+            5, 5, 5, 5, 5, 8, 8, 8, 8, 8, 8, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 4, 4, 4, 4, 4, 4, 4, 8, 8, 8, 8, 8, 0, 0, 0, 8, 8, 8, 8, 8, 8, 3, 3,
+            3, 3, 3, 3, 3, 3, 8, 8, 8, 8, 8, 8, 8, 8, 0, 0, 8, 8, 8, 8, 8, 8, 8, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 8, 8, 8, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 4, 4, 4,
+            4, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 5, 5, 5, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 4, 4, 4, 4, 4, 0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 0, 4, 4, 0, 0, 4, 0, 0, 4, 4, 0, 0, 4, 4, 4, 4, 0, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 0, 4, 0, 4, 4, 4, 4, 4, 4, 4, 0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 0, 4, 4, 4, 4, 0, 0, 4, 4, 4, 4, 4, 4, 4, 4, 0, 4, 4, 4, 4, 4, 4, 4, 0, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 0, 4, 4, 4, 4, 0, 4, 4, 4, 4, 4, 0, 4, 0, 0, 0, 4, 4, 4,
+            4, 4, 4, 4, 0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 0, 0, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 0, 0, 4, 4, 6, 6, 6, 6, 6, 6, 6,
+            0, 0, 0, 0, 6, 6, 6, 6, 6, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 6, 6, 6, 6, 8,
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 0, 0, 0, 0, 0, 0, 8, 8, 8, 8, 8, 8, 0, 0, 0,
+            0, 0, 6, 6, 0, 6, 6, 0, 6, 6, 6, 6, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 7, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 0, 0, 0, 0, 10, 0, 6, 6, 6, 6, 6,
+            6, 0, 0, 0, 0, 0, 0, 0, 0, 6, 6, 12, 12, 12, 12, 12, 12, 12, 0, 12, 12, 12, 12, 0, 12,
+            12, 0, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 0, 0, 4, 4, 0, 4, 0,
+            0, 4, 0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 0, 4, 4, 4, 4, 0, 4, 0, 4, 0, 0, 0, 0, 0, 0, 4,
+            0, 0, 0, 0, 4, 0, 4, 0, 4, 0, 4, 4, 4, 0, 4, 4, 0, 4, 0, 0, 4, 0, 4, 0, 4, 0, 4, 0, 4,
+            0, 4, 4, 0, 4, 0, 0, 4, 4, 4, 4, 0, 4, 4, 4, 4, 4, 4, 4, 0, 4, 4, 4, 4, 0, 4, 4, 4, 4,
+            0, 4, 0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 0, 0, 0, 0, 0, 4, 4, 4, 0, 4, 4, 4, 4, 4, 0, 4, 4, 4, 4, 4, 5, 5, 5, 5, 5, 5, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 5, 5, 5, 5, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 4, 4, 4, 5, 5, 5, 0,
+            0, 0, 0, 0, 0, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 4, 4, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 0, 0, 0, 0, 0, 0, 0, 5, 5, 5, 5, 5, 5, 5, 5, 5, 0, 0,
+            0, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 0, 0, 0, 0, 0, 0, 0, 0, 5, 5, 5, 5, 5, 5, 5, 5,
+            5, 5, 5, 0, 0, 0, 5, 5, 5, 5, 5, 5, 5, 5, 5, 0, 5, 0, 0, 0, 0, 5, 5, 5, 5, 5, 5, 5, 5,
+            5, 5, 5, 5, 5, 5, 5, 5, 0, 0, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 0, 0, 0, 0, 5, 5, 5,
+            5, 0, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 0, 0, 0, 0,
+            0, 9, 9, 9, 9, 9, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+            12, 9, 9, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 12, 9, 9, 9, 9, 9, 9, 9, 9,
+            9, 12, 12, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 12, 12, 9, 9, 9, 9, 9,
+            12, 9, 9, 9, 9, 9, 9, 9, 12, 9, 9, 9, 9, 9, 9, 9, 9, 12, 12, 9, 9, 9, 9, 12, 9, 9, 9, 9,
+            9, 12, 9, 9, 9, 9, 9, 9, 9, 12, 9, 9, 9, 9, 9, 9, 9, 9, 12, 9, 9, 9, 9, 9, 9, 9, 12, 9,
+            9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 0, 0, 0, 0, 0, 9, 9, 9, 9, 9, 9,
+            9, 9, 9, 9, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0
+        ],
+        block_bits: 5,
+        leaf_bits: 4,
+    };
 
-    const IDENTIFIER_TYPE: &'static [(char, char, IdentifierType)] = &[
-        ('\u{9}', '\u{d}',  IdentifierType::Not_XID), ('\u{20}', '\u{26}',
-         IdentifierType::Not_XID), ('\u{27}', '\u{27}',  IdentifierType::Inclusion), ('\u{28}',
-        '\u{2c}',  IdentifierType::Not_XID), ('\u{2d}', '\u{2e}',  IdentifierType::Inclusion),
-        ('\u{2f}', '\u{2f}',  IdentifierType::Not_XID), ('\u{30}', '\u{39}',
-         IdentifierType::Recommended), ('\u{3a}', '\u{3a}',  IdentifierType::Inclusion), ('\u{3b}',
-        '\u{40}',  IdentifierType::Not_XID), ('\u{41}', '\u{5a}',  IdentifierType::Recommended),
-        ('\u{5b}', '\u{5e}',  IdentifierType::Not_XID), ('\u{5f}', '\u{5f}',
-         IdentifierType::Recommended), ('\u{60}', '\u{60}',  IdentifierType::Not_XID), ('\u{61}',
-        '\u{7a}',  IdentifierType::Recommended), ('\u{7b}', '\u{7e}',  IdentifierType::Not_XID),
-        ('\u{85}', '\u{85}',  IdentifierType::Not_XID), ('\u{a0}', '\u{a0}',
-         IdentifierType::Not_NFKC), ('\u{a1}', '\u{a7}',  IdentifierType::Not_XID), ('\u{a8}',
-        '\u{a8}',  IdentifierType::Not_NFKC), ('\u{a9}', '\u{a9}',  IdentifierType::Not_XID),
-        ('\u{aa}', '\u{aa}',  IdentifierType::Not_NFKC), ('\u{ab}', '\u{ac}',
-         IdentifierType::Not_XID), ('\u{ad}', '\u{ad}',  IdentifierType::Default_Ignorable),
-        ('\u{ae}', '\u{ae}',  IdentifierType::Not_XID), ('\u{af}', '\u{af}',
-         IdentifierType::Not_NFKC), ('\u{b0}', '\u{b1}',  IdentifierType::Not_XID), ('\u{b2}',
-        '\u{b5}',  IdentifierType::Not_NFKC), ('\u{b6}', '\u{b6}',  IdentifierType::Not_XID),
-        ('\u{b7}', '\u{b7}',  IdentifierType::Inclusion), ('\u{b8}', '\u{ba}',
-         IdentifierType::Not_NFKC), ('\u{bb}', '\u{bb}',  IdentifierType::Not_XID), ('\u{bc}',
-        '\u{be}',  IdentifierType::Not_NFKC), ('\u{bf}', '\u{bf}',  IdentifierType::Not_XID),
-        ('\u{c0}', '\u{d6}',  IdentifierType::Recommended), ('\u{d7}', '\u{d7}',
-         IdentifierType::Not_XID), ('\u{d8}', '\u{f6}',  IdentifierType::Recommended), ('\u{f7}',
-        '\u{f7}',  IdentifierType::Not_XID), ('\u{f8}', '\u{113}',  IdentifierType::Recommended),
-        ('\u{114}', '\u{115}',  IdentifierType::Uncommon_Use), ('\u{116}', '\u{12b}',
-         IdentifierType::Recommended), ('\u{12c}', '\u{12d}',  IdentifierType::Uncommon_Use),
-        ('\u{12e}', '\u{131}',  IdentifierType::Recommended), ('\u{132}', '\u{133}',
-         IdentifierType::Not_NFKC), ('\u{134}', '\u{137}',  IdentifierType::Recommended),
-        ('\u{138}', '\u{138}',  IdentifierType::Obsolete), ('\u{139}', '\u{13e}',
-         IdentifierType::Recommended), ('\u{13f}', '\u{140}',  IdentifierType::Not_NFKC),
-        ('\u{141}', '\u{148}',  IdentifierType::Recommended), ('\u{149}', '\u{149}',
-         IdentifierType::Deprecated), ('\u{14a}', '\u{14d}',  IdentifierType::Recommended),
-        ('\u{14e}', '\u{14f}',  IdentifierType::Uncommon_Use), ('\u{150}', '\u{155}',
-         IdentifierType::Recommended), ('\u{156}', '\u{157}',  IdentifierType::Uncommon_Use),
-        ('\u{158}', '\u{161}',  IdentifierType::Recommended), ('\u{162}', '\u{163}',
-         IdentifierType::Uncommon_Use), ('\u{164}', '\u{17e}',  IdentifierType::Recommended),
-        ('\u{17f}', '\u{17f}',  IdentifierType::Not_NFKC), ('\u{180}', '\u{180}',
-         IdentifierType::Technical), ('\u{181}', '\u{181}',  IdentifierType::Recommended),
-        ('\u{182}', '\u{185}',  IdentifierType::Uncommon_Use), ('\u{186}', '\u{186}',
-         IdentifierType::Recommended), ('\u{187}', '\u{188}',  IdentifierType::Uncommon_Use),
-        ('\u{189}', '\u{18a}',  IdentifierType::Recommended), ('\u{18b}', '\u{18c}',
-         IdentifierType::Uncommon_Use), ('\u{18e}', '\u{192}',  IdentifierType::Recommended),
-        ('\u{193}', '\u{193}',  IdentifierType::Uncommon_Use), ('\u{194}', '\u{194}',
-         IdentifierType::Recommended), ('\u{195}', '\u{195}',  IdentifierType::Uncommon_Use),
-        ('\u{196}', '\u{199}',  IdentifierType::Recommended), ('\u{19a}', '\u{19c}',
-         IdentifierType::Uncommon_Use), ('\u{19d}', '\u{19d}',  IdentifierType::Recommended),
-        ('\u{19e}', '\u{19f}',  IdentifierType::Uncommon_Use), ('\u{1a0}', '\u{1a1}',
-         IdentifierType::Recommended), ('\u{1a2}', '\u{1a9}',  IdentifierType::Uncommon_Use),
-        ('\u{1ac}', '\u{1ae}',  IdentifierType::Uncommon_Use), ('\u{1af}', '\u{1b0}',
-         IdentifierType::Recommended), ('\u{1b1}', '\u{1b1}',  IdentifierType::Uncommon_Use),
-        ('\u{1b2}', '\u{1b4}',  IdentifierType::Recommended), ('\u{1b5}', '\u{1b6}',
-         IdentifierType::Uncommon_Use), ('\u{1b7}', '\u{1b7}',  IdentifierType::Recommended),
-        ('\u{1b8}', '\u{1b8}',  IdentifierType::Uncommon_Use), ('\u{1b9}', '\u{1b9}',
-         IdentifierType::Obsolete), ('\u{1bc}', '\u{1bd}',  IdentifierType::Uncommon_Use),
-        ('\u{1bf}', '\u{1bf}',  IdentifierType::Obsolete), ('\u{1c0}', '\u{1c3}',
-         IdentifierType::Technical), ('\u{1c4}', '\u{1cc}',  IdentifierType::Not_NFKC), ('\u{1cd}',
-        '\u{1d4}',  IdentifierType::Recommended), ('\u{1d5}', '\u{1dc}',
-         IdentifierType::Uncommon_Use), ('\u{1dd}', '\u{1dd}',  IdentifierType::Recommended),
-        ('\u{1de}', '\u{1e5}',  IdentifierType::Uncommon_Use), ('\u{1e6}', '\u{1e9}',
-         IdentifierType::Recommended), ('\u{1ea}', '\u{1ed}',  IdentifierType::Uncommon_Use),
-        ('\u{1ee}', '\u{1ef}',  IdentifierType::Recommended), ('\u{1f0}', '\u{1f0}',
-         IdentifierType::Uncommon_Use), ('\u{1f1}', '\u{1f3}',  IdentifierType::Not_NFKC),
-        ('\u{1f4}', '\u{1f5}',  IdentifierType::Uncommon_Use), ('\u{1f6}', '\u{1f7}',
-         IdentifierType::Obsolete), ('\u{1f8}', '\u{1f9}',  IdentifierType::Recommended),
-        ('\u{1fa}', '\u{1ff}',  IdentifierType::Uncommon_Use), ('\u{200}', '\u{217}',
-         IdentifierType::Technical), ('\u{218}', '\u{21b}',  IdentifierType::Recommended),
-        ('\u{21c}', '\u{21d}',  IdentifierType::Obsolete), ('\u{21e}', '\u{21f}',
-         IdentifierType::Uncommon_Use), ('\u{220}', '\u{220}',  IdentifierType::Uncommon_Use),
-        ('\u{221}', '\u{221}',  IdentifierType::Uncommon_Use), ('\u{222}', '\u{233}',
-         IdentifierType::Uncommon_Use), ('\u{234}', '\u{236}',  IdentifierType::Technical),
-        ('\u{237}', '\u{241}',  IdentifierType::Uncommon_Use), ('\u{242}', '\u{243}',
-         IdentifierType::Uncommon_Use), ('\u{244}', '\u{244}',  IdentifierType::Recommended),
-        ('\u{245}', '\u{24b}',  IdentifierType::Uncommon_Use), ('\u{24c}', '\u{24d}',
-         IdentifierType::Recommended), ('\u{24e}', '\u{24f}',  IdentifierType::Uncommon_Use),
-        ('\u{250}', '\u{252}',  IdentifierType::Technical), ('\u{253}', '\u{254}',
-         IdentifierType::Recommended), ('\u{255}', '\u{255}',  IdentifierType::Technical),
-        ('\u{256}', '\u{257}',  IdentifierType::Recommended), ('\u{258}', '\u{258}',
-         IdentifierType::Technical), ('\u{259}', '\u{259}',  IdentifierType::Recommended),
-        ('\u{25a}', '\u{25a}',  IdentifierType::Technical), ('\u{25b}', '\u{25b}',
-         IdentifierType::Recommended), ('\u{25c}', '\u{262}',  IdentifierType::Technical),
-        ('\u{263}', '\u{263}',  IdentifierType::Recommended), ('\u{264}', '\u{267}',
-         IdentifierType::Technical), ('\u{268}', '\u{269}',  IdentifierType::Recommended),
-        ('\u{26a}', '\u{271}',  IdentifierType::Technical), ('\u{272}', '\u{272}',
-         IdentifierType::Recommended), ('\u{273}', '\u{276}',  IdentifierType::Technical),
-        ('\u{278}', '\u{27b}',  IdentifierType::Technical), ('\u{27d}', '\u{288}',
-         IdentifierType::Technical), ('\u{289}', '\u{289}',  IdentifierType::Recommended),
-        ('\u{28a}', '\u{28a}',  IdentifierType::Technical), ('\u{28b}', '\u{28b}',
-         IdentifierType::Recommended), ('\u{28c}', '\u{291}',  IdentifierType::Technical),
-        ('\u{292}', '\u{292}',  IdentifierType::Recommended), ('\u{293}', '\u{29d}',
-         IdentifierType::Technical), ('\u{29f}', '\u{2a8}',  IdentifierType::Technical), ('\u{2a9}',
-        '\u{2ad}',  IdentifierType::Technical), ('\u{2ae}', '\u{2af}',  IdentifierType::Technical),
-        ('\u{2b0}', '\u{2b8}',  IdentifierType::Not_NFKC), ('\u{2b9}', '\u{2ba}',
-         IdentifierType::Technical), ('\u{2bb}', '\u{2bc}',  IdentifierType::Inclusion), ('\u{2bd}',
-        '\u{2c1}',  IdentifierType::Technical), ('\u{2c2}', '\u{2c5}',  IdentifierType::Not_XID),
-        ('\u{2c6}', '\u{2d1}',  IdentifierType::Technical), ('\u{2d2}', '\u{2d7}',
-         IdentifierType::Not_XID), ('\u{2d8}', '\u{2dd}',  IdentifierType::Not_NFKC), ('\u{2de}',
-        '\u{2de}',  IdentifierType::Not_XID), ('\u{2df}', '\u{2df}',  IdentifierType::Not_XID),
-        ('\u{2e0}', '\u{2e4}',  IdentifierType::Not_NFKC), ('\u{2e5}', '\u{2e9}',
-         IdentifierType::Not_XID), ('\u{2ec}', '\u{2ec}',  IdentifierType::Technical), ('\u{2ed}',
-        '\u{2ed}',  IdentifierType::Not_XID), ('\u{2ee}', '\u{2ee}',  IdentifierType::Technical),
-        ('\u{2ef}', '\u{2ff}',  IdentifierType::Not_XID), ('\u{300}', '\u{304}',
-         IdentifierType::Recommended), ('\u{305}', '\u{305}',  IdentifierType::Uncommon_Use),
-        ('\u{306}', '\u{30c}',  IdentifierType::Recommended), ('\u{30d}', '\u{30d}',
-         IdentifierType::Uncommon_Use), ('\u{30e}', '\u{315}',  IdentifierType::Technical),
-        ('\u{316}', '\u{316}',  IdentifierType::Uncommon_Use), ('\u{317}', '\u{31a}',
-         IdentifierType::Technical), ('\u{31b}', '\u{31b}',  IdentifierType::Recommended),
-        ('\u{31c}', '\u{320}',  IdentifierType::Technical), ('\u{321}', '\u{322}',
-         IdentifierType::Uncommon_Use), ('\u{323}', '\u{323}',  IdentifierType::Recommended),
-        ('\u{324}', '\u{325}',  IdentifierType::Technical), ('\u{326}', '\u{328}',
-         IdentifierType::Recommended), ('\u{329}', '\u{330}',  IdentifierType::Technical),
-        ('\u{331}', '\u{331}',  IdentifierType::Recommended), ('\u{332}', '\u{332}',
-         IdentifierType::Uncommon_Use), ('\u{333}', '\u{333}',  IdentifierType::Technical),
-        ('\u{334}', '\u{334}',  IdentifierType::Uncommon_Use), ('\u{335}', '\u{335}',
-         IdentifierType::Technical), ('\u{336}', '\u{336}',  IdentifierType::Uncommon_Use),
-        ('\u{337}', '\u{33f}',  IdentifierType::Technical), ('\u{340}', '\u{341}',
-         IdentifierType::Not_NFKC), ('\u{342}', '\u{342}',  IdentifierType::Technical), ('\u{343}',
-        '\u{344}',  IdentifierType::Not_NFKC), ('\u{345}', '\u{345}',  IdentifierType::Obsolete),
-        ('\u{346}', '\u{34e}',  IdentifierType::Technical), ('\u{34f}', '\u{34f}',
-         IdentifierType::Default_Ignorable), ('\u{350}', '\u{357}',  IdentifierType::Technical),
-        ('\u{358}', '\u{358}',  IdentifierType::Uncommon_Use), ('\u{359}', '\u{35c}',
-         IdentifierType::Technical), ('\u{35d}', '\u{35f}',  IdentifierType::Technical), ('\u{360}',
-        '\u{361}',  IdentifierType::Technical), ('\u{362}', '\u{362}',  IdentifierType::Technical),
-        ('\u{363}', '\u{36f}',  IdentifierType::Obsolete), ('\u{370}', '\u{373}',
-         IdentifierType::Obsolete), ('\u{374}', '\u{374}',  IdentifierType::Not_NFKC), ('\u{376}',
-        '\u{377}',  IdentifierType::Obsolete), ('\u{37a}', '\u{37a}',  IdentifierType::Not_NFKC),
-        ('\u{37b}', '\u{37d}',  IdentifierType::Obsolete), ('\u{37e}', '\u{37e}',
-         IdentifierType::Not_NFKC), ('\u{37f}', '\u{37f}',  IdentifierType::Obsolete), ('\u{384}',
-        '\u{385}',  IdentifierType::Not_NFKC), ('\u{386}', '\u{386}',  IdentifierType::Recommended),
-        ('\u{387}', '\u{387}',  IdentifierType::Not_NFKC), ('\u{388}', '\u{38a}',
-         IdentifierType::Recommended), ('\u{38c}', '\u{38c}',  IdentifierType::Recommended),
-        ('\u{38e}', '\u{3a1}',  IdentifierType::Recommended), ('\u{3a3}', '\u{3ce}',
-         IdentifierType::Recommended), ('\u{3cf}', '\u{3cf}',  IdentifierType::Technical),
-        ('\u{3d0}', '\u{3d6}',  IdentifierType::Not_NFKC), ('\u{3d7}', '\u{3d7}',
-         IdentifierType::Technical), ('\u{3d8}', '\u{3d9}',  IdentifierType::Obsolete), ('\u{3da}',
-        '\u{3da}',  IdentifierType::Obsolete), ('\u{3db}', '\u{3db}',  IdentifierType::Obsolete),
-        ('\u{3dc}', '\u{3dc}',  IdentifierType::Obsolete), ('\u{3dd}', '\u{3dd}',
-         IdentifierType::Obsolete), ('\u{3de}', '\u{3de}',  IdentifierType::Obsolete), ('\u{3df}',
-        '\u{3df}',  IdentifierType::Obsolete), ('\u{3e0}', '\u{3e0}',  IdentifierType::Obsolete),
-        ('\u{3e1}', '\u{3e1}',  IdentifierType::Obsolete), ('\u{3e2}', '\u{3ef}',
-         IdentifierType::Exclusion), ('\u{3f0}', '\u{3f2}',  IdentifierType::Not_NFKC), ('\u{3f4}',
-        '\u{3f5}',  IdentifierType::Not_NFKC), ('\u{3f6}', '\u{3f6}',  IdentifierType::Not_XID),
-        ('\u{3f7}', '\u{3f8}',  IdentifierType::Obsolete), ('\u{3f9}', '\u{3f9}',
-         IdentifierType::Not_NFKC), ('\u{3fa}', '\u{3fb}',  IdentifierType::Obsolete), ('\u{3fd}',
-        '\u{3ff}',  IdentifierType::Obsolete), ('\u{400}', '\u{400}',
-         IdentifierType::Uncommon_Use), ('\u{401}', '\u{40c}',  IdentifierType::Recommended),
-        ('\u{40d}', '\u{40d}',  IdentifierType::Uncommon_Use), ('\u{40e}', '\u{44f}',
-         IdentifierType::Recommended), ('\u{450}', '\u{450}',  IdentifierType::Uncommon_Use),
-        ('\u{451}', '\u{45c}',  IdentifierType::Recommended), ('\u{45d}', '\u{45d}',
-         IdentifierType::Uncommon_Use), ('\u{45e}', '\u{45f}',  IdentifierType::Recommended),
-        ('\u{460}', '\u{481}',  IdentifierType::Obsolete), ('\u{483}', '\u{483}',
-         IdentifierType::Obsolete), ('\u{48a}', '\u{48b}',  IdentifierType::Uncommon_Use),
-        ('\u{48c}', '\u{48f}',  IdentifierType::Uncommon_Use), ('\u{490}', '\u{49b}',
-         IdentifierType::Recommended), ('\u{49c}', '\u{49d}',  IdentifierType::Obsolete),
-        ('\u{49e}', '\u{4a5}',  IdentifierType::Recommended), ('\u{4a6}', '\u{4a7}',
-         IdentifierType::Obsolete), ('\u{4a8}', '\u{4b7}',  IdentifierType::Recommended),
-        ('\u{4b8}', '\u{4b9}',  IdentifierType::Obsolete), ('\u{4ba}', '\u{4c0}',
-         IdentifierType::Recommended), ('\u{4c1}', '\u{4c4}',  IdentifierType::Uncommon_Use),
-        ('\u{4c5}', '\u{4c6}',  IdentifierType::Uncommon_Use), ('\u{4c7}', '\u{4c8}',
-         IdentifierType::Uncommon_Use), ('\u{4c9}', '\u{4ca}',  IdentifierType::Uncommon_Use),
-        ('\u{4cb}', '\u{4cc}',  IdentifierType::Uncommon_Use), ('\u{4cd}', '\u{4ce}',
-         IdentifierType::Uncommon_Use), ('\u{4cf}', '\u{4cf}',  IdentifierType::Recommended),
-        ('\u{4d0}', '\u{4d9}',  IdentifierType::Recommended), ('\u{4da}', '\u{4db}',
-         IdentifierType::Uncommon_Use), ('\u{4dc}', '\u{4e9}',  IdentifierType::Recommended),
-        ('\u{4ea}', '\u{4eb}',  IdentifierType::Uncommon_Use), ('\u{4ec}', '\u{4ed}',
-         IdentifierType::Uncommon_Use), ('\u{4ee}', '\u{4f5}',  IdentifierType::Recommended),
-        ('\u{4f6}', '\u{4f7}',  IdentifierType::Uncommon_Use), ('\u{4f8}', '\u{4f9}',
-         IdentifierType::Recommended), ('\u{4fa}', '\u{4ff}',  IdentifierType::Uncommon_Use),
-        ('\u{500}', '\u{50f}',  IdentifierType::Obsolete), ('\u{510}', '\u{513}',
-         IdentifierType::Uncommon_Use), ('\u{514}', '\u{523}',  IdentifierType::Obsolete),
-        ('\u{524}', '\u{525}',  IdentifierType::Recommended), ('\u{526}', '\u{527}',
-         IdentifierType::Obsolete), ('\u{528}', '\u{52f}',  IdentifierType::Obsolete), ('\u{531}',
-        '\u{556}',  IdentifierType::Recommended), ('\u{559}', '\u{559}',
-         IdentifierType::Technical), ('\u{55a}', '\u{55f}',  IdentifierType::Not_XID), ('\u{560}',
-        '\u{560}',  IdentifierType::Technical), ('\u{561}', '\u{586}',
-         IdentifierType::Recommended), ('\u{587}', '\u{587}',  IdentifierType::Not_NFKC),
-        ('\u{588}', '\u{588}',  IdentifierType::Technical), ('\u{589}', '\u{589}',
-         IdentifierType::Not_XID), ('\u{58a}', '\u{58a}',  IdentifierType::Inclusion), ('\u{58d}',
-        '\u{58e}',  IdentifierType::Not_XID), ('\u{58f}', '\u{58f}',  IdentifierType::Not_XID),
-        ('\u{591}', '\u{5a1}',  IdentifierType::Uncommon_Use), ('\u{5a3}', '\u{5af}',
-         IdentifierType::Uncommon_Use), ('\u{5b0}', '\u{5b9}',  IdentifierType::Uncommon_Use),
-        ('\u{5ba}', '\u{5ba}',  IdentifierType::Uncommon_Use), ('\u{5bb}', '\u{5bd}',
-         IdentifierType::Uncommon_Use), ('\u{5be}', '\u{5be}',  IdentifierType::Not_XID),
-        ('\u{5bf}', '\u{5bf}',  IdentifierType::Uncommon_Use), ('\u{5c0}', '\u{5c0}',
-         IdentifierType::Not_XID), ('\u{5c1}', '\u{5c2}',  IdentifierType::Uncommon_Use),
-        ('\u{5c3}', '\u{5c3}',  IdentifierType::Not_XID), ('\u{5c4}', '\u{5c4}',
-         IdentifierType::Uncommon_Use), ('\u{5d0}', '\u{5ea}',  IdentifierType::Recommended),
-        ('\u{5ef}', '\u{5ef}',  IdentifierType::Uncommon_Use), ('\u{5f0}', '\u{5f2}',
-         IdentifierType::Uncommon_Use), ('\u{5f3}', '\u{5f4}',  IdentifierType::Inclusion),
-        ('\u{600}', '\u{603}',  IdentifierType::Not_XID), ('\u{604}', '\u{604}',
-         IdentifierType::Not_XID), ('\u{605}', '\u{605}',  IdentifierType::Not_XID), ('\u{606}',
-        '\u{60a}',  IdentifierType::Not_XID), ('\u{60b}', '\u{60b}',  IdentifierType::Not_XID),
-        ('\u{60c}', '\u{60c}',  IdentifierType::Not_XID), ('\u{60d}', '\u{60f}',
-         IdentifierType::Not_XID), ('\u{610}', '\u{615}',  IdentifierType::Uncommon_Use),
-        ('\u{616}', '\u{61a}',  IdentifierType::Uncommon_Use), ('\u{61b}', '\u{61b}',
-         IdentifierType::Not_XID), ('\u{61c}', '\u{61c}',  IdentifierType::Default_Ignorable),
-        ('\u{61d}', '\u{61d}',  IdentifierType::Not_XID), ('\u{61e}', '\u{61e}',
-         IdentifierType::Not_XID), ('\u{61f}', '\u{61f}',  IdentifierType::Not_XID), ('\u{620}',
-        '\u{620}',  IdentifierType::Recommended), ('\u{621}', '\u{63a}',
-         IdentifierType::Recommended), ('\u{63b}', '\u{63c}',  IdentifierType::Obsolete),
-        ('\u{63d}', '\u{63d}',  IdentifierType::Recommended), ('\u{63e}', '\u{63f}',
-         IdentifierType::Obsolete), ('\u{640}', '\u{640}',  IdentifierType::Obsolete), ('\u{641}',
-        '\u{652}',  IdentifierType::Recommended), ('\u{654}', '\u{655}',
-         IdentifierType::Recommended), ('\u{656}', '\u{658}',  IdentifierType::Uncommon_Use),
-        ('\u{659}', '\u{65e}',  IdentifierType::Uncommon_Use), ('\u{65f}', '\u{65f}',
-         IdentifierType::Uncommon_Use), ('\u{660}', '\u{669}',  IdentifierType::Recommended),
-        ('\u{66a}', '\u{66d}',  IdentifierType::Not_XID), ('\u{66e}', '\u{66f}',
-         IdentifierType::Obsolete), ('\u{670}', '\u{670}',  IdentifierType::Recommended),
-        ('\u{671}', '\u{671}',  IdentifierType::Technical), ('\u{672}', '\u{672}',
-         IdentifierType::Recommended), ('\u{673}', '\u{673}',  IdentifierType::Deprecated),
-        ('\u{674}', '\u{674}',  IdentifierType::Recommended), ('\u{675}', '\u{678}',
-         IdentifierType::Not_NFKC), ('\u{679}', '\u{68f}',  IdentifierType::Recommended),
-        ('\u{690}', '\u{690}',  IdentifierType::Obsolete), ('\u{691}', '\u{69a}',
-         IdentifierType::Recommended), ('\u{69b}', '\u{69e}',  IdentifierType::Uncommon_Use),
-        ('\u{69f}', '\u{6a0}',  IdentifierType::Recommended), ('\u{6a1}', '\u{6a1}',
-         IdentifierType::Uncommon_Use), ('\u{6a2}', '\u{6a2}',  IdentifierType::Recommended),
-        ('\u{6a3}', '\u{6a3}',  IdentifierType::Uncommon_Use), ('\u{6a4}', '\u{6ab}',
-         IdentifierType::Recommended), ('\u{6ac}', '\u{6ac}',  IdentifierType::Obsolete),
-        ('\u{6ad}', '\u{6b1}',  IdentifierType::Recommended), ('\u{6b2}', '\u{6b2}',
-         IdentifierType::Uncommon_Use), ('\u{6b3}', '\u{6b3}',  IdentifierType::Recommended),
-        ('\u{6b4}', '\u{6b4}',  IdentifierType::Uncommon_Use), ('\u{6b5}', '\u{6b7}',
-         IdentifierType::Recommended), ('\u{6b8}', '\u{6b9}',  IdentifierType::Uncommon_Use),
-        ('\u{6ba}', '\u{6be}',  IdentifierType::Recommended), ('\u{6bf}', '\u{6bf}',
-         IdentifierType::Uncommon_Use), ('\u{6c0}', '\u{6ce}',  IdentifierType::Recommended),
-        ('\u{6cf}', '\u{6cf}',  IdentifierType::Recommended), ('\u{6d0}', '\u{6d3}',
-         IdentifierType::Recommended), ('\u{6d4}', '\u{6d4}',  IdentifierType::Not_XID), ('\u{6d5}',
-        '\u{6d5}',  IdentifierType::Recommended), ('\u{6d6}', '\u{6dc}',
-         IdentifierType::Uncommon_Use), ('\u{6dd}', '\u{6dd}',  IdentifierType::Not_XID),
-        ('\u{6de}', '\u{6de}',  IdentifierType::Not_XID), ('\u{6df}', '\u{6e4}',
-         IdentifierType::Uncommon_Use), ('\u{6e5}', '\u{6e6}',  IdentifierType::Technical),
-        ('\u{6e7}', '\u{6e8}',  IdentifierType::Uncommon_Use), ('\u{6e9}', '\u{6e9}',
-         IdentifierType::Not_XID), ('\u{6ea}', '\u{6ed}',  IdentifierType::Uncommon_Use),
-        ('\u{6ee}', '\u{6ef}',  IdentifierType::Recommended), ('\u{6f0}', '\u{6f9}',
-         IdentifierType::Recommended), ('\u{6fa}', '\u{6fc}',  IdentifierType::Uncommon_Use),
-        ('\u{6fd}', '\u{6fe}',  IdentifierType::Inclusion), ('\u{6ff}', '\u{6ff}',
-         IdentifierType::Recommended), ('\u{710}', '\u{72c}',  IdentifierType::Limited_Use),
-        ('\u{72d}', '\u{72f}',  IdentifierType::Limited_Use), ('\u{730}', '\u{73f}',
-         IdentifierType::Limited_Use), ('\u{74d}', '\u{74f}',  IdentifierType::Limited_Use),
-        ('\u{750}', '\u{750}',  IdentifierType::Uncommon_Use), ('\u{751}', '\u{752}',
-         IdentifierType::Recommended), ('\u{753}', '\u{755}',  IdentifierType::Uncommon_Use),
-        ('\u{756}', '\u{756}',  IdentifierType::Recommended), ('\u{757}', '\u{75f}',
-         IdentifierType::Uncommon_Use), ('\u{760}', '\u{760}',  IdentifierType::Recommended),
-        ('\u{761}', '\u{761}',  IdentifierType::Uncommon_Use), ('\u{762}', '\u{763}',
-         IdentifierType::Recommended), ('\u{764}', '\u{765}',  IdentifierType::Uncommon_Use),
-        ('\u{766}', '\u{768}',  IdentifierType::Recommended), ('\u{769}', '\u{769}',
-         IdentifierType::Uncommon_Use), ('\u{76a}', '\u{76a}',  IdentifierType::Recommended),
-        ('\u{76b}', '\u{76d}',  IdentifierType::Uncommon_Use), ('\u{76e}', '\u{771}',
-         IdentifierType::Recommended), ('\u{772}', '\u{77d}',  IdentifierType::Uncommon_Use),
-        ('\u{77e}', '\u{77f}',  IdentifierType::Obsolete), ('\u{780}', '\u{7b0}',
-         IdentifierType::Recommended), ('\u{7b1}', '\u{7b1}',  IdentifierType::Recommended),
-        ('\u{7c0}', '\u{7e7}',  IdentifierType::Limited_Use), ('\u{7eb}', '\u{7f5}',
-         IdentifierType::Limited_Use), ('\u{7fd}', '\u{7fd}',  IdentifierType::Limited_Use),
-        ('\u{800}', '\u{82d}',  IdentifierType::Exclusion), ('\u{840}', '\u{85b}',
-         IdentifierType::Limited_Use), ('\u{860}', '\u{86a}',  IdentifierType::Limited_Use),
-        ('\u{870}', '\u{887}',  IdentifierType::Technical), ('\u{889}', '\u{88d}',
-         IdentifierType::Uncommon_Use), ('\u{88e}', '\u{88e}',  IdentifierType::Obsolete),
-        ('\u{88f}', '\u{88f}',  IdentifierType::Recommended), ('\u{890}', '\u{891}',
-         IdentifierType::Not_XID), ('\u{897}', '\u{897}',  IdentifierType::Uncommon_Use),
-        ('\u{898}', '\u{89f}',  IdentifierType::Uncommon_Use), ('\u{8a0}', '\u{8a0}',
-         IdentifierType::Recommended), ('\u{8a1}', '\u{8a1}',  IdentifierType::Uncommon_Use),
-        ('\u{8a2}', '\u{8a9}',  IdentifierType::Recommended), ('\u{8aa}', '\u{8ac}',
-         IdentifierType::Uncommon_Use), ('\u{8ad}', '\u{8b1}',  IdentifierType::Obsolete),
-        ('\u{8b2}', '\u{8b2}',  IdentifierType::Uncommon_Use), ('\u{8b3}', '\u{8b4}',
-         IdentifierType::Uncommon_Use), ('\u{8b5}', '\u{8b5}',  IdentifierType::Obsolete),
-        ('\u{8b6}', '\u{8ba}',  IdentifierType::Uncommon_Use), ('\u{8bb}', '\u{8bd}',
-         IdentifierType::Recommended), ('\u{8be}', '\u{8c2}',  IdentifierType::Recommended),
-        ('\u{8c3}', '\u{8c6}',  IdentifierType::Uncommon_Use), ('\u{8c7}', '\u{8c7}',
-         IdentifierType::Recommended), ('\u{8c8}', '\u{8c8}',  IdentifierType::Uncommon_Use),
-        ('\u{8c9}', '\u{8c9}',  IdentifierType::Technical), ('\u{8ca}', '\u{8d2}',
-         IdentifierType::Uncommon_Use), ('\u{8d3}', '\u{8d3}',  IdentifierType::Uncommon_Use),
-        ('\u{8d4}', '\u{8e1}',  IdentifierType::Uncommon_Use), ('\u{8e2}', '\u{8e2}',
-         IdentifierType::Not_XID), ('\u{8e3}', '\u{8e3}',  IdentifierType::Uncommon_Use),
-        ('\u{8e4}', '\u{8fe}',  IdentifierType::Uncommon_Use), ('\u{8ff}', '\u{8ff}',
-         IdentifierType::Uncommon_Use), ('\u{900}', '\u{900}',  IdentifierType::Uncommon_Use),
-        ('\u{901}', '\u{903}',  IdentifierType::Recommended), ('\u{904}', '\u{904}',
-         IdentifierType::Uncommon_Use), ('\u{905}', '\u{90b}',  IdentifierType::Recommended),
-        ('\u{90c}', '\u{90c}',  IdentifierType::Obsolete), ('\u{90d}', '\u{928}',
-         IdentifierType::Recommended), ('\u{929}', '\u{929}',  IdentifierType::Uncommon_Use),
-        ('\u{92a}', '\u{933}',  IdentifierType::Recommended), ('\u{934}', '\u{934}',
-         IdentifierType::Uncommon_Use), ('\u{935}', '\u{939}',  IdentifierType::Recommended),
-        ('\u{93a}', '\u{93b}',  IdentifierType::Recommended), ('\u{93c}', '\u{93c}',
-         IdentifierType::Recommended), ('\u{93d}', '\u{93d}',  IdentifierType::Obsolete),
-        ('\u{93e}', '\u{943}',  IdentifierType::Recommended), ('\u{944}', '\u{944}',
-         IdentifierType::Uncommon_Use), ('\u{945}', '\u{94d}',  IdentifierType::Recommended),
-        ('\u{94e}', '\u{94e}',  IdentifierType::Obsolete), ('\u{94f}', '\u{94f}',
-         IdentifierType::Recommended), ('\u{950}', '\u{950}',  IdentifierType::Technical),
-        ('\u{951}', '\u{952}',  IdentifierType::Obsolete), ('\u{953}', '\u{954}',
-         IdentifierType::Technical), ('\u{955}', '\u{955}',  IdentifierType::Uncommon_Use),
-        ('\u{956}', '\u{957}',  IdentifierType::Recommended), ('\u{958}', '\u{95f}',
-         IdentifierType::Not_NFKC), ('\u{960}', '\u{963}',  IdentifierType::Obsolete), ('\u{964}',
-        '\u{965}',  IdentifierType::Not_XID), ('\u{966}', '\u{96f}',  IdentifierType::Recommended),
-        ('\u{970}', '\u{970}',  IdentifierType::Not_XID), ('\u{971}', '\u{971}',
-         IdentifierType::Obsolete), ('\u{972}', '\u{972}',  IdentifierType::Recommended),
-        ('\u{973}', '\u{977}',  IdentifierType::Recommended), ('\u{978}', '\u{978}',
-         IdentifierType::Obsolete), ('\u{979}', '\u{97a}',  IdentifierType::Uncommon_Use),
-        ('\u{97b}', '\u{97c}',  IdentifierType::Recommended), ('\u{97d}', '\u{97d}',
-         IdentifierType::Technical), ('\u{97e}', '\u{97f}',  IdentifierType::Recommended),
-        ('\u{980}', '\u{980}',  IdentifierType::Obsolete), ('\u{981}', '\u{983}',
-         IdentifierType::Recommended), ('\u{985}', '\u{98b}',  IdentifierType::Recommended),
-        ('\u{98c}', '\u{98c}',  IdentifierType::Uncommon_Use), ('\u{98f}', '\u{990}',
-         IdentifierType::Recommended), ('\u{993}', '\u{9a8}',  IdentifierType::Recommended),
-        ('\u{9aa}', '\u{9b0}',  IdentifierType::Recommended), ('\u{9b2}', '\u{9b2}',
-         IdentifierType::Recommended), ('\u{9b6}', '\u{9b9}',  IdentifierType::Recommended),
-        ('\u{9bc}', '\u{9bc}',  IdentifierType::Recommended), ('\u{9bd}', '\u{9bd}',
-         IdentifierType::Obsolete), ('\u{9be}', '\u{9c4}',  IdentifierType::Recommended),
-        ('\u{9c7}', '\u{9c8}',  IdentifierType::Recommended), ('\u{9cb}', '\u{9cd}',
-         IdentifierType::Recommended), ('\u{9ce}', '\u{9ce}',  IdentifierType::Recommended),
-        ('\u{9d7}', '\u{9d7}',  IdentifierType::Uncommon_Use), ('\u{9dc}', '\u{9dd}',
-         IdentifierType::Not_NFKC), ('\u{9df}', '\u{9df}',  IdentifierType::Not_NFKC), ('\u{9e0}',
-        '\u{9e3}',  IdentifierType::Obsolete), ('\u{9e6}', '\u{9f1}',  IdentifierType::Recommended),
-        ('\u{9f2}', '\u{9fa}',  IdentifierType::Not_XID), ('\u{9fb}', '\u{9fb}',
-         IdentifierType::Not_XID), ('\u{9fc}', '\u{9fc}',  IdentifierType::Obsolete), ('\u{9fd}',
-        '\u{9fd}',  IdentifierType::Not_XID), ('\u{9fe}', '\u{9fe}',  IdentifierType::Uncommon_Use),
-        ('\u{a01}', '\u{a01}',  IdentifierType::Uncommon_Use), ('\u{a02}', '\u{a02}',
-         IdentifierType::Recommended), ('\u{a03}', '\u{a03}',  IdentifierType::Uncommon_Use),
-        ('\u{a05}', '\u{a0a}',  IdentifierType::Recommended), ('\u{a0f}', '\u{a10}',
-         IdentifierType::Recommended), ('\u{a13}', '\u{a28}',  IdentifierType::Recommended),
-        ('\u{a2a}', '\u{a30}',  IdentifierType::Recommended), ('\u{a32}', '\u{a32}',
-         IdentifierType::Recommended), ('\u{a33}', '\u{a33}',  IdentifierType::Not_NFKC),
-        ('\u{a35}', '\u{a35}',  IdentifierType::Recommended), ('\u{a36}', '\u{a36}',
-         IdentifierType::Not_NFKC), ('\u{a38}', '\u{a39}',  IdentifierType::Recommended),
-        ('\u{a3c}', '\u{a3c}',  IdentifierType::Recommended), ('\u{a3e}', '\u{a42}',
-         IdentifierType::Recommended), ('\u{a47}', '\u{a48}',  IdentifierType::Recommended),
-        ('\u{a4b}', '\u{a4d}',  IdentifierType::Recommended), ('\u{a51}', '\u{a51}',
-         IdentifierType::Uncommon_Use), ('\u{a59}', '\u{a5b}',  IdentifierType::Not_NFKC),
-        ('\u{a5c}', '\u{a5c}',  IdentifierType::Recommended), ('\u{a5e}', '\u{a5e}',
-         IdentifierType::Not_NFKC), ('\u{a66}', '\u{a6f}',  IdentifierType::Uncommon_Use),
-        ('\u{a70}', '\u{a71}',  IdentifierType::Recommended), ('\u{a72}', '\u{a73}',
-         IdentifierType::Uncommon_Use), ('\u{a74}', '\u{a74}',  IdentifierType::Technical),
-        ('\u{a75}', '\u{a75}',  IdentifierType::Uncommon_Use), ('\u{a76}', '\u{a76}',
-         IdentifierType::Not_XID), ('\u{a81}', '\u{a81}',  IdentifierType::Uncommon_Use),
-        ('\u{a82}', '\u{a83}',  IdentifierType::Recommended), ('\u{a85}', '\u{a8b}',
-         IdentifierType::Recommended), ('\u{a8c}', '\u{a8c}',  IdentifierType::Recommended),
-        ('\u{a8d}', '\u{a8d}',  IdentifierType::Recommended), ('\u{a8f}', '\u{a91}',
-         IdentifierType::Recommended), ('\u{a93}', '\u{aa8}',  IdentifierType::Recommended),
-        ('\u{aaa}', '\u{ab0}',  IdentifierType::Recommended), ('\u{ab2}', '\u{ab3}',
-         IdentifierType::Recommended), ('\u{ab5}', '\u{ab9}',  IdentifierType::Recommended),
-        ('\u{abc}', '\u{abc}',  IdentifierType::Recommended), ('\u{abd}', '\u{abd}',
-         IdentifierType::Obsolete), ('\u{abe}', '\u{ac5}',  IdentifierType::Recommended),
-        ('\u{ac7}', '\u{ac9}',  IdentifierType::Recommended), ('\u{acb}', '\u{acd}',
-         IdentifierType::Recommended), ('\u{ad0}', '\u{ad0}',  IdentifierType::Technical),
-        ('\u{ae0}', '\u{ae0}',  IdentifierType::Obsolete), ('\u{ae1}', '\u{ae3}',
-         IdentifierType::Obsolete), ('\u{ae6}', '\u{aef}',  IdentifierType::Recommended),
-        ('\u{af0}', '\u{af0}',  IdentifierType::Not_XID), ('\u{af1}', '\u{af1}',
-         IdentifierType::Not_XID), ('\u{af9}', '\u{af9}',  IdentifierType::Uncommon_Use),
-        ('\u{afa}', '\u{aff}',  IdentifierType::Uncommon_Use), ('\u{b01}', '\u{b03}',
-         IdentifierType::Recommended), ('\u{b05}', '\u{b0b}',  IdentifierType::Recommended),
-        ('\u{b0c}', '\u{b0c}',  IdentifierType::Uncommon_Use), ('\u{b0f}', '\u{b10}',
-         IdentifierType::Recommended), ('\u{b13}', '\u{b28}',  IdentifierType::Recommended),
-        ('\u{b2a}', '\u{b30}',  IdentifierType::Recommended), ('\u{b32}', '\u{b33}',
-         IdentifierType::Recommended), ('\u{b35}', '\u{b35}',  IdentifierType::Uncommon_Use),
-        ('\u{b36}', '\u{b39}',  IdentifierType::Recommended), ('\u{b3c}', '\u{b3c}',
-         IdentifierType::Recommended), ('\u{b3d}', '\u{b3d}',  IdentifierType::Obsolete),
-        ('\u{b3e}', '\u{b43}',  IdentifierType::Recommended), ('\u{b44}', '\u{b44}',
-         IdentifierType::Uncommon_Use), ('\u{b47}', '\u{b48}',  IdentifierType::Recommended),
-        ('\u{b4b}', '\u{b4d}',  IdentifierType::Recommended), ('\u{b55}', '\u{b55}',
-         IdentifierType::Uncommon_Use), ('\u{b56}', '\u{b56}',  IdentifierType::Recommended),
-        ('\u{b57}', '\u{b57}',  IdentifierType::Uncommon_Use), ('\u{b5c}', '\u{b5d}',
-         IdentifierType::Not_NFKC), ('\u{b5f}', '\u{b5f}',  IdentifierType::Recommended),
-        ('\u{b60}', '\u{b61}',  IdentifierType::Obsolete), ('\u{b62}', '\u{b63}',
-         IdentifierType::Uncommon_Use), ('\u{b66}', '\u{b6f}',  IdentifierType::Uncommon_Use),
-        ('\u{b70}', '\u{b70}',  IdentifierType::Not_XID), ('\u{b71}', '\u{b71}',
-         IdentifierType::Recommended), ('\u{b72}', '\u{b77}',  IdentifierType::Not_XID), ('\u{b82}',
-        '\u{b82}',  IdentifierType::Technical), ('\u{b83}', '\u{b83}',
-         IdentifierType::Recommended), ('\u{b85}', '\u{b8a}',  IdentifierType::Recommended),
-        ('\u{b8e}', '\u{b90}',  IdentifierType::Recommended), ('\u{b92}', '\u{b95}',
-         IdentifierType::Recommended), ('\u{b99}', '\u{b9a}',  IdentifierType::Recommended),
-        ('\u{b9c}', '\u{b9c}',  IdentifierType::Recommended), ('\u{b9e}', '\u{b9f}',
-         IdentifierType::Recommended), ('\u{ba3}', '\u{ba4}',  IdentifierType::Recommended),
-        ('\u{ba8}', '\u{baa}',  IdentifierType::Recommended), ('\u{bae}', '\u{bb5}',
-         IdentifierType::Recommended), ('\u{bb6}', '\u{bb6}',  IdentifierType::Recommended),
-        ('\u{bb7}', '\u{bb9}',  IdentifierType::Recommended), ('\u{bbe}', '\u{bc2}',
-         IdentifierType::Recommended), ('\u{bc6}', '\u{bc8}',  IdentifierType::Recommended),
-        ('\u{bca}', '\u{bcd}',  IdentifierType::Recommended), ('\u{bd0}', '\u{bd0}',
-         IdentifierType::Technical), ('\u{bd7}', '\u{bd7}',  IdentifierType::Uncommon_Use),
-        ('\u{be6}', '\u{be6}',  IdentifierType::Uncommon_Use), ('\u{be7}', '\u{bef}',
-         IdentifierType::Uncommon_Use), ('\u{bf0}', '\u{bf2}',  IdentifierType::Not_XID),
-        ('\u{bf3}', '\u{bfa}',  IdentifierType::Not_XID), ('\u{c00}', '\u{c00}',
-         IdentifierType::Obsolete), ('\u{c01}', '\u{c01}',  IdentifierType::Uncommon_Use),
-        ('\u{c02}', '\u{c03}',  IdentifierType::Recommended), ('\u{c04}', '\u{c04}',
-         IdentifierType::Uncommon_Use), ('\u{c05}', '\u{c0b}',  IdentifierType::Recommended),
-        ('\u{c0c}', '\u{c0c}',  IdentifierType::Uncommon_Use), ('\u{c0e}', '\u{c10}',
-         IdentifierType::Recommended), ('\u{c12}', '\u{c28}',  IdentifierType::Recommended),
-        ('\u{c2a}', '\u{c30}',  IdentifierType::Recommended), ('\u{c31}', '\u{c31}',
-         IdentifierType::Uncommon_Use), ('\u{c32}', '\u{c33}',  IdentifierType::Recommended),
-        ('\u{c34}', '\u{c34}',  IdentifierType::Obsolete), ('\u{c35}', '\u{c39}',
-         IdentifierType::Recommended), ('\u{c3c}', '\u{c3c}',  IdentifierType::Uncommon_Use),
-        ('\u{c3d}', '\u{c3d}',  IdentifierType::Obsolete), ('\u{c3e}', '\u{c44}',
-         IdentifierType::Recommended), ('\u{c46}', '\u{c48}',  IdentifierType::Recommended),
-        ('\u{c4a}', '\u{c4d}',  IdentifierType::Recommended), ('\u{c55}', '\u{c56}',
-         IdentifierType::Uncommon_Use), ('\u{c58}', '\u{c59}',  IdentifierType::Obsolete),
-        ('\u{c5a}', '\u{c5a}',  IdentifierType::Uncommon_Use), ('\u{c5c}', '\u{c5c}',
-         IdentifierType::Obsolete), ('\u{c5d}', '\u{c5d}',  IdentifierType::Uncommon_Use),
-        ('\u{c60}', '\u{c61}',  IdentifierType::Obsolete), ('\u{c62}', '\u{c63}',
-         IdentifierType::Uncommon_Use), ('\u{c66}', '\u{c6f}',  IdentifierType::Uncommon_Use),
-        ('\u{c77}', '\u{c77}',  IdentifierType::Not_XID), ('\u{c78}', '\u{c7f}',
-         IdentifierType::Not_XID), ('\u{c80}', '\u{c80}',  IdentifierType::Uncommon_Use),
-        ('\u{c81}', '\u{c81}',  IdentifierType::Obsolete), ('\u{c82}', '\u{c83}',
-         IdentifierType::Recommended), ('\u{c84}', '\u{c84}',  IdentifierType::Not_XID), ('\u{c85}',
-        '\u{c8b}',  IdentifierType::Recommended), ('\u{c8c}', '\u{c8c}',  IdentifierType::Obsolete),
-        ('\u{c8e}', '\u{c90}',  IdentifierType::Recommended), ('\u{c92}', '\u{ca8}',
-         IdentifierType::Recommended), ('\u{caa}', '\u{cb0}',  IdentifierType::Recommended),
-        ('\u{cb1}', '\u{cb1}',  IdentifierType::Obsolete), ('\u{cb2}', '\u{cb3}',
-         IdentifierType::Recommended), ('\u{cb5}', '\u{cb9}',  IdentifierType::Recommended),
-        ('\u{cbc}', '\u{cbc}',  IdentifierType::Uncommon_Use), ('\u{cbd}', '\u{cbd}',
-         IdentifierType::Obsolete), ('\u{cbe}', '\u{cc3}',  IdentifierType::Recommended),
-        ('\u{cc4}', '\u{cc4}',  IdentifierType::Uncommon_Use), ('\u{cc6}', '\u{cc8}',
-         IdentifierType::Recommended), ('\u{cca}', '\u{ccd}',  IdentifierType::Recommended),
-        ('\u{cd5}', '\u{cd6}',  IdentifierType::Uncommon_Use), ('\u{cdc}', '\u{cdc}',
-         IdentifierType::Obsolete), ('\u{cdd}', '\u{cdd}',  IdentifierType::Uncommon_Use),
-        ('\u{cde}', '\u{cde}',  IdentifierType::Obsolete), ('\u{ce0}', '\u{ce1}',
-         IdentifierType::Obsolete), ('\u{ce2}', '\u{ce3}',  IdentifierType::Obsolete), ('\u{ce6}',
-        '\u{cef}',  IdentifierType::Recommended), ('\u{cf1}', '\u{cf2}',  IdentifierType::Obsolete),
-        ('\u{cf3}', '\u{cf3}',  IdentifierType::Uncommon_Use), ('\u{d00}', '\u{d00}',
-         IdentifierType::Uncommon_Use), ('\u{d01}', '\u{d01}',  IdentifierType::Obsolete),
-        ('\u{d02}', '\u{d03}',  IdentifierType::Recommended), ('\u{d05}', '\u{d0b}',
-         IdentifierType::Recommended), ('\u{d0c}', '\u{d0c}',  IdentifierType::Uncommon_Use),
-        ('\u{d0e}', '\u{d10}',  IdentifierType::Recommended), ('\u{d12}', '\u{d28}',
-         IdentifierType::Recommended), ('\u{d29}', '\u{d29}',  IdentifierType::Uncommon_Use),
-        ('\u{d2a}', '\u{d39}',  IdentifierType::Recommended), ('\u{d3a}', '\u{d3a}',
-         IdentifierType::Obsolete), ('\u{d3b}', '\u{d3c}',  IdentifierType::Obsolete), ('\u{d3d}',
-        '\u{d3d}',  IdentifierType::Obsolete), ('\u{d3e}', '\u{d43}',  IdentifierType::Recommended),
-        ('\u{d44}', '\u{d44}',  IdentifierType::Uncommon_Use), ('\u{d46}', '\u{d48}',
-         IdentifierType::Recommended), ('\u{d4a}', '\u{d4b}',  IdentifierType::Recommended),
-        ('\u{d4c}', '\u{d4c}',  IdentifierType::Obsolete), ('\u{d4d}', '\u{d4d}',
-         IdentifierType::Recommended), ('\u{d4e}', '\u{d4e}',  IdentifierType::Obsolete),
-        ('\u{d4f}', '\u{d4f}',  IdentifierType::Not_XID), ('\u{d54}', '\u{d56}',
-         IdentifierType::Uncommon_Use), ('\u{d57}', '\u{d57}',  IdentifierType::Recommended),
-        ('\u{d58}', '\u{d5e}',  IdentifierType::Not_XID), ('\u{d5f}', '\u{d5f}',
-         IdentifierType::Obsolete), ('\u{d60}', '\u{d61}',  IdentifierType::Obsolete), ('\u{d62}',
-        '\u{d63}',  IdentifierType::Uncommon_Use), ('\u{d66}', '\u{d6f}',
-         IdentifierType::Uncommon_Use), ('\u{d70}', '\u{d75}',  IdentifierType::Not_XID),
-        ('\u{d76}', '\u{d78}',  IdentifierType::Not_XID), ('\u{d79}', '\u{d79}',
-         IdentifierType::Not_XID), ('\u{d7a}', '\u{d7f}',  IdentifierType::Recommended), ('\u{d81}',
-        '\u{d81}',  IdentifierType::Technical), ('\u{d82}', '\u{d83}',
-         IdentifierType::Recommended), ('\u{d85}', '\u{d8d}',  IdentifierType::Recommended),
-        ('\u{d8e}', '\u{d8e}',  IdentifierType::Uncommon_Use), ('\u{d91}', '\u{d96}',
-         IdentifierType::Recommended), ('\u{d9a}', '\u{d9d}',  IdentifierType::Recommended),
-        ('\u{d9e}', '\u{d9e}',  IdentifierType::Obsolete), ('\u{d9f}', '\u{db1}',
-         IdentifierType::Recommended), ('\u{db3}', '\u{dbb}',  IdentifierType::Recommended),
-        ('\u{dbd}', '\u{dbd}',  IdentifierType::Recommended), ('\u{dc0}', '\u{dc6}',
-         IdentifierType::Recommended), ('\u{dca}', '\u{dca}',  IdentifierType::Recommended),
-        ('\u{dcf}', '\u{dd4}',  IdentifierType::Recommended), ('\u{dd6}', '\u{dd6}',
-         IdentifierType::Recommended), ('\u{dd8}', '\u{dde}',  IdentifierType::Recommended),
-        ('\u{de6}', '\u{def}',  IdentifierType::Uncommon_Use), ('\u{df2}', '\u{df2}',
-         IdentifierType::Recommended), ('\u{df4}', '\u{df4}',  IdentifierType::Not_XID), ('\u{e01}',
-        '\u{e32}',  IdentifierType::Recommended), ('\u{e33}', '\u{e33}',  IdentifierType::Not_NFKC),
-        ('\u{e34}', '\u{e3a}',  IdentifierType::Recommended), ('\u{e3f}', '\u{e3f}',
-         IdentifierType::Not_XID), ('\u{e40}', '\u{e4d}',  IdentifierType::Recommended), ('\u{e4e}',
-        '\u{e4e}',  IdentifierType::Uncommon_Use), ('\u{e4f}', '\u{e4f}',  IdentifierType::Not_XID),
-        ('\u{e50}', '\u{e59}',  IdentifierType::Recommended), ('\u{e5a}', '\u{e5b}',
-         IdentifierType::Not_XID), ('\u{e81}', '\u{e82}',  IdentifierType::Recommended), ('\u{e84}',
-        '\u{e84}',  IdentifierType::Recommended), ('\u{e86}', '\u{e86}',
-         IdentifierType::Uncommon_Use), ('\u{e87}', '\u{e88}',  IdentifierType::Recommended),
-        ('\u{e89}', '\u{e89}',  IdentifierType::Uncommon_Use), ('\u{e8a}', '\u{e8a}',
-         IdentifierType::Recommended), ('\u{e8c}', '\u{e8c}',  IdentifierType::Uncommon_Use),
-        ('\u{e8d}', '\u{e8d}',  IdentifierType::Recommended), ('\u{e8e}', '\u{e93}',
-         IdentifierType::Uncommon_Use), ('\u{e94}', '\u{e97}',  IdentifierType::Recommended),
-        ('\u{e98}', '\u{e98}',  IdentifierType::Uncommon_Use), ('\u{e99}', '\u{e9f}',
-         IdentifierType::Recommended), ('\u{ea0}', '\u{ea0}',  IdentifierType::Uncommon_Use),
-        ('\u{ea1}', '\u{ea3}',  IdentifierType::Recommended), ('\u{ea5}', '\u{ea5}',
-         IdentifierType::Recommended), ('\u{ea7}', '\u{ea7}',  IdentifierType::Recommended),
-        ('\u{ea8}', '\u{ea9}',  IdentifierType::Uncommon_Use), ('\u{eaa}', '\u{eab}',
-         IdentifierType::Recommended), ('\u{eac}', '\u{eac}',  IdentifierType::Uncommon_Use),
-        ('\u{ead}', '\u{eae}',  IdentifierType::Recommended), ('\u{eaf}', '\u{eaf}',
-         IdentifierType::Technical), ('\u{eb0}', '\u{eb2}',  IdentifierType::Recommended),
-        ('\u{eb3}', '\u{eb3}',  IdentifierType::Not_NFKC), ('\u{eb4}', '\u{eb9}',
-         IdentifierType::Recommended), ('\u{eba}', '\u{eba}',  IdentifierType::Uncommon_Use),
-        ('\u{ebb}', '\u{ebd}',  IdentifierType::Recommended), ('\u{ec0}', '\u{ec4}',
-         IdentifierType::Recommended), ('\u{ec6}', '\u{ec6}',  IdentifierType::Recommended),
-        ('\u{ec8}', '\u{ecd}',  IdentifierType::Recommended), ('\u{ece}', '\u{ece}',
-         IdentifierType::Uncommon_Use), ('\u{ed0}', '\u{ed9}',  IdentifierType::Recommended),
-        ('\u{edc}', '\u{edd}',  IdentifierType::Not_NFKC), ('\u{ede}', '\u{edf}',
-         IdentifierType::Uncommon_Use), ('\u{f00}', '\u{f00}',  IdentifierType::Technical),
-        ('\u{f01}', '\u{f0a}',  IdentifierType::Not_XID), ('\u{f0b}', '\u{f0b}',
-         IdentifierType::Inclusion), ('\u{f0c}', '\u{f0c}',  IdentifierType::Not_NFKC), ('\u{f0d}',
-        '\u{f17}',  IdentifierType::Not_XID), ('\u{f18}', '\u{f19}',  IdentifierType::Technical),
-        ('\u{f1a}', '\u{f1f}',  IdentifierType::Not_XID), ('\u{f20}', '\u{f29}',
-         IdentifierType::Recommended), ('\u{f2a}', '\u{f34}',  IdentifierType::Not_XID), ('\u{f35}',
-        '\u{f35}',  IdentifierType::Technical), ('\u{f36}', '\u{f36}',  IdentifierType::Not_XID),
-        ('\u{f37}', '\u{f37}',  IdentifierType::Technical), ('\u{f38}', '\u{f38}',
-         IdentifierType::Not_XID), ('\u{f39}', '\u{f39}',  IdentifierType::Uncommon_Use),
-        ('\u{f3a}', '\u{f3d}',  IdentifierType::Not_XID), ('\u{f3e}', '\u{f3f}',
-         IdentifierType::Technical), ('\u{f40}', '\u{f42}',  IdentifierType::Recommended),
-        ('\u{f43}', '\u{f43}',  IdentifierType::Not_NFKC), ('\u{f44}', '\u{f47}',
-         IdentifierType::Recommended), ('\u{f49}', '\u{f4c}',  IdentifierType::Recommended),
-        ('\u{f4d}', '\u{f4d}',  IdentifierType::Not_NFKC), ('\u{f4e}', '\u{f51}',
-         IdentifierType::Recommended), ('\u{f52}', '\u{f52}',  IdentifierType::Not_NFKC),
-        ('\u{f53}', '\u{f56}',  IdentifierType::Recommended), ('\u{f57}', '\u{f57}',
-         IdentifierType::Not_NFKC), ('\u{f58}', '\u{f5b}',  IdentifierType::Recommended),
-        ('\u{f5c}', '\u{f5c}',  IdentifierType::Not_NFKC), ('\u{f5d}', '\u{f68}',
-         IdentifierType::Recommended), ('\u{f69}', '\u{f69}',  IdentifierType::Not_NFKC),
-        ('\u{f6b}', '\u{f6c}',  IdentifierType::Uncommon_Use), ('\u{f71}', '\u{f72}',
-         IdentifierType::Recommended), ('\u{f73}', '\u{f73}',  IdentifierType::Not_NFKC),
-        ('\u{f74}', '\u{f74}',  IdentifierType::Recommended), ('\u{f75}', '\u{f76}',
-         IdentifierType::Not_NFKC), ('\u{f77}', '\u{f77}',  IdentifierType::Deprecated), ('\u{f78}',
-        '\u{f78}',  IdentifierType::Not_NFKC), ('\u{f79}', '\u{f79}',  IdentifierType::Deprecated),
-        ('\u{f7a}', '\u{f80}',  IdentifierType::Recommended), ('\u{f81}', '\u{f81}',
-         IdentifierType::Not_NFKC), ('\u{f84}', '\u{f84}',  IdentifierType::Recommended),
-        ('\u{f85}', '\u{f85}',  IdentifierType::Not_XID), ('\u{f86}', '\u{f8b}',
-         IdentifierType::Obsolete), ('\u{f8c}', '\u{f8f}',  IdentifierType::Obsolete), ('\u{f90}',
-        '\u{f92}',  IdentifierType::Recommended), ('\u{f93}', '\u{f93}',  IdentifierType::Not_NFKC),
-        ('\u{f94}', '\u{f95}',  IdentifierType::Recommended), ('\u{f96}', '\u{f96}',
-         IdentifierType::Recommended), ('\u{f97}', '\u{f97}',  IdentifierType::Recommended),
-        ('\u{f99}', '\u{f9c}',  IdentifierType::Recommended), ('\u{f9d}', '\u{f9d}',
-         IdentifierType::Not_NFKC), ('\u{f9e}', '\u{fa1}',  IdentifierType::Recommended),
-        ('\u{fa2}', '\u{fa2}',  IdentifierType::Not_NFKC), ('\u{fa3}', '\u{fa6}',
-         IdentifierType::Recommended), ('\u{fa7}', '\u{fa7}',  IdentifierType::Not_NFKC),
-        ('\u{fa8}', '\u{fab}',  IdentifierType::Recommended), ('\u{fac}', '\u{fac}',
-         IdentifierType::Not_NFKC), ('\u{fad}', '\u{fad}',  IdentifierType::Recommended),
-        ('\u{fae}', '\u{fb0}',  IdentifierType::Uncommon_Use), ('\u{fb1}', '\u{fb7}',
-         IdentifierType::Recommended), ('\u{fb8}', '\u{fb8}',  IdentifierType::Recommended),
-        ('\u{fb9}', '\u{fb9}',  IdentifierType::Not_NFKC), ('\u{fba}', '\u{fbc}',
-         IdentifierType::Recommended), ('\u{fbe}', '\u{fc5}',  IdentifierType::Not_XID), ('\u{fc7}',
-        '\u{fcc}',  IdentifierType::Not_XID), ('\u{fce}', '\u{fce}',  IdentifierType::Not_XID),
-        ('\u{fcf}', '\u{fcf}',  IdentifierType::Not_XID), ('\u{fd0}', '\u{fd1}',
-         IdentifierType::Not_XID), ('\u{fd2}', '\u{fd4}',  IdentifierType::Not_XID), ('\u{fd5}',
-        '\u{fd8}',  IdentifierType::Not_XID), ('\u{fd9}', '\u{fda}',  IdentifierType::Not_XID),
-        ('\u{1000}', '\u{1021}',  IdentifierType::Recommended), ('\u{1022}', '\u{1022}',
-         IdentifierType::Recommended), ('\u{1023}', '\u{1027}',  IdentifierType::Recommended),
-        ('\u{1028}', '\u{1028}',  IdentifierType::Recommended), ('\u{1029}', '\u{102a}',
-         IdentifierType::Recommended), ('\u{102b}', '\u{102b}',  IdentifierType::Recommended),
-        ('\u{102c}', '\u{1032}',  IdentifierType::Recommended), ('\u{1033}', '\u{1035}',
-         IdentifierType::Recommended), ('\u{1036}', '\u{1039}',  IdentifierType::Recommended),
-        ('\u{103a}', '\u{103f}',  IdentifierType::Recommended), ('\u{1040}', '\u{1049}',
-         IdentifierType::Recommended), ('\u{104a}', '\u{104f}',  IdentifierType::Not_XID),
-        ('\u{105a}', '\u{1064}',  IdentifierType::Recommended), ('\u{1065}', '\u{1074}',
-         IdentifierType::Uncommon_Use), ('\u{1075}', '\u{108a}',  IdentifierType::Recommended),
-        ('\u{108b}', '\u{108e}',  IdentifierType::Uncommon_Use), ('\u{108f}', '\u{108f}',
-         IdentifierType::Recommended), ('\u{1090}', '\u{1099}',  IdentifierType::Uncommon_Use),
-        ('\u{109a}', '\u{109d}',  IdentifierType::Uncommon_Use), ('\u{109e}', '\u{109f}',
-         IdentifierType::Not_XID), ('\u{10a0}', '\u{10c5}',  IdentifierType::Obsolete), ('\u{10c7}',
-        '\u{10c7}',  IdentifierType::Recommended), ('\u{10cd}', '\u{10cd}',
-         IdentifierType::Recommended), ('\u{10d0}', '\u{10f0}',  IdentifierType::Recommended),
-        ('\u{10f1}', '\u{10f6}',  IdentifierType::Obsolete), ('\u{10f7}', '\u{10f8}',
-         IdentifierType::Uncommon_Use), ('\u{10fb}', '\u{10fb}',  IdentifierType::Not_XID),
-        ('\u{10fc}', '\u{10fc}',  IdentifierType::Not_NFKC), ('\u{10fd}', '\u{10ff}',
-         IdentifierType::Uncommon_Use), ('\u{1100}', '\u{1159}',  IdentifierType::Obsolete),
-        ('\u{115a}', '\u{115e}',  IdentifierType::Obsolete), ('\u{115f}', '\u{1160}',
-         IdentifierType::Default_Ignorable), ('\u{1161}', '\u{11a2}',  IdentifierType::Obsolete),
-        ('\u{11a3}', '\u{11a7}',  IdentifierType::Obsolete), ('\u{11a8}', '\u{11f9}',
-         IdentifierType::Obsolete), ('\u{11fa}', '\u{11ff}',  IdentifierType::Obsolete),
-        ('\u{1200}', '\u{1206}',  IdentifierType::Recommended), ('\u{1207}', '\u{1207}',
-         IdentifierType::Uncommon_Use), ('\u{1208}', '\u{1246}',  IdentifierType::Recommended),
-        ('\u{1247}', '\u{1247}',  IdentifierType::Recommended), ('\u{1248}', '\u{1248}',
-         IdentifierType::Recommended), ('\u{124a}', '\u{124d}',  IdentifierType::Recommended),
-        ('\u{1250}', '\u{1256}',  IdentifierType::Recommended), ('\u{1258}', '\u{1258}',
-         IdentifierType::Recommended), ('\u{125a}', '\u{125d}',  IdentifierType::Recommended),
-        ('\u{1260}', '\u{1286}',  IdentifierType::Recommended), ('\u{1287}', '\u{1287}',
-         IdentifierType::Uncommon_Use), ('\u{1288}', '\u{1288}',  IdentifierType::Recommended),
-        ('\u{128a}', '\u{128d}',  IdentifierType::Recommended), ('\u{1290}', '\u{12ae}',
-         IdentifierType::Recommended), ('\u{12af}', '\u{12af}',  IdentifierType::Uncommon_Use),
-        ('\u{12b0}', '\u{12b0}',  IdentifierType::Recommended), ('\u{12b2}', '\u{12b5}',
-         IdentifierType::Recommended), ('\u{12b8}', '\u{12be}',  IdentifierType::Recommended),
-        ('\u{12c0}', '\u{12c0}',  IdentifierType::Recommended), ('\u{12c2}', '\u{12c5}',
-         IdentifierType::Recommended), ('\u{12c8}', '\u{12ce}',  IdentifierType::Recommended),
-        ('\u{12cf}', '\u{12cf}',  IdentifierType::Recommended), ('\u{12d0}', '\u{12d6}',
-         IdentifierType::Recommended), ('\u{12d8}', '\u{12ee}',  IdentifierType::Recommended),
-        ('\u{12ef}', '\u{12ef}',  IdentifierType::Recommended), ('\u{12f0}', '\u{12f7}',
-         IdentifierType::Recommended), ('\u{12f8}', '\u{12ff}',  IdentifierType::Uncommon_Use),
-        ('\u{1300}', '\u{130e}',  IdentifierType::Recommended), ('\u{130f}', '\u{130f}',
-         IdentifierType::Uncommon_Use), ('\u{1310}', '\u{1310}',  IdentifierType::Recommended),
-        ('\u{1312}', '\u{1315}',  IdentifierType::Recommended), ('\u{1318}', '\u{131e}',
-         IdentifierType::Recommended), ('\u{131f}', '\u{131f}',  IdentifierType::Uncommon_Use),
-        ('\u{1320}', '\u{1346}',  IdentifierType::Recommended), ('\u{1347}', '\u{1347}',
-         IdentifierType::Uncommon_Use), ('\u{1348}', '\u{1359}',  IdentifierType::Recommended),
-        ('\u{135a}', '\u{135a}',  IdentifierType::Uncommon_Use), ('\u{135d}', '\u{135e}',
-         IdentifierType::Uncommon_Use), ('\u{135f}', '\u{135f}',  IdentifierType::Uncommon_Use),
-        ('\u{1360}', '\u{1360}',  IdentifierType::Not_XID), ('\u{1361}', '\u{1368}',
-         IdentifierType::Not_XID), ('\u{1369}', '\u{1371}',  IdentifierType::Obsolete), ('\u{1372}',
-        '\u{137c}',  IdentifierType::Not_XID), ('\u{1380}', '\u{138f}',
-         IdentifierType::Uncommon_Use), ('\u{1390}', '\u{1399}',  IdentifierType::Not_XID),
-        ('\u{13a0}', '\u{13f4}',  IdentifierType::Limited_Use), ('\u{13f5}', '\u{13f5}',
-         IdentifierType::Limited_Use), ('\u{13f8}', '\u{13fd}',  IdentifierType::Limited_Use),
-        ('\u{1401}', '\u{166c}',  IdentifierType::Limited_Use), ('\u{166f}', '\u{1676}',
-         IdentifierType::Limited_Use), ('\u{1677}', '\u{167f}',  IdentifierType::Limited_Use),
-        ('\u{1681}', '\u{169a}',  IdentifierType::Exclusion), ('\u{16a0}', '\u{16ea}',
-         IdentifierType::Exclusion), ('\u{16ee}', '\u{16f0}',  IdentifierType::Exclusion),
-        ('\u{16f1}', '\u{16f8}',  IdentifierType::Exclusion), ('\u{1700}', '\u{170c}',
-         IdentifierType::Exclusion), ('\u{170d}', '\u{170d}',  IdentifierType::Exclusion),
-        ('\u{170e}', '\u{1714}',  IdentifierType::Exclusion), ('\u{1715}', '\u{1715}',
-         IdentifierType::Exclusion), ('\u{171f}', '\u{171f}',  IdentifierType::Exclusion),
-        ('\u{1720}', '\u{1734}',  IdentifierType::Exclusion), ('\u{1740}', '\u{1753}',
-         IdentifierType::Exclusion), ('\u{1760}', '\u{176c}',  IdentifierType::Exclusion),
-        ('\u{176e}', '\u{1770}',  IdentifierType::Exclusion), ('\u{1772}', '\u{1773}',
-         IdentifierType::Exclusion), ('\u{1780}', '\u{179c}',  IdentifierType::Recommended),
-        ('\u{179d}', '\u{179e}',  IdentifierType::Uncommon_Use), ('\u{179f}', '\u{17a2}',
-         IdentifierType::Recommended), ('\u{17a3}', '\u{17a4}',  IdentifierType::Deprecated),
-        ('\u{17a5}', '\u{17a7}',  IdentifierType::Recommended), ('\u{17a8}', '\u{17a8}',
-         IdentifierType::Obsolete), ('\u{17a9}', '\u{17a9}',  IdentifierType::Uncommon_Use),
-        ('\u{17aa}', '\u{17b3}',  IdentifierType::Recommended), ('\u{17b4}', '\u{17b5}',
-         IdentifierType::Default_Ignorable), ('\u{17b6}', '\u{17cd}',  IdentifierType::Recommended),
-        ('\u{17ce}', '\u{17cf}',  IdentifierType::Technical), ('\u{17d0}', '\u{17d0}',
-         IdentifierType::Recommended), ('\u{17d2}', '\u{17d2}',  IdentifierType::Recommended),
-        ('\u{17d3}', '\u{17d3}',  IdentifierType::Obsolete), ('\u{17d4}', '\u{17d6}',
-         IdentifierType::Not_XID), ('\u{17d7}', '\u{17d7}',  IdentifierType::Uncommon_Use),
-        ('\u{17d9}', '\u{17db}',  IdentifierType::Not_XID), ('\u{17dc}', '\u{17dc}',
-         IdentifierType::Obsolete), ('\u{17e0}', '\u{17e9}',  IdentifierType::Recommended),
-        ('\u{17f0}', '\u{17f9}',  IdentifierType::Not_XID), ('\u{180b}', '\u{180d}',
-         IdentifierType::Default_Ignorable), ('\u{180e}', '\u{180e}',
-         IdentifierType::Default_Ignorable), ('\u{180f}', '\u{180f}',
-         IdentifierType::Default_Ignorable), ('\u{1810}', '\u{1819}',  IdentifierType::Exclusion),
-        ('\u{1820}', '\u{1877}',  IdentifierType::Exclusion), ('\u{1878}', '\u{1878}',
-         IdentifierType::Exclusion), ('\u{1880}', '\u{18a8}',  IdentifierType::Exclusion),
-        ('\u{18aa}', '\u{18aa}',  IdentifierType::Exclusion), ('\u{18b0}', '\u{18f5}',
-         IdentifierType::Limited_Use), ('\u{1900}', '\u{191c}',  IdentifierType::Limited_Use),
-        ('\u{191d}', '\u{191e}',  IdentifierType::Limited_Use), ('\u{1920}', '\u{192b}',
-         IdentifierType::Limited_Use), ('\u{1930}', '\u{193b}',  IdentifierType::Limited_Use),
-        ('\u{1946}', '\u{196d}',  IdentifierType::Limited_Use), ('\u{1970}', '\u{1974}',
-         IdentifierType::Limited_Use), ('\u{1980}', '\u{19a9}',  IdentifierType::Limited_Use),
-        ('\u{19aa}', '\u{19ab}',  IdentifierType::Limited_Use), ('\u{19b0}', '\u{19c9}',
-         IdentifierType::Limited_Use), ('\u{19d0}', '\u{19d9}',  IdentifierType::Limited_Use),
-        ('\u{19da}', '\u{19da}',  IdentifierType::Limited_Use), ('\u{19e0}', '\u{19ff}',
-         IdentifierType::Not_XID), ('\u{1a00}', '\u{1a1b}',  IdentifierType::Exclusion),
-        ('\u{1a20}', '\u{1a5e}',  IdentifierType::Limited_Use), ('\u{1a60}', '\u{1a7c}',
-         IdentifierType::Limited_Use), ('\u{1a7f}', '\u{1a89}',  IdentifierType::Limited_Use),
-        ('\u{1a90}', '\u{1a99}',  IdentifierType::Limited_Use), ('\u{1aa7}', '\u{1aa7}',
-         IdentifierType::Limited_Use), ('\u{1ab0}', '\u{1abd}',  IdentifierType::Obsolete),
-        ('\u{1abe}', '\u{1abe}',  IdentifierType::Not_XID), ('\u{1abf}', '\u{1ac0}',
-         IdentifierType::Technical), ('\u{1ac1}', '\u{1ace}',  IdentifierType::Uncommon_Use),
-        ('\u{1acf}', '\u{1add}',  IdentifierType::Technical), ('\u{1ae0}', '\u{1aeb}',
-         IdentifierType::Technical), ('\u{1b00}', '\u{1b4b}',  IdentifierType::Limited_Use),
-        ('\u{1b4c}', '\u{1b4c}',  IdentifierType::Limited_Use), ('\u{1b50}', '\u{1b59}',
-         IdentifierType::Limited_Use), ('\u{1b80}', '\u{1baa}',  IdentifierType::Limited_Use),
-        ('\u{1bab}', '\u{1bad}',  IdentifierType::Limited_Use), ('\u{1bae}', '\u{1bb9}',
-         IdentifierType::Limited_Use), ('\u{1bba}', '\u{1bbf}',  IdentifierType::Limited_Use),
-        ('\u{1bc0}', '\u{1bf3}',  IdentifierType::Limited_Use), ('\u{1c00}', '\u{1c37}',
-         IdentifierType::Limited_Use), ('\u{1c40}', '\u{1c49}',  IdentifierType::Limited_Use),
-        ('\u{1c4d}', '\u{1c7d}',  IdentifierType::Limited_Use), ('\u{1c80}', '\u{1c88}',
-         IdentifierType::Obsolete), ('\u{1c89}', '\u{1c8a}',  IdentifierType::Uncommon_Use),
-        ('\u{1c90}', '\u{1cba}',  IdentifierType::Recommended), ('\u{1cbd}', '\u{1cbf}',
-         IdentifierType::Recommended), ('\u{1cd0}', '\u{1cd2}',  IdentifierType::Obsolete),
-        ('\u{1cd4}', '\u{1cf2}',  IdentifierType::Obsolete), ('\u{1cf3}', '\u{1cf6}',
-         IdentifierType::Obsolete), ('\u{1cf7}', '\u{1cf7}',  IdentifierType::Obsolete),
-        ('\u{1cf8}', '\u{1cf9}',  IdentifierType::Obsolete), ('\u{1cfa}', '\u{1cfa}',
-         IdentifierType::Exclusion), ('\u{1d00}', '\u{1d2b}',  IdentifierType::Technical),
-        ('\u{1d2c}', '\u{1d2e}',  IdentifierType::Not_NFKC), ('\u{1d2f}', '\u{1d2f}',
-         IdentifierType::Technical), ('\u{1d30}', '\u{1d3a}',  IdentifierType::Not_NFKC),
-        ('\u{1d3b}', '\u{1d3b}',  IdentifierType::Technical), ('\u{1d3c}', '\u{1d4d}',
-         IdentifierType::Not_NFKC), ('\u{1d4e}', '\u{1d4e}',  IdentifierType::Technical),
-        ('\u{1d4f}', '\u{1d6a}',  IdentifierType::Not_NFKC), ('\u{1d6b}', '\u{1d6b}',
-         IdentifierType::Technical), ('\u{1d6c}', '\u{1d77}',  IdentifierType::Technical),
-        ('\u{1d78}', '\u{1d78}',  IdentifierType::Not_NFKC), ('\u{1d79}', '\u{1d9a}',
-         IdentifierType::Technical), ('\u{1d9b}', '\u{1dbf}',  IdentifierType::Not_NFKC),
-        ('\u{1dc4}', '\u{1dca}',  IdentifierType::Technical), ('\u{1dcb}', '\u{1dcd}',
-         IdentifierType::Technical), ('\u{1dcf}', '\u{1dd0}',  IdentifierType::Technical),
-        ('\u{1de7}', '\u{1df5}',  IdentifierType::Technical), ('\u{1df6}', '\u{1df9}',
-         IdentifierType::Technical), ('\u{1dfb}', '\u{1dfb}',  IdentifierType::Technical),
-        ('\u{1dfc}', '\u{1dfc}',  IdentifierType::Technical), ('\u{1dfd}', '\u{1dfd}',
-         IdentifierType::Technical), ('\u{1dfe}', '\u{1dff}',  IdentifierType::Technical),
-        ('\u{1e00}', '\u{1e01}',  IdentifierType::Technical), ('\u{1e02}', '\u{1e0b}',
-         IdentifierType::Uncommon_Use), ('\u{1e0c}', '\u{1e0d}',  IdentifierType::Recommended),
-        ('\u{1e0e}', '\u{1e11}',  IdentifierType::Uncommon_Use), ('\u{1e12}', '\u{1e13}',
-         IdentifierType::Recommended), ('\u{1e14}', '\u{1e17}',  IdentifierType::Uncommon_Use),
-        ('\u{1e18}', '\u{1e1b}',  IdentifierType::Technical), ('\u{1e1c}', '\u{1e1f}',
-         IdentifierType::Uncommon_Use), ('\u{1e20}', '\u{1e21}',  IdentifierType::Recommended),
-        ('\u{1e22}', '\u{1e23}',  IdentifierType::Uncommon_Use), ('\u{1e24}', '\u{1e25}',
-         IdentifierType::Recommended), ('\u{1e26}', '\u{1e29}',  IdentifierType::Uncommon_Use),
-        ('\u{1e2a}', '\u{1e2d}',  IdentifierType::Technical), ('\u{1e2e}', '\u{1e35}',
-         IdentifierType::Uncommon_Use), ('\u{1e36}', '\u{1e37}',  IdentifierType::Recommended),
-        ('\u{1e38}', '\u{1e3b}',  IdentifierType::Uncommon_Use), ('\u{1e3c}', '\u{1e3f}',
-         IdentifierType::Recommended), ('\u{1e40}', '\u{1e41}',  IdentifierType::Uncommon_Use),
-        ('\u{1e42}', '\u{1e4b}',  IdentifierType::Recommended), ('\u{1e4c}', '\u{1e59}',
-         IdentifierType::Uncommon_Use), ('\u{1e5a}', '\u{1e5b}',  IdentifierType::Recommended),
-        ('\u{1e5c}', '\u{1e61}',  IdentifierType::Uncommon_Use), ('\u{1e62}', '\u{1e63}',
-         IdentifierType::Recommended), ('\u{1e64}', '\u{1e6b}',  IdentifierType::Uncommon_Use),
-        ('\u{1e6c}', '\u{1e6d}',  IdentifierType::Recommended), ('\u{1e6e}', '\u{1e6f}',
-         IdentifierType::Uncommon_Use), ('\u{1e70}', '\u{1e71}',  IdentifierType::Recommended),
-        ('\u{1e72}', '\u{1e77}',  IdentifierType::Technical), ('\u{1e78}', '\u{1e8b}',
-         IdentifierType::Uncommon_Use), ('\u{1e8c}', '\u{1e8d}',  IdentifierType::Recommended),
-        ('\u{1e8e}', '\u{1e91}',  IdentifierType::Uncommon_Use), ('\u{1e92}', '\u{1e93}',
-         IdentifierType::Recommended), ('\u{1e94}', '\u{1e99}',  IdentifierType::Uncommon_Use),
-        ('\u{1e9a}', '\u{1e9a}',  IdentifierType::Not_NFKC), ('\u{1e9b}', '\u{1e9b}',
-         IdentifierType::Not_NFKC), ('\u{1e9c}', '\u{1e9d}',  IdentifierType::Technical),
-        ('\u{1e9e}', '\u{1e9e}',  IdentifierType::Recommended), ('\u{1e9f}', '\u{1e9f}',
-         IdentifierType::Technical), ('\u{1ea0}', '\u{1ef9}',  IdentifierType::Recommended),
-        ('\u{1efa}', '\u{1eff}',  IdentifierType::Technical), ('\u{1f00}', '\u{1f15}',
-         IdentifierType::Obsolete), ('\u{1f18}', '\u{1f1d}',  IdentifierType::Obsolete),
-        ('\u{1f20}', '\u{1f45}',  IdentifierType::Obsolete), ('\u{1f48}', '\u{1f4d}',
-         IdentifierType::Obsolete), ('\u{1f50}', '\u{1f57}',  IdentifierType::Obsolete),
-        ('\u{1f59}', '\u{1f59}',  IdentifierType::Obsolete), ('\u{1f5b}', '\u{1f5b}',
-         IdentifierType::Obsolete), ('\u{1f5d}', '\u{1f5d}',  IdentifierType::Obsolete),
-        ('\u{1f5f}', '\u{1f70}',  IdentifierType::Obsolete), ('\u{1f71}', '\u{1f71}',
-         IdentifierType::Not_NFKC), ('\u{1f72}', '\u{1f72}',  IdentifierType::Obsolete),
-        ('\u{1f73}', '\u{1f73}',  IdentifierType::Not_NFKC), ('\u{1f74}', '\u{1f74}',
-         IdentifierType::Obsolete), ('\u{1f75}', '\u{1f75}',  IdentifierType::Not_NFKC),
-        ('\u{1f76}', '\u{1f76}',  IdentifierType::Obsolete), ('\u{1f77}', '\u{1f77}',
-         IdentifierType::Not_NFKC), ('\u{1f78}', '\u{1f78}',  IdentifierType::Obsolete),
-        ('\u{1f79}', '\u{1f79}',  IdentifierType::Not_NFKC), ('\u{1f7a}', '\u{1f7a}',
-         IdentifierType::Obsolete), ('\u{1f7b}', '\u{1f7b}',  IdentifierType::Not_NFKC),
-        ('\u{1f7c}', '\u{1f7c}',  IdentifierType::Obsolete), ('\u{1f7d}', '\u{1f7d}',
-         IdentifierType::Not_NFKC), ('\u{1f80}', '\u{1f9f}',  IdentifierType::Obsolete),
-        ('\u{1fa0}', '\u{1faf}',  IdentifierType::Recommended), ('\u{1fb2}', '\u{1fb4}',
-         IdentifierType::Recommended), ('\u{1fb6}', '\u{1fba}',  IdentifierType::Obsolete),
-        ('\u{1fbb}', '\u{1fbb}',  IdentifierType::Not_NFKC), ('\u{1fbc}', '\u{1fbc}',
-         IdentifierType::Obsolete), ('\u{1fbd}', '\u{1fc1}',  IdentifierType::Not_NFKC),
-        ('\u{1fc2}', '\u{1fc4}',  IdentifierType::Obsolete), ('\u{1fc6}', '\u{1fc8}',
-         IdentifierType::Obsolete), ('\u{1fc9}', '\u{1fc9}',  IdentifierType::Not_NFKC),
-        ('\u{1fca}', '\u{1fca}',  IdentifierType::Obsolete), ('\u{1fcb}', '\u{1fcb}',
-         IdentifierType::Not_NFKC), ('\u{1fcc}', '\u{1fcc}',  IdentifierType::Obsolete),
-        ('\u{1fcd}', '\u{1fcf}',  IdentifierType::Not_NFKC), ('\u{1fd0}', '\u{1fd2}',
-         IdentifierType::Obsolete), ('\u{1fd3}', '\u{1fd3}',  IdentifierType::Not_NFKC),
-        ('\u{1fd6}', '\u{1fda}',  IdentifierType::Obsolete), ('\u{1fdb}', '\u{1fdb}',
-         IdentifierType::Not_NFKC), ('\u{1fdd}', '\u{1fdf}',  IdentifierType::Not_NFKC),
-        ('\u{1fe0}', '\u{1fe2}',  IdentifierType::Obsolete), ('\u{1fe3}', '\u{1fe3}',
-         IdentifierType::Not_NFKC), ('\u{1fe4}', '\u{1fea}',  IdentifierType::Obsolete),
-        ('\u{1feb}', '\u{1feb}',  IdentifierType::Not_NFKC), ('\u{1fec}', '\u{1fec}',
-         IdentifierType::Recommended), ('\u{1fed}', '\u{1fef}',  IdentifierType::Not_NFKC),
-        ('\u{1ff2}', '\u{1ff4}',  IdentifierType::Obsolete), ('\u{1ff6}', '\u{1ff8}',
-         IdentifierType::Obsolete), ('\u{1ff9}', '\u{1ff9}',  IdentifierType::Not_NFKC),
-        ('\u{1ffa}', '\u{1ffa}',  IdentifierType::Obsolete), ('\u{1ffb}', '\u{1ffb}',
-         IdentifierType::Not_NFKC), ('\u{1ffc}', '\u{1ffc}',  IdentifierType::Obsolete),
-        ('\u{1ffd}', '\u{1ffe}',  IdentifierType::Not_NFKC), ('\u{2000}', '\u{200a}',
-         IdentifierType::Not_NFKC), ('\u{200b}', '\u{200f}',  IdentifierType::Default_Ignorable),
-        ('\u{2010}', '\u{2010}',  IdentifierType::Inclusion), ('\u{2011}', '\u{2011}',
-         IdentifierType::Not_NFKC), ('\u{2012}', '\u{2016}',  IdentifierType::Not_XID), ('\u{2017}',
-        '\u{2017}',  IdentifierType::Not_NFKC), ('\u{2018}', '\u{2018}',  IdentifierType::Not_XID),
-        ('\u{2019}', '\u{2019}',  IdentifierType::Inclusion), ('\u{201a}', '\u{2023}',
-         IdentifierType::Not_XID), ('\u{2024}', '\u{2026}',  IdentifierType::Not_NFKC), ('\u{2027}',
-        '\u{2027}',  IdentifierType::Inclusion), ('\u{2028}', '\u{2029}',  IdentifierType::Not_XID),
-        ('\u{202a}', '\u{202e}',  IdentifierType::Default_Ignorable), ('\u{202f}', '\u{202f}',
-         IdentifierType::Not_NFKC), ('\u{2030}', '\u{2032}',  IdentifierType::Not_XID), ('\u{2033}',
-        '\u{2034}',  IdentifierType::Not_NFKC), ('\u{2035}', '\u{2035}',  IdentifierType::Not_XID),
-        ('\u{2036}', '\u{2037}',  IdentifierType::Not_NFKC), ('\u{2038}', '\u{203b}',
-         IdentifierType::Not_XID), ('\u{203c}', '\u{203c}',  IdentifierType::Not_NFKC), ('\u{203d}',
-        '\u{203d}',  IdentifierType::Not_XID), ('\u{203e}', '\u{203e}',  IdentifierType::Not_NFKC),
-        ('\u{203f}', '\u{2040}',  IdentifierType::Technical), ('\u{2041}', '\u{2046}',
-         IdentifierType::Not_XID), ('\u{2047}', '\u{2047}',  IdentifierType::Not_NFKC), ('\u{2048}',
-        '\u{2049}',  IdentifierType::Not_NFKC), ('\u{204a}', '\u{204d}',  IdentifierType::Not_XID),
-        ('\u{204e}', '\u{2052}',  IdentifierType::Not_XID), ('\u{2053}', '\u{2053}',
-         IdentifierType::Not_XID), ('\u{2054}', '\u{2054}',  IdentifierType::Uncommon_Use),
-        ('\u{2055}', '\u{2055}',  IdentifierType::Not_XID), ('\u{2057}', '\u{2057}',
-         IdentifierType::Not_NFKC), ('\u{205f}', '\u{205f}',  IdentifierType::Not_NFKC),
-        ('\u{2060}', '\u{2063}',  IdentifierType::Default_Ignorable), ('\u{2064}', '\u{2064}',
-         IdentifierType::Default_Ignorable), ('\u{2066}', '\u{2069}',
-         IdentifierType::Default_Ignorable), ('\u{206a}', '\u{206f}',  IdentifierType::Deprecated),
-        ('\u{2070}', '\u{2070}',  IdentifierType::Not_NFKC), ('\u{2071}', '\u{2071}',
-         IdentifierType::Not_NFKC), ('\u{2074}', '\u{208e}',  IdentifierType::Not_NFKC),
-        ('\u{2090}', '\u{2094}',  IdentifierType::Not_NFKC), ('\u{2095}', '\u{209c}',
-         IdentifierType::Not_NFKC), ('\u{20a0}', '\u{20a7}',  IdentifierType::Not_XID), ('\u{20a8}',
-        '\u{20a8}',  IdentifierType::Not_NFKC), ('\u{20a9}', '\u{20aa}',  IdentifierType::Not_XID),
-        ('\u{20ab}', '\u{20ab}',  IdentifierType::Not_XID), ('\u{20ac}', '\u{20ac}',
-         IdentifierType::Not_XID), ('\u{20ad}', '\u{20af}',  IdentifierType::Not_XID), ('\u{20b0}',
-        '\u{20b1}',  IdentifierType::Not_XID), ('\u{20b2}', '\u{20b5}',  IdentifierType::Not_XID),
-        ('\u{20b6}', '\u{20b8}',  IdentifierType::Not_XID), ('\u{20b9}', '\u{20b9}',
-         IdentifierType::Not_XID), ('\u{20ba}', '\u{20ba}',  IdentifierType::Not_XID), ('\u{20bb}',
-        '\u{20bd}',  IdentifierType::Not_XID), ('\u{20be}', '\u{20be}',  IdentifierType::Not_XID),
-        ('\u{20bf}', '\u{20bf}',  IdentifierType::Not_XID), ('\u{20c0}', '\u{20c0}',
-         IdentifierType::Not_XID), ('\u{20c1}', '\u{20c1}',  IdentifierType::Not_XID), ('\u{20d0}',
-        '\u{20dc}',  IdentifierType::Technical), ('\u{20e1}', '\u{20e1}',
-         IdentifierType::Technical), ('\u{20e5}', '\u{20ea}',  IdentifierType::Technical),
-        ('\u{20eb}', '\u{20eb}',  IdentifierType::Technical), ('\u{20ec}', '\u{20ef}',
-         IdentifierType::Technical), ('\u{20f0}', '\u{20f0}',  IdentifierType::Technical),
-        ('\u{2100}', '\u{2103}',  IdentifierType::Not_NFKC), ('\u{2104}', '\u{2104}',
-         IdentifierType::Not_XID), ('\u{2105}', '\u{2107}',  IdentifierType::Not_NFKC), ('\u{2108}',
-        '\u{2108}',  IdentifierType::Not_XID), ('\u{2109}', '\u{2113}',  IdentifierType::Not_NFKC),
-        ('\u{2114}', '\u{2114}',  IdentifierType::Not_XID), ('\u{2115}', '\u{2116}',
-         IdentifierType::Not_NFKC), ('\u{2117}', '\u{2117}',  IdentifierType::Not_XID), ('\u{2118}',
-        '\u{2118}',  IdentifierType::Technical), ('\u{2119}', '\u{211d}',
-         IdentifierType::Not_NFKC), ('\u{211e}', '\u{211f}',  IdentifierType::Not_XID), ('\u{2120}',
-        '\u{2122}',  IdentifierType::Not_NFKC), ('\u{2123}', '\u{2123}',  IdentifierType::Not_XID),
-        ('\u{2124}', '\u{2124}',  IdentifierType::Not_NFKC), ('\u{2125}', '\u{2125}',
-         IdentifierType::Not_XID), ('\u{2126}', '\u{2126}',  IdentifierType::Not_NFKC), ('\u{2128}',
-        '\u{2128}',  IdentifierType::Not_NFKC), ('\u{2129}', '\u{2129}',  IdentifierType::Not_XID),
-        ('\u{212a}', '\u{212d}',  IdentifierType::Not_NFKC), ('\u{212e}', '\u{212e}',
-         IdentifierType::Technical), ('\u{212f}', '\u{2131}',  IdentifierType::Not_NFKC),
-        ('\u{2132}', '\u{2132}',  IdentifierType::Obsolete), ('\u{2133}', '\u{2138}',
-         IdentifierType::Not_NFKC), ('\u{2139}', '\u{2139}',  IdentifierType::Not_NFKC),
-        ('\u{213a}', '\u{213a}',  IdentifierType::Not_XID), ('\u{213b}', '\u{213b}',
-         IdentifierType::Not_NFKC), ('\u{213c}', '\u{213c}',  IdentifierType::Not_NFKC),
-        ('\u{213d}', '\u{2140}',  IdentifierType::Not_NFKC), ('\u{2141}', '\u{2144}',
-         IdentifierType::Not_XID), ('\u{2145}', '\u{2149}',  IdentifierType::Not_NFKC), ('\u{214a}',
-        '\u{214b}',  IdentifierType::Not_XID), ('\u{214c}', '\u{214c}',  IdentifierType::Not_XID),
-        ('\u{214d}', '\u{214d}',  IdentifierType::Not_XID), ('\u{214e}', '\u{214e}',
-         IdentifierType::Obsolete), ('\u{2150}', '\u{2152}',  IdentifierType::Not_NFKC),
-        ('\u{2153}', '\u{217f}',  IdentifierType::Not_NFKC), ('\u{2184}', '\u{2184}',
-         IdentifierType::Obsolete), ('\u{2185}', '\u{2188}',  IdentifierType::Obsolete),
-        ('\u{2189}', '\u{2189}',  IdentifierType::Not_NFKC), ('\u{2190}', '\u{21ea}',
-         IdentifierType::Not_XID), ('\u{21eb}', '\u{21f3}',  IdentifierType::Not_XID), ('\u{21f4}',
-        '\u{21ff}',  IdentifierType::Not_XID), ('\u{2200}', '\u{222b}',  IdentifierType::Not_XID),
-        ('\u{222c}', '\u{222d}',  IdentifierType::Not_NFKC), ('\u{222e}', '\u{222e}',
-         IdentifierType::Not_XID), ('\u{222f}', '\u{2230}',  IdentifierType::Not_NFKC), ('\u{2231}',
-        '\u{22f1}',  IdentifierType::Not_XID), ('\u{22f2}', '\u{22ff}',  IdentifierType::Not_XID),
-        ('\u{2300}', '\u{2300}',  IdentifierType::Not_XID), ('\u{2301}', '\u{2301}',
-         IdentifierType::Not_XID), ('\u{2302}', '\u{2328}',  IdentifierType::Not_XID), ('\u{2329}',
-        '\u{232a}',  IdentifierType::Deprecated), ('\u{232b}', '\u{237a}',
-         IdentifierType::Not_XID), ('\u{237b}', '\u{237b}',  IdentifierType::Not_XID), ('\u{237c}',
-        '\u{237c}',  IdentifierType::Not_XID), ('\u{237d}', '\u{239a}',  IdentifierType::Not_XID),
-        ('\u{239b}', '\u{23ce}',  IdentifierType::Not_XID), ('\u{23cf}', '\u{23d0}',
-         IdentifierType::Not_XID), ('\u{23d1}', '\u{23db}',  IdentifierType::Not_XID), ('\u{23dc}',
-        '\u{23e7}',  IdentifierType::Not_XID), ('\u{23e8}', '\u{23e8}',  IdentifierType::Not_XID),
-        ('\u{23e9}', '\u{23f3}',  IdentifierType::Not_XID), ('\u{23f4}', '\u{23fa}',
-         IdentifierType::Not_XID), ('\u{23fb}', '\u{23fe}',  IdentifierType::Not_XID), ('\u{23ff}',
-        '\u{23ff}',  IdentifierType::Not_XID), ('\u{2400}', '\u{2424}',  IdentifierType::Not_XID),
-        ('\u{2425}', '\u{2426}',  IdentifierType::Not_XID), ('\u{2427}', '\u{2429}',
-         IdentifierType::Not_XID), ('\u{2440}', '\u{244a}',  IdentifierType::Not_XID), ('\u{2460}',
-        '\u{24ea}',  IdentifierType::Not_NFKC), ('\u{2500}', '\u{2595}',  IdentifierType::Not_XID),
-        ('\u{2596}', '\u{259f}',  IdentifierType::Not_XID), ('\u{25a0}', '\u{25ef}',
-         IdentifierType::Not_XID), ('\u{25f0}', '\u{25f7}',  IdentifierType::Not_XID), ('\u{25f8}',
-        '\u{25ff}',  IdentifierType::Not_XID), ('\u{2600}', '\u{2613}',  IdentifierType::Not_XID),
-        ('\u{2614}', '\u{2615}',  IdentifierType::Not_XID), ('\u{2616}', '\u{2617}',
-         IdentifierType::Not_XID), ('\u{2618}', '\u{2618}',  IdentifierType::Not_XID), ('\u{2619}',
-        '\u{2619}',  IdentifierType::Not_XID), ('\u{261a}', '\u{266f}',  IdentifierType::Not_XID),
-        ('\u{2670}', '\u{2671}',  IdentifierType::Not_XID), ('\u{2672}', '\u{267d}',
-         IdentifierType::Not_XID), ('\u{267e}', '\u{267f}',  IdentifierType::Not_XID), ('\u{2680}',
-        '\u{2689}',  IdentifierType::Not_XID), ('\u{268a}', '\u{2691}',  IdentifierType::Not_XID),
-        ('\u{2692}', '\u{269c}',  IdentifierType::Not_XID), ('\u{269d}', '\u{269d}',
-         IdentifierType::Not_XID), ('\u{269e}', '\u{269f}',  IdentifierType::Not_XID), ('\u{26a0}',
-        '\u{26a1}',  IdentifierType::Not_XID), ('\u{26a2}', '\u{26b1}',  IdentifierType::Not_XID),
-        ('\u{26b2}', '\u{26b2}',  IdentifierType::Not_XID), ('\u{26b3}', '\u{26bc}',
-         IdentifierType::Not_XID), ('\u{26bd}', '\u{26bf}',  IdentifierType::Not_XID), ('\u{26c0}',
-        '\u{26c3}',  IdentifierType::Not_XID), ('\u{26c4}', '\u{26cd}',  IdentifierType::Not_XID),
-        ('\u{26ce}', '\u{26ce}',  IdentifierType::Not_XID), ('\u{26cf}', '\u{26e1}',
-         IdentifierType::Not_XID), ('\u{26e2}', '\u{26e2}',  IdentifierType::Not_XID), ('\u{26e3}',
-        '\u{26e3}',  IdentifierType::Not_XID), ('\u{26e4}', '\u{26e7}',  IdentifierType::Not_XID),
-        ('\u{26e8}', '\u{26ff}',  IdentifierType::Not_XID), ('\u{2700}', '\u{2700}',
-         IdentifierType::Not_XID), ('\u{2701}', '\u{2704}',  IdentifierType::Not_XID), ('\u{2705}',
-        '\u{2705}',  IdentifierType::Not_XID), ('\u{2706}', '\u{2709}',  IdentifierType::Not_XID),
-        ('\u{270a}', '\u{270b}',  IdentifierType::Not_XID), ('\u{270c}', '\u{2727}',
-         IdentifierType::Not_XID), ('\u{2728}', '\u{2728}',  IdentifierType::Not_XID), ('\u{2729}',
-        '\u{274b}',  IdentifierType::Not_XID), ('\u{274c}', '\u{274c}',  IdentifierType::Not_XID),
-        ('\u{274d}', '\u{274d}',  IdentifierType::Not_XID), ('\u{274e}', '\u{274e}',
-         IdentifierType::Not_XID), ('\u{274f}', '\u{2752}',  IdentifierType::Not_XID), ('\u{2753}',
-        '\u{2755}',  IdentifierType::Not_XID), ('\u{2756}', '\u{2756}',  IdentifierType::Not_XID),
-        ('\u{2757}', '\u{2757}',  IdentifierType::Not_XID), ('\u{2758}', '\u{275e}',
-         IdentifierType::Not_XID), ('\u{275f}', '\u{2760}',  IdentifierType::Not_XID), ('\u{2761}',
-        '\u{2767}',  IdentifierType::Not_XID), ('\u{2768}', '\u{2775}',  IdentifierType::Not_XID),
-        ('\u{2776}', '\u{2794}',  IdentifierType::Not_XID), ('\u{2795}', '\u{2797}',
-         IdentifierType::Not_XID), ('\u{2798}', '\u{27af}',  IdentifierType::Not_XID), ('\u{27b0}',
-        '\u{27b0}',  IdentifierType::Not_XID), ('\u{27b1}', '\u{27be}',  IdentifierType::Not_XID),
-        ('\u{27bf}', '\u{27bf}',  IdentifierType::Not_XID), ('\u{27c0}', '\u{27c6}',
-         IdentifierType::Not_XID), ('\u{27c7}', '\u{27ca}',  IdentifierType::Not_XID), ('\u{27cb}',
-        '\u{27cb}',  IdentifierType::Not_XID), ('\u{27cc}', '\u{27cc}',  IdentifierType::Not_XID),
-        ('\u{27cd}', '\u{27cd}',  IdentifierType::Not_XID), ('\u{27ce}', '\u{27cf}',
-         IdentifierType::Not_XID), ('\u{27d0}', '\u{27eb}',  IdentifierType::Not_XID), ('\u{27ec}',
-        '\u{27ef}',  IdentifierType::Not_XID), ('\u{27f0}', '\u{27ff}',  IdentifierType::Not_XID),
-        ('\u{2900}', '\u{2a0b}',  IdentifierType::Not_XID), ('\u{2a0c}', '\u{2a0c}',
-         IdentifierType::Not_NFKC), ('\u{2a0d}', '\u{2a73}',  IdentifierType::Not_XID), ('\u{2a74}',
-        '\u{2a76}',  IdentifierType::Not_NFKC), ('\u{2a77}', '\u{2adb}',  IdentifierType::Not_XID),
-        ('\u{2adc}', '\u{2adc}',  IdentifierType::Not_NFKC), ('\u{2add}', '\u{2aff}',
-         IdentifierType::Not_XID), ('\u{2b00}', '\u{2b0d}',  IdentifierType::Not_XID), ('\u{2b0e}',
-        '\u{2b13}',  IdentifierType::Not_XID), ('\u{2b14}', '\u{2b1a}',  IdentifierType::Not_XID),
-        ('\u{2b1b}', '\u{2b1f}',  IdentifierType::Not_XID), ('\u{2b20}', '\u{2b23}',
-         IdentifierType::Not_XID), ('\u{2b24}', '\u{2b4c}',  IdentifierType::Not_XID), ('\u{2b4d}',
-        '\u{2b4f}',  IdentifierType::Not_XID), ('\u{2b50}', '\u{2b54}',  IdentifierType::Not_XID),
-        ('\u{2b55}', '\u{2b59}',  IdentifierType::Not_XID), ('\u{2b5a}', '\u{2b73}',
-         IdentifierType::Not_XID), ('\u{2b76}', '\u{2b95}',  IdentifierType::Not_XID), ('\u{2b96}',
-        '\u{2b96}',  IdentifierType::Not_XID), ('\u{2b97}', '\u{2b97}',  IdentifierType::Not_XID),
-        ('\u{2b98}', '\u{2bb9}',  IdentifierType::Not_XID), ('\u{2bba}', '\u{2bbc}',
-         IdentifierType::Not_XID), ('\u{2bbd}', '\u{2bc8}',  IdentifierType::Not_XID), ('\u{2bc9}',
-        '\u{2bc9}',  IdentifierType::Not_XID), ('\u{2bca}', '\u{2bd1}',  IdentifierType::Not_XID),
-        ('\u{2bd2}', '\u{2bd2}',  IdentifierType::Not_XID), ('\u{2bd3}', '\u{2beb}',
-         IdentifierType::Not_XID), ('\u{2bf0}', '\u{2bfe}',  IdentifierType::Not_XID), ('\u{2bff}',
-        '\u{2bff}',  IdentifierType::Not_XID), ('\u{2c00}', '\u{2c2e}',  IdentifierType::Exclusion),
-        ('\u{2c2f}', '\u{2c2f}',  IdentifierType::Exclusion), ('\u{2c30}', '\u{2c5e}',
-         IdentifierType::Exclusion), ('\u{2c5f}', '\u{2c5f}',  IdentifierType::Exclusion),
-        ('\u{2c60}', '\u{2c67}',  IdentifierType::Technical), ('\u{2c68}', '\u{2c6c}',
-         IdentifierType::Uncommon_Use), ('\u{2c6d}', '\u{2c6f}',  IdentifierType::Obsolete),
-        ('\u{2c70}', '\u{2c70}',  IdentifierType::Obsolete), ('\u{2c71}', '\u{2c73}',
-         IdentifierType::Obsolete), ('\u{2c74}', '\u{2c76}',  IdentifierType::Obsolete),
-        ('\u{2c77}', '\u{2c77}',  IdentifierType::Technical), ('\u{2c78}', '\u{2c7b}',
-         IdentifierType::Technical), ('\u{2c7c}', '\u{2c7d}',  IdentifierType::Not_NFKC),
-        ('\u{2c7e}', '\u{2c7f}',  IdentifierType::Obsolete), ('\u{2c80}', '\u{2ce4}',
-         IdentifierType::Exclusion), ('\u{2ceb}', '\u{2cef}',  IdentifierType::Exclusion),
-        ('\u{2cf2}', '\u{2cf3}',  IdentifierType::Exclusion), ('\u{2d00}', '\u{2d25}',
-         IdentifierType::Obsolete), ('\u{2d27}', '\u{2d27}',  IdentifierType::Technical),
-        ('\u{2d2d}', '\u{2d2d}',  IdentifierType::Technical), ('\u{2d30}', '\u{2d65}',
-         IdentifierType::Limited_Use), ('\u{2d66}', '\u{2d67}',  IdentifierType::Limited_Use),
-        ('\u{2d6f}', '\u{2d6f}',  IdentifierType::Not_NFKC), ('\u{2d7f}', '\u{2d7f}',
-         IdentifierType::Limited_Use), ('\u{2d80}', '\u{2d96}',  IdentifierType::Uncommon_Use),
-        ('\u{2da0}', '\u{2da6}',  IdentifierType::Uncommon_Use), ('\u{2da8}', '\u{2dae}',
-         IdentifierType::Uncommon_Use), ('\u{2db0}', '\u{2db6}',  IdentifierType::Uncommon_Use),
-        ('\u{2db8}', '\u{2dbe}',  IdentifierType::Uncommon_Use), ('\u{2dc0}', '\u{2dc6}',
-         IdentifierType::Uncommon_Use), ('\u{2dc8}', '\u{2dce}',  IdentifierType::Uncommon_Use),
-        ('\u{2dd0}', '\u{2dd6}',  IdentifierType::Uncommon_Use), ('\u{2dd8}', '\u{2dde}',
-         IdentifierType::Uncommon_Use), ('\u{2de0}', '\u{2dff}',  IdentifierType::Obsolete),
-        ('\u{2e17}', '\u{2e17}',  IdentifierType::Not_XID), ('\u{2e18}', '\u{2e1b}',
-         IdentifierType::Not_XID), ('\u{2e1c}', '\u{2e1d}',  IdentifierType::Not_XID), ('\u{2e1e}',
-        '\u{2e29}',  IdentifierType::Not_XID), ('\u{2e33}', '\u{2e34}',  IdentifierType::Not_XID),
-        ('\u{2e36}', '\u{2e38}',  IdentifierType::Not_XID), ('\u{2e3a}', '\u{2e3b}',
-         IdentifierType::Not_XID), ('\u{2e3d}', '\u{2e42}',  IdentifierType::Not_XID), ('\u{2e43}',
-        '\u{2e44}',  IdentifierType::Not_XID), ('\u{2e45}', '\u{2e49}',  IdentifierType::Not_XID),
-        ('\u{2e4a}', '\u{2e4e}',  IdentifierType::Not_XID), ('\u{2e4f}', '\u{2e4f}',
-         IdentifierType::Not_XID), ('\u{2e50}', '\u{2e52}',  IdentifierType::Not_XID), ('\u{2e53}',
-        '\u{2e5d}',  IdentifierType::Not_XID), ('\u{2e80}', '\u{2e99}',  IdentifierType::Not_XID),
-        ('\u{2e9b}', '\u{2e9e}',  IdentifierType::Not_XID), ('\u{2e9f}', '\u{2e9f}',
-         IdentifierType::Not_NFKC), ('\u{2ea0}', '\u{2ef2}',  IdentifierType::Not_XID), ('\u{2ef3}',
-        '\u{2ef3}',  IdentifierType::Not_NFKC), ('\u{2f00}', '\u{2fd5}',  IdentifierType::Not_NFKC),
-        ('\u{2ff0}', '\u{2ffb}',  IdentifierType::Not_XID), ('\u{2ffc}', '\u{2fff}',
-         IdentifierType::Not_XID), ('\u{3000}', '\u{3000}',  IdentifierType::Not_NFKC), ('\u{3001}',
-        '\u{3004}',  IdentifierType::Not_XID), ('\u{3005}', '\u{3007}',
-         IdentifierType::Recommended), ('\u{3008}', '\u{301d}',  IdentifierType::Not_XID),
-        ('\u{301f}', '\u{3020}',  IdentifierType::Not_XID), ('\u{3021}', '\u{302d}',
-         IdentifierType::Technical), ('\u{3030}', '\u{3030}',  IdentifierType::Not_XID),
-        ('\u{3031}', '\u{3035}',  IdentifierType::Technical), ('\u{3036}', '\u{3036}',
-         IdentifierType::Not_NFKC), ('\u{3037}', '\u{3037}',  IdentifierType::Not_XID), ('\u{3038}',
-        '\u{303a}',  IdentifierType::Not_NFKC), ('\u{303b}', '\u{303c}',
-         IdentifierType::Technical), ('\u{303d}', '\u{303d}',  IdentifierType::Not_XID),
-        ('\u{303e}', '\u{303e}',  IdentifierType::Not_XID), ('\u{303f}', '\u{303f}',
-         IdentifierType::Not_XID), ('\u{3041}', '\u{3094}',  IdentifierType::Recommended),
-        ('\u{3095}', '\u{3096}',  IdentifierType::Recommended), ('\u{3099}', '\u{309a}',
-         IdentifierType::Uncommon_Use), ('\u{309b}', '\u{309c}',  IdentifierType::Not_NFKC),
-        ('\u{309d}', '\u{309e}',  IdentifierType::Recommended), ('\u{309f}', '\u{309f}',
-         IdentifierType::Not_NFKC), ('\u{30a0}', '\u{30a0}',  IdentifierType::Inclusion),
-        ('\u{30a1}', '\u{30fa}',  IdentifierType::Recommended), ('\u{30fb}', '\u{30fb}',
-         IdentifierType::Inclusion), ('\u{30fc}', '\u{30fe}',  IdentifierType::Recommended),
-        ('\u{30ff}', '\u{30ff}',  IdentifierType::Not_NFKC), ('\u{3105}', '\u{312c}',
-         IdentifierType::Limited_Use), ('\u{312d}', '\u{312d}',  IdentifierType::Limited_Use),
-        ('\u{312f}', '\u{312f}',  IdentifierType::Limited_Use), ('\u{3131}', '\u{3163}',
-         IdentifierType::Not_NFKC), ('\u{3164}', '\u{3164}',  IdentifierType::Default_Ignorable),
-        ('\u{3165}', '\u{318e}',  IdentifierType::Not_NFKC), ('\u{3190}', '\u{3191}',
-         IdentifierType::Not_XID), ('\u{3192}', '\u{319f}',  IdentifierType::Not_NFKC), ('\u{31a0}',
-        '\u{31b7}',  IdentifierType::Limited_Use), ('\u{31b8}', '\u{31ba}',
-         IdentifierType::Limited_Use), ('\u{31bb}', '\u{31bf}',  IdentifierType::Limited_Use),
-        ('\u{31c0}', '\u{31cf}',  IdentifierType::Not_XID), ('\u{31d0}', '\u{31e3}',
-         IdentifierType::Not_XID), ('\u{31e4}', '\u{31e5}',  IdentifierType::Not_XID), ('\u{31ef}',
-        '\u{31ef}',  IdentifierType::Not_XID), ('\u{31f0}', '\u{31ff}',  IdentifierType::Obsolete),
-        ('\u{3200}', '\u{321c}',  IdentifierType::Not_NFKC), ('\u{321d}', '\u{321e}',
-         IdentifierType::Not_NFKC), ('\u{3220}', '\u{3243}',  IdentifierType::Not_NFKC),
-        ('\u{3244}', '\u{3247}',  IdentifierType::Not_NFKC), ('\u{3248}', '\u{324f}',
-         IdentifierType::Not_XID), ('\u{3250}', '\u{3250}',  IdentifierType::Not_NFKC), ('\u{3251}',
-        '\u{325f}',  IdentifierType::Not_NFKC), ('\u{3260}', '\u{327b}',  IdentifierType::Not_NFKC),
-        ('\u{327c}', '\u{327d}',  IdentifierType::Not_NFKC), ('\u{327e}', '\u{327e}',
-         IdentifierType::Not_NFKC), ('\u{3280}', '\u{32b0}',  IdentifierType::Not_NFKC),
-        ('\u{32b1}', '\u{32bf}',  IdentifierType::Not_NFKC), ('\u{32c0}', '\u{32cb}',
-         IdentifierType::Not_NFKC), ('\u{32cc}', '\u{32cf}',  IdentifierType::Not_NFKC),
-        ('\u{32d0}', '\u{32fe}',  IdentifierType::Not_NFKC), ('\u{32ff}', '\u{32ff}',
-         IdentifierType::Not_NFKC), ('\u{3300}', '\u{3376}',  IdentifierType::Not_NFKC),
-        ('\u{3377}', '\u{337a}',  IdentifierType::Not_NFKC), ('\u{337b}', '\u{33dd}',
-         IdentifierType::Not_NFKC), ('\u{33de}', '\u{33df}',  IdentifierType::Not_NFKC),
-        ('\u{33e0}', '\u{33fe}',  IdentifierType::Not_NFKC), ('\u{33ff}', '\u{33ff}',
-         IdentifierType::Not_NFKC), ('\u{3400}', '\u{3446}',  IdentifierType::Uncommon_Use),
-        ('\u{3447}', '\u{3447}',  IdentifierType::Recommended), ('\u{3448}', '\u{3472}',
-         IdentifierType::Uncommon_Use), ('\u{3473}', '\u{3473}',  IdentifierType::Recommended),
-        ('\u{3474}', '\u{34e3}',  IdentifierType::Uncommon_Use), ('\u{34e4}', '\u{34e4}',
-         IdentifierType::Recommended), ('\u{34e5}', '\u{3576}',  IdentifierType::Uncommon_Use),
-        ('\u{3577}', '\u{3577}',  IdentifierType::Recommended), ('\u{3578}', '\u{359d}',
-         IdentifierType::Uncommon_Use), ('\u{359e}', '\u{359e}',  IdentifierType::Recommended),
-        ('\u{359f}', '\u{35a0}',  IdentifierType::Uncommon_Use), ('\u{35a1}', '\u{35a1}',
-         IdentifierType::Recommended), ('\u{35a2}', '\u{35ac}',  IdentifierType::Uncommon_Use),
-        ('\u{35ad}', '\u{35ad}',  IdentifierType::Recommended), ('\u{35ae}', '\u{35be}',
-         IdentifierType::Uncommon_Use), ('\u{35bf}', '\u{35bf}',  IdentifierType::Recommended),
-        ('\u{35c0}', '\u{35cd}',  IdentifierType::Uncommon_Use), ('\u{35ce}', '\u{35ce}',
-         IdentifierType::Recommended), ('\u{35cf}', '\u{35f2}',  IdentifierType::Uncommon_Use),
-        ('\u{35f3}', '\u{35f3}',  IdentifierType::Recommended), ('\u{35f4}', '\u{35fd}',
-         IdentifierType::Uncommon_Use), ('\u{35fe}', '\u{35fe}',  IdentifierType::Recommended),
-        ('\u{35ff}', '\u{360d}',  IdentifierType::Uncommon_Use), ('\u{360e}', '\u{360e}',
-         IdentifierType::Recommended), ('\u{360f}', '\u{3619}',  IdentifierType::Uncommon_Use),
-        ('\u{361a}', '\u{361a}',  IdentifierType::Recommended), ('\u{361b}', '\u{3917}',
-         IdentifierType::Uncommon_Use), ('\u{3918}', '\u{3918}',  IdentifierType::Recommended),
-        ('\u{3919}', '\u{395f}',  IdentifierType::Uncommon_Use), ('\u{3960}', '\u{3960}',
-         IdentifierType::Recommended), ('\u{3961}', '\u{396d}',  IdentifierType::Uncommon_Use),
-        ('\u{396e}', '\u{396e}',  IdentifierType::Recommended), ('\u{396f}', '\u{39ce}',
-         IdentifierType::Uncommon_Use), ('\u{39cf}', '\u{39d0}',  IdentifierType::Recommended),
-        ('\u{39d1}', '\u{39da}',  IdentifierType::Uncommon_Use), ('\u{39db}', '\u{39db}',
-         IdentifierType::Recommended), ('\u{39dc}', '\u{39de}',  IdentifierType::Uncommon_Use),
-        ('\u{39df}', '\u{39df}',  IdentifierType::Recommended), ('\u{39e0}', '\u{39f7}',
-         IdentifierType::Uncommon_Use), ('\u{39f8}', '\u{39f8}',  IdentifierType::Recommended),
-        ('\u{39f9}', '\u{39fd}',  IdentifierType::Uncommon_Use), ('\u{39fe}', '\u{39fe}',
-         IdentifierType::Recommended), ('\u{39ff}', '\u{3a17}',  IdentifierType::Uncommon_Use),
-        ('\u{3a18}', '\u{3a18}',  IdentifierType::Recommended), ('\u{3a19}', '\u{3a51}',
-         IdentifierType::Uncommon_Use), ('\u{3a52}', '\u{3a52}',  IdentifierType::Recommended),
-        ('\u{3a53}', '\u{3a5b}',  IdentifierType::Uncommon_Use), ('\u{3a5c}', '\u{3a5c}',
-         IdentifierType::Recommended), ('\u{3a5d}', '\u{3a66}',  IdentifierType::Uncommon_Use),
-        ('\u{3a67}', '\u{3a67}',  IdentifierType::Recommended), ('\u{3a68}', '\u{3a72}',
-         IdentifierType::Uncommon_Use), ('\u{3a73}', '\u{3a73}',  IdentifierType::Recommended),
-        ('\u{3a74}', '\u{3b38}',  IdentifierType::Uncommon_Use), ('\u{3b39}', '\u{3b39}',
-         IdentifierType::Recommended), ('\u{3b3a}', '\u{3b4d}',  IdentifierType::Uncommon_Use),
-        ('\u{3b4e}', '\u{3b4e}',  IdentifierType::Recommended), ('\u{3b4f}', '\u{3ba2}',
-         IdentifierType::Uncommon_Use), ('\u{3ba3}', '\u{3ba3}',  IdentifierType::Recommended),
-        ('\u{3ba4}', '\u{3c6d}',  IdentifierType::Uncommon_Use), ('\u{3c6e}', '\u{3c6e}',
-         IdentifierType::Recommended), ('\u{3c6f}', '\u{3cdf}',  IdentifierType::Uncommon_Use),
-        ('\u{3ce0}', '\u{3ce0}',  IdentifierType::Recommended), ('\u{3ce1}', '\u{3de6}',
-         IdentifierType::Uncommon_Use), ('\u{3de7}', '\u{3de7}',  IdentifierType::Recommended),
-        ('\u{3de8}', '\u{3dea}',  IdentifierType::Uncommon_Use), ('\u{3deb}', '\u{3deb}',
-         IdentifierType::Recommended), ('\u{3dec}', '\u{3e73}',  IdentifierType::Uncommon_Use),
-        ('\u{3e74}', '\u{3e74}',  IdentifierType::Recommended), ('\u{3e75}', '\u{3ecf}',
-         IdentifierType::Uncommon_Use), ('\u{3ed0}', '\u{3ed0}',  IdentifierType::Recommended),
-        ('\u{3ed1}', '\u{4055}',  IdentifierType::Uncommon_Use), ('\u{4056}', '\u{4056}',
-         IdentifierType::Recommended), ('\u{4057}', '\u{4064}',  IdentifierType::Uncommon_Use),
-        ('\u{4065}', '\u{4065}',  IdentifierType::Recommended), ('\u{4066}', '\u{4069}',
-         IdentifierType::Uncommon_Use), ('\u{406a}', '\u{406a}',  IdentifierType::Recommended),
-        ('\u{406b}', '\u{40ba}',  IdentifierType::Uncommon_Use), ('\u{40bb}', '\u{40bb}',
-         IdentifierType::Recommended), ('\u{40bc}', '\u{40de}',  IdentifierType::Uncommon_Use),
-        ('\u{40df}', '\u{40df}',  IdentifierType::Recommended), ('\u{40e0}', '\u{4136}',
-         IdentifierType::Uncommon_Use), ('\u{4137}', '\u{4137}',  IdentifierType::Recommended),
-        ('\u{4138}', '\u{415e}',  IdentifierType::Uncommon_Use), ('\u{415f}', '\u{415f}',
-         IdentifierType::Recommended), ('\u{4160}', '\u{4336}',  IdentifierType::Uncommon_Use),
-        ('\u{4337}', '\u{4337}',  IdentifierType::Recommended), ('\u{4338}', '\u{43ab}',
-         IdentifierType::Uncommon_Use), ('\u{43ac}', '\u{43ac}',  IdentifierType::Recommended),
-        ('\u{43ad}', '\u{43b0}',  IdentifierType::Uncommon_Use), ('\u{43b1}', '\u{43b1}',
-         IdentifierType::Recommended), ('\u{43b2}', '\u{43d2}',  IdentifierType::Uncommon_Use),
-        ('\u{43d3}', '\u{43d3}',  IdentifierType::Recommended), ('\u{43d4}', '\u{43dc}',
-         IdentifierType::Uncommon_Use), ('\u{43dd}', '\u{43dd}',  IdentifierType::Recommended),
-        ('\u{43de}', '\u{4442}',  IdentifierType::Uncommon_Use), ('\u{4443}', '\u{4443}',
-         IdentifierType::Recommended), ('\u{4444}', '\u{44d5}',  IdentifierType::Uncommon_Use),
-        ('\u{44d6}', '\u{44d6}',  IdentifierType::Recommended), ('\u{44d7}', '\u{44e9}',
-         IdentifierType::Uncommon_Use), ('\u{44ea}', '\u{44ea}',  IdentifierType::Recommended),
-        ('\u{44eb}', '\u{4605}',  IdentifierType::Uncommon_Use), ('\u{4606}', '\u{4606}',
-         IdentifierType::Recommended), ('\u{4607}', '\u{464b}',  IdentifierType::Uncommon_Use),
-        ('\u{464c}', '\u{464c}',  IdentifierType::Recommended), ('\u{464d}', '\u{4660}',
-         IdentifierType::Uncommon_Use), ('\u{4661}', '\u{4661}',  IdentifierType::Recommended),
-        ('\u{4662}', '\u{4722}',  IdentifierType::Uncommon_Use), ('\u{4723}', '\u{4723}',
-         IdentifierType::Recommended), ('\u{4724}', '\u{4728}',  IdentifierType::Uncommon_Use),
-        ('\u{4729}', '\u{4729}',  IdentifierType::Recommended), ('\u{472a}', '\u{477b}',
-         IdentifierType::Uncommon_Use), ('\u{477c}', '\u{477c}',  IdentifierType::Recommended),
-        ('\u{477d}', '\u{478c}',  IdentifierType::Uncommon_Use), ('\u{478d}', '\u{478d}',
-         IdentifierType::Recommended), ('\u{478e}', '\u{47f3}',  IdentifierType::Uncommon_Use),
-        ('\u{47f4}', '\u{47f4}',  IdentifierType::Recommended), ('\u{47f5}', '\u{4881}',
-         IdentifierType::Uncommon_Use), ('\u{4882}', '\u{4882}',  IdentifierType::Recommended),
-        ('\u{4883}', '\u{4946}',  IdentifierType::Uncommon_Use), ('\u{4947}', '\u{4947}',
-         IdentifierType::Recommended), ('\u{4948}', '\u{4979}',  IdentifierType::Uncommon_Use),
-        ('\u{497a}', '\u{497a}',  IdentifierType::Recommended), ('\u{497b}', '\u{497c}',
-         IdentifierType::Uncommon_Use), ('\u{497d}', '\u{497d}',  IdentifierType::Recommended),
-        ('\u{497e}', '\u{4981}',  IdentifierType::Uncommon_Use), ('\u{4982}', '\u{4983}',
-         IdentifierType::Recommended), ('\u{4984}', '\u{4984}',  IdentifierType::Uncommon_Use),
-        ('\u{4985}', '\u{4986}',  IdentifierType::Recommended), ('\u{4987}', '\u{499a}',
-         IdentifierType::Uncommon_Use), ('\u{499b}', '\u{499b}',  IdentifierType::Recommended),
-        ('\u{499c}', '\u{499e}',  IdentifierType::Uncommon_Use), ('\u{499f}', '\u{499f}',
-         IdentifierType::Recommended), ('\u{49a0}', '\u{49b5}',  IdentifierType::Uncommon_Use),
-        ('\u{49b6}', '\u{49b7}',  IdentifierType::Recommended), ('\u{49b8}', '\u{4a11}',
-         IdentifierType::Uncommon_Use), ('\u{4a12}', '\u{4a12}',  IdentifierType::Recommended),
-        ('\u{4a13}', '\u{4ab7}',  IdentifierType::Uncommon_Use), ('\u{4ab8}', '\u{4ab8}',
-         IdentifierType::Recommended), ('\u{4ab9}', '\u{4c76}',  IdentifierType::Uncommon_Use),
-        ('\u{4c77}', '\u{4c77}',  IdentifierType::Recommended), ('\u{4c78}', '\u{4c7c}',
-         IdentifierType::Uncommon_Use), ('\u{4c7d}', '\u{4c7d}',  IdentifierType::Recommended),
-        ('\u{4c7e}', '\u{4c80}',  IdentifierType::Uncommon_Use), ('\u{4c81}', '\u{4c81}',
-         IdentifierType::Recommended), ('\u{4c82}', '\u{4c84}',  IdentifierType::Uncommon_Use),
-        ('\u{4c85}', '\u{4c85}',  IdentifierType::Recommended), ('\u{4c86}', '\u{4c9c}',
-         IdentifierType::Uncommon_Use), ('\u{4c9d}', '\u{4ca3}',  IdentifierType::Recommended),
-        ('\u{4ca4}', '\u{4d12}',  IdentifierType::Uncommon_Use), ('\u{4d13}', '\u{4d19}',
-         IdentifierType::Recommended), ('\u{4d1a}', '\u{4dad}',  IdentifierType::Uncommon_Use),
-        ('\u{4dae}', '\u{4dae}',  IdentifierType::Recommended), ('\u{4daf}', '\u{4db5}',
-         IdentifierType::Uncommon_Use), ('\u{4db6}', '\u{4dbf}',  IdentifierType::Uncommon_Use),
-        ('\u{4e00}', '\u{4e11}',  IdentifierType::Recommended), ('\u{4e12}', '\u{4e12}',
-         IdentifierType::Uncommon_Use), ('\u{4e13}', '\u{4e28}',  IdentifierType::Recommended),
-        ('\u{4e29}', '\u{4e29}',  IdentifierType::Uncommon_Use), ('\u{4e2a}', '\u{4e67}',
-         IdentifierType::Recommended), ('\u{4e68}', '\u{4e68}',  IdentifierType::Uncommon_Use),
-        ('\u{4e69}', '\u{4e78}',  IdentifierType::Recommended), ('\u{4e79}', '\u{4e79}',
-         IdentifierType::Uncommon_Use), ('\u{4e7a}', '\u{4e95}',  IdentifierType::Recommended),
-        ('\u{4e96}', '\u{4e96}',  IdentifierType::Uncommon_Use), ('\u{4e97}', '\u{4ea2}',
-         IdentifierType::Recommended), ('\u{4ea3}', '\u{4ea3}',  IdentifierType::Uncommon_Use),
-        ('\u{4ea4}', '\u{4ebb}',  IdentifierType::Recommended), ('\u{4ebc}', '\u{4ebc}',
-         IdentifierType::Uncommon_Use), ('\u{4ebd}', '\u{4ecb}',  IdentifierType::Recommended),
-        ('\u{4ecc}', '\u{4ecc}',  IdentifierType::Uncommon_Use), ('\u{4ecd}', '\u{4ee6}',
-         IdentifierType::Recommended), ('\u{4ee7}', '\u{4ee7}',  IdentifierType::Uncommon_Use),
-        ('\u{4ee8}', '\u{4ef7}',  IdentifierType::Recommended), ('\u{4ef8}', '\u{4efa}',
-         IdentifierType::Uncommon_Use), ('\u{4efb}', '\u{4efb}',  IdentifierType::Recommended),
-        ('\u{4efc}', '\u{4efc}',  IdentifierType::Uncommon_Use), ('\u{4efd}', '\u{4efd}',
-         IdentifierType::Recommended), ('\u{4efe}', '\u{4efe}',  IdentifierType::Uncommon_Use),
-        ('\u{4eff}', '\u{4f06}',  IdentifierType::Recommended), ('\u{4f07}', '\u{4f07}',
-         IdentifierType::Uncommon_Use), ('\u{4f08}', '\u{4f15}',  IdentifierType::Recommended),
-        ('\u{4f16}', '\u{4f16}',  IdentifierType::Uncommon_Use), ('\u{4f17}', '\u{4f27}',
-         IdentifierType::Recommended), ('\u{4f28}', '\u{4f28}',  IdentifierType::Uncommon_Use),
-        ('\u{4f29}', '\u{4f30}',  IdentifierType::Recommended), ('\u{4f31}', '\u{4f31}',
-         IdentifierType::Uncommon_Use), ('\u{4f32}', '\u{4f34}',  IdentifierType::Recommended),
-        ('\u{4f35}', '\u{4f35}',  IdentifierType::Uncommon_Use), ('\u{4f36}', '\u{4f36}',
-         IdentifierType::Recommended), ('\u{4f37}', '\u{4f37}',  IdentifierType::Uncommon_Use),
-        ('\u{4f38}', '\u{4f3f}',  IdentifierType::Recommended), ('\u{4f40}', '\u{4f40}',
-         IdentifierType::Uncommon_Use), ('\u{4f41}', '\u{4f43}',  IdentifierType::Recommended),
-        ('\u{4f44}', '\u{4f44}',  IdentifierType::Uncommon_Use), ('\u{4f45}', '\u{4f70}',
-         IdentifierType::Recommended), ('\u{4f71}', '\u{4f71}',  IdentifierType::Uncommon_Use),
-        ('\u{4f72}', '\u{4f8b}',  IdentifierType::Recommended), ('\u{4f8c}', '\u{4f8c}',
-         IdentifierType::Uncommon_Use), ('\u{4f8d}', '\u{4f8d}',  IdentifierType::Recommended),
-        ('\u{4f8e}', '\u{4f8e}',  IdentifierType::Uncommon_Use), ('\u{4f8f}', '\u{4fa1}',
-         IdentifierType::Recommended), ('\u{4fa2}', '\u{4fa2}',  IdentifierType::Uncommon_Use),
-        ('\u{4fa3}', '\u{4fbc}',  IdentifierType::Recommended), ('\u{4fbd}', '\u{4fbd}',
-         IdentifierType::Uncommon_Use), ('\u{4fbe}', '\u{4fc5}',  IdentifierType::Recommended),
-        ('\u{4fc6}', '\u{4fc6}',  IdentifierType::Uncommon_Use), ('\u{4fc7}', '\u{4fc7}',
-         IdentifierType::Recommended), ('\u{4fc8}', '\u{4fc8}',  IdentifierType::Uncommon_Use),
-        ('\u{4fc9}', '\u{4fcb}',  IdentifierType::Recommended), ('\u{4fcc}', '\u{4fcc}',
-         IdentifierType::Uncommon_Use), ('\u{4fcd}', '\u{4fe1}',  IdentifierType::Recommended),
-        ('\u{4fe2}', '\u{4fe2}',  IdentifierType::Uncommon_Use), ('\u{4fe3}', '\u{4ffb}',
-         IdentifierType::Recommended), ('\u{4ffc}', '\u{4ffd}',  IdentifierType::Uncommon_Use),
-        ('\u{4ffe}', '\u{500f}',  IdentifierType::Recommended), ('\u{5010}', '\u{5010}',
-         IdentifierType::Uncommon_Use), ('\u{5011}', '\u{5033}',  IdentifierType::Recommended),
-        ('\u{5034}', '\u{5034}',  IdentifierType::Uncommon_Use), ('\u{5035}', '\u{5037}',
-         IdentifierType::Recommended), ('\u{5038}', '\u{5038}',  IdentifierType::Uncommon_Use),
-        ('\u{5039}', '\u{503c}',  IdentifierType::Recommended), ('\u{503d}', '\u{503d}',
-         IdentifierType::Uncommon_Use), ('\u{503e}', '\u{5041}',  IdentifierType::Recommended),
-        ('\u{5042}', '\u{5042}',  IdentifierType::Uncommon_Use), ('\u{5043}', '\u{5051}',
-         IdentifierType::Recommended), ('\u{5052}', '\u{5052}',  IdentifierType::Uncommon_Use),
-        ('\u{5053}', '\u{5057}',  IdentifierType::Recommended), ('\u{5058}', '\u{5058}',
-         IdentifierType::Uncommon_Use), ('\u{5059}', '\u{507b}',  IdentifierType::Recommended),
-        ('\u{507c}', '\u{507c}',  IdentifierType::Uncommon_Use), ('\u{507d}', '\u{5080}',
-         IdentifierType::Recommended), ('\u{5081}', '\u{5081}',  IdentifierType::Uncommon_Use),
-        ('\u{5082}', '\u{5092}',  IdentifierType::Recommended), ('\u{5093}', '\u{5093}',
-         IdentifierType::Uncommon_Use), ('\u{5094}', '\u{5096}',  IdentifierType::Recommended),
-        ('\u{5097}', '\u{5097}',  IdentifierType::Uncommon_Use), ('\u{5098}', '\u{509e}',
-         IdentifierType::Recommended), ('\u{509f}', '\u{50a1}',  IdentifierType::Uncommon_Use),
-        ('\u{50a2}', '\u{50b8}',  IdentifierType::Recommended), ('\u{50b9}', '\u{50b9}',
-         IdentifierType::Uncommon_Use), ('\u{50ba}', '\u{50c2}',  IdentifierType::Recommended),
-        ('\u{50c3}', '\u{50c3}',  IdentifierType::Uncommon_Use), ('\u{50c4}', '\u{50d7}',
-         IdentifierType::Recommended), ('\u{50d8}', '\u{50d8}',  IdentifierType::Uncommon_Use),
-        ('\u{50d9}', '\u{50de}',  IdentifierType::Recommended), ('\u{50df}', '\u{50df}',
-         IdentifierType::Uncommon_Use), ('\u{50e0}', '\u{50e0}',  IdentifierType::Recommended),
-        ('\u{50e1}', '\u{50e2}',  IdentifierType::Uncommon_Use), ('\u{50e3}', '\u{50ea}',
-         IdentifierType::Recommended), ('\u{50eb}', '\u{50eb}',  IdentifierType::Uncommon_Use),
-        ('\u{50ec}', '\u{50f3}',  IdentifierType::Recommended), ('\u{50f4}', '\u{50f4}',
-         IdentifierType::Uncommon_Use), ('\u{50f5}', '\u{50f6}',  IdentifierType::Recommended),
-        ('\u{50f7}', '\u{50f7}',  IdentifierType::Uncommon_Use), ('\u{50f8}', '\u{511a}',
-         IdentifierType::Recommended), ('\u{511b}', '\u{511b}',  IdentifierType::Uncommon_Use),
-        ('\u{511c}', '\u{5127}',  IdentifierType::Recommended), ('\u{5128}', '\u{5128}',
-         IdentifierType::Uncommon_Use), ('\u{5129}', '\u{512a}',  IdentifierType::Recommended),
-        ('\u{512b}', '\u{512b}',  IdentifierType::Uncommon_Use), ('\u{512c}', '\u{5141}',
-         IdentifierType::Recommended), ('\u{5142}', '\u{5142}',  IdentifierType::Uncommon_Use),
-        ('\u{5143}', '\u{5149}',  IdentifierType::Recommended), ('\u{514a}', '\u{514a}',
-         IdentifierType::Uncommon_Use), ('\u{514b}', '\u{514e}',  IdentifierType::Recommended),
-        ('\u{514f}', '\u{514f}',  IdentifierType::Uncommon_Use), ('\u{5150}', '\u{5152}',
-         IdentifierType::Recommended), ('\u{5153}', '\u{5153}',  IdentifierType::Uncommon_Use),
-        ('\u{5154}', '\u{5157}',  IdentifierType::Recommended), ('\u{5158}', '\u{5158}',
-         IdentifierType::Uncommon_Use), ('\u{5159}', '\u{515f}',  IdentifierType::Recommended),
-        ('\u{5160}', '\u{5160}',  IdentifierType::Uncommon_Use), ('\u{5161}', '\u{5163}',
-         IdentifierType::Recommended), ('\u{5164}', '\u{5164}',  IdentifierType::Uncommon_Use),
-        ('\u{5165}', '\u{5171}',  IdentifierType::Recommended), ('\u{5172}', '\u{5172}',
-         IdentifierType::Uncommon_Use), ('\u{5173}', '\u{517d}',  IdentifierType::Recommended),
-        ('\u{517e}', '\u{517e}',  IdentifierType::Uncommon_Use), ('\u{517f}', '\u{5182}',
-         IdentifierType::Recommended), ('\u{5183}', '\u{5184}',  IdentifierType::Uncommon_Use),
-        ('\u{5185}', '\u{518d}',  IdentifierType::Recommended), ('\u{518e}', '\u{518e}',
-         IdentifierType::Uncommon_Use), ('\u{518f}', '\u{51a0}',  IdentifierType::Recommended),
-        ('\u{51a1}', '\u{51a1}',  IdentifierType::Uncommon_Use), ('\u{51a2}', '\u{51a2}',
-         IdentifierType::Recommended), ('\u{51a3}', '\u{51a3}',  IdentifierType::Uncommon_Use),
-        ('\u{51a4}', '\u{51ac}',  IdentifierType::Recommended), ('\u{51ad}', '\u{51ad}',
-         IdentifierType::Uncommon_Use), ('\u{51ae}', '\u{51b7}',  IdentifierType::Recommended),
-        ('\u{51b8}', '\u{51b8}',  IdentifierType::Uncommon_Use), ('\u{51b9}', '\u{51b9}',
-         IdentifierType::Recommended), ('\u{51ba}', '\u{51ba}',  IdentifierType::Uncommon_Use),
-        ('\u{51bb}', '\u{51c1}',  IdentifierType::Recommended), ('\u{51c2}', '\u{51c2}',
-         IdentifierType::Uncommon_Use), ('\u{51c3}', '\u{51d1}',  IdentifierType::Recommended),
-        ('\u{51d2}', '\u{51d3}',  IdentifierType::Uncommon_Use), ('\u{51d4}', '\u{51de}',
-         IdentifierType::Recommended), ('\u{51df}', '\u{51df}',  IdentifierType::Uncommon_Use),
-        ('\u{51e0}', '\u{51eb}',  IdentifierType::Recommended), ('\u{51ec}', '\u{51ec}',
-         IdentifierType::Uncommon_Use), ('\u{51ed}', '\u{51ed}',  IdentifierType::Recommended),
-        ('\u{51ee}', '\u{51ee}',  IdentifierType::Uncommon_Use), ('\u{51ef}', '\u{51f1}',
-         IdentifierType::Recommended), ('\u{51f2}', '\u{51f2}',  IdentifierType::Uncommon_Use),
-        ('\u{51f3}', '\u{5252}',  IdentifierType::Recommended), ('\u{5253}', '\u{5253}',
-         IdentifierType::Uncommon_Use), ('\u{5254}', '\u{5265}',  IdentifierType::Recommended),
-        ('\u{5266}', '\u{5266}',  IdentifierType::Uncommon_Use), ('\u{5267}', '\u{5278}',
-         IdentifierType::Recommended), ('\u{5279}', '\u{5279}',  IdentifierType::Uncommon_Use),
-        ('\u{527a}', '\u{5284}',  IdentifierType::Recommended), ('\u{5285}', '\u{5285}',
-         IdentifierType::Uncommon_Use), ('\u{5286}', '\u{528d}',  IdentifierType::Recommended),
-        ('\u{528e}', '\u{528e}',  IdentifierType::Uncommon_Use), ('\u{528f}', '\u{52c3}',
-         IdentifierType::Recommended), ('\u{52c4}', '\u{52c4}',  IdentifierType::Uncommon_Use),
-        ('\u{52c5}', '\u{52c7}',  IdentifierType::Recommended), ('\u{52c8}', '\u{52c8}',
-         IdentifierType::Uncommon_Use), ('\u{52c9}', '\u{52cb}',  IdentifierType::Recommended),
-        ('\u{52cc}', '\u{52cc}',  IdentifierType::Uncommon_Use), ('\u{52cd}', '\u{52cd}',
-         IdentifierType::Recommended), ('\u{52ce}', '\u{52ce}',  IdentifierType::Uncommon_Use),
-        ('\u{52cf}', '\u{52d0}',  IdentifierType::Recommended), ('\u{52d1}', '\u{52d1}',
-         IdentifierType::Uncommon_Use), ('\u{52d2}', '\u{52d3}',  IdentifierType::Recommended),
-        ('\u{52d4}', '\u{52d4}',  IdentifierType::Uncommon_Use), ('\u{52d5}', '\u{52e0}',
-         IdentifierType::Recommended), ('\u{52e1}', '\u{52e1}',  IdentifierType::Uncommon_Use),
-        ('\u{52e2}', '\u{52e4}',  IdentifierType::Recommended), ('\u{52e5}', '\u{52e5}',
-         IdentifierType::Uncommon_Use), ('\u{52e6}', '\u{52ed}',  IdentifierType::Recommended),
-        ('\u{52ee}', '\u{52ee}',  IdentifierType::Uncommon_Use), ('\u{52ef}', '\u{5302}',
-         IdentifierType::Recommended), ('\u{5303}', '\u{5304}',  IdentifierType::Uncommon_Use),
-        ('\u{5305}', '\u{5317}',  IdentifierType::Recommended), ('\u{5318}', '\u{5318}',
-         IdentifierType::Uncommon_Use), ('\u{5319}', '\u{531a}',  IdentifierType::Recommended),
-        ('\u{531b}', '\u{531b}',  IdentifierType::Uncommon_Use), ('\u{531c}', '\u{531d}',
-         IdentifierType::Recommended), ('\u{531e}', '\u{531e}',  IdentifierType::Uncommon_Use),
-        ('\u{531f}', '\u{5326}',  IdentifierType::Recommended), ('\u{5327}', '\u{5327}',
-         IdentifierType::Uncommon_Use), ('\u{5328}', '\u{5328}',  IdentifierType::Recommended),
-        ('\u{5329}', '\u{5329}',  IdentifierType::Uncommon_Use), ('\u{532a}', '\u{5331}',
-         IdentifierType::Recommended), ('\u{5332}', '\u{5332}',  IdentifierType::Uncommon_Use),
-        ('\u{5333}', '\u{5334}',  IdentifierType::Recommended), ('\u{5335}', '\u{5336}',
-         IdentifierType::Uncommon_Use), ('\u{5337}', '\u{5341}',  IdentifierType::Recommended),
-        ('\u{5342}', '\u{5342}',  IdentifierType::Uncommon_Use), ('\u{5343}', '\u{535a}',
-         IdentifierType::Recommended), ('\u{535b}', '\u{535b}',  IdentifierType::Uncommon_Use),
-        ('\u{535c}', '\u{535c}',  IdentifierType::Recommended), ('\u{535d}', '\u{535d}',
-         IdentifierType::Uncommon_Use), ('\u{535e}', '\u{5369}',  IdentifierType::Recommended),
-        ('\u{536a}', '\u{536a}',  IdentifierType::Uncommon_Use), ('\u{536b}', '\u{536c}',
-         IdentifierType::Recommended), ('\u{536d}', '\u{536d}',  IdentifierType::Uncommon_Use),
-        ('\u{536e}', '\u{537f}',  IdentifierType::Recommended), ('\u{5380}', '\u{5380}',
-         IdentifierType::Uncommon_Use), ('\u{5381}', '\u{53a0}',  IdentifierType::Recommended),
-        ('\u{53a1}', '\u{53a1}',  IdentifierType::Uncommon_Use), ('\u{53a2}', '\u{53a9}',
-         IdentifierType::Recommended), ('\u{53aa}', '\u{53ab}',  IdentifierType::Uncommon_Use),
-        ('\u{53ac}', '\u{53ae}',  IdentifierType::Recommended), ('\u{53af}', '\u{53af}',
-         IdentifierType::Uncommon_Use), ('\u{53b0}', '\u{53b9}',  IdentifierType::Recommended),
-        ('\u{53ba}', '\u{53ba}',  IdentifierType::Uncommon_Use), ('\u{53bb}', '\u{53c4}',
-         IdentifierType::Recommended), ('\u{53c5}', '\u{53c5}',  IdentifierType::Uncommon_Use),
-        ('\u{53c6}', '\u{53ce}',  IdentifierType::Recommended), ('\u{53cf}', '\u{53cf}',
-         IdentifierType::Uncommon_Use), ('\u{53d0}', '\u{53dc}',  IdentifierType::Recommended),
-        ('\u{53dd}', '\u{53de}',  IdentifierType::Uncommon_Use), ('\u{53df}', '\u{53e6}',
-         IdentifierType::Recommended), ('\u{53e7}', '\u{53e7}',  IdentifierType::Uncommon_Use),
-        ('\u{53e8}', '\u{53fe}',  IdentifierType::Recommended), ('\u{53ff}', '\u{5400}',
-         IdentifierType::Uncommon_Use), ('\u{5401}', '\u{5419}',  IdentifierType::Recommended),
-        ('\u{541a}', '\u{541a}',  IdentifierType::Uncommon_Use), ('\u{541b}', '\u{5421}',
-         IdentifierType::Recommended), ('\u{5422}', '\u{5422}',  IdentifierType::Uncommon_Use),
-        ('\u{5423}', '\u{544b}',  IdentifierType::Recommended), ('\u{544c}', '\u{544c}',
-         IdentifierType::Uncommon_Use), ('\u{544d}', '\u{545c}',  IdentifierType::Recommended),
-        ('\u{545d}', '\u{545d}',  IdentifierType::Uncommon_Use), ('\u{545e}', '\u{5468}',
-         IdentifierType::Recommended), ('\u{5469}', '\u{5469}',  IdentifierType::Uncommon_Use),
-        ('\u{546a}', '\u{5489}',  IdentifierType::Recommended), ('\u{548a}', '\u{548a}',
-         IdentifierType::Uncommon_Use), ('\u{548b}', '\u{54b4}',  IdentifierType::Recommended),
-        ('\u{54b5}', '\u{54b5}',  IdentifierType::Uncommon_Use), ('\u{54b6}', '\u{54f5}',
-         IdentifierType::Recommended), ('\u{54f6}', '\u{54f6}',  IdentifierType::Uncommon_Use),
-        ('\u{54f7}', '\u{5514}',  IdentifierType::Recommended), ('\u{5515}', '\u{5515}',
-         IdentifierType::Uncommon_Use), ('\u{5516}', '\u{5517}',  IdentifierType::Recommended),
-        ('\u{5518}', '\u{5519}',  IdentifierType::Uncommon_Use), ('\u{551a}', '\u{5546}',
-         IdentifierType::Recommended), ('\u{5547}', '\u{5547}',  IdentifierType::Uncommon_Use),
-        ('\u{5548}', '\u{555f}',  IdentifierType::Recommended), ('\u{5560}', '\u{5560}',
-         IdentifierType::Uncommon_Use), ('\u{5561}', '\u{5579}',  IdentifierType::Recommended),
-        ('\u{557a}', '\u{557a}',  IdentifierType::Uncommon_Use), ('\u{557b}', '\u{55df}',
-         IdentifierType::Recommended), ('\u{55e0}', '\u{55e0}',  IdentifierType::Uncommon_Use),
-        ('\u{55e1}', '\u{55f7}',  IdentifierType::Recommended), ('\u{55f8}', '\u{55f8}',
-         IdentifierType::Uncommon_Use), ('\u{55f9}', '\u{5609}',  IdentifierType::Recommended),
-        ('\u{560a}', '\u{560b}',  IdentifierType::Uncommon_Use), ('\u{560c}', '\u{561f}',
-         IdentifierType::Recommended), ('\u{5620}', '\u{5620}',  IdentifierType::Uncommon_Use),
-        ('\u{5621}', '\u{562a}',  IdentifierType::Recommended), ('\u{562b}', '\u{562b}',
-         IdentifierType::Uncommon_Use), ('\u{562c}', '\u{5636}',  IdentifierType::Recommended),
-        ('\u{5637}', '\u{5637}',  IdentifierType::Uncommon_Use), ('\u{5638}', '\u{563b}',
-         IdentifierType::Recommended), ('\u{563c}', '\u{563c}',  IdentifierType::Uncommon_Use),
-        ('\u{563d}', '\u{5643}',  IdentifierType::Recommended), ('\u{5644}', '\u{5644}',
-         IdentifierType::Uncommon_Use), ('\u{5645}', '\u{564a}',  IdentifierType::Recommended),
-        ('\u{564b}', '\u{564b}',  IdentifierType::Uncommon_Use), ('\u{564c}', '\u{5650}',
-         IdentifierType::Recommended), ('\u{5651}', '\u{5651}',  IdentifierType::Uncommon_Use),
-        ('\u{5652}', '\u{5655}',  IdentifierType::Recommended), ('\u{5656}', '\u{5656}',
-         IdentifierType::Uncommon_Use), ('\u{5657}', '\u{565e}',  IdentifierType::Recommended),
-        ('\u{565f}', '\u{565f}',  IdentifierType::Uncommon_Use), ('\u{5660}', '\u{5660}',
-         IdentifierType::Recommended), ('\u{5661}', '\u{5661}',  IdentifierType::Uncommon_Use),
-        ('\u{5662}', '\u{5674}',  IdentifierType::Recommended), ('\u{5675}', '\u{5675}',
-         IdentifierType::Uncommon_Use), ('\u{5676}', '\u{567c}',  IdentifierType::Recommended),
-        ('\u{567d}', '\u{567d}',  IdentifierType::Uncommon_Use), ('\u{567e}', '\u{5687}',
-         IdentifierType::Recommended), ('\u{5688}', '\u{5688}',  IdentifierType::Uncommon_Use),
-        ('\u{5689}', '\u{568a}',  IdentifierType::Recommended), ('\u{568b}', '\u{568b}',
-         IdentifierType::Uncommon_Use), ('\u{568c}', '\u{5695}',  IdentifierType::Recommended),
-        ('\u{5696}', '\u{5696}',  IdentifierType::Uncommon_Use), ('\u{5697}', '\u{569d}',
-         IdentifierType::Recommended), ('\u{569e}', '\u{569e}',  IdentifierType::Uncommon_Use),
-        ('\u{569f}', '\u{56b9}',  IdentifierType::Recommended), ('\u{56ba}', '\u{56ba}',
-         IdentifierType::Uncommon_Use), ('\u{56bb}', '\u{56ce}',  IdentifierType::Recommended),
-        ('\u{56cf}', '\u{56cf}',  IdentifierType::Uncommon_Use), ('\u{56d0}', '\u{56d8}',
-         IdentifierType::Recommended), ('\u{56d9}', '\u{56d9}',  IdentifierType::Uncommon_Use),
-        ('\u{56da}', '\u{56e5}',  IdentifierType::Recommended), ('\u{56e6}', '\u{56e6}',
-         IdentifierType::Uncommon_Use), ('\u{56e7}', '\u{56f5}',  IdentifierType::Recommended),
-        ('\u{56f6}', '\u{56f6}',  IdentifierType::Uncommon_Use), ('\u{56f7}', '\u{56f7}',
-         IdentifierType::Recommended), ('\u{56f8}', '\u{56f8}',  IdentifierType::Uncommon_Use),
-        ('\u{56f9}', '\u{56fa}',  IdentifierType::Recommended), ('\u{56fb}', '\u{56fc}',
-         IdentifierType::Uncommon_Use), ('\u{56fd}', '\u{5704}',  IdentifierType::Recommended),
-        ('\u{5705}', '\u{5705}',  IdentifierType::Uncommon_Use), ('\u{5706}', '\u{5710}',
-         IdentifierType::Recommended), ('\u{5711}', '\u{5711}',  IdentifierType::Uncommon_Use),
-        ('\u{5712}', '\u{5716}',  IdentifierType::Recommended), ('\u{5717}', '\u{5717}',
-         IdentifierType::Uncommon_Use), ('\u{5718}', '\u{5720}',  IdentifierType::Recommended),
-        ('\u{5721}', '\u{5721}',  IdentifierType::Uncommon_Use), ('\u{5722}', '\u{5723}',
-         IdentifierType::Recommended), ('\u{5724}', '\u{5724}',  IdentifierType::Uncommon_Use),
-        ('\u{5725}', '\u{573c}',  IdentifierType::Recommended), ('\u{573d}', '\u{573d}',
-         IdentifierType::Uncommon_Use), ('\u{573e}', '\u{5742}',  IdentifierType::Recommended),
-        ('\u{5743}', '\u{5743}',  IdentifierType::Uncommon_Use), ('\u{5744}', '\u{5747}',
-         IdentifierType::Recommended), ('\u{5748}', '\u{5748}',  IdentifierType::Uncommon_Use),
-        ('\u{5749}', '\u{5754}',  IdentifierType::Recommended), ('\u{5755}', '\u{5756}',
-         IdentifierType::Uncommon_Use), ('\u{5757}', '\u{5757}',  IdentifierType::Recommended),
-        ('\u{5758}', '\u{5758}',  IdentifierType::Uncommon_Use), ('\u{5759}', '\u{5762}',
-         IdentifierType::Recommended), ('\u{5763}', '\u{5763}',  IdentifierType::Uncommon_Use),
-        ('\u{5764}', '\u{5777}',  IdentifierType::Recommended), ('\u{5778}', '\u{5778}',
-         IdentifierType::Uncommon_Use), ('\u{5779}', '\u{5780}',  IdentifierType::Recommended),
-        ('\u{5781}', '\u{5781}',  IdentifierType::Uncommon_Use), ('\u{5782}', '\u{5786}',
-         IdentifierType::Recommended), ('\u{5787}', '\u{5787}',  IdentifierType::Uncommon_Use),
-        ('\u{5788}', '\u{5795}',  IdentifierType::Recommended), ('\u{5796}', '\u{5796}',
-         IdentifierType::Uncommon_Use), ('\u{5797}', '\u{57a7}',  IdentifierType::Recommended),
-        ('\u{57a8}', '\u{57a8}',  IdentifierType::Uncommon_Use), ('\u{57a9}', '\u{57c9}',
-         IdentifierType::Recommended), ('\u{57ca}', '\u{57ca}',  IdentifierType::Uncommon_Use),
-        ('\u{57cb}', '\u{57d0}',  IdentifierType::Recommended), ('\u{57d1}', '\u{57d1}',
-         IdentifierType::Uncommon_Use), ('\u{57d2}', '\u{57da}',  IdentifierType::Recommended),
-        ('\u{57db}', '\u{57db}',  IdentifierType::Uncommon_Use), ('\u{57dc}', '\u{5816}',
-         IdentifierType::Recommended), ('\u{5817}', '\u{5818}',  IdentifierType::Uncommon_Use),
-        ('\u{5819}', '\u{584f}',  IdentifierType::Recommended), ('\u{5850}', '\u{5850}',
-         IdentifierType::Uncommon_Use), ('\u{5851}', '\u{5855}',  IdentifierType::Recommended),
-        ('\u{5856}', '\u{5856}',  IdentifierType::Uncommon_Use), ('\u{5857}', '\u{585f}',
-         IdentifierType::Recommended), ('\u{5860}', '\u{5860}',  IdentifierType::Uncommon_Use),
-        ('\u{5861}', '\u{5865}',  IdentifierType::Recommended), ('\u{5866}', '\u{5867}',
-         IdentifierType::Uncommon_Use), ('\u{5868}', '\u{5876}',  IdentifierType::Recommended),
-        ('\u{5877}', '\u{5877}',  IdentifierType::Uncommon_Use), ('\u{5878}', '\u{5894}',
-         IdentifierType::Recommended), ('\u{5895}', '\u{5895}',  IdentifierType::Uncommon_Use),
-        ('\u{5896}', '\u{58a9}',  IdentifierType::Recommended), ('\u{58aa}', '\u{58aa}',
-         IdentifierType::Uncommon_Use), ('\u{58ab}', '\u{58b5}',  IdentifierType::Recommended),
-        ('\u{58b6}', '\u{58b6}',  IdentifierType::Uncommon_Use), ('\u{58b7}', '\u{58bf}',
-         IdentifierType::Recommended), ('\u{58c0}', '\u{58c0}',  IdentifierType::Uncommon_Use),
-        ('\u{58c1}', '\u{58c2}',  IdentifierType::Recommended), ('\u{58c3}', '\u{58c4}',
-         IdentifierType::Uncommon_Use), ('\u{58c5}', '\u{58cc}',  IdentifierType::Recommended),
-        ('\u{58cd}', '\u{58cd}',  IdentifierType::Uncommon_Use), ('\u{58ce}', '\u{58cf}',
-         IdentifierType::Recommended), ('\u{58d0}', '\u{58d0}',  IdentifierType::Uncommon_Use),
-        ('\u{58d1}', '\u{58e0}',  IdentifierType::Recommended), ('\u{58e1}', '\u{58e1}',
-         IdentifierType::Uncommon_Use), ('\u{58e2}', '\u{58e5}',  IdentifierType::Recommended),
-        ('\u{58e6}', '\u{58e6}',  IdentifierType::Uncommon_Use), ('\u{58e7}', '\u{58f4}',
-         IdentifierType::Recommended), ('\u{58f5}', '\u{58f5}',  IdentifierType::Uncommon_Use),
-        ('\u{58f6}', '\u{5900}',  IdentifierType::Recommended), ('\u{5901}', '\u{5901}',
-         IdentifierType::Uncommon_Use), ('\u{5902}', '\u{5904}',  IdentifierType::Recommended),
-        ('\u{5905}', '\u{5905}',  IdentifierType::Uncommon_Use), ('\u{5906}', '\u{5907}',
-         IdentifierType::Recommended), ('\u{5908}', '\u{5908}',  IdentifierType::Uncommon_Use),
-        ('\u{5909}', '\u{5910}',  IdentifierType::Recommended), ('\u{5911}', '\u{5911}',
-         IdentifierType::Uncommon_Use), ('\u{5912}', '\u{5912}',  IdentifierType::Recommended),
-        ('\u{5913}', '\u{5913}',  IdentifierType::Uncommon_Use), ('\u{5914}', '\u{5922}',
-         IdentifierType::Recommended), ('\u{5923}', '\u{5923}',  IdentifierType::Uncommon_Use),
-        ('\u{5924}', '\u{5932}',  IdentifierType::Recommended), ('\u{5933}', '\u{5933}',
-         IdentifierType::Uncommon_Use), ('\u{5934}', '\u{5935}',  IdentifierType::Recommended),
-        ('\u{5936}', '\u{5936}',  IdentifierType::Uncommon_Use), ('\u{5937}', '\u{5958}',
-         IdentifierType::Recommended), ('\u{5959}', '\u{5959}',  IdentifierType::Uncommon_Use),
-        ('\u{595a}', '\u{595a}',  IdentifierType::Recommended), ('\u{595b}', '\u{595b}',
-         IdentifierType::Uncommon_Use), ('\u{595c}', '\u{59b6}',  IdentifierType::Recommended),
-        ('\u{59b7}', '\u{59b7}',  IdentifierType::Uncommon_Use), ('\u{59b8}', '\u{59e6}',
-         IdentifierType::Recommended), ('\u{59e7}', '\u{59e7}',  IdentifierType::Uncommon_Use),
-        ('\u{59e8}', '\u{5a23}',  IdentifierType::Recommended), ('\u{5a24}', '\u{5a24}',
-         IdentifierType::Uncommon_Use), ('\u{5a25}', '\u{5a25}',  IdentifierType::Recommended),
-        ('\u{5a26}', '\u{5a26}',  IdentifierType::Uncommon_Use), ('\u{5a27}', '\u{5a2b}',
-         IdentifierType::Recommended), ('\u{5a2c}', '\u{5a2c}',  IdentifierType::Uncommon_Use),
-        ('\u{5a2d}', '\u{5a2f}',  IdentifierType::Recommended), ('\u{5a30}', '\u{5a30}',
-         IdentifierType::Uncommon_Use), ('\u{5a31}', '\u{5a53}',  IdentifierType::Recommended),
-        ('\u{5a54}', '\u{5a54}',  IdentifierType::Uncommon_Use), ('\u{5a55}', '\u{5a58}',
-         IdentifierType::Recommended), ('\u{5a59}', '\u{5a59}',  IdentifierType::Uncommon_Use),
-        ('\u{5a5a}', '\u{5a6e}',  IdentifierType::Recommended), ('\u{5a6f}', '\u{5a6f}',
-         IdentifierType::Uncommon_Use), ('\u{5a70}', '\u{5a70}',  IdentifierType::Recommended),
-        ('\u{5a71}', '\u{5a71}',  IdentifierType::Uncommon_Use), ('\u{5a72}', '\u{5a86}',
-         IdentifierType::Recommended), ('\u{5a87}', '\u{5a87}',  IdentifierType::Uncommon_Use),
-        ('\u{5a88}', '\u{5a8c}',  IdentifierType::Recommended), ('\u{5a8d}', '\u{5a8d}',
-         IdentifierType::Uncommon_Use), ('\u{5a8e}', '\u{5aaa}',  IdentifierType::Recommended),
-        ('\u{5aab}', '\u{5aab}',  IdentifierType::Uncommon_Use), ('\u{5aac}', '\u{5ad2}',
-         IdentifierType::Recommended), ('\u{5ad3}', '\u{5ad3}',  IdentifierType::Uncommon_Use),
-        ('\u{5ad4}', '\u{5aee}',  IdentifierType::Recommended), ('\u{5aef}', '\u{5af0}',
-         IdentifierType::Uncommon_Use), ('\u{5af1}', '\u{5b09}',  IdentifierType::Recommended),
-        ('\u{5b0a}', '\u{5b0a}',  IdentifierType::Uncommon_Use), ('\u{5b0b}', '\u{5b0c}',
-         IdentifierType::Recommended), ('\u{5b0d}', '\u{5b0d}',  IdentifierType::Uncommon_Use),
-        ('\u{5b0e}', '\u{5b38}',  IdentifierType::Recommended), ('\u{5b39}', '\u{5b39}',
-         IdentifierType::Uncommon_Use), ('\u{5b3a}', '\u{5b45}',  IdentifierType::Recommended),
-        ('\u{5b46}', '\u{5b46}',  IdentifierType::Uncommon_Use), ('\u{5b47}', '\u{5b4e}',
-         IdentifierType::Recommended), ('\u{5b4f}', '\u{5b4f}',  IdentifierType::Uncommon_Use),
-        ('\u{5b50}', '\u{5b51}',  IdentifierType::Recommended), ('\u{5b52}', '\u{5b52}',
-         IdentifierType::Uncommon_Use), ('\u{5b53}', '\u{5b5f}',  IdentifierType::Recommended),
-        ('\u{5b60}', '\u{5b61}',  IdentifierType::Uncommon_Use), ('\u{5b62}', '\u{5b6e}',
-         IdentifierType::Recommended), ('\u{5b6f}', '\u{5b6f}',  IdentifierType::Uncommon_Use),
-        ('\u{5b70}', '\u{5b78}',  IdentifierType::Recommended), ('\u{5b79}', '\u{5b79}',
-         IdentifierType::Uncommon_Use), ('\u{5b7a}', '\u{5b7d}',  IdentifierType::Recommended),
-        ('\u{5b7e}', '\u{5b7e}',  IdentifierType::Uncommon_Use), ('\u{5b7f}', '\u{5b85}',
-         IdentifierType::Recommended), ('\u{5b86}', '\u{5b86}',  IdentifierType::Uncommon_Use),
-        ('\u{5b87}', '\u{5b8f}',  IdentifierType::Recommended), ('\u{5b90}', '\u{5b90}',
-         IdentifierType::Uncommon_Use), ('\u{5b91}', '\u{5ba8}',  IdentifierType::Recommended),
-        ('\u{5ba9}', '\u{5ba9}',  IdentifierType::Uncommon_Use), ('\u{5baa}', '\u{5bb1}',
-         IdentifierType::Recommended), ('\u{5bb2}', '\u{5bb2}',  IdentifierType::Uncommon_Use),
-        ('\u{5bb3}', '\u{5bb6}',  IdentifierType::Recommended), ('\u{5bb7}', '\u{5bb7}',
-         IdentifierType::Uncommon_Use), ('\u{5bb8}', '\u{5bbb}',  IdentifierType::Recommended),
-        ('\u{5bbc}', '\u{5bbc}',  IdentifierType::Uncommon_Use), ('\u{5bbd}', '\u{5bc7}',
-         IdentifierType::Recommended), ('\u{5bc8}', '\u{5bc8}',  IdentifierType::Uncommon_Use),
-        ('\u{5bc9}', '\u{5bd9}',  IdentifierType::Recommended), ('\u{5bda}', '\u{5bda}',
-         IdentifierType::Uncommon_Use), ('\u{5bdb}', '\u{5bff}',  IdentifierType::Recommended),
-        ('\u{5c00}', '\u{5c00}',  IdentifierType::Uncommon_Use), ('\u{5c01}', '\u{5c1a}',
-         IdentifierType::Recommended), ('\u{5c1b}', '\u{5c1b}',  IdentifierType::Uncommon_Use),
-        ('\u{5c1c}', '\u{5c22}',  IdentifierType::Recommended), ('\u{5c23}', '\u{5c23}',
-         IdentifierType::Uncommon_Use), ('\u{5c24}', '\u{5c25}',  IdentifierType::Recommended),
-        ('\u{5c26}', '\u{5c26}',  IdentifierType::Uncommon_Use), ('\u{5c27}', '\u{5c28}',
-         IdentifierType::Recommended), ('\u{5c29}', '\u{5c29}',  IdentifierType::Uncommon_Use),
-        ('\u{5c2a}', '\u{5c35}',  IdentifierType::Recommended), ('\u{5c36}', '\u{5c36}',
-         IdentifierType::Uncommon_Use), ('\u{5c37}', '\u{5c59}',  IdentifierType::Recommended),
-        ('\u{5c5a}', '\u{5c5a}',  IdentifierType::Uncommon_Use), ('\u{5c5b}', '\u{5c84}',
-         IdentifierType::Recommended), ('\u{5c85}', '\u{5c85}',  IdentifierType::Uncommon_Use),
-        ('\u{5c86}', '\u{5cb3}',  IdentifierType::Recommended), ('\u{5cb4}', '\u{5cb4}',
-         IdentifierType::Uncommon_Use), ('\u{5cb5}', '\u{5cb8}',  IdentifierType::Recommended),
-        ('\u{5cb9}', '\u{5cb9}',  IdentifierType::Uncommon_Use), ('\u{5cba}', '\u{5cd4}',
-         IdentifierType::Recommended), ('\u{5cd5}', '\u{5cd5}',  IdentifierType::Uncommon_Use),
-        ('\u{5cd6}', '\u{5cdc}',  IdentifierType::Recommended), ('\u{5cdd}', '\u{5cdd}',
-         IdentifierType::Uncommon_Use), ('\u{5cde}', '\u{5cf4}',  IdentifierType::Recommended),
-        ('\u{5cf5}', '\u{5cf5}',  IdentifierType::Uncommon_Use), ('\u{5cf6}', '\u{5d2a}',
-         IdentifierType::Recommended), ('\u{5d2b}', '\u{5d2b}',  IdentifierType::Uncommon_Use),
-        ('\u{5d2c}', '\u{5d2e}',  IdentifierType::Recommended), ('\u{5d2f}', '\u{5d2f}',
-         IdentifierType::Uncommon_Use), ('\u{5d30}', '\u{5d3a}',  IdentifierType::Recommended),
-        ('\u{5d3b}', '\u{5d3b}',  IdentifierType::Uncommon_Use), ('\u{5d3c}', '\u{5d52}',
-         IdentifierType::Recommended), ('\u{5d53}', '\u{5d53}',  IdentifierType::Uncommon_Use),
-        ('\u{5d54}', '\u{5d56}',  IdentifierType::Recommended), ('\u{5d57}', '\u{5d57}',
-         IdentifierType::Uncommon_Use), ('\u{5d58}', '\u{5d5f}',  IdentifierType::Recommended),
-        ('\u{5d60}', '\u{5d60}',  IdentifierType::Uncommon_Use), ('\u{5d61}', '\u{5d82}',
-         IdentifierType::Recommended), ('\u{5d83}', '\u{5d83}',  IdentifierType::Uncommon_Use),
-        ('\u{5d84}', '\u{5d95}',  IdentifierType::Recommended), ('\u{5d96}', '\u{5d96}',
-         IdentifierType::Uncommon_Use), ('\u{5d97}', '\u{5da2}',  IdentifierType::Recommended),
-        ('\u{5da3}', '\u{5da4}',  IdentifierType::Uncommon_Use), ('\u{5da5}', '\u{5daa}',
-         IdentifierType::Recommended), ('\u{5dab}', '\u{5dab}',  IdentifierType::Uncommon_Use),
-        ('\u{5dac}', '\u{5db2}',  IdentifierType::Recommended), ('\u{5db3}', '\u{5db3}',
-         IdentifierType::Uncommon_Use), ('\u{5db4}', '\u{5db8}',  IdentifierType::Recommended),
-        ('\u{5db9}', '\u{5db9}',  IdentifierType::Uncommon_Use), ('\u{5dba}', '\u{5dc3}',
-         IdentifierType::Recommended), ('\u{5dc4}', '\u{5dc4}',  IdentifierType::Uncommon_Use),
-        ('\u{5dc5}', '\u{5dd6}',  IdentifierType::Recommended), ('\u{5dd7}', '\u{5dd7}',
-         IdentifierType::Uncommon_Use), ('\u{5dd8}', '\u{5dd9}',  IdentifierType::Recommended),
-        ('\u{5dda}', '\u{5dda}',  IdentifierType::Uncommon_Use), ('\u{5ddb}', '\u{5ddb}',
-         IdentifierType::Recommended), ('\u{5ddc}', '\u{5ddc}',  IdentifierType::Uncommon_Use),
-        ('\u{5ddd}', '\u{5df5}',  IdentifierType::Recommended), ('\u{5df6}', '\u{5df6}',
-         IdentifierType::Uncommon_Use), ('\u{5df7}', '\u{5e11}',  IdentifierType::Recommended),
-        ('\u{5e12}', '\u{5e12}',  IdentifierType::Uncommon_Use), ('\u{5e13}', '\u{5e47}',
-         IdentifierType::Recommended), ('\u{5e48}', '\u{5e48}',  IdentifierType::Uncommon_Use),
-        ('\u{5e49}', '\u{5e50}',  IdentifierType::Recommended), ('\u{5e51}', '\u{5e51}',
-         IdentifierType::Uncommon_Use), ('\u{5e52}', '\u{5e91}',  IdentifierType::Recommended),
-        ('\u{5e92}', '\u{5e92}',  IdentifierType::Uncommon_Use), ('\u{5e93}', '\u{5eb9}',
-         IdentifierType::Recommended), ('\u{5eba}', '\u{5eba}',  IdentifierType::Uncommon_Use),
-        ('\u{5ebb}', '\u{5ebf}',  IdentifierType::Recommended), ('\u{5ec0}', '\u{5ec0}',
-         IdentifierType::Uncommon_Use), ('\u{5ec1}', '\u{5eea}',  IdentifierType::Recommended),
-        ('\u{5eeb}', '\u{5eeb}',  IdentifierType::Uncommon_Use), ('\u{5eec}', '\u{5ef8}',
-         IdentifierType::Recommended), ('\u{5ef9}', '\u{5ef9}',  IdentifierType::Uncommon_Use),
-        ('\u{5efa}', '\u{5f0d}',  IdentifierType::Recommended), ('\u{5f0e}', '\u{5f0e}',
-         IdentifierType::Uncommon_Use), ('\u{5f0f}', '\u{5f3a}',  IdentifierType::Recommended),
-        ('\u{5f3b}', '\u{5f3b}',  IdentifierType::Uncommon_Use), ('\u{5f3c}', '\u{5f3c}',
-         IdentifierType::Recommended), ('\u{5f3d}', '\u{5f3d}',  IdentifierType::Uncommon_Use),
-        ('\u{5f3e}', '\u{5f8e}',  IdentifierType::Recommended), ('\u{5f8f}', '\u{5f8f}',
-         IdentifierType::Uncommon_Use), ('\u{5f90}', '\u{5f99}',  IdentifierType::Recommended),
-        ('\u{5f9a}', '\u{5f9a}',  IdentifierType::Uncommon_Use), ('\u{5f9b}', '\u{5fa2}',
-         IdentifierType::Recommended), ('\u{5fa3}', '\u{5fa4}',  IdentifierType::Uncommon_Use),
-        ('\u{5fa5}', '\u{5faf}',  IdentifierType::Recommended), ('\u{5fb0}', '\u{5fb0}',
-         IdentifierType::Uncommon_Use), ('\u{5fb1}', '\u{5fc1}',  IdentifierType::Recommended),
-        ('\u{5fc2}', '\u{5fc2}',  IdentifierType::Uncommon_Use), ('\u{5fc3}', '\u{5fcd}',
-         IdentifierType::Recommended), ('\u{5fce}', '\u{5fce}',  IdentifierType::Uncommon_Use),
-        ('\u{5fcf}', '\u{5fda}',  IdentifierType::Recommended), ('\u{5fdb}', '\u{5fdb}',
-         IdentifierType::Uncommon_Use), ('\u{5fdc}', '\u{5fe1}',  IdentifierType::Recommended),
-        ('\u{5fe2}', '\u{5fe2}',  IdentifierType::Uncommon_Use), ('\u{5fe3}', '\u{5feb}',
-         IdentifierType::Recommended), ('\u{5fec}', '\u{5fec}',  IdentifierType::Uncommon_Use),
-        ('\u{5fed}', '\u{5ffb}',  IdentifierType::Recommended), ('\u{5ffc}', '\u{5ffc}',
-         IdentifierType::Uncommon_Use), ('\u{5ffd}', '\u{6022}',  IdentifierType::Recommended),
-        ('\u{6023}', '\u{6023}',  IdentifierType::Uncommon_Use), ('\u{6024}', '\u{6055}',
-         IdentifierType::Recommended), ('\u{6056}', '\u{6056}',  IdentifierType::Uncommon_Use),
-        ('\u{6057}', '\u{6060}',  IdentifierType::Recommended), ('\u{6061}', '\u{6061}',
-         IdentifierType::Uncommon_Use), ('\u{6062}', '\u{6070}',  IdentifierType::Recommended),
-        ('\u{6071}', '\u{6071}',  IdentifierType::Uncommon_Use), ('\u{6072}', '\u{6073}',
-         IdentifierType::Recommended), ('\u{6074}', '\u{6074}',  IdentifierType::Uncommon_Use),
-        ('\u{6075}', '\u{6090}',  IdentifierType::Recommended), ('\u{6091}', '\u{6091}',
-         IdentifierType::Uncommon_Use), ('\u{6092}', '\u{6092}',  IdentifierType::Recommended),
-        ('\u{6093}', '\u{6093}',  IdentifierType::Uncommon_Use), ('\u{6094}', '\u{60a4}',
-         IdentifierType::Recommended), ('\u{60a5}', '\u{60a5}',  IdentifierType::Uncommon_Use),
-        ('\u{60a6}', '\u{60d1}',  IdentifierType::Recommended), ('\u{60d2}', '\u{60d2}',
-         IdentifierType::Uncommon_Use), ('\u{60d3}', '\u{60d5}',  IdentifierType::Recommended),
-        ('\u{60d6}', '\u{60d6}',  IdentifierType::Uncommon_Use), ('\u{60d7}', '\u{60dd}',
-         IdentifierType::Recommended), ('\u{60de}', '\u{60de}',  IdentifierType::Uncommon_Use),
-        ('\u{60df}', '\u{60e4}',  IdentifierType::Recommended), ('\u{60e5}', '\u{60e5}',
-         IdentifierType::Uncommon_Use), ('\u{60e6}', '\u{60fc}',  IdentifierType::Recommended),
-        ('\u{60fd}', '\u{60fd}',  IdentifierType::Uncommon_Use), ('\u{60fe}', '\u{6101}',
-         IdentifierType::Recommended), ('\u{6102}', '\u{6102}',  IdentifierType::Uncommon_Use),
-        ('\u{6103}', '\u{6106}',  IdentifierType::Recommended), ('\u{6107}', '\u{6107}',
-         IdentifierType::Uncommon_Use), ('\u{6108}', '\u{6110}',  IdentifierType::Recommended),
-        ('\u{6111}', '\u{6111}',  IdentifierType::Uncommon_Use), ('\u{6112}', '\u{611d}',
-         IdentifierType::Recommended), ('\u{611e}', '\u{611e}',  IdentifierType::Uncommon_Use),
-        ('\u{611f}', '\u{6130}',  IdentifierType::Recommended), ('\u{6131}', '\u{6131}',
-         IdentifierType::Uncommon_Use), ('\u{6132}', '\u{6132}',  IdentifierType::Recommended),
-        ('\u{6133}', '\u{6133}',  IdentifierType::Uncommon_Use), ('\u{6134}', '\u{6134}',
-         IdentifierType::Recommended), ('\u{6135}', '\u{6135}',  IdentifierType::Uncommon_Use),
-        ('\u{6136}', '\u{6137}',  IdentifierType::Recommended), ('\u{6138}', '\u{6139}',
-         IdentifierType::Uncommon_Use), ('\u{613a}', '\u{615f}',  IdentifierType::Recommended),
-        ('\u{6160}', '\u{6160}',  IdentifierType::Uncommon_Use), ('\u{6161}', '\u{617a}',
-         IdentifierType::Recommended), ('\u{617b}', '\u{617b}',  IdentifierType::Uncommon_Use),
-        ('\u{617c}', '\u{617e}',  IdentifierType::Recommended), ('\u{617f}', '\u{617f}',
-         IdentifierType::Uncommon_Use), ('\u{6180}', '\u{6185}',  IdentifierType::Recommended),
-        ('\u{6186}', '\u{6186}',  IdentifierType::Uncommon_Use), ('\u{6187}', '\u{6196}',
-         IdentifierType::Recommended), ('\u{6197}', '\u{6197}',  IdentifierType::Uncommon_Use),
-        ('\u{6198}', '\u{619b}',  IdentifierType::Recommended), ('\u{619c}', '\u{619c}',
-         IdentifierType::Uncommon_Use), ('\u{619d}', '\u{61b8}',  IdentifierType::Recommended),
-        ('\u{61b9}', '\u{61b9}',  IdentifierType::Uncommon_Use), ('\u{61ba}', '\u{61ba}',
-         IdentifierType::Recommended), ('\u{61bb}', '\u{61bb}',  IdentifierType::Uncommon_Use),
-        ('\u{61bc}', '\u{61d2}',  IdentifierType::Recommended), ('\u{61d3}', '\u{61d3}',
-         IdentifierType::Uncommon_Use), ('\u{61d4}', '\u{61d4}',  IdentifierType::Recommended),
-        ('\u{61d5}', '\u{61d5}',  IdentifierType::Uncommon_Use), ('\u{61d6}', '\u{61eb}',
-         IdentifierType::Recommended), ('\u{61ec}', '\u{61ec}',  IdentifierType::Uncommon_Use),
-        ('\u{61ed}', '\u{61ee}',  IdentifierType::Recommended), ('\u{61ef}', '\u{61ef}',
-         IdentifierType::Uncommon_Use), ('\u{61f0}', '\u{6204}',  IdentifierType::Recommended),
-        ('\u{6205}', '\u{6205}',  IdentifierType::Uncommon_Use), ('\u{6206}', '\u{6234}',
-         IdentifierType::Recommended), ('\u{6235}', '\u{6235}',  IdentifierType::Uncommon_Use),
-        ('\u{6236}', '\u{6238}',  IdentifierType::Recommended), ('\u{6239}', '\u{6239}',
-         IdentifierType::Uncommon_Use), ('\u{623a}', '\u{6256}',  IdentifierType::Recommended),
-        ('\u{6257}', '\u{6257}',  IdentifierType::Uncommon_Use), ('\u{6258}', '\u{628c}',
-         IdentifierType::Recommended), ('\u{628d}', '\u{628d}',  IdentifierType::Uncommon_Use),
-        ('\u{628e}', '\u{629c}',  IdentifierType::Recommended), ('\u{629d}', '\u{629d}',
-         IdentifierType::Uncommon_Use), ('\u{629e}', '\u{62dd}',  IdentifierType::Recommended),
-        ('\u{62de}', '\u{62de}',  IdentifierType::Uncommon_Use), ('\u{62df}', '\u{62e9}',
-         IdentifierType::Recommended), ('\u{62ea}', '\u{62ea}',  IdentifierType::Uncommon_Use),
-        ('\u{62eb}', '\u{6309}',  IdentifierType::Recommended), ('\u{630a}', '\u{630a}',
-         IdentifierType::Uncommon_Use), ('\u{630b}', '\u{6316}',  IdentifierType::Recommended),
-        ('\u{6317}', '\u{6317}',  IdentifierType::Uncommon_Use), ('\u{6318}', '\u{6330}',
-         IdentifierType::Recommended), ('\u{6331}', '\u{6331}',  IdentifierType::Uncommon_Use),
-        ('\u{6332}', '\u{6336}',  IdentifierType::Recommended), ('\u{6337}', '\u{6337}',
-         IdentifierType::Uncommon_Use), ('\u{6338}', '\u{635a}',  IdentifierType::Recommended),
-        ('\u{635b}', '\u{635b}',  IdentifierType::Uncommon_Use), ('\u{635c}', '\u{638a}',
-         IdentifierType::Recommended), ('\u{638b}', '\u{638b}',  IdentifierType::Uncommon_Use),
-        ('\u{638c}', '\u{6392}',  IdentifierType::Recommended), ('\u{6393}', '\u{6393}',
-         IdentifierType::Uncommon_Use), ('\u{6394}', '\u{63d0}',  IdentifierType::Recommended),
-        ('\u{63d1}', '\u{63d1}',  IdentifierType::Uncommon_Use), ('\u{63d2}', '\u{643a}',
-         IdentifierType::Recommended), ('\u{643b}', '\u{643c}',  IdentifierType::Uncommon_Use),
-        ('\u{643d}', '\u{6448}',  IdentifierType::Recommended), ('\u{6449}', '\u{6449}',
-         IdentifierType::Uncommon_Use), ('\u{644a}', '\u{6459}',  IdentifierType::Recommended),
-        ('\u{645a}', '\u{645a}',  IdentifierType::Uncommon_Use), ('\u{645b}', '\u{647d}',
-         IdentifierType::Recommended), ('\u{647e}', '\u{647e}',  IdentifierType::Uncommon_Use),
-        ('\u{647f}', '\u{6485}',  IdentifierType::Recommended), ('\u{6486}', '\u{6486}',
-         IdentifierType::Uncommon_Use), ('\u{6487}', '\u{64a0}',  IdentifierType::Recommended),
-        ('\u{64a1}', '\u{64a1}',  IdentifierType::Uncommon_Use), ('\u{64a2}', '\u{64ae}',
-         IdentifierType::Recommended), ('\u{64af}', '\u{64af}',  IdentifierType::Uncommon_Use),
-        ('\u{64b0}', '\u{64b5}',  IdentifierType::Recommended), ('\u{64b6}', '\u{64b6}',
-         IdentifierType::Uncommon_Use), ('\u{64b7}', '\u{64c7}',  IdentifierType::Recommended),
-        ('\u{64c8}', '\u{64c8}',  IdentifierType::Uncommon_Use), ('\u{64c9}', '\u{64d4}',
-         IdentifierType::Recommended), ('\u{64d5}', '\u{64d5}',  IdentifierType::Uncommon_Use),
-        ('\u{64d6}', '\u{64ed}',  IdentifierType::Recommended), ('\u{64ee}', '\u{64ee}',
-         IdentifierType::Uncommon_Use), ('\u{64ef}', '\u{64f4}',  IdentifierType::Recommended),
-        ('\u{64f5}', '\u{64f5}',  IdentifierType::Uncommon_Use), ('\u{64f6}', '\u{64f8}',
-         IdentifierType::Recommended), ('\u{64f9}', '\u{64f9}',  IdentifierType::Uncommon_Use),
-        ('\u{64fa}', '\u{6501}',  IdentifierType::Recommended), ('\u{6502}', '\u{6502}',
-         IdentifierType::Uncommon_Use), ('\u{6503}', '\u{6509}',  IdentifierType::Recommended),
-        ('\u{650a}', '\u{650a}',  IdentifierType::Uncommon_Use), ('\u{650b}', '\u{651e}',
-         IdentifierType::Recommended), ('\u{651f}', '\u{651f}',  IdentifierType::Uncommon_Use),
-        ('\u{6520}', '\u{6527}',  IdentifierType::Recommended), ('\u{6528}', '\u{6528}',
-         IdentifierType::Uncommon_Use), ('\u{6529}', '\u{653f}',  IdentifierType::Recommended),
-        ('\u{6540}', '\u{6540}',  IdentifierType::Uncommon_Use), ('\u{6541}', '\u{6541}',
-         IdentifierType::Recommended), ('\u{6542}', '\u{6542}',  IdentifierType::Uncommon_Use),
-        ('\u{6543}', '\u{6559}',  IdentifierType::Recommended), ('\u{655a}', '\u{655a}',
-         IdentifierType::Uncommon_Use), ('\u{655b}', '\u{655e}',  IdentifierType::Recommended),
-        ('\u{655f}', '\u{655f}',  IdentifierType::Uncommon_Use), ('\u{6560}', '\u{657c}',
-         IdentifierType::Recommended), ('\u{657d}', '\u{657d}',  IdentifierType::Uncommon_Use),
-        ('\u{657e}', '\u{6589}',  IdentifierType::Recommended), ('\u{658a}', '\u{658a}',
-         IdentifierType::Uncommon_Use), ('\u{658b}', '\u{6599}',  IdentifierType::Recommended),
-        ('\u{659a}', '\u{659a}',  IdentifierType::Uncommon_Use), ('\u{659b}', '\u{65b4}',
-         IdentifierType::Recommended), ('\u{65b5}', '\u{65b5}',  IdentifierType::Uncommon_Use),
-        ('\u{65b6}', '\u{65bd}',  IdentifierType::Recommended), ('\u{65be}', '\u{65be}',
-         IdentifierType::Uncommon_Use), ('\u{65bf}', '\u{65c7}',  IdentifierType::Recommended),
-        ('\u{65c8}', '\u{65c9}',  IdentifierType::Uncommon_Use), ('\u{65ca}', '\u{65d0}',
-         IdentifierType::Recommended), ('\u{65d1}', '\u{65d1}',  IdentifierType::Uncommon_Use),
-        ('\u{65d2}', '\u{65d7}',  IdentifierType::Recommended), ('\u{65d8}', '\u{65d8}',
-         IdentifierType::Uncommon_Use), ('\u{65d9}', '\u{65db}',  IdentifierType::Recommended),
-        ('\u{65dc}', '\u{65dc}',  IdentifierType::Uncommon_Use), ('\u{65dd}', '\u{65e3}',
-         IdentifierType::Recommended), ('\u{65e4}', '\u{65e4}',  IdentifierType::Uncommon_Use),
-        ('\u{65e5}', '\u{65e9}',  IdentifierType::Recommended), ('\u{65ea}', '\u{65ea}',
-         IdentifierType::Uncommon_Use), ('\u{65eb}', '\u{65f8}',  IdentifierType::Recommended),
-        ('\u{65f9}', '\u{65f9}',  IdentifierType::Uncommon_Use), ('\u{65fa}', '\u{65fd}',
-         IdentifierType::Recommended), ('\u{65fe}', '\u{65fe}',  IdentifierType::Uncommon_Use),
-        ('\u{65ff}', '\u{6616}',  IdentifierType::Recommended), ('\u{6617}', '\u{6617}',
-         IdentifierType::Uncommon_Use), ('\u{6618}', '\u{662b}',  IdentifierType::Recommended),
-        ('\u{662c}', '\u{662c}',  IdentifierType::Uncommon_Use), ('\u{662d}', '\u{6636}',
-         IdentifierType::Recommended), ('\u{6637}', '\u{6638}',  IdentifierType::Uncommon_Use),
-        ('\u{6639}', '\u{6647}',  IdentifierType::Recommended), ('\u{6648}', '\u{6648}',
-         IdentifierType::Uncommon_Use), ('\u{6649}', '\u{664c}',  IdentifierType::Recommended),
-        ('\u{664d}', '\u{664d}',  IdentifierType::Uncommon_Use), ('\u{664e}', '\u{665f}',
-         IdentifierType::Recommended), ('\u{6660}', '\u{6660}',  IdentifierType::Uncommon_Use),
-        ('\u{6661}', '\u{6662}',  IdentifierType::Recommended), ('\u{6663}', '\u{6663}',
-         IdentifierType::Uncommon_Use), ('\u{6664}', '\u{6691}',  IdentifierType::Recommended),
-        ('\u{6692}', '\u{6692}',  IdentifierType::Uncommon_Use), ('\u{6693}', '\u{669b}',
-         IdentifierType::Recommended), ('\u{669c}', '\u{669c}',  IdentifierType::Uncommon_Use),
-        ('\u{669d}', '\u{669d}',  IdentifierType::Recommended), ('\u{669e}', '\u{669e}',
-         IdentifierType::Uncommon_Use), ('\u{669f}', '\u{66ab}',  IdentifierType::Recommended),
-        ('\u{66ac}', '\u{66ad}',  IdentifierType::Uncommon_Use), ('\u{66ae}', '\u{66cf}',
-         IdentifierType::Recommended), ('\u{66d0}', '\u{66d0}',  IdentifierType::Uncommon_Use),
-        ('\u{66d1}', '\u{66d2}',  IdentifierType::Recommended), ('\u{66d3}', '\u{66d3}',
-         IdentifierType::Uncommon_Use), ('\u{66d4}', '\u{66d6}',  IdentifierType::Recommended),
-        ('\u{66d7}', '\u{66d7}',  IdentifierType::Uncommon_Use), ('\u{66d8}', '\u{66de}',
-         IdentifierType::Recommended), ('\u{66df}', '\u{66df}',  IdentifierType::Uncommon_Use),
-        ('\u{66e0}', '\u{66ee}',  IdentifierType::Recommended), ('\u{66ef}', '\u{66ef}',
-         IdentifierType::Uncommon_Use), ('\u{66f0}', '\u{6701}',  IdentifierType::Recommended),
-        ('\u{6702}', '\u{6702}',  IdentifierType::Uncommon_Use), ('\u{6703}', '\u{6706}',
-         IdentifierType::Recommended), ('\u{6707}', '\u{6707}',  IdentifierType::Uncommon_Use),
-        ('\u{6708}', '\u{6718}',  IdentifierType::Recommended), ('\u{6719}', '\u{6719}',
-         IdentifierType::Uncommon_Use), ('\u{671a}', '\u{6723}',  IdentifierType::Recommended),
-        ('\u{6724}', '\u{6724}',  IdentifierType::Uncommon_Use), ('\u{6725}', '\u{6728}',
-         IdentifierType::Recommended), ('\u{6729}', '\u{6729}',  IdentifierType::Uncommon_Use),
-        ('\u{672a}', '\u{6766}',  IdentifierType::Recommended), ('\u{6767}', '\u{6767}',
-         IdentifierType::Uncommon_Use), ('\u{6768}', '\u{6787}',  IdentifierType::Recommended),
-        ('\u{6788}', '\u{6788}',  IdentifierType::Uncommon_Use), ('\u{6789}', '\u{6795}',
-         IdentifierType::Recommended), ('\u{6796}', '\u{6796}',  IdentifierType::Uncommon_Use),
-        ('\u{6797}', '\u{67bc}',  IdentifierType::Recommended), ('\u{67bd}', '\u{67bd}',
-         IdentifierType::Uncommon_Use), ('\u{67be}', '\u{67be}',  IdentifierType::Recommended),
-        ('\u{67bf}', '\u{67bf}',  IdentifierType::Uncommon_Use), ('\u{67c0}', '\u{67d4}',
-         IdentifierType::Recommended), ('\u{67d5}', '\u{67d5}',  IdentifierType::Uncommon_Use),
-        ('\u{67d6}', '\u{67d6}',  IdentifierType::Recommended), ('\u{67d7}', '\u{67d7}',
-         IdentifierType::Uncommon_Use), ('\u{67d8}', '\u{67f8}',  IdentifierType::Recommended),
-        ('\u{67f9}', '\u{67f9}',  IdentifierType::Uncommon_Use), ('\u{67fa}', '\u{6800}',
-         IdentifierType::Recommended), ('\u{6801}', '\u{6801}',  IdentifierType::Uncommon_Use),
-        ('\u{6802}', '\u{6814}',  IdentifierType::Recommended), ('\u{6815}', '\u{6815}',
-         IdentifierType::Uncommon_Use), ('\u{6816}', '\u{6826}',  IdentifierType::Recommended),
-        ('\u{6827}', '\u{6827}',  IdentifierType::Uncommon_Use), ('\u{6828}', '\u{682f}',
-         IdentifierType::Recommended), ('\u{6830}', '\u{6830}',  IdentifierType::Uncommon_Use),
-        ('\u{6831}', '\u{6857}',  IdentifierType::Recommended), ('\u{6858}', '\u{6858}',
-         IdentifierType::Uncommon_Use), ('\u{6859}', '\u{6859}',  IdentifierType::Recommended),
-        ('\u{685a}', '\u{685a}',  IdentifierType::Uncommon_Use), ('\u{685b}', '\u{685d}',
-         IdentifierType::Recommended), ('\u{685e}', '\u{685e}',  IdentifierType::Uncommon_Use),
-        ('\u{685f}', '\u{6879}',  IdentifierType::Recommended), ('\u{687a}', '\u{687a}',
-         IdentifierType::Uncommon_Use), ('\u{687b}', '\u{6894}',  IdentifierType::Recommended),
-        ('\u{6895}', '\u{6895}',  IdentifierType::Uncommon_Use), ('\u{6896}', '\u{6898}',
-         IdentifierType::Recommended), ('\u{6899}', '\u{6899}',  IdentifierType::Uncommon_Use),
-        ('\u{689a}', '\u{68a4}',  IdentifierType::Recommended), ('\u{68a5}', '\u{68a5}',
-         IdentifierType::Uncommon_Use), ('\u{68a6}', '\u{68b7}',  IdentifierType::Recommended),
-        ('\u{68b8}', '\u{68b8}',  IdentifierType::Uncommon_Use), ('\u{68b9}', '\u{68c2}',
-         IdentifierType::Recommended), ('\u{68c3}', '\u{68c3}',  IdentifierType::Uncommon_Use),
-        ('\u{68c4}', '\u{68d8}',  IdentifierType::Recommended), ('\u{68d9}', '\u{68d9}',
-         IdentifierType::Uncommon_Use), ('\u{68da}', '\u{68e1}',  IdentifierType::Recommended),
-        ('\u{68e2}', '\u{68e2}',  IdentifierType::Uncommon_Use), ('\u{68e3}', '\u{68e4}',
-         IdentifierType::Recommended), ('\u{68e5}', '\u{68e5}',  IdentifierType::Uncommon_Use),
-        ('\u{68e6}', '\u{6908}',  IdentifierType::Recommended), ('\u{6909}', '\u{6909}',
-         IdentifierType::Uncommon_Use), ('\u{690a}', '\u{693d}',  IdentifierType::Recommended),
-        ('\u{693e}', '\u{693e}',  IdentifierType::Uncommon_Use), ('\u{693f}', '\u{694c}',
-         IdentifierType::Recommended), ('\u{694d}', '\u{694d}',  IdentifierType::Uncommon_Use),
-        ('\u{694e}', '\u{699e}',  IdentifierType::Recommended), ('\u{699f}', '\u{699f}',
-         IdentifierType::Uncommon_Use), ('\u{69a0}', '\u{69a1}',  IdentifierType::Recommended),
-        ('\u{69a2}', '\u{69a2}',  IdentifierType::Uncommon_Use), ('\u{69a3}', '\u{69bf}',
-         IdentifierType::Recommended), ('\u{69c0}', '\u{69c0}',  IdentifierType::Uncommon_Use),
-        ('\u{69c1}', '\u{69d0}',  IdentifierType::Recommended), ('\u{69d1}', '\u{69d2}',
-         IdentifierType::Uncommon_Use), ('\u{69d3}', '\u{69d4}',  IdentifierType::Recommended),
-        ('\u{69d5}', '\u{69d7}',  IdentifierType::Uncommon_Use), ('\u{69d8}', '\u{6a02}',
-         IdentifierType::Recommended), ('\u{6a03}', '\u{6a03}',  IdentifierType::Uncommon_Use),
-        ('\u{6a04}', '\u{6a1b}',  IdentifierType::Recommended), ('\u{6a1c}', '\u{6a1c}',
-         IdentifierType::Uncommon_Use), ('\u{6a1d}', '\u{6a23}',  IdentifierType::Recommended),
-        ('\u{6a24}', '\u{6a24}',  IdentifierType::Uncommon_Use), ('\u{6a25}', '\u{6a36}',
-         IdentifierType::Recommended), ('\u{6a37}', '\u{6a37}',  IdentifierType::Uncommon_Use),
-        ('\u{6a38}', '\u{6a49}',  IdentifierType::Recommended), ('\u{6a4a}', '\u{6a4a}',
-         IdentifierType::Uncommon_Use), ('\u{6a4b}', '\u{6a5b}',  IdentifierType::Recommended),
-        ('\u{6a5c}', '\u{6a5c}',  IdentifierType::Uncommon_Use), ('\u{6a5d}', '\u{6a6d}',
-         IdentifierType::Recommended), ('\u{6a6e}', '\u{6a6e}',  IdentifierType::Uncommon_Use),
-        ('\u{6a6f}', '\u{6a6f}',  IdentifierType::Recommended), ('\u{6a70}', '\u{6a70}',
-         IdentifierType::Uncommon_Use), ('\u{6a71}', '\u{6a85}',  IdentifierType::Recommended),
-        ('\u{6a86}', '\u{6a86}',  IdentifierType::Uncommon_Use), ('\u{6a87}', '\u{6a89}',
-         IdentifierType::Recommended), ('\u{6a8a}', '\u{6a8a}',  IdentifierType::Uncommon_Use),
-        ('\u{6a8b}', '\u{6a8e}',  IdentifierType::Recommended), ('\u{6a8f}', '\u{6a8f}',
-         IdentifierType::Uncommon_Use), ('\u{6a90}', '\u{6a98}',  IdentifierType::Recommended),
-        ('\u{6a99}', '\u{6a99}',  IdentifierType::Uncommon_Use), ('\u{6a9a}', '\u{6a9c}',
-         IdentifierType::Recommended), ('\u{6a9d}', '\u{6a9d}',  IdentifierType::Uncommon_Use),
-        ('\u{6a9e}', '\u{6ab0}',  IdentifierType::Recommended), ('\u{6ab1}', '\u{6ab1}',
-         IdentifierType::Uncommon_Use), ('\u{6ab2}', '\u{6abd}',  IdentifierType::Recommended),
-        ('\u{6abe}', '\u{6abe}',  IdentifierType::Uncommon_Use), ('\u{6abf}', '\u{6abf}',
-         IdentifierType::Recommended), ('\u{6ac0}', '\u{6ac0}',  IdentifierType::Uncommon_Use),
-        ('\u{6ac1}', '\u{6ac3}',  IdentifierType::Recommended), ('\u{6ac4}', '\u{6ac4}',
-         IdentifierType::Uncommon_Use), ('\u{6ac5}', '\u{6ac8}',  IdentifierType::Recommended),
-        ('\u{6ac9}', '\u{6ac9}',  IdentifierType::Uncommon_Use), ('\u{6aca}', '\u{6ad7}',
-         IdentifierType::Recommended), ('\u{6ad8}', '\u{6ad8}',  IdentifierType::Uncommon_Use),
-        ('\u{6ad9}', '\u{6ae8}',  IdentifierType::Recommended), ('\u{6ae9}', '\u{6ae9}',
-         IdentifierType::Uncommon_Use), ('\u{6aea}', '\u{6b0d}',  IdentifierType::Recommended),
-        ('\u{6b0e}', '\u{6b0e}',  IdentifierType::Uncommon_Use), ('\u{6b0f}', '\u{6b1a}',
-         IdentifierType::Recommended), ('\u{6b1b}', '\u{6b1b}',  IdentifierType::Uncommon_Use),
-        ('\u{6b1c}', '\u{6b2d}',  IdentifierType::Recommended), ('\u{6b2e}', '\u{6b2e}',
-         IdentifierType::Uncommon_Use), ('\u{6b2f}', '\u{6b34}',  IdentifierType::Recommended),
-        ('\u{6b35}', '\u{6b35}',  IdentifierType::Uncommon_Use), ('\u{6b36}', '\u{6b3f}',
-         IdentifierType::Recommended), ('\u{6b40}', '\u{6b40}',  IdentifierType::Uncommon_Use),
-        ('\u{6b41}', '\u{6b56}',  IdentifierType::Recommended), ('\u{6b57}', '\u{6b58}',
-         IdentifierType::Uncommon_Use), ('\u{6b59}', '\u{6b5c}',  IdentifierType::Recommended),
-        ('\u{6b5d}', '\u{6b5d}',  IdentifierType::Uncommon_Use), ('\u{6b5e}', '\u{6b67}',
-         IdentifierType::Recommended), ('\u{6b68}', '\u{6b68}',  IdentifierType::Uncommon_Use),
-        ('\u{6b69}', '\u{6b6b}',  IdentifierType::Recommended), ('\u{6b6c}', '\u{6b6c}',
-         IdentifierType::Uncommon_Use), ('\u{6b6d}', '\u{6b6d}',  IdentifierType::Recommended),
-        ('\u{6b6e}', '\u{6b6e}',  IdentifierType::Uncommon_Use), ('\u{6b6f}', '\u{6b70}',
-         IdentifierType::Recommended), ('\u{6b71}', '\u{6b71}',  IdentifierType::Uncommon_Use),
-        ('\u{6b72}', '\u{6b74}',  IdentifierType::Recommended), ('\u{6b75}', '\u{6b75}',
-         IdentifierType::Uncommon_Use), ('\u{6b76}', '\u{6b7c}',  IdentifierType::Recommended),
-        ('\u{6b7d}', '\u{6b7d}',  IdentifierType::Uncommon_Use), ('\u{6b7e}', '\u{6bb7}',
-         IdentifierType::Recommended), ('\u{6bb8}', '\u{6bb8}',  IdentifierType::Uncommon_Use),
-        ('\u{6bb9}', '\u{6be8}',  IdentifierType::Recommended), ('\u{6be9}', '\u{6be9}',
-         IdentifierType::Uncommon_Use), ('\u{6bea}', '\u{6bf0}',  IdentifierType::Recommended),
-        ('\u{6bf1}', '\u{6bf1}',  IdentifierType::Uncommon_Use), ('\u{6bf2}', '\u{6bf3}',
-         IdentifierType::Recommended), ('\u{6bf4}', '\u{6bf4}',  IdentifierType::Uncommon_Use),
-        ('\u{6bf5}', '\u{6bf9}',  IdentifierType::Recommended), ('\u{6bfa}', '\u{6bfa}',
-         IdentifierType::Uncommon_Use), ('\u{6bfb}', '\u{6c09}',  IdentifierType::Recommended),
-        ('\u{6c0a}', '\u{6c0a}',  IdentifierType::Uncommon_Use), ('\u{6c0b}', '\u{6c1b}',
-         IdentifierType::Recommended), ('\u{6c1c}', '\u{6c1c}',  IdentifierType::Uncommon_Use),
-        ('\u{6c1d}', '\u{6c2c}',  IdentifierType::Recommended), ('\u{6c2d}', '\u{6c2d}',
-         IdentifierType::Uncommon_Use), ('\u{6c2e}', '\u{6c3b}',  IdentifierType::Recommended),
-        ('\u{6c3c}', '\u{6c3c}',  IdentifierType::Uncommon_Use), ('\u{6c3d}', '\u{6c44}',
-         IdentifierType::Recommended), ('\u{6c45}', '\u{6c45}',  IdentifierType::Uncommon_Use),
-        ('\u{6c46}', '\u{6c6b}',  IdentifierType::Recommended), ('\u{6c6c}', '\u{6c6c}',
-         IdentifierType::Uncommon_Use), ('\u{6c6d}', '\u{6c6d}',  IdentifierType::Recommended),
-        ('\u{6c6e}', '\u{6c6e}',  IdentifierType::Uncommon_Use), ('\u{6c6f}', '\u{6c9f}',
-         IdentifierType::Recommended), ('\u{6ca0}', '\u{6ca0}',  IdentifierType::Uncommon_Use),
-        ('\u{6ca1}', '\u{6cd7}',  IdentifierType::Recommended), ('\u{6cd8}', '\u{6cd8}',
-         IdentifierType::Uncommon_Use), ('\u{6cd9}', '\u{6cf3}',  IdentifierType::Recommended),
-        ('\u{6cf4}', '\u{6cf4}',  IdentifierType::Uncommon_Use), ('\u{6cf5}', '\u{6d01}',
-         IdentifierType::Recommended), ('\u{6d02}', '\u{6d02}',  IdentifierType::Uncommon_Use),
-        ('\u{6d03}', '\u{6d1b}',  IdentifierType::Recommended), ('\u{6d1c}', '\u{6d1c}',
-         IdentifierType::Uncommon_Use), ('\u{6d1d}', '\u{6d23}',  IdentifierType::Recommended),
-        ('\u{6d24}', '\u{6d24}',  IdentifierType::Uncommon_Use), ('\u{6d25}', '\u{6d70}',
-         IdentifierType::Recommended), ('\u{6d71}', '\u{6d71}',  IdentifierType::Uncommon_Use),
-        ('\u{6d72}', '\u{6d80}',  IdentifierType::Recommended), ('\u{6d81}', '\u{6d81}',
-         IdentifierType::Uncommon_Use), ('\u{6d82}', '\u{6d95}',  IdentifierType::Recommended),
-        ('\u{6d96}', '\u{6d96}',  IdentifierType::Uncommon_Use), ('\u{6d97}', '\u{6daf}',
-         IdentifierType::Recommended), ('\u{6db0}', '\u{6db1}',  IdentifierType::Uncommon_Use),
-        ('\u{6db2}', '\u{6db5}',  IdentifierType::Recommended), ('\u{6db6}', '\u{6db6}',
-         IdentifierType::Uncommon_Use), ('\u{6db7}', '\u{6dfd}',  IdentifierType::Recommended),
-        ('\u{6dfe}', '\u{6dff}',  IdentifierType::Uncommon_Use), ('\u{6e00}', '\u{6e00}',
-         IdentifierType::Recommended), ('\u{6e01}', '\u{6e02}',  IdentifierType::Uncommon_Use),
-        ('\u{6e03}', '\u{6e05}',  IdentifierType::Recommended), ('\u{6e06}', '\u{6e06}',
-         IdentifierType::Uncommon_Use), ('\u{6e07}', '\u{6e11}',  IdentifierType::Recommended),
-        ('\u{6e12}', '\u{6e12}',  IdentifierType::Uncommon_Use), ('\u{6e13}', '\u{6e17}',
-         IdentifierType::Recommended), ('\u{6e18}', '\u{6e18}',  IdentifierType::Uncommon_Use),
-        ('\u{6e19}', '\u{6e29}',  IdentifierType::Recommended), ('\u{6e2a}', '\u{6e2a}',
-         IdentifierType::Uncommon_Use), ('\u{6e2b}', '\u{6e4b}',  IdentifierType::Recommended),
-        ('\u{6e4c}', '\u{6e4c}',  IdentifierType::Uncommon_Use), ('\u{6e4d}', '\u{6e6b}',
-         IdentifierType::Recommended), ('\u{6e6c}', '\u{6e6c}',  IdentifierType::Uncommon_Use),
-        ('\u{6e6d}', '\u{6e7a}',  IdentifierType::Recommended), ('\u{6e7b}', '\u{6e7d}',
-         IdentifierType::Uncommon_Use), ('\u{6e7e}', '\u{6e8a}',  IdentifierType::Recommended),
-        ('\u{6e8b}', '\u{6e8b}',  IdentifierType::Uncommon_Use), ('\u{6e8c}', '\u{6e94}',
-         IdentifierType::Recommended), ('\u{6e95}', '\u{6e95}',  IdentifierType::Uncommon_Use),
-        ('\u{6e96}', '\u{6eda}',  IdentifierType::Recommended), ('\u{6edb}', '\u{6edb}',
-         IdentifierType::Uncommon_Use), ('\u{6edc}', '\u{6ee2}',  IdentifierType::Recommended),
-        ('\u{6ee3}', '\u{6ee3}',  IdentifierType::Uncommon_Use), ('\u{6ee4}', '\u{6f03}',
-         IdentifierType::Recommended), ('\u{6f04}', '\u{6f04}',  IdentifierType::Uncommon_Use),
-        ('\u{6f05}', '\u{6f0a}',  IdentifierType::Recommended), ('\u{6f0b}', '\u{6f0b}',
-         IdentifierType::Uncommon_Use), ('\u{6f0c}', '\u{6f41}',  IdentifierType::Recommended),
-        ('\u{6f42}', '\u{6f42}',  IdentifierType::Uncommon_Use), ('\u{6f43}', '\u{6f47}',
-         IdentifierType::Recommended), ('\u{6f48}', '\u{6f48}',  IdentifierType::Uncommon_Use),
-        ('\u{6f49}', '\u{6f49}',  IdentifierType::Recommended), ('\u{6f4a}', '\u{6f4a}',
-         IdentifierType::Uncommon_Use), ('\u{6f4b}', '\u{6f78}',  IdentifierType::Recommended),
-        ('\u{6f79}', '\u{6f79}',  IdentifierType::Uncommon_Use), ('\u{6f7a}', '\u{6f97}',
-         IdentifierType::Recommended), ('\u{6f98}', '\u{6f98}',  IdentifierType::Uncommon_Use),
-        ('\u{6f99}', '\u{6f99}',  IdentifierType::Recommended), ('\u{6f9a}', '\u{6f9a}',
-         IdentifierType::Uncommon_Use), ('\u{6f9b}', '\u{6f9e}',  IdentifierType::Recommended),
-        ('\u{6f9f}', '\u{6f9f}',  IdentifierType::Uncommon_Use), ('\u{6fa0}', '\u{6fb6}',
-         IdentifierType::Recommended), ('\u{6fb7}', '\u{6fb7}',  IdentifierType::Uncommon_Use),
-        ('\u{6fb8}', '\u{6fc4}',  IdentifierType::Recommended), ('\u{6fc5}', '\u{6fc5}',
-         IdentifierType::Uncommon_Use), ('\u{6fc6}', '\u{6fcf}',  IdentifierType::Recommended),
-        ('\u{6fd0}', '\u{6fd0}',  IdentifierType::Uncommon_Use), ('\u{6fd1}', '\u{6fd2}',
-         IdentifierType::Recommended), ('\u{6fd3}', '\u{6fd3}',  IdentifierType::Uncommon_Use),
-        ('\u{6fd4}', '\u{6ff4}',  IdentifierType::Recommended), ('\u{6ff5}', '\u{6ff5}',
-         IdentifierType::Uncommon_Use), ('\u{6ff6}', '\u{6ffc}',  IdentifierType::Recommended),
-        ('\u{6ffd}', '\u{6ffd}',  IdentifierType::Uncommon_Use), ('\u{6ffe}', '\u{700f}',
-         IdentifierType::Recommended), ('\u{7010}', '\u{7010}',  IdentifierType::Uncommon_Use),
-        ('\u{7011}', '\u{7012}',  IdentifierType::Recommended), ('\u{7013}', '\u{7013}',
-         IdentifierType::Uncommon_Use), ('\u{7014}', '\u{7046}',  IdentifierType::Recommended),
-        ('\u{7047}', '\u{7047}',  IdentifierType::Uncommon_Use), ('\u{7048}', '\u{704a}',
-         IdentifierType::Recommended), ('\u{704b}', '\u{704b}',  IdentifierType::Uncommon_Use),
-        ('\u{704c}', '\u{704d}',  IdentifierType::Recommended), ('\u{704e}', '\u{704e}',
-         IdentifierType::Uncommon_Use), ('\u{704f}', '\u{7071}',  IdentifierType::Recommended),
-        ('\u{7072}', '\u{7073}',  IdentifierType::Uncommon_Use), ('\u{7074}', '\u{707a}',
-         IdentifierType::Recommended), ('\u{707b}', '\u{707b}',  IdentifierType::Uncommon_Use),
-        ('\u{707c}', '\u{7080}',  IdentifierType::Recommended), ('\u{7081}', '\u{7081}',
-         IdentifierType::Uncommon_Use), ('\u{7082}', '\u{708c}',  IdentifierType::Recommended),
-        ('\u{708d}', '\u{708d}',  IdentifierType::Uncommon_Use), ('\u{708e}', '\u{7096}',
-         IdentifierType::Recommended), ('\u{7097}', '\u{7097}',  IdentifierType::Uncommon_Use),
-        ('\u{7098}', '\u{709a}',  IdentifierType::Recommended), ('\u{709b}', '\u{709b}',
-         IdentifierType::Uncommon_Use), ('\u{709c}', '\u{70a9}',  IdentifierType::Recommended),
-        ('\u{70aa}', '\u{70aa}',  IdentifierType::Uncommon_Use), ('\u{70ab}', '\u{70b1}',
-         IdentifierType::Recommended), ('\u{70b2}', '\u{70b2}',  IdentifierType::Uncommon_Use),
-        ('\u{70b3}', '\u{70b5}',  IdentifierType::Recommended), ('\u{70b6}', '\u{70b6}',
-         IdentifierType::Uncommon_Use), ('\u{70b7}', '\u{70d4}',  IdentifierType::Recommended),
-        ('\u{70d5}', '\u{70d5}',  IdentifierType::Uncommon_Use), ('\u{70d6}', '\u{70fd}',
-         IdentifierType::Recommended), ('\u{70fe}', '\u{70fe}',  IdentifierType::Uncommon_Use),
-        ('\u{70ff}', '\u{7107}',  IdentifierType::Recommended), ('\u{7108}', '\u{7108}',
-         IdentifierType::Uncommon_Use), ('\u{7109}', '\u{7123}',  IdentifierType::Recommended),
-        ('\u{7124}', '\u{7124}',  IdentifierType::Uncommon_Use), ('\u{7125}', '\u{7132}',
-         IdentifierType::Recommended), ('\u{7133}', '\u{7134}',  IdentifierType::Uncommon_Use),
-        ('\u{7135}', '\u{7156}',  IdentifierType::Recommended), ('\u{7157}', '\u{7157}',
-         IdentifierType::Uncommon_Use), ('\u{7158}', '\u{716a}',  IdentifierType::Recommended),
-        ('\u{716b}', '\u{716b}',  IdentifierType::Uncommon_Use), ('\u{716c}', '\u{716c}',
-         IdentifierType::Recommended), ('\u{716d}', '\u{716d}',  IdentifierType::Uncommon_Use),
-        ('\u{716e}', '\u{718c}',  IdentifierType::Recommended), ('\u{718d}', '\u{718d}',
-         IdentifierType::Uncommon_Use), ('\u{718e}', '\u{7195}',  IdentifierType::Recommended),
-        ('\u{7196}', '\u{7196}',  IdentifierType::Uncommon_Use), ('\u{7197}', '\u{71a5}',
-         IdentifierType::Recommended), ('\u{71a6}', '\u{71a6}',  IdentifierType::Uncommon_Use),
-        ('\u{71a7}', '\u{71aa}',  IdentifierType::Recommended), ('\u{71ab}', '\u{71ab}',
-         IdentifierType::Uncommon_Use), ('\u{71ac}', '\u{71b5}',  IdentifierType::Recommended),
-        ('\u{71b6}', '\u{71b6}',  IdentifierType::Uncommon_Use), ('\u{71b7}', '\u{71cb}',
-         IdentifierType::Recommended), ('\u{71cc}', '\u{71cc}',  IdentifierType::Uncommon_Use),
-        ('\u{71cd}', '\u{71d2}',  IdentifierType::Recommended), ('\u{71d3}', '\u{71d3}',
-         IdentifierType::Uncommon_Use), ('\u{71d4}', '\u{71f2}',  IdentifierType::Recommended),
-        ('\u{71f3}', '\u{71f3}',  IdentifierType::Uncommon_Use), ('\u{71f4}', '\u{71f9}',
-         IdentifierType::Recommended), ('\u{71fa}', '\u{71fa}',  IdentifierType::Uncommon_Use),
-        ('\u{71fb}', '\u{720a}',  IdentifierType::Recommended), ('\u{720b}', '\u{720b}',
-         IdentifierType::Uncommon_Use), ('\u{720c}', '\u{7210}',  IdentifierType::Recommended),
-        ('\u{7211}', '\u{7211}',  IdentifierType::Uncommon_Use), ('\u{7212}', '\u{7214}',
-         IdentifierType::Recommended), ('\u{7215}', '\u{7215}',  IdentifierType::Uncommon_Use),
-        ('\u{7216}', '\u{7216}',  IdentifierType::Recommended), ('\u{7217}', '\u{7217}',
-         IdentifierType::Uncommon_Use), ('\u{7218}', '\u{721f}',  IdentifierType::Recommended),
-        ('\u{7220}', '\u{7220}',  IdentifierType::Uncommon_Use), ('\u{7221}', '\u{7223}',
-         IdentifierType::Recommended), ('\u{7224}', '\u{7225}',  IdentifierType::Uncommon_Use),
-        ('\u{7226}', '\u{722e}',  IdentifierType::Recommended), ('\u{722f}', '\u{722f}',
-         IdentifierType::Uncommon_Use), ('\u{7230}', '\u{7233}',  IdentifierType::Recommended),
-        ('\u{7234}', '\u{7234}',  IdentifierType::Uncommon_Use), ('\u{7235}', '\u{7244}',
-         IdentifierType::Recommended), ('\u{7245}', '\u{7245}',  IdentifierType::Uncommon_Use),
-        ('\u{7246}', '\u{724d}',  IdentifierType::Recommended), ('\u{724e}', '\u{724e}',
-         IdentifierType::Uncommon_Use), ('\u{724f}', '\u{724f}',  IdentifierType::Recommended),
-        ('\u{7250}', '\u{7250}',  IdentifierType::Uncommon_Use), ('\u{7251}', '\u{7254}',
-         IdentifierType::Recommended), ('\u{7255}', '\u{7255}',  IdentifierType::Uncommon_Use),
-        ('\u{7256}', '\u{72aa}',  IdentifierType::Recommended), ('\u{72ab}', '\u{72ab}',
-         IdentifierType::Uncommon_Use), ('\u{72ac}', '\u{72bd}',  IdentifierType::Recommended),
-        ('\u{72be}', '\u{72be}',  IdentifierType::Uncommon_Use), ('\u{72bf}', '\u{7301}',
-         IdentifierType::Recommended), ('\u{7302}', '\u{7302}',  IdentifierType::Uncommon_Use),
-        ('\u{7303}', '\u{730f}',  IdentifierType::Recommended), ('\u{7310}', '\u{7310}',
-         IdentifierType::Uncommon_Use), ('\u{7311}', '\u{7327}',  IdentifierType::Recommended),
-        ('\u{7328}', '\u{7328}',  IdentifierType::Uncommon_Use), ('\u{7329}', '\u{7352}',
-         IdentifierType::Recommended), ('\u{7353}', '\u{7353}',  IdentifierType::Uncommon_Use),
-        ('\u{7354}', '\u{739b}',  IdentifierType::Recommended), ('\u{739c}', '\u{739c}',
-         IdentifierType::Uncommon_Use), ('\u{739d}', '\u{73c0}',  IdentifierType::Recommended),
-        ('\u{73c1}', '\u{73c1}',  IdentifierType::Uncommon_Use), ('\u{73c2}', '\u{73f2}',
-         IdentifierType::Recommended), ('\u{73f3}', '\u{73f3}',  IdentifierType::Uncommon_Use),
-        ('\u{73f4}', '\u{73fa}',  IdentifierType::Recommended), ('\u{73fb}', '\u{73fb}',
-         IdentifierType::Uncommon_Use), ('\u{73fc}', '\u{7417}',  IdentifierType::Recommended),
-        ('\u{7418}', '\u{7418}',  IdentifierType::Uncommon_Use), ('\u{7419}', '\u{7438}',
-         IdentifierType::Recommended), ('\u{7439}', '\u{7439}',  IdentifierType::Uncommon_Use),
-        ('\u{743a}', '\u{743d}',  IdentifierType::Recommended), ('\u{743e}', '\u{743e}',
-         IdentifierType::Uncommon_Use), ('\u{743f}', '\u{7446}',  IdentifierType::Recommended),
-        ('\u{7447}', '\u{7447}',  IdentifierType::Uncommon_Use), ('\u{7448}', '\u{7448}',
-         IdentifierType::Recommended), ('\u{7449}', '\u{7449}',  IdentifierType::Uncommon_Use),
-        ('\u{744a}', '\u{7457}',  IdentifierType::Recommended), ('\u{7458}', '\u{7458}',
-         IdentifierType::Uncommon_Use), ('\u{7459}', '\u{747a}',  IdentifierType::Recommended),
-        ('\u{747b}', '\u{747b}',  IdentifierType::Uncommon_Use), ('\u{747c}', '\u{7483}',
-         IdentifierType::Recommended), ('\u{7484}', '\u{7484}',  IdentifierType::Uncommon_Use),
-        ('\u{7485}', '\u{7495}',  IdentifierType::Recommended), ('\u{7496}', '\u{7496}',
-         IdentifierType::Uncommon_Use), ('\u{7497}', '\u{749c}',  IdentifierType::Recommended),
-        ('\u{749d}', '\u{749d}',  IdentifierType::Uncommon_Use), ('\u{749e}', '\u{74c6}',
-         IdentifierType::Recommended), ('\u{74c7}', '\u{74c7}',  IdentifierType::Uncommon_Use),
-        ('\u{74c8}', '\u{74c8}',  IdentifierType::Recommended), ('\u{74c9}', '\u{74c9}',
-         IdentifierType::Uncommon_Use), ('\u{74ca}', '\u{74cb}',  IdentifierType::Recommended),
-        ('\u{74cc}', '\u{74cc}',  IdentifierType::Uncommon_Use), ('\u{74cd}', '\u{74ea}',
-         IdentifierType::Recommended), ('\u{74eb}', '\u{74eb}',  IdentifierType::Uncommon_Use),
-        ('\u{74ec}', '\u{751f}',  IdentifierType::Recommended), ('\u{7520}', '\u{7520}',
-         IdentifierType::Uncommon_Use), ('\u{7521}', '\u{7540}',  IdentifierType::Recommended),
-        ('\u{7541}', '\u{7541}',  IdentifierType::Uncommon_Use), ('\u{7542}', '\u{7551}',
-         IdentifierType::Recommended), ('\u{7552}', '\u{7552}',  IdentifierType::Uncommon_Use),
-        ('\u{7553}', '\u{7554}',  IdentifierType::Recommended), ('\u{7555}', '\u{7555}',
-         IdentifierType::Uncommon_Use), ('\u{7556}', '\u{755d}',  IdentifierType::Recommended),
-        ('\u{755e}', '\u{755e}',  IdentifierType::Uncommon_Use), ('\u{755f}', '\u{7560}',
-         IdentifierType::Recommended), ('\u{7561}', '\u{7561}',  IdentifierType::Uncommon_Use),
-        ('\u{7562}', '\u{7570}',  IdentifierType::Recommended), ('\u{7571}', '\u{7571}',
-         IdentifierType::Uncommon_Use), ('\u{7572}', '\u{757a}',  IdentifierType::Recommended),
-        ('\u{757b}', '\u{757b}',  IdentifierType::Uncommon_Use), ('\u{757c}', '\u{7584}',
-         IdentifierType::Recommended), ('\u{7585}', '\u{7585}',  IdentifierType::Uncommon_Use),
-        ('\u{7586}', '\u{75a8}',  IdentifierType::Recommended), ('\u{75a9}', '\u{75a9}',
-         IdentifierType::Uncommon_Use), ('\u{75aa}', '\u{75b6}',  IdentifierType::Recommended),
-        ('\u{75b7}', '\u{75b7}',  IdentifierType::Uncommon_Use), ('\u{75b8}', '\u{75db}',
-         IdentifierType::Recommended), ('\u{75dc}', '\u{75dc}',  IdentifierType::Uncommon_Use),
-        ('\u{75dd}', '\u{75ed}',  IdentifierType::Recommended), ('\u{75ee}', '\u{75ee}',
-         IdentifierType::Uncommon_Use), ('\u{75ef}', '\u{762b}',  IdentifierType::Recommended),
-        ('\u{762c}', '\u{762c}',  IdentifierType::Uncommon_Use), ('\u{762d}', '\u{7643}',
-         IdentifierType::Recommended), ('\u{7644}', '\u{7645}',  IdentifierType::Uncommon_Use),
-        ('\u{7646}', '\u{7650}',  IdentifierType::Recommended), ('\u{7651}', '\u{7651}',
-         IdentifierType::Uncommon_Use), ('\u{7652}', '\u{7654}',  IdentifierType::Recommended),
-        ('\u{7655}', '\u{7655}',  IdentifierType::Uncommon_Use), ('\u{7656}', '\u{7672}',
-         IdentifierType::Recommended), ('\u{7673}', '\u{7673}',  IdentifierType::Uncommon_Use),
-        ('\u{7674}', '\u{768c}',  IdentifierType::Recommended), ('\u{768d}', '\u{768d}',
-         IdentifierType::Uncommon_Use), ('\u{768e}', '\u{76a0}',  IdentifierType::Recommended),
-        ('\u{76a1}', '\u{76a2}',  IdentifierType::Uncommon_Use), ('\u{76a3}', '\u{76a4}',
-         IdentifierType::Recommended), ('\u{76a5}', '\u{76a5}',  IdentifierType::Uncommon_Use),
-        ('\u{76a6}', '\u{76a7}',  IdentifierType::Recommended), ('\u{76a8}', '\u{76a8}',
-         IdentifierType::Uncommon_Use), ('\u{76a9}', '\u{76b2}',  IdentifierType::Recommended),
-        ('\u{76b3}', '\u{76b3}',  IdentifierType::Uncommon_Use), ('\u{76b4}', '\u{76b5}',
-         IdentifierType::Recommended), ('\u{76b6}', '\u{76b6}',  IdentifierType::Uncommon_Use),
-        ('\u{76b7}', '\u{76c0}',  IdentifierType::Recommended), ('\u{76c1}', '\u{76c1}',
-         IdentifierType::Uncommon_Use), ('\u{76c2}', '\u{76ca}',  IdentifierType::Recommended),
-        ('\u{76cb}', '\u{76cb}',  IdentifierType::Uncommon_Use), ('\u{76cc}', '\u{76d8}',
-         IdentifierType::Recommended), ('\u{76d9}', '\u{76d9}',  IdentifierType::Uncommon_Use),
-        ('\u{76da}', '\u{76ea}',  IdentifierType::Recommended), ('\u{76eb}', '\u{76eb}',
-         IdentifierType::Uncommon_Use), ('\u{76ec}', '\u{76ff}',  IdentifierType::Recommended),
-        ('\u{7700}', '\u{7700}',  IdentifierType::Uncommon_Use), ('\u{7701}', '\u{7701}',
-         IdentifierType::Recommended), ('\u{7702}', '\u{7702}',  IdentifierType::Uncommon_Use),
-        ('\u{7703}', '\u{770d}',  IdentifierType::Recommended), ('\u{770e}', '\u{770e}',
-         IdentifierType::Uncommon_Use), ('\u{770f}', '\u{7720}',  IdentifierType::Recommended),
-        ('\u{7721}', '\u{7721}',  IdentifierType::Uncommon_Use), ('\u{7722}', '\u{772a}',
-         IdentifierType::Recommended), ('\u{772b}', '\u{772b}',  IdentifierType::Uncommon_Use),
-        ('\u{772c}', '\u{773e}',  IdentifierType::Recommended), ('\u{773f}', '\u{773f}',
-         IdentifierType::Uncommon_Use), ('\u{7740}', '\u{7741}',  IdentifierType::Recommended),
-        ('\u{7742}', '\u{7742}',  IdentifierType::Uncommon_Use), ('\u{7743}', '\u{7763}',
-         IdentifierType::Recommended), ('\u{7764}', '\u{7764}',  IdentifierType::Uncommon_Use),
-        ('\u{7765}', '\u{7795}',  IdentifierType::Recommended), ('\u{7796}', '\u{7796}',
-         IdentifierType::Uncommon_Use), ('\u{7797}', '\u{77a3}',  IdentifierType::Recommended),
-        ('\u{77a4}', '\u{77a4}',  IdentifierType::Uncommon_Use), ('\u{77a5}', '\u{77bd}',
-         IdentifierType::Recommended), ('\u{77be}', '\u{77be}',  IdentifierType::Uncommon_Use),
-        ('\u{77bf}', '\u{77c0}',  IdentifierType::Recommended), ('\u{77c1}', '\u{77c1}',
-         IdentifierType::Uncommon_Use), ('\u{77c2}', '\u{77d1}',  IdentifierType::Recommended),
-        ('\u{77d2}', '\u{77d2}',  IdentifierType::Uncommon_Use), ('\u{77d3}', '\u{77dc}',
-         IdentifierType::Recommended), ('\u{77dd}', '\u{77dd}',  IdentifierType::Uncommon_Use),
-        ('\u{77de}', '\u{77e3}',  IdentifierType::Recommended), ('\u{77e4}', '\u{77e4}',
-         IdentifierType::Uncommon_Use), ('\u{77e5}', '\u{77e5}',  IdentifierType::Recommended),
-        ('\u{77e6}', '\u{77e6}',  IdentifierType::Uncommon_Use), ('\u{77e7}', '\u{77f3}',
-         IdentifierType::Recommended), ('\u{77f4}', '\u{77f5}',  IdentifierType::Uncommon_Use),
-        ('\u{77f6}', '\u{7823}',  IdentifierType::Recommended), ('\u{7824}', '\u{7824}',
-         IdentifierType::Uncommon_Use), ('\u{7825}', '\u{7835}',  IdentifierType::Recommended),
-        ('\u{7836}', '\u{7836}',  IdentifierType::Uncommon_Use), ('\u{7837}', '\u{7841}',
-         IdentifierType::Recommended), ('\u{7842}', '\u{7842}',  IdentifierType::Uncommon_Use),
-        ('\u{7843}', '\u{7845}',  IdentifierType::Recommended), ('\u{7846}', '\u{7846}',
-         IdentifierType::Uncommon_Use), ('\u{7847}', '\u{784a}',  IdentifierType::Recommended),
-        ('\u{784b}', '\u{784b}',  IdentifierType::Uncommon_Use), ('\u{784c}', '\u{7875}',
-         IdentifierType::Recommended), ('\u{7876}', '\u{7876}',  IdentifierType::Uncommon_Use),
-        ('\u{7877}', '\u{7887}',  IdentifierType::Recommended), ('\u{7888}', '\u{7888}',
-         IdentifierType::Uncommon_Use), ('\u{7889}', '\u{78c1}',  IdentifierType::Recommended),
-        ('\u{78c2}', '\u{78c2}',  IdentifierType::Uncommon_Use), ('\u{78c3}', '\u{78c6}',
-         IdentifierType::Recommended), ('\u{78c7}', '\u{78c7}',  IdentifierType::Uncommon_Use),
-        ('\u{78c8}', '\u{78d1}',  IdentifierType::Recommended), ('\u{78d2}', '\u{78d2}',
-         IdentifierType::Uncommon_Use), ('\u{78d3}', '\u{78ef}',  IdentifierType::Recommended),
-        ('\u{78f0}', '\u{78f0}',  IdentifierType::Uncommon_Use), ('\u{78f1}', '\u{78f7}',
-         IdentifierType::Recommended), ('\u{78f8}', '\u{78f8}',  IdentifierType::Uncommon_Use),
-        ('\u{78f9}', '\u{78ff}',  IdentifierType::Recommended), ('\u{7900}', '\u{7900}',
-         IdentifierType::Uncommon_Use), ('\u{7901}', '\u{7907}',  IdentifierType::Recommended),
-        ('\u{7908}', '\u{7908}',  IdentifierType::Uncommon_Use), ('\u{7909}', '\u{790c}',
-         IdentifierType::Recommended), ('\u{790d}', '\u{790d}',  IdentifierType::Uncommon_Use),
-        ('\u{790e}', '\u{7914}',  IdentifierType::Recommended), ('\u{7915}', '\u{7915}',
-         IdentifierType::Uncommon_Use), ('\u{7916}', '\u{791e}',  IdentifierType::Recommended),
-        ('\u{791f}', '\u{7920}',  IdentifierType::Uncommon_Use), ('\u{7921}', '\u{7931}',
-         IdentifierType::Recommended), ('\u{7932}', '\u{7932}',  IdentifierType::Uncommon_Use),
-        ('\u{7933}', '\u{7935}',  IdentifierType::Recommended), ('\u{7936}', '\u{7936}',
-         IdentifierType::Uncommon_Use), ('\u{7937}', '\u{7958}',  IdentifierType::Recommended),
-        ('\u{7959}', '\u{7959}',  IdentifierType::Uncommon_Use), ('\u{795a}', '\u{796b}',
-         IdentifierType::Recommended), ('\u{796c}', '\u{796c}',  IdentifierType::Uncommon_Use),
-        ('\u{796d}', '\u{796d}',  IdentifierType::Recommended), ('\u{796e}', '\u{796e}',
-         IdentifierType::Uncommon_Use), ('\u{796f}', '\u{7974}',  IdentifierType::Recommended),
-        ('\u{7975}', '\u{7976}',  IdentifierType::Uncommon_Use), ('\u{7977}', '\u{7985}',
-         IdentifierType::Recommended), ('\u{7986}', '\u{7987}',  IdentifierType::Uncommon_Use),
-        ('\u{7988}', '\u{799d}',  IdentifierType::Recommended), ('\u{799e}', '\u{799e}',
-         IdentifierType::Uncommon_Use), ('\u{799f}', '\u{79a8}',  IdentifierType::Recommended),
-        ('\u{79a9}', '\u{79a9}',  IdentifierType::Uncommon_Use), ('\u{79aa}', '\u{79bb}',
-         IdentifierType::Recommended), ('\u{79bc}', '\u{79bc}',  IdentifierType::Uncommon_Use),
-        ('\u{79bd}', '\u{79c3}',  IdentifierType::Recommended), ('\u{79c4}', '\u{79c4}',
-         IdentifierType::Uncommon_Use), ('\u{79c5}', '\u{79c6}',  IdentifierType::Recommended),
-        ('\u{79c7}', '\u{79c7}',  IdentifierType::Uncommon_Use), ('\u{79c8}', '\u{79cb}',
-         IdentifierType::Recommended), ('\u{79cc}', '\u{79cc}',  IdentifierType::Uncommon_Use),
-        ('\u{79cd}', '\u{79d3}',  IdentifierType::Recommended), ('\u{79d4}', '\u{79d4}',
-         IdentifierType::Uncommon_Use), ('\u{79d5}', '\u{79d6}',  IdentifierType::Recommended),
-        ('\u{79d7}', '\u{79d7}',  IdentifierType::Uncommon_Use), ('\u{79d8}', '\u{7a00}',
-         IdentifierType::Recommended), ('\u{7a01}', '\u{7a01}',  IdentifierType::Uncommon_Use),
-        ('\u{7a02}', '\u{7a06}',  IdentifierType::Recommended), ('\u{7a07}', '\u{7a07}',
-         IdentifierType::Uncommon_Use), ('\u{7a08}', '\u{7a08}',  IdentifierType::Recommended),
-        ('\u{7a09}', '\u{7a09}',  IdentifierType::Uncommon_Use), ('\u{7a0a}', '\u{7a2b}',
-         IdentifierType::Recommended), ('\u{7a2c}', '\u{7a2c}',  IdentifierType::Uncommon_Use),
-        ('\u{7a2d}', '\u{7a37}',  IdentifierType::Recommended), ('\u{7a38}', '\u{7a38}',
-         IdentifierType::Uncommon_Use), ('\u{7a39}', '\u{7a39}',  IdentifierType::Recommended),
-        ('\u{7a3a}', '\u{7a3a}',  IdentifierType::Uncommon_Use), ('\u{7a3b}', '\u{7a63}',
-         IdentifierType::Recommended), ('\u{7a64}', '\u{7a64}',  IdentifierType::Uncommon_Use),
-        ('\u{7a65}', '\u{7a69}',  IdentifierType::Recommended), ('\u{7a6a}', '\u{7a6a}',
-         IdentifierType::Uncommon_Use), ('\u{7a6b}', '\u{7a6e}',  IdentifierType::Recommended),
-        ('\u{7a6f}', '\u{7a6f}',  IdentifierType::Uncommon_Use), ('\u{7a70}', '\u{7a81}',
-         IdentifierType::Recommended), ('\u{7a82}', '\u{7a82}',  IdentifierType::Uncommon_Use),
-        ('\u{7a83}', '\u{7a99}',  IdentifierType::Recommended), ('\u{7a9a}', '\u{7a9b}',
-         IdentifierType::Uncommon_Use), ('\u{7a9c}', '\u{7ab8}',  IdentifierType::Recommended),
-        ('\u{7ab9}', '\u{7ab9}',  IdentifierType::Uncommon_Use), ('\u{7aba}', '\u{7aba}',
-         IdentifierType::Recommended), ('\u{7abb}', '\u{7abd}',  IdentifierType::Uncommon_Use),
-        ('\u{7abe}', '\u{7ac1}',  IdentifierType::Recommended), ('\u{7ac2}', '\u{7ac2}',
-         IdentifierType::Uncommon_Use), ('\u{7ac3}', '\u{7ac5}',  IdentifierType::Recommended),
-        ('\u{7ac6}', '\u{7ac6}',  IdentifierType::Uncommon_Use), ('\u{7ac7}', '\u{7ae8}',
-         IdentifierType::Recommended), ('\u{7ae9}', '\u{7ae9}',  IdentifierType::Uncommon_Use),
-        ('\u{7aea}', '\u{7af4}',  IdentifierType::Recommended), ('\u{7af5}', '\u{7af5}',
-         IdentifierType::Uncommon_Use), ('\u{7af6}', '\u{7afb}',  IdentifierType::Recommended),
-        ('\u{7afc}', '\u{7afc}',  IdentifierType::Uncommon_Use), ('\u{7afd}', '\u{7b06}',
-         IdentifierType::Recommended), ('\u{7b07}', '\u{7b07}',  IdentifierType::Uncommon_Use),
-        ('\u{7b08}', '\u{7b1e}',  IdentifierType::Recommended), ('\u{7b1f}', '\u{7b1f}',
-         IdentifierType::Uncommon_Use), ('\u{7b20}', '\u{7b26}',  IdentifierType::Recommended),
-        ('\u{7b27}', '\u{7b27}',  IdentifierType::Uncommon_Use), ('\u{7b28}', '\u{7b28}',
-         IdentifierType::Recommended), ('\u{7b29}', '\u{7b29}',  IdentifierType::Uncommon_Use),
-        ('\u{7b2a}', '\u{7b41}',  IdentifierType::Recommended), ('\u{7b42}', '\u{7b42}',
-         IdentifierType::Uncommon_Use), ('\u{7b43}', '\u{7b52}',  IdentifierType::Recommended),
-        ('\u{7b53}', '\u{7b53}',  IdentifierType::Uncommon_Use), ('\u{7b54}', '\u{7ba2}',
-         IdentifierType::Recommended), ('\u{7ba3}', '\u{7ba3}',  IdentifierType::Uncommon_Use),
-        ('\u{7ba4}', '\u{7ba4}',  IdentifierType::Recommended), ('\u{7ba5}', '\u{7ba5}',
-         IdentifierType::Uncommon_Use), ('\u{7ba6}', '\u{7baf}',  IdentifierType::Recommended),
-        ('\u{7bb0}', '\u{7bb0}',  IdentifierType::Uncommon_Use), ('\u{7bb1}', '\u{7bb1}',
-         IdentifierType::Recommended), ('\u{7bb2}', '\u{7bb2}',  IdentifierType::Uncommon_Use),
-        ('\u{7bb3}', '\u{7bf9}',  IdentifierType::Recommended), ('\u{7bfa}', '\u{7bfa}',
-         IdentifierType::Uncommon_Use), ('\u{7bfb}', '\u{7c1a}',  IdentifierType::Recommended),
-        ('\u{7c1b}', '\u{7c1b}',  IdentifierType::Uncommon_Use), ('\u{7c1c}', '\u{7c2d}',
-         IdentifierType::Recommended), ('\u{7c2e}', '\u{7c2f}',  IdentifierType::Uncommon_Use),
-        ('\u{7c30}', '\u{7c51}',  IdentifierType::Recommended), ('\u{7c52}', '\u{7c52}',
-         IdentifierType::Uncommon_Use), ('\u{7c53}', '\u{7c54}',  IdentifierType::Recommended),
-        ('\u{7c55}', '\u{7c55}',  IdentifierType::Uncommon_Use), ('\u{7c56}', '\u{7c5c}',
-         IdentifierType::Recommended), ('\u{7c5d}', '\u{7c5d}',  IdentifierType::Uncommon_Use),
-        ('\u{7c5e}', '\u{7c75}',  IdentifierType::Recommended), ('\u{7c76}', '\u{7c76}',
-         IdentifierType::Uncommon_Use), ('\u{7c77}', '\u{7c86}',  IdentifierType::Recommended),
-        ('\u{7c87}', '\u{7c87}',  IdentifierType::Uncommon_Use), ('\u{7c88}', '\u{7c92}',
-         IdentifierType::Recommended), ('\u{7c93}', '\u{7c93}',  IdentifierType::Uncommon_Use),
-        ('\u{7c94}', '\u{7c99}',  IdentifierType::Recommended), ('\u{7c9a}', '\u{7c9a}',
-         IdentifierType::Uncommon_Use), ('\u{7c9b}', '\u{7cab}',  IdentifierType::Recommended),
-        ('\u{7cac}', '\u{7cac}',  IdentifierType::Uncommon_Use), ('\u{7cad}', '\u{7cd2}',
-         IdentifierType::Recommended), ('\u{7cd3}', '\u{7cd3}',  IdentifierType::Uncommon_Use),
-        ('\u{7cd4}', '\u{7cd9}',  IdentifierType::Recommended), ('\u{7cda}', '\u{7cdb}',
-         IdentifierType::Uncommon_Use), ('\u{7cdc}', '\u{7ce0}',  IdentifierType::Recommended),
-        ('\u{7ce1}', '\u{7ce1}',  IdentifierType::Uncommon_Use), ('\u{7ce2}', '\u{7ce2}',
-         IdentifierType::Recommended), ('\u{7ce3}', '\u{7ce3}',  IdentifierType::Uncommon_Use),
-        ('\u{7ce4}', '\u{7ce4}',  IdentifierType::Recommended), ('\u{7ce5}', '\u{7ce6}',
-         IdentifierType::Uncommon_Use), ('\u{7ce7}', '\u{7cfb}',  IdentifierType::Recommended),
-        ('\u{7cfc}', '\u{7cfc}',  IdentifierType::Uncommon_Use), ('\u{7cfd}', '\u{7cfe}',
-         IdentifierType::Recommended), ('\u{7cff}', '\u{7cff}',  IdentifierType::Uncommon_Use),
-        ('\u{7d00}', '\u{7d22}',  IdentifierType::Recommended), ('\u{7d23}', '\u{7d23}',
-         IdentifierType::Uncommon_Use), ('\u{7d24}', '\u{7d29}',  IdentifierType::Recommended),
-        ('\u{7d2a}', '\u{7d2a}',  IdentifierType::Uncommon_Use), ('\u{7d2b}', '\u{7d2c}',
-         IdentifierType::Recommended), ('\u{7d2d}', '\u{7d2d}',  IdentifierType::Uncommon_Use),
-        ('\u{7d2e}', '\u{7d47}',  IdentifierType::Recommended), ('\u{7d48}', '\u{7d48}',
-         IdentifierType::Uncommon_Use), ('\u{7d49}', '\u{7d4c}',  IdentifierType::Recommended),
-        ('\u{7d4d}', '\u{7d4d}',  IdentifierType::Uncommon_Use), ('\u{7d4e}', '\u{7d59}',
-         IdentifierType::Recommended), ('\u{7d5a}', '\u{7d5a}',  IdentifierType::Uncommon_Use),
-        ('\u{7d5b}', '\u{7d63}',  IdentifierType::Recommended), ('\u{7d64}', '\u{7d64}',
-         IdentifierType::Uncommon_Use), ('\u{7d65}', '\u{7d77}',  IdentifierType::Recommended),
-        ('\u{7d78}', '\u{7d78}',  IdentifierType::Uncommon_Use), ('\u{7d79}', '\u{7d81}',
-         IdentifierType::Recommended), ('\u{7d82}', '\u{7d82}',  IdentifierType::Uncommon_Use),
-        ('\u{7d83}', '\u{7d94}',  IdentifierType::Recommended), ('\u{7d95}', '\u{7d95}',
-         IdentifierType::Uncommon_Use), ('\u{7d96}', '\u{7d97}',  IdentifierType::Recommended),
-        ('\u{7d98}', '\u{7d98}',  IdentifierType::Uncommon_Use), ('\u{7d99}', '\u{7da3}',
-         IdentifierType::Recommended), ('\u{7da4}', '\u{7da4}',  IdentifierType::Uncommon_Use),
-        ('\u{7da5}', '\u{7da7}',  IdentifierType::Recommended), ('\u{7da8}', '\u{7da8}',
-         IdentifierType::Uncommon_Use), ('\u{7da9}', '\u{7dcc}',  IdentifierType::Recommended),
-        ('\u{7dcd}', '\u{7dcd}',  IdentifierType::Uncommon_Use), ('\u{7dce}', '\u{7dd2}',
-         IdentifierType::Recommended), ('\u{7dd3}', '\u{7dd3}',  IdentifierType::Uncommon_Use),
-        ('\u{7dd4}', '\u{7de4}',  IdentifierType::Recommended), ('\u{7de5}', '\u{7de5}',
-         IdentifierType::Uncommon_Use), ('\u{7de6}', '\u{7dea}',  IdentifierType::Recommended),
-        ('\u{7deb}', '\u{7deb}',  IdentifierType::Uncommon_Use), ('\u{7dec}', '\u{7dfc}',
-         IdentifierType::Recommended), ('\u{7dfd}', '\u{7dff}',  IdentifierType::Uncommon_Use),
-        ('\u{7e00}', '\u{7e17}',  IdentifierType::Recommended), ('\u{7e18}', '\u{7e18}',
-         IdentifierType::Uncommon_Use), ('\u{7e19}', '\u{7e5a}',  IdentifierType::Recommended),
-        ('\u{7e5b}', '\u{7e5b}',  IdentifierType::Uncommon_Use), ('\u{7e5c}', '\u{7e63}',
-         IdentifierType::Recommended), ('\u{7e64}', '\u{7e64}',  IdentifierType::Uncommon_Use),
-        ('\u{7e65}', '\u{7e9c}',  IdentifierType::Recommended), ('\u{7e9d}', '\u{7e9d}',
-         IdentifierType::Uncommon_Use), ('\u{7e9e}', '\u{7f3a}',  IdentifierType::Recommended),
-        ('\u{7f3b}', '\u{7f3c}',  IdentifierType::Uncommon_Use), ('\u{7f3d}', '\u{7f40}',
-         IdentifierType::Recommended), ('\u{7f41}', '\u{7f41}',  IdentifierType::Uncommon_Use),
-        ('\u{7f42}', '\u{7f45}',  IdentifierType::Recommended), ('\u{7f46}', '\u{7f46}',
-         IdentifierType::Uncommon_Use), ('\u{7f47}', '\u{7f58}',  IdentifierType::Recommended),
-        ('\u{7f59}', '\u{7f59}',  IdentifierType::Uncommon_Use), ('\u{7f5a}', '\u{7f83}',
-         IdentifierType::Recommended), ('\u{7f84}', '\u{7f84}',  IdentifierType::Uncommon_Use),
-        ('\u{7f85}', '\u{7f8f}',  IdentifierType::Recommended), ('\u{7f90}', '\u{7f90}',
-         IdentifierType::Uncommon_Use), ('\u{7f91}', '\u{7f96}',  IdentifierType::Recommended),
-        ('\u{7f97}', '\u{7f97}',  IdentifierType::Uncommon_Use), ('\u{7f98}', '\u{7f98}',
-         IdentifierType::Recommended), ('\u{7f99}', '\u{7f99}',  IdentifierType::Uncommon_Use),
-        ('\u{7f9a}', '\u{7fb3}',  IdentifierType::Recommended), ('\u{7fb4}', '\u{7fb4}',
-         IdentifierType::Uncommon_Use), ('\u{7fb5}', '\u{7fd5}',  IdentifierType::Recommended),
-        ('\u{7fd6}', '\u{7fd6}',  IdentifierType::Uncommon_Use), ('\u{7fd7}', '\u{7fdc}',
-         IdentifierType::Recommended), ('\u{7fdd}', '\u{7fdd}',  IdentifierType::Uncommon_Use),
-        ('\u{7fde}', '\u{7fe3}',  IdentifierType::Recommended), ('\u{7fe4}', '\u{7fe4}',
-         IdentifierType::Uncommon_Use), ('\u{7fe5}', '\u{8009}',  IdentifierType::Recommended),
-        ('\u{800a}', '\u{800a}',  IdentifierType::Uncommon_Use), ('\u{800b}', '\u{802e}',
-         IdentifierType::Recommended), ('\u{802f}', '\u{802f}',  IdentifierType::Uncommon_Use),
-        ('\u{8030}', '\u{803b}',  IdentifierType::Recommended), ('\u{803c}', '\u{803c}',
-         IdentifierType::Uncommon_Use), ('\u{803d}', '\u{803f}',  IdentifierType::Recommended),
-        ('\u{8040}', '\u{8040}',  IdentifierType::Uncommon_Use), ('\u{8041}', '\u{8065}',
-         IdentifierType::Recommended), ('\u{8066}', '\u{8066}',  IdentifierType::Uncommon_Use),
-        ('\u{8067}', '\u{8087}',  IdentifierType::Recommended), ('\u{8088}', '\u{8088}',
-         IdentifierType::Uncommon_Use), ('\u{8089}', '\u{808d}',  IdentifierType::Recommended),
-        ('\u{808e}', '\u{808e}',  IdentifierType::Uncommon_Use), ('\u{808f}', '\u{8093}',
-         IdentifierType::Recommended), ('\u{8094}', '\u{8094}',  IdentifierType::Uncommon_Use),
-        ('\u{8095}', '\u{80a5}',  IdentifierType::Recommended), ('\u{80a6}', '\u{80a8}',
-         IdentifierType::Uncommon_Use), ('\u{80a9}', '\u{80b2}',  IdentifierType::Recommended),
-        ('\u{80b3}', '\u{80b3}',  IdentifierType::Uncommon_Use), ('\u{80b4}', '\u{80b8}',
-         IdentifierType::Recommended), ('\u{80b9}', '\u{80b9}',  IdentifierType::Uncommon_Use),
-        ('\u{80ba}', '\u{80de}',  IdentifierType::Recommended), ('\u{80df}', '\u{80df}',
-         IdentifierType::Uncommon_Use), ('\u{80e0}', '\u{8102}',  IdentifierType::Recommended),
-        ('\u{8103}', '\u{8104}',  IdentifierType::Uncommon_Use), ('\u{8105}', '\u{8133}',
-         IdentifierType::Recommended), ('\u{8134}', '\u{8135}',  IdentifierType::Uncommon_Use),
-        ('\u{8136}', '\u{8183}',  IdentifierType::Recommended), ('\u{8184}', '\u{8184}',
-         IdentifierType::Uncommon_Use), ('\u{8185}', '\u{818f}',  IdentifierType::Recommended),
-        ('\u{8190}', '\u{8190}',  IdentifierType::Uncommon_Use), ('\u{8191}', '\u{8195}',
-         IdentifierType::Recommended), ('\u{8196}', '\u{8196}',  IdentifierType::Uncommon_Use),
-        ('\u{8197}', '\u{81ca}',  IdentifierType::Recommended), ('\u{81cb}', '\u{81cb}',
-         IdentifierType::Uncommon_Use), ('\u{81cc}', '\u{81e3}',  IdentifierType::Recommended),
-        ('\u{81e4}', '\u{81e4}',  IdentifierType::Uncommon_Use), ('\u{81e5}', '\u{81ee}',
-         IdentifierType::Recommended), ('\u{81ef}', '\u{81f0}',  IdentifierType::Uncommon_Use),
-        ('\u{81f1}', '\u{8212}',  IdentifierType::Recommended), ('\u{8213}', '\u{8213}',
-         IdentifierType::Uncommon_Use), ('\u{8214}', '\u{8223}',  IdentifierType::Recommended),
-        ('\u{8224}', '\u{8224}',  IdentifierType::Uncommon_Use), ('\u{8225}', '\u{8240}',
-         IdentifierType::Recommended), ('\u{8241}', '\u{8241}',  IdentifierType::Uncommon_Use),
-        ('\u{8242}', '\u{8264}',  IdentifierType::Recommended), ('\u{8265}', '\u{8265}',
-         IdentifierType::Uncommon_Use), ('\u{8266}', '\u{828b}',  IdentifierType::Recommended),
-        ('\u{828c}', '\u{828c}',  IdentifierType::Uncommon_Use), ('\u{828d}', '\u{82b1}',
-         IdentifierType::Recommended), ('\u{82b2}', '\u{82b2}',  IdentifierType::Uncommon_Use),
-        ('\u{82b3}', '\u{82e1}',  IdentifierType::Recommended), ('\u{82e2}', '\u{82e2}',
-         IdentifierType::Uncommon_Use), ('\u{82e3}', '\u{82fb}',  IdentifierType::Recommended),
-        ('\u{82fc}', '\u{82fc}',  IdentifierType::Uncommon_Use), ('\u{82fd}', '\u{8309}',
-         IdentifierType::Recommended), ('\u{830a}', '\u{830a}',  IdentifierType::Uncommon_Use),
-        ('\u{830b}', '\u{830f}',  IdentifierType::Recommended), ('\u{8310}', '\u{8310}',
-         IdentifierType::Uncommon_Use), ('\u{8311}', '\u{832f}',  IdentifierType::Recommended),
-        ('\u{8330}', '\u{8330}',  IdentifierType::Uncommon_Use), ('\u{8331}', '\u{8354}',
-         IdentifierType::Recommended), ('\u{8355}', '\u{8355}',  IdentifierType::Uncommon_Use),
-        ('\u{8356}', '\u{83bd}',  IdentifierType::Recommended), ('\u{83be}', '\u{83be}',
-         IdentifierType::Uncommon_Use), ('\u{83bf}', '\u{83e5}',  IdentifierType::Recommended),
-        ('\u{83e6}', '\u{83e6}',  IdentifierType::Uncommon_Use), ('\u{83e7}', '\u{83ec}',
-         IdentifierType::Recommended), ('\u{83ed}', '\u{83ed}',  IdentifierType::Uncommon_Use),
-        ('\u{83ee}', '\u{8413}',  IdentifierType::Recommended), ('\u{8414}', '\u{8414}',
-         IdentifierType::Uncommon_Use), ('\u{8415}', '\u{8415}',  IdentifierType::Recommended),
-        ('\u{8416}', '\u{8417}',  IdentifierType::Uncommon_Use), ('\u{8418}', '\u{841e}',
-         IdentifierType::Recommended), ('\u{841f}', '\u{841f}',  IdentifierType::Uncommon_Use),
-        ('\u{8420}', '\u{8457}',  IdentifierType::Recommended), ('\u{8458}', '\u{8458}',
-         IdentifierType::Uncommon_Use), ('\u{8459}', '\u{8482}',  IdentifierType::Recommended),
-        ('\u{8483}', '\u{8483}',  IdentifierType::Uncommon_Use), ('\u{8484}', '\u{8494}',
-         IdentifierType::Recommended), ('\u{8495}', '\u{8495}',  IdentifierType::Uncommon_Use),
-        ('\u{8496}', '\u{84b6}',  IdentifierType::Recommended), ('\u{84b7}', '\u{84b7}',
-         IdentifierType::Uncommon_Use), ('\u{84b8}', '\u{84c2}',  IdentifierType::Recommended),
-        ('\u{84c3}', '\u{84c3}',  IdentifierType::Uncommon_Use), ('\u{84c4}', '\u{84ec}',
-         IdentifierType::Recommended), ('\u{84ed}', '\u{84ed}',  IdentifierType::Uncommon_Use),
-        ('\u{84ee}', '\u{8504}',  IdentifierType::Recommended), ('\u{8505}', '\u{8505}',
-         IdentifierType::Uncommon_Use), ('\u{8506}', '\u{850f}',  IdentifierType::Recommended),
-        ('\u{8510}', '\u{8510}',  IdentifierType::Uncommon_Use), ('\u{8511}', '\u{8531}',
-         IdentifierType::Recommended), ('\u{8532}', '\u{8533}',  IdentifierType::Uncommon_Use),
-        ('\u{8534}', '\u{854b}',  IdentifierType::Recommended), ('\u{854c}', '\u{854c}',
-         IdentifierType::Uncommon_Use), ('\u{854d}', '\u{854f}',  IdentifierType::Recommended),
-        ('\u{8550}', '\u{8550}',  IdentifierType::Uncommon_Use), ('\u{8551}', '\u{857e}',
-         IdentifierType::Recommended), ('\u{857f}', '\u{857f}',  IdentifierType::Uncommon_Use),
-        ('\u{8580}', '\u{8592}',  IdentifierType::Recommended), ('\u{8593}', '\u{8593}',
-         IdentifierType::Uncommon_Use), ('\u{8594}', '\u{85b1}',  IdentifierType::Recommended),
-        ('\u{85b2}', '\u{85b2}',  IdentifierType::Uncommon_Use), ('\u{85b3}', '\u{85ba}',
-         IdentifierType::Recommended), ('\u{85bb}', '\u{85bb}',  IdentifierType::Uncommon_Use),
-        ('\u{85bc}', '\u{85cb}',  IdentifierType::Recommended), ('\u{85cc}', '\u{85cc}',
-         IdentifierType::Uncommon_Use), ('\u{85cd}', '\u{85ed}',  IdentifierType::Recommended),
-        ('\u{85ee}', '\u{85ee}',  IdentifierType::Uncommon_Use), ('\u{85ef}', '\u{85f2}',
-         IdentifierType::Recommended), ('\u{85f3}', '\u{85f3}',  IdentifierType::Uncommon_Use),
-        ('\u{85f4}', '\u{85fb}',  IdentifierType::Recommended), ('\u{85fc}', '\u{85fc}',
-         IdentifierType::Uncommon_Use), ('\u{85fd}', '\u{8602}',  IdentifierType::Recommended),
-        ('\u{8603}', '\u{8603}',  IdentifierType::Uncommon_Use), ('\u{8604}', '\u{860c}',
-         IdentifierType::Recommended), ('\u{860d}', '\u{860e}',  IdentifierType::Uncommon_Use),
-        ('\u{860f}', '\u{860f}',  IdentifierType::Recommended), ('\u{8610}', '\u{8610}',
-         IdentifierType::Uncommon_Use), ('\u{8611}', '\u{8614}',  IdentifierType::Recommended),
-        ('\u{8615}', '\u{8615}',  IdentifierType::Uncommon_Use), ('\u{8616}', '\u{861c}',
-         IdentifierType::Recommended), ('\u{861d}', '\u{861d}',  IdentifierType::Uncommon_Use),
-        ('\u{861e}', '\u{8636}',  IdentifierType::Recommended), ('\u{8637}', '\u{8637}',
-         IdentifierType::Uncommon_Use), ('\u{8638}', '\u{8656}',  IdentifierType::Recommended),
-        ('\u{8657}', '\u{8657}',  IdentifierType::Uncommon_Use), ('\u{8658}', '\u{8674}',
-         IdentifierType::Recommended), ('\u{8675}', '\u{8675}',  IdentifierType::Uncommon_Use),
-        ('\u{8676}', '\u{8688}',  IdentifierType::Recommended), ('\u{8689}', '\u{8689}',
-         IdentifierType::Uncommon_Use), ('\u{868a}', '\u{8691}',  IdentifierType::Recommended),
-        ('\u{8692}', '\u{8692}',  IdentifierType::Uncommon_Use), ('\u{8693}', '\u{869f}',
-         IdentifierType::Recommended), ('\u{86a0}', '\u{86a0}',  IdentifierType::Uncommon_Use),
-        ('\u{86a1}', '\u{86a5}',  IdentifierType::Recommended), ('\u{86a6}', '\u{86a6}',
-         IdentifierType::Uncommon_Use), ('\u{86a7}', '\u{86d4}',  IdentifierType::Recommended),
-        ('\u{86d5}', '\u{86d5}',  IdentifierType::Uncommon_Use), ('\u{86d6}', '\u{86df}',
-         IdentifierType::Recommended), ('\u{86e0}', '\u{86e0}',  IdentifierType::Uncommon_Use),
-        ('\u{86e1}', '\u{86e6}',  IdentifierType::Recommended), ('\u{86e7}', '\u{86e7}',
-         IdentifierType::Uncommon_Use), ('\u{86e8}', '\u{86fc}',  IdentifierType::Recommended),
-        ('\u{86fd}', '\u{86fd}',  IdentifierType::Uncommon_Use), ('\u{86fe}', '\u{871c}',
-         IdentifierType::Recommended), ('\u{871d}', '\u{871d}',  IdentifierType::Uncommon_Use),
-        ('\u{871e}', '\u{872e}',  IdentifierType::Recommended), ('\u{872f}', '\u{872f}',
-         IdentifierType::Uncommon_Use), ('\u{8730}', '\u{873c}',  IdentifierType::Recommended),
-        ('\u{873d}', '\u{873d}',  IdentifierType::Uncommon_Use), ('\u{873e}', '\u{8744}',
-         IdentifierType::Recommended), ('\u{8745}', '\u{8745}',  IdentifierType::Uncommon_Use),
-        ('\u{8746}', '\u{8770}',  IdentifierType::Recommended), ('\u{8771}', '\u{8771}',
-         IdentifierType::Uncommon_Use), ('\u{8772}', '\u{878d}',  IdentifierType::Recommended),
-        ('\u{878e}', '\u{878e}',  IdentifierType::Uncommon_Use), ('\u{878f}', '\u{8798}',
-         IdentifierType::Recommended), ('\u{8799}', '\u{8799}',  IdentifierType::Uncommon_Use),
-        ('\u{879a}', '\u{87d9}',  IdentifierType::Recommended), ('\u{87da}', '\u{87da}',
-         IdentifierType::Uncommon_Use), ('\u{87db}', '\u{87ef}',  IdentifierType::Recommended),
-        ('\u{87f0}', '\u{87f0}',  IdentifierType::Uncommon_Use), ('\u{87f1}', '\u{8806}',
-         IdentifierType::Recommended), ('\u{8807}', '\u{8807}',  IdentifierType::Uncommon_Use),
-        ('\u{8808}', '\u{8811}',  IdentifierType::Recommended), ('\u{8812}', '\u{8812}',
-         IdentifierType::Uncommon_Use), ('\u{8813}', '\u{882c}',  IdentifierType::Recommended),
-        ('\u{882d}', '\u{882d}',  IdentifierType::Uncommon_Use), ('\u{882e}', '\u{8839}',
-         IdentifierType::Recommended), ('\u{883a}', '\u{883a}',  IdentifierType::Uncommon_Use),
-        ('\u{883b}', '\u{8846}',  IdentifierType::Recommended), ('\u{8847}', '\u{8847}',
-         IdentifierType::Uncommon_Use), ('\u{8848}', '\u{8857}',  IdentifierType::Recommended),
-        ('\u{8858}', '\u{8858}',  IdentifierType::Uncommon_Use), ('\u{8859}', '\u{885b}',
-         IdentifierType::Recommended), ('\u{885c}', '\u{885c}',  IdentifierType::Uncommon_Use),
-        ('\u{885d}', '\u{885e}',  IdentifierType::Recommended), ('\u{885f}', '\u{885f}',
-         IdentifierType::Uncommon_Use), ('\u{8860}', '\u{8879}',  IdentifierType::Recommended),
-        ('\u{887a}', '\u{887a}',  IdentifierType::Uncommon_Use), ('\u{887b}', '\u{88e5}',
-         IdentifierType::Recommended), ('\u{88e6}', '\u{88e6}',  IdentifierType::Uncommon_Use),
-        ('\u{88e7}', '\u{88e8}',  IdentifierType::Recommended), ('\u{88e9}', '\u{88e9}',
-         IdentifierType::Uncommon_Use), ('\u{88ea}', '\u{88ec}',  IdentifierType::Recommended),
-        ('\u{88ed}', '\u{88ed}',  IdentifierType::Uncommon_Use), ('\u{88ee}', '\u{8902}',
-         IdentifierType::Recommended), ('\u{8903}', '\u{8903}',  IdentifierType::Uncommon_Use),
-        ('\u{8904}', '\u{890e}',  IdentifierType::Recommended), ('\u{890f}', '\u{890f}',
-         IdentifierType::Uncommon_Use), ('\u{8910}', '\u{8923}',  IdentifierType::Recommended),
-        ('\u{8924}', '\u{8924}',  IdentifierType::Uncommon_Use), ('\u{8925}', '\u{8964}',
-         IdentifierType::Recommended), ('\u{8965}', '\u{8965}',  IdentifierType::Uncommon_Use),
-        ('\u{8966}', '\u{8974}',  IdentifierType::Recommended), ('\u{8975}', '\u{8975}',
-         IdentifierType::Uncommon_Use), ('\u{8976}', '\u{897c}',  IdentifierType::Recommended),
-        ('\u{897d}', '\u{897d}',  IdentifierType::Uncommon_Use), ('\u{897e}', '\u{898c}',
-         IdentifierType::Recommended), ('\u{898d}', '\u{898d}',  IdentifierType::Uncommon_Use),
-        ('\u{898e}', '\u{898f}',  IdentifierType::Recommended), ('\u{8990}', '\u{8990}',
-         IdentifierType::Uncommon_Use), ('\u{8991}', '\u{8993}',  IdentifierType::Recommended),
-        ('\u{8994}', '\u{8994}',  IdentifierType::Uncommon_Use), ('\u{8995}', '\u{8998}',
-         IdentifierType::Recommended), ('\u{8999}', '\u{8999}',  IdentifierType::Uncommon_Use),
-        ('\u{899a}', '\u{89af}',  IdentifierType::Recommended), ('\u{89b0}', '\u{89b0}',
-         IdentifierType::Uncommon_Use), ('\u{89b1}', '\u{89b3}',  IdentifierType::Recommended),
-        ('\u{89b4}', '\u{89b4}',  IdentifierType::Uncommon_Use), ('\u{89b5}', '\u{89ba}',
-         IdentifierType::Recommended), ('\u{89bb}', '\u{89bc}',  IdentifierType::Uncommon_Use),
-        ('\u{89bd}', '\u{89ed}',  IdentifierType::Recommended), ('\u{89ee}', '\u{89ee}',
-         IdentifierType::Uncommon_Use), ('\u{89ef}', '\u{89f4}',  IdentifierType::Recommended),
-        ('\u{89f5}', '\u{89f5}',  IdentifierType::Uncommon_Use), ('\u{89f6}', '\u{89f8}',
-         IdentifierType::Recommended), ('\u{89f9}', '\u{89f9}',  IdentifierType::Uncommon_Use),
-        ('\u{89fa}', '\u{89fc}',  IdentifierType::Recommended), ('\u{89fd}', '\u{89fd}',
-         IdentifierType::Uncommon_Use), ('\u{89fe}', '\u{8a04}',  IdentifierType::Recommended),
-        ('\u{8a05}', '\u{8a06}',  IdentifierType::Uncommon_Use), ('\u{8a07}', '\u{8a13}',
-         IdentifierType::Recommended), ('\u{8a14}', '\u{8a14}',  IdentifierType::Uncommon_Use),
-        ('\u{8a15}', '\u{8a18}',  IdentifierType::Recommended), ('\u{8a19}', '\u{8a19}',
-         IdentifierType::Uncommon_Use), ('\u{8a1a}', '\u{8a1f}',  IdentifierType::Recommended),
-        ('\u{8a20}', '\u{8a21}',  IdentifierType::Uncommon_Use), ('\u{8a22}', '\u{8a2a}',
-         IdentifierType::Recommended), ('\u{8a2b}', '\u{8a2b}',  IdentifierType::Uncommon_Use),
-        ('\u{8a2c}', '\u{8a3c}',  IdentifierType::Recommended), ('\u{8a3d}', '\u{8a3d}',
-         IdentifierType::Uncommon_Use), ('\u{8a3e}', '\u{8a4a}',  IdentifierType::Recommended),
-        ('\u{8a4b}', '\u{8a4b}',  IdentifierType::Uncommon_Use), ('\u{8a4c}', '\u{8a63}',
-         IdentifierType::Recommended), ('\u{8a64}', '\u{8a64}',  IdentifierType::Uncommon_Use),
-        ('\u{8a65}', '\u{8a77}',  IdentifierType::Recommended), ('\u{8a78}', '\u{8a78}',
-         IdentifierType::Uncommon_Use), ('\u{8a79}', '\u{8a7c}',  IdentifierType::Recommended),
-        ('\u{8a7d}', '\u{8a7d}',  IdentifierType::Uncommon_Use), ('\u{8a7e}', '\u{8a87}',
-         IdentifierType::Recommended), ('\u{8a88}', '\u{8a88}',  IdentifierType::Uncommon_Use),
-        ('\u{8a89}', '\u{8a9e}',  IdentifierType::Recommended), ('\u{8a9f}', '\u{8a9f}',
-         IdentifierType::Uncommon_Use), ('\u{8aa0}', '\u{8aae}',  IdentifierType::Recommended),
-        ('\u{8aaf}', '\u{8aaf}',  IdentifierType::Uncommon_Use), ('\u{8ab0}', '\u{8ab6}',
-         IdentifierType::Recommended), ('\u{8ab7}', '\u{8ab7}',  IdentifierType::Uncommon_Use),
-        ('\u{8ab8}', '\u{8acf}',  IdentifierType::Recommended), ('\u{8ad0}', '\u{8ad0}',
-         IdentifierType::Uncommon_Use), ('\u{8ad1}', '\u{8aeb}',  IdentifierType::Recommended),
-        ('\u{8aec}', '\u{8aec}',  IdentifierType::Uncommon_Use), ('\u{8aed}', '\u{8b28}',
-         IdentifierType::Recommended), ('\u{8b29}', '\u{8b29}',  IdentifierType::Uncommon_Use),
-        ('\u{8b2a}', '\u{8b31}',  IdentifierType::Recommended), ('\u{8b32}', '\u{8b32}',
-         IdentifierType::Uncommon_Use), ('\u{8b33}', '\u{8b37}',  IdentifierType::Recommended),
-        ('\u{8b38}', '\u{8b38}',  IdentifierType::Uncommon_Use), ('\u{8b39}', '\u{8b3e}',
-         IdentifierType::Recommended), ('\u{8b3f}', '\u{8b3f}',  IdentifierType::Uncommon_Use),
-        ('\u{8b40}', '\u{8b60}',  IdentifierType::Recommended), ('\u{8b61}', '\u{8b62}',
-         IdentifierType::Uncommon_Use), ('\u{8b63}', '\u{8b68}',  IdentifierType::Recommended),
-        ('\u{8b69}', '\u{8b69}',  IdentifierType::Uncommon_Use), ('\u{8b6a}', '\u{8b74}',
-         IdentifierType::Recommended), ('\u{8b75}', '\u{8b75}',  IdentifierType::Uncommon_Use),
-        ('\u{8b76}', '\u{8b7b}',  IdentifierType::Recommended), ('\u{8b7c}', '\u{8b7c}',
-         IdentifierType::Uncommon_Use), ('\u{8b7d}', '\u{8b80}',  IdentifierType::Recommended),
-        ('\u{8b81}', '\u{8b81}',  IdentifierType::Uncommon_Use), ('\u{8b82}', '\u{8b86}',
-         IdentifierType::Recommended), ('\u{8b87}', '\u{8b87}',  IdentifierType::Uncommon_Use),
-        ('\u{8b88}', '\u{8b8c}',  IdentifierType::Recommended), ('\u{8b8d}', '\u{8b8d}',
-         IdentifierType::Uncommon_Use), ('\u{8b8e}', '\u{8b8e}',  IdentifierType::Recommended),
-        ('\u{8b8f}', '\u{8b8f}',  IdentifierType::Uncommon_Use), ('\u{8b90}', '\u{8b9a}',
-         IdentifierType::Recommended), ('\u{8b9b}', '\u{8b9b}',  IdentifierType::Uncommon_Use),
-        ('\u{8b9c}', '\u{8c37}',  IdentifierType::Recommended), ('\u{8c38}', '\u{8c38}',
-         IdentifierType::Uncommon_Use), ('\u{8c39}', '\u{8c3f}',  IdentifierType::Recommended),
-        ('\u{8c40}', '\u{8c40}',  IdentifierType::Uncommon_Use), ('\u{8c41}', '\u{8c43}',
-         IdentifierType::Recommended), ('\u{8c44}', '\u{8c44}',  IdentifierType::Uncommon_Use),
-        ('\u{8c45}', '\u{8c50}',  IdentifierType::Recommended), ('\u{8c51}', '\u{8c53}',
-         IdentifierType::Uncommon_Use), ('\u{8c54}', '\u{8c57}',  IdentifierType::Recommended),
-        ('\u{8c58}', '\u{8c58}',  IdentifierType::Uncommon_Use), ('\u{8c59}', '\u{8c73}',
-         IdentifierType::Recommended), ('\u{8c74}', '\u{8c74}',  IdentifierType::Uncommon_Use),
-        ('\u{8c75}', '\u{8c7e}',  IdentifierType::Recommended), ('\u{8c7f}', '\u{8c7f}',
-         IdentifierType::Uncommon_Use), ('\u{8c80}', '\u{8c82}',  IdentifierType::Recommended),
-        ('\u{8c83}', '\u{8c83}',  IdentifierType::Uncommon_Use), ('\u{8c84}', '\u{8c86}',
-         IdentifierType::Recommended), ('\u{8c87}', '\u{8c87}',  IdentifierType::Uncommon_Use),
-        ('\u{8c88}', '\u{8c8a}',  IdentifierType::Recommended), ('\u{8c8b}', '\u{8c8b}',
-         IdentifierType::Uncommon_Use), ('\u{8c8c}', '\u{8c9a}',  IdentifierType::Recommended),
-        ('\u{8c9b}', '\u{8c9b}',  IdentifierType::Uncommon_Use), ('\u{8c9c}', '\u{8ca5}',
-         IdentifierType::Recommended), ('\u{8ca6}', '\u{8ca6}',  IdentifierType::Uncommon_Use),
-        ('\u{8ca7}', '\u{8cca}',  IdentifierType::Recommended), ('\u{8ccb}', '\u{8ccb}',
-         IdentifierType::Uncommon_Use), ('\u{8ccc}', '\u{8cd5}',  IdentifierType::Recommended),
-        ('\u{8cd6}', '\u{8cd6}',  IdentifierType::Uncommon_Use), ('\u{8cd7}', '\u{8cd7}',
-         IdentifierType::Recommended), ('\u{8cd8}', '\u{8cd8}',  IdentifierType::Uncommon_Use),
-        ('\u{8cd9}', '\u{8ce8}',  IdentifierType::Recommended), ('\u{8ce9}', '\u{8ce9}',
-         IdentifierType::Uncommon_Use), ('\u{8cea}', '\u{8cf6}',  IdentifierType::Recommended),
-        ('\u{8cf7}', '\u{8cf7}',  IdentifierType::Uncommon_Use), ('\u{8cf8}', '\u{8d00}',
-         IdentifierType::Recommended), ('\u{8d01}', '\u{8d01}',  IdentifierType::Uncommon_Use),
-        ('\u{8d02}', '\u{8d10}',  IdentifierType::Recommended), ('\u{8d11}', '\u{8d12}',
-         IdentifierType::Uncommon_Use), ('\u{8d13}', '\u{8d7b}',  IdentifierType::Recommended),
-        ('\u{8d7c}', '\u{8d7c}',  IdentifierType::Uncommon_Use), ('\u{8d7d}', '\u{8da5}',
-         IdentifierType::Recommended), ('\u{8da6}', '\u{8da6}',  IdentifierType::Uncommon_Use),
-        ('\u{8da7}', '\u{8dbf}',  IdentifierType::Recommended), ('\u{8dc0}', '\u{8dc0}',
-         IdentifierType::Uncommon_Use), ('\u{8dc1}', '\u{8de4}',  IdentifierType::Recommended),
-        ('\u{8de5}', '\u{8de5}',  IdentifierType::Uncommon_Use), ('\u{8de6}', '\u{8e00}',
-         IdentifierType::Recommended), ('\u{8e01}', '\u{8e01}',  IdentifierType::Uncommon_Use),
-        ('\u{8e02}', '\u{8e0a}',  IdentifierType::Recommended), ('\u{8e0b}', '\u{8e0b}',
-         IdentifierType::Uncommon_Use), ('\u{8e0c}', '\u{8e31}',  IdentifierType::Recommended),
-        ('\u{8e32}', '\u{8e32}',  IdentifierType::Uncommon_Use), ('\u{8e33}', '\u{8e45}',
-         IdentifierType::Recommended), ('\u{8e46}', '\u{8e46}',  IdentifierType::Uncommon_Use),
-        ('\u{8e47}', '\u{8e4e}',  IdentifierType::Recommended), ('\u{8e4f}', '\u{8e4f}',
-         IdentifierType::Uncommon_Use), ('\u{8e50}', '\u{8e6d}',  IdentifierType::Recommended),
-        ('\u{8e6e}', '\u{8e6e}',  IdentifierType::Uncommon_Use), ('\u{8e6f}', '\u{8e74}',
-         IdentifierType::Recommended), ('\u{8e75}', '\u{8e75}',  IdentifierType::Uncommon_Use),
-        ('\u{8e76}', '\u{8e76}',  IdentifierType::Recommended), ('\u{8e77}', '\u{8e77}',
-         IdentifierType::Uncommon_Use), ('\u{8e78}', '\u{8e78}',  IdentifierType::Recommended),
-        ('\u{8e79}', '\u{8e79}',  IdentifierType::Uncommon_Use), ('\u{8e7a}', '\u{8e9a}',
-         IdentifierType::Recommended), ('\u{8e9b}', '\u{8e9b}',  IdentifierType::Uncommon_Use),
-        ('\u{8e9c}', '\u{8ea1}',  IdentifierType::Recommended), ('\u{8ea2}', '\u{8ea2}',
-         IdentifierType::Uncommon_Use), ('\u{8ea3}', '\u{8eb2}',  IdentifierType::Recommended),
-        ('\u{8eb3}', '\u{8eb3}',  IdentifierType::Uncommon_Use), ('\u{8eb4}', '\u{8eb5}',
-         IdentifierType::Recommended), ('\u{8eb6}', '\u{8eb7}',  IdentifierType::Uncommon_Use),
-        ('\u{8eb8}', '\u{8ec0}',  IdentifierType::Recommended), ('\u{8ec1}', '\u{8ec1}',
-         IdentifierType::Uncommon_Use), ('\u{8ec2}', '\u{8ec3}',  IdentifierType::Recommended),
-        ('\u{8ec4}', '\u{8ec4}',  IdentifierType::Uncommon_Use), ('\u{8ec5}', '\u{8ed8}',
-         IdentifierType::Recommended), ('\u{8ed9}', '\u{8ed9}',  IdentifierType::Uncommon_Use),
-        ('\u{8eda}', '\u{8eef}',  IdentifierType::Recommended), ('\u{8ef0}', '\u{8ef0}',
-         IdentifierType::Uncommon_Use), ('\u{8ef1}', '\u{8f0e}',  IdentifierType::Recommended),
-        ('\u{8f0f}', '\u{8f0f}',  IdentifierType::Uncommon_Use), ('\u{8f10}', '\u{8f2c}',
-         IdentifierType::Recommended), ('\u{8f2d}', '\u{8f2d}',  IdentifierType::Uncommon_Use),
-        ('\u{8f2e}', '\u{8f39}',  IdentifierType::Recommended), ('\u{8f3a}', '\u{8f3a}',
-         IdentifierType::Uncommon_Use), ('\u{8f3b}', '\u{8f40}',  IdentifierType::Recommended),
-        ('\u{8f41}', '\u{8f41}',  IdentifierType::Uncommon_Use), ('\u{8f42}', '\u{8f9c}',
-         IdentifierType::Recommended), ('\u{8f9d}', '\u{8f9d}',  IdentifierType::Uncommon_Use),
-        ('\u{8f9e}', '\u{8fa3}',  IdentifierType::Recommended), ('\u{8fa4}', '\u{8fa4}',
-         IdentifierType::Uncommon_Use), ('\u{8fa5}', '\u{8fb2}',  IdentifierType::Recommended),
-        ('\u{8fb3}', '\u{8fb3}',  IdentifierType::Uncommon_Use), ('\u{8fb4}', '\u{8fc2}',
-         IdentifierType::Recommended), ('\u{8fc3}', '\u{8fc3}',  IdentifierType::Uncommon_Use),
-        ('\u{8fc4}', '\u{8fc9}',  IdentifierType::Recommended), ('\u{8fca}', '\u{8fca}',
-         IdentifierType::Uncommon_Use), ('\u{8fcb}', '\u{8fe6}',  IdentifierType::Recommended),
-        ('\u{8fe7}', '\u{8fe7}',  IdentifierType::Uncommon_Use), ('\u{8fe8}', '\u{9029}',
-         IdentifierType::Recommended), ('\u{902a}', '\u{902a}',  IdentifierType::Uncommon_Use),
-        ('\u{902b}', '\u{902b}',  IdentifierType::Recommended), ('\u{902c}', '\u{902c}',
-         IdentifierType::Uncommon_Use), ('\u{902d}', '\u{9036}',  IdentifierType::Recommended),
-        ('\u{9037}', '\u{9037}',  IdentifierType::Uncommon_Use), ('\u{9038}', '\u{903f}',
-         IdentifierType::Recommended), ('\u{9040}', '\u{9040}',  IdentifierType::Uncommon_Use),
-        ('\u{9041}', '\u{9045}',  IdentifierType::Recommended), ('\u{9046}', '\u{9046}',
-         IdentifierType::Uncommon_Use), ('\u{9047}', '\u{90aa}',  IdentifierType::Recommended),
-        ('\u{90ab}', '\u{90ab}',  IdentifierType::Uncommon_Use), ('\u{90ac}', '\u{90cb}',
-         IdentifierType::Recommended), ('\u{90cc}', '\u{90cd}',  IdentifierType::Uncommon_Use),
-        ('\u{90ce}', '\u{90d1}',  IdentifierType::Recommended), ('\u{90d2}', '\u{90d2}',
-         IdentifierType::Uncommon_Use), ('\u{90d3}', '\u{90f5}',  IdentifierType::Recommended),
-        ('\u{90f6}', '\u{90f6}',  IdentifierType::Uncommon_Use), ('\u{90f7}', '\u{9109}',
-         IdentifierType::Recommended), ('\u{910a}', '\u{910a}',  IdentifierType::Uncommon_Use),
-        ('\u{910b}', '\u{913b}',  IdentifierType::Recommended), ('\u{913c}', '\u{913d}',
-         IdentifierType::Uncommon_Use), ('\u{913e}', '\u{9158}',  IdentifierType::Recommended),
-        ('\u{9159}', '\u{9159}',  IdentifierType::Uncommon_Use), ('\u{915a}', '\u{917a}',
-         IdentifierType::Recommended), ('\u{917b}', '\u{917b}',  IdentifierType::Uncommon_Use),
-        ('\u{917c}', '\u{9194}',  IdentifierType::Recommended), ('\u{9195}', '\u{9195}',
-         IdentifierType::Uncommon_Use), ('\u{9196}', '\u{9197}',  IdentifierType::Recommended),
-        ('\u{9198}', '\u{9198}',  IdentifierType::Uncommon_Use), ('\u{9199}', '\u{91a8}',
-         IdentifierType::Recommended), ('\u{91a9}', '\u{91a9}',  IdentifierType::Uncommon_Use),
-        ('\u{91aa}', '\u{91be}',  IdentifierType::Recommended), ('\u{91bf}', '\u{91bf}',
-         IdentifierType::Uncommon_Use), ('\u{91c0}', '\u{91c3}',  IdentifierType::Recommended),
-        ('\u{91c4}', '\u{91c4}',  IdentifierType::Uncommon_Use), ('\u{91c5}', '\u{91df}',
-         IdentifierType::Recommended), ('\u{91e0}', '\u{91e0}',  IdentifierType::Uncommon_Use),
-        ('\u{91e1}', '\u{91ee}',  IdentifierType::Recommended), ('\u{91ef}', '\u{91ef}',
-         IdentifierType::Uncommon_Use), ('\u{91f0}', '\u{9212}',  IdentifierType::Recommended),
-        ('\u{9213}', '\u{9213}',  IdentifierType::Uncommon_Use), ('\u{9214}', '\u{921e}',
-         IdentifierType::Recommended), ('\u{921f}', '\u{921f}',  IdentifierType::Uncommon_Use),
-        ('\u{9220}', '\u{9221}',  IdentifierType::Recommended), ('\u{9222}', '\u{9222}',
-         IdentifierType::Uncommon_Use), ('\u{9223}', '\u{9242}',  IdentifierType::Recommended),
-        ('\u{9243}', '\u{9243}',  IdentifierType::Uncommon_Use), ('\u{9244}', '\u{9268}',
-         IdentifierType::Recommended), ('\u{9269}', '\u{926a}',  IdentifierType::Uncommon_Use),
-        ('\u{926b}', '\u{9280}',  IdentifierType::Recommended), ('\u{9281}', '\u{9281}',
-         IdentifierType::Uncommon_Use), ('\u{9282}', '\u{9283}',  IdentifierType::Recommended),
-        ('\u{9284}', '\u{9284}',  IdentifierType::Uncommon_Use), ('\u{9285}', '\u{929d}',
-         IdentifierType::Recommended), ('\u{929e}', '\u{929e}',  IdentifierType::Uncommon_Use),
-        ('\u{929f}', '\u{92bc}',  IdentifierType::Recommended), ('\u{92bd}', '\u{92bd}',
-         IdentifierType::Uncommon_Use), ('\u{92be}', '\u{92d3}',  IdentifierType::Recommended),
-        ('\u{92d4}', '\u{92d4}',  IdentifierType::Uncommon_Use), ('\u{92d5}', '\u{92da}',
-         IdentifierType::Recommended), ('\u{92db}', '\u{92db}',  IdentifierType::Uncommon_Use),
-        ('\u{92dc}', '\u{92e1}',  IdentifierType::Recommended), ('\u{92e2}', '\u{92e2}',
-         IdentifierType::Uncommon_Use), ('\u{92e3}', '\u{931b}',  IdentifierType::Recommended),
-        ('\u{931c}', '\u{931c}',  IdentifierType::Uncommon_Use), ('\u{931d}', '\u{932f}',
-         IdentifierType::Recommended), ('\u{9330}', '\u{9331}',  IdentifierType::Uncommon_Use),
-        ('\u{9332}', '\u{9361}',  IdentifierType::Recommended), ('\u{9362}', '\u{9362}',
-         IdentifierType::Uncommon_Use), ('\u{9363}', '\u{9367}',  IdentifierType::Recommended),
-        ('\u{9368}', '\u{9368}',  IdentifierType::Uncommon_Use), ('\u{9369}', '\u{936a}',
-         IdentifierType::Recommended), ('\u{936b}', '\u{936b}',  IdentifierType::Uncommon_Use),
-        ('\u{936c}', '\u{936e}',  IdentifierType::Recommended), ('\u{936f}', '\u{936f}',
-         IdentifierType::Uncommon_Use), ('\u{9370}', '\u{9372}',  IdentifierType::Recommended),
-        ('\u{9373}', '\u{9373}',  IdentifierType::Uncommon_Use), ('\u{9374}', '\u{9377}',
-         IdentifierType::Recommended), ('\u{9378}', '\u{9378}',  IdentifierType::Uncommon_Use),
-        ('\u{9379}', '\u{937e}',  IdentifierType::Recommended), ('\u{937f}', '\u{937f}',
-         IdentifierType::Uncommon_Use), ('\u{9380}', '\u{9380}',  IdentifierType::Recommended),
-        ('\u{9381}', '\u{9381}',  IdentifierType::Uncommon_Use), ('\u{9382}', '\u{938a}',
-         IdentifierType::Recommended), ('\u{938b}', '\u{938b}',  IdentifierType::Uncommon_Use),
-        ('\u{938c}', '\u{939b}',  IdentifierType::Recommended), ('\u{939c}', '\u{939c}',
-         IdentifierType::Uncommon_Use), ('\u{939d}', '\u{939f}',  IdentifierType::Recommended),
-        ('\u{93a0}', '\u{93a0}',  IdentifierType::Uncommon_Use), ('\u{93a1}', '\u{93aa}',
-         IdentifierType::Recommended), ('\u{93ab}', '\u{93ab}',  IdentifierType::Uncommon_Use),
-        ('\u{93ac}', '\u{93ba}',  IdentifierType::Recommended), ('\u{93bb}', '\u{93bb}',
-         IdentifierType::Uncommon_Use), ('\u{93bc}', '\u{93df}',  IdentifierType::Recommended),
-        ('\u{93e0}', '\u{93e0}',  IdentifierType::Uncommon_Use), ('\u{93e1}', '\u{93f2}',
-         IdentifierType::Recommended), ('\u{93f3}', '\u{93f3}',  IdentifierType::Uncommon_Use),
-        ('\u{93f4}', '\u{9401}',  IdentifierType::Recommended), ('\u{9402}', '\u{9402}',
-         IdentifierType::Uncommon_Use), ('\u{9403}', '\u{9416}',  IdentifierType::Recommended),
-        ('\u{9417}', '\u{9417}',  IdentifierType::Uncommon_Use), ('\u{9418}', '\u{941b}',
-         IdentifierType::Recommended), ('\u{941c}', '\u{941c}',  IdentifierType::Uncommon_Use),
-        ('\u{941d}', '\u{941d}',  IdentifierType::Recommended), ('\u{941e}', '\u{941f}',
-         IdentifierType::Uncommon_Use), ('\u{9420}', '\u{9423}',  IdentifierType::Recommended),
-        ('\u{9424}', '\u{9424}',  IdentifierType::Uncommon_Use), ('\u{9425}', '\u{9442}',
-         IdentifierType::Recommended), ('\u{9443}', '\u{9443}',  IdentifierType::Uncommon_Use),
-        ('\u{9444}', '\u{944d}',  IdentifierType::Recommended), ('\u{944e}', '\u{944e}',
-         IdentifierType::Uncommon_Use), ('\u{944f}', '\u{946b}',  IdentifierType::Recommended),
-        ('\u{946c}', '\u{946c}',  IdentifierType::Uncommon_Use), ('\u{946d}', '\u{947a}',
-         IdentifierType::Recommended), ('\u{947b}', '\u{947b}',  IdentifierType::Uncommon_Use),
-        ('\u{947c}', '\u{9577}',  IdentifierType::Recommended), ('\u{9578}', '\u{9579}',
-         IdentifierType::Uncommon_Use), ('\u{957a}', '\u{957d}',  IdentifierType::Recommended),
-        ('\u{957e}', '\u{957e}',  IdentifierType::Uncommon_Use), ('\u{957f}', '\u{9584}',
-         IdentifierType::Recommended), ('\u{9585}', '\u{9585}',  IdentifierType::Uncommon_Use),
-        ('\u{9586}', '\u{9596}',  IdentifierType::Recommended), ('\u{9597}', '\u{9597}',
-         IdentifierType::Uncommon_Use), ('\u{9598}', '\u{95b2}',  IdentifierType::Recommended),
-        ('\u{95b3}', '\u{95b4}',  IdentifierType::Uncommon_Use), ('\u{95b5}', '\u{95b7}',
-         IdentifierType::Recommended), ('\u{95b8}', '\u{95b8}',  IdentifierType::Uncommon_Use),
-        ('\u{95b9}', '\u{95c0}',  IdentifierType::Recommended), ('\u{95c1}', '\u{95c1}',
-         IdentifierType::Uncommon_Use), ('\u{95c2}', '\u{95d8}',  IdentifierType::Recommended),
-        ('\u{95d9}', '\u{95d9}',  IdentifierType::Uncommon_Use), ('\u{95da}', '\u{95dc}',
-         IdentifierType::Recommended), ('\u{95dd}', '\u{95dd}',  IdentifierType::Uncommon_Use),
-        ('\u{95de}', '\u{9624}',  IdentifierType::Recommended), ('\u{9625}', '\u{9626}',
-         IdentifierType::Uncommon_Use), ('\u{9627}', '\u{9628}',  IdentifierType::Recommended),
-        ('\u{9629}', '\u{9629}',  IdentifierType::Uncommon_Use), ('\u{962a}', '\u{963d}',
-         IdentifierType::Recommended), ('\u{963e}', '\u{963e}',  IdentifierType::Uncommon_Use),
-        ('\u{963f}', '\u{9655}',  IdentifierType::Recommended), ('\u{9656}', '\u{9657}',
-         IdentifierType::Uncommon_Use), ('\u{9658}', '\u{9678}',  IdentifierType::Recommended),
-        ('\u{9679}', '\u{9679}',  IdentifierType::Uncommon_Use), ('\u{967a}', '\u{967a}',
-         IdentifierType::Recommended), ('\u{967b}', '\u{967b}',  IdentifierType::Uncommon_Use),
-        ('\u{967c}', '\u{967e}',  IdentifierType::Recommended), ('\u{967f}', '\u{967f}',
-         IdentifierType::Uncommon_Use), ('\u{9680}', '\u{9680}',  IdentifierType::Recommended),
-        ('\u{9681}', '\u{9682}',  IdentifierType::Uncommon_Use), ('\u{9683}', '\u{968b}',
-         IdentifierType::Recommended), ('\u{968c}', '\u{968c}',  IdentifierType::Uncommon_Use),
-        ('\u{968d}', '\u{9695}',  IdentifierType::Recommended), ('\u{9696}', '\u{9696}',
-         IdentifierType::Uncommon_Use), ('\u{9697}', '\u{9699}',  IdentifierType::Recommended),
-        ('\u{969a}', '\u{969a}',  IdentifierType::Uncommon_Use), ('\u{969b}', '\u{969c}',
-         IdentifierType::Recommended), ('\u{969d}', '\u{969d}',  IdentifierType::Uncommon_Use),
-        ('\u{969e}', '\u{969e}',  IdentifierType::Recommended), ('\u{969f}', '\u{969f}',
-         IdentifierType::Uncommon_Use), ('\u{96a0}', '\u{96aa}',  IdentifierType::Recommended),
-        ('\u{96ab}', '\u{96ab}',  IdentifierType::Uncommon_Use), ('\u{96ac}', '\u{96ae}',
-         IdentifierType::Recommended), ('\u{96af}', '\u{96af}',  IdentifierType::Uncommon_Use),
-        ('\u{96b0}', '\u{96b4}',  IdentifierType::Recommended), ('\u{96b5}', '\u{96b5}',
-         IdentifierType::Uncommon_Use), ('\u{96b6}', '\u{96e3}',  IdentifierType::Recommended),
-        ('\u{96e4}', '\u{96e4}',  IdentifierType::Uncommon_Use), ('\u{96e5}', '\u{96e5}',
-         IdentifierType::Recommended), ('\u{96e6}', '\u{96e7}',  IdentifierType::Uncommon_Use),
-        ('\u{96e8}', '\u{96fb}',  IdentifierType::Recommended), ('\u{96fc}', '\u{96fc}',
-         IdentifierType::Uncommon_Use), ('\u{96fd}', '\u{9713}',  IdentifierType::Recommended),
-        ('\u{9714}', '\u{9714}',  IdentifierType::Uncommon_Use), ('\u{9715}', '\u{9716}',
-         IdentifierType::Recommended), ('\u{9717}', '\u{9717}',  IdentifierType::Uncommon_Use),
-        ('\u{9718}', '\u{9719}',  IdentifierType::Recommended), ('\u{971a}', '\u{971b}',
-         IdentifierType::Uncommon_Use), ('\u{971c}', '\u{9732}',  IdentifierType::Recommended),
-        ('\u{9733}', '\u{9734}',  IdentifierType::Uncommon_Use), ('\u{9735}', '\u{9736}',
-         IdentifierType::Recommended), ('\u{9737}', '\u{9737}',  IdentifierType::Uncommon_Use),
-        ('\u{9738}', '\u{973f}',  IdentifierType::Recommended), ('\u{9740}', '\u{9741}',
-         IdentifierType::Uncommon_Use), ('\u{9742}', '\u{974c}',  IdentifierType::Recommended),
-        ('\u{974d}', '\u{974d}',  IdentifierType::Uncommon_Use), ('\u{974e}', '\u{9756}',
-         IdentifierType::Recommended), ('\u{9757}', '\u{9757}',  IdentifierType::Uncommon_Use),
-        ('\u{9758}', '\u{9762}',  IdentifierType::Recommended), ('\u{9763}', '\u{9763}',
-         IdentifierType::Uncommon_Use), ('\u{9764}', '\u{9774}',  IdentifierType::Recommended),
-        ('\u{9775}', '\u{9775}',  IdentifierType::Uncommon_Use), ('\u{9776}', '\u{9786}',
-         IdentifierType::Recommended), ('\u{9787}', '\u{9787}',  IdentifierType::Uncommon_Use),
-        ('\u{9788}', '\u{9788}',  IdentifierType::Recommended), ('\u{9789}', '\u{9789}',
-         IdentifierType::Uncommon_Use), ('\u{978a}', '\u{979a}',  IdentifierType::Recommended),
-        ('\u{979b}', '\u{979b}',  IdentifierType::Uncommon_Use), ('\u{979c}', '\u{97a8}',
-         IdentifierType::Recommended), ('\u{97a9}', '\u{97a9}',  IdentifierType::Uncommon_Use),
-        ('\u{97aa}', '\u{97af}',  IdentifierType::Recommended), ('\u{97b0}', '\u{97b1}',
-         IdentifierType::Uncommon_Use), ('\u{97b2}', '\u{97b4}',  IdentifierType::Recommended),
-        ('\u{97b5}', '\u{97b5}',  IdentifierType::Uncommon_Use), ('\u{97b6}', '\u{97bd}',
-         IdentifierType::Recommended), ('\u{97be}', '\u{97be}',  IdentifierType::Uncommon_Use),
-        ('\u{97bf}', '\u{97bf}',  IdentifierType::Recommended), ('\u{97c0}', '\u{97c0}',
-         IdentifierType::Uncommon_Use), ('\u{97c1}', '\u{97d1}',  IdentifierType::Recommended),
-        ('\u{97d2}', '\u{97d2}',  IdentifierType::Uncommon_Use), ('\u{97d3}', '\u{97fb}',
-         IdentifierType::Recommended), ('\u{97fc}', '\u{97fc}',  IdentifierType::Uncommon_Use),
-        ('\u{97fd}', '\u{981e}',  IdentifierType::Recommended), ('\u{981f}', '\u{981f}',
-         IdentifierType::Uncommon_Use), ('\u{9820}', '\u{9824}',  IdentifierType::Recommended),
-        ('\u{9825}', '\u{9825}',  IdentifierType::Uncommon_Use), ('\u{9826}', '\u{9829}',
-         IdentifierType::Recommended), ('\u{982a}', '\u{982a}',  IdentifierType::Uncommon_Use),
-        ('\u{982b}', '\u{9832}',  IdentifierType::Recommended), ('\u{9833}', '\u{9833}',
-         IdentifierType::Uncommon_Use), ('\u{9834}', '\u{9839}',  IdentifierType::Recommended),
-        ('\u{983a}', '\u{983a}',  IdentifierType::Uncommon_Use), ('\u{983b}', '\u{983d}',
-         IdentifierType::Recommended), ('\u{983e}', '\u{983e}',  IdentifierType::Uncommon_Use),
-        ('\u{983f}', '\u{9841}',  IdentifierType::Recommended), ('\u{9842}', '\u{9842}',
-         IdentifierType::Uncommon_Use), ('\u{9843}', '\u{9846}',  IdentifierType::Recommended),
-        ('\u{9847}', '\u{9847}',  IdentifierType::Uncommon_Use), ('\u{9848}', '\u{9855}',
-         IdentifierType::Recommended), ('\u{9856}', '\u{9856}',  IdentifierType::Uncommon_Use),
-        ('\u{9857}', '\u{9865}',  IdentifierType::Recommended), ('\u{9866}', '\u{9866}',
-         IdentifierType::Uncommon_Use), ('\u{9867}', '\u{9867}',  IdentifierType::Recommended),
-        ('\u{9868}', '\u{9868}',  IdentifierType::Uncommon_Use), ('\u{9869}', '\u{98b6}',
-         IdentifierType::Recommended), ('\u{98b7}', '\u{98b7}',  IdentifierType::Uncommon_Use),
-        ('\u{98b8}', '\u{98c9}',  IdentifierType::Recommended), ('\u{98ca}', '\u{98ca}',
-         IdentifierType::Uncommon_Use), ('\u{98cb}', '\u{98e3}',  IdentifierType::Recommended),
-        ('\u{98e4}', '\u{98e4}',  IdentifierType::Uncommon_Use), ('\u{98e5}', '\u{98eb}',
-         IdentifierType::Recommended), ('\u{98ec}', '\u{98ec}',  IdentifierType::Uncommon_Use),
-        ('\u{98ed}', '\u{98f0}',  IdentifierType::Recommended), ('\u{98f1}', '\u{98f1}',
-         IdentifierType::Uncommon_Use), ('\u{98f2}', '\u{98f7}',  IdentifierType::Recommended),
-        ('\u{98f8}', '\u{98f8}',  IdentifierType::Uncommon_Use), ('\u{98f9}', '\u{98fa}',
-         IdentifierType::Recommended), ('\u{98fb}', '\u{98fb}',  IdentifierType::Uncommon_Use),
-        ('\u{98fc}', '\u{9918}',  IdentifierType::Recommended), ('\u{9919}', '\u{9919}',
-         IdentifierType::Uncommon_Use), ('\u{991a}', '\u{993a}',  IdentifierType::Recommended),
-        ('\u{993b}', '\u{993b}',  IdentifierType::Uncommon_Use), ('\u{993c}', '\u{9943}',
-         IdentifierType::Recommended), ('\u{9944}', '\u{9944}',  IdentifierType::Uncommon_Use),
-        ('\u{9945}', '\u{9959}',  IdentifierType::Recommended), ('\u{995a}', '\u{995a}',
-         IdentifierType::Uncommon_Use), ('\u{995b}', '\u{995c}',  IdentifierType::Recommended),
-        ('\u{995d}', '\u{995d}',  IdentifierType::Uncommon_Use), ('\u{995e}', '\u{99be}',
-         IdentifierType::Recommended), ('\u{99bf}', '\u{99bf}',  IdentifierType::Uncommon_Use),
-        ('\u{99c0}', '\u{99df}',  IdentifierType::Recommended), ('\u{99e0}', '\u{99e0}',
-         IdentifierType::Uncommon_Use), ('\u{99e1}', '\u{99e5}',  IdentifierType::Recommended),
-        ('\u{99e6}', '\u{99e6}',  IdentifierType::Uncommon_Use), ('\u{99e7}', '\u{99ea}',
-         IdentifierType::Recommended), ('\u{99eb}', '\u{99eb}',  IdentifierType::Uncommon_Use),
-        ('\u{99ec}', '\u{99f4}',  IdentifierType::Recommended), ('\u{99f5}', '\u{99f5}',
-         IdentifierType::Uncommon_Use), ('\u{99f6}', '\u{9a0f}',  IdentifierType::Recommended),
-        ('\u{9a10}', '\u{9a10}',  IdentifierType::Uncommon_Use), ('\u{9a11}', '\u{9a16}',
-         IdentifierType::Recommended), ('\u{9a17}', '\u{9a18}',  IdentifierType::Uncommon_Use),
-        ('\u{9a19}', '\u{9a3a}',  IdentifierType::Recommended), ('\u{9a3b}', '\u{9a3b}',
-         IdentifierType::Uncommon_Use), ('\u{9a3c}', '\u{9a50}',  IdentifierType::Recommended),
-        ('\u{9a51}', '\u{9a51}',  IdentifierType::Uncommon_Use), ('\u{9a52}', '\u{9a57}',
-         IdentifierType::Recommended), ('\u{9a58}', '\u{9a58}',  IdentifierType::Uncommon_Use),
-        ('\u{9a59}', '\u{9a5c}',  IdentifierType::Recommended), ('\u{9a5d}', '\u{9a5d}',
-         IdentifierType::Uncommon_Use), ('\u{9a5e}', '\u{9a62}',  IdentifierType::Recommended),
-        ('\u{9a63}', '\u{9a63}',  IdentifierType::Uncommon_Use), ('\u{9a64}', '\u{9aa8}',
-         IdentifierType::Recommended), ('\u{9aa9}', '\u{9aa9}',  IdentifierType::Uncommon_Use),
-        ('\u{9aaa}', '\u{9abc}',  IdentifierType::Recommended), ('\u{9abd}', '\u{9abd}',
-         IdentifierType::Uncommon_Use), ('\u{9abe}', '\u{9ac7}',  IdentifierType::Recommended),
-        ('\u{9ac8}', '\u{9ac8}',  IdentifierType::Uncommon_Use), ('\u{9ac9}', '\u{9ad6}',
-         IdentifierType::Recommended), ('\u{9ad7}', '\u{9ad7}',  IdentifierType::Uncommon_Use),
-        ('\u{9ad8}', '\u{9adf}',  IdentifierType::Recommended), ('\u{9ae0}', '\u{9ae0}',
-         IdentifierType::Uncommon_Use), ('\u{9ae1}', '\u{9ae3}',  IdentifierType::Recommended),
-        ('\u{9ae4}', '\u{9ae4}',  IdentifierType::Uncommon_Use), ('\u{9ae5}', '\u{9ae7}',
-         IdentifierType::Recommended), ('\u{9ae8}', '\u{9ae9}',  IdentifierType::Uncommon_Use),
-        ('\u{9aea}', '\u{9aef}',  IdentifierType::Recommended), ('\u{9af0}', '\u{9af0}',
-         IdentifierType::Uncommon_Use), ('\u{9af1}', '\u{9aff}',  IdentifierType::Recommended),
-        ('\u{9b00}', '\u{9b00}',  IdentifierType::Uncommon_Use), ('\u{9b01}', '\u{9b01}',
-         IdentifierType::Recommended), ('\u{9b02}', '\u{9b02}',  IdentifierType::Uncommon_Use),
-        ('\u{9b03}', '\u{9b08}',  IdentifierType::Recommended), ('\u{9b09}', '\u{9b09}',
-         IdentifierType::Uncommon_Use), ('\u{9b0a}', '\u{9b13}',  IdentifierType::Recommended),
-        ('\u{9b14}', '\u{9b14}',  IdentifierType::Uncommon_Use), ('\u{9b15}', '\u{9b1a}',
-         IdentifierType::Recommended), ('\u{9b1b}', '\u{9b1b}',  IdentifierType::Uncommon_Use),
-        ('\u{9b1c}', '\u{9b33}',  IdentifierType::Recommended), ('\u{9b34}', '\u{9b34}',
-         IdentifierType::Uncommon_Use), ('\u{9b35}', '\u{9b3c}',  IdentifierType::Recommended),
-        ('\u{9b3d}', '\u{9b3d}',  IdentifierType::Uncommon_Use), ('\u{9b3e}', '\u{9b3f}',
-         IdentifierType::Recommended), ('\u{9b40}', '\u{9b40}',  IdentifierType::Uncommon_Use),
-        ('\u{9b41}', '\u{9b4f}',  IdentifierType::Recommended), ('\u{9b50}', '\u{9b50}',
-         IdentifierType::Uncommon_Use), ('\u{9b51}', '\u{9b56}',  IdentifierType::Recommended),
-        ('\u{9b57}', '\u{9b57}',  IdentifierType::Uncommon_Use), ('\u{9b58}', '\u{9b61}',
-         IdentifierType::Recommended), ('\u{9b62}', '\u{9b62}',  IdentifierType::Uncommon_Use),
-        ('\u{9b63}', '\u{9b71}',  IdentifierType::Recommended), ('\u{9b72}', '\u{9b72}',
-         IdentifierType::Uncommon_Use), ('\u{9b73}', '\u{9b88}',  IdentifierType::Recommended),
-        ('\u{9b89}', '\u{9b89}',  IdentifierType::Uncommon_Use), ('\u{9b8a}', '\u{9b8b}',
-         IdentifierType::Recommended), ('\u{9b8c}', '\u{9b8c}',  IdentifierType::Uncommon_Use),
-        ('\u{9b8d}', '\u{9b98}',  IdentifierType::Recommended), ('\u{9b99}', '\u{9b99}',
-         IdentifierType::Uncommon_Use), ('\u{9b9a}', '\u{9bc1}',  IdentifierType::Recommended),
-        ('\u{9bc2}', '\u{9bc2}',  IdentifierType::Uncommon_Use), ('\u{9bc3}', '\u{9bf5}',
-         IdentifierType::Recommended), ('\u{9bf6}', '\u{9bf6}',  IdentifierType::Uncommon_Use),
-        ('\u{9bf7}', '\u{9bff}',  IdentifierType::Recommended), ('\u{9c00}', '\u{9c01}',
-         IdentifierType::Uncommon_Use), ('\u{9c02}', '\u{9c02}',  IdentifierType::Recommended),
-        ('\u{9c03}', '\u{9c03}',  IdentifierType::Uncommon_Use), ('\u{9c04}', '\u{9c41}',
-         IdentifierType::Recommended), ('\u{9c42}', '\u{9c42}',  IdentifierType::Uncommon_Use),
-        ('\u{9c43}', '\u{9c4e}',  IdentifierType::Recommended), ('\u{9c4f}', '\u{9c4f}',
-         IdentifierType::Uncommon_Use), ('\u{9c50}', '\u{9c50}',  IdentifierType::Recommended),
-        ('\u{9c51}', '\u{9c51}',  IdentifierType::Uncommon_Use), ('\u{9c52}', '\u{9c60}',
-         IdentifierType::Recommended), ('\u{9c61}', '\u{9c61}',  IdentifierType::Uncommon_Use),
-        ('\u{9c62}', '\u{9c63}',  IdentifierType::Recommended), ('\u{9c64}', '\u{9c64}',
-         IdentifierType::Uncommon_Use), ('\u{9c65}', '\u{9c7a}',  IdentifierType::Recommended),
-        ('\u{9c7b}', '\u{9c7b}',  IdentifierType::Uncommon_Use), ('\u{9c7c}', '\u{9d0b}',
-         IdentifierType::Recommended), ('\u{9d0c}', '\u{9d0d}',  IdentifierType::Uncommon_Use),
-        ('\u{9d0e}', '\u{9d10}',  IdentifierType::Recommended), ('\u{9d11}', '\u{9d11}',
-         IdentifierType::Uncommon_Use), ('\u{9d12}', '\u{9d26}',  IdentifierType::Recommended),
-        ('\u{9d27}', '\u{9d27}',  IdentifierType::Uncommon_Use), ('\u{9d28}', '\u{9d34}',
-         IdentifierType::Recommended), ('\u{9d35}', '\u{9d35}',  IdentifierType::Uncommon_Use),
-        ('\u{9d36}', '\u{9d3b}',  IdentifierType::Recommended), ('\u{9d3c}', '\u{9d3c}',
-         IdentifierType::Uncommon_Use), ('\u{9d3d}', '\u{9d6c}',  IdentifierType::Recommended),
-        ('\u{9d6d}', '\u{9d6d}',  IdentifierType::Uncommon_Use), ('\u{9d6e}', '\u{9d94}',
-         IdentifierType::Recommended), ('\u{9d95}', '\u{9d95}',  IdentifierType::Uncommon_Use),
-        ('\u{9d96}', '\u{9dad}',  IdentifierType::Recommended), ('\u{9dae}', '\u{9dae}',
-         IdentifierType::Uncommon_Use), ('\u{9daf}', '\u{9dbc}',  IdentifierType::Recommended),
-        ('\u{9dbd}', '\u{9dbd}',  IdentifierType::Uncommon_Use), ('\u{9dbe}', '\u{9dbf}',
-         IdentifierType::Recommended), ('\u{9dc0}', '\u{9dc0}',  IdentifierType::Uncommon_Use),
-        ('\u{9dc1}', '\u{9de9}',  IdentifierType::Recommended), ('\u{9dea}', '\u{9dea}',
-         IdentifierType::Uncommon_Use), ('\u{9deb}', '\u{9dfb}',  IdentifierType::Recommended),
-        ('\u{9dfc}', '\u{9dfc}',  IdentifierType::Uncommon_Use), ('\u{9dfd}', '\u{9e0d}',
-         IdentifierType::Recommended), ('\u{9e0e}', '\u{9e0e}',  IdentifierType::Uncommon_Use),
-        ('\u{9e0f}', '\u{9e15}',  IdentifierType::Recommended), ('\u{9e16}', '\u{9e16}',
-         IdentifierType::Uncommon_Use), ('\u{9e17}', '\u{9e1b}',  IdentifierType::Recommended),
-        ('\u{9e1c}', '\u{9e1c}',  IdentifierType::Uncommon_Use), ('\u{9e1d}', '\u{9e7a}',
-         IdentifierType::Recommended), ('\u{9e7b}', '\u{9e7b}',  IdentifierType::Uncommon_Use),
-        ('\u{9e7c}', '\u{9e8e}',  IdentifierType::Recommended), ('\u{9e8f}', '\u{9e90}',
-         IdentifierType::Uncommon_Use), ('\u{9e91}', '\u{9e97}',  IdentifierType::Recommended),
-        ('\u{9e98}', '\u{9e98}',  IdentifierType::Uncommon_Use), ('\u{9e99}', '\u{9e9d}',
-         IdentifierType::Recommended), ('\u{9e9e}', '\u{9e9e}',  IdentifierType::Uncommon_Use),
-        ('\u{9e9f}', '\u{9ea1}',  IdentifierType::Recommended), ('\u{9ea2}', '\u{9ea2}',
-         IdentifierType::Uncommon_Use), ('\u{9ea3}', '\u{9eaa}',  IdentifierType::Recommended),
-        ('\u{9eab}', '\u{9eac}',  IdentifierType::Uncommon_Use), ('\u{9ead}', '\u{9eb0}',
-         IdentifierType::Recommended), ('\u{9eb1}', '\u{9eb1}',  IdentifierType::Uncommon_Use),
-        ('\u{9eb2}', '\u{9eeb}',  IdentifierType::Recommended), ('\u{9eec}', '\u{9eec}',
-         IdentifierType::Uncommon_Use), ('\u{9eed}', '\u{9ef0}',  IdentifierType::Recommended),
-        ('\u{9ef1}', '\u{9ef1}',  IdentifierType::Uncommon_Use), ('\u{9ef2}', '\u{9f02}',
-         IdentifierType::Recommended), ('\u{9f03}', '\u{9f03}',  IdentifierType::Uncommon_Use),
-        ('\u{9f04}', '\u{9f10}',  IdentifierType::Recommended), ('\u{9f11}', '\u{9f11}',
-         IdentifierType::Uncommon_Use), ('\u{9f12}', '\u{9f13}',  IdentifierType::Recommended),
-        ('\u{9f14}', '\u{9f14}',  IdentifierType::Uncommon_Use), ('\u{9f15}', '\u{9f25}',
-         IdentifierType::Recommended), ('\u{9f26}', '\u{9f26}',  IdentifierType::Uncommon_Use),
-        ('\u{9f27}', '\u{9f44}',  IdentifierType::Recommended), ('\u{9f45}', '\u{9f45}',
-         IdentifierType::Uncommon_Use), ('\u{9f46}', '\u{9f52}',  IdentifierType::Recommended),
-        ('\u{9f53}', '\u{9f53}',  IdentifierType::Uncommon_Use), ('\u{9f54}', '\u{9f6c}',
-         IdentifierType::Recommended), ('\u{9f6d}', '\u{9f6d}',  IdentifierType::Uncommon_Use),
-        ('\u{9f6e}', '\u{9fa0}',  IdentifierType::Recommended), ('\u{9fa1}', '\u{9fa1}',
-         IdentifierType::Uncommon_Use), ('\u{9fa2}', '\u{9fa2}',  IdentifierType::Recommended),
-        ('\u{9fa3}', '\u{9fa3}',  IdentifierType::Uncommon_Use), ('\u{9fa4}', '\u{9fa5}',
-         IdentifierType::Recommended), ('\u{9fa6}', '\u{9fbb}',  IdentifierType::Uncommon_Use),
-        ('\u{9fbc}', '\u{9fc3}',  IdentifierType::Uncommon_Use), ('\u{9fc4}', '\u{9fcb}',
-         IdentifierType::Uncommon_Use), ('\u{9fcc}', '\u{9fcc}',  IdentifierType::Uncommon_Use),
-        ('\u{9fcd}', '\u{9fd5}',  IdentifierType::Uncommon_Use), ('\u{9fd6}', '\u{9fea}',
-         IdentifierType::Uncommon_Use), ('\u{9feb}', '\u{9fef}',  IdentifierType::Uncommon_Use),
-        ('\u{9ff0}', '\u{9ffc}',  IdentifierType::Uncommon_Use), ('\u{9ffd}', '\u{9fff}',
-         IdentifierType::Uncommon_Use), ('\u{a000}', '\u{a48c}',  IdentifierType::Limited_Use),
-        ('\u{a4d0}', '\u{a4fd}',  IdentifierType::Limited_Use), ('\u{a500}', '\u{a60c}',
-         IdentifierType::Limited_Use), ('\u{a613}', '\u{a629}',  IdentifierType::Limited_Use),
-        ('\u{a640}', '\u{a65f}',  IdentifierType::Obsolete), ('\u{a660}', '\u{a661}',
-         IdentifierType::Obsolete), ('\u{a662}', '\u{a66e}',  IdentifierType::Obsolete),
-        ('\u{a66f}', '\u{a66f}',  IdentifierType::Uncommon_Use), ('\u{a674}', '\u{a67b}',
-         IdentifierType::Obsolete), ('\u{a67c}', '\u{a67d}',  IdentifierType::Uncommon_Use),
-        ('\u{a67e}', '\u{a67e}',  IdentifierType::Not_XID), ('\u{a67f}', '\u{a697}',
-         IdentifierType::Obsolete), ('\u{a698}', '\u{a69b}',  IdentifierType::Obsolete),
-        ('\u{a69c}', '\u{a69d}',  IdentifierType::Not_NFKC), ('\u{a69f}', '\u{a69f}',
-         IdentifierType::Obsolete), ('\u{a6a0}', '\u{a6f1}',  IdentifierType::Limited_Use),
-        ('\u{a717}', '\u{a71a}',  IdentifierType::Technical), ('\u{a71b}', '\u{a71f}',
-         IdentifierType::Technical), ('\u{a720}', '\u{a721}',  IdentifierType::Not_XID),
-        ('\u{a730}', '\u{a76f}',  IdentifierType::Obsolete), ('\u{a770}', '\u{a770}',
-         IdentifierType::Not_NFKC), ('\u{a771}', '\u{a787}',  IdentifierType::Obsolete),
-        ('\u{a788}', '\u{a788}',  IdentifierType::Technical), ('\u{a789}', '\u{a78a}',
-         IdentifierType::Not_XID), ('\u{a78b}', '\u{a78c}',  IdentifierType::Uncommon_Use),
-        ('\u{a78d}', '\u{a78d}',  IdentifierType::Recommended), ('\u{a78e}', '\u{a78e}',
-         IdentifierType::Technical), ('\u{a78f}', '\u{a78f}',  IdentifierType::Uncommon_Use),
-        ('\u{a790}', '\u{a791}',  IdentifierType::Obsolete), ('\u{a792}', '\u{a793}',
-         IdentifierType::Uncommon_Use), ('\u{a794}', '\u{a79f}',  IdentifierType::Obsolete),
-        ('\u{a7a0}', '\u{a7a9}',  IdentifierType::Obsolete), ('\u{a7aa}', '\u{a7aa}',
-         IdentifierType::Recommended), ('\u{a7ab}', '\u{a7ad}',  IdentifierType::Obsolete),
-        ('\u{a7ae}', '\u{a7ae}',  IdentifierType::Technical), ('\u{a7af}', '\u{a7af}',
-         IdentifierType::Technical), ('\u{a7b0}', '\u{a7b1}',  IdentifierType::Obsolete),
-        ('\u{a7b2}', '\u{a7b7}',  IdentifierType::Uncommon_Use), ('\u{a7b8}', '\u{a7b9}',
-         IdentifierType::Uncommon_Use), ('\u{a7ba}', '\u{a7bf}',  IdentifierType::Technical),
-        ('\u{a7c0}', '\u{a7c1}',  IdentifierType::Obsolete), ('\u{a7c2}', '\u{a7c3}',
-         IdentifierType::Uncommon_Use), ('\u{a7c4}', '\u{a7c4}',  IdentifierType::Obsolete),
-        ('\u{a7c5}', '\u{a7c6}',  IdentifierType::Technical), ('\u{a7c7}', '\u{a7ca}',
-         IdentifierType::Obsolete), ('\u{a7cb}', '\u{a7cd}',  IdentifierType::Uncommon_Use),
-        ('\u{a7ce}', '\u{a7cf}',  IdentifierType::Uncommon_Use), ('\u{a7d0}', '\u{a7d1}',
-         IdentifierType::Obsolete), ('\u{a7d2}', '\u{a7d2}',  IdentifierType::Obsolete),
-        ('\u{a7d3}', '\u{a7d3}',  IdentifierType::Obsolete), ('\u{a7d4}', '\u{a7d4}',
-         IdentifierType::Obsolete), ('\u{a7d5}', '\u{a7d9}',  IdentifierType::Obsolete),
-        ('\u{a7da}', '\u{a7dc}',  IdentifierType::Uncommon_Use), ('\u{a7f1}', '\u{a7f1}',
-         IdentifierType::Not_NFKC), ('\u{a7f2}', '\u{a7f4}',  IdentifierType::Not_NFKC),
-        ('\u{a7f5}', '\u{a7f6}',  IdentifierType::Obsolete), ('\u{a7f7}', '\u{a7f7}',
-         IdentifierType::Obsolete), ('\u{a7f8}', '\u{a7f9}',  IdentifierType::Not_NFKC),
-        ('\u{a7fa}', '\u{a7fa}',  IdentifierType::Technical), ('\u{a7fb}', '\u{a7ff}',
-         IdentifierType::Obsolete), ('\u{a800}', '\u{a827}',  IdentifierType::Limited_Use),
-        ('\u{a82c}', '\u{a82c}',  IdentifierType::Limited_Use), ('\u{a830}', '\u{a839}',
-         IdentifierType::Not_XID), ('\u{a840}', '\u{a873}',  IdentifierType::Exclusion),
-        ('\u{a880}', '\u{a8c4}',  IdentifierType::Limited_Use), ('\u{a8c5}', '\u{a8c5}',
-         IdentifierType::Limited_Use), ('\u{a8d0}', '\u{a8d9}',  IdentifierType::Limited_Use),
-        ('\u{a8e0}', '\u{a8f7}',  IdentifierType::Obsolete), ('\u{a8fb}', '\u{a8fb}',
-         IdentifierType::Obsolete), ('\u{a8fe}', '\u{a8ff}',  IdentifierType::Obsolete),
-        ('\u{a900}', '\u{a92d}',  IdentifierType::Limited_Use), ('\u{a92e}', '\u{a92e}',
-         IdentifierType::Not_XID), ('\u{a930}', '\u{a953}',  IdentifierType::Exclusion),
-        ('\u{a960}', '\u{a97c}',  IdentifierType::Obsolete), ('\u{a980}', '\u{a9c0}',
-         IdentifierType::Limited_Use), ('\u{a9d0}', '\u{a9d9}',  IdentifierType::Limited_Use),
-        ('\u{a9e0}', '\u{a9e6}',  IdentifierType::Obsolete), ('\u{a9e7}', '\u{a9fe}',
-         IdentifierType::Uncommon_Use), ('\u{aa00}', '\u{aa36}',  IdentifierType::Limited_Use),
-        ('\u{aa40}', '\u{aa4d}',  IdentifierType::Limited_Use), ('\u{aa50}', '\u{aa59}',
-         IdentifierType::Limited_Use), ('\u{aa60}', '\u{aa76}',  IdentifierType::Uncommon_Use),
-        ('\u{aa77}', '\u{aa79}',  IdentifierType::Not_XID), ('\u{aa7a}', '\u{aa7a}',
-         IdentifierType::Uncommon_Use), ('\u{aa7b}', '\u{aa7b}',  IdentifierType::Recommended),
-        ('\u{aa7c}', '\u{aa7f}',  IdentifierType::Uncommon_Use), ('\u{aa80}', '\u{aac2}',
-         IdentifierType::Limited_Use), ('\u{aadb}', '\u{aadd}',  IdentifierType::Limited_Use),
-        ('\u{aae0}', '\u{aaef}',  IdentifierType::Limited_Use), ('\u{aaf2}', '\u{aaf6}',
-         IdentifierType::Limited_Use), ('\u{ab01}', '\u{ab06}',  IdentifierType::Uncommon_Use),
-        ('\u{ab09}', '\u{ab0e}',  IdentifierType::Uncommon_Use), ('\u{ab11}', '\u{ab16}',
-         IdentifierType::Uncommon_Use), ('\u{ab20}', '\u{ab26}',  IdentifierType::Uncommon_Use),
-        ('\u{ab28}', '\u{ab2e}',  IdentifierType::Uncommon_Use), ('\u{ab30}', '\u{ab5a}',
-         IdentifierType::Obsolete), ('\u{ab5b}', '\u{ab5b}',  IdentifierType::Not_XID), ('\u{ab5c}',
-        '\u{ab5f}',  IdentifierType::Not_NFKC), ('\u{ab60}', '\u{ab63}',
-         IdentifierType::Uncommon_Use), ('\u{ab64}', '\u{ab65}',  IdentifierType::Obsolete),
-        ('\u{ab66}', '\u{ab67}',  IdentifierType::Uncommon_Use), ('\u{ab68}', '\u{ab68}',
-         IdentifierType::Technical), ('\u{ab69}', '\u{ab69}',  IdentifierType::Not_NFKC),
-        ('\u{ab6a}', '\u{ab6b}',  IdentifierType::Not_XID), ('\u{ab70}', '\u{abbf}',
-         IdentifierType::Limited_Use), ('\u{abc0}', '\u{abea}',  IdentifierType::Limited_Use),
-        ('\u{abec}', '\u{abed}',  IdentifierType::Limited_Use), ('\u{abf0}', '\u{abf9}',
-         IdentifierType::Limited_Use), ('\u{ac00}', '\u{d7a3}',  IdentifierType::Recommended),
-        ('\u{d7b0}', '\u{d7c6}',  IdentifierType::Obsolete), ('\u{d7cb}', '\u{d7fb}',
-         IdentifierType::Obsolete), ('\u{f900}', '\u{fa0d}',  IdentifierType::Not_NFKC),
-        ('\u{fa0e}', '\u{fa0f}',  IdentifierType::Uncommon_Use), ('\u{fa10}', '\u{fa10}',
-         IdentifierType::Not_NFKC), ('\u{fa11}', '\u{fa11}',  IdentifierType::Uncommon_Use),
-        ('\u{fa12}', '\u{fa12}',  IdentifierType::Not_NFKC), ('\u{fa13}', '\u{fa14}',
-         IdentifierType::Uncommon_Use), ('\u{fa15}', '\u{fa1e}',  IdentifierType::Not_NFKC),
-        ('\u{fa1f}', '\u{fa1f}',  IdentifierType::Uncommon_Use), ('\u{fa20}', '\u{fa20}',
-         IdentifierType::Not_NFKC), ('\u{fa21}', '\u{fa21}',  IdentifierType::Uncommon_Use),
-        ('\u{fa22}', '\u{fa22}',  IdentifierType::Not_NFKC), ('\u{fa23}', '\u{fa24}',
-         IdentifierType::Uncommon_Use), ('\u{fa25}', '\u{fa26}',  IdentifierType::Not_NFKC),
-        ('\u{fa27}', '\u{fa29}',  IdentifierType::Uncommon_Use), ('\u{fa2a}', '\u{fa2d}',
-         IdentifierType::Not_NFKC), ('\u{fa2e}', '\u{fa2f}',  IdentifierType::Not_NFKC),
-        ('\u{fa30}', '\u{fa6a}',  IdentifierType::Not_NFKC), ('\u{fa6b}', '\u{fa6d}',
-         IdentifierType::Not_NFKC), ('\u{fa70}', '\u{fad9}',  IdentifierType::Not_NFKC),
-        ('\u{fb00}', '\u{fb06}',  IdentifierType::Not_NFKC), ('\u{fb13}', '\u{fb17}',
-         IdentifierType::Not_NFKC), ('\u{fb1d}', '\u{fb1d}',  IdentifierType::Not_NFKC),
-        ('\u{fb1f}', '\u{fb36}',  IdentifierType::Not_NFKC), ('\u{fb38}', '\u{fb3c}',
-         IdentifierType::Not_NFKC), ('\u{fb3e}', '\u{fb3e}',  IdentifierType::Not_NFKC),
-        ('\u{fb40}', '\u{fb41}',  IdentifierType::Not_NFKC), ('\u{fb43}', '\u{fb44}',
-         IdentifierType::Not_NFKC), ('\u{fb46}', '\u{fbb1}',  IdentifierType::Not_NFKC),
-        ('\u{fbd3}', '\u{fd3d}',  IdentifierType::Not_NFKC), ('\u{fd50}', '\u{fd8f}',
-         IdentifierType::Not_NFKC), ('\u{fd92}', '\u{fdc7}',  IdentifierType::Not_NFKC),
-        ('\u{fdf0}', '\u{fdfb}',  IdentifierType::Not_NFKC), ('\u{fdfc}', '\u{fdfc}',
-         IdentifierType::Not_NFKC), ('\u{fe00}', '\u{fe0f}',  IdentifierType::Default_Ignorable),
-        ('\u{fe10}', '\u{fe19}',  IdentifierType::Not_NFKC), ('\u{fe20}', '\u{fe23}',
-         IdentifierType::Technical), ('\u{fe24}', '\u{fe26}',  IdentifierType::Technical),
-        ('\u{fe27}', '\u{fe2d}',  IdentifierType::Technical), ('\u{fe30}', '\u{fe44}',
-         IdentifierType::Not_NFKC), ('\u{fe47}', '\u{fe48}',  IdentifierType::Not_NFKC),
-        ('\u{fe49}', '\u{fe52}',  IdentifierType::Not_NFKC), ('\u{fe54}', '\u{fe66}',
-         IdentifierType::Not_NFKC), ('\u{fe68}', '\u{fe6b}',  IdentifierType::Not_NFKC),
-        ('\u{fe70}', '\u{fe72}',  IdentifierType::Not_NFKC), ('\u{fe73}', '\u{fe73}',
-         IdentifierType::Technical), ('\u{fe74}', '\u{fe74}',  IdentifierType::Not_NFKC),
-        ('\u{fe76}', '\u{fefc}',  IdentifierType::Not_NFKC), ('\u{feff}', '\u{feff}',
-         IdentifierType::Default_Ignorable), ('\u{ff01}', '\u{ff5e}',  IdentifierType::Not_NFKC),
-        ('\u{ff5f}', '\u{ff60}',  IdentifierType::Not_NFKC), ('\u{ff61}', '\u{ff9f}',
-         IdentifierType::Not_NFKC), ('\u{ffa0}', '\u{ffa0}',  IdentifierType::Default_Ignorable),
-        ('\u{ffa1}', '\u{ffbe}',  IdentifierType::Not_NFKC), ('\u{ffc2}', '\u{ffc7}',
-         IdentifierType::Not_NFKC), ('\u{ffca}', '\u{ffcf}',  IdentifierType::Not_NFKC),
-        ('\u{ffd2}', '\u{ffd7}',  IdentifierType::Not_NFKC), ('\u{ffda}', '\u{ffdc}',
-         IdentifierType::Not_NFKC), ('\u{ffe0}', '\u{ffe6}',  IdentifierType::Not_NFKC),
-        ('\u{ffe8}', '\u{ffee}',  IdentifierType::Not_NFKC), ('\u{fff9}', '\u{fffb}',
-         IdentifierType::Not_XID), ('\u{fffc}', '\u{fffc}',  IdentifierType::Not_XID), ('\u{fffd}',
-        '\u{fffd}',  IdentifierType::Not_XID), ('\u{10000}', '\u{1000b}',
-         IdentifierType::Exclusion), ('\u{1000d}', '\u{10026}',  IdentifierType::Exclusion),
-        ('\u{10028}', '\u{1003a}',  IdentifierType::Exclusion), ('\u{1003c}', '\u{1003d}',
-         IdentifierType::Exclusion), ('\u{1003f}', '\u{1004d}',  IdentifierType::Exclusion),
-        ('\u{10050}', '\u{1005d}',  IdentifierType::Exclusion), ('\u{10080}', '\u{100fa}',
-         IdentifierType::Exclusion), ('\u{10140}', '\u{10174}',  IdentifierType::Obsolete),
-        ('\u{10175}', '\u{1018a}',  IdentifierType::Not_XID), ('\u{1018b}', '\u{1018c}',
-         IdentifierType::Not_XID), ('\u{1018d}', '\u{1018e}',  IdentifierType::Not_XID),
-        ('\u{10190}', '\u{1019b}',  IdentifierType::Not_XID), ('\u{1019c}', '\u{1019c}',
-         IdentifierType::Not_XID), ('\u{101a0}', '\u{101a0}',  IdentifierType::Not_XID),
-        ('\u{101fd}', '\u{101fd}',  IdentifierType::Obsolete), ('\u{10280}', '\u{1029c}',
-         IdentifierType::Exclusion), ('\u{102a0}', '\u{102d0}',  IdentifierType::Exclusion),
-        ('\u{102e0}', '\u{102e0}',  IdentifierType::Obsolete), ('\u{10300}', '\u{1031e}',
-         IdentifierType::Exclusion), ('\u{1031f}', '\u{1031f}',  IdentifierType::Exclusion),
-        ('\u{1032d}', '\u{1032f}',  IdentifierType::Exclusion), ('\u{10330}', '\u{1034a}',
-         IdentifierType::Exclusion), ('\u{10350}', '\u{1037a}',  IdentifierType::Exclusion),
-        ('\u{10380}', '\u{1039d}',  IdentifierType::Exclusion), ('\u{103a0}', '\u{103c3}',
-         IdentifierType::Exclusion), ('\u{103c8}', '\u{103cf}',  IdentifierType::Exclusion),
-        ('\u{103d1}', '\u{103d5}',  IdentifierType::Exclusion), ('\u{10400}', '\u{10425}',
-         IdentifierType::Exclusion), ('\u{10426}', '\u{10427}',  IdentifierType::Exclusion),
-        ('\u{10428}', '\u{1044d}',  IdentifierType::Exclusion), ('\u{1044e}', '\u{1049d}',
-         IdentifierType::Exclusion), ('\u{104a0}', '\u{104a9}',  IdentifierType::Exclusion),
-        ('\u{104b0}', '\u{104d3}',  IdentifierType::Limited_Use), ('\u{104d8}', '\u{104fb}',
-         IdentifierType::Limited_Use), ('\u{10500}', '\u{10527}',  IdentifierType::Exclusion),
-        ('\u{10530}', '\u{10563}',  IdentifierType::Exclusion), ('\u{10570}', '\u{1057a}',
-         IdentifierType::Exclusion), ('\u{1057c}', '\u{1058a}',  IdentifierType::Exclusion),
-        ('\u{1058c}', '\u{10592}',  IdentifierType::Exclusion), ('\u{10594}', '\u{10595}',
-         IdentifierType::Exclusion), ('\u{10597}', '\u{105a1}',  IdentifierType::Exclusion),
-        ('\u{105a3}', '\u{105b1}',  IdentifierType::Exclusion), ('\u{105b3}', '\u{105b9}',
-         IdentifierType::Exclusion), ('\u{105bb}', '\u{105bc}',  IdentifierType::Exclusion),
-        ('\u{105c0}', '\u{105f3}',  IdentifierType::Exclusion), ('\u{10600}', '\u{10736}',
-         IdentifierType::Exclusion), ('\u{10740}', '\u{10755}',  IdentifierType::Exclusion),
-        ('\u{10760}', '\u{10767}',  IdentifierType::Exclusion), ('\u{10780}', '\u{10780}',
-         IdentifierType::Uncommon_Use), ('\u{10781}', '\u{10785}',  IdentifierType::Not_NFKC),
-        ('\u{10787}', '\u{107b0}',  IdentifierType::Not_NFKC), ('\u{107b2}', '\u{107ba}',
-         IdentifierType::Not_NFKC), ('\u{10800}', '\u{10805}',  IdentifierType::Exclusion),
-        ('\u{10808}', '\u{10808}',  IdentifierType::Exclusion), ('\u{1080a}', '\u{10835}',
-         IdentifierType::Exclusion), ('\u{10837}', '\u{10838}',  IdentifierType::Exclusion),
-        ('\u{1083c}', '\u{1083c}',  IdentifierType::Exclusion), ('\u{1083f}', '\u{1083f}',
-         IdentifierType::Exclusion), ('\u{10840}', '\u{10855}',  IdentifierType::Exclusion),
-        ('\u{10860}', '\u{10876}',  IdentifierType::Exclusion), ('\u{10880}', '\u{1089e}',
-         IdentifierType::Exclusion), ('\u{108e0}', '\u{108f2}',  IdentifierType::Exclusion),
-        ('\u{108f4}', '\u{108f5}',  IdentifierType::Exclusion), ('\u{10900}', '\u{10915}',
-         IdentifierType::Exclusion), ('\u{10920}', '\u{10939}',  IdentifierType::Exclusion),
-        ('\u{10940}', '\u{10959}',  IdentifierType::Exclusion), ('\u{10980}', '\u{109b7}',
-         IdentifierType::Exclusion), ('\u{109be}', '\u{109bf}',  IdentifierType::Exclusion),
-        ('\u{10a00}', '\u{10a03}',  IdentifierType::Exclusion), ('\u{10a05}', '\u{10a06}',
-         IdentifierType::Exclusion), ('\u{10a0c}', '\u{10a13}',  IdentifierType::Exclusion),
-        ('\u{10a15}', '\u{10a17}',  IdentifierType::Exclusion), ('\u{10a19}', '\u{10a33}',
-         IdentifierType::Exclusion), ('\u{10a34}', '\u{10a35}',  IdentifierType::Exclusion),
-        ('\u{10a38}', '\u{10a3a}',  IdentifierType::Exclusion), ('\u{10a3f}', '\u{10a3f}',
-         IdentifierType::Exclusion), ('\u{10a60}', '\u{10a7c}',  IdentifierType::Exclusion),
-        ('\u{10a80}', '\u{10a9c}',  IdentifierType::Exclusion), ('\u{10ac0}', '\u{10ac7}',
-         IdentifierType::Exclusion), ('\u{10ac9}', '\u{10ae6}',  IdentifierType::Exclusion),
-        ('\u{10b00}', '\u{10b35}',  IdentifierType::Exclusion), ('\u{10b40}', '\u{10b55}',
-         IdentifierType::Exclusion), ('\u{10b60}', '\u{10b72}',  IdentifierType::Exclusion),
-        ('\u{10b80}', '\u{10b91}',  IdentifierType::Exclusion), ('\u{10c00}', '\u{10c48}',
-         IdentifierType::Exclusion), ('\u{10c80}', '\u{10cb2}',  IdentifierType::Exclusion),
-        ('\u{10cc0}', '\u{10cf2}',  IdentifierType::Exclusion), ('\u{10d00}', '\u{10d27}',
-         IdentifierType::Limited_Use), ('\u{10d30}', '\u{10d39}',  IdentifierType::Limited_Use),
-        ('\u{10d40}', '\u{10d65}',  IdentifierType::Exclusion), ('\u{10d69}', '\u{10d6d}',
-         IdentifierType::Exclusion), ('\u{10d6f}', '\u{10d85}',  IdentifierType::Exclusion),
-        ('\u{10e60}', '\u{10e7e}',  IdentifierType::Not_XID), ('\u{10e80}', '\u{10ea9}',
-         IdentifierType::Exclusion), ('\u{10eab}', '\u{10eac}',  IdentifierType::Exclusion),
-        ('\u{10eb0}', '\u{10eb1}',  IdentifierType::Exclusion), ('\u{10ec2}', '\u{10ec4}',
-         IdentifierType::Uncommon_Use), ('\u{10ec5}', '\u{10ec6}',  IdentifierType::Technical),
-        ('\u{10ec7}', '\u{10ec7}',  IdentifierType::Uncommon_Use), ('\u{10ed0}', '\u{10ed8}',
-         IdentifierType::Not_XID), ('\u{10efa}', '\u{10efa}',  IdentifierType::Uncommon_Use),
-        ('\u{10efb}', '\u{10efb}',  IdentifierType::Technical), ('\u{10efc}', '\u{10efc}',
-         IdentifierType::Uncommon_Use), ('\u{10efd}', '\u{10eff}',  IdentifierType::Uncommon_Use),
-        ('\u{10f00}', '\u{10f1c}',  IdentifierType::Exclusion), ('\u{10f27}', '\u{10f27}',
-         IdentifierType::Exclusion), ('\u{10f30}', '\u{10f50}',  IdentifierType::Exclusion),
-        ('\u{10f70}', '\u{10f85}',  IdentifierType::Exclusion), ('\u{10fb0}', '\u{10fc4}',
-         IdentifierType::Exclusion), ('\u{10fe0}', '\u{10ff6}',  IdentifierType::Exclusion),
-        ('\u{11000}', '\u{11046}',  IdentifierType::Exclusion), ('\u{11066}', '\u{1106f}',
-         IdentifierType::Exclusion), ('\u{11070}', '\u{11075}',  IdentifierType::Exclusion),
-        ('\u{1107f}', '\u{1107f}',  IdentifierType::Exclusion), ('\u{11080}', '\u{110ba}',
-         IdentifierType::Exclusion), ('\u{110c2}', '\u{110c2}',  IdentifierType::Exclusion),
-        ('\u{110d0}', '\u{110e8}',  IdentifierType::Exclusion), ('\u{110f0}', '\u{110f9}',
-         IdentifierType::Exclusion), ('\u{11100}', '\u{11134}',  IdentifierType::Limited_Use),
-        ('\u{11136}', '\u{1113f}',  IdentifierType::Limited_Use), ('\u{11144}', '\u{11146}',
-         IdentifierType::Limited_Use), ('\u{11147}', '\u{11147}',  IdentifierType::Limited_Use),
-        ('\u{11150}', '\u{11173}',  IdentifierType::Exclusion), ('\u{11176}', '\u{11176}',
-         IdentifierType::Exclusion), ('\u{11180}', '\u{111c4}',  IdentifierType::Exclusion),
-        ('\u{111c9}', '\u{111cc}',  IdentifierType::Exclusion), ('\u{111ce}', '\u{111cf}',
-         IdentifierType::Exclusion), ('\u{111d0}', '\u{111d9}',  IdentifierType::Exclusion),
-        ('\u{111da}', '\u{111da}',  IdentifierType::Exclusion), ('\u{111dc}', '\u{111dc}',
-         IdentifierType::Exclusion), ('\u{111e1}', '\u{111f4}',  IdentifierType::Not_XID),
-        ('\u{11200}', '\u{11211}',  IdentifierType::Exclusion), ('\u{11213}', '\u{11237}',
-         IdentifierType::Exclusion), ('\u{1123e}', '\u{1123e}',  IdentifierType::Exclusion),
-        ('\u{1123f}', '\u{11241}',  IdentifierType::Exclusion), ('\u{11280}', '\u{11286}',
-         IdentifierType::Exclusion), ('\u{11288}', '\u{11288}',  IdentifierType::Exclusion),
-        ('\u{1128a}', '\u{1128d}',  IdentifierType::Exclusion), ('\u{1128f}', '\u{1129d}',
-         IdentifierType::Exclusion), ('\u{1129f}', '\u{112a8}',  IdentifierType::Exclusion),
-        ('\u{112b0}', '\u{112ea}',  IdentifierType::Exclusion), ('\u{112f0}', '\u{112f9}',
-         IdentifierType::Exclusion), ('\u{11300}', '\u{11300}',  IdentifierType::Exclusion),
-        ('\u{11301}', '\u{11301}',  IdentifierType::Recommended), ('\u{11302}', '\u{11302}',
-         IdentifierType::Exclusion), ('\u{11303}', '\u{11303}',  IdentifierType::Recommended),
-        ('\u{11305}', '\u{1130c}',  IdentifierType::Exclusion), ('\u{1130f}', '\u{11310}',
-         IdentifierType::Exclusion), ('\u{11313}', '\u{11328}',  IdentifierType::Exclusion),
-        ('\u{1132a}', '\u{11330}',  IdentifierType::Exclusion), ('\u{11332}', '\u{11333}',
-         IdentifierType::Exclusion), ('\u{11335}', '\u{11339}',  IdentifierType::Exclusion),
-        ('\u{1133b}', '\u{1133b}',  IdentifierType::Uncommon_Use), ('\u{1133c}', '\u{1133c}',
-         IdentifierType::Recommended), ('\u{1133d}', '\u{11344}',  IdentifierType::Exclusion),
-        ('\u{11347}', '\u{11348}',  IdentifierType::Exclusion), ('\u{1134b}', '\u{1134d}',
-         IdentifierType::Exclusion), ('\u{11350}', '\u{11350}',  IdentifierType::Exclusion),
-        ('\u{11357}', '\u{11357}',  IdentifierType::Exclusion), ('\u{1135d}', '\u{11363}',
-         IdentifierType::Exclusion), ('\u{11366}', '\u{1136c}',  IdentifierType::Exclusion),
-        ('\u{11370}', '\u{11374}',  IdentifierType::Exclusion), ('\u{11380}', '\u{11389}',
-         IdentifierType::Exclusion), ('\u{1138b}', '\u{1138b}',  IdentifierType::Exclusion),
-        ('\u{1138e}', '\u{1138e}',  IdentifierType::Exclusion), ('\u{11390}', '\u{113b5}',
-         IdentifierType::Exclusion), ('\u{113b7}', '\u{113c0}',  IdentifierType::Exclusion),
-        ('\u{113c2}', '\u{113c2}',  IdentifierType::Exclusion), ('\u{113c5}', '\u{113c5}',
-         IdentifierType::Exclusion), ('\u{113c7}', '\u{113ca}',  IdentifierType::Exclusion),
-        ('\u{113cc}', '\u{113d3}',  IdentifierType::Exclusion), ('\u{113e1}', '\u{113e2}',
-         IdentifierType::Exclusion), ('\u{11400}', '\u{1144a}',  IdentifierType::Limited_Use),
-        ('\u{11450}', '\u{11459}',  IdentifierType::Limited_Use), ('\u{1145e}', '\u{1145e}',
-         IdentifierType::Limited_Use), ('\u{1145f}', '\u{1145f}',  IdentifierType::Limited_Use),
-        ('\u{11460}', '\u{11461}',  IdentifierType::Limited_Use), ('\u{11480}', '\u{114c5}',
-         IdentifierType::Exclusion), ('\u{114c7}', '\u{114c7}',  IdentifierType::Exclusion),
-        ('\u{114d0}', '\u{114d9}',  IdentifierType::Exclusion), ('\u{11580}', '\u{115b5}',
-         IdentifierType::Exclusion), ('\u{115b8}', '\u{115c0}',  IdentifierType::Exclusion),
-        ('\u{115d8}', '\u{115dd}',  IdentifierType::Exclusion), ('\u{11600}', '\u{11640}',
-         IdentifierType::Exclusion), ('\u{11644}', '\u{11644}',  IdentifierType::Exclusion),
-        ('\u{11650}', '\u{11659}',  IdentifierType::Exclusion), ('\u{11680}', '\u{116b7}',
-         IdentifierType::Exclusion), ('\u{116b8}', '\u{116b8}',  IdentifierType::Exclusion),
-        ('\u{116c0}', '\u{116c9}',  IdentifierType::Exclusion), ('\u{116d0}', '\u{116e3}',
-         IdentifierType::Uncommon_Use), ('\u{11700}', '\u{11719}',  IdentifierType::Exclusion),
-        ('\u{1171a}', '\u{1171a}',  IdentifierType::Exclusion), ('\u{1171d}', '\u{1172b}',
-         IdentifierType::Exclusion), ('\u{11730}', '\u{11739}',  IdentifierType::Exclusion),
-        ('\u{11740}', '\u{11746}',  IdentifierType::Exclusion), ('\u{11800}', '\u{1183a}',
-         IdentifierType::Exclusion), ('\u{118a0}', '\u{118e9}',  IdentifierType::Exclusion),
-        ('\u{118ff}', '\u{118ff}',  IdentifierType::Exclusion), ('\u{11900}', '\u{11906}',
-         IdentifierType::Exclusion), ('\u{11909}', '\u{11909}',  IdentifierType::Exclusion),
-        ('\u{1190c}', '\u{11913}',  IdentifierType::Exclusion), ('\u{11915}', '\u{11916}',
-         IdentifierType::Exclusion), ('\u{11918}', '\u{11935}',  IdentifierType::Exclusion),
-        ('\u{11937}', '\u{11938}',  IdentifierType::Exclusion), ('\u{1193b}', '\u{11943}',
-         IdentifierType::Exclusion), ('\u{11950}', '\u{11959}',  IdentifierType::Exclusion),
-        ('\u{119a0}', '\u{119a7}',  IdentifierType::Exclusion), ('\u{119aa}', '\u{119d7}',
-         IdentifierType::Exclusion), ('\u{119da}', '\u{119e1}',  IdentifierType::Exclusion),
-        ('\u{119e3}', '\u{119e4}',  IdentifierType::Exclusion), ('\u{11a00}', '\u{11a3e}',
-         IdentifierType::Exclusion), ('\u{11a47}', '\u{11a47}',  IdentifierType::Exclusion),
-        ('\u{11a50}', '\u{11a83}',  IdentifierType::Exclusion), ('\u{11a84}', '\u{11a85}',
-         IdentifierType::Exclusion), ('\u{11a86}', '\u{11a99}',  IdentifierType::Exclusion),
-        ('\u{11a9d}', '\u{11a9d}',  IdentifierType::Exclusion), ('\u{11ab0}', '\u{11abf}',
-         IdentifierType::Limited_Use), ('\u{11ac0}', '\u{11af8}',  IdentifierType::Exclusion),
-        ('\u{11b00}', '\u{11b09}',  IdentifierType::Not_XID), ('\u{11b60}', '\u{11b67}',
-         IdentifierType::Exclusion), ('\u{11bc0}', '\u{11be0}',  IdentifierType::Exclusion),
-        ('\u{11bf0}', '\u{11bf9}',  IdentifierType::Exclusion), ('\u{11c00}', '\u{11c08}',
-         IdentifierType::Exclusion), ('\u{11c0a}', '\u{11c36}',  IdentifierType::Exclusion),
-        ('\u{11c38}', '\u{11c40}',  IdentifierType::Exclusion), ('\u{11c50}', '\u{11c59}',
-         IdentifierType::Exclusion), ('\u{11c72}', '\u{11c8f}',  IdentifierType::Exclusion),
-        ('\u{11c92}', '\u{11ca7}',  IdentifierType::Exclusion), ('\u{11ca9}', '\u{11cb6}',
-         IdentifierType::Exclusion), ('\u{11d00}', '\u{11d06}',  IdentifierType::Exclusion),
-        ('\u{11d08}', '\u{11d09}',  IdentifierType::Exclusion), ('\u{11d0b}', '\u{11d36}',
-         IdentifierType::Exclusion), ('\u{11d3a}', '\u{11d3a}',  IdentifierType::Exclusion),
-        ('\u{11d3c}', '\u{11d3d}',  IdentifierType::Exclusion), ('\u{11d3f}', '\u{11d47}',
-         IdentifierType::Exclusion), ('\u{11d50}', '\u{11d59}',  IdentifierType::Exclusion),
-        ('\u{11d60}', '\u{11d65}',  IdentifierType::Exclusion), ('\u{11d67}', '\u{11d68}',
-         IdentifierType::Exclusion), ('\u{11d6a}', '\u{11d8e}',  IdentifierType::Exclusion),
-        ('\u{11d90}', '\u{11d91}',  IdentifierType::Exclusion), ('\u{11d93}', '\u{11d98}',
-         IdentifierType::Exclusion), ('\u{11da0}', '\u{11da9}',  IdentifierType::Exclusion),
-        ('\u{11db0}', '\u{11ddb}',  IdentifierType::Exclusion), ('\u{11de0}', '\u{11de9}',
-         IdentifierType::Exclusion), ('\u{11ee0}', '\u{11ef6}',  IdentifierType::Exclusion),
-        ('\u{11f00}', '\u{11f10}',  IdentifierType::Exclusion), ('\u{11f12}', '\u{11f3a}',
-         IdentifierType::Exclusion), ('\u{11f3e}', '\u{11f42}',  IdentifierType::Exclusion),
-        ('\u{11f50}', '\u{11f59}',  IdentifierType::Exclusion), ('\u{11f5a}', '\u{11f5a}',
-         IdentifierType::Exclusion), ('\u{11fb0}', '\u{11fb0}',  IdentifierType::Limited_Use),
-        ('\u{11fc0}', '\u{11ff1}',  IdentifierType::Not_XID), ('\u{11fff}', '\u{11fff}',
-         IdentifierType::Not_XID), ('\u{12000}', '\u{1236e}',  IdentifierType::Exclusion),
-        ('\u{1236f}', '\u{12398}',  IdentifierType::Exclusion), ('\u{12399}', '\u{12399}',
-         IdentifierType::Exclusion), ('\u{12400}', '\u{12462}',  IdentifierType::Exclusion),
-        ('\u{12463}', '\u{1246e}',  IdentifierType::Exclusion), ('\u{12480}', '\u{12543}',
-         IdentifierType::Exclusion), ('\u{12f90}', '\u{12ff0}',  IdentifierType::Exclusion),
-        ('\u{13000}', '\u{1342e}',  IdentifierType::Exclusion), ('\u{1342f}', '\u{1342f}',
-         IdentifierType::Exclusion), ('\u{13440}', '\u{13455}',  IdentifierType::Exclusion),
-        ('\u{13460}', '\u{143fa}',  IdentifierType::Exclusion), ('\u{14400}', '\u{14646}',
-         IdentifierType::Exclusion), ('\u{16100}', '\u{16139}',  IdentifierType::Exclusion),
-        ('\u{16800}', '\u{16a38}',  IdentifierType::Limited_Use), ('\u{16a70}', '\u{16abe}',
-         IdentifierType::Exclusion), ('\u{16ac0}', '\u{16ac9}',  IdentifierType::Exclusion),
-        ('\u{16ad0}', '\u{16aed}',  IdentifierType::Exclusion), ('\u{16af0}', '\u{16af4}',
-         IdentifierType::Exclusion), ('\u{16b00}', '\u{16b36}',  IdentifierType::Exclusion),
-        ('\u{16b40}', '\u{16b43}',  IdentifierType::Exclusion), ('\u{16b50}', '\u{16b59}',
-         IdentifierType::Exclusion), ('\u{16b63}', '\u{16b77}',  IdentifierType::Exclusion),
-        ('\u{16b7d}', '\u{16b8f}',  IdentifierType::Exclusion), ('\u{16d40}', '\u{16d6c}',
-         IdentifierType::Exclusion), ('\u{16d70}', '\u{16d79}',  IdentifierType::Exclusion),
-        ('\u{16e40}', '\u{16e7f}',  IdentifierType::Exclusion), ('\u{16ea0}', '\u{16eb8}',
-         IdentifierType::Exclusion), ('\u{16ebb}', '\u{16ed3}',  IdentifierType::Exclusion),
-        ('\u{16f00}', '\u{16f44}',  IdentifierType::Limited_Use), ('\u{16f45}', '\u{16f4a}',
-         IdentifierType::Limited_Use), ('\u{16f4f}', '\u{16f4f}',  IdentifierType::Limited_Use),
-        ('\u{16f50}', '\u{16f7e}',  IdentifierType::Limited_Use), ('\u{16f7f}', '\u{16f87}',
-         IdentifierType::Limited_Use), ('\u{16f8f}', '\u{16f9f}',  IdentifierType::Limited_Use),
-        ('\u{16fe0}', '\u{16fe0}',  IdentifierType::Exclusion), ('\u{16fe1}', '\u{16fe1}',
-         IdentifierType::Exclusion), ('\u{16fe2}', '\u{16fe2}',  IdentifierType::Not_XID),
-        ('\u{16fe3}', '\u{16fe3}',  IdentifierType::Obsolete), ('\u{16fe4}', '\u{16fe4}',
-         IdentifierType::Exclusion), ('\u{16ff0}', '\u{16ff1}',  IdentifierType::Obsolete),
-        ('\u{16ff2}', '\u{16ff6}',  IdentifierType::Technical), ('\u{17000}', '\u{187ec}',
-         IdentifierType::Exclusion), ('\u{187ed}', '\u{187f1}',  IdentifierType::Exclusion),
-        ('\u{187f2}', '\u{187f7}',  IdentifierType::Exclusion), ('\u{187f8}', '\u{187ff}',
-         IdentifierType::Exclusion), ('\u{18800}', '\u{18af2}',  IdentifierType::Exclusion),
-        ('\u{18af3}', '\u{18cd5}',  IdentifierType::Exclusion), ('\u{18cff}', '\u{18cff}',
-         IdentifierType::Exclusion), ('\u{18d00}', '\u{18d08}',  IdentifierType::Exclusion),
-        ('\u{18d09}', '\u{18d1e}',  IdentifierType::Exclusion), ('\u{18d80}', '\u{18df2}',
-         IdentifierType::Exclusion), ('\u{1aff0}', '\u{1aff3}',  IdentifierType::Uncommon_Use),
-        ('\u{1aff5}', '\u{1affb}',  IdentifierType::Uncommon_Use), ('\u{1affd}', '\u{1affe}',
-         IdentifierType::Uncommon_Use), ('\u{1b000}', '\u{1b001}',  IdentifierType::Obsolete),
-        ('\u{1b002}', '\u{1b11e}',  IdentifierType::Obsolete), ('\u{1b11f}', '\u{1b122}',
-         IdentifierType::Obsolete), ('\u{1b132}', '\u{1b132}',  IdentifierType::Obsolete),
-        ('\u{1b150}', '\u{1b152}',  IdentifierType::Obsolete), ('\u{1b155}', '\u{1b155}',
-         IdentifierType::Obsolete), ('\u{1b164}', '\u{1b167}',  IdentifierType::Obsolete),
-        ('\u{1b170}', '\u{1b2fb}',  IdentifierType::Exclusion), ('\u{1bc00}', '\u{1bc6a}',
-         IdentifierType::Exclusion), ('\u{1bc70}', '\u{1bc7c}',  IdentifierType::Exclusion),
-        ('\u{1bc80}', '\u{1bc88}',  IdentifierType::Exclusion), ('\u{1bc90}', '\u{1bc99}',
-         IdentifierType::Exclusion), ('\u{1bc9d}', '\u{1bc9e}',  IdentifierType::Exclusion),
-        ('\u{1bca0}', '\u{1bca3}',  IdentifierType::Default_Ignorable), ('\u{1cc00}', '\u{1ccd5}',
-         IdentifierType::Not_XID), ('\u{1ccd6}', '\u{1ccf9}',  IdentifierType::Not_NFKC),
-        ('\u{1ccfa}', '\u{1ccfc}',  IdentifierType::Not_XID), ('\u{1cd00}', '\u{1ceb3}',
-         IdentifierType::Not_XID), ('\u{1ceba}', '\u{1ced0}',  IdentifierType::Not_XID),
-        ('\u{1cee0}', '\u{1cef0}',  IdentifierType::Not_XID), ('\u{1cf00}', '\u{1cf2d}',
-         IdentifierType::Technical), ('\u{1cf30}', '\u{1cf46}',  IdentifierType::Technical),
-        ('\u{1d15e}', '\u{1d164}',  IdentifierType::Not_NFKC), ('\u{1d165}', '\u{1d169}',
-         IdentifierType::Technical), ('\u{1d16d}', '\u{1d172}',  IdentifierType::Technical),
-        ('\u{1d173}', '\u{1d17a}',  IdentifierType::Default_Ignorable), ('\u{1d17b}', '\u{1d182}',
-         IdentifierType::Technical), ('\u{1d185}', '\u{1d18b}',  IdentifierType::Technical),
-        ('\u{1d1aa}', '\u{1d1ad}',  IdentifierType::Technical), ('\u{1d1bb}', '\u{1d1c0}',
-         IdentifierType::Not_NFKC), ('\u{1d2c0}', '\u{1d2d3}',  IdentifierType::Not_XID),
-        ('\u{1d2e0}', '\u{1d2f3}',  IdentifierType::Not_XID), ('\u{1d360}', '\u{1d371}',
-         IdentifierType::Not_XID), ('\u{1d372}', '\u{1d378}',  IdentifierType::Not_XID),
-        ('\u{1d400}', '\u{1d454}',  IdentifierType::Not_NFKC), ('\u{1d456}', '\u{1d49c}',
-         IdentifierType::Not_NFKC), ('\u{1d49e}', '\u{1d49f}',  IdentifierType::Not_NFKC),
-        ('\u{1d4a2}', '\u{1d4a2}',  IdentifierType::Not_NFKC), ('\u{1d4a5}', '\u{1d4a6}',
-         IdentifierType::Not_NFKC), ('\u{1d4a9}', '\u{1d4ac}',  IdentifierType::Not_NFKC),
-        ('\u{1d4ae}', '\u{1d4b9}',  IdentifierType::Not_NFKC), ('\u{1d4bb}', '\u{1d4bb}',
-         IdentifierType::Not_NFKC), ('\u{1d4bd}', '\u{1d4c0}',  IdentifierType::Not_NFKC),
-        ('\u{1d4c1}', '\u{1d4c1}',  IdentifierType::Not_NFKC), ('\u{1d4c2}', '\u{1d4c3}',
-         IdentifierType::Not_NFKC), ('\u{1d4c5}', '\u{1d505}',  IdentifierType::Not_NFKC),
-        ('\u{1d507}', '\u{1d50a}',  IdentifierType::Not_NFKC), ('\u{1d50d}', '\u{1d514}',
-         IdentifierType::Not_NFKC), ('\u{1d516}', '\u{1d51c}',  IdentifierType::Not_NFKC),
-        ('\u{1d51e}', '\u{1d539}',  IdentifierType::Not_NFKC), ('\u{1d53b}', '\u{1d53e}',
-         IdentifierType::Not_NFKC), ('\u{1d540}', '\u{1d544}',  IdentifierType::Not_NFKC),
-        ('\u{1d546}', '\u{1d546}',  IdentifierType::Not_NFKC), ('\u{1d54a}', '\u{1d550}',
-         IdentifierType::Not_NFKC), ('\u{1d552}', '\u{1d6a3}',  IdentifierType::Not_NFKC),
-        ('\u{1d6a4}', '\u{1d6a5}',  IdentifierType::Not_NFKC), ('\u{1d6a8}', '\u{1d7c9}',
-         IdentifierType::Not_NFKC), ('\u{1d7ca}', '\u{1d7cb}',  IdentifierType::Not_NFKC),
-        ('\u{1d7ce}', '\u{1d7ff}',  IdentifierType::Not_NFKC), ('\u{1da00}', '\u{1da36}',
-         IdentifierType::Exclusion), ('\u{1da3b}', '\u{1da6c}',  IdentifierType::Exclusion),
-        ('\u{1da75}', '\u{1da75}',  IdentifierType::Exclusion), ('\u{1da84}', '\u{1da84}',
-         IdentifierType::Exclusion), ('\u{1da9b}', '\u{1da9f}',  IdentifierType::Exclusion),
-        ('\u{1daa1}', '\u{1daaf}',  IdentifierType::Exclusion), ('\u{1df00}', '\u{1df1e}',
-         IdentifierType::Technical), ('\u{1df25}', '\u{1df2a}',  IdentifierType::Technical),
-        ('\u{1e000}', '\u{1e006}',  IdentifierType::Exclusion), ('\u{1e008}', '\u{1e018}',
-         IdentifierType::Exclusion), ('\u{1e01b}', '\u{1e021}',  IdentifierType::Exclusion),
-        ('\u{1e023}', '\u{1e024}',  IdentifierType::Exclusion), ('\u{1e026}', '\u{1e02a}',
-         IdentifierType::Exclusion), ('\u{1e030}', '\u{1e06d}',  IdentifierType::Not_NFKC),
-        ('\u{1e08f}', '\u{1e08f}',  IdentifierType::Obsolete), ('\u{1e100}', '\u{1e12c}',
-         IdentifierType::Limited_Use), ('\u{1e130}', '\u{1e13d}',  IdentifierType::Limited_Use),
-        ('\u{1e140}', '\u{1e149}',  IdentifierType::Limited_Use), ('\u{1e14e}', '\u{1e14e}',
-         IdentifierType::Limited_Use), ('\u{1e290}', '\u{1e2ae}',  IdentifierType::Exclusion),
-        ('\u{1e2c0}', '\u{1e2f9}',  IdentifierType::Limited_Use), ('\u{1e4d0}', '\u{1e4f9}',
-         IdentifierType::Exclusion), ('\u{1e5d0}', '\u{1e5fa}',  IdentifierType::Exclusion),
-        ('\u{1e6c0}', '\u{1e6de}',  IdentifierType::Exclusion), ('\u{1e6e0}', '\u{1e6f5}',
-         IdentifierType::Exclusion), ('\u{1e6fe}', '\u{1e6ff}',  IdentifierType::Exclusion),
-        ('\u{1e7e0}', '\u{1e7e6}',  IdentifierType::Recommended), ('\u{1e7e8}', '\u{1e7eb}',
-         IdentifierType::Recommended), ('\u{1e7ed}', '\u{1e7ee}',  IdentifierType::Recommended),
-        ('\u{1e7f0}', '\u{1e7fe}',  IdentifierType::Recommended), ('\u{1e800}', '\u{1e8c4}',
-         IdentifierType::Exclusion), ('\u{1e8d0}', '\u{1e8d6}',  IdentifierType::Exclusion),
-        ('\u{1e900}', '\u{1e94a}',  IdentifierType::Limited_Use), ('\u{1e94b}', '\u{1e94b}',
-         IdentifierType::Limited_Use), ('\u{1e950}', '\u{1e959}',  IdentifierType::Limited_Use),
-        ('\u{1ec71}', '\u{1ecb4}',  IdentifierType::Not_XID), ('\u{1ed01}', '\u{1ed3d}',
-         IdentifierType::Not_XID), ('\u{1ee00}', '\u{1ee03}',  IdentifierType::Not_NFKC),
-        ('\u{1ee05}', '\u{1ee1f}',  IdentifierType::Not_NFKC), ('\u{1ee21}', '\u{1ee22}',
-         IdentifierType::Not_NFKC), ('\u{1ee24}', '\u{1ee24}',  IdentifierType::Not_NFKC),
-        ('\u{1ee27}', '\u{1ee27}',  IdentifierType::Not_NFKC), ('\u{1ee29}', '\u{1ee32}',
-         IdentifierType::Not_NFKC), ('\u{1ee34}', '\u{1ee37}',  IdentifierType::Not_NFKC),
-        ('\u{1ee39}', '\u{1ee39}',  IdentifierType::Not_NFKC), ('\u{1ee3b}', '\u{1ee3b}',
-         IdentifierType::Not_NFKC), ('\u{1ee42}', '\u{1ee42}',  IdentifierType::Not_NFKC),
-        ('\u{1ee47}', '\u{1ee47}',  IdentifierType::Not_NFKC), ('\u{1ee49}', '\u{1ee49}',
-         IdentifierType::Not_NFKC), ('\u{1ee4b}', '\u{1ee4b}',  IdentifierType::Not_NFKC),
-        ('\u{1ee4d}', '\u{1ee4f}',  IdentifierType::Not_NFKC), ('\u{1ee51}', '\u{1ee52}',
-         IdentifierType::Not_NFKC), ('\u{1ee54}', '\u{1ee54}',  IdentifierType::Not_NFKC),
-        ('\u{1ee57}', '\u{1ee57}',  IdentifierType::Not_NFKC), ('\u{1ee59}', '\u{1ee59}',
-         IdentifierType::Not_NFKC), ('\u{1ee5b}', '\u{1ee5b}',  IdentifierType::Not_NFKC),
-        ('\u{1ee5d}', '\u{1ee5d}',  IdentifierType::Not_NFKC), ('\u{1ee5f}', '\u{1ee5f}',
-         IdentifierType::Not_NFKC), ('\u{1ee61}', '\u{1ee62}',  IdentifierType::Not_NFKC),
-        ('\u{1ee64}', '\u{1ee64}',  IdentifierType::Not_NFKC), ('\u{1ee67}', '\u{1ee6a}',
-         IdentifierType::Not_NFKC), ('\u{1ee6c}', '\u{1ee72}',  IdentifierType::Not_NFKC),
-        ('\u{1ee74}', '\u{1ee77}',  IdentifierType::Not_NFKC), ('\u{1ee79}', '\u{1ee7c}',
-         IdentifierType::Not_NFKC), ('\u{1ee7e}', '\u{1ee7e}',  IdentifierType::Not_NFKC),
-        ('\u{1ee80}', '\u{1ee89}',  IdentifierType::Not_NFKC), ('\u{1ee8b}', '\u{1ee9b}',
-         IdentifierType::Not_NFKC), ('\u{1eea1}', '\u{1eea3}',  IdentifierType::Not_NFKC),
-        ('\u{1eea5}', '\u{1eea9}',  IdentifierType::Not_NFKC), ('\u{1eeab}', '\u{1eebb}',
-         IdentifierType::Not_NFKC), ('\u{1eef0}', '\u{1eef1}',  IdentifierType::Not_XID),
-        ('\u{1f000}', '\u{1f02b}',  IdentifierType::Not_XID), ('\u{1f030}', '\u{1f093}',
-         IdentifierType::Not_XID), ('\u{1f0a0}', '\u{1f0ae}',  IdentifierType::Not_XID),
-        ('\u{1f0b1}', '\u{1f0be}',  IdentifierType::Not_XID), ('\u{1f0bf}', '\u{1f0bf}',
-         IdentifierType::Not_XID), ('\u{1f0c1}', '\u{1f0cf}',  IdentifierType::Not_XID),
-        ('\u{1f0d1}', '\u{1f0df}',  IdentifierType::Not_XID), ('\u{1f0e0}', '\u{1f0f5}',
-         IdentifierType::Not_XID), ('\u{1f100}', '\u{1f10a}',  IdentifierType::Not_NFKC),
-        ('\u{1f10b}', '\u{1f10c}',  IdentifierType::Not_XID), ('\u{1f10d}', '\u{1f10f}',
-         IdentifierType::Not_XID), ('\u{1f110}', '\u{1f12e}',  IdentifierType::Not_NFKC),
-        ('\u{1f12f}', '\u{1f12f}',  IdentifierType::Not_XID), ('\u{1f130}', '\u{1f130}',
-         IdentifierType::Not_NFKC), ('\u{1f131}', '\u{1f131}',  IdentifierType::Not_NFKC),
-        ('\u{1f132}', '\u{1f13c}',  IdentifierType::Not_NFKC), ('\u{1f13d}', '\u{1f13d}',
-         IdentifierType::Not_NFKC), ('\u{1f13e}', '\u{1f13e}',  IdentifierType::Not_NFKC),
-        ('\u{1f13f}', '\u{1f13f}',  IdentifierType::Not_NFKC), ('\u{1f140}', '\u{1f141}',
-         IdentifierType::Not_NFKC), ('\u{1f142}', '\u{1f142}',  IdentifierType::Not_NFKC),
-        ('\u{1f143}', '\u{1f145}',  IdentifierType::Not_NFKC), ('\u{1f146}', '\u{1f146}',
-         IdentifierType::Not_NFKC), ('\u{1f147}', '\u{1f149}',  IdentifierType::Not_NFKC),
-        ('\u{1f14a}', '\u{1f14e}',  IdentifierType::Not_NFKC), ('\u{1f14f}', '\u{1f14f}',
-         IdentifierType::Not_NFKC), ('\u{1f150}', '\u{1f156}',  IdentifierType::Not_XID),
-        ('\u{1f157}', '\u{1f157}',  IdentifierType::Not_XID), ('\u{1f158}', '\u{1f15e}',
-         IdentifierType::Not_XID), ('\u{1f15f}', '\u{1f15f}',  IdentifierType::Not_XID),
-        ('\u{1f160}', '\u{1f169}',  IdentifierType::Not_XID), ('\u{1f16a}', '\u{1f16b}',
-         IdentifierType::Not_NFKC), ('\u{1f16c}', '\u{1f16c}',  IdentifierType::Not_NFKC),
-        ('\u{1f16d}', '\u{1f16f}',  IdentifierType::Not_XID), ('\u{1f170}', '\u{1f178}',
-         IdentifierType::Not_XID), ('\u{1f179}', '\u{1f179}',  IdentifierType::Not_XID),
-        ('\u{1f17a}', '\u{1f17a}',  IdentifierType::Not_XID), ('\u{1f17b}', '\u{1f17c}',
-         IdentifierType::Not_XID), ('\u{1f17d}', '\u{1f17e}',  IdentifierType::Not_XID),
-        ('\u{1f17f}', '\u{1f17f}',  IdentifierType::Not_XID), ('\u{1f180}', '\u{1f189}',
-         IdentifierType::Not_XID), ('\u{1f18a}', '\u{1f18d}',  IdentifierType::Not_XID),
-        ('\u{1f18e}', '\u{1f18f}',  IdentifierType::Not_XID), ('\u{1f190}', '\u{1f190}',
-         IdentifierType::Not_NFKC), ('\u{1f191}', '\u{1f19a}',  IdentifierType::Not_XID),
-        ('\u{1f19b}', '\u{1f1ac}',  IdentifierType::Not_XID), ('\u{1f1ad}', '\u{1f1ad}',
-         IdentifierType::Not_XID), ('\u{1f1e6}', '\u{1f1ff}',  IdentifierType::Not_XID),
-        ('\u{1f200}', '\u{1f200}',  IdentifierType::Not_NFKC), ('\u{1f201}', '\u{1f202}',
-         IdentifierType::Not_NFKC), ('\u{1f210}', '\u{1f231}',  IdentifierType::Not_NFKC),
-        ('\u{1f232}', '\u{1f23a}',  IdentifierType::Not_NFKC), ('\u{1f23b}', '\u{1f23b}',
-         IdentifierType::Not_NFKC), ('\u{1f240}', '\u{1f248}',  IdentifierType::Not_NFKC),
-        ('\u{1f250}', '\u{1f251}',  IdentifierType::Not_NFKC), ('\u{1f260}', '\u{1f265}',
-         IdentifierType::Not_XID), ('\u{1f300}', '\u{1f320}',  IdentifierType::Not_XID),
-        ('\u{1f321}', '\u{1f32c}',  IdentifierType::Not_XID), ('\u{1f32d}', '\u{1f32f}',
-         IdentifierType::Not_XID), ('\u{1f330}', '\u{1f335}',  IdentifierType::Not_XID),
-        ('\u{1f336}', '\u{1f336}',  IdentifierType::Not_XID), ('\u{1f337}', '\u{1f37c}',
-         IdentifierType::Not_XID), ('\u{1f37d}', '\u{1f37d}',  IdentifierType::Not_XID),
-        ('\u{1f37e}', '\u{1f37f}',  IdentifierType::Not_XID), ('\u{1f380}', '\u{1f393}',
-         IdentifierType::Not_XID), ('\u{1f394}', '\u{1f39f}',  IdentifierType::Not_XID),
-        ('\u{1f3a0}', '\u{1f3c4}',  IdentifierType::Not_XID), ('\u{1f3c5}', '\u{1f3c5}',
-         IdentifierType::Not_XID), ('\u{1f3c6}', '\u{1f3ca}',  IdentifierType::Not_XID),
-        ('\u{1f3cb}', '\u{1f3ce}',  IdentifierType::Not_XID), ('\u{1f3cf}', '\u{1f3d3}',
-         IdentifierType::Not_XID), ('\u{1f3d4}', '\u{1f3df}',  IdentifierType::Not_XID),
-        ('\u{1f3e0}', '\u{1f3f0}',  IdentifierType::Not_XID), ('\u{1f3f1}', '\u{1f3f7}',
-         IdentifierType::Not_XID), ('\u{1f3f8}', '\u{1f3ff}',  IdentifierType::Not_XID),
-        ('\u{1f400}', '\u{1f43e}',  IdentifierType::Not_XID), ('\u{1f43f}', '\u{1f43f}',
-         IdentifierType::Not_XID), ('\u{1f440}', '\u{1f440}',  IdentifierType::Not_XID),
-        ('\u{1f441}', '\u{1f441}',  IdentifierType::Not_XID), ('\u{1f442}', '\u{1f4f7}',
-         IdentifierType::Not_XID), ('\u{1f4f8}', '\u{1f4f8}',  IdentifierType::Not_XID),
-        ('\u{1f4f9}', '\u{1f4fc}',  IdentifierType::Not_XID), ('\u{1f4fd}', '\u{1f4fe}',
-         IdentifierType::Not_XID), ('\u{1f4ff}', '\u{1f4ff}',  IdentifierType::Not_XID),
-        ('\u{1f500}', '\u{1f53d}',  IdentifierType::Not_XID), ('\u{1f53e}', '\u{1f53f}',
-         IdentifierType::Not_XID), ('\u{1f540}', '\u{1f543}',  IdentifierType::Not_XID),
-        ('\u{1f544}', '\u{1f54a}',  IdentifierType::Not_XID), ('\u{1f54b}', '\u{1f54e}',
-         IdentifierType::Not_XID), ('\u{1f550}', '\u{1f567}',  IdentifierType::Not_XID),
-        ('\u{1f568}', '\u{1f579}',  IdentifierType::Not_XID), ('\u{1f57a}', '\u{1f57a}',
-         IdentifierType::Not_XID), ('\u{1f57b}', '\u{1f5a3}',  IdentifierType::Not_XID),
-        ('\u{1f5a4}', '\u{1f5a4}',  IdentifierType::Not_XID), ('\u{1f5a5}', '\u{1f5fa}',
-         IdentifierType::Not_XID), ('\u{1f5fb}', '\u{1f5ff}',  IdentifierType::Not_XID),
-        ('\u{1f600}', '\u{1f600}',  IdentifierType::Not_XID), ('\u{1f601}', '\u{1f610}',
-         IdentifierType::Not_XID), ('\u{1f611}', '\u{1f611}',  IdentifierType::Not_XID),
-        ('\u{1f612}', '\u{1f614}',  IdentifierType::Not_XID), ('\u{1f615}', '\u{1f615}',
-         IdentifierType::Not_XID), ('\u{1f616}', '\u{1f616}',  IdentifierType::Not_XID),
-        ('\u{1f617}', '\u{1f617}',  IdentifierType::Not_XID), ('\u{1f618}', '\u{1f618}',
-         IdentifierType::Not_XID), ('\u{1f619}', '\u{1f619}',  IdentifierType::Not_XID),
-        ('\u{1f61a}', '\u{1f61a}',  IdentifierType::Not_XID), ('\u{1f61b}', '\u{1f61b}',
-         IdentifierType::Not_XID), ('\u{1f61c}', '\u{1f61e}',  IdentifierType::Not_XID),
-        ('\u{1f61f}', '\u{1f61f}',  IdentifierType::Not_XID), ('\u{1f620}', '\u{1f625}',
-         IdentifierType::Not_XID), ('\u{1f626}', '\u{1f627}',  IdentifierType::Not_XID),
-        ('\u{1f628}', '\u{1f62b}',  IdentifierType::Not_XID), ('\u{1f62c}', '\u{1f62c}',
-         IdentifierType::Not_XID), ('\u{1f62d}', '\u{1f62d}',  IdentifierType::Not_XID),
-        ('\u{1f62e}', '\u{1f62f}',  IdentifierType::Not_XID), ('\u{1f630}', '\u{1f633}',
-         IdentifierType::Not_XID), ('\u{1f634}', '\u{1f634}',  IdentifierType::Not_XID),
-        ('\u{1f635}', '\u{1f640}',  IdentifierType::Not_XID), ('\u{1f641}', '\u{1f642}',
-         IdentifierType::Not_XID), ('\u{1f643}', '\u{1f644}',  IdentifierType::Not_XID),
-        ('\u{1f645}', '\u{1f64f}',  IdentifierType::Not_XID), ('\u{1f650}', '\u{1f67f}',
-         IdentifierType::Not_XID), ('\u{1f680}', '\u{1f6c5}',  IdentifierType::Not_XID),
-        ('\u{1f6c6}', '\u{1f6cf}',  IdentifierType::Not_XID), ('\u{1f6d0}', '\u{1f6d0}',
-         IdentifierType::Not_XID), ('\u{1f6d1}', '\u{1f6d2}',  IdentifierType::Not_XID),
-        ('\u{1f6d3}', '\u{1f6d4}',  IdentifierType::Not_XID), ('\u{1f6d5}', '\u{1f6d5}',
-         IdentifierType::Not_XID), ('\u{1f6d6}', '\u{1f6d7}',  IdentifierType::Not_XID),
-        ('\u{1f6d8}', '\u{1f6d8}',  IdentifierType::Not_XID), ('\u{1f6dc}', '\u{1f6dc}',
-         IdentifierType::Not_XID), ('\u{1f6dd}', '\u{1f6df}',  IdentifierType::Not_XID),
-        ('\u{1f6e0}', '\u{1f6ec}',  IdentifierType::Not_XID), ('\u{1f6f0}', '\u{1f6f3}',
-         IdentifierType::Not_XID), ('\u{1f6f4}', '\u{1f6f6}',  IdentifierType::Not_XID),
-        ('\u{1f6f7}', '\u{1f6f8}',  IdentifierType::Not_XID), ('\u{1f6f9}', '\u{1f6f9}',
-         IdentifierType::Not_XID), ('\u{1f6fa}', '\u{1f6fa}',  IdentifierType::Not_XID),
-        ('\u{1f6fb}', '\u{1f6fc}',  IdentifierType::Not_XID), ('\u{1f700}', '\u{1f773}',
-         IdentifierType::Not_XID), ('\u{1f774}', '\u{1f776}',  IdentifierType::Not_XID),
-        ('\u{1f777}', '\u{1f77a}',  IdentifierType::Not_XID), ('\u{1f77b}', '\u{1f77f}',
-         IdentifierType::Not_XID), ('\u{1f780}', '\u{1f7d4}',  IdentifierType::Not_XID),
-        ('\u{1f7d5}', '\u{1f7d8}',  IdentifierType::Not_XID), ('\u{1f7d9}', '\u{1f7d9}',
-         IdentifierType::Not_XID), ('\u{1f7e0}', '\u{1f7eb}',  IdentifierType::Not_XID),
-        ('\u{1f7f0}', '\u{1f7f0}',  IdentifierType::Not_XID), ('\u{1f800}', '\u{1f80b}',
-         IdentifierType::Not_XID), ('\u{1f810}', '\u{1f847}',  IdentifierType::Not_XID),
-        ('\u{1f850}', '\u{1f859}',  IdentifierType::Not_XID), ('\u{1f860}', '\u{1f887}',
-         IdentifierType::Not_XID), ('\u{1f890}', '\u{1f8ad}',  IdentifierType::Not_XID),
-        ('\u{1f8b0}', '\u{1f8b1}',  IdentifierType::Not_XID), ('\u{1f8b2}', '\u{1f8bb}',
-         IdentifierType::Not_XID), ('\u{1f8c0}', '\u{1f8c1}',  IdentifierType::Not_XID),
-        ('\u{1f8d0}', '\u{1f8d8}',  IdentifierType::Not_XID), ('\u{1f900}', '\u{1f90b}',
-         IdentifierType::Not_XID), ('\u{1f90c}', '\u{1f90c}',  IdentifierType::Not_XID),
-        ('\u{1f90d}', '\u{1f90f}',  IdentifierType::Not_XID), ('\u{1f910}', '\u{1f918}',
-         IdentifierType::Not_XID), ('\u{1f919}', '\u{1f91e}',  IdentifierType::Not_XID),
-        ('\u{1f91f}', '\u{1f91f}',  IdentifierType::Not_XID), ('\u{1f920}', '\u{1f927}',
-         IdentifierType::Not_XID), ('\u{1f928}', '\u{1f92f}',  IdentifierType::Not_XID),
-        ('\u{1f930}', '\u{1f930}',  IdentifierType::Not_XID), ('\u{1f931}', '\u{1f932}',
-         IdentifierType::Not_XID), ('\u{1f933}', '\u{1f93e}',  IdentifierType::Not_XID),
-        ('\u{1f93f}', '\u{1f93f}',  IdentifierType::Not_XID), ('\u{1f940}', '\u{1f94b}',
-         IdentifierType::Not_XID), ('\u{1f94c}', '\u{1f94c}',  IdentifierType::Not_XID),
-        ('\u{1f94d}', '\u{1f94f}',  IdentifierType::Not_XID), ('\u{1f950}', '\u{1f95e}',
-         IdentifierType::Not_XID), ('\u{1f95f}', '\u{1f96b}',  IdentifierType::Not_XID),
-        ('\u{1f96c}', '\u{1f970}',  IdentifierType::Not_XID), ('\u{1f971}', '\u{1f971}',
-         IdentifierType::Not_XID), ('\u{1f972}', '\u{1f972}',  IdentifierType::Not_XID),
-        ('\u{1f973}', '\u{1f976}',  IdentifierType::Not_XID), ('\u{1f977}', '\u{1f978}',
-         IdentifierType::Not_XID), ('\u{1f979}', '\u{1f979}',  IdentifierType::Not_XID),
-        ('\u{1f97a}', '\u{1f97a}',  IdentifierType::Not_XID), ('\u{1f97b}', '\u{1f97b}',
-         IdentifierType::Not_XID), ('\u{1f97c}', '\u{1f97f}',  IdentifierType::Not_XID),
-        ('\u{1f980}', '\u{1f984}',  IdentifierType::Not_XID), ('\u{1f985}', '\u{1f991}',
-         IdentifierType::Not_XID), ('\u{1f992}', '\u{1f997}',  IdentifierType::Not_XID),
-        ('\u{1f998}', '\u{1f9a2}',  IdentifierType::Not_XID), ('\u{1f9a3}', '\u{1f9a4}',
-         IdentifierType::Not_XID), ('\u{1f9a5}', '\u{1f9aa}',  IdentifierType::Not_XID),
-        ('\u{1f9ab}', '\u{1f9ad}',  IdentifierType::Not_XID), ('\u{1f9ae}', '\u{1f9af}',
-         IdentifierType::Not_XID), ('\u{1f9b0}', '\u{1f9b9}',  IdentifierType::Not_XID),
-        ('\u{1f9ba}', '\u{1f9bf}',  IdentifierType::Not_XID), ('\u{1f9c0}', '\u{1f9c0}',
-         IdentifierType::Not_XID), ('\u{1f9c1}', '\u{1f9c2}',  IdentifierType::Not_XID),
-        ('\u{1f9c3}', '\u{1f9ca}',  IdentifierType::Not_XID), ('\u{1f9cb}', '\u{1f9cb}',
-         IdentifierType::Not_XID), ('\u{1f9cc}', '\u{1f9cc}',  IdentifierType::Not_XID),
-        ('\u{1f9cd}', '\u{1f9cf}',  IdentifierType::Not_XID), ('\u{1f9d0}', '\u{1f9e6}',
-         IdentifierType::Not_XID), ('\u{1f9e7}', '\u{1f9ff}',  IdentifierType::Not_XID),
-        ('\u{1fa00}', '\u{1fa53}',  IdentifierType::Not_XID), ('\u{1fa54}', '\u{1fa57}',
-         IdentifierType::Not_XID), ('\u{1fa60}', '\u{1fa6d}',  IdentifierType::Not_XID),
-        ('\u{1fa70}', '\u{1fa73}',  IdentifierType::Not_XID), ('\u{1fa74}', '\u{1fa74}',
-         IdentifierType::Not_XID), ('\u{1fa75}', '\u{1fa77}',  IdentifierType::Not_XID),
-        ('\u{1fa78}', '\u{1fa7a}',  IdentifierType::Not_XID), ('\u{1fa7b}', '\u{1fa7c}',
-         IdentifierType::Not_XID), ('\u{1fa80}', '\u{1fa82}',  IdentifierType::Not_XID),
-        ('\u{1fa83}', '\u{1fa86}',  IdentifierType::Not_XID), ('\u{1fa87}', '\u{1fa88}',
-         IdentifierType::Not_XID), ('\u{1fa89}', '\u{1fa89}',  IdentifierType::Not_XID),
-        ('\u{1fa8a}', '\u{1fa8a}',  IdentifierType::Not_XID), ('\u{1fa8e}', '\u{1fa8e}',
-         IdentifierType::Not_XID), ('\u{1fa8f}', '\u{1fa8f}',  IdentifierType::Not_XID),
-        ('\u{1fa90}', '\u{1fa95}',  IdentifierType::Not_XID), ('\u{1fa96}', '\u{1faa8}',
-         IdentifierType::Not_XID), ('\u{1faa9}', '\u{1faac}',  IdentifierType::Not_XID),
-        ('\u{1faad}', '\u{1faaf}',  IdentifierType::Not_XID), ('\u{1fab0}', '\u{1fab6}',
-         IdentifierType::Not_XID), ('\u{1fab7}', '\u{1faba}',  IdentifierType::Not_XID),
-        ('\u{1fabb}', '\u{1fabd}',  IdentifierType::Not_XID), ('\u{1fabe}', '\u{1fabe}',
-         IdentifierType::Not_XID), ('\u{1fabf}', '\u{1fabf}',  IdentifierType::Not_XID),
-        ('\u{1fac0}', '\u{1fac2}',  IdentifierType::Not_XID), ('\u{1fac3}', '\u{1fac5}',
-         IdentifierType::Not_XID), ('\u{1fac6}', '\u{1fac6}',  IdentifierType::Not_XID),
-        ('\u{1fac8}', '\u{1fac8}',  IdentifierType::Not_XID), ('\u{1facd}', '\u{1facd}',
-         IdentifierType::Not_XID), ('\u{1face}', '\u{1facf}',  IdentifierType::Not_XID),
-        ('\u{1fad0}', '\u{1fad6}',  IdentifierType::Not_XID), ('\u{1fad7}', '\u{1fad9}',
-         IdentifierType::Not_XID), ('\u{1fada}', '\u{1fadb}',  IdentifierType::Not_XID),
-        ('\u{1fadc}', '\u{1fadc}',  IdentifierType::Not_XID), ('\u{1fadf}', '\u{1fadf}',
-         IdentifierType::Not_XID), ('\u{1fae0}', '\u{1fae7}',  IdentifierType::Not_XID),
-        ('\u{1fae8}', '\u{1fae8}',  IdentifierType::Not_XID), ('\u{1fae9}', '\u{1fae9}',
-         IdentifierType::Not_XID), ('\u{1faea}', '\u{1faea}',  IdentifierType::Not_XID),
-        ('\u{1faef}', '\u{1faef}',  IdentifierType::Not_XID), ('\u{1faf0}', '\u{1faf6}',
-         IdentifierType::Not_XID), ('\u{1faf7}', '\u{1faf8}',  IdentifierType::Not_XID),
-        ('\u{1fb00}', '\u{1fb92}',  IdentifierType::Not_XID), ('\u{1fb94}', '\u{1fbca}',
-         IdentifierType::Not_XID), ('\u{1fbcb}', '\u{1fbef}',  IdentifierType::Not_XID),
-        ('\u{1fbf0}', '\u{1fbf9}',  IdentifierType::Not_NFKC), ('\u{1fbfa}', '\u{1fbfa}',
-         IdentifierType::Not_XID), ('\u{20000}', '\u{2070d}',  IdentifierType::Uncommon_Use),
-        ('\u{2070e}', '\u{2070e}',  IdentifierType::Recommended), ('\u{2070f}', '\u{20730}',
-         IdentifierType::Uncommon_Use), ('\u{20731}', '\u{20731}',  IdentifierType::Recommended),
-        ('\u{20732}', '\u{20778}',  IdentifierType::Uncommon_Use), ('\u{20779}', '\u{20779}',
-         IdentifierType::Recommended), ('\u{2077a}', '\u{20c52}',  IdentifierType::Uncommon_Use),
-        ('\u{20c53}', '\u{20c53}',  IdentifierType::Recommended), ('\u{20c54}', '\u{20c77}',
-         IdentifierType::Uncommon_Use), ('\u{20c78}', '\u{20c78}',  IdentifierType::Recommended),
-        ('\u{20c79}', '\u{20c95}',  IdentifierType::Uncommon_Use), ('\u{20c96}', '\u{20c96}',
-         IdentifierType::Recommended), ('\u{20c97}', '\u{20cce}',  IdentifierType::Uncommon_Use),
-        ('\u{20ccf}', '\u{20ccf}',  IdentifierType::Recommended), ('\u{20cd0}', '\u{20cd4}',
-         IdentifierType::Uncommon_Use), ('\u{20cd5}', '\u{20cd5}',  IdentifierType::Recommended),
-        ('\u{20cd6}', '\u{20d14}',  IdentifierType::Uncommon_Use), ('\u{20d15}', '\u{20d15}',
-         IdentifierType::Recommended), ('\u{20d16}', '\u{20d7b}',  IdentifierType::Uncommon_Use),
-        ('\u{20d7c}', '\u{20d7c}',  IdentifierType::Recommended), ('\u{20d7d}', '\u{20d7e}',
-         IdentifierType::Uncommon_Use), ('\u{20d7f}', '\u{20d7f}',  IdentifierType::Recommended),
-        ('\u{20d80}', '\u{20e0d}',  IdentifierType::Uncommon_Use), ('\u{20e0e}', '\u{20e0f}',
-         IdentifierType::Recommended), ('\u{20e10}', '\u{20e76}',  IdentifierType::Uncommon_Use),
-        ('\u{20e77}', '\u{20e77}',  IdentifierType::Recommended), ('\u{20e78}', '\u{20e9c}',
-         IdentifierType::Uncommon_Use), ('\u{20e9d}', '\u{20e9d}',  IdentifierType::Recommended),
-        ('\u{20e9e}', '\u{20ea1}',  IdentifierType::Uncommon_Use), ('\u{20ea2}', '\u{20ea2}',
-         IdentifierType::Recommended), ('\u{20ea3}', '\u{20ed6}',  IdentifierType::Uncommon_Use),
-        ('\u{20ed7}', '\u{20ed7}',  IdentifierType::Recommended), ('\u{20ed8}', '\u{20ef8}',
-         IdentifierType::Uncommon_Use), ('\u{20ef9}', '\u{20efa}',  IdentifierType::Recommended),
-        ('\u{20efb}', '\u{20f2c}',  IdentifierType::Uncommon_Use), ('\u{20f2d}', '\u{20f2e}',
-         IdentifierType::Recommended), ('\u{20f2f}', '\u{20f4b}',  IdentifierType::Uncommon_Use),
-        ('\u{20f4c}', '\u{20f4c}',  IdentifierType::Recommended), ('\u{20f4d}', '\u{20fb3}',
-         IdentifierType::Uncommon_Use), ('\u{20fb4}', '\u{20fb4}',  IdentifierType::Recommended),
-        ('\u{20fb5}', '\u{20fbb}',  IdentifierType::Uncommon_Use), ('\u{20fbc}', '\u{20fbc}',
-         IdentifierType::Recommended), ('\u{20fbd}', '\u{20fe9}',  IdentifierType::Uncommon_Use),
-        ('\u{20fea}', '\u{20fea}',  IdentifierType::Recommended), ('\u{20feb}', '\u{2105b}',
-         IdentifierType::Uncommon_Use), ('\u{2105c}', '\u{2105c}',  IdentifierType::Recommended),
-        ('\u{2105d}', '\u{2106e}',  IdentifierType::Uncommon_Use), ('\u{2106f}', '\u{2106f}',
-         IdentifierType::Recommended), ('\u{21070}', '\u{21074}',  IdentifierType::Uncommon_Use),
-        ('\u{21075}', '\u{21076}',  IdentifierType::Recommended), ('\u{21077}', '\u{2107a}',
-         IdentifierType::Uncommon_Use), ('\u{2107b}', '\u{2107b}',  IdentifierType::Recommended),
-        ('\u{2107c}', '\u{210c0}',  IdentifierType::Uncommon_Use), ('\u{210c1}', '\u{210c1}',
-         IdentifierType::Recommended), ('\u{210c2}', '\u{210c8}',  IdentifierType::Uncommon_Use),
-        ('\u{210c9}', '\u{210c9}',  IdentifierType::Recommended), ('\u{210ca}', '\u{211d8}',
-         IdentifierType::Uncommon_Use), ('\u{211d9}', '\u{211d9}',  IdentifierType::Recommended),
-        ('\u{211da}', '\u{220c6}',  IdentifierType::Uncommon_Use), ('\u{220c7}', '\u{220c7}',
-         IdentifierType::Recommended), ('\u{220c8}', '\u{227b4}',  IdentifierType::Uncommon_Use),
-        ('\u{227b5}', '\u{227b5}',  IdentifierType::Recommended), ('\u{227b6}', '\u{22ad4}',
-         IdentifierType::Uncommon_Use), ('\u{22ad5}', '\u{22ad5}',  IdentifierType::Recommended),
-        ('\u{22ad6}', '\u{22b42}',  IdentifierType::Uncommon_Use), ('\u{22b43}', '\u{22b43}',
-         IdentifierType::Recommended), ('\u{22b44}', '\u{22bc9}',  IdentifierType::Uncommon_Use),
-        ('\u{22bca}', '\u{22bca}',  IdentifierType::Recommended), ('\u{22bcb}', '\u{22c50}',
-         IdentifierType::Uncommon_Use), ('\u{22c51}', '\u{22c51}',  IdentifierType::Recommended),
-        ('\u{22c52}', '\u{22c54}',  IdentifierType::Uncommon_Use), ('\u{22c55}', '\u{22c55}',
-         IdentifierType::Recommended), ('\u{22c56}', '\u{22cc1}',  IdentifierType::Uncommon_Use),
-        ('\u{22cc2}', '\u{22cc2}',  IdentifierType::Recommended), ('\u{22cc3}', '\u{22d07}',
-         IdentifierType::Uncommon_Use), ('\u{22d08}', '\u{22d08}',  IdentifierType::Recommended),
-        ('\u{22d09}', '\u{22d4b}',  IdentifierType::Uncommon_Use), ('\u{22d4c}', '\u{22d4c}',
-         IdentifierType::Recommended), ('\u{22d4d}', '\u{22d66}',  IdentifierType::Uncommon_Use),
-        ('\u{22d67}', '\u{22d67}',  IdentifierType::Recommended), ('\u{22d68}', '\u{22eb2}',
-         IdentifierType::Uncommon_Use), ('\u{22eb3}', '\u{22eb3}',  IdentifierType::Recommended),
-        ('\u{22eb4}', '\u{23cb6}',  IdentifierType::Uncommon_Use), ('\u{23cb7}', '\u{23cb7}',
-         IdentifierType::Recommended), ('\u{23cb8}', '\u{244d2}',  IdentifierType::Uncommon_Use),
-        ('\u{244d3}', '\u{244d3}',  IdentifierType::Recommended), ('\u{244d4}', '\u{24db7}',
-         IdentifierType::Uncommon_Use), ('\u{24db8}', '\u{24db8}',  IdentifierType::Recommended),
-        ('\u{24db9}', '\u{24de9}',  IdentifierType::Uncommon_Use), ('\u{24dea}', '\u{24dea}',
-         IdentifierType::Recommended), ('\u{24deb}', '\u{2512a}',  IdentifierType::Uncommon_Use),
-        ('\u{2512b}', '\u{2512b}',  IdentifierType::Recommended), ('\u{2512c}', '\u{26257}',
-         IdentifierType::Uncommon_Use), ('\u{26258}', '\u{26258}',  IdentifierType::Recommended),
-        ('\u{26259}', '\u{267cb}',  IdentifierType::Uncommon_Use), ('\u{267cc}', '\u{267cc}',
-         IdentifierType::Recommended), ('\u{267cd}', '\u{269f1}',  IdentifierType::Uncommon_Use),
-        ('\u{269f2}', '\u{269f2}',  IdentifierType::Recommended), ('\u{269f3}', '\u{269f9}',
-         IdentifierType::Uncommon_Use), ('\u{269fa}', '\u{269fa}',  IdentifierType::Recommended),
-        ('\u{269fb}', '\u{27a3d}',  IdentifierType::Uncommon_Use), ('\u{27a3e}', '\u{27a3e}',
-         IdentifierType::Recommended), ('\u{27a3f}', '\u{2815c}',  IdentifierType::Uncommon_Use),
-        ('\u{2815d}', '\u{2815d}',  IdentifierType::Recommended), ('\u{2815e}', '\u{28206}',
-         IdentifierType::Uncommon_Use), ('\u{28207}', '\u{28207}',  IdentifierType::Recommended),
-        ('\u{28208}', '\u{282e1}',  IdentifierType::Uncommon_Use), ('\u{282e2}', '\u{282e2}',
-         IdentifierType::Recommended), ('\u{282e3}', '\u{28cc9}',  IdentifierType::Uncommon_Use),
-        ('\u{28cca}', '\u{28cca}',  IdentifierType::Recommended), ('\u{28ccb}', '\u{28ccc}',
-         IdentifierType::Uncommon_Use), ('\u{28ccd}', '\u{28ccd}',  IdentifierType::Recommended),
-        ('\u{28cce}', '\u{28cd1}',  IdentifierType::Uncommon_Use), ('\u{28cd2}', '\u{28cd2}',
-         IdentifierType::Recommended), ('\u{28cd3}', '\u{29d97}',  IdentifierType::Uncommon_Use),
-        ('\u{29d98}', '\u{29d98}',  IdentifierType::Recommended), ('\u{29d99}', '\u{2a6d6}',
-         IdentifierType::Uncommon_Use), ('\u{2a6d7}', '\u{2a6dd}',  IdentifierType::Uncommon_Use),
-        ('\u{2a6de}', '\u{2a6df}',  IdentifierType::Uncommon_Use), ('\u{2a700}', '\u{2b734}',
-         IdentifierType::Uncommon_Use), ('\u{2b735}', '\u{2b738}',  IdentifierType::Uncommon_Use),
-        ('\u{2b739}', '\u{2b739}',  IdentifierType::Uncommon_Use), ('\u{2b73a}', '\u{2b73f}',
-         IdentifierType::Uncommon_Use), ('\u{2b740}', '\u{2b81d}',  IdentifierType::Uncommon_Use),
-        ('\u{2b820}', '\u{2cea1}',  IdentifierType::Uncommon_Use), ('\u{2cea2}', '\u{2cead}',
-         IdentifierType::Uncommon_Use), ('\u{2ceb0}', '\u{2ebe0}',  IdentifierType::Uncommon_Use),
-        ('\u{2ebf0}', '\u{2ee5d}',  IdentifierType::Uncommon_Use), ('\u{2f800}', '\u{2fa1d}',
-         IdentifierType::Not_NFKC), ('\u{30000}', '\u{3134a}',  IdentifierType::Uncommon_Use),
-        ('\u{31350}', '\u{323af}',  IdentifierType::Uncommon_Use), ('\u{323b0}', '\u{33479}',
-         IdentifierType::Uncommon_Use), ('\u{e0001}', '\u{e0001}',  IdentifierType::Deprecated),
-        ('\u{e0020}', '\u{e007f}',  IdentifierType::Default_Ignorable), ('\u{e0100}', '\u{e01ef}',
-         IdentifierType::Default_Ignorable)
+    const IDENTIFIER_TYPES: &[Option<IdentifierType>] = &[
+        None, Some(IdentifierType::Not_Character), Some(IdentifierType::Deprecated),
+        Some(IdentifierType::Default_Ignorable), Some(IdentifierType::Not_NFKC),
+        Some(IdentifierType::Not_XID), Some(IdentifierType::Exclusion),
+        Some(IdentifierType::Obsolete), Some(IdentifierType::Technical),
+        Some(IdentifierType::Uncommon_Use), Some(IdentifierType::Limited_Use),
+        Some(IdentifierType::Inclusion), Some(IdentifierType::Recommended)
     ];
 
 }
 
 pub mod default_ignorable_code_point {
 
+    use super::util::Trie;
+
     #[inline]
     pub fn default_ignorable_code_point(c: char) -> bool {
-        match c as usize {
-            _ => super::util::bsearch_range_table(c, DEFAULT_IGNORABLE)
-        }
+        super::util::trie_bitset_contains(c, &DEFAULT_IGNORABLE)
     }
 
     // Default ignorable code point table:
-    const DEFAULT_IGNORABLE: &'static [(char, char)] = &[
-        ('\u{ad}', '\u{ad}'), ('\u{34f}', '\u{34f}'), ('\u{61c}', '\u{61c}'), ('\u{115f}',
-        '\u{1160}'), ('\u{17b4}', '\u{17b5}'), ('\u{180b}', '\u{180d}'), ('\u{180e}', '\u{180e}'),
-        ('\u{180f}', '\u{180f}'), ('\u{200b}', '\u{200f}'), ('\u{202a}', '\u{202e}'), ('\u{2060}',
-        '\u{2064}'), ('\u{2065}', '\u{2065}'), ('\u{2066}', '\u{206f}'), ('\u{3164}', '\u{3164}'),
-        ('\u{fe00}', '\u{fe0f}'), ('\u{feff}', '\u{feff}'), ('\u{ffa0}', '\u{ffa0}'), ('\u{fff0}',
-        '\u{fff8}'), ('\u{1bca0}', '\u{1bca3}'), ('\u{1d173}', '\u{1d17a}'), ('\u{e0000}',
-        '\u{e0000}'), ('\u{e0001}', '\u{e0001}'), ('\u{e0002}', '\u{e001f}'), ('\u{e0020}',
-        '\u{e007f}'), ('\u{e0080}', '\u{e00ff}'), ('\u{e0100}', '\u{e01ef}'), ('\u{e01f0}',
-        '\u{e0fff}')
-    ];
+    const DEFAULT_IGNORABLE: Trie<u64> = Trie {
+        latin1: &[
+            0x0, 0x0, 0x200000000000, 0x0
+        ],
+        index: &[
+            0, 1, 2, 3, 4, 1, 5, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 6, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 7, 1, 1,
+            8, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9, 9
+        ],
+        blocks: &[
+            0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 5, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 11, 0, 0, 12, 13, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16
+        ],
+        leaves: &[
+            0x0, 0x200000000000, 0x8000, 0x10000000, 0x180000000, 0x30000000000000, 0xf800,
+            0x7c000000f800, 0xffff00000000, 0x1000000000, 0xffff, 0x8000000000000000, 0x100000000,
+            0x1ff000000000000, 0xf00000000, 0x7f8000000000000, 0xffffffffffffffff
+        ],
+        block_bits: 5,
+        leaf_bits: 0,
+    };
 
 }
 
+#[cfg(feature = "confusables")]
 pub mod confusable_detection {
 
+    use super::util::Trie;
+
     #[inline]
     pub fn char_confusable_prototype(c: char) -> Option<&'static [char]> {
-        // FIXME: do we want to special case ASCII here?
-        match c as usize {
-            _ => super::util::bsearch_value_table(c, CONFUSABLES)
+        match CONFUSABLES.lookup(c as u32) {
+            0 => None,
+            i => Some(PROTOTYPES[i as usize - 1]),
         }
     }
 
     // Confusable table:
-    const CONFUSABLES: &'static [(char, &'static [char])] = &[
-        ('\u{22}',  &['\u{27}',  '\u{27}']), ('\u{25}',  &['\u{ba}',  '\u{2f}',  '\u{2080}']),
-        ('\u{30}',  &['\u{4f}']), ('\u{31}',  &['\u{6c}']), ('\u{49}',  &['\u{6c}']), ('\u{60}',
-         &['\u{27}']), ('\u{6d}',  &['\u{72}',  '\u{6e}']), ('\u{7c}',  &['\u{6c}']), ('\u{a0}',
-         &['\u{20}']), ('\u{a2}',  &['\u{63}',  '\u{338}']), ('\u{a5}',  &['\u{59}',  '\u{335}']),
-        ('\u{af}',  &['\u{2c9}']), ('\u{b4}',  &['\u{27}']), ('\u{b5}',  &['\u{3bc}']), ('\u{b8}',
-         &['\u{2c}']), ('\u{c6}',  &['\u{41}',  '\u{45}']), ('\u{c7}',  &['\u{43}',  '\u{326}']),
-        ('\u{d0}',  &['\u{44}',  '\u{335}']), ('\u{d7}',  &['\u{78}']), ('\u{d8}',  &['\u{4f}',
-         '\u{338}']), ('\u{e6}',  &['\u{61}',  '\u{65}']), ('\u{e7}',  &['\u{63}',  '\u{326}']),
-        ('\u{f0}',  &['\u{2202}',  '\u{335}']), ('\u{f6}',  &['\u{629}']), ('\u{f8}',  &['\u{6f}',
-         '\u{338}']), ('\u{fe}',  &['\u{70}']), ('\u{110}',  &['\u{44}',  '\u{335}']), ('\u{111}',
-         &['\u{64}',  '\u{335}']), ('\u{11a}',  &['\u{114}']), ('\u{11b}',  &['\u{115}']),
-        ('\u{126}',  &['\u{48}',  '\u{335}']), ('\u{127}',  &['\u{68}',  '\u{335}']), ('\u{131}',
-         &['\u{69}']), ('\u{132}',  &['\u{6c}',  '\u{4a}']), ('\u{133}',  &['\u{69}',  '\u{6a}']),
-        ('\u{13f}',  &['\u{6c}',  '\u{b7}']), ('\u{140}',  &['\u{6c}',  '\u{b7}']), ('\u{141}',
-         &['\u{4c}',  '\u{338}']), ('\u{142}',  &['\u{6c}',  '\u{338}']), ('\u{146}',
-         &['\u{272}']), ('\u{149}',  &['\u{27}',  '\u{6e}']), ('\u{14b}',  &['\u{6e}',  '\u{329}']),
-        ('\u{150}',  &['\u{d6}']), ('\u{152}',  &['\u{4f}',  '\u{45}']), ('\u{153}',  &['\u{6f}',
-         '\u{65}']), ('\u{163}',  &['\u{1ab}']), ('\u{166}',  &['\u{54}',  '\u{335}']), ('\u{167}',
-         &['\u{74}',  '\u{335}']), ('\u{17f}',  &['\u{66}']), ('\u{180}',  &['\u{62}',  '\u{335}']),
-        ('\u{181}',  &['\u{27}',  '\u{42}']), ('\u{182}',  &['\u{62}',  '\u{304}']), ('\u{183}',
-         &['\u{62}',  '\u{304}']), ('\u{184}',  &['\u{62}']), ('\u{187}',  &['\u{43}',  '\u{27}']),
-        ('\u{189}',  &['\u{44}',  '\u{335}']), ('\u{18a}',  &['\u{27}',  '\u{44}']), ('\u{18c}',
-         &['\u{64}',  '\u{304}']), ('\u{18d}',  &['\u{67}']), ('\u{191}',  &['\u{46}',  '\u{326}']),
-        ('\u{192}',  &['\u{66}']), ('\u{193}',  &['\u{47}',  '\u{27}']), ('\u{196}',  &['\u{6c}']),
-        ('\u{197}',  &['\u{6c}',  '\u{335}']), ('\u{198}',  &['\u{4b}',  '\u{27}']), ('\u{199}',
-         &['\u{6b}',  '\u{314}']), ('\u{19a}',  &['\u{6c}',  '\u{335}']), ('\u{19b}',  &['\u{3bb}',
-         '\u{338}']), ('\u{19d}',  &['\u{4e}',  '\u{326}']), ('\u{19e}',  &['\u{6e}',  '\u{329}']),
-        ('\u{19f}',  &['\u{4f}',  '\u{335}']), ('\u{1a0}',  &['\u{4f}',  '\u{27}']), ('\u{1a1}',
-         &['\u{6f}',  '\u{27}']), ('\u{1a4}',  &['\u{27}',  '\u{50}']), ('\u{1a5}',  &['\u{70}',
-         '\u{314}']), ('\u{1a6}',  &['\u{52}']), ('\u{1a7}',  &['\u{32}']), ('\u{1ac}',  &['\u{27}',
-         '\u{54}']), ('\u{1ad}',  &['\u{74}',  '\u{314}']), ('\u{1ae}',  &['\u{54}',  '\u{328}']),
-        ('\u{1b3}',  &['\u{27}',  '\u{59}']), ('\u{1b4}',  &['\u{79}',  '\u{314}']), ('\u{1b5}',
-         &['\u{5a}',  '\u{335}']), ('\u{1b6}',  &['\u{7a}',  '\u{335}']), ('\u{1b7}',  &['\u{33}']),
-        ('\u{1bb}',  &['\u{32}',  '\u{335}']), ('\u{1bc}',  &['\u{35}']), ('\u{1bd}',  &['\u{73}']),
-        ('\u{1bf}',  &['\u{70}']), ('\u{1c0}',  &['\u{6c}']), ('\u{1c1}',  &['\u{6c}',  '\u{6c}']),
-        ('\u{1c3}',  &['\u{21}']), ('\u{1c4}',  &['\u{44}',  '\u{17d}']), ('\u{1c5}',  &['\u{44}',
-         '\u{17e}']), ('\u{1c6}',  &['\u{64}',  '\u{17e}']), ('\u{1c7}',  &['\u{4c}',  '\u{4a}']),
-        ('\u{1c8}',  &['\u{4c}',  '\u{6a}']), ('\u{1c9}',  &['\u{6c}',  '\u{6a}']), ('\u{1ca}',
-         &['\u{4e}',  '\u{4a}']), ('\u{1cb}',  &['\u{4e}',  '\u{6a}']), ('\u{1cc}',  &['\u{6e}',
-         '\u{6a}']), ('\u{1cd}',  &['\u{102}']), ('\u{1ce}',  &['\u{103}']), ('\u{1cf}',
-         &['\u{12c}']), ('\u{1d0}',  &['\u{12d}']), ('\u{1d1}',  &['\u{14e}']), ('\u{1d2}',
-         &['\u{14f}']), ('\u{1d3}',  &['\u{16c}']), ('\u{1d4}',  &['\u{16d}']), ('\u{1e4}',
-         &['\u{47}',  '\u{335}']), ('\u{1e5}',  &['\u{67}',  '\u{335}']), ('\u{1e6}',
-         &['\u{11e}']), ('\u{1e7}',  &['\u{11f}']), ('\u{1f1}',  &['\u{44}',  '\u{5a}']),
-        ('\u{1f2}',  &['\u{44}',  '\u{7a}']), ('\u{1f3}',  &['\u{64}',  '\u{7a}']), ('\u{1f5}',
-         &['\u{123}']), ('\u{1fe}',  &['\u{4f}',  '\u{338}',  '\u{301}']), ('\u{21a}',
-         &['\u{162}']), ('\u{21b}',  &['\u{1ab}']), ('\u{21c}',  &['\u{33}']), ('\u{222}',
-         &['\u{38}']), ('\u{223}',  &['\u{38}']), ('\u{224}',  &['\u{5a}',  '\u{326}']), ('\u{225}',
-         &['\u{7a}',  '\u{326}']), ('\u{226}',  &['\u{c5}']), ('\u{227}',  &['\u{e5}']), ('\u{23c}',
-         &['\u{63}',  '\u{338}']), ('\u{23e}',  &['\u{54}',  '\u{338}']), ('\u{241}',  &['\u{3f}']),
-        ('\u{244}',  &['\u{55}',  '\u{335}']), ('\u{246}',  &['\u{45}',  '\u{338}']), ('\u{247}',
-         &['\u{65}',  '\u{338}']), ('\u{248}',  &['\u{4a}',  '\u{335}']), ('\u{249}',  &['\u{6a}',
-         '\u{335}']), ('\u{24d}',  &['\u{72}',  '\u{335}']), ('\u{24e}',  &['\u{59}',  '\u{335}']),
-        ('\u{24f}',  &['\u{79}',  '\u{335}']), ('\u{251}',  &['\u{61}']), ('\u{253}',  &['\u{62}',
-         '\u{314}']), ('\u{256}',  &['\u{64}',  '\u{328}']), ('\u{257}',  &['\u{64}',  '\u{314}']),
-        ('\u{259}',  &['\u{1dd}']), ('\u{25a}',  &['\u{1dd}',  '\u{2de}']), ('\u{25b}',
-         &['\u{a793}']), ('\u{260}',  &['\u{67}',  '\u{314}']), ('\u{261}',  &['\u{67}']),
-        ('\u{263}',  &['\u{79}']), ('\u{266}',  &['\u{68}',  '\u{314}']), ('\u{268}',  &['\u{69}',
-         '\u{335}']), ('\u{269}',  &['\u{69}']), ('\u{26a}',  &['\u{69}']), ('\u{26b}',  &['\u{6c}',
-         '\u{334}']), ('\u{26d}',  &['\u{6c}',  '\u{328}']), ('\u{26e}',  &['\u{6c}',  '\u{21d}']),
-        ('\u{26f}',  &['\u{77}']), ('\u{271}',  &['\u{72}',  '\u{6e}',  '\u{326}']), ('\u{273}',
-         &['\u{6e}',  '\u{328}']), ('\u{275}',  &['\u{6f}',  '\u{335}']), ('\u{276}',  &['\u{6f}',
-         '\u{1d07}']), ('\u{27c}',  &['\u{72}',  '\u{329}']), ('\u{27d}',  &['\u{72}',  '\u{328}']),
-        ('\u{282}',  &['\u{73}',  '\u{328}']), ('\u{28b}',  &['\u{75}']), ('\u{28f}',  &['\u{79}']),
-        ('\u{290}',  &['\u{7a}',  '\u{328}']), ('\u{292}',  &['\u{21d}']), ('\u{294}',
-         &['\u{3f}']), ('\u{295}',  &['\u{a7ce}']), ('\u{2a0}',  &['\u{71}',  '\u{314}']),
-        ('\u{2a3}',  &['\u{64}',  '\u{7a}']), ('\u{2a4}',  &['\u{64}',  '\u{21d}']), ('\u{2a5}',
-         &['\u{64}',  '\u{291}']), ('\u{2a6}',  &['\u{74}',  '\u{73}']), ('\u{2a7}',  &['\u{74}',
-         '\u{283}']), ('\u{2a8}',  &['\u{74}',  '\u{255}']), ('\u{2a9}',  &['\u{66}',  '\u{6e}',
-         '\u{329}']), ('\u{2aa}',  &['\u{6c}',  '\u{73}']), ('\u{2ab}',  &['\u{6c}',  '\u{7a}']),
-        ('\u{2b3}',  &['\u{18f4}']), ('\u{2b9}',  &['\u{27}']), ('\u{2ba}',  &['\u{27}',
-         '\u{27}']), ('\u{2bb}',  &['\u{27}']), ('\u{2bc}',  &['\u{27}']), ('\u{2bd}',
-         &['\u{27}']), ('\u{2be}',  &['\u{27}']), ('\u{2bf}',  &['\u{559}']), ('\u{2c2}',
-         &['\u{3c}']), ('\u{2c3}',  &['\u{3e}']), ('\u{2c4}',  &['\u{5e}']), ('\u{2c6}',
-         &['\u{5e}']), ('\u{2c8}',  &['\u{27}']), ('\u{2ca}',  &['\u{27}']), ('\u{2cb}',
-         &['\u{27}']), ('\u{2d0}',  &['\u{3a}']), ('\u{2d3}',  &['\u{559}']), ('\u{2d7}',
-         &['\u{2d}']), ('\u{2d8}',  &['\u{2c7}']), ('\u{2d9}',  &['\u{971}']), ('\u{2da}',
-         &['\u{b0}']), ('\u{2db}',  &['\u{69}']), ('\u{2dc}',  &['\u{7e}']), ('\u{2dd}',
-         &['\u{27}',  '\u{27}']), ('\u{2e1}',  &['\u{18f3}']), ('\u{2e2}',  &['\u{18f5}']),
-        ('\u{2e4}',  &['\u{2c1}']), ('\u{2ee}',  &['\u{27}',  '\u{27}']), ('\u{2f4}',  &['\u{27}']),
-        ('\u{2f6}',  &['\u{27}',  '\u{27}']), ('\u{2f8}',  &['\u{3a}']), ('\u{2fb}',  &['\u{2ea}']),
-        ('\u{305}',  &['\u{304}']), ('\u{30c}',  &['\u{306}']), ('\u{30d}',  &['\u{670}']),
-        ('\u{310}',  &['\u{306}',  '\u{307}']), ('\u{311}',  &['\u{302}']), ('\u{315}',
-         &['\u{313}']), ('\u{317}',  &['\u{650}']), ('\u{31a}',  &['\u{1ae9}']), ('\u{320}',
-         &['\u{331}']), ('\u{321}',  &['\u{326}']), ('\u{322}',  &['\u{328}']), ('\u{327}',
-         &['\u{326}']), ('\u{336}',  &['\u{335}']), ('\u{337}',  &['\u{338}']), ('\u{339}',
-         &['\u{326}']), ('\u{340}',  &['\u{300}']), ('\u{341}',  &['\u{301}']), ('\u{342}',
-         &['\u{303}']), ('\u{343}',  &['\u{313}']), ('\u{345}',  &['\u{328}']), ('\u{347}',
-         &['\u{333}']), ('\u{348}',  &['\u{10efa}']), ('\u{357}',  &['\u{350}']), ('\u{358}',
-         &['\u{307}']), ('\u{366}',  &['\u{30a}']), ('\u{36e}',  &['\u{306}']), ('\u{370}',
-         &['\u{2c75}']), ('\u{374}',  &['\u{27}']), ('\u{375}',  &['\u{2cf}']), ('\u{376}',
-         &['\u{418}']), ('\u{377}',  &['\u{1d0e}']), ('\u{37a}',  &['\u{69}']), ('\u{37b}',
-         &['\u{254}']), ('\u{37d}',  &['\u{a73f}']), ('\u{37e}',  &['\u{3b}']), ('\u{37f}',
-         &['\u{4a}']), ('\u{384}',  &['\u{27}']), ('\u{387}',  &['\u{b7}']), ('\u{391}',
-         &['\u{41}']), ('\u{392}',  &['\u{42}']), ('\u{395}',  &['\u{45}']), ('\u{396}',
-         &['\u{5a}']), ('\u{397}',  &['\u{48}']), ('\u{398}',  &['\u{4f}',  '\u{335}']), ('\u{399}',
-         &['\u{6c}']), ('\u{39a}',  &['\u{4b}']), ('\u{39b}',  &['\u{245}']), ('\u{39c}',
-         &['\u{4d}']), ('\u{39d}',  &['\u{4e}']), ('\u{39f}',  &['\u{4f}']), ('\u{3a1}',
-         &['\u{50}']), ('\u{3a3}',  &['\u{1a9}']), ('\u{3a4}',  &['\u{54}']), ('\u{3a5}',
-         &['\u{59}']), ('\u{3a7}',  &['\u{58}']), ('\u{3b1}',  &['\u{61}']), ('\u{3b2}',
-         &['\u{df}']), ('\u{3b3}',  &['\u{79}']), ('\u{3b4}',  &['\u{1e9f}']), ('\u{3b5}',
-         &['\u{a793}']), ('\u{3b7}',  &['\u{6e}',  '\u{329}']), ('\u{3b8}',  &['\u{4f}',
-         '\u{335}']), ('\u{3b9}',  &['\u{69}']), ('\u{3ba}',  &['\u{138}']), ('\u{3bd}',
-         &['\u{76}']), ('\u{3bf}',  &['\u{6f}']), ('\u{3c1}',  &['\u{70}']), ('\u{3c3}',
-         &['\u{6f}']), ('\u{3c4}',  &['\u{1d1b}']), ('\u{3c5}',  &['\u{75}']), ('\u{3c6}',
-         &['\u{278}']), ('\u{3d0}',  &['\u{df}']), ('\u{3d1}',  &['\u{4f}',  '\u{335}']),
-        ('\u{3d2}',  &['\u{59}']), ('\u{3d5}',  &['\u{278}']), ('\u{3d6}',  &['\u{3c0}']),
-        ('\u{3db}',  &['\u{3c2}']), ('\u{3dc}',  &['\u{46}']), ('\u{3e4}',  &['\u{427}']),
-        ('\u{3e5}',  &['\u{447}']), ('\u{3e8}',  &['\u{32}']), ('\u{3e9}',  &['\u{1a8}']),
-        ('\u{3ec}',  &['\u{36}']), ('\u{3ed}',  &['\u{6f}']), ('\u{3f0}',  &['\u{138}']),
-        ('\u{3f1}',  &['\u{70}']), ('\u{3f2}',  &['\u{63}']), ('\u{3f3}',  &['\u{6a}']), ('\u{3f4}',
-         &['\u{4f}',  '\u{335}']), ('\u{3f5}',  &['\u{a793}']), ('\u{3f7}',  &['\u{de}']),
-        ('\u{3f8}',  &['\u{70}']), ('\u{3f9}',  &['\u{43}']), ('\u{3fa}',  &['\u{4d}']), ('\u{3fd}',
-         &['\u{186}']), ('\u{3ff}',  &['\u{a73e}']), ('\u{404}',  &['\u{a792}']), ('\u{405}',
-         &['\u{53}']), ('\u{406}',  &['\u{6c}']), ('\u{408}',  &['\u{4a}']), ('\u{410}',
-         &['\u{41}']), ('\u{411}',  &['\u{62}',  '\u{304}']), ('\u{412}',  &['\u{42}']), ('\u{413}',
-         &['\u{393}']), ('\u{415}',  &['\u{45}']), ('\u{417}',  &['\u{33}']), ('\u{419}',
-         &['\u{40d}']), ('\u{41a}',  &['\u{4b}']), ('\u{41b}',  &['\u{245}']), ('\u{41c}',
-         &['\u{4d}']), ('\u{41d}',  &['\u{48}']), ('\u{41e}',  &['\u{4f}']), ('\u{41f}',
-         &['\u{3a0}']), ('\u{420}',  &['\u{50}']), ('\u{421}',  &['\u{43}']), ('\u{422}',
-         &['\u{54}']), ('\u{423}',  &['\u{59}']), ('\u{424}',  &['\u{3a6}']), ('\u{425}',
-         &['\u{58}']), ('\u{42b}',  &['\u{62}',  '\u{6c}']), ('\u{42c}',  &['\u{62}']), ('\u{42e}',
-         &['\u{6c}',  '\u{4f}']), ('\u{430}',  &['\u{61}']), ('\u{431}',  &['\u{36}']), ('\u{432}',
-         &['\u{299}']), ('\u{433}',  &['\u{72}']), ('\u{435}',  &['\u{65}']), ('\u{437}',
-         &['\u{25c}']), ('\u{438}',  &['\u{1d0e}']), ('\u{43a}',  &['\u{138}']), ('\u{43c}',
-         &['\u{28d}']), ('\u{43d}',  &['\u{29c}']), ('\u{43e}',  &['\u{6f}']), ('\u{43f}',
-         &['\u{3c0}']), ('\u{440}',  &['\u{70}']), ('\u{441}',  &['\u{63}']), ('\u{442}',
-         &['\u{1d1b}']), ('\u{443}',  &['\u{79}']), ('\u{444}',  &['\u{278}']), ('\u{445}',
-         &['\u{78}']), ('\u{448}',  &['\u{77}']), ('\u{44a}',  &['\u{2c9}',  '\u{62}']), ('\u{44b}',
-         &['\u{185}',  '\u{69}']), ('\u{44c}',  &['\u{185}']), ('\u{44f}',  &['\u{1d19}']),
-        ('\u{454}',  &['\u{a793}']), ('\u{455}',  &['\u{73}']), ('\u{456}',  &['\u{69}']),
-        ('\u{458}',  &['\u{6a}']), ('\u{45b}',  &['\u{68}',  '\u{335}']), ('\u{45d}',
-         &['\u{439}']), ('\u{45f}',  &['\u{75}',  '\u{329}']), ('\u{461}',  &['\u{77}']),
-        ('\u{462}',  &['\u{62}',  '\u{335}']), ('\u{463}',  &['\u{62}',  '\u{335}']), ('\u{470}',
-         &['\u{3a8}']), ('\u{471}',  &['\u{3c8}']), ('\u{472}',  &['\u{4f}',  '\u{335}']),
-        ('\u{473}',  &['\u{6f}',  '\u{335}']), ('\u{474}',  &['\u{56}']), ('\u{475}',  &['\u{76}']),
-        ('\u{47c}',  &['\u{460}',  '\u{486}',  '\u{487}']), ('\u{47d}',  &['\u{77}',  '\u{486}',
-         '\u{487}']), ('\u{48a}',  &['\u{40d}',  '\u{326}']), ('\u{48b}',  &['\u{439}',
-         '\u{326}']), ('\u{48c}',  &['\u{62}',  '\u{335}']), ('\u{48d}',  &['\u{62}',  '\u{335}']),
-        ('\u{490}',  &['\u{393}',  '\u{27}']), ('\u{491}',  &['\u{72}',  '\u{27}']), ('\u{492}',
-         &['\u{393}',  '\u{335}']), ('\u{493}',  &['\u{72}',  '\u{335}']), ('\u{496}',  &['\u{416}',
-         '\u{329}']), ('\u{497}',  &['\u{436}',  '\u{329}']), ('\u{498}',  &['\u{33}',  '\u{326}']),
-        ('\u{499}',  &['\u{25c}',  '\u{326}']), ('\u{49a}',  &['\u{4b}',  '\u{329}']), ('\u{49b}',
-         &['\u{138}',  '\u{329}']), ('\u{49e}',  &['\u{4b}',  '\u{335}']), ('\u{49f}',  &['\u{138}',
-         '\u{335}']), ('\u{4a2}',  &['\u{48}',  '\u{329}']), ('\u{4a3}',  &['\u{29c}',  '\u{329}']),
-        ('\u{4aa}',  &['\u{43}',  '\u{326}']), ('\u{4ab}',  &['\u{63}',  '\u{326}']), ('\u{4ac}',
-         &['\u{54}',  '\u{329}']), ('\u{4ad}',  &['\u{1d1b}',  '\u{329}']), ('\u{4ae}',
-         &['\u{59}']), ('\u{4af}',  &['\u{79}']), ('\u{4b0}',  &['\u{59}',  '\u{335}']), ('\u{4b1}',
-         &['\u{79}',  '\u{335}']), ('\u{4b2}',  &['\u{58}',  '\u{329}']), ('\u{4bb}',  &['\u{68}']),
-        ('\u{4bd}',  &['\u{65}']), ('\u{4be}',  &['\u{4bc}',  '\u{328}']), ('\u{4bf}',  &['\u{65}',
-         '\u{328}']), ('\u{4c0}',  &['\u{6c}']), ('\u{4c5}',  &['\u{245}',  '\u{326}']), ('\u{4c6}',
-         &['\u{43b}',  '\u{326}']), ('\u{4c7}',  &['\u{48}',  '\u{326}']), ('\u{4c8}',  &['\u{29c}',
-         '\u{326}']), ('\u{4c9}',  &['\u{48}',  '\u{326}']), ('\u{4ca}',  &['\u{29c}',  '\u{326}']),
-        ('\u{4cb}',  &['\u{4b6}']), ('\u{4cc}',  &['\u{4b7}']), ('\u{4cd}',  &['\u{4d}',
-         '\u{326}']), ('\u{4ce}',  &['\u{28d}',  '\u{326}']), ('\u{4cf}',  &['\u{6c}']), ('\u{4d4}',
-         &['\u{41}',  '\u{45}']), ('\u{4d5}',  &['\u{61}',  '\u{65}']), ('\u{4d8}',  &['\u{18f}']),
-        ('\u{4d9}',  &['\u{1dd}']), ('\u{4e0}',  &['\u{33}']), ('\u{4e1}',  &['\u{21d}']),
-        ('\u{4e8}',  &['\u{4f}',  '\u{335}']), ('\u{4e9}',  &['\u{6f}',  '\u{335}']), ('\u{501}',
-         &['\u{64}']), ('\u{50a}',  &['\u{1f6}']), ('\u{50c}',  &['\u{47}']), ('\u{50d}',
-         &['\u{262}']), ('\u{510}',  &['\u{190}']), ('\u{511}',  &['\u{a793}']), ('\u{51b}',
-         &['\u{71}']), ('\u{51c}',  &['\u{57}']), ('\u{51d}',  &['\u{77}']), ('\u{53b}',
-         &['\u{12ae}']), ('\u{544}',  &['\u{1206}']), ('\u{54a}',  &['\u{1323}']), ('\u{54c}',
-         &['\u{1261}']), ('\u{54d}',  &['\u{55}']), ('\u{54f}',  &['\u{53}']), ('\u{553}',
-         &['\u{3a6}']), ('\u{555}',  &['\u{4f}']), ('\u{55a}',  &['\u{27}']), ('\u{55d}',
-         &['\u{27}']), ('\u{561}',  &['\u{77}']), ('\u{563}',  &['\u{71}']), ('\u{566}',
-         &['\u{71}']), ('\u{56e}',  &['\u{1e9f}']), ('\u{570}',  &['\u{68}']), ('\u{572}',
-         &['\u{6e}',  '\u{329}']), ('\u{575}',  &['\u{237}']), ('\u{578}',  &['\u{6e}']),
-        ('\u{57a}',  &['\u{270}']), ('\u{57c}',  &['\u{6e}']), ('\u{57d}',  &['\u{75}']),
-        ('\u{581}',  &['\u{67}']), ('\u{582}',  &['\u{69}']), ('\u{584}',  &['\u{66}']), ('\u{585}',
-         &['\u{6f}']), ('\u{587}',  &['\u{565}',  '\u{69}']), ('\u{589}',  &['\u{3a}']), ('\u{59c}',
-         &['\u{301}']), ('\u{59d}',  &['\u{301}']), ('\u{5a4}',  &['\u{59a}']), ('\u{5a8}',
-         &['\u{599}']), ('\u{5ad}',  &['\u{596}']), ('\u{5ae}',  &['\u{598}']), ('\u{5af}',
-         &['\u{30a}']), ('\u{5b4}',  &['\u{323}']), ('\u{5b9}',  &['\u{307}']), ('\u{5ba}',
-         &['\u{307}']), ('\u{5c0}',  &['\u{6c}']), ('\u{5c1}',  &['\u{307}']), ('\u{5c2}',
-         &['\u{307}']), ('\u{5c3}',  &['\u{3a}']), ('\u{5c4}',  &['\u{307}']), ('\u{5c5}',
-         &['\u{323}']), ('\u{5d5}',  &['\u{6c}']), ('\u{5d8}',  &['\u{76}']), ('\u{5d9}',
-         &['\u{27}']), ('\u{5df}',  &['\u{6c}']), ('\u{5e1}',  &['\u{6f}']), ('\u{5f0}',
-         &['\u{6c}',  '\u{6c}']), ('\u{5f1}',  &['\u{6c}',  '\u{27}']), ('\u{5f2}',  &['\u{27}',
-         '\u{27}']), ('\u{5f3}',  &['\u{27}']), ('\u{5f4}',  &['\u{27}',  '\u{27}']), ('\u{609}',
-         &['\u{ba}',  '\u{2f}',  '\u{2080}',  '\u{2080}']), ('\u{60a}',  &['\u{ba}',  '\u{2f}',
-         '\u{2080}',  '\u{2080}',  '\u{2080}']), ('\u{60d}',  &['\u{2c}']), ('\u{60f}',
-         &['\u{639}']), ('\u{618}',  &['\u{301}']), ('\u{619}',  &['\u{313}']), ('\u{61a}',
-         &['\u{650}']), ('\u{623}',  &['\u{6c}',  '\u{674}']), ('\u{624}',  &['\u{648}',
-         '\u{674}']), ('\u{625}',  &['\u{6c}',  '\u{655}']), ('\u{626}',  &['\u{649}',  '\u{674}']),
-        ('\u{627}',  &['\u{6c}']), ('\u{62b}',  &['\u{649}',  '\u{6db}']), ('\u{634}',  &['\u{633}',
-         '\u{6db}']), ('\u{63d}',  &['\u{649}',  '\u{302}']), ('\u{63f}',  &['\u{649}',
-         '\u{6db}']), ('\u{647}',  &['\u{6f}']), ('\u{64a}',  &['\u{649}']), ('\u{64b}',
-         &['\u{30b}']), ('\u{64e}',  &['\u{301}']), ('\u{64f}',  &['\u{313}']), ('\u{652}',
-         &['\u{30a}']), ('\u{653}',  &['\u{303}']), ('\u{656}',  &['\u{329}']), ('\u{657}',
-         &['\u{312}']), ('\u{658}',  &['\u{306}']), ('\u{659}',  &['\u{304}']), ('\u{65a}',
-         &['\u{306}']), ('\u{65b}',  &['\u{302}']), ('\u{65c}',  &['\u{323}']), ('\u{65d}',
-         &['\u{314}']), ('\u{65f}',  &['\u{655}']), ('\u{660}',  &['\u{2e}']), ('\u{661}',
-         &['\u{6c}']), ('\u{665}',  &['\u{6f}']), ('\u{667}',  &['\u{56}']), ('\u{668}',
-         &['\u{245}']), ('\u{66a}',  &['\u{ba}',  '\u{2f}',  '\u{2080}']), ('\u{66b}',
-         &['\u{2c}']), ('\u{66c}',  &['\u{60c}']), ('\u{66d}',  &['\u{2a}']), ('\u{66e}',
-         &['\u{649}']), ('\u{66f}',  &['\u{6a1}']), ('\u{672}',  &['\u{6c}',  '\u{674}']),
-        ('\u{673}',  &['\u{6c}',  '\u{655}']), ('\u{675}',  &['\u{6c}',  '\u{674}']), ('\u{676}',
-         &['\u{648}',  '\u{674}']), ('\u{677}',  &['\u{648}',  '\u{313}',  '\u{674}']), ('\u{678}',
-         &['\u{649}',  '\u{674}']), ('\u{679}',  &['\u{649}',  '\u{615}']), ('\u{67a}',
-         &['\u{62a}']), ('\u{67e}',  &['\u{649}',  '\u{6db}']), ('\u{681}',  &['\u{62d}',
-         '\u{654}']), ('\u{685}',  &['\u{62d}',  '\u{6db}']), ('\u{688}',  &['\u{62f}',
-         '\u{615}']), ('\u{68b}',  &['\u{68a}',  '\u{615}']), ('\u{68e}',  &['\u{62f}',
-         '\u{6db}']), ('\u{68f}',  &['\u{62f}',  '\u{6db}']), ('\u{691}',  &['\u{631}',
-         '\u{615}']), ('\u{692}',  &['\u{631}',  '\u{306}']), ('\u{698}',  &['\u{631}',
-         '\u{6db}']), ('\u{69e}',  &['\u{635}',  '\u{6db}']), ('\u{69f}',  &['\u{637}',
-         '\u{6db}']), ('\u{6a4}',  &['\u{6a1}',  '\u{6db}']), ('\u{6a7}',  &['\u{641}']),
-        ('\u{6a8}',  &['\u{6a1}',  '\u{6db}']), ('\u{6a9}',  &['\u{643}']), ('\u{6aa}',
-         &['\u{643}']), ('\u{6ad}',  &['\u{643}',  '\u{6db}']), ('\u{6b4}',  &['\u{6af}',
-         '\u{6db}']), ('\u{6b5}',  &['\u{644}',  '\u{306}']), ('\u{6b7}',  &['\u{644}',
-         '\u{6db}']), ('\u{6ba}',  &['\u{649}']), ('\u{6bb}',  &['\u{649}',  '\u{615}']),
-        ('\u{6bd}',  &['\u{649}',  '\u{6db}']), ('\u{6be}',  &['\u{6f}']), ('\u{6c1}',
-         &['\u{6f}']), ('\u{6c2}',  &['\u{6c0}']), ('\u{6c3}',  &['\u{629}']), ('\u{6c6}',
-         &['\u{648}',  '\u{306}']), ('\u{6c7}',  &['\u{648}',  '\u{313}']), ('\u{6c8}',
-         &['\u{648}',  '\u{670}']), ('\u{6c9}',  &['\u{648}',  '\u{302}']), ('\u{6cb}',
-         &['\u{648}',  '\u{6db}']), ('\u{6cc}',  &['\u{649}']), ('\u{6ce}',  &['\u{649}',
-         '\u{306}']), ('\u{6d0}',  &['\u{67b}']), ('\u{6d1}',  &['\u{649}',  '\u{6db}']),
-        ('\u{6d2}',  &['\u{649}']), ('\u{6d4}',  &['\u{2d}']), ('\u{6d5}',  &['\u{6f}']),
-        ('\u{6df}',  &['\u{30a}']), ('\u{6e8}',  &['\u{306}',  '\u{307}']), ('\u{6ec}',
-         &['\u{307}']), ('\u{6ee}',  &['\u{62f}',  '\u{302}']), ('\u{6ef}',  &['\u{631}',
-         '\u{302}']), ('\u{6f0}',  &['\u{2e}']), ('\u{6f1}',  &['\u{6c}']), ('\u{6f2}',
-         &['\u{662}']), ('\u{6f3}',  &['\u{663}']), ('\u{6f4}',  &['\u{664}']), ('\u{6f5}',
-         &['\u{6f}']), ('\u{6f6}',  &['\u{666}']), ('\u{6f7}',  &['\u{56}']), ('\u{6f8}',
-         &['\u{245}']), ('\u{6f9}',  &['\u{669}']), ('\u{6fd}',  &['\u{621}',  '\u{10efa}']),
-        ('\u{6fe}',  &['\u{645}',  '\u{10efa}']), ('\u{6ff}',  &['\u{6f}',  '\u{302}']), ('\u{701}',
-         &['\u{2e}']), ('\u{702}',  &['\u{2e}']), ('\u{703}',  &['\u{3a}']), ('\u{704}',
-         &['\u{3a}']), ('\u{740}',  &['\u{307}']), ('\u{741}',  &['\u{307}']), ('\u{742}',
-         &['\u{73c}']), ('\u{747}',  &['\u{301}']), ('\u{751}',  &['\u{628}',  '\u{6db}']),
-        ('\u{752}',  &['\u{649}',  '\u{6db}']), ('\u{756}',  &['\u{649}',  '\u{306}']), ('\u{762}',
-         &['\u{6ac}']), ('\u{763}',  &['\u{643}',  '\u{6db}']), ('\u{767}',  &['\u{754}']),
-        ('\u{768}',  &['\u{646}',  '\u{615}']), ('\u{769}',  &['\u{646}',  '\u{306}']), ('\u{76c}',
-         &['\u{631}',  '\u{654}']), ('\u{771}',  &['\u{697}',  '\u{615}']), ('\u{772}',
-         &['\u{62d}',  '\u{654}']), ('\u{77e}',  &['\u{633}',  '\u{302}']), ('\u{7c0}',
-         &['\u{4f}']), ('\u{7ca}',  &['\u{6c}']), ('\u{7eb}',  &['\u{304}']), ('\u{7ed}',
-         &['\u{307}']), ('\u{7ee}',  &['\u{302}']), ('\u{7f3}',  &['\u{308}']), ('\u{7f4}',
-         &['\u{27}']), ('\u{7f5}',  &['\u{27}']), ('\u{7fa}',  &['\u{5f}']), ('\u{88f}',
-         &['\u{649}',  '\u{30a}']), ('\u{8a1}',  &['\u{628}',  '\u{654}']), ('\u{8a4}',
-         &['\u{6a2}',  '\u{6db}']), ('\u{8a7}',  &['\u{645}',  '\u{6db}']), ('\u{8a8}',
-         &['\u{649}',  '\u{654}']), ('\u{8a9}',  &['\u{754}']), ('\u{8ae}',  &['\u{62f}',
-         '\u{324}',  '\u{323}']), ('\u{8af}',  &['\u{635}',  '\u{324}',  '\u{323}']), ('\u{8b0}',
-         &['\u{6af}']), ('\u{8b1}',  &['\u{648}']), ('\u{8b2}',  &['\u{632}',  '\u{302}']),
-        ('\u{8b6}',  &['\u{628}',  '\u{6e2}']), ('\u{8b7}',  &['\u{649}',  '\u{6db}',  '\u{6e2}']),
-        ('\u{8b9}',  &['\u{631}',  '\u{306}',  '\u{307}']), ('\u{8ba}',  &['\u{649}',  '\u{306}',
-         '\u{307}']), ('\u{8bb}',  &['\u{6a1}']), ('\u{8bc}',  &['\u{6a1}']), ('\u{8bd}',
-         &['\u{649}']), ('\u{8be}',  &['\u{649}',  '\u{6db}',  '\u{306}']), ('\u{8bf}',
-         &['\u{62a}',  '\u{306}']), ('\u{8c0}',  &['\u{649}',  '\u{615}',  '\u{306}']), ('\u{8c1}',
-         &['\u{686}',  '\u{306}']), ('\u{8c2}',  &['\u{643}',  '\u{306}']), ('\u{8e5}',
-         &['\u{64c}']), ('\u{8e8}',  &['\u{64c}']), ('\u{8ea}',  &['\u{307}']), ('\u{8eb}',
-         &['\u{308}']), ('\u{8ed}',  &['\u{323}']), ('\u{8ee}',  &['\u{324}']), ('\u{8f0}',
-         &['\u{30b}']), ('\u{8f1}',  &['\u{64c}']), ('\u{8f2}',  &['\u{64d}']), ('\u{8f3}',
-         &['\u{313}']), ('\u{8f8}',  &['\u{350}']), ('\u{8f9}',  &['\u{354}']), ('\u{8fa}',
-         &['\u{355}']), ('\u{8ff}',  &['\u{350}']), ('\u{900}',  &['\u{352}']), ('\u{901}',
-         &['\u{306}',  '\u{307}']), ('\u{902}',  &['\u{307}']), ('\u{903}',  &['\u{3a}']),
-        ('\u{904}',  &['\u{905}',  '\u{946}']), ('\u{906}',  &['\u{905}',  '\u{93e}']), ('\u{908}',
-         &['\u{930}',  '\u{94d}',  '\u{907}']), ('\u{90d}',  &['\u{90f}',  '\u{306}']), ('\u{90e}',
-         &['\u{90f}',  '\u{946}']), ('\u{910}',  &['\u{90f}',  '\u{11b64}']), ('\u{911}',
-         &['\u{905}',  '\u{93e}',  '\u{306}']), ('\u{912}',  &['\u{905}',  '\u{93e}',  '\u{946}']),
-        ('\u{913}',  &['\u{905}',  '\u{93e}',  '\u{11b64}']), ('\u{914}',  &['\u{905}',  '\u{93e}',
-         '\u{948}']), ('\u{93b}',  &['\u{93e}',  '\u{93a}']), ('\u{93c}',  &['\u{323}']),
-        ('\u{93f}',  &['\u{9bf}']), ('\u{945}',  &['\u{306}']), ('\u{947}',  &['\u{11b64}']),
-        ('\u{949}',  &['\u{93e}',  '\u{306}']), ('\u{952}',  &['\u{331}']), ('\u{953}',
-         &['\u{300}']), ('\u{954}',  &['\u{301}']), ('\u{956}',  &['\u{11b62}']), ('\u{957}',
-         &['\u{11b63}']), ('\u{965}',  &['\u{964}',  '\u{964}']), ('\u{966}',  &['\u{6f}']),
-        ('\u{967}',  &['\u{669}']), ('\u{969}',  &['\u{33}']), ('\u{972}',  &['\u{905}',
-         '\u{306}']), ('\u{973}',  &['\u{905}',  '\u{93a}']), ('\u{974}',  &['\u{905}',  '\u{93e}',
-         '\u{93a}']), ('\u{975}',  &['\u{905}',  '\u{94f}']), ('\u{97d}',  &['\u{3f}']), ('\u{981}',
-         &['\u{306}',  '\u{307}']), ('\u{986}',  &['\u{985}',  '\u{9be}']), ('\u{9bc}',
-         &['\u{323}']), ('\u{9e0}',  &['\u{98b}',  '\u{9c3}']), ('\u{9e1}',  &['\u{98b}',
-         '\u{9c3}']), ('\u{9e6}',  &['\u{6f}']), ('\u{9ea}',  &['\u{38}']), ('\u{9ed}',
-         &['\u{39}']), ('\u{9f0}',  &['\u{9b0}']), ('\u{a02}',  &['\u{307}']), ('\u{a03}',
-         &['\u{983}']), ('\u{a06}',  &['\u{a05}',  '\u{a3e}']), ('\u{a07}',  &['\u{92a}',
-         '\u{94d}',  '\u{91f}',  '\u{9bf}']), ('\u{a08}',  &['\u{92a}',  '\u{94d}',  '\u{91f}',
-         '\u{a40}']), ('\u{a09}',  &['\u{a73}',  '\u{11b62}']), ('\u{a0a}',  &['\u{a73}',
-         '\u{11b63}']), ('\u{a0f}',  &['\u{92a}',  '\u{94d}',  '\u{91f}',  '\u{11b64}']),
-        ('\u{a10}',  &['\u{a05}',  '\u{948}']), ('\u{a14}',  &['\u{a05}',  '\u{a4c}']), ('\u{a15}',
-         &['\u{935}']), ('\u{a1c}',  &['\u{924}',  '\u{94d}',  '\u{924}']), ('\u{a1f}',
-         &['\u{91f}']), ('\u{a20}',  &['\u{920}']), ('\u{a24}',  &['\u{909}']), ('\u{a27}',
-         &['\u{92a}']), ('\u{a2b}',  &['\u{922}']), ('\u{a2e}',  &['\u{92d}']), ('\u{a35}',
-         &['\u{939}']), ('\u{a38}',  &['\u{92e}']), ('\u{a3c}',  &['\u{323}']), ('\u{a3f}',
-         &['\u{9bf}']), ('\u{a41}',  &['\u{11b62}']), ('\u{a42}',  &['\u{11b63}']), ('\u{a47}',
-         &['\u{11b64}']), ('\u{a48}',  &['\u{948}']), ('\u{a4b}',  &['\u{946}']), ('\u{a4d}',
-         &['\u{94d}']), ('\u{a66}',  &['\u{6f}']), ('\u{a67}',  &['\u{39}']), ('\u{a6a}',
-         &['\u{38}']), ('\u{a72}',  &['\u{92a}',  '\u{94d}',  '\u{91f}']), ('\u{a81}',  &['\u{306}',
-         '\u{307}']), ('\u{a82}',  &['\u{307}']), ('\u{a83}',  &['\u{3a}']), ('\u{a86}',
-         &['\u{a85}',  '\u{abe}']), ('\u{a8d}',  &['\u{a85}',  '\u{ac5}']), ('\u{a8f}',
-         &['\u{a85}',  '\u{ac7}']), ('\u{a90}',  &['\u{a85}',  '\u{ac8}']), ('\u{a91}',
-         &['\u{a85}',  '\u{abe}',  '\u{ac5}']), ('\u{a93}',  &['\u{a85}',  '\u{abe}',  '\u{ac7}']),
-        ('\u{a94}',  &['\u{a85}',  '\u{abe}',  '\u{ac8}']), ('\u{aaa}',  &['\u{447}']), ('\u{ab0}',
-         &['\u{968}']), ('\u{abc}',  &['\u{323}']), ('\u{abd}',  &['\u{93d}']), ('\u{ac1}',
-         &['\u{941}']), ('\u{ac2}',  &['\u{942}']), ('\u{acd}',  &['\u{94d}']), ('\u{ae6}',
-         &['\u{6f}']), ('\u{ae8}',  &['\u{968}']), ('\u{ae9}',  &['\u{33}']), ('\u{aea}',
-         &['\u{96a}']), ('\u{aeb}',  &['\u{447}']), ('\u{aee}',  &['\u{96e}']), ('\u{af0}',
-         &['\u{970}']), ('\u{b01}',  &['\u{306}',  '\u{307}']), ('\u{b03}',  &['\u{38}']),
-        ('\u{b06}',  &['\u{b05}',  '\u{b3e}']), ('\u{b20}',  &['\u{4f}']), ('\u{b3c}',
-         &['\u{323}']), ('\u{b66}',  &['\u{6f}']), ('\u{b68}',  &['\u{39}']), ('\u{b82}',
-         &['\u{30a}']), ('\u{b8a}',  &['\u{b89}',  '\u{bb3}']), ('\u{b94}',  &['\u{b92}',
-         '\u{bb3}']), ('\u{b9c}',  &['\u{b90}']), ('\u{bb0}',  &['\u{b88}']), ('\u{bb8}',
-         &['\u{bb6}']), ('\u{bbe}',  &['\u{b88}']), ('\u{bc8}',  &['\u{ba9}']), ('\u{bca}',
-         &['\u{bc6}',  '\u{b88}']), ('\u{bcb}',  &['\u{bc7}',  '\u{b88}']), ('\u{bcc}',
-         &['\u{bc6}',  '\u{bb3}']), ('\u{bcd}',  &['\u{307}']), ('\u{bd7}',  &['\u{bb3}']),
-        ('\u{be6}',  &['\u{6f}']), ('\u{be7}',  &['\u{b95}']), ('\u{be8}',  &['\u{b89}']),
-        ('\u{bea}',  &['\u{b9a}']), ('\u{beb}',  &['\u{b88}',  '\u{bc1}']), ('\u{bec}',
-         &['\u{b9a}',  '\u{bc1}']), ('\u{bed}',  &['\u{b8e}']), ('\u{bee}',  &['\u{b85}']),
-        ('\u{bf0}',  &['\u{baf}']), ('\u{bf2}',  &['\u{b9a}',  '\u{bc2}']), ('\u{bf4}',
-         &['\u{bae}',  '\u{bc0}']), ('\u{bf5}',  &['\u{bf3}']), ('\u{bf7}',  &['\u{b8e}',
-         '\u{bb5}']), ('\u{bf8}',  &['\u{bb7}']), ('\u{bfa}',  &['\u{ba8}',  '\u{bc0}']),
-        ('\u{c00}',  &['\u{306}',  '\u{307}']), ('\u{c02}',  &['\u{6f}']), ('\u{c03}',
-         &['\u{983}']), ('\u{c13}',  &['\u{c12}',  '\u{c55}']), ('\u{c14}',  &['\u{c12}',
-         '\u{c4c}']), ('\u{c16}',  &['\u{c96}',  '\u{323}']), ('\u{c20}',  &['\u{c30}',
-         '\u{5bc}']), ('\u{c22}',  &['\u{c21}',  '\u{323}']), ('\u{c25}',  &['\u{c27}',
-         '\u{5bc}']), ('\u{c2d}',  &['\u{c2c}',  '\u{323}']), ('\u{c2e}',  &['\u{c35}',
-         '\u{c41}']), ('\u{c37}',  &['\u{c35}',  '\u{323}']), ('\u{c39}',  &['\u{c35}',
-         '\u{c3e}']), ('\u{c42}',  &['\u{c41}',  '\u{c3e}']), ('\u{c44}',  &['\u{c43}',
-         '\u{c3e}']), ('\u{c60}',  &['\u{c0b}',  '\u{c3e}']), ('\u{c61}',  &['\u{c0c}',
-         '\u{c3e}']), ('\u{c66}',  &['\u{6f}']), ('\u{c81}',  &['\u{306}',  '\u{307}']), ('\u{c82}',
-         &['\u{6f}']), ('\u{c83}',  &['\u{983}']), ('\u{c85}',  &['\u{c05}']), ('\u{c86}',
-         &['\u{c06}']), ('\u{c87}',  &['\u{c07}']), ('\u{c90}',  &['\u{c10}']), ('\u{c92}',
-         &['\u{c12}']), ('\u{c93}',  &['\u{c12}',  '\u{c55}']), ('\u{c94}',  &['\u{c12}',
-         '\u{c4c}']), ('\u{c97}',  &['\u{c17}']), ('\u{c9c}',  &['\u{c1c}']), ('\u{c9d}',
-         &['\u{c1d}']), ('\u{c9e}',  &['\u{c1e}']), ('\u{c9f}',  &['\u{c1f}']), ('\u{ca3}',
-         &['\u{c23}']), ('\u{ca6}',  &['\u{c26}']), ('\u{ca8}',  &['\u{c28}']), ('\u{caf}',
-         &['\u{c2f}']), ('\u{cb0}',  &['\u{c30}']), ('\u{cb1}',  &['\u{c31}']), ('\u{cb2}',
-         &['\u{c32}']), ('\u{cb3}',  &['\u{c33}']), ('\u{cbf}',  &['\u{c3f}']), ('\u{cc1}',
-         &['\u{c41}']), ('\u{cc3}',  &['\u{c43}']), ('\u{cdc}',  &['\u{c5c}']), ('\u{ce1}',
-         &['\u{c8c}',  '\u{cbe}']), ('\u{ce6}',  &['\u{4f}']), ('\u{ce7}',  &['\u{c67}']),
-        ('\u{ce8}',  &['\u{c68}']), ('\u{cef}',  &['\u{c6f}']), ('\u{d01}',  &['\u{306}',
-         '\u{307}']), ('\u{d02}',  &['\u{6f}']), ('\u{d03}',  &['\u{983}']), ('\u{d08}',
-         &['\u{d07}',  '\u{d57}']), ('\u{d09}',  &['\u{b89}']), ('\u{d0a}',  &['\u{b89}',
-         '\u{d57}']), ('\u{d0c}',  &['\u{d28}',  '\u{d41}']), ('\u{d10}',  &['\u{bc6}',
-         '\u{d0e}']), ('\u{d13}',  &['\u{d12}',  '\u{d3e}']), ('\u{d14}',  &['\u{d12}',
-         '\u{d57}']), ('\u{d16}',  &['\u{bb5}']), ('\u{d19}',  &['\u{d28}',  '\u{d41}']),
-        ('\u{d1c}',  &['\u{b90}']), ('\u{d1f}',  &['\u{73}']), ('\u{d20}',  &['\u{6f}']),
-        ('\u{d23}',  &['\u{ba3}']), ('\u{d25}',  &['\u{bae}']), ('\u{d31}',  &['\u{d30}']),
-        ('\u{d34}',  &['\u{bb4}']), ('\u{d36}',  &['\u{bb6}']), ('\u{d3a}',  &['\u{b9f}',
-         '\u{bbf}']), ('\u{d3f}',  &['\u{bbf}']), ('\u{d40}',  &['\u{bbf}']), ('\u{d42}',
-         &['\u{d41}']), ('\u{d43}',  &['\u{d41}']), ('\u{d46}',  &['\u{bc6}']), ('\u{d47}',
-         &['\u{bc7}']), ('\u{d48}',  &['\u{bc6}',  '\u{bc6}']), ('\u{d4e}',  &['\u{971}']),
-        ('\u{d5a}',  &['\u{d28}',  '\u{d4d}',  '\u{d2e}']), ('\u{d5f}',  &['\u{6f}',  '\u{d30}',
-         '\u{6f}']), ('\u{d61}',  &['\u{d1e}']), ('\u{d66}',  &['\u{6f}']), ('\u{d6a}',
-         &['\u{d30}',  '\u{d4d}']), ('\u{d6b}',  &['\u{d26}',  '\u{d4d}',  '\u{d30}']), ('\u{d6c}',
-         &['\u{d28}',  '\u{d4d}',  '\u{d28}']), ('\u{d6d}',  &['\u{39}']), ('\u{d6e}',  &['\u{d35}',
-         '\u{d4d}',  '\u{d30}']), ('\u{d6f}',  &['\u{d28}',  '\u{d4d}']), ('\u{d76}',  &['\u{d39}',
-         '\u{d4d}',  '\u{d2e}']), ('\u{d79}',  &['\u{d28}',  '\u{d41}']), ('\u{d7a}',  &['\u{ba3}',
-         '\u{d4d}']), ('\u{d7b}',  &['\u{d28}',  '\u{d4d}']), ('\u{d7c}',  &['\u{d30}',
-         '\u{d4d}']), ('\u{d7d}',  &['\u{d32}',  '\u{d4d}']), ('\u{d7e}',  &['\u{d33}',
-         '\u{d4d}']), ('\u{d82}',  &['\u{6f}']), ('\u{d83}',  &['\u{983}']), ('\u{d8d}',
-         &['\u{dc3}',  '\u{dd8}']), ('\u{d92}',  &['\u{d91}',  '\u{dca}']), ('\u{d93}',
-         &['\u{d91}',  '\u{dd9}']), ('\u{db5}',  &['\u{d91}']), ('\u{db6}',  &['\u{d9b}']),
-        ('\u{db9}',  &['\u{d94}']), ('\u{dc0}',  &['\u{da0}']), ('\u{dc4}',  &['\u{db7}']),
-        ('\u{de9}',  &['\u{de8}',  '\u{dcf}']), ('\u{dea}',  &['\u{da2}']), ('\u{deb}',
-         &['\u{daf}']), ('\u{def}',  &['\u{de8}',  '\u{dd3}']), ('\u{e03}',  &['\u{e02}']),
-        ('\u{e0b}',  &['\u{e0a}']), ('\u{e0f}',  &['\u{e0e}']), ('\u{e14}',  &['\u{e04}']),
-        ('\u{e15}',  &['\u{e04}']), ('\u{e17}',  &['\u{e11}']), ('\u{e21}',  &['\u{e06}']),
-        ('\u{e26}',  &['\u{e20}']), ('\u{e33}',  &['\u{30a}',  '\u{e32}']), ('\u{e41}',
-         &['\u{e40}',  '\u{e40}']), ('\u{e45}',  &['\u{e32}']), ('\u{e4d}',  &['\u{30a}']),
-        ('\u{e50}',  &['\u{6f}']), ('\u{e88}',  &['\u{e08}']), ('\u{e8d}',  &['\u{e22}']),
-        ('\u{e9a}',  &['\u{e1a}']), ('\u{e9b}',  &['\u{e1b}']), ('\u{e9d}',  &['\u{e1d}']),
-        ('\u{e9e}',  &['\u{e1e}']), ('\u{e9f}',  &['\u{e1f}']), ('\u{eb3}',  &['\u{30a}',
-         '\u{eb2}']), ('\u{eb8}',  &['\u{e38}']), ('\u{eb9}',  &['\u{e39}']), ('\u{ec8}',
-         &['\u{e48}']), ('\u{ec9}',  &['\u{e49}']), ('\u{eca}',  &['\u{e4a}']), ('\u{ecb}',
-         &['\u{e4b}']), ('\u{ecd}',  &['\u{30a}']), ('\u{ed0}',  &['\u{6f}']), ('\u{edc}',
-         &['\u{eab}',  '\u{e99}']), ('\u{edd}',  &['\u{eab}',  '\u{ea1}']), ('\u{f00}',
-         &['\u{f68}',  '\u{f7c}',  '\u{f7e}']), ('\u{f02}',  &['\u{f60}',  '\u{f74}',  '\u{f82}',
-         '\u{f7f}']), ('\u{f03}',  &['\u{f60}',  '\u{f74}',  '\u{f82}',  '\u{f14}']), ('\u{f0c}',
-         &['\u{f0b}']), ('\u{f0e}',  &['\u{f0d}',  '\u{f0d}']), ('\u{f1b}',  &['\u{f1a}',
-         '\u{f1a}']), ('\u{f1e}',  &['\u{f1d}',  '\u{f1d}']), ('\u{f1f}',  &['\u{f1a}',
-         '\u{f1d}']), ('\u{f37}',  &['\u{325}']), ('\u{f6a}',  &['\u{f62}']), ('\u{f77}',
-         &['\u{fb2}',  '\u{f71}',  '\u{f80}']), ('\u{f79}',  &['\u{fb3}',  '\u{f71}',  '\u{f80}']),
-        ('\u{f7b}',  &['\u{f7a}',  '\u{f7a}']), ('\u{f7d}',  &['\u{f7c}',  '\u{f7c}']), ('\u{fce}',
-         &['\u{f1d}',  '\u{f1a}']), ('\u{fd5}',  &['\u{5350}']), ('\u{fd6}',  &['\u{534d}']),
-        ('\u{1000}',  &['\u{d30}',  '\u{102c}']), ('\u{1002}',  &['\u{d30}']), ('\u{1004}',
-         &['\u{63}']), ('\u{1010}',  &['\u{6f}',  '\u{102c}']), ('\u{101d}',  &['\u{6f}']),
-        ('\u{101f}',  &['\u{1015}',  '\u{102c}']), ('\u{1023}',  &['\u{d30}',  '\u{102c}',
-         '\u{1039}',  '\u{d30}',  '\u{102c}']), ('\u{1029}',  &['\u{101e}',  '\u{103c}']),
-        ('\u{102a}',  &['\u{101e}',  '\u{103c}',  '\u{b47}',  '\u{102c}',  '\u{103a}']),
-        ('\u{1031}',  &['\u{b47}']), ('\u{1036}',  &['\u{30a}']), ('\u{1038}',  &['\u{983}']),
-        ('\u{1040}',  &['\u{6f}']), ('\u{104b}',  &['\u{104a}',  '\u{104a}']), ('\u{105a}',
-         &['\u{63}']), ('\u{1061}',  &['\u{101b}',  '\u{103e}']), ('\u{1065}',  &['\u{1041}']),
-        ('\u{1066}',  &['\u{1015}',  '\u{103e}']), ('\u{106f}',  &['\u{1015}',  '\u{102c}',
-         '\u{103e}']), ('\u{1070}',  &['\u{1003}',  '\u{103e}']), ('\u{107e}',  &['\u{107d}',
-         '\u{103e}']), ('\u{1081}',  &['\u{d30}',  '\u{103e}']), ('\u{109e}',  &['\u{1083}',
-         '\u{30a}']), ('\u{10a0}',  &['\u{a786}']), ('\u{10d7}',  &['\u{6f}',  '\u{102c}']),
-        ('\u{10d8}',  &['\u{d30}']), ('\u{10e7}',  &['\u{79}']), ('\u{10f3}',  &['\u{21d}']),
-        ('\u{10ff}',  &['\u{6f}']), ('\u{1101}',  &['\u{1100}',  '\u{1100}']), ('\u{1104}',
-         &['\u{1103}',  '\u{1103}']), ('\u{1108}',  &['\u{1107}',  '\u{1107}']), ('\u{110a}',
-         &['\u{1109}',  '\u{1109}']), ('\u{110d}',  &['\u{110c}',  '\u{110c}']), ('\u{1113}',
-         &['\u{1102}',  '\u{1100}']), ('\u{1114}',  &['\u{1102}',  '\u{1102}']), ('\u{1115}',
-         &['\u{1102}',  '\u{1103}']), ('\u{1116}',  &['\u{1102}',  '\u{1107}']), ('\u{1117}',
-         &['\u{1103}',  '\u{1100}']), ('\u{1118}',  &['\u{1105}',  '\u{1102}']), ('\u{1119}',
-         &['\u{1105}',  '\u{1105}']), ('\u{111a}',  &['\u{1105}',  '\u{1112}']), ('\u{111b}',
-         &['\u{1105}',  '\u{110b}']), ('\u{111c}',  &['\u{1106}',  '\u{1107}']), ('\u{111d}',
-         &['\u{1106}',  '\u{110b}']), ('\u{111e}',  &['\u{1107}',  '\u{1100}']), ('\u{111f}',
-         &['\u{1107}',  '\u{1102}']), ('\u{1120}',  &['\u{1107}',  '\u{1103}']), ('\u{1121}',
-         &['\u{1107}',  '\u{1109}']), ('\u{1122}',  &['\u{1107}',  '\u{1109}',  '\u{1100}']),
-        ('\u{1123}',  &['\u{1107}',  '\u{1109}',  '\u{1103}']), ('\u{1124}',  &['\u{1107}',
-         '\u{1109}',  '\u{1107}']), ('\u{1125}',  &['\u{1107}',  '\u{1109}',  '\u{1109}']),
-        ('\u{1126}',  &['\u{1107}',  '\u{1109}',  '\u{110c}']), ('\u{1127}',  &['\u{1107}',
-         '\u{110c}']), ('\u{1128}',  &['\u{1107}',  '\u{110e}']), ('\u{1129}',  &['\u{1107}',
-         '\u{1110}']), ('\u{112a}',  &['\u{1107}',  '\u{1111}']), ('\u{112b}',  &['\u{1107}',
-         '\u{110b}']), ('\u{112c}',  &['\u{1107}',  '\u{1107}',  '\u{110b}']), ('\u{112d}',
-         &['\u{1109}',  '\u{1100}']), ('\u{112e}',  &['\u{1109}',  '\u{1102}']), ('\u{112f}',
-         &['\u{1109}',  '\u{1103}']), ('\u{1130}',  &['\u{1109}',  '\u{1105}']), ('\u{1131}',
-         &['\u{1109}',  '\u{1106}']), ('\u{1132}',  &['\u{1109}',  '\u{1107}']), ('\u{1133}',
-         &['\u{1109}',  '\u{1107}',  '\u{1100}']), ('\u{1134}',  &['\u{1109}',  '\u{1109}',
-         '\u{1109}']), ('\u{1135}',  &['\u{1109}',  '\u{110b}']), ('\u{1136}',  &['\u{1109}',
-         '\u{110c}']), ('\u{1137}',  &['\u{1109}',  '\u{110e}']), ('\u{1138}',  &['\u{1109}',
-         '\u{110f}']), ('\u{1139}',  &['\u{1109}',  '\u{1110}']), ('\u{113a}',  &['\u{1109}',
-         '\u{1111}']), ('\u{113b}',  &['\u{1105}',  '\u{1112}']), ('\u{113d}',  &['\u{113c}',
-         '\u{113c}']), ('\u{113f}',  &['\u{113e}',  '\u{113e}']), ('\u{1141}',  &['\u{110b}',
-         '\u{1100}']), ('\u{1142}',  &['\u{110b}',  '\u{1103}']), ('\u{1143}',  &['\u{110b}',
-         '\u{1106}']), ('\u{1144}',  &['\u{110b}',  '\u{1107}']), ('\u{1145}',  &['\u{110b}',
-         '\u{1109}']), ('\u{1146}',  &['\u{110b}',  '\u{1140}']), ('\u{1147}',  &['\u{110b}',
-         '\u{110b}']), ('\u{1148}',  &['\u{110b}',  '\u{110c}']), ('\u{1149}',  &['\u{110b}',
-         '\u{110e}']), ('\u{114a}',  &['\u{110b}',  '\u{1110}']), ('\u{114b}',  &['\u{110b}',
-         '\u{1111}']), ('\u{114d}',  &['\u{110c}',  '\u{110b}']), ('\u{114f}',  &['\u{114e}',
-         '\u{114e}']), ('\u{1151}',  &['\u{1150}',  '\u{1150}']), ('\u{1152}',  &['\u{110e}',
-         '\u{110f}']), ('\u{1153}',  &['\u{110e}',  '\u{1112}']), ('\u{1156}',  &['\u{1111}',
-         '\u{1107}']), ('\u{1157}',  &['\u{1111}',  '\u{110b}']), ('\u{1158}',  &['\u{1112}',
-         '\u{1112}']), ('\u{115a}',  &['\u{1100}',  '\u{1103}']), ('\u{115b}',  &['\u{1102}',
-         '\u{1109}']), ('\u{115c}',  &['\u{1102}',  '\u{110c}']), ('\u{115d}',  &['\u{1102}',
-         '\u{1112}']), ('\u{115e}',  &['\u{1103}',  '\u{1105}']), ('\u{1162}',  &['\u{1161}',
-         '\u{4e28}']), ('\u{1164}',  &['\u{1163}',  '\u{4e28}']), ('\u{1166}',  &['\u{1165}',
-         '\u{4e28}']), ('\u{1168}',  &['\u{1167}',  '\u{4e28}']), ('\u{116a}',  &['\u{1169}',
-         '\u{1161}']), ('\u{116b}',  &['\u{1169}',  '\u{1161}',  '\u{4e28}']), ('\u{116c}',
-         &['\u{1169}',  '\u{4e28}']), ('\u{116f}',  &['\u{116e}',  '\u{1165}']), ('\u{1170}',
-         &['\u{116e}',  '\u{1165}',  '\u{4e28}']), ('\u{1171}',  &['\u{116e}',  '\u{4e28}']),
-        ('\u{1173}',  &['\u{30fc}']), ('\u{1174}',  &['\u{30fc}',  '\u{4e28}']), ('\u{1175}',
-         &['\u{4e28}']), ('\u{1176}',  &['\u{1161}',  '\u{1169}']), ('\u{1177}',  &['\u{1161}',
-         '\u{116e}']), ('\u{1178}',  &['\u{1163}',  '\u{1169}']), ('\u{1179}',  &['\u{1163}',
-         '\u{116d}']), ('\u{117a}',  &['\u{1165}',  '\u{1169}']), ('\u{117b}',  &['\u{1165}',
-         '\u{116e}']), ('\u{117c}',  &['\u{1165}',  '\u{30fc}']), ('\u{117d}',  &['\u{1167}',
-         '\u{1169}']), ('\u{117e}',  &['\u{1167}',  '\u{116e}']), ('\u{117f}',  &['\u{1169}',
-         '\u{1165}']), ('\u{1180}',  &['\u{1169}',  '\u{1165}',  '\u{4e28}']), ('\u{1181}',
-         &['\u{1169}',  '\u{1167}',  '\u{4e28}']), ('\u{1182}',  &['\u{1169}',  '\u{1169}']),
-        ('\u{1183}',  &['\u{1169}',  '\u{116e}']), ('\u{1184}',  &['\u{116d}',  '\u{1163}']),
-        ('\u{1185}',  &['\u{116d}',  '\u{1163}',  '\u{4e28}']), ('\u{1186}',  &['\u{116d}',
-         '\u{1163}']), ('\u{1187}',  &['\u{116d}',  '\u{1169}']), ('\u{1188}',  &['\u{116d}',
-         '\u{4e28}']), ('\u{1189}',  &['\u{116e}',  '\u{1161}']), ('\u{118a}',  &['\u{116e}',
-         '\u{1161}',  '\u{4e28}']), ('\u{118b}',  &['\u{116e}',  '\u{1165}',  '\u{30fc}']),
-        ('\u{118c}',  &['\u{116e}',  '\u{1167}',  '\u{4e28}']), ('\u{118d}',  &['\u{116e}',
-         '\u{116e}']), ('\u{118e}',  &['\u{1172}',  '\u{1161}']), ('\u{118f}',  &['\u{1172}',
-         '\u{1165}']), ('\u{1190}',  &['\u{1172}',  '\u{1165}',  '\u{4e28}']), ('\u{1191}',
-         &['\u{1172}',  '\u{1167}']), ('\u{1192}',  &['\u{1172}',  '\u{1167}',  '\u{4e28}']),
-        ('\u{1193}',  &['\u{1172}',  '\u{116e}']), ('\u{1194}',  &['\u{1172}',  '\u{4e28}']),
-        ('\u{1195}',  &['\u{30fc}',  '\u{116e}']), ('\u{1196}',  &['\u{30fc}',  '\u{30fc}']),
-        ('\u{1197}',  &['\u{30fc}',  '\u{4e28}',  '\u{116e}']), ('\u{1198}',  &['\u{4e28}',
-         '\u{1161}']), ('\u{1199}',  &['\u{4e28}',  '\u{1163}']), ('\u{119a}',  &['\u{4e28}',
-         '\u{1169}']), ('\u{119b}',  &['\u{4e28}',  '\u{116e}']), ('\u{119c}',  &['\u{4e28}',
-         '\u{30fc}']), ('\u{119d}',  &['\u{4e28}',  '\u{119e}']), ('\u{119f}',  &['\u{119e}',
-         '\u{1165}']), ('\u{11a0}',  &['\u{119e}',  '\u{116e}']), ('\u{11a1}',  &['\u{119e}',
-         '\u{4e28}']), ('\u{11a2}',  &['\u{119e}',  '\u{119e}']), ('\u{11a3}',  &['\u{1161}',
-         '\u{30fc}']), ('\u{11a4}',  &['\u{1163}',  '\u{116e}']), ('\u{11a5}',  &['\u{1167}',
-         '\u{1163}']), ('\u{11a6}',  &['\u{1169}',  '\u{1163}']), ('\u{11a7}',  &['\u{1169}',
-         '\u{1163}',  '\u{4e28}']), ('\u{11a8}',  &['\u{1100}']), ('\u{11a9}',  &['\u{1100}',
-         '\u{1100}']), ('\u{11aa}',  &['\u{1100}',  '\u{1109}']), ('\u{11ab}',  &['\u{1102}']),
-        ('\u{11ac}',  &['\u{1102}',  '\u{110c}']), ('\u{11ad}',  &['\u{1102}',  '\u{1112}']),
-        ('\u{11ae}',  &['\u{1103}']), ('\u{11af}',  &['\u{1105}']), ('\u{11b0}',  &['\u{1105}',
-         '\u{1100}']), ('\u{11b1}',  &['\u{1105}',  '\u{1106}']), ('\u{11b2}',  &['\u{1105}',
-         '\u{1107}']), ('\u{11b3}',  &['\u{1105}',  '\u{1109}']), ('\u{11b4}',  &['\u{1105}',
-         '\u{1110}']), ('\u{11b5}',  &['\u{1105}',  '\u{1111}']), ('\u{11b6}',  &['\u{1105}',
-         '\u{1112}']), ('\u{11b7}',  &['\u{1106}']), ('\u{11b8}',  &['\u{1107}']), ('\u{11b9}',
-         &['\u{1107}',  '\u{1109}']), ('\u{11ba}',  &['\u{1109}']), ('\u{11bb}',  &['\u{1109}',
-         '\u{1109}']), ('\u{11bc}',  &['\u{110b}']), ('\u{11bd}',  &['\u{110c}']), ('\u{11be}',
-         &['\u{110e}']), ('\u{11bf}',  &['\u{110f}']), ('\u{11c0}',  &['\u{1110}']), ('\u{11c1}',
-         &['\u{1111}']), ('\u{11c2}',  &['\u{1112}']), ('\u{11c3}',  &['\u{1100}',  '\u{1105}']),
-        ('\u{11c4}',  &['\u{1100}',  '\u{1109}',  '\u{1100}']), ('\u{11c5}',  &['\u{1102}',
-         '\u{1100}']), ('\u{11c6}',  &['\u{1102}',  '\u{1103}']), ('\u{11c7}',  &['\u{1102}',
-         '\u{1109}']), ('\u{11c8}',  &['\u{1102}',  '\u{1140}']), ('\u{11c9}',  &['\u{1102}',
-         '\u{1110}']), ('\u{11ca}',  &['\u{1103}',  '\u{1100}']), ('\u{11cb}',  &['\u{1103}',
-         '\u{1105}']), ('\u{11cc}',  &['\u{1105}',  '\u{1100}',  '\u{1109}']), ('\u{11cd}',
-         &['\u{1105}',  '\u{1102}']), ('\u{11ce}',  &['\u{1105}',  '\u{1103}']), ('\u{11cf}',
-         &['\u{1105}',  '\u{1103}',  '\u{1112}']), ('\u{11d0}',  &['\u{1105}',  '\u{1105}']),
-        ('\u{11d1}',  &['\u{1105}',  '\u{1106}',  '\u{1100}']), ('\u{11d2}',  &['\u{1105}',
-         '\u{1106}',  '\u{1109}']), ('\u{11d3}',  &['\u{1105}',  '\u{1107}',  '\u{1109}']),
-        ('\u{11d4}',  &['\u{1105}',  '\u{1107}',  '\u{1112}']), ('\u{11d5}',  &['\u{1105}',
-         '\u{1107}',  '\u{110b}']), ('\u{11d6}',  &['\u{1105}',  '\u{1109}',  '\u{1109}']),
-        ('\u{11d7}',  &['\u{1105}',  '\u{1140}']), ('\u{11d8}',  &['\u{1105}',  '\u{110f}']),
-        ('\u{11d9}',  &['\u{1105}',  '\u{1159}']), ('\u{11da}',  &['\u{1106}',  '\u{1100}']),
-        ('\u{11db}',  &['\u{1106}',  '\u{1105}']), ('\u{11dc}',  &['\u{1106}',  '\u{1107}']),
-        ('\u{11dd}',  &['\u{1106}',  '\u{1109}']), ('\u{11de}',  &['\u{1106}',  '\u{1109}',
-         '\u{1109}']), ('\u{11df}',  &['\u{1106}',  '\u{1140}']), ('\u{11e0}',  &['\u{1106}',
-         '\u{110e}']), ('\u{11e1}',  &['\u{1106}',  '\u{1112}']), ('\u{11e2}',  &['\u{1106}',
-         '\u{110b}']), ('\u{11e3}',  &['\u{1107}',  '\u{1105}']), ('\u{11e4}',  &['\u{1107}',
-         '\u{1111}']), ('\u{11e5}',  &['\u{1107}',  '\u{1112}']), ('\u{11e6}',  &['\u{1107}',
-         '\u{110b}']), ('\u{11e7}',  &['\u{1109}',  '\u{1100}']), ('\u{11e8}',  &['\u{1109}',
-         '\u{1103}']), ('\u{11e9}',  &['\u{1109}',  '\u{1105}']), ('\u{11ea}',  &['\u{1109}',
-         '\u{1107}']), ('\u{11eb}',  &['\u{1140}']), ('\u{11ec}',  &['\u{110b}',  '\u{1100}']),
-        ('\u{11ed}',  &['\u{110b}',  '\u{1100}',  '\u{1100}']), ('\u{11ee}',  &['\u{110b}',
-         '\u{110b}']), ('\u{11ef}',  &['\u{110b}',  '\u{110f}']), ('\u{11f0}',  &['\u{114c}']),
-        ('\u{11f1}',  &['\u{110b}',  '\u{1109}']), ('\u{11f2}',  &['\u{110b}',  '\u{1140}']),
-        ('\u{11f3}',  &['\u{1111}',  '\u{1107}']), ('\u{11f4}',  &['\u{1111}',  '\u{110b}']),
-        ('\u{11f5}',  &['\u{1112}',  '\u{1102}']), ('\u{11f6}',  &['\u{1112}',  '\u{1105}']),
-        ('\u{11f7}',  &['\u{1112}',  '\u{1106}']), ('\u{11f8}',  &['\u{1112}',  '\u{1107}']),
-        ('\u{11f9}',  &['\u{1159}']), ('\u{11fa}',  &['\u{1100}',  '\u{1102}']), ('\u{11fb}',
-         &['\u{1100}',  '\u{1107}']), ('\u{11fc}',  &['\u{1100}',  '\u{110e}']), ('\u{11fd}',
-         &['\u{1100}',  '\u{110f}']), ('\u{11fe}',  &['\u{1100}',  '\u{1112}']), ('\u{11ff}',
-         &['\u{1102}',  '\u{1102}']), ('\u{1200}',  &['\u{55}']), ('\u{1223}',  &['\u{270}']),
-        ('\u{1240}',  &['\u{3a6}']), ('\u{1260}',  &['\u{548}']), ('\u{1294}',  &['\u{571}']),
-        ('\u{12d0}',  &['\u{4f}']), ('\u{13a0}',  &['\u{44}']), ('\u{13a1}',  &['\u{52}']),
-        ('\u{13a2}',  &['\u{54}']), ('\u{13a4}',  &['\u{4f}',  '\u{27}']), ('\u{13a5}',
-         &['\u{69}']), ('\u{13a8}',  &['\u{2c75}']), ('\u{13a9}',  &['\u{59}']), ('\u{13aa}',
-         &['\u{41}']), ('\u{13ab}',  &['\u{4a}']), ('\u{13ac}',  &['\u{45}']), ('\u{13ae}',
-         &['\u{3f}']), ('\u{13b0}',  &['\u{2c75}']), ('\u{13b1}',  &['\u{393}']), ('\u{13b3}',
-         &['\u{57}']), ('\u{13b7}',  &['\u{4d}']), ('\u{13bb}',  &['\u{48}']), ('\u{13bd}',
-         &['\u{59}']), ('\u{13be}',  &['\u{4f}',  '\u{335}']), ('\u{13bf}',  &['\u{1ab}']),
-        ('\u{13c0}',  &['\u{47}']), ('\u{13c2}',  &['\u{68}']), ('\u{13c3}',  &['\u{5a}']),
-        ('\u{13c7}',  &['\u{460}']), ('\u{13cb}',  &['\u{190}']), ('\u{13cc}',  &['\u{55}',
-         '\u{335}']), ('\u{13ce}',  &['\u{34}']), ('\u{13cf}',  &['\u{62}']), ('\u{13d2}',
-         &['\u{52}']), ('\u{13d4}',  &['\u{57}']), ('\u{13d5}',  &['\u{53}']), ('\u{13d9}',
-         &['\u{56}']), ('\u{13da}',  &['\u{53}']), ('\u{13de}',  &['\u{4c}']), ('\u{13df}',
-         &['\u{43}']), ('\u{13e2}',  &['\u{50}']), ('\u{13e6}',  &['\u{4b}']), ('\u{13e7}',
-         &['\u{64}']), ('\u{13eb}',  &['\u{4f}',  '\u{335}']), ('\u{13ee}',  &['\u{36}']),
-        ('\u{13f0}',  &['\u{df}']), ('\u{13f2}',  &['\u{68}',  '\u{314}']), ('\u{13f3}',
-         &['\u{47}']), ('\u{13f4}',  &['\u{42}']), ('\u{13fb}',  &['\u{262}']), ('\u{13fc}',
-         &['\u{299}']), ('\u{1400}',  &['\u{3d}']), ('\u{1403}',  &['\u{394}']), ('\u{140c}',
-         &['\u{b7}',  '\u{1401}']), ('\u{140d}',  &['\u{1401}',  '\u{b7}']), ('\u{140e}',
-         &['\u{b7}',  '\u{394}']), ('\u{140f}',  &['\u{394}',  '\u{b7}']), ('\u{1410}',  &['\u{b7}',
-         '\u{1404}']), ('\u{1411}',  &['\u{1404}',  '\u{b7}']), ('\u{1412}',  &['\u{b7}',
-         '\u{1405}']), ('\u{1413}',  &['\u{1405}',  '\u{b7}']), ('\u{1414}',  &['\u{b7}',
-         '\u{1406}']), ('\u{1415}',  &['\u{1406}',  '\u{b7}']), ('\u{1417}',  &['\u{b7}',
-         '\u{140a}']), ('\u{1418}',  &['\u{140a}',  '\u{b7}']), ('\u{1419}',  &['\u{b7}',
-         '\u{140b}']), ('\u{141a}',  &['\u{140b}',  '\u{b7}']), ('\u{1427}',  &['\u{b7}']),
-        ('\u{142b}',  &['\u{1401}',  '\u{1420}']), ('\u{142c}',  &['\u{394}',  '\u{1420}']),
-        ('\u{142d}',  &['\u{1405}',  '\u{1420}']), ('\u{142e}',  &['\u{140a}',  '\u{1420}']),
-        ('\u{142f}',  &['\u{56}']), ('\u{1431}',  &['\u{245}']), ('\u{1433}',  &['\u{3e}']),
-        ('\u{1437}',  &['\u{b7}',  '\u{3e}']), ('\u{1438}',  &['\u{3c}']), ('\u{143a}',  &['\u{b7}',
-         '\u{56}']), ('\u{143b}',  &['\u{56}',  '\u{b7}']), ('\u{143c}',  &['\u{b7}',  '\u{245}']),
-        ('\u{143d}',  &['\u{245}',  '\u{b7}']), ('\u{143e}',  &['\u{b7}',  '\u{1432}']),
-        ('\u{143f}',  &['\u{1432}',  '\u{b7}']), ('\u{1440}',  &['\u{b7}',  '\u{3e}']), ('\u{1441}',
-         &['\u{3e}',  '\u{b7}']), ('\u{1442}',  &['\u{b7}',  '\u{1434}']), ('\u{1443}',
-         &['\u{1434}',  '\u{b7}']), ('\u{1444}',  &['\u{b7}',  '\u{3c}']), ('\u{1445}',  &['\u{3c}',
-         '\u{b7}']), ('\u{1446}',  &['\u{b7}',  '\u{1439}']), ('\u{1447}',  &['\u{1439}',
-         '\u{b7}']), ('\u{144a}',  &['\u{27}']), ('\u{144c}',  &['\u{55}']), ('\u{144e}',
-         &['\u{548}']), ('\u{1454}',  &['\u{b7}',  '\u{1450}']), ('\u{1457}',  &['\u{b7}',
-         '\u{55}']), ('\u{1458}',  &['\u{55}',  '\u{b7}']), ('\u{1459}',  &['\u{b7}',  '\u{548}']),
-        ('\u{145a}',  &['\u{548}',  '\u{b7}']), ('\u{145b}',  &['\u{b7}',  '\u{144f}']),
-        ('\u{145c}',  &['\u{144f}',  '\u{b7}']), ('\u{145d}',  &['\u{b7}',  '\u{1450}']),
-        ('\u{145e}',  &['\u{1450}',  '\u{b7}']), ('\u{145f}',  &['\u{b7}',  '\u{1451}']),
-        ('\u{1460}',  &['\u{1451}',  '\u{b7}']), ('\u{1461}',  &['\u{b7}',  '\u{1455}']),
-        ('\u{1462}',  &['\u{1455}',  '\u{b7}']), ('\u{1463}',  &['\u{b7}',  '\u{1456}']),
-        ('\u{1464}',  &['\u{1456}',  '\u{b7}']), ('\u{1467}',  &['\u{55}',  '\u{27}']), ('\u{1468}',
-         &['\u{548}',  '\u{27}']), ('\u{1469}',  &['\u{1450}',  '\u{27}']), ('\u{146a}',
-         &['\u{1455}',  '\u{27}']), ('\u{146d}',  &['\u{50}']), ('\u{146f}',  &['\u{64}']),
-        ('\u{1472}',  &['\u{62}']), ('\u{1473}',  &['\u{62}',  '\u{307}']), ('\u{1474}',
-         &['\u{b7}',  '\u{146b}']), ('\u{1475}',  &['\u{146b}',  '\u{b7}']), ('\u{1476}',
-         &['\u{b7}',  '\u{50}']), ('\u{1477}',  &['\u{70}',  '\u{b7}']), ('\u{1478}',  &['\u{b7}',
-         '\u{146e}']), ('\u{1479}',  &['\u{146e}',  '\u{b7}']), ('\u{147a}',  &['\u{b7}',
-         '\u{64}']), ('\u{147b}',  &['\u{64}',  '\u{b7}']), ('\u{147c}',  &['\u{b7}',  '\u{1470}']),
-        ('\u{147d}',  &['\u{1470}',  '\u{b7}']), ('\u{147e}',  &['\u{b7}',  '\u{62}']), ('\u{147f}',
-         &['\u{62}',  '\u{b7}']), ('\u{1480}',  &['\u{b7}',  '\u{62}',  '\u{307}']), ('\u{1481}',
-         &['\u{62}',  '\u{307}',  '\u{b7}']), ('\u{1485}',  &['\u{146b}',  '\u{27}']), ('\u{1486}',
-         &['\u{50}',  '\u{27}']), ('\u{1487}',  &['\u{64}',  '\u{27}']), ('\u{1488}',  &['\u{62}',
-         '\u{27}']), ('\u{148d}',  &['\u{4a}']), ('\u{1492}',  &['\u{b7}',  '\u{1489}']),
-        ('\u{1493}',  &['\u{1489}',  '\u{b7}']), ('\u{1494}',  &['\u{b7}',  '\u{148b}']),
-        ('\u{1495}',  &['\u{148b}',  '\u{b7}']), ('\u{1496}',  &['\u{b7}',  '\u{148c}']),
-        ('\u{1497}',  &['\u{148c}',  '\u{b7}']), ('\u{1498}',  &['\u{b7}',  '\u{4a}']), ('\u{1499}',
-         &['\u{4a}',  '\u{b7}']), ('\u{149a}',  &['\u{b7}',  '\u{148e}']), ('\u{149b}',
-         &['\u{148e}',  '\u{b7}']), ('\u{149c}',  &['\u{b7}',  '\u{1490}']), ('\u{149d}',
-         &['\u{1490}',  '\u{b7}']), ('\u{149e}',  &['\u{b7}',  '\u{1491}']), ('\u{149f}',
-         &['\u{1491}',  '\u{b7}']), ('\u{14a5}',  &['\u{393}']), ('\u{14aa}',  &['\u{4c}']),
-        ('\u{14ac}',  &['\u{b7}',  '\u{14a3}']), ('\u{14ad}',  &['\u{14a3}',  '\u{b7}']),
-        ('\u{14ae}',  &['\u{b7}',  '\u{393}']), ('\u{14af}',  &['\u{393}',  '\u{b7}']), ('\u{14b0}',
-         &['\u{b7}',  '\u{14a6}']), ('\u{14b1}',  &['\u{14a6}',  '\u{b7}']), ('\u{14b2}',
-         &['\u{b7}',  '\u{14a7}']), ('\u{14b3}',  &['\u{14a7}',  '\u{b7}']), ('\u{14b4}',
-         &['\u{b7}',  '\u{14a8}']), ('\u{14b5}',  &['\u{14a8}',  '\u{b7}']), ('\u{14b6}',
-         &['\u{b7}',  '\u{4c}']), ('\u{14b7}',  &['\u{6c}',  '\u{b7}']), ('\u{14b8}',  &['\u{b7}',
-         '\u{14ab}']), ('\u{14b9}',  &['\u{14ab}',  '\u{b7}']), ('\u{14bf}',  &['\u{32}']),
-        ('\u{14c9}',  &['\u{b7}',  '\u{14c0}']), ('\u{14ca}',  &['\u{14c0}',  '\u{b7}']),
-        ('\u{14cb}',  &['\u{b7}',  '\u{14c7}']), ('\u{14cc}',  &['\u{14c7}',  '\u{b7}']),
-        ('\u{14cd}',  &['\u{b7}',  '\u{14c8}']), ('\u{14ce}',  &['\u{14c8}',  '\u{b7}']),
-        ('\u{14d1}',  &['\u{1421}']), ('\u{14dc}',  &['\u{b7}',  '\u{14d3}']), ('\u{14dd}',
-         &['\u{14d3}',  '\u{b7}']), ('\u{14de}',  &['\u{b7}',  '\u{14d5}']), ('\u{14df}',
-         &['\u{14d5}',  '\u{b7}']), ('\u{14e0}',  &['\u{b7}',  '\u{14d6}']), ('\u{14e1}',
-         &['\u{14d6}',  '\u{b7}']), ('\u{14e2}',  &['\u{b7}',  '\u{14d7}']), ('\u{14e3}',
-         &['\u{14d7}',  '\u{b7}']), ('\u{14e4}',  &['\u{b7}',  '\u{14d8}']), ('\u{14e5}',
-         &['\u{14d8}',  '\u{b7}']), ('\u{14e6}',  &['\u{b7}',  '\u{14da}']), ('\u{14e7}',
-         &['\u{14da}',  '\u{b7}']), ('\u{14e8}',  &['\u{b7}',  '\u{14db}']), ('\u{14e9}',
-         &['\u{14db}',  '\u{b7}']), ('\u{14f6}',  &['\u{b7}',  '\u{14ed}']), ('\u{14f7}',
-         &['\u{14ed}',  '\u{b7}']), ('\u{14f8}',  &['\u{b7}',  '\u{14ef}']), ('\u{14f9}',
-         &['\u{14ef}',  '\u{b7}']), ('\u{14fa}',  &['\u{b7}',  '\u{14f0}']), ('\u{14fb}',
-         &['\u{14f0}',  '\u{b7}']), ('\u{14fc}',  &['\u{b7}',  '\u{14f1}']), ('\u{14fd}',
-         &['\u{14f1}',  '\u{b7}']), ('\u{14fe}',  &['\u{b7}',  '\u{14f2}']), ('\u{14ff}',
-         &['\u{14f2}',  '\u{b7}']), ('\u{1500}',  &['\u{b7}',  '\u{14f4}']), ('\u{1501}',
-         &['\u{14f4}',  '\u{b7}']), ('\u{1502}',  &['\u{b7}',  '\u{14f5}']), ('\u{1503}',
-         &['\u{14f5}',  '\u{b7}']), ('\u{150c}',  &['\u{150b}',  '\u{3c}']), ('\u{150d}',
-         &['\u{150b}',  '\u{1455}']), ('\u{150e}',  &['\u{150b}',  '\u{62}']), ('\u{150f}',
-         &['\u{150b}',  '\u{1490}']), ('\u{1517}',  &['\u{b7}',  '\u{1510}']), ('\u{1518}',
-         &['\u{1510}',  '\u{b7}']), ('\u{1519}',  &['\u{b7}',  '\u{1511}']), ('\u{151a}',
-         &['\u{1511}',  '\u{b7}']), ('\u{151b}',  &['\u{b7}',  '\u{1512}']), ('\u{151c}',
-         &['\u{1512}',  '\u{b7}']), ('\u{151d}',  &['\u{b7}',  '\u{1513}']), ('\u{151e}',
-         &['\u{1513}',  '\u{b7}']), ('\u{151f}',  &['\u{b7}',  '\u{1514}']), ('\u{1520}',
-         &['\u{1514}',  '\u{b7}']), ('\u{1521}',  &['\u{b7}',  '\u{1515}']), ('\u{1522}',
-         &['\u{1515}',  '\u{b7}']), ('\u{1523}',  &['\u{b7}',  '\u{1516}']), ('\u{1524}',
-         &['\u{1516}',  '\u{b7}']), ('\u{152f}',  &['\u{b7}',  '\u{34}']), ('\u{1530}',  &['\u{34}',
-         '\u{b7}']), ('\u{1531}',  &['\u{b7}',  '\u{1528}']), ('\u{1532}',  &['\u{1528}',
-         '\u{b7}']), ('\u{1533}',  &['\u{b7}',  '\u{1529}']), ('\u{1534}',  &['\u{1529}',
-         '\u{b7}']), ('\u{1535}',  &['\u{b7}',  '\u{152a}']), ('\u{1536}',  &['\u{152a}',
-         '\u{b7}']), ('\u{1537}',  &['\u{b7}',  '\u{152b}']), ('\u{1538}',  &['\u{152b}',
-         '\u{b7}']), ('\u{1539}',  &['\u{b7}',  '\u{152d}']), ('\u{153a}',  &['\u{152d}',
-         '\u{b7}']), ('\u{153b}',  &['\u{b7}',  '\u{152e}']), ('\u{153c}',  &['\u{152e}',
-         '\u{b7}']), ('\u{1540}',  &['\u{1429}']), ('\u{1541}',  &['\u{78}']), ('\u{154e}',
-         &['\u{b7}',  '\u{154c}']), ('\u{154f}',  &['\u{154c}',  '\u{b7}']), ('\u{155b}',
-         &['\u{b7}',  '\u{155a}']), ('\u{155c}',  &['\u{155a}',  '\u{b7}']), ('\u{1568}',
-         &['\u{b7}',  '\u{1567}']), ('\u{1569}',  &['\u{1567}',  '\u{b7}']), ('\u{1577}',
-         &['\u{1e9f}']), ('\u{157c}',  &['\u{48}']), ('\u{157d}',  &['\u{78}']), ('\u{157e}',
-         &['\u{1550}',  '\u{146c}']), ('\u{157f}',  &['\u{1550}',  '\u{50}']), ('\u{1580}',
-         &['\u{1550}',  '\u{146e}']), ('\u{1581}',  &['\u{1550}',  '\u{64}']), ('\u{1582}',
-         &['\u{1550}',  '\u{1470}']), ('\u{1583}',  &['\u{1550}',  '\u{62}']), ('\u{1584}',
-         &['\u{1550}',  '\u{62}',  '\u{307}']), ('\u{1585}',  &['\u{1550}',  '\u{1483}']),
-        ('\u{1587}',  &['\u{52}']), ('\u{158e}',  &['\u{1595}',  '\u{148a}']), ('\u{158f}',
-         &['\u{1595}',  '\u{148b}']), ('\u{1590}',  &['\u{1595}',  '\u{148c}']), ('\u{1591}',
-         &['\u{1595}',  '\u{4a}']), ('\u{1592}',  &['\u{1595}',  '\u{148e}']), ('\u{1593}',
-         &['\u{1595}',  '\u{1490}']), ('\u{1594}',  &['\u{1595}',  '\u{1491}']), ('\u{15af}',
-         &['\u{62}']), ('\u{15b4}',  &['\u{46}']), ('\u{15b5}',  &['\u{2132}']), ('\u{15b7}',
-         &['\u{a7fb}']), ('\u{15c4}',  &['\u{2c6f}']), ('\u{15c5}',  &['\u{41}']), ('\u{15de}',
-         &['\u{44}']), ('\u{15ea}',  &['\u{44}']), ('\u{15ef}',  &['\u{460}']), ('\u{15f0}',
-         &['\u{4d}']), ('\u{15f7}',  &['\u{42}']), ('\u{1602}',  &['\u{1490}']), ('\u{1603}',
-         &['\u{1489}']), ('\u{1604}',  &['\u{14d3}']), ('\u{1607}',  &['\u{14da}']), ('\u{1622}',
-         &['\u{1543}']), ('\u{1623}',  &['\u{1546}']), ('\u{1624}',  &['\u{154a}']), ('\u{162e}',
-         &['\u{1b1}']), ('\u{162f}',  &['\u{3a9}']), ('\u{1634}',  &['\u{1b1}']), ('\u{1635}',
-         &['\u{3a9}']), ('\u{166d}',  &['\u{58}']), ('\u{166e}',  &['\u{78}']), ('\u{166f}',
-         &['\u{1550}',  '\u{146b}']), ('\u{1670}',  &['\u{1595}',  '\u{1489}']), ('\u{1671}',
-         &['\u{1596}',  '\u{148b}']), ('\u{1672}',  &['\u{1596}',  '\u{148c}']), ('\u{1673}',
-         &['\u{1596}',  '\u{4a}']), ('\u{1674}',  &['\u{1596}',  '\u{148e}']), ('\u{1675}',
-         &['\u{1596}',  '\u{1490}']), ('\u{1676}',  &['\u{1596}',  '\u{1491}']), ('\u{1677}',
-         &['\u{15a7}',  '\u{b7}']), ('\u{1678}',  &['\u{15a8}',  '\u{b7}']), ('\u{1679}',
-         &['\u{15a9}',  '\u{b7}']), ('\u{167a}',  &['\u{15aa}',  '\u{b7}']), ('\u{167b}',
-         &['\u{15ab}',  '\u{b7}']), ('\u{167c}',  &['\u{15ac}',  '\u{b7}']), ('\u{167d}',
-         &['\u{15ad}',  '\u{b7}']), ('\u{1680}',  &['\u{20}']), ('\u{16b2}',  &['\u{3c}']),
-        ('\u{16b7}',  &['\u{58}']), ('\u{16c1}',  &['\u{6c}']), ('\u{16c2}',  &['\u{16bd}']),
-        ('\u{16cc}',  &['\u{27}']), ('\u{16d5}',  &['\u{4b}']), ('\u{16d6}',  &['\u{4d}']),
-        ('\u{16d8}',  &['\u{3a8}']), ('\u{16e1}',  &['\u{16bc}']), ('\u{16eb}',  &['\u{b7}']),
-        ('\u{16ec}',  &['\u{3a}']), ('\u{16ed}',  &['\u{2b}']), ('\u{16f0}',  &['\u{3a6}']),
-        ('\u{1734}',  &['\u{1715}']), ('\u{1735}',  &['\u{2f}']), ('\u{178f}',  &['\u{178a}']),
-        ('\u{17a3}',  &['\u{17a2}']), ('\u{17b7}',  &['\u{e34}']), ('\u{17b8}',  &['\u{e35}']),
-        ('\u{17b9}',  &['\u{e36}']), ('\u{17ba}',  &['\u{e37}']), ('\u{17c6}',  &['\u{30a}']),
-        ('\u{17cb}',  &['\u{e48}']), ('\u{17d3}',  &['\u{30a}']), ('\u{17d4}',  &['\u{e2f}']),
-        ('\u{17d5}',  &['\u{e5a}']), ('\u{17d9}',  &['\u{e4f}']), ('\u{17da}',  &['\u{e5b}']),
-        ('\u{17e0}',  &['\u{6f}']), ('\u{1803}',  &['\u{3a}']), ('\u{1809}',  &['\u{3a}']),
-        ('\u{1855}',  &['\u{1835}']), ('\u{1896}',  &['\u{185c}']), ('\u{18b3}',  &['\u{b7}',
-         '\u{18b1}']), ('\u{18b6}',  &['\u{b7}',  '\u{18b4}']), ('\u{18b9}',  &['\u{b7}',
-         '\u{18b8}']), ('\u{18c2}',  &['\u{b7}',  '\u{18c0}']), ('\u{18c6}',  &['\u{b7}',
-         '\u{14c2}']), ('\u{18c7}',  &['\u{14c2}',  '\u{b7}']), ('\u{18c8}',  &['\u{b7}',
-         '\u{14c3}']), ('\u{18c9}',  &['\u{14c3}',  '\u{b7}']), ('\u{18ca}',  &['\u{b7}',
-         '\u{14c4}']), ('\u{18cb}',  &['\u{14c4}',  '\u{b7}']), ('\u{18cc}',  &['\u{b7}',
-         '\u{14c5}']), ('\u{18cd}',  &['\u{14c5}',  '\u{b7}']), ('\u{18ce}',  &['\u{b7}',
-         '\u{1543}']), ('\u{18cf}',  &['\u{b7}',  '\u{1546}']), ('\u{18d0}',  &['\u{b7}',
-         '\u{1547}']), ('\u{18d1}',  &['\u{b7}',  '\u{1548}']), ('\u{18d2}',  &['\u{b7}',
-         '\u{1549}']), ('\u{18d3}',  &['\u{b7}',  '\u{154b}']), ('\u{18db}',  &['\u{18f5}']),
-        ('\u{18dc}',  &['\u{18df}',  '\u{141e}']), ('\u{18dd}',  &['\u{141e}',  '\u{18df}']),
-        ('\u{18e0}',  &['\u{1543}',  '\u{b7}']), ('\u{18e3}',  &['\u{155e}',  '\u{b7}']),
-        ('\u{18e4}',  &['\u{1566}',  '\u{b7}']), ('\u{18e5}',  &['\u{156b}',  '\u{b7}']),
-        ('\u{18e8}',  &['\u{1586}',  '\u{b7}']), ('\u{18ea}',  &['\u{1597}',  '\u{b7}']),
-        ('\u{18ed}',  &['\u{460}',  '\u{b7}']), ('\u{18f0}',  &['\u{15f4}',  '\u{b7}']),
-        ('\u{18f2}',  &['\u{161b}',  '\u{b7}']), ('\u{19d0}',  &['\u{199e}']), ('\u{19d1}',
-         &['\u{19b1}']), ('\u{1a80}',  &['\u{1a45}']), ('\u{1a90}',  &['\u{1a45}']), ('\u{1aa9}',
-         &['\u{1aa8}',  '\u{1aa8}']), ('\u{1aab}',  &['\u{1aaa}',  '\u{1aa8}']), ('\u{1ab4}',
-         &['\u{6db}']), ('\u{1ab7}',  &['\u{328}']), ('\u{1ad9}',  &['\u{1ac6}']), ('\u{1ae2}',
-         &['\u{304}']), ('\u{1ae7}',  &['\u{1ae5}']), ('\u{1ae8}',  &['\u{304}',  '\u{304}']),
-        ('\u{1b52}',  &['\u{1b0d}']), ('\u{1b53}',  &['\u{1b11}']), ('\u{1b58}',  &['\u{1b28}']),
-        ('\u{1b5c}',  &['\u{1b50}']), ('\u{1b5f}',  &['\u{1b5e}',  '\u{1b5e}']), ('\u{1c3c}',
-         &['\u{1c3b}',  '\u{1c3b}']), ('\u{1c7f}',  &['\u{1c7e}',  '\u{1c7e}']), ('\u{1cd0}',
-         &['\u{302}']), ('\u{1cd2}',  &['\u{304}']), ('\u{1cd3}',  &['\u{27}',  '\u{27}']),
-        ('\u{1cd5}',  &['\u{32b}']), ('\u{1cd8}',  &['\u{32e}']), ('\u{1cd9}',  &['\u{32d}']),
-        ('\u{1cda}',  &['\u{30e}']), ('\u{1cdc}',  &['\u{329}']), ('\u{1cdd}',  &['\u{323}']),
-        ('\u{1cde}',  &['\u{324}']), ('\u{1ced}',  &['\u{316}']), ('\u{1d04}',  &['\u{63}']),
-        ('\u{1d08}',  &['\u{25c}']), ('\u{1d0b}',  &['\u{138}']), ('\u{1d0d}',  &['\u{28d}']),
-        ('\u{1d0f}',  &['\u{6f}']), ('\u{1d10}',  &['\u{254}']), ('\u{1d11}',  &['\u{6f}']),
-        ('\u{1d14}',  &['\u{1dd}',  '\u{6f}']), ('\u{1d1c}',  &['\u{75}']), ('\u{1d20}',
-         &['\u{76}']), ('\u{1d21}',  &['\u{77}']), ('\u{1d22}',  &['\u{7a}']), ('\u{1d24}',
-         &['\u{1a8}']), ('\u{1d26}',  &['\u{72}']), ('\u{1d27}',  &['\u{28c}']), ('\u{1d28}',
-         &['\u{3c0}']), ('\u{1d29}',  &['\u{1d18}']), ('\u{1d2b}',  &['\u{43b}']), ('\u{1d3e}',
-         &['\u{18d6}']), ('\u{1d52}',  &['\u{ba}']), ('\u{1d6b}',  &['\u{75}',  '\u{65}']),
-        ('\u{1d6e}',  &['\u{66}',  '\u{334}']), ('\u{1d6f}',  &['\u{72}',  '\u{6e}',  '\u{334}']),
-        ('\u{1d70}',  &['\u{6e}',  '\u{334}']), ('\u{1d72}',  &['\u{72}',  '\u{334}']), ('\u{1d73}',
-         &['\u{27e}',  '\u{334}']), ('\u{1d74}',  &['\u{73}',  '\u{334}']), ('\u{1d75}',
-         &['\u{74}',  '\u{334}']), ('\u{1d76}',  &['\u{7a}',  '\u{334}']), ('\u{1d78}',
-         &['\u{1d34}']), ('\u{1d7b}',  &['\u{69}',  '\u{335}']), ('\u{1d7c}',  &['\u{69}',
-         '\u{335}']), ('\u{1d7d}',  &['\u{70}',  '\u{335}']), ('\u{1d7e}',  &['\u{75}',
-         '\u{335}']), ('\u{1d7f}',  &['\u{28a}',  '\u{335}']), ('\u{1d83}',  &['\u{67}']),
-        ('\u{1d8c}',  &['\u{79}']), ('\u{1d90}',  &['\u{24b}']), ('\u{1d9f}',  &['\u{1d4b}']),
-        ('\u{1da2}',  &['\u{1d4d}']), ('\u{1dba}',  &['\u{18d4}']), ('\u{1dbb}',  &['\u{1646}']),
-        ('\u{1de8}',  &['\u{1ada}']), ('\u{1dee}',  &['\u{2dec}']), ('\u{1e43}',  &['\u{ab51}']),
-        ('\u{1e9a}',  &['\u{1ea3}']), ('\u{1e9d}',  &['\u{66}']), ('\u{1e9e}',  &['\u{df}']),
-        ('\u{1eff}',  &['\u{79}']), ('\u{1f7d}',  &['\u{1ff4}']), ('\u{1fbd}',  &['\u{27}']),
-        ('\u{1fbe}',  &['\u{69}']), ('\u{1fbf}',  &['\u{27}']), ('\u{1fc0}',  &['\u{7e}']),
-        ('\u{1fef}',  &['\u{27}']), ('\u{1ff6}',  &['\u{13ef}']), ('\u{1ffd}',  &['\u{27}']),
-        ('\u{1ffe}',  &['\u{27}']), ('\u{2000}',  &['\u{20}']), ('\u{2001}',  &['\u{20}']),
-        ('\u{2002}',  &['\u{20}']), ('\u{2003}',  &['\u{20}']), ('\u{2004}',  &['\u{20}']),
-        ('\u{2005}',  &['\u{20}']), ('\u{2006}',  &['\u{20}']), ('\u{2007}',  &['\u{20}']),
-        ('\u{2008}',  &['\u{20}']), ('\u{2009}',  &['\u{20}']), ('\u{200a}',  &['\u{20}']),
-        ('\u{2010}',  &['\u{2d}']), ('\u{2011}',  &['\u{2d}']), ('\u{2012}',  &['\u{2d}']),
-        ('\u{2013}',  &['\u{2d}']), ('\u{2014}',  &['\u{30fc}']), ('\u{2015}',  &['\u{30fc}']),
-        ('\u{2016}',  &['\u{6c}',  '\u{6c}']), ('\u{2018}',  &['\u{27}']), ('\u{2019}',
-         &['\u{27}']), ('\u{201a}',  &['\u{2c}']), ('\u{201b}',  &['\u{27}']), ('\u{201c}',
-         &['\u{27}',  '\u{27}']), ('\u{201d}',  &['\u{27}',  '\u{27}']), ('\u{201f}',  &['\u{27}',
-         '\u{27}']), ('\u{2022}',  &['\u{b7}']), ('\u{2024}',  &['\u{2e}']), ('\u{2025}',
-         &['\u{2e}',  '\u{2e}']), ('\u{2026}',  &['\u{2e}',  '\u{2e}',  '\u{2e}']), ('\u{2027}',
-         &['\u{b7}']), ('\u{2028}',  &['\u{20}']), ('\u{2029}',  &['\u{20}']), ('\u{202f}',
-         &['\u{20}']), ('\u{2030}',  &['\u{ba}',  '\u{2f}',  '\u{2080}',  '\u{2080}']), ('\u{2031}',
-         &['\u{ba}',  '\u{2f}',  '\u{2080}',  '\u{2080}',  '\u{2080}']), ('\u{2032}',  &['\u{27}']),
-        ('\u{2033}',  &['\u{27}',  '\u{27}']), ('\u{2034}',  &['\u{27}',  '\u{27}',  '\u{27}']),
-        ('\u{2035}',  &['\u{27}']), ('\u{2036}',  &['\u{27}',  '\u{27}']), ('\u{2037}',  &['\u{27}',
-         '\u{27}',  '\u{27}']), ('\u{2039}',  &['\u{3c}']), ('\u{203a}',  &['\u{3e}']), ('\u{203c}',
-         &['\u{21}',  '\u{21}']), ('\u{203e}',  &['\u{2c9}']), ('\u{2041}',  &['\u{2f}']),
-        ('\u{2043}',  &['\u{2d}']), ('\u{2044}',  &['\u{2f}']), ('\u{2047}',  &['\u{3f}',
-         '\u{3f}']), ('\u{2048}',  &['\u{3f}',  '\u{21}']), ('\u{2049}',  &['\u{21}',  '\u{3f}']),
-        ('\u{204e}',  &['\u{2a}']), ('\u{2052}',  &['\u{ba}',  '\u{2f}',  '\u{2080}']), ('\u{2053}',
-         &['\u{7e}']), ('\u{2057}',  &['\u{27}',  '\u{27}',  '\u{27}',  '\u{27}']), ('\u{205a}',
-         &['\u{3a}']), ('\u{205d}',  &['\u{2d57}']), ('\u{205e}',  &['\u{2d42}']), ('\u{205f}',
-         &['\u{20}']), ('\u{2070}',  &['\u{ba}']), ('\u{2079}',  &['\u{a770}']), ('\u{20a1}',
-         &['\u{43}',  '\u{20eb}']), ('\u{20a4}',  &['\u{a3}']), ('\u{20a5}',  &['\u{72}',  '\u{6e}',
-         '\u{338}']), ('\u{20a8}',  &['\u{52}',  '\u{73}']), ('\u{20a9}',  &['\u{57}',  '\u{335}']),
-        ('\u{20ab}',  &['\u{64}',  '\u{335}',  '\u{331}']), ('\u{20ac}',  &['\u{a792}']),
-        ('\u{20ad}',  &['\u{4b}',  '\u{335}']), ('\u{20ae}',  &['\u{54}',  '\u{20eb}']),
-        ('\u{20b6}',  &['\u{6c}',  '\u{74}']), ('\u{20bd}',  &['\u{554}']), ('\u{20c1}',
-         &['\u{631}',  '\u{649}',  '\u{6c}',  '\u{644}']), ('\u{20db}',  &['\u{6db}']), ('\u{2100}',
-         &['\u{61}',  '\u{2f}',  '\u{63}']), ('\u{2101}',  &['\u{61}',  '\u{2f}',  '\u{73}']),
-        ('\u{2102}',  &['\u{43}']), ('\u{2103}',  &['\u{b0}',  '\u{43}']), ('\u{2105}',  &['\u{63}',
-         '\u{2f}',  '\u{6f}']), ('\u{2106}',  &['\u{63}',  '\u{2f}',  '\u{75}']), ('\u{2107}',
-         &['\u{190}']), ('\u{2108}',  &['\u{42d}']), ('\u{2109}',  &['\u{b0}',  '\u{46}']),
-        ('\u{210a}',  &['\u{67}']), ('\u{210b}',  &['\u{48}']), ('\u{210c}',  &['\u{48}']),
-        ('\u{210d}',  &['\u{48}']), ('\u{210e}',  &['\u{68}']), ('\u{210f}',  &['\u{68}',
-         '\u{335}']), ('\u{2110}',  &['\u{6c}']), ('\u{2111}',  &['\u{6c}']), ('\u{2112}',
-         &['\u{4c}']), ('\u{2113}',  &['\u{6c}']), ('\u{2115}',  &['\u{4e}']), ('\u{2116}',
-         &['\u{4e}',  '\u{6f}']), ('\u{2119}',  &['\u{50}']), ('\u{211a}',  &['\u{51}']),
-        ('\u{211b}',  &['\u{52}']), ('\u{211c}',  &['\u{52}']), ('\u{211d}',  &['\u{52}']),
-        ('\u{2121}',  &['\u{54}',  '\u{45}',  '\u{4c}']), ('\u{2124}',  &['\u{5a}']), ('\u{2126}',
-         &['\u{3a9}']), ('\u{2127}',  &['\u{1b1}']), ('\u{2128}',  &['\u{5a}']), ('\u{2129}',
-         &['\u{27f}']), ('\u{212a}',  &['\u{4b}']), ('\u{212c}',  &['\u{42}']), ('\u{212d}',
-         &['\u{43}']), ('\u{212e}',  &['\u{65}']), ('\u{212f}',  &['\u{65}']), ('\u{2130}',
-         &['\u{45}']), ('\u{2131}',  &['\u{46}']), ('\u{2133}',  &['\u{4d}']), ('\u{2134}',
-         &['\u{6f}']), ('\u{2135}',  &['\u{5d0}']), ('\u{2136}',  &['\u{5d1}']), ('\u{2137}',
-         &['\u{5d2}']), ('\u{2138}',  &['\u{5d3}']), ('\u{2139}',  &['\u{69}']), ('\u{213b}',
-         &['\u{46}',  '\u{41}',  '\u{58}']), ('\u{213c}',  &['\u{3c0}']), ('\u{213d}',
-         &['\u{79}']), ('\u{213e}',  &['\u{393}']), ('\u{213f}',  &['\u{3a0}']), ('\u{2140}',
-         &['\u{1a9}']), ('\u{2141}',  &['\u{a4e8}']), ('\u{2142}',  &['\u{a4f6}']), ('\u{2143}',
-         &['\u{16f00}']), ('\u{2145}',  &['\u{44}']), ('\u{2146}',  &['\u{64}']), ('\u{2147}',
-         &['\u{65}']), ('\u{2148}',  &['\u{69}']), ('\u{2149}',  &['\u{6a}']), ('\u{2160}',
-         &['\u{6c}']), ('\u{2161}',  &['\u{6c}',  '\u{6c}']), ('\u{2162}',  &['\u{6c}',  '\u{6c}',
-         '\u{6c}']), ('\u{2163}',  &['\u{6c}',  '\u{56}']), ('\u{2164}',  &['\u{56}']), ('\u{2165}',
-         &['\u{56}',  '\u{6c}']), ('\u{2166}',  &['\u{56}',  '\u{6c}',  '\u{6c}']), ('\u{2167}',
-         &['\u{56}',  '\u{6c}',  '\u{6c}',  '\u{6c}']), ('\u{2168}',  &['\u{6c}',  '\u{58}']),
-        ('\u{2169}',  &['\u{58}']), ('\u{216a}',  &['\u{58}',  '\u{6c}']), ('\u{216b}',  &['\u{58}',
-         '\u{6c}',  '\u{6c}']), ('\u{216c}',  &['\u{4c}']), ('\u{216d}',  &['\u{43}']), ('\u{216e}',
-         &['\u{44}']), ('\u{216f}',  &['\u{4d}']), ('\u{2170}',  &['\u{69}']), ('\u{2171}',
-         &['\u{69}',  '\u{69}']), ('\u{2172}',  &['\u{69}',  '\u{69}',  '\u{69}']), ('\u{2173}',
-         &['\u{69}',  '\u{76}']), ('\u{2174}',  &['\u{76}']), ('\u{2175}',  &['\u{76}',  '\u{69}']),
-        ('\u{2176}',  &['\u{76}',  '\u{69}',  '\u{69}']), ('\u{2177}',  &['\u{76}',  '\u{69}',
-         '\u{69}',  '\u{69}']), ('\u{2178}',  &['\u{69}',  '\u{78}']), ('\u{2179}',  &['\u{78}']),
-        ('\u{217a}',  &['\u{78}',  '\u{69}']), ('\u{217b}',  &['\u{78}',  '\u{69}',  '\u{69}']),
-        ('\u{217c}',  &['\u{6c}']), ('\u{217d}',  &['\u{63}']), ('\u{217e}',  &['\u{64}']),
-        ('\u{217f}',  &['\u{72}',  '\u{6e}']), ('\u{2183}',  &['\u{186}']), ('\u{2184}',
-         &['\u{254}']), ('\u{2191}',  &['\u{16cf}']), ('\u{2195}',  &['\u{16e8}']), ('\u{21b5}',
-         &['\u{21b2}']), ('\u{21ba}',  &['\u{1f10e}']), ('\u{21be}',  &['\u{16da}']), ('\u{21bf}',
-         &['\u{16d0}']), ('\u{21c4}',  &['\u{1f8d0}']), ('\u{21cc}',  &['\u{1f8d1}']), ('\u{2200}',
-         &['\u{2c6f}']), ('\u{2203}',  &['\u{18e}']), ('\u{2206}',  &['\u{394}']), ('\u{220f}',
-         &['\u{3a0}']), ('\u{2211}',  &['\u{1a9}']), ('\u{2212}',  &['\u{2d}']), ('\u{2214}',
-         &['\u{2b}',  '\u{307}']), ('\u{2215}',  &['\u{2f}']), ('\u{2216}',  &['\u{5c}']),
-        ('\u{2217}',  &['\u{2a}']), ('\u{2218}',  &['\u{b0}']), ('\u{2219}',  &['\u{b7}']),
-        ('\u{221e}',  &['\u{6f}',  '\u{6f}']), ('\u{2223}',  &['\u{6c}']), ('\u{2225}',  &['\u{6c}',
-         '\u{6c}']), ('\u{2228}',  &['\u{76}']), ('\u{2229}',  &['\u{548}']), ('\u{222a}',
-         &['\u{55}']), ('\u{222b}',  &['\u{283}']), ('\u{222c}',  &['\u{283}',  '\u{283}']),
-        ('\u{222d}',  &['\u{283}',  '\u{283}',  '\u{283}']), ('\u{222f}',  &['\u{222e}',
-         '\u{222e}']), ('\u{2230}',  &['\u{222e}',  '\u{222e}',  '\u{222e}']), ('\u{2236}',
-         &['\u{3a}']), ('\u{2238}',  &['\u{2d}',  '\u{307}']), ('\u{223c}',  &['\u{7e}']),
-        ('\u{2250}',  &['\u{3d}',  '\u{307}']), ('\u{2251}',  &['\u{3d}',  '\u{307}',  '\u{323}']),
-        ('\u{2257}',  &['\u{3d}',  '\u{30a}']), ('\u{2259}',  &['\u{3d}',  '\u{302}']), ('\u{225a}',
-         &['\u{3d}',  '\u{306}']), ('\u{225e}',  &['\u{3d}',  '\u{36b}']), ('\u{2263}',
-         &['\u{2261}']), ('\u{226a}',  &['\u{3c}',  '\u{3c}']), ('\u{226b}',  &['\u{3e}',
-         '\u{3e}']), ('\u{2282}',  &['\u{1455}']), ('\u{2283}',  &['\u{1450}']), ('\u{2295}',
-         &['\u{102a8}']), ('\u{2296}',  &['\u{4f}',  '\u{335}']), ('\u{2299}',  &['\u{298}']),
-        ('\u{229d}',  &['\u{4f}',  '\u{335}']), ('\u{22a4}',  &['\u{54}']), ('\u{22a5}',
-         &['\u{a4d5}']), ('\u{22c0}',  &['\u{2227}']), ('\u{22c1}',  &['\u{76}']), ('\u{22c2}',
-         &['\u{548}']), ('\u{22c3}',  &['\u{55}']), ('\u{22c4}',  &['\u{16dc}']), ('\u{22c5}',
-         &['\u{b7}']), ('\u{22c8}',  &['\u{16de}']), ('\u{22d6}',  &['\u{3c}',  '\u{b7}']),
-        ('\u{22d7}',  &['\u{b7}',  '\u{3e}']), ('\u{22d8}',  &['\u{3c}',  '\u{3c}',  '\u{3c}']),
-        ('\u{22d9}',  &['\u{3e}',  '\u{3e}',  '\u{3e}']), ('\u{22ee}',  &['\u{2d57}']), ('\u{22ef}',
-         &['\u{b7}',  '\u{b7}',  '\u{b7}']), ('\u{22f4}',  &['\u{a793}']), ('\u{22ff}',
-         &['\u{45}']), ('\u{2300}',  &['\u{2205}']), ('\u{2325}',  &['\u{2324}']), ('\u{2329}',
-         &['\u{276c}']), ('\u{232a}',  &['\u{276d}']), ('\u{2341}',  &['\u{303c}']), ('\u{2359}',
-         &['\u{394}',  '\u{332}']), ('\u{235a}',  &['\u{16dc}',  '\u{332}']), ('\u{235c}',
-         &['\u{b0}',  '\u{332}']), ('\u{235f}',  &['\u{229b}']), ('\u{2361}',  &['\u{54}',
-         '\u{308}']), ('\u{2362}',  &['\u{2207}',  '\u{308}']), ('\u{2363}',  &['\u{22c6}',
-         '\u{308}']), ('\u{2364}',  &['\u{b0}',  '\u{308}']), ('\u{2365}',  &['\u{629}']),
-        ('\u{2368}',  &['\u{7e}',  '\u{308}']), ('\u{2369}',  &['\u{1435}']), ('\u{236b}',
-         &['\u{2207}',  '\u{334}']), ('\u{236c}',  &['\u{4f}',  '\u{335}']), ('\u{2373}',
-         &['\u{69}']), ('\u{2374}',  &['\u{70}']), ('\u{2375}',  &['\u{3c9}']), ('\u{2376}',
-         &['\u{61}',  '\u{332}']), ('\u{2377}',  &['\u{a793}',  '\u{332}']), ('\u{2378}',
-         &['\u{69}',  '\u{332}']), ('\u{2379}',  &['\u{3c9}',  '\u{332}']), ('\u{237a}',
-         &['\u{61}']), ('\u{237f}',  &['\u{16bd}']), ('\u{239c}',  &['\u{4e28}']), ('\u{239f}',
-         &['\u{4e28}']), ('\u{23a2}',  &['\u{4e28}']), ('\u{23a5}',  &['\u{4e28}']), ('\u{23aa}',
-         &['\u{4e28}']), ('\u{23ae}',  &['\u{4e28}']), ('\u{23c1}',  &['\u{2355}']), ('\u{23c2}',
-         &['\u{234e}']), ('\u{23c3}',  &['\u{234b}']), ('\u{23c6}',  &['\u{236d}']), ('\u{23e8}',
-         &['\u{2081}',  '\u{2080}']), ('\u{23fc}',  &['\u{23fb}']), ('\u{23fd}',  &['\u{6c}']),
-        ('\u{23fe}',  &['\u{263e}']), ('\u{244a}',  &['\u{5c}',  '\u{5c}']), ('\u{2460}',
-         &['\u{2780}']), ('\u{2461}',  &['\u{2781}']), ('\u{2462}',  &['\u{2782}']), ('\u{2463}',
-         &['\u{2783}']), ('\u{2464}',  &['\u{2784}']), ('\u{2465}',  &['\u{2785}']), ('\u{2466}',
-         &['\u{2786}']), ('\u{2467}',  &['\u{2787}']), ('\u{2468}',  &['\u{2788}']), ('\u{2469}',
-         &['\u{2789}']), ('\u{2474}',  &['\u{28}',  '\u{6c}',  '\u{29}']), ('\u{2475}',  &['\u{28}',
-         '\u{32}',  '\u{29}']), ('\u{2476}',  &['\u{28}',  '\u{33}',  '\u{29}']), ('\u{2477}',
-         &['\u{28}',  '\u{34}',  '\u{29}']), ('\u{2478}',  &['\u{28}',  '\u{35}',  '\u{29}']),
-        ('\u{2479}',  &['\u{28}',  '\u{36}',  '\u{29}']), ('\u{247a}',  &['\u{28}',  '\u{37}',
-         '\u{29}']), ('\u{247b}',  &['\u{28}',  '\u{38}',  '\u{29}']), ('\u{247c}',  &['\u{28}',
-         '\u{39}',  '\u{29}']), ('\u{247d}',  &['\u{28}',  '\u{6c}',  '\u{4f}',  '\u{29}']),
-        ('\u{247e}',  &['\u{28}',  '\u{6c}',  '\u{6c}',  '\u{29}']), ('\u{247f}',  &['\u{28}',
-         '\u{6c}',  '\u{32}',  '\u{29}']), ('\u{2480}',  &['\u{28}',  '\u{6c}',  '\u{33}',
-         '\u{29}']), ('\u{2481}',  &['\u{28}',  '\u{6c}',  '\u{34}',  '\u{29}']), ('\u{2482}',
-         &['\u{28}',  '\u{6c}',  '\u{35}',  '\u{29}']), ('\u{2483}',  &['\u{28}',  '\u{6c}',
-         '\u{36}',  '\u{29}']), ('\u{2484}',  &['\u{28}',  '\u{6c}',  '\u{37}',  '\u{29}']),
-        ('\u{2485}',  &['\u{28}',  '\u{6c}',  '\u{38}',  '\u{29}']), ('\u{2486}',  &['\u{28}',
-         '\u{6c}',  '\u{39}',  '\u{29}']), ('\u{2487}',  &['\u{28}',  '\u{32}',  '\u{4f}',
-         '\u{29}']), ('\u{2488}',  &['\u{6c}',  '\u{2e}']), ('\u{2489}',  &['\u{32}',  '\u{2e}']),
-        ('\u{248a}',  &['\u{33}',  '\u{2e}']), ('\u{248b}',  &['\u{34}',  '\u{2e}']), ('\u{248c}',
-         &['\u{35}',  '\u{2e}']), ('\u{248d}',  &['\u{36}',  '\u{2e}']), ('\u{248e}',  &['\u{37}',
-         '\u{2e}']), ('\u{248f}',  &['\u{38}',  '\u{2e}']), ('\u{2490}',  &['\u{39}',  '\u{2e}']),
-        ('\u{2491}',  &['\u{6c}',  '\u{4f}',  '\u{2e}']), ('\u{2492}',  &['\u{6c}',  '\u{6c}',
-         '\u{2e}']), ('\u{2493}',  &['\u{6c}',  '\u{32}',  '\u{2e}']), ('\u{2494}',  &['\u{6c}',
-         '\u{33}',  '\u{2e}']), ('\u{2495}',  &['\u{6c}',  '\u{34}',  '\u{2e}']), ('\u{2496}',
-         &['\u{6c}',  '\u{35}',  '\u{2e}']), ('\u{2497}',  &['\u{6c}',  '\u{36}',  '\u{2e}']),
-        ('\u{2498}',  &['\u{6c}',  '\u{37}',  '\u{2e}']), ('\u{2499}',  &['\u{6c}',  '\u{38}',
-         '\u{2e}']), ('\u{249a}',  &['\u{6c}',  '\u{39}',  '\u{2e}']), ('\u{249b}',  &['\u{32}',
-         '\u{4f}',  '\u{2e}']), ('\u{249c}',  &['\u{28}',  '\u{61}',  '\u{29}']), ('\u{249d}',
-         &['\u{28}',  '\u{62}',  '\u{29}']), ('\u{249e}',  &['\u{28}',  '\u{63}',  '\u{29}']),
-        ('\u{249f}',  &['\u{28}',  '\u{64}',  '\u{29}']), ('\u{24a0}',  &['\u{28}',  '\u{65}',
-         '\u{29}']), ('\u{24a1}',  &['\u{28}',  '\u{66}',  '\u{29}']), ('\u{24a2}',  &['\u{28}',
-         '\u{67}',  '\u{29}']), ('\u{24a3}',  &['\u{28}',  '\u{68}',  '\u{29}']), ('\u{24a4}',
-         &['\u{28}',  '\u{69}',  '\u{29}']), ('\u{24a5}',  &['\u{28}',  '\u{6a}',  '\u{29}']),
-        ('\u{24a6}',  &['\u{28}',  '\u{6b}',  '\u{29}']), ('\u{24a7}',  &['\u{28}',  '\u{6c}',
-         '\u{29}']), ('\u{24a8}',  &['\u{28}',  '\u{72}',  '\u{6e}',  '\u{29}']), ('\u{24a9}',
-         &['\u{28}',  '\u{6e}',  '\u{29}']), ('\u{24aa}',  &['\u{28}',  '\u{6f}',  '\u{29}']),
-        ('\u{24ab}',  &['\u{28}',  '\u{70}',  '\u{29}']), ('\u{24ac}',  &['\u{28}',  '\u{71}',
-         '\u{29}']), ('\u{24ad}',  &['\u{28}',  '\u{72}',  '\u{29}']), ('\u{24ae}',  &['\u{28}',
-         '\u{73}',  '\u{29}']), ('\u{24af}',  &['\u{28}',  '\u{74}',  '\u{29}']), ('\u{24b0}',
-         &['\u{28}',  '\u{75}',  '\u{29}']), ('\u{24b1}',  &['\u{28}',  '\u{76}',  '\u{29}']),
-        ('\u{24b2}',  &['\u{28}',  '\u{77}',  '\u{29}']), ('\u{24b3}',  &['\u{28}',  '\u{78}',
-         '\u{29}']), ('\u{24b4}',  &['\u{28}',  '\u{79}',  '\u{29}']), ('\u{24b5}',  &['\u{28}',
-         '\u{7a}',  '\u{29}']), ('\u{24b8}',  &['\u{a9}']), ('\u{24c5}',  &['\u{2117}']),
-        ('\u{24c7}',  &['\u{ae}']), ('\u{24db}',  &['\u{24be}']), ('\u{24ea}',  &['\u{1f10d}']),
-        ('\u{2500}',  &['\u{30fc}']), ('\u{2501}',  &['\u{30fc}']), ('\u{2503}',  &['\u{2502}']),
-        ('\u{250f}',  &['\u{250c}']), ('\u{2523}',  &['\u{251c}']), ('\u{256a}',  &['\u{1c2}']),
-        ('\u{2571}',  &['\u{2f}']), ('\u{2573}',  &['\u{58}']), ('\u{2588}',  &['\u{220e}']),
-        ('\u{2590}',  &['\u{258c}']), ('\u{2594}',  &['\u{2c9}']), ('\u{2597}',  &['\u{2596}']),
-        ('\u{259d}',  &['\u{2598}']), ('\u{25a0}',  &['\u{220e}']), ('\u{25b1}',  &['\u{23e5}']),
-        ('\u{25b3}',  &['\u{394}']), ('\u{25b7}',  &['\u{22b3}']), ('\u{25b8}',  &['\u{25b6}']),
-        ('\u{25ba}',  &['\u{25b6}']), ('\u{25bd}',  &['\u{102bc}']), ('\u{25c1}',  &['\u{22b2}']),
-        ('\u{25c7}',  &['\u{16dc}']), ('\u{25ca}',  &['\u{16dc}']), ('\u{25cb}',  &['\u{b0}']),
-        ('\u{25ce}',  &['\u{233e}']), ('\u{25e0}',  &['\u{2312}']), ('\u{25e6}',  &['\u{b0}']),
-        ('\u{2609}',  &['\u{298}']), ('\u{2610}',  &['\u{25a1}']), ('\u{2625}',  &['\u{1099e}']),
-        ('\u{2630}',  &['\u{39e}']), ('\u{2638}',  &['\u{2388}']), ('\u{264e}',  &['\u{224f}']),
-        ('\u{2657}',  &['\u{1fa55}']), ('\u{265d}',  &['\u{1fa57}']), ('\u{2662}',  &['\u{16dc}']),
-        ('\u{2669}',  &['\u{1d158}',  '\u{1d165}']), ('\u{266a}',  &['\u{1d158}',  '\u{1d165}',
-         '\u{1d16e}']), ('\u{26ac}',  &['\u{970}']), ('\u{2768}',  &['\u{28}']), ('\u{2769}',
-         &['\u{29}']), ('\u{276e}',  &['\u{3c}']), ('\u{276f}',  &['\u{3e}']), ('\u{2772}',
-         &['\u{28}']), ('\u{2773}',  &['\u{29}']), ('\u{2774}',  &['\u{7b}']), ('\u{2775}',
-         &['\u{7d}']), ('\u{2795}',  &['\u{2b}']), ('\u{2796}',  &['\u{2d}']), ('\u{2797}',
-         &['\u{f7}']), ('\u{27c2}',  &['\u{a4d5}']), ('\u{27c8}',  &['\u{5c}',  '\u{1455}']),
-        ('\u{27c9}',  &['\u{1450}',  '\u{2f}']), ('\u{27cb}',  &['\u{2f}']), ('\u{27cd}',
-         &['\u{5c}']), ('\u{27d9}',  &['\u{54}']), ('\u{27e8}',  &['\u{276c}']), ('\u{27e9}',
-         &['\u{276d}']), ('\u{28ff}',  &['\u{1cee0}']), ('\u{292b}',  &['\u{78}']), ('\u{292c}',
-         &['\u{78}']), ('\u{2963}',  &['\u{16d0}',  '\u{16da}']), ('\u{2965}',  &['\u{21c3}',
-         '\u{21c2}']), ('\u{296e}',  &['\u{16d0}',  '\u{21c2}']), ('\u{296f}',  &['\u{21c3}',
-         '\u{16da}']), ('\u{2999}',  &['\u{2d42}']), ('\u{29b0}',  &['\u{2349}']), ('\u{29b5}',
-         &['\u{1cef0}']), ('\u{29be}',  &['\u{233e}']), ('\u{29c4}',  &['\u{303c}']), ('\u{29c5}',
-         &['\u{2342}']), ('\u{29c7}',  &['\u{233b}']), ('\u{29d6}',  &['\u{102c0}']), ('\u{29d9}',
-         &['\u{299a}']), ('\u{29f4}',  &['\u{3a}',  '\u{2192}']), ('\u{29f5}',  &['\u{5c}']),
-        ('\u{29f6}',  &['\u{2f}',  '\u{304}']), ('\u{29f8}',  &['\u{2f}']), ('\u{29f9}',
-         &['\u{5c}']), ('\u{2a00}',  &['\u{298}']), ('\u{2a01}',  &['\u{102a8}']), ('\u{2a02}',
-         &['\u{2297}']), ('\u{2a03}',  &['\u{228d}']), ('\u{2a04}',  &['\u{228e}']), ('\u{2a05}',
-         &['\u{2293}']), ('\u{2a06}',  &['\u{2294}']), ('\u{2a0c}',  &['\u{283}',  '\u{283}',
-         '\u{283}',  '\u{283}']), ('\u{2a1d}',  &['\u{16de}']), ('\u{2a20}',  &['\u{3e}',
-         '\u{3e}']), ('\u{2a21}',  &['\u{16da}']), ('\u{2a22}',  &['\u{2b}',  '\u{30a}']),
-        ('\u{2a23}',  &['\u{2b}',  '\u{302}']), ('\u{2a24}',  &['\u{2b}',  '\u{303}']), ('\u{2a25}',
-         &['\u{2b}',  '\u{323}']), ('\u{2a26}',  &['\u{2b}',  '\u{330}']), ('\u{2a27}',  &['\u{2b}',
-         '\u{2082}']), ('\u{2a29}',  &['\u{2d}',  '\u{313}']), ('\u{2a2a}',  &['\u{2d}',
-         '\u{323}']), ('\u{2a2f}',  &['\u{78}']), ('\u{2a30}',  &['\u{78}',  '\u{307}']),
-        ('\u{2a3d}',  &['\u{2319}']), ('\u{2a3e}',  &['\u{2a1f}']), ('\u{2a3f}',  &['\u{2210}']),
-        ('\u{2a6a}',  &['\u{7e}',  '\u{307}']), ('\u{2a6e}',  &['\u{3d}',  '\u{20f0}']),
-        ('\u{2a74}',  &['\u{3a}',  '\u{3a}',  '\u{3d}']), ('\u{2a75}',  &['\u{3d}',  '\u{3d}']),
-        ('\u{2a76}',  &['\u{3d}',  '\u{3d}',  '\u{3d}']), ('\u{2aa5}',  &['\u{3e}',  '\u{3c}']),
-        ('\u{2aaa}',  &['\u{15d5}']), ('\u{2aab}',  &['\u{15d2}']), ('\u{2ad7}',  &['\u{1450}',
-         '\u{1455}']), ('\u{2afb}',  &['\u{2f}',  '\u{2f}',  '\u{2f}']), ('\u{2afd}',  &['\u{2f}',
-         '\u{2f}']), ('\u{2b96}',  &['\u{3d}',  '\u{1ab2}']), ('\u{2bec}',  &['\u{219e}']),
-        ('\u{2bed}',  &['\u{219f}']), ('\u{2bee}',  &['\u{21a0}']), ('\u{2bef}',  &['\u{21a1}']),
-        ('\u{2c67}',  &['\u{48}',  '\u{329}']), ('\u{2c69}',  &['\u{4b}',  '\u{329}']), ('\u{2c82}',
-         &['\u{42}']), ('\u{2c83}',  &['\u{299}']), ('\u{2c84}',  &['\u{393}']), ('\u{2c85}',
-         &['\u{72}']), ('\u{2c86}',  &['\u{394}']), ('\u{2c88}',  &['\u{a792}']), ('\u{2c89}',
-         &['\u{a793}']), ('\u{2c8b}',  &['\u{3c2}']), ('\u{2c8c}',  &['\u{2c6b}']), ('\u{2c8d}',
-         &['\u{2c6c}']), ('\u{2c8e}',  &['\u{48}']), ('\u{2c8f}',  &['\u{29c}']), ('\u{2c90}',
-         &['\u{4f}',  '\u{335}']), ('\u{2c91}',  &['\u{6f}',  '\u{335}']), ('\u{2c92}',
-         &['\u{6c}']), ('\u{2c93}',  &['\u{69}']), ('\u{2c94}',  &['\u{4b}']), ('\u{2c95}',
-         &['\u{138}']), ('\u{2c96}',  &['\u{3bb}']), ('\u{2c97}',  &['\u{28c}']), ('\u{2c98}',
-         &['\u{4d}']), ('\u{2c99}',  &['\u{28d}']), ('\u{2c9a}',  &['\u{4e}']), ('\u{2c9b}',
-         &['\u{274}']), ('\u{2c9c}',  &['\u{33}']), ('\u{2c9d}',  &['\u{293}']), ('\u{2c9e}',
-         &['\u{4f}']), ('\u{2c9f}',  &['\u{6f}']), ('\u{2ca0}',  &['\u{3a0}']), ('\u{2ca1}',
-         &['\u{3c0}']), ('\u{2ca2}',  &['\u{50}']), ('\u{2ca3}',  &['\u{70}']), ('\u{2ca4}',
-         &['\u{43}']), ('\u{2ca5}',  &['\u{63}']), ('\u{2ca6}',  &['\u{54}']), ('\u{2ca7}',
-         &['\u{1d1b}']), ('\u{2ca8}',  &['\u{59}']), ('\u{2ca9}',  &['\u{79}']), ('\u{2caa}',
-         &['\u{3a6}']), ('\u{2cab}',  &['\u{278}']), ('\u{2cac}',  &['\u{58}']), ('\u{2cad}',
-         &['\u{3c7}']), ('\u{2cae}',  &['\u{3a8}']), ('\u{2caf}',  &['\u{3c8}']), ('\u{2cb0}',
-         &['\u{a64c}']), ('\u{2cb1}',  &['\u{3c9}']), ('\u{2cb2}',  &['\u{2d}',  '\u{307}']),
-        ('\u{2cb3}',  &['\u{2d}',  '\u{307}']), ('\u{2cb4}',  &['\u{3c}',  '\u{b7}']), ('\u{2cb5}',
-         &['\u{3c}',  '\u{b7}']), ('\u{2cb6}',  &['\u{39e}']), ('\u{2cb7}',  &['\u{2261}']),
-        ('\u{2cba}',  &['\u{2d}']), ('\u{2cbb}',  &['\u{2d}']), ('\u{2cbc}',  &['\u{428}']),
-        ('\u{2cbd}',  &['\u{77}']), ('\u{2cc0}',  &['\u{554}']), ('\u{2cc1}',  &['\u{3fc}']),
-        ('\u{2cc4}',  &['\u{33}']), ('\u{2cc5}',  &['\u{21d}']), ('\u{2cc6}',  &['\u{2f}']),
-        ('\u{2cc7}',  &['\u{2f}']), ('\u{2cca}',  &['\u{39}']), ('\u{2ccb}',  &['\u{39}']),
-        ('\u{2ccc}',  &['\u{33}']), ('\u{2ccd}',  &['\u{21d}']), ('\u{2cce}',  &['\u{50}']),
-        ('\u{2ccf}',  &['\u{70}']), ('\u{2cd0}',  &['\u{4c}']), ('\u{2cd1}',  &['\u{29f}']),
-        ('\u{2cd2}',  &['\u{36}']), ('\u{2cd3}',  &['\u{36}']), ('\u{2cdc}',  &['\u{36}']),
-        ('\u{2cdd}',  &['\u{1e9f}']), ('\u{2ce0}',  &['\u{278}']), ('\u{2ce1}',  &['\u{278}']),
-        ('\u{2ce4}',  &['\u{3d7}']), ('\u{2ce8}',  &['\u{554}']), ('\u{2ce9}',  &['\u{2627}']),
-        ('\u{2cf9}',  &['\u{5c}',  '\u{5c}']), ('\u{2d31}',  &['\u{4f}',  '\u{335}']), ('\u{2d37}',
-         &['\u{245}']), ('\u{2d38}',  &['\u{56}']), ('\u{2d39}',  &['\u{45}']), ('\u{2d3a}',
-         &['\u{18e}']), ('\u{2d41}',  &['\u{4f}',  '\u{338}']), ('\u{2d48}',  &['\u{b7}',  '\u{b7}',
-         '\u{b7}']), ('\u{2d49}',  &['\u{1a9}']), ('\u{2d4f}',  &['\u{6c}']), ('\u{2d51}',
-         &['\u{21}']), ('\u{2d54}',  &['\u{4f}']), ('\u{2d55}',  &['\u{51}']), ('\u{2d59}',
-         &['\u{298}']), ('\u{2d5d}',  &['\u{58}']), ('\u{2d60}',  &['\u{394}']), ('\u{2d63}',
-         &['\u{16ef}']), ('\u{2de8}',  &['\u{1ddf}']), ('\u{2dea}',  &['\u{30a}']), ('\u{2ded}',
-         &['\u{368}']), ('\u{2dee}',  &['\u{1adb}']), ('\u{2def}',  &['\u{36f}']), ('\u{2df6}',
-         &['\u{363}']), ('\u{2df7}',  &['\u{364}']), ('\u{2e1a}',  &['\u{2d}',  '\u{308}']),
-        ('\u{2e1e}',  &['\u{7e}',  '\u{307}']), ('\u{2e1f}',  &['\u{7e}',  '\u{323}']), ('\u{2e26}',
-         &['\u{1455}']), ('\u{2e27}',  &['\u{1450}']), ('\u{2e28}',  &['\u{28}',  '\u{28}']),
-        ('\u{2e29}',  &['\u{29}',  '\u{29}']), ('\u{2e2a}',  &['\u{2235}']), ('\u{2e2b}',
-         &['\u{2234}']), ('\u{2e2c}',  &['\u{2237}']), ('\u{2e2e}',  &['\u{61f}']), ('\u{2e30}',
-         &['\u{b0}']), ('\u{2e31}',  &['\u{b7}']), ('\u{2e32}',  &['\u{60c}']), ('\u{2e35}',
-         &['\u{61b}']), ('\u{2e39}',  &['\u{1e9f}']), ('\u{2e3d}',  &['\u{2d42}']), ('\u{2e3f}',
-         &['\u{b6}']), ('\u{2e40}',  &['\u{3d}']), ('\u{2e82}',  &['\u{4e5b}']), ('\u{2e83}',
-         &['\u{4e5a}']), ('\u{2e85}',  &['\u{4ebb}']), ('\u{2e89}',  &['\u{5202}']), ('\u{2e8b}',
-         &['\u{353e}']), ('\u{2e8e}',  &['\u{5140}']), ('\u{2e8f}',  &['\u{5c23}']), ('\u{2e90}',
-         &['\u{5c22}']), ('\u{2e92}',  &['\u{5df3}']), ('\u{2e93}',  &['\u{5e7a}']), ('\u{2e94}',
-         &['\u{5f51}']), ('\u{2e96}',  &['\u{5fc4}']), ('\u{2e97}',  &['\u{38fa}']), ('\u{2e98}',
-         &['\u{624c}']), ('\u{2e99}',  &['\u{6535}']), ('\u{2e9b}',  &['\u{65e1}']), ('\u{2e9e}',
-         &['\u{6b7a}']), ('\u{2e9f}',  &['\u{6bcd}']), ('\u{2ea0}',  &['\u{6c11}']), ('\u{2ea1}',
-         &['\u{6c35}']), ('\u{2ea2}',  &['\u{6c3a}']), ('\u{2ea3}',  &['\u{706c}']), ('\u{2ea4}',
-         &['\u{722b}']), ('\u{2ea6}',  &['\u{4e2c}']), ('\u{2ea8}',  &['\u{72ad}']), ('\u{2eab}',
-         &['\u{7f52}']), ('\u{2ead}',  &['\u{793b}']), ('\u{2eaf}',  &['\u{7cf9}']), ('\u{2eb1}',
-         &['\u{7f53}']), ('\u{2eb2}',  &['\u{7f52}']), ('\u{2eb9}',  &['\u{8002}']), ('\u{2eba}',
-         &['\u{8080}']), ('\u{2ebe}',  &['\u{8279}']), ('\u{2ebf}',  &['\u{8279}']), ('\u{2ec0}',
-         &['\u{8279}']), ('\u{2ec1}',  &['\u{864e}']), ('\u{2ec2}',  &['\u{8864}']), ('\u{2ec3}',
-         &['\u{8980}']), ('\u{2ec4}',  &['\u{897f}']), ('\u{2ec5}',  &['\u{89c1}']), ('\u{2ec8}',
-         &['\u{8ba0}']), ('\u{2ec9}',  &['\u{8d1d}']), ('\u{2ecb}',  &['\u{8f66}']), ('\u{2ecc}',
-         &['\u{8fb6}']), ('\u{2ecd}',  &['\u{8fb6}']), ('\u{2ecf}',  &['\u{961d}']), ('\u{2ed0}',
-         &['\u{9485}']), ('\u{2ed1}',  &['\u{1110}',  '\u{30fc}',  '\u{1102}',  '\u{110c}']),
-        ('\u{2ed2}',  &['\u{9578}']), ('\u{2ed3}',  &['\u{957f}']), ('\u{2ed4}',  &['\u{95e8}']),
-        ('\u{2ed6}',  &['\u{961d}']), ('\u{2ed8}',  &['\u{9752}']), ('\u{2ed9}',  &['\u{97e6}']),
-        ('\u{2eda}',  &['\u{9875}']), ('\u{2edb}',  &['\u{98ce}']), ('\u{2edc}',  &['\u{98de}']),
-        ('\u{2edd}',  &['\u{98df}']), ('\u{2edf}',  &['\u{98e0}']), ('\u{2ee0}',  &['\u{9963}']),
-        ('\u{2ee2}',  &['\u{9a6c}']), ('\u{2ee4}',  &['\u{9b3c}']), ('\u{2ee5}',  &['\u{9c7c}']),
-        ('\u{2ee8}',  &['\u{9ea6}']), ('\u{2ee9}',  &['\u{9ec4}']), ('\u{2eeb}',  &['\u{6589}']),
-        ('\u{2eec}',  &['\u{9f50}']), ('\u{2eed}',  &['\u{6b6f}']), ('\u{2eee}',  &['\u{9f7f}']),
-        ('\u{2eef}',  &['\u{7adc}']), ('\u{2ef0}',  &['\u{9f99}']), ('\u{2ef2}',  &['\u{4e80}']),
-        ('\u{2ef3}',  &['\u{9f9f}']), ('\u{2f00}',  &['\u{30fc}']), ('\u{2f01}',  &['\u{4e28}']),
-        ('\u{2f02}',  &['\u{5c}']), ('\u{2f03}',  &['\u{2f}']), ('\u{2f04}',  &['\u{4e59}']),
-        ('\u{2f05}',  &['\u{4e85}']), ('\u{2f06}',  &['\u{4e8c}']), ('\u{2f07}',  &['\u{4ea0}']),
-        ('\u{2f08}',  &['\u{4eba}']), ('\u{2f09}',  &['\u{513f}']), ('\u{2f0a}',  &['\u{5165}']),
-        ('\u{2f0b}',  &['\u{516b}']), ('\u{2f0c}',  &['\u{5182}']), ('\u{2f0d}',  &['\u{5196}']),
-        ('\u{2f0e}',  &['\u{51ab}']), ('\u{2f0f}',  &['\u{51e0}']), ('\u{2f10}',  &['\u{51f5}']),
-        ('\u{2f11}',  &['\u{5200}']), ('\u{2f12}',  &['\u{529b}']), ('\u{2f13}',  &['\u{52f9}']),
-        ('\u{2f14}',  &['\u{5315}']), ('\u{2f15}',  &['\u{531a}']), ('\u{2f16}',  &['\u{5338}']),
-        ('\u{2f17}',  &['\u{5341}']), ('\u{2f18}',  &['\u{535c}']), ('\u{2f19}',  &['\u{5369}']),
-        ('\u{2f1a}',  &['\u{5382}']), ('\u{2f1b}',  &['\u{53b6}']), ('\u{2f1c}',  &['\u{53c8}']),
-        ('\u{2f1d}',  &['\u{53e3}']), ('\u{2f1e}',  &['\u{53e3}']), ('\u{2f1f}',  &['\u{571f}']),
-        ('\u{2f20}',  &['\u{571f}']), ('\u{2f21}',  &['\u{5902}']), ('\u{2f22}',  &['\u{590a}']),
-        ('\u{2f23}',  &['\u{5915}']), ('\u{2f24}',  &['\u{5927}']), ('\u{2f25}',  &['\u{5973}']),
-        ('\u{2f26}',  &['\u{5b50}']), ('\u{2f27}',  &['\u{5b80}']), ('\u{2f28}',  &['\u{5bf8}']),
-        ('\u{2f29}',  &['\u{5c0f}']), ('\u{2f2a}',  &['\u{5c22}']), ('\u{2f2b}',  &['\u{5c38}']),
-        ('\u{2f2c}',  &['\u{5c6e}']), ('\u{2f2d}',  &['\u{5c71}']), ('\u{2f2e}',  &['\u{5ddb}']),
-        ('\u{2f2f}',  &['\u{5de5}']), ('\u{2f30}',  &['\u{5df1}']), ('\u{2f31}',  &['\u{5dfe}']),
-        ('\u{2f32}',  &['\u{5e72}']), ('\u{2f33}',  &['\u{5e7a}']), ('\u{2f34}',  &['\u{5e7f}']),
-        ('\u{2f35}',  &['\u{5ef4}']), ('\u{2f36}',  &['\u{5efe}']), ('\u{2f37}',  &['\u{5f0b}']),
-        ('\u{2f38}',  &['\u{5f13}']), ('\u{2f39}',  &['\u{5f50}']), ('\u{2f3a}',  &['\u{5f61}']),
-        ('\u{2f3b}',  &['\u{5f73}']), ('\u{2f3c}',  &['\u{5fc3}']), ('\u{2f3d}',  &['\u{6208}']),
-        ('\u{2f3e}',  &['\u{6236}']), ('\u{2f3f}',  &['\u{624b}']), ('\u{2f40}',  &['\u{652f}']),
-        ('\u{2f41}',  &['\u{6534}']), ('\u{2f42}',  &['\u{6587}']), ('\u{2f43}',  &['\u{6597}']),
-        ('\u{2f44}',  &['\u{65a4}']), ('\u{2f45}',  &['\u{65b9}']), ('\u{2f46}',  &['\u{65e0}']),
-        ('\u{2f47}',  &['\u{65e5}']), ('\u{2f48}',  &['\u{66f0}']), ('\u{2f49}',  &['\u{6708}']),
-        ('\u{2f4a}',  &['\u{6728}']), ('\u{2f4b}',  &['\u{6b20}']), ('\u{2f4c}',  &['\u{6b62}']),
-        ('\u{2f4d}',  &['\u{6b79}']), ('\u{2f4e}',  &['\u{6bb3}']), ('\u{2f4f}',  &['\u{6bcb}']),
-        ('\u{2f50}',  &['\u{6bd4}']), ('\u{2f51}',  &['\u{6bdb}']), ('\u{2f52}',  &['\u{6c0f}']),
-        ('\u{2f53}',  &['\u{6c14}']), ('\u{2f54}',  &['\u{6c34}']), ('\u{2f55}',  &['\u{706b}']),
-        ('\u{2f56}',  &['\u{722a}']), ('\u{2f57}',  &['\u{7236}']), ('\u{2f58}',  &['\u{723b}']),
-        ('\u{2f59}',  &['\u{1102}',  '\u{116e}',  '\u{4e28}']), ('\u{2f5a}',  &['\u{7247}']),
-        ('\u{2f5b}',  &['\u{7259}']), ('\u{2f5c}',  &['\u{725b}']), ('\u{2f5d}',  &['\u{72ac}']),
-        ('\u{2f5e}',  &['\u{7384}']), ('\u{2f5f}',  &['\u{7389}']), ('\u{2f60}',  &['\u{74dc}']),
-        ('\u{2f61}',  &['\u{74e6}']), ('\u{2f62}',  &['\u{7518}']), ('\u{2f63}',  &['\u{751f}']),
-        ('\u{2f64}',  &['\u{7528}']), ('\u{2f65}',  &['\u{7530}']), ('\u{2f66}',  &['\u{758b}']),
-        ('\u{2f67}',  &['\u{7592}']), ('\u{2f68}',  &['\u{7676}']), ('\u{2f69}',  &['\u{767d}']),
-        ('\u{2f6a}',  &['\u{76ae}']), ('\u{2f6b}',  &['\u{76bf}']), ('\u{2f6c}',  &['\u{76ee}']),
-        ('\u{2f6d}',  &['\u{77db}']), ('\u{2f6e}',  &['\u{77e2}']), ('\u{2f6f}',  &['\u{77f3}']),
-        ('\u{2f70}',  &['\u{793a}']), ('\u{2f71}',  &['\u{79b8}']), ('\u{2f72}',  &['\u{79be}']),
-        ('\u{2f73}',  &['\u{7a74}']), ('\u{2f74}',  &['\u{7acb}']), ('\u{2f75}',  &['\u{7af9}']),
-        ('\u{2f76}',  &['\u{7c73}']), ('\u{2f77}',  &['\u{7cf8}']), ('\u{2f78}',  &['\u{7f36}']),
-        ('\u{2f79}',  &['\u{7f51}']), ('\u{2f7a}',  &['\u{7f8a}']), ('\u{2f7b}',  &['\u{7fbd}']),
-        ('\u{2f7c}',  &['\u{8001}']), ('\u{2f7d}',  &['\u{800c}']), ('\u{2f7e}',  &['\u{8012}']),
-        ('\u{2f7f}',  &['\u{8033}']), ('\u{2f80}',  &['\u{807f}']), ('\u{2f81}',  &['\u{8089}']),
-        ('\u{2f82}',  &['\u{81e3}']), ('\u{2f83}',  &['\u{81ea}']), ('\u{2f84}',  &['\u{81f3}']),
-        ('\u{2f85}',  &['\u{81fc}']), ('\u{2f86}',  &['\u{820c}']), ('\u{2f87}',  &['\u{821b}']),
-        ('\u{2f88}',  &['\u{821f}']), ('\u{2f89}',  &['\u{826e}']), ('\u{2f8a}',  &['\u{8272}']),
-        ('\u{2f8b}',  &['\u{8278}']), ('\u{2f8c}',  &['\u{864d}']), ('\u{2f8d}',  &['\u{866b}']),
-        ('\u{2f8e}',  &['\u{8840}']), ('\u{2f8f}',  &['\u{884c}']), ('\u{2f90}',  &['\u{8863}']),
-        ('\u{2f91}',  &['\u{897e}']), ('\u{2f92}',  &['\u{898b}']), ('\u{2f93}',  &['\u{89d2}']),
-        ('\u{2f94}',  &['\u{8a00}']), ('\u{2f95}',  &['\u{8c37}']), ('\u{2f96}',  &['\u{8c46}']),
-        ('\u{2f97}',  &['\u{8c55}']), ('\u{2f98}',  &['\u{8c78}']), ('\u{2f99}',  &['\u{8c9d}']),
-        ('\u{2f9a}',  &['\u{8d64}']), ('\u{2f9b}',  &['\u{8d70}']), ('\u{2f9c}',  &['\u{8db3}']),
-        ('\u{2f9d}',  &['\u{8eab}']), ('\u{2f9e}',  &['\u{8eca}']), ('\u{2f9f}',  &['\u{8f9b}']),
-        ('\u{2fa0}',  &['\u{8fb0}']), ('\u{2fa1}',  &['\u{8fb5}']), ('\u{2fa2}',  &['\u{9091}']),
-        ('\u{2fa3}',  &['\u{9149}']), ('\u{2fa4}',  &['\u{91c6}']), ('\u{2fa5}',  &['\u{91cc}']),
-        ('\u{2fa6}',  &['\u{91d1}']), ('\u{2fa7}',  &['\u{1110}',  '\u{30fc}',  '\u{1102}',
-         '\u{110c}']), ('\u{2fa8}',  &['\u{9580}']), ('\u{2fa9}',  &['\u{961c}']), ('\u{2faa}',
-         &['\u{96b6}']), ('\u{2fab}',  &['\u{96b9}']), ('\u{2fac}',  &['\u{96e8}']), ('\u{2fad}',
-         &['\u{9751}']), ('\u{2fae}',  &['\u{975e}']), ('\u{2faf}',  &['\u{9762}']), ('\u{2fb0}',
-         &['\u{9769}']), ('\u{2fb1}',  &['\u{97cb}']), ('\u{2fb2}',  &['\u{97ed}']), ('\u{2fb3}',
-         &['\u{97f3}']), ('\u{2fb4}',  &['\u{9801}']), ('\u{2fb5}',  &['\u{98a8}']), ('\u{2fb6}',
-         &['\u{98db}']), ('\u{2fb7}',  &['\u{98df}']), ('\u{2fb8}',  &['\u{9996}']), ('\u{2fb9}',
-         &['\u{9999}']), ('\u{2fba}',  &['\u{99ac}']), ('\u{2fbb}',  &['\u{9aa8}']), ('\u{2fbc}',
-         &['\u{9ad8}']), ('\u{2fbd}',  &['\u{9adf}']), ('\u{2fbe}',  &['\u{9b25}']), ('\u{2fbf}',
-         &['\u{9b2f}']), ('\u{2fc0}',  &['\u{9b32}']), ('\u{2fc1}',  &['\u{9b3c}']), ('\u{2fc2}',
-         &['\u{9b5a}']), ('\u{2fc3}',  &['\u{9ce5}']), ('\u{2fc4}',  &['\u{9e75}']), ('\u{2fc5}',
-         &['\u{9e7f}']), ('\u{2fc6}',  &['\u{9ea5}']), ('\u{2fc7}',  &['\u{9ebb}']), ('\u{2fc8}',
-         &['\u{9ec3}']), ('\u{2fc9}',  &['\u{9ecd}']), ('\u{2fca}',  &['\u{9ed1}']), ('\u{2fcb}',
-         &['\u{9ef9}']), ('\u{2fcc}',  &['\u{9efd}']), ('\u{2fcd}',  &['\u{9f0e}']), ('\u{2fce}',
-         &['\u{9f13}']), ('\u{2fcf}',  &['\u{9f20}']), ('\u{2fd0}',  &['\u{9f3b}']), ('\u{2fd1}',
-         &['\u{9f4a}']), ('\u{2fd2}',  &['\u{9f52}']), ('\u{2fd3}',  &['\u{9f8d}']), ('\u{2fd4}',
-         &['\u{9f9c}']), ('\u{2fd5}',  &['\u{9fa0}']), ('\u{3002}',  &['\u{2f3}']), ('\u{3003}',
-         &['\u{27}',  '\u{27}']), ('\u{3007}',  &['\u{4f}']), ('\u{3008}',  &['\u{276c}']),
-        ('\u{3009}',  &['\u{276d}']), ('\u{3012}',  &['\u{20b8}']), ('\u{3014}',  &['\u{28}']),
-        ('\u{3015}',  &['\u{29}']), ('\u{301a}',  &['\u{27e6}']), ('\u{301b}',  &['\u{27e7}']),
-        ('\u{302c}',  &['\u{309}']), ('\u{302d}',  &['\u{325}']), ('\u{3033}',  &['\u{2f}']),
-        ('\u{3036}',  &['\u{20b8}']), ('\u{3038}',  &['\u{5341}']), ('\u{3039}',  &['\u{5344}']),
-        ('\u{303a}',  &['\u{5345}']), ('\u{304f}',  &['\u{276c}']), ('\u{309a}',  &['\u{30a}']),
-        ('\u{309b}',  &['\u{ff9e}']), ('\u{309c}',  &['\u{ff9f}']), ('\u{30a0}',  &['\u{3d}']),
-        ('\u{30a4}',  &['\u{4ebb}']), ('\u{30a8}',  &['\u{5de5}']), ('\u{30ab}',  &['\u{529b}']),
-        ('\u{30bf}',  &['\u{5915}']), ('\u{30c8}',  &['\u{535c}']), ('\u{30cb}',  &['\u{4e8c}']),
-        ('\u{30ce}',  &['\u{2f}']), ('\u{30cf}',  &['\u{516b}']), ('\u{30d8}',  &['\u{3078}']),
-        ('\u{30ed}',  &['\u{53e3}']), ('\u{30fb}',  &['\u{b7}']), ('\u{3126}',  &['\u{513f}']),
-        ('\u{3131}',  &['\u{1100}']), ('\u{3132}',  &['\u{1100}',  '\u{1100}']), ('\u{3133}',
-         &['\u{1100}',  '\u{1109}']), ('\u{3134}',  &['\u{1102}']), ('\u{3135}',  &['\u{1102}',
-         '\u{110c}']), ('\u{3136}',  &['\u{1102}',  '\u{1112}']), ('\u{3137}',  &['\u{1103}']),
-        ('\u{3138}',  &['\u{1103}',  '\u{1103}']), ('\u{3139}',  &['\u{1105}']), ('\u{313a}',
-         &['\u{1105}',  '\u{1100}']), ('\u{313b}',  &['\u{1105}',  '\u{1106}']), ('\u{313c}',
-         &['\u{1105}',  '\u{1107}']), ('\u{313d}',  &['\u{1105}',  '\u{1109}']), ('\u{313e}',
-         &['\u{1105}',  '\u{1110}']), ('\u{313f}',  &['\u{1105}',  '\u{1111}']), ('\u{3140}',
-         &['\u{1105}',  '\u{1112}']), ('\u{3141}',  &['\u{1106}']), ('\u{3142}',  &['\u{1107}']),
-        ('\u{3143}',  &['\u{1107}',  '\u{1107}']), ('\u{3144}',  &['\u{1107}',  '\u{1109}']),
-        ('\u{3145}',  &['\u{1109}']), ('\u{3146}',  &['\u{1109}',  '\u{1109}']), ('\u{3147}',
-         &['\u{110b}']), ('\u{3148}',  &['\u{110c}']), ('\u{3149}',  &['\u{110c}',  '\u{110c}']),
-        ('\u{314a}',  &['\u{110e}']), ('\u{314b}',  &['\u{110f}']), ('\u{314c}',  &['\u{1110}']),
-        ('\u{314d}',  &['\u{1111}']), ('\u{314e}',  &['\u{1112}']), ('\u{314f}',  &['\u{1161}']),
-        ('\u{3150}',  &['\u{1161}',  '\u{4e28}']), ('\u{3151}',  &['\u{1163}']), ('\u{3152}',
-         &['\u{1163}',  '\u{4e28}']), ('\u{3153}',  &['\u{1165}']), ('\u{3154}',  &['\u{1165}',
-         '\u{4e28}']), ('\u{3155}',  &['\u{1167}']), ('\u{3156}',  &['\u{1167}',  '\u{4e28}']),
-        ('\u{3157}',  &['\u{1169}']), ('\u{3158}',  &['\u{1169}',  '\u{1161}']), ('\u{3159}',
-         &['\u{1169}',  '\u{1161}',  '\u{4e28}']), ('\u{315a}',  &['\u{1169}',  '\u{4e28}']),
-        ('\u{315b}',  &['\u{116d}']), ('\u{315c}',  &['\u{116e}']), ('\u{315d}',  &['\u{116e}',
-         '\u{1165}']), ('\u{315e}',  &['\u{116e}',  '\u{1165}',  '\u{4e28}']), ('\u{315f}',
-         &['\u{116e}',  '\u{4e28}']), ('\u{3160}',  &['\u{1172}']), ('\u{3161}',  &['\u{30fc}']),
-        ('\u{3162}',  &['\u{30fc}',  '\u{4e28}']), ('\u{3163}',  &['\u{4e28}']), ('\u{3164}',
-         &['\u{1160}']), ('\u{3165}',  &['\u{1102}',  '\u{1102}']), ('\u{3166}',  &['\u{1102}',
-         '\u{1103}']), ('\u{3167}',  &['\u{1102}',  '\u{1109}']), ('\u{3168}',  &['\u{1102}',
-         '\u{1140}']), ('\u{3169}',  &['\u{1105}',  '\u{1100}',  '\u{1109}']), ('\u{316a}',
-         &['\u{1105}',  '\u{1103}']), ('\u{316b}',  &['\u{1105}',  '\u{1107}',  '\u{1109}']),
-        ('\u{316c}',  &['\u{1105}',  '\u{1140}']), ('\u{316d}',  &['\u{1105}',  '\u{1159}']),
-        ('\u{316e}',  &['\u{1106}',  '\u{1107}']), ('\u{316f}',  &['\u{1106}',  '\u{1109}']),
-        ('\u{3170}',  &['\u{1106}',  '\u{1140}']), ('\u{3171}',  &['\u{1106}',  '\u{110b}']),
-        ('\u{3172}',  &['\u{1107}',  '\u{1100}']), ('\u{3173}',  &['\u{1107}',  '\u{1103}']),
-        ('\u{3174}',  &['\u{1107}',  '\u{1109}',  '\u{1100}']), ('\u{3175}',  &['\u{1107}',
-         '\u{1109}',  '\u{1103}']), ('\u{3176}',  &['\u{1107}',  '\u{110c}']), ('\u{3177}',
-         &['\u{1107}',  '\u{1110}']), ('\u{3178}',  &['\u{1107}',  '\u{110b}']), ('\u{3179}',
-         &['\u{1107}',  '\u{1107}',  '\u{110b}']), ('\u{317a}',  &['\u{1109}',  '\u{1100}']),
-        ('\u{317b}',  &['\u{1109}',  '\u{1102}']), ('\u{317c}',  &['\u{1109}',  '\u{1103}']),
-        ('\u{317d}',  &['\u{1109}',  '\u{1107}']), ('\u{317e}',  &['\u{1109}',  '\u{110c}']),
-        ('\u{317f}',  &['\u{1140}']), ('\u{3180}',  &['\u{110b}',  '\u{110b}']), ('\u{3181}',
-         &['\u{114c}']), ('\u{3182}',  &['\u{110b}',  '\u{1109}']), ('\u{3183}',  &['\u{110b}',
-         '\u{1140}']), ('\u{3184}',  &['\u{1111}',  '\u{110b}']), ('\u{3185}',  &['\u{1112}',
-         '\u{1112}']), ('\u{3186}',  &['\u{1159}']), ('\u{3187}',  &['\u{116d}',  '\u{1163}']),
-        ('\u{3188}',  &['\u{116d}',  '\u{1163}',  '\u{4e28}']), ('\u{3189}',  &['\u{116d}',
-         '\u{4e28}']), ('\u{318a}',  &['\u{1172}',  '\u{1167}']), ('\u{318b}',  &['\u{1172}',
-         '\u{1167}',  '\u{4e28}']), ('\u{318c}',  &['\u{1172}',  '\u{4e28}']), ('\u{318d}',
-         &['\u{119e}']), ('\u{318e}',  &['\u{119e}',  '\u{4e28}']), ('\u{31d0}',  &['\u{30fc}']),
-        ('\u{31d1}',  &['\u{4e28}']), ('\u{31d3}',  &['\u{2f}']), ('\u{31d4}',  &['\u{5c}']),
-        ('\u{31d6}',  &['\u{4e5b}']), ('\u{31da}',  &['\u{4e85}']), ('\u{31db}',  &['\u{276c}']),
-        ('\u{31df}',  &['\u{4e5a}']), ('\u{31e0}',  &['\u{4e59}']), ('\u{3200}',  &['\u{28}',
-         '\u{1100}',  '\u{29}']), ('\u{3201}',  &['\u{28}',  '\u{1102}',  '\u{29}']), ('\u{3202}',
-         &['\u{28}',  '\u{1103}',  '\u{29}']), ('\u{3203}',  &['\u{28}',  '\u{1105}',  '\u{29}']),
-        ('\u{3204}',  &['\u{28}',  '\u{1106}',  '\u{29}']), ('\u{3205}',  &['\u{28}',  '\u{1107}',
-         '\u{29}']), ('\u{3206}',  &['\u{28}',  '\u{1109}',  '\u{29}']), ('\u{3207}',  &['\u{28}',
-         '\u{110b}',  '\u{29}']), ('\u{3208}',  &['\u{28}',  '\u{110c}',  '\u{29}']), ('\u{3209}',
-         &['\u{28}',  '\u{110e}',  '\u{29}']), ('\u{320a}',  &['\u{28}',  '\u{110f}',  '\u{29}']),
-        ('\u{320b}',  &['\u{28}',  '\u{1110}',  '\u{29}']), ('\u{320c}',  &['\u{28}',  '\u{1111}',
-         '\u{29}']), ('\u{320d}',  &['\u{28}',  '\u{1112}',  '\u{29}']), ('\u{320e}',  &['\u{28}',
-         '\u{ac00}',  '\u{29}']), ('\u{320f}',  &['\u{28}',  '\u{b098}',  '\u{29}']), ('\u{3210}',
-         &['\u{28}',  '\u{b2e4}',  '\u{29}']), ('\u{3211}',  &['\u{28}',  '\u{b77c}',  '\u{29}']),
-        ('\u{3212}',  &['\u{28}',  '\u{b9c8}',  '\u{29}']), ('\u{3213}',  &['\u{28}',  '\u{bc14}',
-         '\u{29}']), ('\u{3214}',  &['\u{28}',  '\u{c0ac}',  '\u{29}']), ('\u{3215}',  &['\u{28}',
-         '\u{c544}',  '\u{29}']), ('\u{3216}',  &['\u{28}',  '\u{c790}',  '\u{29}']), ('\u{3217}',
-         &['\u{28}',  '\u{cc28}',  '\u{29}']), ('\u{3218}',  &['\u{28}',  '\u{ce74}',  '\u{29}']),
-        ('\u{3219}',  &['\u{28}',  '\u{d0c0}',  '\u{29}']), ('\u{321a}',  &['\u{28}',  '\u{d30c}',
-         '\u{29}']), ('\u{321b}',  &['\u{28}',  '\u{d558}',  '\u{29}']), ('\u{321c}',  &['\u{28}',
-         '\u{c8fc}',  '\u{29}']), ('\u{321d}',  &['\u{28}',  '\u{c624}',  '\u{c804}',  '\u{29}']),
-        ('\u{321e}',  &['\u{28}',  '\u{c624}',  '\u{d6c4}',  '\u{29}']), ('\u{3220}',  &['\u{28}',
-         '\u{30fc}',  '\u{29}']), ('\u{3221}',  &['\u{28}',  '\u{4e8c}',  '\u{29}']), ('\u{3222}',
-         &['\u{28}',  '\u{4e09}',  '\u{29}']), ('\u{3223}',  &['\u{28}',  '\u{56db}',  '\u{29}']),
-        ('\u{3224}',  &['\u{28}',  '\u{4e94}',  '\u{29}']), ('\u{3225}',  &['\u{28}',  '\u{516d}',
-         '\u{29}']), ('\u{3226}',  &['\u{28}',  '\u{4e03}',  '\u{29}']), ('\u{3227}',  &['\u{28}',
-         '\u{516b}',  '\u{29}']), ('\u{3228}',  &['\u{28}',  '\u{4e5d}',  '\u{29}']), ('\u{3229}',
-         &['\u{28}',  '\u{5341}',  '\u{29}']), ('\u{322a}',  &['\u{28}',  '\u{6708}',  '\u{29}']),
-        ('\u{322b}',  &['\u{28}',  '\u{706b}',  '\u{29}']), ('\u{322c}',  &['\u{28}',  '\u{6c34}',
-         '\u{29}']), ('\u{322d}',  &['\u{28}',  '\u{6728}',  '\u{29}']), ('\u{322e}',  &['\u{28}',
-         '\u{91d1}',  '\u{29}']), ('\u{322f}',  &['\u{28}',  '\u{571f}',  '\u{29}']), ('\u{3230}',
-         &['\u{28}',  '\u{65e5}',  '\u{29}']), ('\u{3231}',  &['\u{28}',  '\u{682a}',  '\u{29}']),
-        ('\u{3232}',  &['\u{28}',  '\u{6709}',  '\u{29}']), ('\u{3233}',  &['\u{28}',  '\u{793e}',
-         '\u{29}']), ('\u{3234}',  &['\u{28}',  '\u{540d}',  '\u{29}']), ('\u{3235}',  &['\u{28}',
-         '\u{7279}',  '\u{29}']), ('\u{3236}',  &['\u{28}',  '\u{8ca1}',  '\u{29}']), ('\u{3237}',
-         &['\u{28}',  '\u{795d}',  '\u{29}']), ('\u{3238}',  &['\u{28}',  '\u{52b4}',  '\u{29}']),
-        ('\u{3239}',  &['\u{28}',  '\u{4ee3}',  '\u{29}']), ('\u{323a}',  &['\u{28}',  '\u{547c}',
-         '\u{29}']), ('\u{323b}',  &['\u{28}',  '\u{5b66}',  '\u{29}']), ('\u{323c}',  &['\u{28}',
-         '\u{76e3}',  '\u{29}']), ('\u{323d}',  &['\u{28}',  '\u{4f01}',  '\u{29}']), ('\u{323e}',
-         &['\u{28}',  '\u{8cc7}',  '\u{29}']), ('\u{323f}',  &['\u{28}',  '\u{5354}',  '\u{29}']),
-        ('\u{3240}',  &['\u{28}',  '\u{796d}',  '\u{29}']), ('\u{3241}',  &['\u{28}',  '\u{4f11}',
-         '\u{29}']), ('\u{3242}',  &['\u{28}',  '\u{81ea}',  '\u{29}']), ('\u{3243}',  &['\u{28}',
-         '\u{81f3}',  '\u{29}']), ('\u{32c0}',  &['\u{6c}',  '\u{6708}']), ('\u{32c1}',  &['\u{32}',
-         '\u{6708}']), ('\u{32c2}',  &['\u{33}',  '\u{6708}']), ('\u{32c3}',  &['\u{34}',
-         '\u{6708}']), ('\u{32c4}',  &['\u{35}',  '\u{6708}']), ('\u{32c5}',  &['\u{36}',
-         '\u{6708}']), ('\u{32c6}',  &['\u{37}',  '\u{6708}']), ('\u{32c7}',  &['\u{38}',
-         '\u{6708}']), ('\u{32c8}',  &['\u{39}',  '\u{6708}']), ('\u{32c9}',  &['\u{6c}',  '\u{4f}',
-         '\u{6708}']), ('\u{32ca}',  &['\u{6c}',  '\u{6c}',  '\u{6708}']), ('\u{32cb}',  &['\u{6c}',
-         '\u{32}',  '\u{6708}']), ('\u{3358}',  &['\u{4f}',  '\u{70b9}']), ('\u{3359}',  &['\u{6c}',
-         '\u{70b9}']), ('\u{335a}',  &['\u{32}',  '\u{70b9}']), ('\u{335b}',  &['\u{33}',
-         '\u{70b9}']), ('\u{335c}',  &['\u{34}',  '\u{70b9}']), ('\u{335d}',  &['\u{35}',
-         '\u{70b9}']), ('\u{335e}',  &['\u{36}',  '\u{70b9}']), ('\u{335f}',  &['\u{37}',
-         '\u{70b9}']), ('\u{3360}',  &['\u{38}',  '\u{70b9}']), ('\u{3361}',  &['\u{39}',
-         '\u{70b9}']), ('\u{3362}',  &['\u{6c}',  '\u{4f}',  '\u{70b9}']), ('\u{3363}',  &['\u{6c}',
-         '\u{6c}',  '\u{70b9}']), ('\u{3364}',  &['\u{6c}',  '\u{32}',  '\u{70b9}']), ('\u{3365}',
-         &['\u{6c}',  '\u{33}',  '\u{70b9}']), ('\u{3366}',  &['\u{6c}',  '\u{34}',  '\u{70b9}']),
-        ('\u{3367}',  &['\u{6c}',  '\u{35}',  '\u{70b9}']), ('\u{3368}',  &['\u{6c}',  '\u{36}',
-         '\u{70b9}']), ('\u{3369}',  &['\u{6c}',  '\u{37}',  '\u{70b9}']), ('\u{336a}',  &['\u{6c}',
-         '\u{38}',  '\u{70b9}']), ('\u{336b}',  &['\u{6c}',  '\u{39}',  '\u{70b9}']), ('\u{336c}',
-         &['\u{32}',  '\u{4f}',  '\u{70b9}']), ('\u{336d}',  &['\u{32}',  '\u{6c}',  '\u{70b9}']),
-        ('\u{336e}',  &['\u{32}',  '\u{32}',  '\u{70b9}']), ('\u{336f}',  &['\u{32}',  '\u{33}',
-         '\u{70b9}']), ('\u{3370}',  &['\u{32}',  '\u{34}',  '\u{70b9}']), ('\u{33e0}',  &['\u{6c}',
-         '\u{65e5}']), ('\u{33e1}',  &['\u{32}',  '\u{65e5}']), ('\u{33e2}',  &['\u{33}',
-         '\u{65e5}']), ('\u{33e3}',  &['\u{34}',  '\u{65e5}']), ('\u{33e4}',  &['\u{35}',
-         '\u{65e5}']), ('\u{33e5}',  &['\u{36}',  '\u{65e5}']), ('\u{33e6}',  &['\u{37}',
-         '\u{65e5}']), ('\u{33e7}',  &['\u{38}',  '\u{65e5}']), ('\u{33e8}',  &['\u{39}',
-         '\u{65e5}']), ('\u{33e9}',  &['\u{6c}',  '\u{4f}',  '\u{65e5}']), ('\u{33ea}',  &['\u{6c}',
-         '\u{6c}',  '\u{65e5}']), ('\u{33eb}',  &['\u{6c}',  '\u{32}',  '\u{65e5}']), ('\u{33ec}',
-         &['\u{6c}',  '\u{33}',  '\u{65e5}']), ('\u{33ed}',  &['\u{6c}',  '\u{34}',  '\u{65e5}']),
-        ('\u{33ee}',  &['\u{6c}',  '\u{35}',  '\u{65e5}']), ('\u{33ef}',  &['\u{6c}',  '\u{36}',
-         '\u{65e5}']), ('\u{33f0}',  &['\u{6c}',  '\u{37}',  '\u{65e5}']), ('\u{33f1}',  &['\u{6c}',
-         '\u{38}',  '\u{65e5}']), ('\u{33f2}',  &['\u{6c}',  '\u{39}',  '\u{65e5}']), ('\u{33f3}',
-         &['\u{32}',  '\u{4f}',  '\u{65e5}']), ('\u{33f4}',  &['\u{32}',  '\u{6c}',  '\u{65e5}']),
-        ('\u{33f5}',  &['\u{32}',  '\u{32}',  '\u{65e5}']), ('\u{33f6}',  &['\u{32}',  '\u{33}',
-         '\u{65e5}']), ('\u{33f7}',  &['\u{32}',  '\u{34}',  '\u{65e5}']), ('\u{33f8}',  &['\u{32}',
-         '\u{35}',  '\u{65e5}']), ('\u{33f9}',  &['\u{32}',  '\u{36}',  '\u{65e5}']), ('\u{33fa}',
-         &['\u{32}',  '\u{37}',  '\u{65e5}']), ('\u{33fb}',  &['\u{32}',  '\u{38}',  '\u{65e5}']),
-        ('\u{33fc}',  &['\u{32}',  '\u{39}',  '\u{65e5}']), ('\u{33fd}',  &['\u{33}',  '\u{4f}',
-         '\u{65e5}']), ('\u{33fe}',  &['\u{33}',  '\u{6c}',  '\u{65e5}']), ('\u{39b3}',
-         &['\u{363d}']), ('\u{439b}',  &['\u{3588}']), ('\u{4420}',  &['\u{3b3b}']), ('\u{4695}',
-         &['\u{278ae}']), ('\u{4e00}',  &['\u{30fc}']), ('\u{4e15}',  &['\u{110c}',  '\u{1169}']),
-        ('\u{4e1b}',  &['\u{1109}',  '\u{1109}',  '\u{30fc}']), ('\u{4e36}',  &['\u{5c}']),
-        ('\u{4e3f}',  &['\u{2f}']), ('\u{4e8e}',  &['\u{1b122}']), ('\u{4eca}',  &['\u{1109}',
-         '\u{30fc}',  '\u{1100}']), ('\u{5002}',  &['\u{4f75}']), ('\u{503c}',  &['\u{5024}']),
-        ('\u{5152}',  &['\u{16ff3}']), ('\u{535f}',  &['\u{1106}',  '\u{1161}']), ('\u{5408}',
-         &['\u{1109}',  '\u{30fc}',  '\u{1106}']), ('\u{555f}',  &['\u{5553}']), ('\u{56d7}',
-         &['\u{53e3}']), ('\u{586b}',  &['\u{5861}']), ('\u{58eb}',  &['\u{571f}']), ('\u{58ff}',
-         &['\u{58ab}']), ('\u{5b00}',  &['\u{5aaf}']), ('\u{5e32}',  &['\u{5e21}']), ('\u{5e50}',
-         &['\u{3b3a}']), ('\u{6138}',  &['\u{2b73f}']), ('\u{6238}',  &['\u{6236}']), ('\u{6409}',
-         &['\u{3a41}']), ('\u{6663}',  &['\u{403f}']), ('\u{6669}',  &['\u{665a}']), ('\u{66f6}',
-         &['\u{3ada}']), ('\u{6726}',  &['\u{4443}']), ('\u{67ff}',  &['\u{676e}']), ('\u{69e9}',
-         &['\u{3ba3}']), ('\u{6a27}',  &['\u{699d}']), ('\u{6f59}',  &['\u{6e88}']), ('\u{723f}',
-         &['\u{1102}',  '\u{116e}',  '\u{4e28}']), ('\u{784f}',  &['\u{7814}']), ('\u{7d76}',
-         &['\u{7d55}']), ('\u{80a6}',  &['\u{670c}']), ('\u{80ca}',  &['\u{6710}']), ('\u{80d0}',
-         &['\u{670f}']), ('\u{80f6}',  &['\u{3b35}']), ('\u{8101}',  &['\u{6713}']), ('\u{8127}',
-         &['\u{6718}']), ('\u{8141}',  &['\u{80fc}']), ('\u{81a7}',  &['\u{6723}']), ('\u{853f}',
-         &['\u{848d}']), ('\u{8641}',  &['\u{8637}']), ('\u{8a1e}',  &['\u{46b6}']), ('\u{8a7d}',
-         &['\u{8a2e}']), ('\u{8b8f}',  &['\u{8b86}']), ('\u{8c63}',  &['\u{8c5c}']), ('\u{8d86}',
-         &['\u{8d7f}']), ('\u{8dfa}',  &['\u{8de5}']), ('\u{8e9b}',  &['\u{8e97}']), ('\u{8f27}',
-         &['\u{8eff}']), ('\u{90de}',  &['\u{90ce}']), ('\u{93ae}',  &['\u{93ad}']), ('\u{9577}',
-         &['\u{1110}',  '\u{30fc}',  '\u{1102}',  '\u{110c}']), ('\u{96b8}',  &['\u{96b7}']),
-        ('\u{9e43}',  &['\u{9e42}']), ('\u{9ed2}',  &['\u{9ed1}']), ('\u{9fc3}',  &['\u{4039}']),
-        ('\u{a494}',  &['\u{a2cd}']), ('\u{a49c}',  &['\u{a0c0}']), ('\u{a49e}',  &['\u{a04a}']),
-        ('\u{a4a7}',  &['\u{a458}']), ('\u{a4a8}',  &['\u{a132}']), ('\u{a4ac}',  &['\u{a050}']),
-        ('\u{a4b0}',  &['\u{a3c2}']), ('\u{a4ba}',  &['\u{a3bf}']), ('\u{a4be}',  &['\u{a2b1}']),
-        ('\u{a4bf}',  &['\u{a259}']), ('\u{a4c0}',  &['\u{a3ab}']), ('\u{a4c2}',  &['\u{a3b5}']),
-        ('\u{a4d0}',  &['\u{42}']), ('\u{a4d1}',  &['\u{50}']), ('\u{a4d2}',  &['\u{64}']),
-        ('\u{a4d3}',  &['\u{44}']), ('\u{a4d4}',  &['\u{54}']), ('\u{a4d6}',  &['\u{47}']),
-        ('\u{a4d7}',  &['\u{4b}']), ('\u{a4d9}',  &['\u{4a}']), ('\u{a4da}',  &['\u{43}']),
-        ('\u{a4db}',  &['\u{186}']), ('\u{a4dc}',  &['\u{5a}']), ('\u{a4dd}',  &['\u{46}']),
-        ('\u{a4de}',  &['\u{2132}']), ('\u{a4df}',  &['\u{4d}']), ('\u{a4e0}',  &['\u{4e}']),
-        ('\u{a4e1}',  &['\u{4c}']), ('\u{a4e2}',  &['\u{53}']), ('\u{a4e3}',  &['\u{52}']),
-        ('\u{a4e5}',  &['\u{245}']), ('\u{a4e6}',  &['\u{56}']), ('\u{a4e7}',  &['\u{48}']),
-        ('\u{a4ea}',  &['\u{57}']), ('\u{a4eb}',  &['\u{58}']), ('\u{a4ec}',  &['\u{59}']),
-        ('\u{a4ed}',  &['\u{1660}']), ('\u{a4ee}',  &['\u{41}']), ('\u{a4ef}',  &['\u{2c6f}']),
-        ('\u{a4f0}',  &['\u{45}']), ('\u{a4f1}',  &['\u{18e}']), ('\u{a4f2}',  &['\u{6c}']),
-        ('\u{a4f3}',  &['\u{4f}']), ('\u{a4f4}',  &['\u{55}']), ('\u{a4f5}',  &['\u{548}']),
-        ('\u{a4f7}',  &['\u{15e1}']), ('\u{a4f8}',  &['\u{2e}']), ('\u{a4f9}',  &['\u{2c}']),
-        ('\u{a4fa}',  &['\u{2e}',  '\u{2e}']), ('\u{a4fb}',  &['\u{2e}',  '\u{2c}']), ('\u{a4fd}',
-         &['\u{3a}']), ('\u{a4fe}',  &['\u{2d}',  '\u{2e}']), ('\u{a4ff}',  &['\u{3d}']),
-        ('\u{a60e}',  &['\u{2e}']), ('\u{a644}',  &['\u{32}']), ('\u{a645}',  &['\u{1a8}']),
-        ('\u{a647}',  &['\u{69}']), ('\u{a64d}',  &['\u{3c9}']), ('\u{a650}',  &['\u{42a}',
-         '\u{6c}']), ('\u{a651}',  &['\u{2c9}',  '\u{62}',  '\u{69}']), ('\u{a668}',  &['\u{298}']),
-        ('\u{a66f}',  &['\u{20e9}']), ('\u{a67c}',  &['\u{306}']), ('\u{a67e}',  &['\u{2c7}']),
-        ('\u{a695}',  &['\u{68}',  '\u{314}']), ('\u{a698}',  &['\u{4f}',  '\u{4f}']), ('\u{a699}',
-         &['\u{6f}',  '\u{6f}']), ('\u{a69a}',  &['\u{102a8}']), ('\u{a6a1}',  &['\u{418}']),
-        ('\u{a6b0}',  &['\u{16b9}']), ('\u{a6b1}',  &['\u{2c75}']), ('\u{a6cd}',  &['\u{2a1}']),
-        ('\u{a6ce}',  &['\u{245}']), ('\u{a6db}',  &['\u{3a0}']), ('\u{a6df}',  &['\u{56}']),
-        ('\u{a6eb}',  &['\u{3f}']), ('\u{a6ef}',  &['\u{32}']), ('\u{a6f0}',  &['\u{302}']),
-        ('\u{a6f1}',  &['\u{304}']), ('\u{a6f4}',  &['\u{a6f3}',  '\u{a6f3}']), ('\u{a714}',
-         &['\u{2eb}']), ('\u{a716}',  &['\u{2ea}']), ('\u{a728}',  &['\u{54}',  '\u{33}']),
-        ('\u{a729}',  &['\u{74}',  '\u{21d}']), ('\u{a731}',  &['\u{73}']), ('\u{a732}',
-         &['\u{41}',  '\u{41}']), ('\u{a733}',  &['\u{61}',  '\u{61}']), ('\u{a734}',  &['\u{41}',
-         '\u{4f}']), ('\u{a735}',  &['\u{61}',  '\u{6f}']), ('\u{a736}',  &['\u{41}',  '\u{55}']),
-        ('\u{a737}',  &['\u{61}',  '\u{75}']), ('\u{a738}',  &['\u{41}',  '\u{56}']), ('\u{a739}',
-         &['\u{61}',  '\u{76}']), ('\u{a73a}',  &['\u{41}',  '\u{56}']), ('\u{a73b}',  &['\u{61}',
-         '\u{76}']), ('\u{a73c}',  &['\u{41}',  '\u{59}']), ('\u{a73d}',  &['\u{61}',  '\u{79}']),
-        ('\u{a740}',  &['\u{4b}',  '\u{335}']), ('\u{a74a}',  &['\u{4f}',  '\u{335}']), ('\u{a74b}',
-         &['\u{6f}',  '\u{335}']), ('\u{a74e}',  &['\u{4f}',  '\u{4f}']), ('\u{a74f}',  &['\u{6f}',
-         '\u{6f}']), ('\u{a75a}',  &['\u{32}']), ('\u{a761}',  &['\u{77}',  '\u{326}']),
-        ('\u{a76a}',  &['\u{33}']), ('\u{a76b}',  &['\u{21d}']), ('\u{a76e}',  &['\u{39}']),
-        ('\u{a777}',  &['\u{74}',  '\u{66}']), ('\u{a778}',  &['\u{26}']), ('\u{a77a}',
-         &['\u{a779}']), ('\u{a789}',  &['\u{3a}']), ('\u{a78c}',  &['\u{27}']), ('\u{a78f}',
-         &['\u{b7}']), ('\u{a795}',  &['\u{a727}']), ('\u{a798}',  &['\u{46}']), ('\u{a799}',
-         &['\u{66}']), ('\u{a79a}',  &['\u{10412}']), ('\u{a79b}',  &['\u{1043a}']), ('\u{a79d}',
-         &['\u{29a}']), ('\u{a79e}',  &['\u{a4e4}']), ('\u{a79f}',  &['\u{75}']), ('\u{a7ab}',
-         &['\u{33}']), ('\u{a7b1}',  &['\u{a4d5}']), ('\u{a7b2}',  &['\u{4a}']), ('\u{a7b3}',
-         &['\u{58}']), ('\u{a7b4}',  &['\u{42}']), ('\u{a7b5}',  &['\u{df}']), ('\u{a7b6}',
-         &['\u{a64c}']), ('\u{a7b7}',  &['\u{3c9}']), ('\u{a7cf}',  &['\u{a7ce}']), ('\u{a7d2}',
-         &['\u{a7d3}']), ('\u{a7d4}',  &['\u{a7d5}']), ('\u{a7d6}',  &['\u{df}']), ('\u{a7da}',
-         &['\u{245}']), ('\u{a7db}',  &['\u{3bb}']), ('\u{a7dc}',  &['\u{245}',  '\u{338}']),
-        ('\u{a7f1}',  &['\u{18f5}']), ('\u{a7f7}',  &['\u{30fc}']), ('\u{a830}',  &['\u{964}']),
-        ('\u{a960}',  &['\u{1103}',  '\u{1106}']), ('\u{a961}',  &['\u{1103}',  '\u{1107}']),
-        ('\u{a962}',  &['\u{1103}',  '\u{1109}']), ('\u{a963}',  &['\u{1103}',  '\u{110c}']),
-        ('\u{a964}',  &['\u{1105}',  '\u{1100}']), ('\u{a965}',  &['\u{1105}',  '\u{1100}',
-         '\u{1100}']), ('\u{a966}',  &['\u{1105}',  '\u{1103}']), ('\u{a967}',  &['\u{1105}',
-         '\u{1103}',  '\u{1103}']), ('\u{a968}',  &['\u{1105}',  '\u{1106}']), ('\u{a969}',
-         &['\u{1105}',  '\u{1107}']), ('\u{a96a}',  &['\u{1105}',  '\u{1107}',  '\u{1107}']),
-        ('\u{a96b}',  &['\u{1105}',  '\u{1107}',  '\u{110b}']), ('\u{a96c}',  &['\u{1105}',
-         '\u{1109}']), ('\u{a96d}',  &['\u{1105}',  '\u{110c}']), ('\u{a96e}',  &['\u{1105}',
-         '\u{110f}']), ('\u{a96f}',  &['\u{1106}',  '\u{1100}']), ('\u{a970}',  &['\u{1106}',
-         '\u{1103}']), ('\u{a971}',  &['\u{1106}',  '\u{1109}']), ('\u{a972}',  &['\u{1107}',
-         '\u{1109}',  '\u{1110}']), ('\u{a973}',  &['\u{1107}',  '\u{110f}']), ('\u{a974}',
-         &['\u{1107}',  '\u{1112}']), ('\u{a975}',  &['\u{1109}',  '\u{1109}',  '\u{1107}']),
-        ('\u{a976}',  &['\u{110b}',  '\u{1105}']), ('\u{a977}',  &['\u{110b}',  '\u{1112}']),
-        ('\u{a978}',  &['\u{110c}',  '\u{110c}',  '\u{1112}']), ('\u{a979}',  &['\u{1110}',
-         '\u{1110}']), ('\u{a97a}',  &['\u{1111}',  '\u{1112}']), ('\u{a97b}',  &['\u{1112}',
-         '\u{1109}']), ('\u{a97c}',  &['\u{1159}',  '\u{1159}']), ('\u{a992}',  &['\u{2c3f}']),
-        ('\u{a9a3}',  &['\u{a99d}']), ('\u{a9c6}',  &['\u{a9d0}']), ('\u{a9cf}',  &['\u{662}']),
-        ('\u{aa53}',  &['\u{aa01}']), ('\u{aa56}',  &['\u{aa23}']), ('\u{ab32}',  &['\u{65}']),
-        ('\u{ab35}',  &['\u{66}']), ('\u{ab3d}',  &['\u{6f}']), ('\u{ab3e}',  &['\u{6f}',
-         '\u{338}']), ('\u{ab3f}',  &['\u{254}',  '\u{338}']), ('\u{ab41}',  &['\u{1dd}',  '\u{6f}',
-         '\u{338}']), ('\u{ab42}',  &['\u{1dd}',  '\u{6f}',  '\u{335}']), ('\u{ab47}',
-         &['\u{72}']), ('\u{ab48}',  &['\u{72}']), ('\u{ab4d}',  &['\u{283}']), ('\u{ab4e}',
-         &['\u{75}']), ('\u{ab52}',  &['\u{75}']), ('\u{ab53}',  &['\u{3c7}']), ('\u{ab55}',
-         &['\u{3c7}']), ('\u{ab5a}',  &['\u{79}']), ('\u{ab60}',  &['\u{459}']), ('\u{ab62}',
-         &['\u{254}',  '\u{65}']), ('\u{ab63}',  &['\u{75}',  '\u{6f}']), ('\u{ab70}',
-         &['\u{1d05}']), ('\u{ab71}',  &['\u{280}']), ('\u{ab72}',  &['\u{1d1b}']), ('\u{ab74}',
-         &['\u{6f}',  '\u{31b}']), ('\u{ab75}',  &['\u{69}']), ('\u{ab7a}',  &['\u{1d00}']),
-        ('\u{ab7b}',  &['\u{1d0a}']), ('\u{ab7c}',  &['\u{1d07}']), ('\u{ab7e}',  &['\u{242}']),
-        ('\u{ab80}',  &['\u{2c76}']), ('\u{ab81}',  &['\u{72}']), ('\u{ab83}',  &['\u{77}']),
-        ('\u{ab87}',  &['\u{28d}']), ('\u{ab8b}',  &['\u{29c}']), ('\u{ab8e}',  &['\u{6f}',
-         '\u{335}']), ('\u{ab90}',  &['\u{262}']), ('\u{ab93}',  &['\u{7a}']), ('\u{ab9b}',
-         &['\u{a793}']), ('\u{ab9c}',  &['\u{75}',  '\u{335}']), ('\u{ab9f}',  &['\u{185}']),
-        ('\u{aba2}',  &['\u{280}']), ('\u{aba9}',  &['\u{76}']), ('\u{abaa}',  &['\u{73}']),
-        ('\u{abae}',  &['\u{29f}']), ('\u{abaf}',  &['\u{63}']), ('\u{abb2}',  &['\u{1d18}']),
-        ('\u{abb6}',  &['\u{138}']), ('\u{abbb}',  &['\u{6f}',  '\u{335}']), ('\u{d7b0}',
-         &['\u{1169}',  '\u{1167}']), ('\u{d7b1}',  &['\u{1169}',  '\u{1169}',  '\u{4e28}']),
-        ('\u{d7b2}',  &['\u{116d}',  '\u{1161}']), ('\u{d7b3}',  &['\u{116d}',  '\u{1161}',
-         '\u{4e28}']), ('\u{d7b4}',  &['\u{116d}',  '\u{1165}']), ('\u{d7b5}',  &['\u{116e}',
-         '\u{1167}']), ('\u{d7b6}',  &['\u{116e}',  '\u{4e28}',  '\u{4e28}']), ('\u{d7b7}',
-         &['\u{1172}',  '\u{1161}',  '\u{4e28}']), ('\u{d7b8}',  &['\u{1172}',  '\u{1169}']),
-        ('\u{d7b9}',  &['\u{30fc}',  '\u{1161}']), ('\u{d7ba}',  &['\u{30fc}',  '\u{1165}']),
-        ('\u{d7bb}',  &['\u{30fc}',  '\u{1165}',  '\u{4e28}']), ('\u{d7bc}',  &['\u{30fc}',
-         '\u{1169}']), ('\u{d7bd}',  &['\u{4e28}',  '\u{1163}',  '\u{1169}']), ('\u{d7be}',
-         &['\u{4e28}',  '\u{1163}',  '\u{4e28}']), ('\u{d7bf}',  &['\u{4e28}',  '\u{1167}']),
-        ('\u{d7c0}',  &['\u{4e28}',  '\u{1167}',  '\u{4e28}']), ('\u{d7c1}',  &['\u{4e28}',
-         '\u{1169}',  '\u{4e28}']), ('\u{d7c2}',  &['\u{4e28}',  '\u{116d}']), ('\u{d7c3}',
-         &['\u{4e28}',  '\u{1172}']), ('\u{d7c4}',  &['\u{4e28}',  '\u{4e28}']), ('\u{d7c5}',
-         &['\u{119e}',  '\u{1161}']), ('\u{d7c6}',  &['\u{119e}',  '\u{1165}',  '\u{4e28}']),
-        ('\u{d7cb}',  &['\u{1102}',  '\u{1105}']), ('\u{d7cc}',  &['\u{1102}',  '\u{110e}']),
-        ('\u{d7cd}',  &['\u{1103}',  '\u{1103}']), ('\u{d7ce}',  &['\u{1103}',  '\u{1103}',
-         '\u{1107}']), ('\u{d7cf}',  &['\u{1103}',  '\u{1107}']), ('\u{d7d0}',  &['\u{1103}',
-         '\u{1109}']), ('\u{d7d1}',  &['\u{1103}',  '\u{1109}',  '\u{1100}']), ('\u{d7d2}',
-         &['\u{1103}',  '\u{110c}']), ('\u{d7d3}',  &['\u{1103}',  '\u{110e}']), ('\u{d7d4}',
-         &['\u{1103}',  '\u{1110}']), ('\u{d7d5}',  &['\u{1105}',  '\u{1100}',  '\u{1100}']),
-        ('\u{d7d6}',  &['\u{1105}',  '\u{1100}',  '\u{1112}']), ('\u{d7d7}',  &['\u{1105}',
-         '\u{1105}',  '\u{110f}']), ('\u{d7d8}',  &['\u{1105}',  '\u{1106}',  '\u{1112}']),
-        ('\u{d7d9}',  &['\u{1105}',  '\u{1107}',  '\u{1103}']), ('\u{d7da}',  &['\u{1105}',
-         '\u{1107}',  '\u{1111}']), ('\u{d7db}',  &['\u{1105}',  '\u{114c}']), ('\u{d7dc}',
-         &['\u{1105}',  '\u{1159}',  '\u{1112}']), ('\u{d7dd}',  &['\u{1105}',  '\u{110b}']),
-        ('\u{d7de}',  &['\u{1106}',  '\u{1102}']), ('\u{d7df}',  &['\u{1106}',  '\u{1102}',
-         '\u{1102}']), ('\u{d7e0}',  &['\u{1106}',  '\u{1106}']), ('\u{d7e1}',  &['\u{1106}',
-         '\u{1107}',  '\u{1109}']), ('\u{d7e2}',  &['\u{1106}',  '\u{110c}']), ('\u{d7e3}',
-         &['\u{1107}',  '\u{1103}']), ('\u{d7e4}',  &['\u{1107}',  '\u{1105}',  '\u{1111}']),
-        ('\u{d7e5}',  &['\u{1107}',  '\u{1106}']), ('\u{d7e6}',  &['\u{1107}',  '\u{1107}']),
-        ('\u{d7e7}',  &['\u{1107}',  '\u{1109}',  '\u{1103}']), ('\u{d7e8}',  &['\u{1107}',
-         '\u{110c}']), ('\u{d7e9}',  &['\u{1107}',  '\u{110e}']), ('\u{d7ea}',  &['\u{1109}',
-         '\u{1106}']), ('\u{d7eb}',  &['\u{1109}',  '\u{1107}',  '\u{110b}']), ('\u{d7ec}',
-         &['\u{1109}',  '\u{1109}',  '\u{1100}']), ('\u{d7ed}',  &['\u{1109}',  '\u{1109}',
-         '\u{1103}']), ('\u{d7ee}',  &['\u{1109}',  '\u{1140}']), ('\u{d7ef}',  &['\u{1109}',
-         '\u{110c}']), ('\u{d7f0}',  &['\u{1109}',  '\u{110e}']), ('\u{d7f1}',  &['\u{1109}',
-         '\u{1110}']), ('\u{d7f2}',  &['\u{1105}',  '\u{1112}']), ('\u{d7f3}',  &['\u{1140}',
-         '\u{1107}']), ('\u{d7f4}',  &['\u{1140}',  '\u{1107}',  '\u{110b}']), ('\u{d7f5}',
-         &['\u{114c}',  '\u{1106}']), ('\u{d7f6}',  &['\u{114c}',  '\u{1112}']), ('\u{d7f7}',
-         &['\u{110c}',  '\u{1107}']), ('\u{d7f8}',  &['\u{110c}',  '\u{1107}',  '\u{1107}']),
-        ('\u{d7f9}',  &['\u{110c}',  '\u{110c}']), ('\u{d7fa}',  &['\u{1111}',  '\u{1109}']),
-        ('\u{d7fb}',  &['\u{1111}',  '\u{1110}']), ('\u{f900}',  &['\u{8c48}']), ('\u{f901}',
-         &['\u{66f4}']), ('\u{f902}',  &['\u{8eca}']), ('\u{f903}',  &['\u{8cc8}']), ('\u{f904}',
-         &['\u{6ed1}']), ('\u{f905}',  &['\u{4e32}']), ('\u{f906}',  &['\u{53e5}']), ('\u{f907}',
-         &['\u{9f9c}']), ('\u{f908}',  &['\u{9f9c}']), ('\u{f909}',  &['\u{5951}']), ('\u{f90a}',
-         &['\u{91d1}']), ('\u{f90b}',  &['\u{5587}']), ('\u{f90c}',  &['\u{5948}']), ('\u{f90d}',
-         &['\u{61f6}']), ('\u{f90e}',  &['\u{7669}']), ('\u{f90f}',  &['\u{7f85}']), ('\u{f910}',
-         &['\u{863f}']), ('\u{f911}',  &['\u{87ba}']), ('\u{f912}',  &['\u{88f8}']), ('\u{f913}',
-         &['\u{908f}']), ('\u{f914}',  &['\u{6a02}']), ('\u{f915}',  &['\u{6d1b}']), ('\u{f916}',
-         &['\u{70d9}']), ('\u{f917}',  &['\u{73de}']), ('\u{f918}',  &['\u{843d}']), ('\u{f919}',
-         &['\u{916a}']), ('\u{f91a}',  &['\u{99f1}']), ('\u{f91b}',  &['\u{4e82}']), ('\u{f91c}',
-         &['\u{5375}']), ('\u{f91d}',  &['\u{6b04}']), ('\u{f91e}',  &['\u{721b}']), ('\u{f91f}',
-         &['\u{862d}']), ('\u{f920}',  &['\u{9e1e}']), ('\u{f921}',  &['\u{5d50}']), ('\u{f922}',
-         &['\u{6feb}']), ('\u{f923}',  &['\u{85cd}']), ('\u{f924}',  &['\u{8964}']), ('\u{f925}',
-         &['\u{62c9}']), ('\u{f926}',  &['\u{81d8}']), ('\u{f927}',  &['\u{881f}']), ('\u{f928}',
-         &['\u{5eca}']), ('\u{f929}',  &['\u{6717}']), ('\u{f92a}',  &['\u{6d6a}']), ('\u{f92b}',
-         &['\u{72fc}']), ('\u{f92c}',  &['\u{90ce}']), ('\u{f92d}',  &['\u{4f86}']), ('\u{f92e}',
-         &['\u{51b7}']), ('\u{f92f}',  &['\u{52de}']), ('\u{f930}',  &['\u{64c4}']), ('\u{f931}',
-         &['\u{6ad3}']), ('\u{f932}',  &['\u{7210}']), ('\u{f933}',  &['\u{76e7}']), ('\u{f934}',
-         &['\u{8001}']), ('\u{f935}',  &['\u{8606}']), ('\u{f936}',  &['\u{865c}']), ('\u{f937}',
-         &['\u{8def}']), ('\u{f938}',  &['\u{9732}']), ('\u{f939}',  &['\u{9b6f}']), ('\u{f93a}',
-         &['\u{9dfa}']), ('\u{f93b}',  &['\u{788c}']), ('\u{f93c}',  &['\u{797f}']), ('\u{f93d}',
-         &['\u{7da0}']), ('\u{f93e}',  &['\u{83c9}']), ('\u{f93f}',  &['\u{9304}']), ('\u{f940}',
-         &['\u{9e7f}']), ('\u{f941}',  &['\u{8ad6}']), ('\u{f942}',  &['\u{58df}']), ('\u{f943}',
-         &['\u{5f04}']), ('\u{f944}',  &['\u{7c60}']), ('\u{f945}',  &['\u{807e}']), ('\u{f946}',
-         &['\u{7262}']), ('\u{f947}',  &['\u{78ca}']), ('\u{f948}',  &['\u{8cc2}']), ('\u{f949}',
-         &['\u{96f7}']), ('\u{f94a}',  &['\u{58d8}']), ('\u{f94b}',  &['\u{5c62}']), ('\u{f94c}',
-         &['\u{6a13}']), ('\u{f94d}',  &['\u{6dda}']), ('\u{f94e}',  &['\u{6f0f}']), ('\u{f94f}',
-         &['\u{7d2f}']), ('\u{f950}',  &['\u{7e37}']), ('\u{f951}',  &['\u{964b}']), ('\u{f952}',
-         &['\u{52d2}']), ('\u{f953}',  &['\u{808b}']), ('\u{f954}',  &['\u{51dc}']), ('\u{f955}',
-         &['\u{51cc}']), ('\u{f956}',  &['\u{7a1c}']), ('\u{f957}',  &['\u{7dbe}']), ('\u{f958}',
-         &['\u{83f1}']), ('\u{f959}',  &['\u{9675}']), ('\u{f95a}',  &['\u{8b80}']), ('\u{f95b}',
-         &['\u{62cf}']), ('\u{f95c}',  &['\u{6a02}']), ('\u{f95d}',  &['\u{8afe}']), ('\u{f95e}',
-         &['\u{4e39}']), ('\u{f95f}',  &['\u{5be7}']), ('\u{f960}',  &['\u{6012}']), ('\u{f961}',
-         &['\u{7387}']), ('\u{f962}',  &['\u{7570}']), ('\u{f963}',  &['\u{5317}']), ('\u{f964}',
-         &['\u{78fb}']), ('\u{f965}',  &['\u{4fbf}']), ('\u{f966}',  &['\u{5fa9}']), ('\u{f967}',
-         &['\u{4e0d}']), ('\u{f968}',  &['\u{6ccc}']), ('\u{f969}',  &['\u{6578}']), ('\u{f96a}',
-         &['\u{7d22}']), ('\u{f96b}',  &['\u{53c3}']), ('\u{f96c}',  &['\u{585e}']), ('\u{f96d}',
-         &['\u{7701}']), ('\u{f96e}',  &['\u{8449}']), ('\u{f96f}',  &['\u{8aaa}']), ('\u{f970}',
-         &['\u{6bba}']), ('\u{f971}',  &['\u{8fb0}']), ('\u{f972}',  &['\u{6c88}']), ('\u{f973}',
-         &['\u{62fe}']), ('\u{f974}',  &['\u{82e5}']), ('\u{f975}',  &['\u{63a0}']), ('\u{f976}',
-         &['\u{7565}']), ('\u{f977}',  &['\u{4eae}']), ('\u{f978}',  &['\u{5169}']), ('\u{f979}',
-         &['\u{51c9}']), ('\u{f97a}',  &['\u{6881}']), ('\u{f97b}',  &['\u{7ce7}']), ('\u{f97c}',
-         &['\u{826f}']), ('\u{f97d}',  &['\u{8ad2}']), ('\u{f97e}',  &['\u{91cf}']), ('\u{f97f}',
-         &['\u{52f5}']), ('\u{f980}',  &['\u{5442}']), ('\u{f981}',  &['\u{5973}']), ('\u{f982}',
-         &['\u{5eec}']), ('\u{f983}',  &['\u{65c5}']), ('\u{f984}',  &['\u{6ffe}']), ('\u{f985}',
-         &['\u{792a}']), ('\u{f986}',  &['\u{95ad}']), ('\u{f987}',  &['\u{9a6a}']), ('\u{f988}',
-         &['\u{9e97}']), ('\u{f989}',  &['\u{9ece}']), ('\u{f98a}',  &['\u{529b}']), ('\u{f98b}',
-         &['\u{66c6}']), ('\u{f98c}',  &['\u{6b77}']), ('\u{f98d}',  &['\u{8f62}']), ('\u{f98e}',
-         &['\u{5e74}']), ('\u{f98f}',  &['\u{6190}']), ('\u{f990}',  &['\u{6200}']), ('\u{f991}',
-         &['\u{649a}']), ('\u{f992}',  &['\u{6f23}']), ('\u{f993}',  &['\u{7149}']), ('\u{f994}',
-         &['\u{7489}']), ('\u{f995}',  &['\u{79ca}']), ('\u{f996}',  &['\u{7df4}']), ('\u{f997}',
-         &['\u{806f}']), ('\u{f998}',  &['\u{8f26}']), ('\u{f999}',  &['\u{84ee}']), ('\u{f99a}',
-         &['\u{9023}']), ('\u{f99b}',  &['\u{934a}']), ('\u{f99c}',  &['\u{5217}']), ('\u{f99d}',
-         &['\u{52a3}']), ('\u{f99e}',  &['\u{54bd}']), ('\u{f99f}',  &['\u{70c8}']), ('\u{f9a0}',
-         &['\u{88c2}']), ('\u{f9a1}',  &['\u{8aaa}']), ('\u{f9a2}',  &['\u{5ec9}']), ('\u{f9a3}',
-         &['\u{5ff5}']), ('\u{f9a4}',  &['\u{637b}']), ('\u{f9a5}',  &['\u{6bae}']), ('\u{f9a6}',
-         &['\u{7c3e}']), ('\u{f9a7}',  &['\u{7375}']), ('\u{f9a8}',  &['\u{4ee4}']), ('\u{f9a9}',
-         &['\u{56f9}']), ('\u{f9aa}',  &['\u{5be7}']), ('\u{f9ab}',  &['\u{5dba}']), ('\u{f9ac}',
-         &['\u{601c}']), ('\u{f9ad}',  &['\u{73b2}']), ('\u{f9ae}',  &['\u{7469}']), ('\u{f9af}',
-         &['\u{7f9a}']), ('\u{f9b0}',  &['\u{8046}']), ('\u{f9b1}',  &['\u{9234}']), ('\u{f9b2}',
-         &['\u{96f6}']), ('\u{f9b3}',  &['\u{9748}']), ('\u{f9b4}',  &['\u{9818}']), ('\u{f9b5}',
-         &['\u{4f8b}']), ('\u{f9b6}',  &['\u{79ae}']), ('\u{f9b7}',  &['\u{91b4}']), ('\u{f9b8}',
-         &['\u{96b7}']), ('\u{f9b9}',  &['\u{60e1}']), ('\u{f9ba}',  &['\u{4e86}']), ('\u{f9bb}',
-         &['\u{50da}']), ('\u{f9bc}',  &['\u{5bee}']), ('\u{f9bd}',  &['\u{5c3f}']), ('\u{f9be}',
-         &['\u{6599}']), ('\u{f9bf}',  &['\u{6a02}']), ('\u{f9c0}',  &['\u{71ce}']), ('\u{f9c1}',
-         &['\u{7642}']), ('\u{f9c2}',  &['\u{84fc}']), ('\u{f9c3}',  &['\u{907c}']), ('\u{f9c4}',
-         &['\u{9f8d}']), ('\u{f9c5}',  &['\u{6688}']), ('\u{f9c6}',  &['\u{962e}']), ('\u{f9c7}',
-         &['\u{5289}']), ('\u{f9c8}',  &['\u{677b}']), ('\u{f9c9}',  &['\u{67f3}']), ('\u{f9ca}',
-         &['\u{6d41}']), ('\u{f9cb}',  &['\u{6e9c}']), ('\u{f9cc}',  &['\u{7409}']), ('\u{f9cd}',
-         &['\u{7559}']), ('\u{f9ce}',  &['\u{786b}']), ('\u{f9cf}',  &['\u{7d10}']), ('\u{f9d0}',
-         &['\u{985e}']), ('\u{f9d1}',  &['\u{516d}']), ('\u{f9d2}',  &['\u{622e}']), ('\u{f9d3}',
-         &['\u{9678}']), ('\u{f9d4}',  &['\u{502b}']), ('\u{f9d5}',  &['\u{5d19}']), ('\u{f9d6}',
-         &['\u{6dea}']), ('\u{f9d7}',  &['\u{8f2a}']), ('\u{f9d8}',  &['\u{5f8b}']), ('\u{f9d9}',
-         &['\u{6144}']), ('\u{f9da}',  &['\u{6817}']), ('\u{f9db}',  &['\u{7387}']), ('\u{f9dc}',
-         &['\u{9686}']), ('\u{f9dd}',  &['\u{5229}']), ('\u{f9de}',  &['\u{540f}']), ('\u{f9df}',
-         &['\u{5c65}']), ('\u{f9e0}',  &['\u{6613}']), ('\u{f9e1}',  &['\u{674e}']), ('\u{f9e2}',
-         &['\u{68a8}']), ('\u{f9e3}',  &['\u{6ce5}']), ('\u{f9e4}',  &['\u{7406}']), ('\u{f9e5}',
-         &['\u{75e2}']), ('\u{f9e6}',  &['\u{7f79}']), ('\u{f9e7}',  &['\u{88cf}']), ('\u{f9e8}',
-         &['\u{88e1}']), ('\u{f9e9}',  &['\u{91cc}']), ('\u{f9ea}',  &['\u{96e2}']), ('\u{f9eb}',
-         &['\u{533f}']), ('\u{f9ec}',  &['\u{6eba}']), ('\u{f9ed}',  &['\u{541d}']), ('\u{f9ee}',
-         &['\u{71d0}']), ('\u{f9ef}',  &['\u{7498}']), ('\u{f9f0}',  &['\u{85fa}']), ('\u{f9f1}',
-         &['\u{96a3}']), ('\u{f9f2}',  &['\u{9c57}']), ('\u{f9f3}',  &['\u{9e9f}']), ('\u{f9f4}',
-         &['\u{6797}']), ('\u{f9f5}',  &['\u{6dcb}']), ('\u{f9f6}',  &['\u{81e8}']), ('\u{f9f7}',
-         &['\u{7acb}']), ('\u{f9f8}',  &['\u{7b20}']), ('\u{f9f9}',  &['\u{7c92}']), ('\u{f9fa}',
-         &['\u{72c0}']), ('\u{f9fb}',  &['\u{7099}']), ('\u{f9fc}',  &['\u{8b58}']), ('\u{f9fd}',
-         &['\u{4ec0}']), ('\u{f9fe}',  &['\u{8336}']), ('\u{f9ff}',  &['\u{523a}']), ('\u{fa00}',
-         &['\u{5207}']), ('\u{fa01}',  &['\u{5ea6}']), ('\u{fa02}',  &['\u{62d3}']), ('\u{fa03}',
-         &['\u{7cd6}']), ('\u{fa04}',  &['\u{5b85}']), ('\u{fa05}',  &['\u{6d1e}']), ('\u{fa06}',
-         &['\u{66b4}']), ('\u{fa07}',  &['\u{8f3b}']), ('\u{fa08}',  &['\u{884c}']), ('\u{fa09}',
-         &['\u{964d}']), ('\u{fa0a}',  &['\u{898b}']), ('\u{fa0b}',  &['\u{5ed3}']), ('\u{fa0c}',
-         &['\u{5140}']), ('\u{fa0d}',  &['\u{55c0}']), ('\u{fa10}',  &['\u{585a}']), ('\u{fa12}',
-         &['\u{6674}']), ('\u{fa15}',  &['\u{51de}']), ('\u{fa16}',  &['\u{732a}']), ('\u{fa17}',
-         &['\u{76ca}']), ('\u{fa18}',  &['\u{793c}']), ('\u{fa19}',  &['\u{795e}']), ('\u{fa1a}',
-         &['\u{7965}']), ('\u{fa1b}',  &['\u{798f}']), ('\u{fa1c}',  &['\u{9756}']), ('\u{fa1d}',
-         &['\u{7cbe}']), ('\u{fa1e}',  &['\u{7fbd}']), ('\u{fa20}',  &['\u{8612}']), ('\u{fa22}',
-         &['\u{8af8}']), ('\u{fa25}',  &['\u{9038}']), ('\u{fa26}',  &['\u{90fd}']), ('\u{fa2a}',
-         &['\u{98ef}']), ('\u{fa2b}',  &['\u{98fc}']), ('\u{fa2c}',  &['\u{9928}']), ('\u{fa2d}',
-         &['\u{9db4}']), ('\u{fa2e}',  &['\u{90ce}']), ('\u{fa2f}',  &['\u{96b7}']), ('\u{fa30}',
-         &['\u{4fae}']), ('\u{fa31}',  &['\u{50e7}']), ('\u{fa32}',  &['\u{514d}']), ('\u{fa33}',
-         &['\u{52c9}']), ('\u{fa34}',  &['\u{52e4}']), ('\u{fa35}',  &['\u{5351}']), ('\u{fa36}',
-         &['\u{559d}']), ('\u{fa37}',  &['\u{5606}']), ('\u{fa38}',  &['\u{5668}']), ('\u{fa39}',
-         &['\u{5840}']), ('\u{fa3a}',  &['\u{58a8}']), ('\u{fa3b}',  &['\u{5c64}']), ('\u{fa3c}',
-         &['\u{5c6e}']), ('\u{fa3d}',  &['\u{6094}']), ('\u{fa3e}',  &['\u{6168}']), ('\u{fa3f}',
-         &['\u{618e}']), ('\u{fa40}',  &['\u{61f2}']), ('\u{fa41}',  &['\u{654f}']), ('\u{fa42}',
-         &['\u{65e2}']), ('\u{fa43}',  &['\u{6691}']), ('\u{fa44}',  &['\u{6885}']), ('\u{fa45}',
-         &['\u{6d77}']), ('\u{fa46}',  &['\u{6e1a}']), ('\u{fa47}',  &['\u{6f22}']), ('\u{fa48}',
-         &['\u{716e}']), ('\u{fa49}',  &['\u{722b}']), ('\u{fa4a}',  &['\u{7422}']), ('\u{fa4b}',
-         &['\u{7891}']), ('\u{fa4c}',  &['\u{793e}']), ('\u{fa4d}',  &['\u{7949}']), ('\u{fa4e}',
-         &['\u{7948}']), ('\u{fa4f}',  &['\u{7950}']), ('\u{fa50}',  &['\u{7956}']), ('\u{fa51}',
-         &['\u{795d}']), ('\u{fa52}',  &['\u{798d}']), ('\u{fa53}',  &['\u{798e}']), ('\u{fa54}',
-         &['\u{7a40}']), ('\u{fa55}',  &['\u{7a81}']), ('\u{fa56}',  &['\u{7bc0}']), ('\u{fa57}',
-         &['\u{7df4}']), ('\u{fa58}',  &['\u{7e09}']), ('\u{fa59}',  &['\u{7e41}']), ('\u{fa5a}',
-         &['\u{7f72}']), ('\u{fa5b}',  &['\u{8005}']), ('\u{fa5c}',  &['\u{81ed}']), ('\u{fa5d}',
-         &['\u{8279}']), ('\u{fa5e}',  &['\u{8279}']), ('\u{fa5f}',  &['\u{8457}']), ('\u{fa60}',
-         &['\u{8910}']), ('\u{fa61}',  &['\u{8996}']), ('\u{fa62}',  &['\u{8b01}']), ('\u{fa63}',
-         &['\u{8b39}']), ('\u{fa64}',  &['\u{8cd3}']), ('\u{fa65}',  &['\u{8d08}']), ('\u{fa66}',
-         &['\u{8fb6}']), ('\u{fa67}',  &['\u{9038}']), ('\u{fa68}',  &['\u{96e3}']), ('\u{fa69}',
-         &['\u{97ff}']), ('\u{fa6a}',  &['\u{983b}']), ('\u{fa6b}',  &['\u{6075}']), ('\u{fa6c}',
-         &['\u{242ee}']), ('\u{fa6d}',  &['\u{8218}']), ('\u{fa70}',  &['\u{4e26}']), ('\u{fa71}',
-         &['\u{51b5}']), ('\u{fa72}',  &['\u{5168}']), ('\u{fa73}',  &['\u{4f80}']), ('\u{fa74}',
-         &['\u{5145}']), ('\u{fa75}',  &['\u{5180}']), ('\u{fa76}',  &['\u{52c7}']), ('\u{fa77}',
-         &['\u{52fa}']), ('\u{fa78}',  &['\u{559d}']), ('\u{fa79}',  &['\u{5555}']), ('\u{fa7a}',
-         &['\u{5599}']), ('\u{fa7b}',  &['\u{55e2}']), ('\u{fa7c}',  &['\u{585a}']), ('\u{fa7d}',
-         &['\u{58b3}']), ('\u{fa7e}',  &['\u{5944}']), ('\u{fa7f}',  &['\u{5954}']), ('\u{fa80}',
-         &['\u{5a62}']), ('\u{fa81}',  &['\u{5b28}']), ('\u{fa82}',  &['\u{5ed2}']), ('\u{fa83}',
-         &['\u{5ed9}']), ('\u{fa84}',  &['\u{5f69}']), ('\u{fa85}',  &['\u{5fad}']), ('\u{fa86}',
-         &['\u{60d8}']), ('\u{fa87}',  &['\u{614e}']), ('\u{fa88}',  &['\u{6108}']), ('\u{fa89}',
-         &['\u{618e}']), ('\u{fa8a}',  &['\u{6160}']), ('\u{fa8b}',  &['\u{61f2}']), ('\u{fa8c}',
-         &['\u{6234}']), ('\u{fa8d}',  &['\u{63c4}']), ('\u{fa8e}',  &['\u{641c}']), ('\u{fa8f}',
-         &['\u{6452}']), ('\u{fa90}',  &['\u{6556}']), ('\u{fa91}',  &['\u{6674}']), ('\u{fa92}',
-         &['\u{6717}']), ('\u{fa93}',  &['\u{671b}']), ('\u{fa94}',  &['\u{6756}']), ('\u{fa95}',
-         &['\u{6b79}']), ('\u{fa96}',  &['\u{6bba}']), ('\u{fa97}',  &['\u{6d41}']), ('\u{fa98}',
-         &['\u{6edb}']), ('\u{fa99}',  &['\u{6ecb}']), ('\u{fa9a}',  &['\u{6f22}']), ('\u{fa9b}',
-         &['\u{701e}']), ('\u{fa9c}',  &['\u{716e}']), ('\u{fa9d}',  &['\u{77a7}']), ('\u{fa9e}',
-         &['\u{7235}']), ('\u{fa9f}',  &['\u{72af}']), ('\u{faa0}',  &['\u{732a}']), ('\u{faa1}',
-         &['\u{7471}']), ('\u{faa2}',  &['\u{7506}']), ('\u{faa3}',  &['\u{753b}']), ('\u{faa4}',
-         &['\u{761d}']), ('\u{faa5}',  &['\u{761f}']), ('\u{faa6}',  &['\u{76ca}']), ('\u{faa7}',
-         &['\u{76db}']), ('\u{faa8}',  &['\u{76f4}']), ('\u{faa9}',  &['\u{774a}']), ('\u{faaa}',
-         &['\u{7740}']), ('\u{faab}',  &['\u{78cc}']), ('\u{faac}',  &['\u{7ab1}']), ('\u{faad}',
-         &['\u{7bc0}']), ('\u{faae}',  &['\u{7c7b}']), ('\u{faaf}',  &['\u{7d5b}']), ('\u{fab0}',
-         &['\u{7df4}']), ('\u{fab1}',  &['\u{7f3e}']), ('\u{fab2}',  &['\u{8005}']), ('\u{fab3}',
-         &['\u{8352}']), ('\u{fab4}',  &['\u{83ef}']), ('\u{fab5}',  &['\u{8779}']), ('\u{fab6}',
-         &['\u{8941}']), ('\u{fab7}',  &['\u{8986}']), ('\u{fab8}',  &['\u{8996}']), ('\u{fab9}',
-         &['\u{8abf}']), ('\u{faba}',  &['\u{8af8}']), ('\u{fabb}',  &['\u{8acb}']), ('\u{fabc}',
-         &['\u{8b01}']), ('\u{fabd}',  &['\u{8afe}']), ('\u{fabe}',  &['\u{8aed}']), ('\u{fabf}',
-         &['\u{8b39}']), ('\u{fac0}',  &['\u{8b8a}']), ('\u{fac1}',  &['\u{8d08}']), ('\u{fac2}',
-         &['\u{8f38}']), ('\u{fac3}',  &['\u{9072}']), ('\u{fac4}',  &['\u{9199}']), ('\u{fac5}',
-         &['\u{9276}']), ('\u{fac6}',  &['\u{967c}']), ('\u{fac7}',  &['\u{96e3}']), ('\u{fac8}',
-         &['\u{9756}']), ('\u{fac9}',  &['\u{97db}']), ('\u{faca}',  &['\u{97ff}']), ('\u{facb}',
-         &['\u{980b}']), ('\u{facc}',  &['\u{983b}']), ('\u{facd}',  &['\u{9b12}']), ('\u{face}',
-         &['\u{9f9c}']), ('\u{facf}',  &['\u{2284a}']), ('\u{fad0}',  &['\u{22844}']), ('\u{fad1}',
-         &['\u{233d5}']), ('\u{fad2}',  &['\u{3b9d}']), ('\u{fad3}',  &['\u{4018}']), ('\u{fad4}',
-         &['\u{4039}']), ('\u{fad5}',  &['\u{25249}']), ('\u{fad6}',  &['\u{25cd0}']), ('\u{fad7}',
-         &['\u{27ed3}']), ('\u{fad8}',  &['\u{9f43}']), ('\u{fad9}',  &['\u{9f8e}']), ('\u{fb00}',
-         &['\u{66}',  '\u{66}']), ('\u{fb01}',  &['\u{66}',  '\u{69}']), ('\u{fb02}',  &['\u{66}',
-         '\u{6c}']), ('\u{fb03}',  &['\u{66}',  '\u{66}',  '\u{69}']), ('\u{fb04}',  &['\u{66}',
-         '\u{66}',  '\u{6c}']), ('\u{fb06}',  &['\u{73}',  '\u{74}']), ('\u{fb13}',  &['\u{574}',
-         '\u{576}']), ('\u{fb14}',  &['\u{574}',  '\u{565}']), ('\u{fb15}',  &['\u{574}',
-         '\u{56b}']), ('\u{fb16}',  &['\u{57e}',  '\u{576}']), ('\u{fb17}',  &['\u{574}',
-         '\u{56d}']), ('\u{fb20}',  &['\u{5e2}']), ('\u{fb21}',  &['\u{5d0}']), ('\u{fb22}',
-         &['\u{5d3}']), ('\u{fb23}',  &['\u{5d4}']), ('\u{fb24}',  &['\u{5db}']), ('\u{fb25}',
-         &['\u{5dc}']), ('\u{fb26}',  &['\u{5dd}']), ('\u{fb27}',  &['\u{5e8}']), ('\u{fb28}',
-         &['\u{5ea}']), ('\u{fb29}',  &['\u{2d}',  '\u{307}']), ('\u{fb2b}',  &['\u{fb2a}']),
-        ('\u{fb2d}',  &['\u{fb2c}']), ('\u{fb2f}',  &['\u{fb2e}']), ('\u{fb30}',  &['\u{fb2e}']),
-        ('\u{fb39}',  &['\u{fb1d}']), ('\u{fb49}',  &['\u{fb2a}']), ('\u{fb4f}',  &['\u{5d0}',
-         '\u{5dc}']), ('\u{fb50}',  &['\u{671}']), ('\u{fb51}',  &['\u{671}']), ('\u{fb52}',
-         &['\u{67b}']), ('\u{fb53}',  &['\u{67b}']), ('\u{fb54}',  &['\u{67b}']), ('\u{fb55}',
-         &['\u{67b}']), ('\u{fb56}',  &['\u{649}',  '\u{6db}']), ('\u{fb57}',  &['\u{649}',
-         '\u{6db}']), ('\u{fb58}',  &['\u{649}',  '\u{6db}']), ('\u{fb59}',  &['\u{649}',
-         '\u{6db}']), ('\u{fb5a}',  &['\u{680}']), ('\u{fb5b}',  &['\u{680}']), ('\u{fb5c}',
-         &['\u{680}']), ('\u{fb5d}',  &['\u{680}']), ('\u{fb5e}',  &['\u{62a}']), ('\u{fb5f}',
-         &['\u{62a}']), ('\u{fb60}',  &['\u{62a}']), ('\u{fb61}',  &['\u{62a}']), ('\u{fb62}',
-         &['\u{67f}']), ('\u{fb63}',  &['\u{67f}']), ('\u{fb64}',  &['\u{67f}']), ('\u{fb65}',
-         &['\u{67f}']), ('\u{fb66}',  &['\u{649}',  '\u{615}']), ('\u{fb67}',  &['\u{649}',
-         '\u{615}']), ('\u{fb68}',  &['\u{649}',  '\u{615}']), ('\u{fb69}',  &['\u{649}',
-         '\u{615}']), ('\u{fb6a}',  &['\u{6a1}',  '\u{6db}']), ('\u{fb6b}',  &['\u{6a1}',
-         '\u{6db}']), ('\u{fb6c}',  &['\u{6a1}',  '\u{6db}']), ('\u{fb6d}',  &['\u{6a1}',
-         '\u{6db}']), ('\u{fb6e}',  &['\u{6a6}']), ('\u{fb6f}',  &['\u{6a6}']), ('\u{fb70}',
-         &['\u{6a6}']), ('\u{fb71}',  &['\u{6a6}']), ('\u{fb72}',  &['\u{684}']), ('\u{fb73}',
-         &['\u{684}']), ('\u{fb74}',  &['\u{684}']), ('\u{fb75}',  &['\u{684}']), ('\u{fb76}',
-         &['\u{683}']), ('\u{fb77}',  &['\u{683}']), ('\u{fb78}',  &['\u{683}']), ('\u{fb79}',
-         &['\u{683}']), ('\u{fb7a}',  &['\u{686}']), ('\u{fb7b}',  &['\u{686}']), ('\u{fb7c}',
-         &['\u{686}']), ('\u{fb7d}',  &['\u{686}']), ('\u{fb7e}',  &['\u{687}']), ('\u{fb7f}',
-         &['\u{687}']), ('\u{fb80}',  &['\u{687}']), ('\u{fb81}',  &['\u{687}']), ('\u{fb82}',
-         &['\u{68d}']), ('\u{fb83}',  &['\u{68d}']), ('\u{fb84}',  &['\u{68c}']), ('\u{fb85}',
-         &['\u{68c}']), ('\u{fb86}',  &['\u{62f}',  '\u{6db}']), ('\u{fb87}',  &['\u{62f}',
-         '\u{6db}']), ('\u{fb88}',  &['\u{62f}',  '\u{615}']), ('\u{fb89}',  &['\u{62f}',
-         '\u{615}']), ('\u{fb8a}',  &['\u{631}',  '\u{6db}']), ('\u{fb8b}',  &['\u{631}',
-         '\u{6db}']), ('\u{fb8c}',  &['\u{631}',  '\u{615}']), ('\u{fb8d}',  &['\u{631}',
-         '\u{615}']), ('\u{fb8e}',  &['\u{643}']), ('\u{fb8f}',  &['\u{643}']), ('\u{fb90}',
-         &['\u{643}']), ('\u{fb91}',  &['\u{643}']), ('\u{fb92}',  &['\u{6af}']), ('\u{fb93}',
-         &['\u{6af}']), ('\u{fb94}',  &['\u{6af}']), ('\u{fb95}',  &['\u{6af}']), ('\u{fb96}',
-         &['\u{6b3}']), ('\u{fb97}',  &['\u{6b3}']), ('\u{fb98}',  &['\u{6b3}']), ('\u{fb99}',
-         &['\u{6b3}']), ('\u{fb9a}',  &['\u{6b1}']), ('\u{fb9b}',  &['\u{6b1}']), ('\u{fb9c}',
-         &['\u{6b1}']), ('\u{fb9d}',  &['\u{6b1}']), ('\u{fb9e}',  &['\u{649}']), ('\u{fb9f}',
-         &['\u{649}']), ('\u{fba0}',  &['\u{649}',  '\u{615}']), ('\u{fba1}',  &['\u{649}',
-         '\u{615}']), ('\u{fba2}',  &['\u{649}',  '\u{615}']), ('\u{fba3}',  &['\u{649}',
-         '\u{615}']), ('\u{fba4}',  &['\u{6c0}']), ('\u{fba5}',  &['\u{6c0}']), ('\u{fba6}',
-         &['\u{6f}']), ('\u{fba7}',  &['\u{6f}']), ('\u{fba8}',  &['\u{6f}']), ('\u{fba9}',
-         &['\u{6f}']), ('\u{fbaa}',  &['\u{6f}']), ('\u{fbab}',  &['\u{6f}']), ('\u{fbac}',
-         &['\u{6f}']), ('\u{fbad}',  &['\u{6f}']), ('\u{fbae}',  &['\u{649}']), ('\u{fbaf}',
-         &['\u{649}']), ('\u{fbb0}',  &['\u{6d3}']), ('\u{fbb1}',  &['\u{6d3}']), ('\u{fbd3}',
-         &['\u{643}',  '\u{6db}']), ('\u{fbd4}',  &['\u{643}',  '\u{6db}']), ('\u{fbd5}',
-         &['\u{643}',  '\u{6db}']), ('\u{fbd6}',  &['\u{643}',  '\u{6db}']), ('\u{fbd7}',
-         &['\u{648}',  '\u{313}']), ('\u{fbd8}',  &['\u{648}',  '\u{313}']), ('\u{fbd9}',
-         &['\u{648}',  '\u{306}']), ('\u{fbda}',  &['\u{648}',  '\u{306}']), ('\u{fbdb}',
-         &['\u{648}',  '\u{670}']), ('\u{fbdc}',  &['\u{648}',  '\u{670}']), ('\u{fbdd}',
-         &['\u{648}',  '\u{313}',  '\u{674}']), ('\u{fbde}',  &['\u{648}',  '\u{6db}']),
-        ('\u{fbdf}',  &['\u{648}',  '\u{6db}']), ('\u{fbe0}',  &['\u{6c5}']), ('\u{fbe1}',
-         &['\u{6c5}']), ('\u{fbe2}',  &['\u{648}',  '\u{302}']), ('\u{fbe3}',  &['\u{648}',
-         '\u{302}']), ('\u{fbe4}',  &['\u{67b}']), ('\u{fbe5}',  &['\u{67b}']), ('\u{fbe6}',
-         &['\u{67b}']), ('\u{fbe7}',  &['\u{67b}']), ('\u{fbe8}',  &['\u{649}']), ('\u{fbe9}',
-         &['\u{649}']), ('\u{fbea}',  &['\u{649}',  '\u{674}',  '\u{6c}']), ('\u{fbeb}',
-         &['\u{649}',  '\u{674}',  '\u{6c}']), ('\u{fbec}',  &['\u{649}',  '\u{674}',  '\u{6f}']),
-        ('\u{fbed}',  &['\u{649}',  '\u{674}',  '\u{6f}']), ('\u{fbee}',  &['\u{649}',  '\u{674}',
-         '\u{648}']), ('\u{fbef}',  &['\u{649}',  '\u{674}',  '\u{648}']), ('\u{fbf0}',
-         &['\u{649}',  '\u{674}',  '\u{648}',  '\u{313}']), ('\u{fbf1}',  &['\u{649}',  '\u{674}',
-         '\u{648}',  '\u{313}']), ('\u{fbf2}',  &['\u{649}',  '\u{674}',  '\u{648}',  '\u{306}']),
-        ('\u{fbf3}',  &['\u{649}',  '\u{674}',  '\u{648}',  '\u{306}']), ('\u{fbf4}',  &['\u{649}',
-         '\u{674}',  '\u{648}',  '\u{670}']), ('\u{fbf5}',  &['\u{649}',  '\u{674}',  '\u{648}',
-         '\u{670}']), ('\u{fbf6}',  &['\u{649}',  '\u{674}',  '\u{67b}']), ('\u{fbf7}',
-         &['\u{649}',  '\u{674}',  '\u{67b}']), ('\u{fbf8}',  &['\u{649}',  '\u{674}',  '\u{67b}']),
-        ('\u{fbf9}',  &['\u{649}',  '\u{674}',  '\u{649}']), ('\u{fbfa}',  &['\u{649}',  '\u{674}',
-         '\u{649}']), ('\u{fbfb}',  &['\u{649}',  '\u{674}',  '\u{649}']), ('\u{fbfc}',
-         &['\u{649}']), ('\u{fbfd}',  &['\u{649}']), ('\u{fbfe}',  &['\u{649}']), ('\u{fbff}',
-         &['\u{649}']), ('\u{fc00}',  &['\u{649}',  '\u{674}',  '\u{62c}']), ('\u{fc01}',
-         &['\u{649}',  '\u{674}',  '\u{62d}']), ('\u{fc02}',  &['\u{649}',  '\u{674}',  '\u{645}']),
-        ('\u{fc03}',  &['\u{649}',  '\u{674}',  '\u{649}']), ('\u{fc04}',  &['\u{649}',  '\u{674}',
-         '\u{649}']), ('\u{fc05}',  &['\u{628}',  '\u{62c}']), ('\u{fc06}',  &['\u{628}',
-         '\u{62d}']), ('\u{fc07}',  &['\u{628}',  '\u{62e}']), ('\u{fc08}',  &['\u{628}',
-         '\u{645}']), ('\u{fc09}',  &['\u{628}',  '\u{649}']), ('\u{fc0a}',  &['\u{628}',
-         '\u{649}']), ('\u{fc0b}',  &['\u{62a}',  '\u{62c}']), ('\u{fc0c}',  &['\u{62a}',
-         '\u{62d}']), ('\u{fc0d}',  &['\u{62a}',  '\u{62e}']), ('\u{fc0e}',  &['\u{62a}',
-         '\u{645}']), ('\u{fc0f}',  &['\u{62a}',  '\u{649}']), ('\u{fc10}',  &['\u{62a}',
-         '\u{649}']), ('\u{fc11}',  &['\u{649}',  '\u{6db}',  '\u{62c}']), ('\u{fc12}',
-         &['\u{649}',  '\u{6db}',  '\u{645}']), ('\u{fc13}',  &['\u{649}',  '\u{6db}',  '\u{649}']),
-        ('\u{fc14}',  &['\u{649}',  '\u{6db}',  '\u{649}']), ('\u{fc15}',  &['\u{62c}',
-         '\u{62d}']), ('\u{fc16}',  &['\u{62c}',  '\u{645}']), ('\u{fc17}',  &['\u{62d}',
-         '\u{62c}']), ('\u{fc18}',  &['\u{62d}',  '\u{645}']), ('\u{fc19}',  &['\u{62e}',
-         '\u{62c}']), ('\u{fc1a}',  &['\u{62e}',  '\u{62d}']), ('\u{fc1b}',  &['\u{62e}',
-         '\u{645}']), ('\u{fc1c}',  &['\u{633}',  '\u{62c}']), ('\u{fc1d}',  &['\u{633}',
-         '\u{62d}']), ('\u{fc1e}',  &['\u{633}',  '\u{62e}']), ('\u{fc1f}',  &['\u{633}',
-         '\u{645}']), ('\u{fc20}',  &['\u{635}',  '\u{62d}']), ('\u{fc21}',  &['\u{635}',
-         '\u{645}']), ('\u{fc22}',  &['\u{636}',  '\u{62c}']), ('\u{fc23}',  &['\u{636}',
-         '\u{62d}']), ('\u{fc24}',  &['\u{636}',  '\u{62e}']), ('\u{fc25}',  &['\u{636}',
-         '\u{645}']), ('\u{fc26}',  &['\u{637}',  '\u{62d}']), ('\u{fc27}',  &['\u{637}',
-         '\u{645}']), ('\u{fc28}',  &['\u{638}',  '\u{645}']), ('\u{fc29}',  &['\u{639}',
-         '\u{62c}']), ('\u{fc2a}',  &['\u{639}',  '\u{645}']), ('\u{fc2b}',  &['\u{63a}',
-         '\u{62c}']), ('\u{fc2c}',  &['\u{63a}',  '\u{645}']), ('\u{fc2d}',  &['\u{641}',
-         '\u{62c}']), ('\u{fc2e}',  &['\u{641}',  '\u{62d}']), ('\u{fc2f}',  &['\u{641}',
-         '\u{62e}']), ('\u{fc30}',  &['\u{641}',  '\u{645}']), ('\u{fc31}',  &['\u{641}',
-         '\u{649}']), ('\u{fc32}',  &['\u{641}',  '\u{649}']), ('\u{fc33}',  &['\u{642}',
-         '\u{62d}']), ('\u{fc34}',  &['\u{642}',  '\u{645}']), ('\u{fc35}',  &['\u{642}',
-         '\u{649}']), ('\u{fc36}',  &['\u{642}',  '\u{649}']), ('\u{fc37}',  &['\u{643}',
-         '\u{6c}']), ('\u{fc38}',  &['\u{643}',  '\u{62c}']), ('\u{fc39}',  &['\u{643}',
-         '\u{62d}']), ('\u{fc3a}',  &['\u{643}',  '\u{62e}']), ('\u{fc3b}',  &['\u{643}',
-         '\u{644}']), ('\u{fc3c}',  &['\u{643}',  '\u{645}']), ('\u{fc3d}',  &['\u{643}',
-         '\u{649}']), ('\u{fc3e}',  &['\u{643}',  '\u{649}']), ('\u{fc3f}',  &['\u{644}',
-         '\u{62c}']), ('\u{fc40}',  &['\u{644}',  '\u{62d}']), ('\u{fc41}',  &['\u{644}',
-         '\u{62e}']), ('\u{fc42}',  &['\u{644}',  '\u{645}']), ('\u{fc43}',  &['\u{644}',
-         '\u{649}']), ('\u{fc44}',  &['\u{644}',  '\u{649}']), ('\u{fc45}',  &['\u{645}',
-         '\u{62c}']), ('\u{fc46}',  &['\u{645}',  '\u{62d}']), ('\u{fc47}',  &['\u{645}',
-         '\u{62e}']), ('\u{fc48}',  &['\u{645}',  '\u{645}']), ('\u{fc49}',  &['\u{645}',
-         '\u{649}']), ('\u{fc4a}',  &['\u{645}',  '\u{649}']), ('\u{fc4b}',  &['\u{628}',
-         '\u{62e}']), ('\u{fc4c}',  &['\u{646}',  '\u{62d}']), ('\u{fc4d}',  &['\u{646}',
-         '\u{62e}']), ('\u{fc4e}',  &['\u{646}',  '\u{645}']), ('\u{fc4f}',  &['\u{646}',
-         '\u{649}']), ('\u{fc50}',  &['\u{646}',  '\u{649}']), ('\u{fc51}',  &['\u{6f}',
-         '\u{62c}']), ('\u{fc52}',  &['\u{6f}',  '\u{645}']), ('\u{fc53}',  &['\u{6f}',
-         '\u{649}']), ('\u{fc54}',  &['\u{6f}',  '\u{649}']), ('\u{fc55}',  &['\u{649}',
-         '\u{62c}']), ('\u{fc56}',  &['\u{649}',  '\u{62d}']), ('\u{fc57}',  &['\u{649}',
-         '\u{62e}']), ('\u{fc58}',  &['\u{649}',  '\u{645}']), ('\u{fc59}',  &['\u{649}',
-         '\u{649}']), ('\u{fc5a}',  &['\u{649}',  '\u{649}']), ('\u{fc5b}',  &['\u{630}',
-         '\u{670}']), ('\u{fc5c}',  &['\u{631}',  '\u{670}']), ('\u{fc5d}',  &['\u{649}',
-         '\u{670}']), ('\u{fc5e}',  &['\u{fe72}',  '\u{651}']), ('\u{fc5f}',  &['\u{fe74}',
-         '\u{651}']), ('\u{fc60}',  &['\u{fe76}',  '\u{651}']), ('\u{fc61}',  &['\u{fe78}',
-         '\u{651}']), ('\u{fc62}',  &['\u{fe7a}',  '\u{651}']), ('\u{fc63}',  &['\u{fe7c}',
-         '\u{670}']), ('\u{fc64}',  &['\u{649}',  '\u{674}',  '\u{631}']), ('\u{fc65}',
-         &['\u{649}',  '\u{674}',  '\u{632}']), ('\u{fc66}',  &['\u{649}',  '\u{674}',  '\u{645}']),
-        ('\u{fc67}',  &['\u{649}',  '\u{674}',  '\u{646}']), ('\u{fc68}',  &['\u{649}',  '\u{674}',
-         '\u{649}']), ('\u{fc69}',  &['\u{649}',  '\u{674}',  '\u{649}']), ('\u{fc6a}',
-         &['\u{628}',  '\u{631}']), ('\u{fc6b}',  &['\u{628}',  '\u{632}']), ('\u{fc6c}',
-         &['\u{628}',  '\u{645}']), ('\u{fc6d}',  &['\u{628}',  '\u{646}']), ('\u{fc6e}',
-         &['\u{628}',  '\u{649}']), ('\u{fc6f}',  &['\u{628}',  '\u{649}']), ('\u{fc70}',
-         &['\u{62a}',  '\u{631}']), ('\u{fc71}',  &['\u{62a}',  '\u{632}']), ('\u{fc72}',
-         &['\u{62a}',  '\u{645}']), ('\u{fc73}',  &['\u{62a}',  '\u{646}']), ('\u{fc74}',
-         &['\u{62a}',  '\u{649}']), ('\u{fc75}',  &['\u{62a}',  '\u{649}']), ('\u{fc76}',
-         &['\u{649}',  '\u{6db}',  '\u{631}']), ('\u{fc77}',  &['\u{649}',  '\u{6db}',  '\u{632}']),
-        ('\u{fc78}',  &['\u{649}',  '\u{6db}',  '\u{645}']), ('\u{fc79}',  &['\u{649}',  '\u{6db}',
-         '\u{646}']), ('\u{fc7a}',  &['\u{649}',  '\u{6db}',  '\u{649}']), ('\u{fc7b}',
-         &['\u{649}',  '\u{6db}',  '\u{649}']), ('\u{fc7c}',  &['\u{641}',  '\u{649}']),
-        ('\u{fc7d}',  &['\u{641}',  '\u{649}']), ('\u{fc7e}',  &['\u{642}',  '\u{649}']),
-        ('\u{fc7f}',  &['\u{642}',  '\u{649}']), ('\u{fc80}',  &['\u{643}',  '\u{6c}']),
-        ('\u{fc81}',  &['\u{643}',  '\u{644}']), ('\u{fc82}',  &['\u{643}',  '\u{645}']),
-        ('\u{fc83}',  &['\u{643}',  '\u{649}']), ('\u{fc84}',  &['\u{643}',  '\u{649}']),
-        ('\u{fc85}',  &['\u{644}',  '\u{645}']), ('\u{fc86}',  &['\u{644}',  '\u{649}']),
-        ('\u{fc87}',  &['\u{644}',  '\u{649}']), ('\u{fc88}',  &['\u{645}',  '\u{6c}']),
-        ('\u{fc89}',  &['\u{645}',  '\u{645}']), ('\u{fc8a}',  &['\u{646}',  '\u{631}']),
-        ('\u{fc8b}',  &['\u{646}',  '\u{632}']), ('\u{fc8c}',  &['\u{646}',  '\u{645}']),
-        ('\u{fc8d}',  &['\u{646}',  '\u{646}']), ('\u{fc8e}',  &['\u{646}',  '\u{649}']),
-        ('\u{fc8f}',  &['\u{646}',  '\u{649}']), ('\u{fc90}',  &['\u{649}',  '\u{670}']),
-        ('\u{fc91}',  &['\u{649}',  '\u{631}']), ('\u{fc92}',  &['\u{649}',  '\u{632}']),
-        ('\u{fc93}',  &['\u{649}',  '\u{645}']), ('\u{fc94}',  &['\u{649}',  '\u{646}']),
-        ('\u{fc95}',  &['\u{649}',  '\u{649}']), ('\u{fc96}',  &['\u{649}',  '\u{649}']),
-        ('\u{fc97}',  &['\u{649}',  '\u{674}',  '\u{62c}']), ('\u{fc98}',  &['\u{649}',  '\u{674}',
-         '\u{62d}']), ('\u{fc99}',  &['\u{649}',  '\u{674}',  '\u{62e}']), ('\u{fc9a}',
-         &['\u{649}',  '\u{674}',  '\u{645}']), ('\u{fc9b}',  &['\u{649}',  '\u{674}',  '\u{6f}']),
-        ('\u{fc9c}',  &['\u{628}',  '\u{62c}']), ('\u{fc9d}',  &['\u{628}',  '\u{62d}']),
-        ('\u{fc9e}',  &['\u{628}',  '\u{62e}']), ('\u{fc9f}',  &['\u{628}',  '\u{645}']),
-        ('\u{fca0}',  &['\u{628}',  '\u{6f}']), ('\u{fca1}',  &['\u{62a}',  '\u{62c}']),
-        ('\u{fca2}',  &['\u{62a}',  '\u{62d}']), ('\u{fca3}',  &['\u{62a}',  '\u{62e}']),
-        ('\u{fca4}',  &['\u{62a}',  '\u{645}']), ('\u{fca5}',  &['\u{62a}',  '\u{6f}']),
-        ('\u{fca6}',  &['\u{649}',  '\u{6db}',  '\u{645}']), ('\u{fca7}',  &['\u{62c}',
-         '\u{62d}']), ('\u{fca8}',  &['\u{62c}',  '\u{645}']), ('\u{fca9}',  &['\u{62d}',
-         '\u{62c}']), ('\u{fcaa}',  &['\u{62d}',  '\u{645}']), ('\u{fcab}',  &['\u{62e}',
-         '\u{62c}']), ('\u{fcac}',  &['\u{62e}',  '\u{645}']), ('\u{fcad}',  &['\u{633}',
-         '\u{62c}']), ('\u{fcae}',  &['\u{633}',  '\u{62d}']), ('\u{fcaf}',  &['\u{633}',
-         '\u{62e}']), ('\u{fcb0}',  &['\u{633}',  '\u{645}']), ('\u{fcb1}',  &['\u{635}',
-         '\u{62d}']), ('\u{fcb2}',  &['\u{635}',  '\u{62e}']), ('\u{fcb3}',  &['\u{635}',
-         '\u{645}']), ('\u{fcb4}',  &['\u{636}',  '\u{62c}']), ('\u{fcb5}',  &['\u{636}',
-         '\u{62d}']), ('\u{fcb6}',  &['\u{636}',  '\u{62e}']), ('\u{fcb7}',  &['\u{636}',
-         '\u{645}']), ('\u{fcb8}',  &['\u{637}',  '\u{62d}']), ('\u{fcb9}',  &['\u{638}',
-         '\u{645}']), ('\u{fcba}',  &['\u{639}',  '\u{62c}']), ('\u{fcbb}',  &['\u{639}',
-         '\u{645}']), ('\u{fcbc}',  &['\u{63a}',  '\u{62c}']), ('\u{fcbd}',  &['\u{63a}',
-         '\u{645}']), ('\u{fcbe}',  &['\u{641}',  '\u{62c}']), ('\u{fcbf}',  &['\u{641}',
-         '\u{62d}']), ('\u{fcc0}',  &['\u{641}',  '\u{62e}']), ('\u{fcc1}',  &['\u{641}',
-         '\u{645}']), ('\u{fcc2}',  &['\u{642}',  '\u{62d}']), ('\u{fcc3}',  &['\u{642}',
-         '\u{645}']), ('\u{fcc4}',  &['\u{643}',  '\u{62c}']), ('\u{fcc5}',  &['\u{643}',
-         '\u{62d}']), ('\u{fcc6}',  &['\u{643}',  '\u{62e}']), ('\u{fcc7}',  &['\u{643}',
-         '\u{644}']), ('\u{fcc8}',  &['\u{643}',  '\u{645}']), ('\u{fcc9}',  &['\u{644}',
-         '\u{62c}']), ('\u{fcca}',  &['\u{644}',  '\u{62d}']), ('\u{fccb}',  &['\u{644}',
-         '\u{62e}']), ('\u{fccc}',  &['\u{644}',  '\u{645}']), ('\u{fccd}',  &['\u{644}',
-         '\u{6f}']), ('\u{fcce}',  &['\u{645}',  '\u{62c}']), ('\u{fccf}',  &['\u{645}',
-         '\u{62d}']), ('\u{fcd0}',  &['\u{645}',  '\u{62e}']), ('\u{fcd1}',  &['\u{645}',
-         '\u{645}']), ('\u{fcd2}',  &['\u{628}',  '\u{62e}']), ('\u{fcd3}',  &['\u{646}',
-         '\u{62d}']), ('\u{fcd4}',  &['\u{646}',  '\u{62e}']), ('\u{fcd5}',  &['\u{646}',
-         '\u{645}']), ('\u{fcd6}',  &['\u{646}',  '\u{6f}']), ('\u{fcd7}',  &['\u{6f}',
-         '\u{62c}']), ('\u{fcd8}',  &['\u{6f}',  '\u{645}']), ('\u{fcd9}',  &['\u{6f}',
-         '\u{670}']), ('\u{fcda}',  &['\u{649}',  '\u{62c}']), ('\u{fcdb}',  &['\u{649}',
-         '\u{62d}']), ('\u{fcdc}',  &['\u{649}',  '\u{62e}']), ('\u{fcdd}',  &['\u{649}',
-         '\u{645}']), ('\u{fcde}',  &['\u{649}',  '\u{6f}']), ('\u{fcdf}',  &['\u{649}',  '\u{674}',
-         '\u{645}']), ('\u{fce0}',  &['\u{649}',  '\u{674}',  '\u{6f}']), ('\u{fce1}',  &['\u{628}',
-         '\u{645}']), ('\u{fce2}',  &['\u{628}',  '\u{6f}']), ('\u{fce3}',  &['\u{62a}',
-         '\u{645}']), ('\u{fce4}',  &['\u{62a}',  '\u{6f}']), ('\u{fce5}',  &['\u{649}',  '\u{6db}',
-         '\u{645}']), ('\u{fce6}',  &['\u{649}',  '\u{6db}',  '\u{6f}']), ('\u{fce7}',  &['\u{633}',
-         '\u{645}']), ('\u{fce8}',  &['\u{633}',  '\u{6f}']), ('\u{fce9}',  &['\u{633}',  '\u{6db}',
-         '\u{645}']), ('\u{fcea}',  &['\u{633}',  '\u{6db}',  '\u{6f}']), ('\u{fceb}',  &['\u{643}',
-         '\u{644}']), ('\u{fcec}',  &['\u{643}',  '\u{645}']), ('\u{fced}',  &['\u{644}',
-         '\u{645}']), ('\u{fcee}',  &['\u{646}',  '\u{645}']), ('\u{fcef}',  &['\u{646}',
-         '\u{6f}']), ('\u{fcf0}',  &['\u{649}',  '\u{645}']), ('\u{fcf1}',  &['\u{649}',
-         '\u{6f}']), ('\u{fcf2}',  &['\u{fe77}',  '\u{651}']), ('\u{fcf3}',  &['\u{fe79}',
-         '\u{651}']), ('\u{fcf4}',  &['\u{fe7b}',  '\u{651}']), ('\u{fcf5}',  &['\u{637}',
-         '\u{649}']), ('\u{fcf6}',  &['\u{637}',  '\u{649}']), ('\u{fcf7}',  &['\u{639}',
-         '\u{649}']), ('\u{fcf8}',  &['\u{639}',  '\u{649}']), ('\u{fcf9}',  &['\u{63a}',
-         '\u{649}']), ('\u{fcfa}',  &['\u{63a}',  '\u{649}']), ('\u{fcfb}',  &['\u{633}',
-         '\u{649}']), ('\u{fcfc}',  &['\u{633}',  '\u{649}']), ('\u{fcfd}',  &['\u{633}',
-         '\u{6db}',  '\u{649}']), ('\u{fcfe}',  &['\u{633}',  '\u{6db}',  '\u{649}']), ('\u{fcff}',
-         &['\u{62d}',  '\u{649}']), ('\u{fd00}',  &['\u{62d}',  '\u{649}']), ('\u{fd01}',
-         &['\u{62c}',  '\u{649}']), ('\u{fd02}',  &['\u{62c}',  '\u{649}']), ('\u{fd03}',
-         &['\u{62e}',  '\u{649}']), ('\u{fd04}',  &['\u{62e}',  '\u{649}']), ('\u{fd05}',
-         &['\u{635}',  '\u{649}']), ('\u{fd06}',  &['\u{635}',  '\u{649}']), ('\u{fd07}',
-         &['\u{636}',  '\u{649}']), ('\u{fd08}',  &['\u{636}',  '\u{649}']), ('\u{fd09}',
-         &['\u{633}',  '\u{6db}',  '\u{62c}']), ('\u{fd0a}',  &['\u{633}',  '\u{6db}',  '\u{62d}']),
-        ('\u{fd0b}',  &['\u{633}',  '\u{6db}',  '\u{62e}']), ('\u{fd0c}',  &['\u{633}',  '\u{6db}',
-         '\u{645}']), ('\u{fd0d}',  &['\u{633}',  '\u{6db}',  '\u{631}']), ('\u{fd0e}',
-         &['\u{633}',  '\u{631}']), ('\u{fd0f}',  &['\u{635}',  '\u{631}']), ('\u{fd10}',
-         &['\u{636}',  '\u{631}']), ('\u{fd11}',  &['\u{637}',  '\u{649}']), ('\u{fd12}',
-         &['\u{637}',  '\u{649}']), ('\u{fd13}',  &['\u{639}',  '\u{649}']), ('\u{fd14}',
-         &['\u{639}',  '\u{649}']), ('\u{fd15}',  &['\u{63a}',  '\u{649}']), ('\u{fd16}',
-         &['\u{63a}',  '\u{649}']), ('\u{fd17}',  &['\u{633}',  '\u{649}']), ('\u{fd18}',
-         &['\u{633}',  '\u{649}']), ('\u{fd19}',  &['\u{633}',  '\u{6db}',  '\u{649}']),
-        ('\u{fd1a}',  &['\u{633}',  '\u{6db}',  '\u{649}']), ('\u{fd1b}',  &['\u{62d}',
-         '\u{649}']), ('\u{fd1c}',  &['\u{62d}',  '\u{649}']), ('\u{fd1d}',  &['\u{62c}',
-         '\u{649}']), ('\u{fd1e}',  &['\u{62c}',  '\u{649}']), ('\u{fd1f}',  &['\u{62e}',
-         '\u{649}']), ('\u{fd20}',  &['\u{62e}',  '\u{649}']), ('\u{fd21}',  &['\u{635}',
-         '\u{649}']), ('\u{fd22}',  &['\u{635}',  '\u{649}']), ('\u{fd23}',  &['\u{636}',
-         '\u{649}']), ('\u{fd24}',  &['\u{636}',  '\u{649}']), ('\u{fd25}',  &['\u{633}',
-         '\u{6db}',  '\u{62c}']), ('\u{fd26}',  &['\u{633}',  '\u{6db}',  '\u{62d}']), ('\u{fd27}',
-         &['\u{633}',  '\u{6db}',  '\u{62e}']), ('\u{fd28}',  &['\u{633}',  '\u{6db}',  '\u{645}']),
-        ('\u{fd29}',  &['\u{633}',  '\u{6db}',  '\u{631}']), ('\u{fd2a}',  &['\u{633}',
-         '\u{631}']), ('\u{fd2b}',  &['\u{635}',  '\u{631}']), ('\u{fd2c}',  &['\u{636}',
-         '\u{631}']), ('\u{fd2d}',  &['\u{633}',  '\u{6db}',  '\u{62c}']), ('\u{fd2e}',
-         &['\u{633}',  '\u{6db}',  '\u{62d}']), ('\u{fd2f}',  &['\u{633}',  '\u{6db}',  '\u{62e}']),
-        ('\u{fd30}',  &['\u{633}',  '\u{6db}',  '\u{645}']), ('\u{fd31}',  &['\u{633}',  '\u{6f}']),
-        ('\u{fd32}',  &['\u{633}',  '\u{6db}',  '\u{6f}']), ('\u{fd33}',  &['\u{637}',  '\u{645}']),
-        ('\u{fd34}',  &['\u{633}',  '\u{62c}']), ('\u{fd35}',  &['\u{633}',  '\u{62d}']),
-        ('\u{fd36}',  &['\u{633}',  '\u{62e}']), ('\u{fd37}',  &['\u{633}',  '\u{6db}',
-         '\u{62c}']), ('\u{fd38}',  &['\u{633}',  '\u{6db}',  '\u{62d}']), ('\u{fd39}',
-         &['\u{633}',  '\u{6db}',  '\u{62e}']), ('\u{fd3a}',  &['\u{637}',  '\u{645}']),
-        ('\u{fd3b}',  &['\u{638}',  '\u{645}']), ('\u{fd3c}',  &['\u{6c}',  '\u{30b}']),
-        ('\u{fd3d}',  &['\u{6c}',  '\u{30b}']), ('\u{fd3e}',  &['\u{28}']), ('\u{fd3f}',
-         &['\u{29}']), ('\u{fd50}',  &['\u{62a}',  '\u{62c}',  '\u{645}']), ('\u{fd51}',
-         &['\u{62a}',  '\u{62d}',  '\u{62c}']), ('\u{fd52}',  &['\u{62a}',  '\u{62d}',  '\u{62c}']),
-        ('\u{fd53}',  &['\u{62a}',  '\u{62d}',  '\u{645}']), ('\u{fd54}',  &['\u{62a}',  '\u{62e}',
-         '\u{645}']), ('\u{fd55}',  &['\u{62a}',  '\u{645}',  '\u{62c}']), ('\u{fd56}',
-         &['\u{62a}',  '\u{645}',  '\u{62d}']), ('\u{fd57}',  &['\u{62a}',  '\u{645}',  '\u{62e}']),
-        ('\u{fd58}',  &['\u{62c}',  '\u{645}',  '\u{62d}']), ('\u{fd59}',  &['\u{62c}',  '\u{645}',
-         '\u{62d}']), ('\u{fd5a}',  &['\u{62d}',  '\u{645}',  '\u{649}']), ('\u{fd5b}',
-         &['\u{62d}',  '\u{645}',  '\u{649}']), ('\u{fd5c}',  &['\u{633}',  '\u{62d}',  '\u{62c}']),
-        ('\u{fd5d}',  &['\u{633}',  '\u{62c}',  '\u{62d}']), ('\u{fd5e}',  &['\u{633}',  '\u{62c}',
-         '\u{649}']), ('\u{fd5f}',  &['\u{633}',  '\u{645}',  '\u{62d}']), ('\u{fd60}',
-         &['\u{633}',  '\u{645}',  '\u{62d}']), ('\u{fd61}',  &['\u{633}',  '\u{645}',  '\u{62c}']),
-        ('\u{fd62}',  &['\u{633}',  '\u{645}',  '\u{645}']), ('\u{fd63}',  &['\u{633}',  '\u{645}',
-         '\u{645}']), ('\u{fd64}',  &['\u{635}',  '\u{62d}',  '\u{62d}']), ('\u{fd65}',
-         &['\u{635}',  '\u{62d}',  '\u{62d}']), ('\u{fd66}',  &['\u{635}',  '\u{645}',  '\u{645}']),
-        ('\u{fd67}',  &['\u{633}',  '\u{6db}',  '\u{62d}',  '\u{645}']), ('\u{fd68}',  &['\u{633}',
-         '\u{6db}',  '\u{62d}',  '\u{645}']), ('\u{fd69}',  &['\u{633}',  '\u{6db}',  '\u{62c}',
-         '\u{649}']), ('\u{fd6a}',  &['\u{633}',  '\u{6db}',  '\u{645}',  '\u{62e}']), ('\u{fd6b}',
-         &['\u{633}',  '\u{6db}',  '\u{645}',  '\u{62e}']), ('\u{fd6c}',  &['\u{633}',  '\u{6db}',
-         '\u{645}',  '\u{645}']), ('\u{fd6d}',  &['\u{633}',  '\u{6db}',  '\u{645}',  '\u{645}']),
-        ('\u{fd6e}',  &['\u{636}',  '\u{62d}',  '\u{649}']), ('\u{fd6f}',  &['\u{636}',  '\u{62e}',
-         '\u{645}']), ('\u{fd70}',  &['\u{636}',  '\u{62e}',  '\u{645}']), ('\u{fd71}',
-         &['\u{637}',  '\u{645}',  '\u{62d}']), ('\u{fd72}',  &['\u{637}',  '\u{645}',  '\u{62d}']),
-        ('\u{fd73}',  &['\u{637}',  '\u{645}',  '\u{645}']), ('\u{fd74}',  &['\u{637}',  '\u{645}',
-         '\u{649}']), ('\u{fd75}',  &['\u{639}',  '\u{62c}',  '\u{645}']), ('\u{fd76}',
-         &['\u{639}',  '\u{645}',  '\u{645}']), ('\u{fd77}',  &['\u{639}',  '\u{645}',  '\u{645}']),
-        ('\u{fd78}',  &['\u{639}',  '\u{645}',  '\u{649}']), ('\u{fd79}',  &['\u{63a}',  '\u{645}',
-         '\u{645}']), ('\u{fd7a}',  &['\u{63a}',  '\u{645}',  '\u{649}']), ('\u{fd7b}',
-         &['\u{63a}',  '\u{645}',  '\u{649}']), ('\u{fd7c}',  &['\u{641}',  '\u{62e}',  '\u{645}']),
-        ('\u{fd7d}',  &['\u{641}',  '\u{62e}',  '\u{645}']), ('\u{fd7e}',  &['\u{642}',  '\u{645}',
-         '\u{62d}']), ('\u{fd7f}',  &['\u{642}',  '\u{645}',  '\u{645}']), ('\u{fd80}',
-         &['\u{644}',  '\u{62d}',  '\u{645}']), ('\u{fd81}',  &['\u{644}',  '\u{62d}',  '\u{649}']),
-        ('\u{fd82}',  &['\u{644}',  '\u{62d}',  '\u{649}']), ('\u{fd83}',  &['\u{644}',  '\u{62c}',
-         '\u{62c}']), ('\u{fd84}',  &['\u{644}',  '\u{62c}',  '\u{62c}']), ('\u{fd85}',
-         &['\u{644}',  '\u{62e}',  '\u{645}']), ('\u{fd86}',  &['\u{644}',  '\u{62e}',  '\u{645}']),
-        ('\u{fd87}',  &['\u{644}',  '\u{645}',  '\u{62d}']), ('\u{fd88}',  &['\u{644}',  '\u{645}',
-         '\u{62d}']), ('\u{fd89}',  &['\u{645}',  '\u{62d}',  '\u{62c}']), ('\u{fd8a}',
-         &['\u{645}',  '\u{62d}',  '\u{645}']), ('\u{fd8b}',  &['\u{645}',  '\u{62d}',  '\u{649}']),
-        ('\u{fd8c}',  &['\u{645}',  '\u{62c}',  '\u{62d}']), ('\u{fd8d}',  &['\u{645}',  '\u{62c}',
-         '\u{645}']), ('\u{fd8e}',  &['\u{645}',  '\u{62e}',  '\u{62c}']), ('\u{fd8f}',
-         &['\u{645}',  '\u{62e}',  '\u{645}']), ('\u{fd92}',  &['\u{645}',  '\u{62c}',  '\u{62e}']),
-        ('\u{fd93}',  &['\u{6f}',  '\u{645}',  '\u{62c}']), ('\u{fd94}',  &['\u{6f}',  '\u{645}',
-         '\u{645}']), ('\u{fd95}',  &['\u{646}',  '\u{62d}',  '\u{645}']), ('\u{fd96}',
-         &['\u{646}',  '\u{62d}',  '\u{649}']), ('\u{fd97}',  &['\u{646}',  '\u{62c}',  '\u{645}']),
-        ('\u{fd98}',  &['\u{646}',  '\u{62c}',  '\u{645}']), ('\u{fd99}',  &['\u{646}',  '\u{62c}',
-         '\u{649}']), ('\u{fd9a}',  &['\u{646}',  '\u{645}',  '\u{649}']), ('\u{fd9b}',
-         &['\u{646}',  '\u{645}',  '\u{649}']), ('\u{fd9c}',  &['\u{649}',  '\u{645}',  '\u{645}']),
-        ('\u{fd9d}',  &['\u{649}',  '\u{645}',  '\u{645}']), ('\u{fd9e}',  &['\u{628}',  '\u{62e}',
-         '\u{649}']), ('\u{fd9f}',  &['\u{62a}',  '\u{62c}',  '\u{649}']), ('\u{fda0}',
-         &['\u{62a}',  '\u{62c}',  '\u{649}']), ('\u{fda1}',  &['\u{62a}',  '\u{62e}',  '\u{649}']),
-        ('\u{fda2}',  &['\u{62a}',  '\u{62e}',  '\u{649}']), ('\u{fda3}',  &['\u{62a}',  '\u{645}',
-         '\u{649}']), ('\u{fda4}',  &['\u{62a}',  '\u{645}',  '\u{649}']), ('\u{fda5}',
-         &['\u{62c}',  '\u{645}',  '\u{649}']), ('\u{fda6}',  &['\u{62c}',  '\u{62d}',  '\u{649}']),
-        ('\u{fda7}',  &['\u{62c}',  '\u{645}',  '\u{649}']), ('\u{fda8}',  &['\u{633}',  '\u{62e}',
-         '\u{649}']), ('\u{fda9}',  &['\u{635}',  '\u{62d}',  '\u{649}']), ('\u{fdaa}',
-         &['\u{633}',  '\u{6db}',  '\u{62d}',  '\u{649}']), ('\u{fdab}',  &['\u{636}',  '\u{62d}',
-         '\u{649}']), ('\u{fdac}',  &['\u{644}',  '\u{62c}',  '\u{649}']), ('\u{fdad}',
-         &['\u{644}',  '\u{645}',  '\u{649}']), ('\u{fdae}',  &['\u{649}',  '\u{62d}',  '\u{649}']),
-        ('\u{fdaf}',  &['\u{649}',  '\u{62c}',  '\u{649}']), ('\u{fdb0}',  &['\u{649}',  '\u{645}',
-         '\u{649}']), ('\u{fdb1}',  &['\u{645}',  '\u{645}',  '\u{649}']), ('\u{fdb2}',
-         &['\u{642}',  '\u{645}',  '\u{649}']), ('\u{fdb3}',  &['\u{646}',  '\u{62d}',  '\u{649}']),
-        ('\u{fdb4}',  &['\u{642}',  '\u{645}',  '\u{62d}']), ('\u{fdb5}',  &['\u{644}',  '\u{62d}',
-         '\u{645}']), ('\u{fdb6}',  &['\u{639}',  '\u{645}',  '\u{649}']), ('\u{fdb7}',
-         &['\u{643}',  '\u{645}',  '\u{649}']), ('\u{fdb8}',  &['\u{646}',  '\u{62c}',  '\u{62d}']),
-        ('\u{fdb9}',  &['\u{645}',  '\u{62e}',  '\u{649}']), ('\u{fdba}',  &['\u{644}',  '\u{62c}',
-         '\u{645}']), ('\u{fdbb}',  &['\u{643}',  '\u{645}',  '\u{645}']), ('\u{fdbc}',
-         &['\u{644}',  '\u{62c}',  '\u{645}']), ('\u{fdbd}',  &['\u{646}',  '\u{62c}',  '\u{62d}']),
-        ('\u{fdbe}',  &['\u{62c}',  '\u{62d}',  '\u{649}']), ('\u{fdbf}',  &['\u{62d}',  '\u{62c}',
-         '\u{649}']), ('\u{fdc0}',  &['\u{645}',  '\u{62c}',  '\u{649}']), ('\u{fdc1}',
-         &['\u{641}',  '\u{645}',  '\u{649}']), ('\u{fdc2}',  &['\u{628}',  '\u{62d}',  '\u{649}']),
-        ('\u{fdc3}',  &['\u{643}',  '\u{645}',  '\u{645}']), ('\u{fdc4}',  &['\u{639}',  '\u{62c}',
-         '\u{645}']), ('\u{fdc5}',  &['\u{635}',  '\u{645}',  '\u{645}']), ('\u{fdc6}',
-         &['\u{633}',  '\u{62e}',  '\u{649}']), ('\u{fdc7}',  &['\u{646}',  '\u{62c}',  '\u{649}']),
-        ('\u{fdf0}',  &['\u{635}',  '\u{644}',  '\u{649}']), ('\u{fdf1}',  &['\u{642}',  '\u{644}',
-         '\u{649}']), ('\u{fdf2}',  &['\u{6c}',  '\u{644}',  '\u{644}',  '\u{651}',  '\u{670}',
-         '\u{6f}']), ('\u{fdf3}',  &['\u{6c}',  '\u{643}',  '\u{628}',  '\u{631}']), ('\u{fdf4}',
-         &['\u{645}',  '\u{62d}',  '\u{645}',  '\u{62f}']), ('\u{fdf5}',  &['\u{635}',  '\u{644}',
-         '\u{639}',  '\u{645}']), ('\u{fdf6}',  &['\u{631}',  '\u{633}',  '\u{648}',  '\u{644}']),
-        ('\u{fdf7}',  &['\u{639}',  '\u{644}',  '\u{649}',  '\u{6f}']), ('\u{fdf8}',  &['\u{648}',
-         '\u{633}',  '\u{644}',  '\u{645}']), ('\u{fdf9}',  &['\u{635}',  '\u{644}',  '\u{649}']),
-        ('\u{fdfa}',  &['\u{635}',  '\u{644}',  '\u{649}',  '\u{20}',  '\u{6c}',  '\u{644}',
-         '\u{644}',  '\u{6f}',  '\u{20}',  '\u{639}',  '\u{644}',  '\u{649}',  '\u{6f}',  '\u{20}',
-         '\u{648}',  '\u{633}',  '\u{644}',  '\u{645}']), ('\u{fdfb}',  &['\u{62c}',  '\u{644}',
-         '\u{20}',  '\u{62c}',  '\u{644}',  '\u{6c}',  '\u{644}',  '\u{6f}']), ('\u{fdfc}',
-         &['\u{631}',  '\u{649}',  '\u{6c}',  '\u{644}']), ('\u{fe19}',  &['\u{2d57}']),
-        ('\u{fe30}',  &['\u{3a}']), ('\u{fe31}',  &['\u{2502}']), ('\u{fe34}',  &['\u{2307}']),
-        ('\u{fe35}',  &['\u{23dc}']), ('\u{fe36}',  &['\u{23dd}']), ('\u{fe37}',  &['\u{23de}']),
-        ('\u{fe38}',  &['\u{23df}']), ('\u{fe39}',  &['\u{23e0}']), ('\u{fe3a}',  &['\u{23e1}']),
-        ('\u{fe49}',  &['\u{2c9}']), ('\u{fe4a}',  &['\u{2c9}']), ('\u{fe4b}',  &['\u{2c9}']),
-        ('\u{fe4c}',  &['\u{2c9}']), ('\u{fe4d}',  &['\u{5f}']), ('\u{fe4e}',  &['\u{5f}']),
-        ('\u{fe4f}',  &['\u{5f}']), ('\u{fe58}',  &['\u{2d}']), ('\u{fe68}',  &['\u{5c}']),
-        ('\u{fe80}',  &['\u{621}']), ('\u{fe81}',  &['\u{622}']), ('\u{fe82}',  &['\u{622}']),
-        ('\u{fe83}',  &['\u{6c}',  '\u{674}']), ('\u{fe84}',  &['\u{6c}',  '\u{674}']), ('\u{fe85}',
-         &['\u{648}',  '\u{674}']), ('\u{fe86}',  &['\u{648}',  '\u{674}']), ('\u{fe87}',
-         &['\u{6c}',  '\u{655}']), ('\u{fe88}',  &['\u{6c}',  '\u{655}']), ('\u{fe89}',
-         &['\u{649}',  '\u{674}']), ('\u{fe8a}',  &['\u{649}',  '\u{674}']), ('\u{fe8b}',
-         &['\u{649}',  '\u{674}']), ('\u{fe8c}',  &['\u{649}',  '\u{674}']), ('\u{fe8d}',
-         &['\u{6c}']), ('\u{fe8e}',  &['\u{6c}']), ('\u{fe8f}',  &['\u{628}']), ('\u{fe90}',
-         &['\u{628}']), ('\u{fe91}',  &['\u{628}']), ('\u{fe92}',  &['\u{628}']), ('\u{fe93}',
-         &['\u{629}']), ('\u{fe94}',  &['\u{629}']), ('\u{fe95}',  &['\u{62a}']), ('\u{fe96}',
-         &['\u{62a}']), ('\u{fe97}',  &['\u{62a}']), ('\u{fe98}',  &['\u{62a}']), ('\u{fe99}',
-         &['\u{649}',  '\u{6db}']), ('\u{fe9a}',  &['\u{649}',  '\u{6db}']), ('\u{fe9b}',
-         &['\u{649}',  '\u{6db}']), ('\u{fe9c}',  &['\u{649}',  '\u{6db}']), ('\u{fe9d}',
-         &['\u{62c}']), ('\u{fe9e}',  &['\u{62c}']), ('\u{fe9f}',  &['\u{62c}']), ('\u{fea0}',
-         &['\u{62c}']), ('\u{fea1}',  &['\u{62d}']), ('\u{fea2}',  &['\u{62d}']), ('\u{fea3}',
-         &['\u{62d}']), ('\u{fea4}',  &['\u{62d}']), ('\u{fea5}',  &['\u{62e}']), ('\u{fea6}',
-         &['\u{62e}']), ('\u{fea7}',  &['\u{62e}']), ('\u{fea8}',  &['\u{62e}']), ('\u{fea9}',
-         &['\u{62f}']), ('\u{feaa}',  &['\u{62f}']), ('\u{feab}',  &['\u{630}']), ('\u{feac}',
-         &['\u{630}']), ('\u{fead}',  &['\u{631}']), ('\u{feae}',  &['\u{631}']), ('\u{feaf}',
-         &['\u{632}']), ('\u{feb0}',  &['\u{632}']), ('\u{feb1}',  &['\u{633}']), ('\u{feb2}',
-         &['\u{633}']), ('\u{feb3}',  &['\u{633}']), ('\u{feb4}',  &['\u{633}']), ('\u{feb5}',
-         &['\u{633}',  '\u{6db}']), ('\u{feb6}',  &['\u{633}',  '\u{6db}']), ('\u{feb7}',
-         &['\u{633}',  '\u{6db}']), ('\u{feb8}',  &['\u{633}',  '\u{6db}']), ('\u{feb9}',
-         &['\u{635}']), ('\u{feba}',  &['\u{635}']), ('\u{febb}',  &['\u{635}']), ('\u{febc}',
-         &['\u{635}']), ('\u{febd}',  &['\u{636}']), ('\u{febe}',  &['\u{636}']), ('\u{febf}',
-         &['\u{636}']), ('\u{fec0}',  &['\u{636}']), ('\u{fec1}',  &['\u{637}']), ('\u{fec2}',
-         &['\u{637}']), ('\u{fec3}',  &['\u{637}']), ('\u{fec4}',  &['\u{637}']), ('\u{fec5}',
-         &['\u{638}']), ('\u{fec6}',  &['\u{638}']), ('\u{fec7}',  &['\u{638}']), ('\u{fec8}',
-         &['\u{638}']), ('\u{fec9}',  &['\u{639}']), ('\u{feca}',  &['\u{639}']), ('\u{fecb}',
-         &['\u{639}']), ('\u{fecc}',  &['\u{639}']), ('\u{fecd}',  &['\u{63a}']), ('\u{fece}',
-         &['\u{63a}']), ('\u{fecf}',  &['\u{63a}']), ('\u{fed0}',  &['\u{63a}']), ('\u{fed1}',
-         &['\u{641}']), ('\u{fed2}',  &['\u{641}']), ('\u{fed3}',  &['\u{641}']), ('\u{fed4}',
-         &['\u{641}']), ('\u{fed5}',  &['\u{642}']), ('\u{fed6}',  &['\u{642}']), ('\u{fed7}',
-         &['\u{642}']), ('\u{fed8}',  &['\u{642}']), ('\u{fed9}',  &['\u{643}']), ('\u{feda}',
-         &['\u{643}']), ('\u{fedb}',  &['\u{643}']), ('\u{fedc}',  &['\u{643}']), ('\u{fedd}',
-         &['\u{644}']), ('\u{fede}',  &['\u{644}']), ('\u{fedf}',  &['\u{644}']), ('\u{fee0}',
-         &['\u{644}']), ('\u{fee1}',  &['\u{645}']), ('\u{fee2}',  &['\u{645}']), ('\u{fee3}',
-         &['\u{645}']), ('\u{fee4}',  &['\u{645}']), ('\u{fee5}',  &['\u{646}']), ('\u{fee6}',
-         &['\u{646}']), ('\u{fee7}',  &['\u{646}']), ('\u{fee8}',  &['\u{646}']), ('\u{fee9}',
-         &['\u{6f}']), ('\u{feea}',  &['\u{6f}']), ('\u{feeb}',  &['\u{6f}']), ('\u{feec}',
-         &['\u{6f}']), ('\u{feed}',  &['\u{648}']), ('\u{feee}',  &['\u{648}']), ('\u{feef}',
-         &['\u{649}']), ('\u{fef0}',  &['\u{649}']), ('\u{fef1}',  &['\u{649}']), ('\u{fef2}',
-         &['\u{649}']), ('\u{fef3}',  &['\u{649}']), ('\u{fef4}',  &['\u{649}']), ('\u{fef5}',
-         &['\u{644}',  '\u{622}']), ('\u{fef6}',  &['\u{644}',  '\u{622}']), ('\u{fef7}',
-         &['\u{644}',  '\u{6c}',  '\u{674}']), ('\u{fef8}',  &['\u{644}',  '\u{6c}',  '\u{674}']),
-        ('\u{fef9}',  &['\u{644}',  '\u{6c}',  '\u{655}']), ('\u{fefa}',  &['\u{644}',  '\u{6c}',
-         '\u{655}']), ('\u{fefb}',  &['\u{644}',  '\u{6c}']), ('\u{fefc}',  &['\u{644}',
-         '\u{6c}']), ('\u{ff01}',  &['\u{21}']), ('\u{ff02}',  &['\u{27}',  '\u{27}']), ('\u{ff07}',
-         &['\u{27}']), ('\u{ff0d}',  &['\u{30fc}']), ('\u{ff1a}',  &['\u{3a}']), ('\u{ff21}',
-         &['\u{41}']), ('\u{ff22}',  &['\u{42}']), ('\u{ff23}',  &['\u{43}']), ('\u{ff25}',
-         &['\u{45}']), ('\u{ff28}',  &['\u{48}']), ('\u{ff29}',  &['\u{6c}']), ('\u{ff2a}',
-         &['\u{4a}']), ('\u{ff2b}',  &['\u{4b}']), ('\u{ff2d}',  &['\u{4d}']), ('\u{ff2e}',
-         &['\u{4e}']), ('\u{ff2f}',  &['\u{4f}']), ('\u{ff30}',  &['\u{50}']), ('\u{ff33}',
-         &['\u{53}']), ('\u{ff34}',  &['\u{54}']), ('\u{ff38}',  &['\u{58}']), ('\u{ff39}',
-         &['\u{59}']), ('\u{ff3a}',  &['\u{5a}']), ('\u{ff3b}',  &['\u{28}']), ('\u{ff3c}',
-         &['\u{5c}']), ('\u{ff3d}',  &['\u{29}']), ('\u{ff3e}',  &['\u{fe3f}']), ('\u{ff40}',
-         &['\u{27}']), ('\u{ff41}',  &['\u{61}']), ('\u{ff43}',  &['\u{63}']), ('\u{ff45}',
-         &['\u{65}']), ('\u{ff47}',  &['\u{67}']), ('\u{ff48}',  &['\u{68}']), ('\u{ff49}',
-         &['\u{69}']), ('\u{ff4a}',  &['\u{6a}']), ('\u{ff4c}',  &['\u{6c}']), ('\u{ff4f}',
-         &['\u{6f}']), ('\u{ff50}',  &['\u{70}']), ('\u{ff53}',  &['\u{73}']), ('\u{ff56}',
-         &['\u{76}']), ('\u{ff58}',  &['\u{78}']), ('\u{ff59}',  &['\u{79}']), ('\u{ff5c}',
-         &['\u{2502}']), ('\u{ff5e}',  &['\u{301c}']), ('\u{ff65}',  &['\u{b7}']), ('\u{ffe3}',
-         &['\u{2c9}']), ('\u{ffe8}',  &['\u{6c}']), ('\u{ffed}',  &['\u{25aa}']), ('\u{10101}',
-         &['\u{b7}']), ('\u{1018e}',  &['\u{4e}',  '\u{30a}']), ('\u{10196}',  &['\u{58}',
-         '\u{335}']), ('\u{10197}',  &['\u{56}',  '\u{335}']), ('\u{10198}',  &['\u{6c}',
-         '\u{335}',  '\u{6c}',  '\u{335}',  '\u{53}',  '\u{335}']), ('\u{10199}',  &['\u{6c}',
-         '\u{335}',  '\u{6c}',  '\u{335}']), ('\u{101a0}',  &['\u{554}']), ('\u{10282}',
-         &['\u{42}']), ('\u{10285}',  &['\u{394}']), ('\u{10286}',  &['\u{45}']), ('\u{10287}',
-         &['\u{46}']), ('\u{1028a}',  &['\u{6c}']), ('\u{1028d}',  &['\u{245}']), ('\u{10290}',
-         &['\u{58}']), ('\u{10292}',  &['\u{4f}']), ('\u{10294}',  &['\u{16dc}']), ('\u{10295}',
-         &['\u{50}']), ('\u{10296}',  &['\u{53}']), ('\u{10297}',  &['\u{54}']), ('\u{1029b}',
-         &['\u{2b}']), ('\u{102a0}',  &['\u{41}']), ('\u{102a1}',  &['\u{42}']), ('\u{102a2}',
-         &['\u{43}']), ('\u{102a3}',  &['\u{394}']), ('\u{102a5}',  &['\u{46}']), ('\u{102ab}',
-         &['\u{4f}']), ('\u{102ad}',  &['\u{3d8}']), ('\u{102b0}',  &['\u{4d}']), ('\u{102b1}',
-         &['\u{54}']), ('\u{102b2}',  &['\u{59}']), ('\u{102b3}',  &['\u{3a6}']), ('\u{102b4}',
-         &['\u{58}']), ('\u{102b5}',  &['\u{3a8}']), ('\u{102b6}',  &['\u{3a9}']), ('\u{102b8}',
-         &['\u{2d40}']), ('\u{102cf}',  &['\u{48}']), ('\u{102e1}',  &['\u{62f}']), ('\u{102e4}',
-         &['\u{648}']), ('\u{102e8}',  &['\u{637}']), ('\u{102f2}',  &['\u{635}']), ('\u{102f5}',
-         &['\u{5a}']), ('\u{10301}',  &['\u{42}']), ('\u{10302}',  &['\u{43}']), ('\u{10309}',
-         &['\u{6c}']), ('\u{10311}',  &['\u{4d}']), ('\u{10312}',  &['\u{3d8}']), ('\u{10315}',
-         &['\u{54}']), ('\u{10317}',  &['\u{58}']), ('\u{1031a}',  &['\u{38}']), ('\u{1031f}',
-         &['\u{2a}']), ('\u{10320}',  &['\u{6c}']), ('\u{10322}',  &['\u{58}']), ('\u{103d1}',
-         &['\u{10382}']), ('\u{103d3}',  &['\u{10393}']), ('\u{10401}',  &['\u{190}']),
-        ('\u{10404}',  &['\u{4f}']), ('\u{10411}',  &['\u{a4f6}']), ('\u{10415}',  &['\u{43}']),
-        ('\u{1041b}',  &['\u{4c}']), ('\u{1041f}',  &['\u{2c70}']), ('\u{10420}',  &['\u{53}']),
-        ('\u{10423}',  &['\u{186}']), ('\u{10425}',  &['\u{418}']), ('\u{10429}',  &['\u{a793}']),
-        ('\u{1042a}',  &['\u{29a}']), ('\u{1042c}',  &['\u{6f}']), ('\u{1043d}',  &['\u{63}']),
-        ('\u{1043f}',  &['\u{277}']), ('\u{10442}',  &['\u{25e}']), ('\u{10443}',  &['\u{29f}']),
-        ('\u{10448}',  &['\u{73}']), ('\u{1044b}',  &['\u{254}']), ('\u{1044d}',  &['\u{1d0e}']),
-        ('\u{104a0}',  &['\u{10486}']), ('\u{104b0}',  &['\u{245}']), ('\u{104b4}',  &['\u{52}']),
-        ('\u{104bc}',  &['\u{4c3}']), ('\u{104c2}',  &['\u{4f}']), ('\u{104c3}',  &['\u{298}']),
-        ('\u{104c4}',  &['\u{de}']), ('\u{104cd}',  &['\u{40b}']), ('\u{104ce}',  &['\u{55}']),
-        ('\u{104d0}',  &['\u{16e6}']), ('\u{104d1}',  &['\u{3a8}']), ('\u{104d2}',  &['\u{37}']),
-        ('\u{104d8}',  &['\u{28c}']), ('\u{104db}',  &['\u{3bb}']), ('\u{104ea}',  &['\u{6f}']),
-        ('\u{104eb}',  &['\u{a669}']), ('\u{104f6}',  &['\u{75}']), ('\u{104f9}',  &['\u{3c8}']),
-        ('\u{10513}',  &['\u{4e}']), ('\u{10516}',  &['\u{4f}']), ('\u{10518}',  &['\u{4b}']),
-        ('\u{1051c}',  &['\u{43}']), ('\u{1051d}',  &['\u{56}']), ('\u{10525}',  &['\u{46}']),
-        ('\u{10526}',  &['\u{4c}']), ('\u{10527}',  &['\u{58}']), ('\u{10a3a}',  &['\u{323}']),
-        ('\u{10a50}',  &['\u{2e}']), ('\u{10a57}',  &['\u{10a56}',  '\u{10a56}']), ('\u{10cfa}',
-         &['\u{10ca5}']), ('\u{10cfc}',  &['\u{10c82}']), ('\u{10ec6}',  &['\u{646}']),
-        ('\u{10ec7}',  &['\u{680}']), ('\u{10ed0}',  &['\u{b0}',  '\u{332}']), ('\u{110bb}',
-         &['\u{970}']), ('\u{111c7}',  &['\u{970}']), ('\u{111ca}',  &['\u{323}']), ('\u{111cb}',
-         &['\u{93a}']), ('\u{111db}',  &['\u{a8fc}']), ('\u{111dc}',  &['\u{a8fb}']), ('\u{111de}',
-         &['\u{2248}']), ('\u{11300}',  &['\u{30a}']), ('\u{11413}',  &['\u{11434}',  '\u{11442}',
-         '\u{11412}']), ('\u{11419}',  &['\u{11434}',  '\u{11442}',  '\u{11418}']), ('\u{11424}',
-         &['\u{11434}',  '\u{11442}',  '\u{11423}']), ('\u{1142a}',  &['\u{11434}',  '\u{11442}',
-         '\u{11429}']), ('\u{1142d}',  &['\u{11434}',  '\u{11442}',  '\u{1142c}']), ('\u{1142f}',
-         &['\u{11434}',  '\u{11442}',  '\u{1142e}']), ('\u{1144c}',  &['\u{1144b}',  '\u{1144b}']),
-        ('\u{11492}',  &['\u{998}']), ('\u{11494}',  &['\u{99a}']), ('\u{11496}',  &['\u{99c}']),
-        ('\u{11498}',  &['\u{99e}']), ('\u{11499}',  &['\u{99f}']), ('\u{1149b}',  &['\u{9a1}']),
-        ('\u{1149d}',  &['\u{9b2}']), ('\u{1149e}',  &['\u{9a4}']), ('\u{1149f}',  &['\u{9a5}']),
-        ('\u{114a0}',  &['\u{9a6}']), ('\u{114a1}',  &['\u{9a7}']), ('\u{114a2}',  &['\u{9a8}']),
-        ('\u{114a3}',  &['\u{9aa}']), ('\u{114a7}',  &['\u{9ae}']), ('\u{114a8}',  &['\u{9af}']),
-        ('\u{114a9}',  &['\u{9ac}']), ('\u{114aa}',  &['\u{9a3}']), ('\u{114ab}',  &['\u{9b0}']),
-        ('\u{114ad}',  &['\u{9b7}']), ('\u{114ae}',  &['\u{9b8}']), ('\u{114b0}',  &['\u{9be}']),
-        ('\u{114b1}',  &['\u{9bf}']), ('\u{114b9}',  &['\u{9c7}']), ('\u{114bc}',  &['\u{9cb}']),
-        ('\u{114bd}',  &['\u{9d7}']), ('\u{114be}',  &['\u{9cc}']), ('\u{114bf}',  &['\u{306}',
-         '\u{307}']), ('\u{114c1}',  &['\u{983}']), ('\u{114c2}',  &['\u{9cd}']), ('\u{114c3}',
-         &['\u{323}']), ('\u{114c4}',  &['\u{9bd}']), ('\u{114c5}',  &['\u{77}',  '\u{307}']),
-        ('\u{114d0}',  &['\u{6f}']), ('\u{114d1}',  &['\u{9e7}']), ('\u{114d2}',  &['\u{9e8}']),
-        ('\u{114d6}',  &['\u{9ec}']), ('\u{115d8}',  &['\u{11582}']), ('\u{115d9}',
-         &['\u{11582}']), ('\u{115da}',  &['\u{11583}']), ('\u{115db}',  &['\u{11584}']),
-        ('\u{115dc}',  &['\u{115b2}']), ('\u{115dd}',  &['\u{115b3}']), ('\u{11642}',
-         &['\u{11641}',  '\u{11641}']), ('\u{11700}',  &['\u{72}',  '\u{6e}']), ('\u{11706}',
-         &['\u{76}']), ('\u{1170a}',  &['\u{77}']), ('\u{1170e}',  &['\u{77}']), ('\u{1170f}',
-         &['\u{77}']), ('\u{118a0}',  &['\u{56}']), ('\u{118a2}',  &['\u{46}']), ('\u{118a3}',
-         &['\u{4c}']), ('\u{118a4}',  &['\u{59}']), ('\u{118a6}',  &['\u{45}']), ('\u{118a8}',
-         &['\u{2207}']), ('\u{118a9}',  &['\u{5a}']), ('\u{118ac}',  &['\u{39}']), ('\u{118ae}',
-         &['\u{45}']), ('\u{118af}',  &['\u{34}']), ('\u{118b2}',  &['\u{4c}']), ('\u{118b5}',
-         &['\u{4f}']), ('\u{118b7}',  &['\u{16dc}']), ('\u{118b8}',  &['\u{55}']), ('\u{118bb}',
-         &['\u{35}']), ('\u{118bc}',  &['\u{54}']), ('\u{118c0}',  &['\u{76}']), ('\u{118c1}',
-         &['\u{73}']), ('\u{118c2}',  &['\u{46}']), ('\u{118c3}',  &['\u{69}']), ('\u{118c4}',
-         &['\u{7a}']), ('\u{118c6}',  &['\u{37}']), ('\u{118c8}',  &['\u{6f}']), ('\u{118ca}',
-         &['\u{33}']), ('\u{118cc}',  &['\u{39}']), ('\u{118ce}',  &['\u{a793}']), ('\u{118d5}',
-         &['\u{36}']), ('\u{118d6}',  &['\u{39}']), ('\u{118d7}',  &['\u{6f}']), ('\u{118d8}',
-         &['\u{75}']), ('\u{118dc}',  &['\u{79}']), ('\u{118e0}',  &['\u{4f}']), ('\u{118e3}',
-         &['\u{72}',  '\u{6e}']), ('\u{118e4}',  &['\u{669}']), ('\u{118e5}',  &['\u{5a}']),
-        ('\u{118e6}',  &['\u{57}']), ('\u{118e9}',  &['\u{43}']), ('\u{118ec}',  &['\u{58}']),
-        ('\u{118ef}',  &['\u{57}']), ('\u{118f2}',  &['\u{43}']), ('\u{11ae6}',  &['\u{11ae5}',
-         '\u{11aef}']), ('\u{11ae7}',  &['\u{11ae5}',  '\u{11af0}']), ('\u{11ae8}',  &['\u{11ae5}',
-         '\u{11ae5}']), ('\u{11ae9}',  &['\u{11ae5}',  '\u{11ae5}',  '\u{11aef}']), ('\u{11aea}',
-         &['\u{11ae5}',  '\u{11ae5}',  '\u{11af0}']), ('\u{11aec}',  &['\u{11aeb}',  '\u{11aef}']),
-        ('\u{11aed}',  &['\u{11aeb}',  '\u{11aeb}']), ('\u{11aee}',  &['\u{11aeb}',  '\u{11aeb}',
-         '\u{11aef}']), ('\u{11af4}',  &['\u{11af3}',  '\u{11aef}']), ('\u{11af5}',  &['\u{11af3}',
-         '\u{11af0}']), ('\u{11af6}',  &['\u{11af3}',  '\u{11af3}']), ('\u{11af7}',  &['\u{11af3}',
-         '\u{11af3}',  '\u{11aef}']), ('\u{11af8}',  &['\u{11af3}',  '\u{11af3}',  '\u{11af0}']),
-        ('\u{11b60}',  &['\u{93a}']), ('\u{11b66}',  &['\u{306}']), ('\u{11c42}',  &['\u{11c41}',
-         '\u{11c41}']), ('\u{11cb2}',  &['\u{11caa}']), ('\u{11dd9}',  &['\u{3a}']), ('\u{11dda}',
-         &['\u{6c}']), ('\u{11de0}',  &['\u{4f}']), ('\u{11de1}',  &['\u{6c}']), ('\u{12038}',
-         &['\u{1039a}']), ('\u{132f9}',  &['\u{1099e}']), ('\u{16ea6}',  &['\u{3a0}']),
-        ('\u{16eaa}',  &['\u{6c}']), ('\u{16eb6}',  &['\u{62}']), ('\u{16ec1}',  &['\u{3c0}']),
-        ('\u{16ed1}',  &['\u{185}']), ('\u{16f07}',  &['\u{393}']), ('\u{16f08}',  &['\u{56}']),
-        ('\u{16f0a}',  &['\u{54}']), ('\u{16f16}',  &['\u{4c}']), ('\u{16f1a}',  &['\u{394}']),
-        ('\u{16f1c}',  &['\u{a658}']), ('\u{16f26}',  &['\u{a4f6}']), ('\u{16f28}',  &['\u{6c}']),
-        ('\u{16f2d}',  &['\u{190}']), ('\u{16f35}',  &['\u{52}']), ('\u{16f3a}',  &['\u{53}']),
-        ('\u{16f3b}',  &['\u{33}']), ('\u{16f3d}',  &['\u{245}']), ('\u{16f3f}',  &['\u{3e}']),
-        ('\u{16f40}',  &['\u{41}']), ('\u{16f42}',  &['\u{55}']), ('\u{16f43}',  &['\u{59}']),
-        ('\u{16f51}',  &['\u{27}']), ('\u{16f52}',  &['\u{27}']), ('\u{16ff2}',  &['\u{513f}']),
-        ('\u{1ccd6}',  &['\u{41}']), ('\u{1ccd7}',  &['\u{42}']), ('\u{1ccd8}',  &['\u{43}']),
-        ('\u{1ccd9}',  &['\u{44}']), ('\u{1ccda}',  &['\u{45}']), ('\u{1ccdb}',  &['\u{46}']),
-        ('\u{1ccdc}',  &['\u{47}']), ('\u{1ccdd}',  &['\u{48}']), ('\u{1ccde}',  &['\u{6c}']),
-        ('\u{1ccdf}',  &['\u{4a}']), ('\u{1cce0}',  &['\u{4b}']), ('\u{1cce1}',  &['\u{4c}']),
-        ('\u{1cce2}',  &['\u{4d}']), ('\u{1cce3}',  &['\u{4e}']), ('\u{1cce4}',  &['\u{4f}']),
-        ('\u{1cce5}',  &['\u{50}']), ('\u{1cce6}',  &['\u{51}']), ('\u{1cce7}',  &['\u{52}']),
-        ('\u{1cce8}',  &['\u{53}']), ('\u{1cce9}',  &['\u{54}']), ('\u{1ccea}',  &['\u{55}']),
-        ('\u{1cceb}',  &['\u{56}']), ('\u{1ccec}',  &['\u{57}']), ('\u{1cced}',  &['\u{58}']),
-        ('\u{1ccee}',  &['\u{59}']), ('\u{1ccef}',  &['\u{5a}']), ('\u{1ccf0}',  &['\u{4f}']),
-        ('\u{1ccf1}',  &['\u{6c}']), ('\u{1ccf2}',  &['\u{32}']), ('\u{1ccf3}',  &['\u{33}']),
-        ('\u{1ccf4}',  &['\u{34}']), ('\u{1ccf5}',  &['\u{35}']), ('\u{1ccf6}',  &['\u{36}']),
-        ('\u{1ccf7}',  &['\u{37}']), ('\u{1ccf8}',  &['\u{38}']), ('\u{1ccf9}',  &['\u{39}']),
-        ('\u{1ccfb}',  &['\u{1f6f8}']), ('\u{1ceef}',  &['\u{2d42}']), ('\u{1d114}',  &['\u{7b}']),
-        ('\u{1d16d}',  &['\u{2e}']), ('\u{1d202}',  &['\u{4fe}']), ('\u{1d206}',  &['\u{33}']),
-        ('\u{1d20b}',  &['\u{418}']), ('\u{1d20d}',  &['\u{56}']), ('\u{1d20f}',  &['\u{5c}']),
-        ('\u{1d212}',  &['\u{37}']), ('\u{1d213}',  &['\u{46}']), ('\u{1d214}',  &['\u{102bc}']),
-        ('\u{1d215}',  &['\u{a4f6}']), ('\u{1d216}',  &['\u{52}']), ('\u{1d217}',  &['\u{2c6f}']),
-        ('\u{1d21a}',  &['\u{4f}',  '\u{335}']), ('\u{1d21b}',  &['\u{2144}']), ('\u{1d21c}',
-         &['\u{a4d5}']), ('\u{1d221}',  &['\u{190}']), ('\u{1d222}',  &['\u{460}']), ('\u{1d22a}',
-         &['\u{4c}']), ('\u{1d22b}',  &['\u{a4f6}']), ('\u{1d230}',  &['\u{a7fb}']), ('\u{1d236}',
-         &['\u{3c}']), ('\u{1d237}',  &['\u{3e}']), ('\u{1d238}',  &['\u{228f}']), ('\u{1d239}',
-         &['\u{2290}']), ('\u{1d23a}',  &['\u{2f}']), ('\u{1d23b}',  &['\u{5c}']), ('\u{1d23f}',
-         &['\u{16cb}']), ('\u{1d245}',  &['\u{548}']), ('\u{1d400}',  &['\u{41}']), ('\u{1d401}',
-         &['\u{42}']), ('\u{1d402}',  &['\u{43}']), ('\u{1d403}',  &['\u{44}']), ('\u{1d404}',
-         &['\u{45}']), ('\u{1d405}',  &['\u{46}']), ('\u{1d406}',  &['\u{47}']), ('\u{1d407}',
-         &['\u{48}']), ('\u{1d408}',  &['\u{6c}']), ('\u{1d409}',  &['\u{4a}']), ('\u{1d40a}',
-         &['\u{4b}']), ('\u{1d40b}',  &['\u{4c}']), ('\u{1d40c}',  &['\u{4d}']), ('\u{1d40d}',
-         &['\u{4e}']), ('\u{1d40e}',  &['\u{4f}']), ('\u{1d40f}',  &['\u{50}']), ('\u{1d410}',
-         &['\u{51}']), ('\u{1d411}',  &['\u{52}']), ('\u{1d412}',  &['\u{53}']), ('\u{1d413}',
-         &['\u{54}']), ('\u{1d414}',  &['\u{55}']), ('\u{1d415}',  &['\u{56}']), ('\u{1d416}',
-         &['\u{57}']), ('\u{1d417}',  &['\u{58}']), ('\u{1d418}',  &['\u{59}']), ('\u{1d419}',
-         &['\u{5a}']), ('\u{1d41a}',  &['\u{61}']), ('\u{1d41b}',  &['\u{62}']), ('\u{1d41c}',
-         &['\u{63}']), ('\u{1d41d}',  &['\u{64}']), ('\u{1d41e}',  &['\u{65}']), ('\u{1d41f}',
-         &['\u{66}']), ('\u{1d420}',  &['\u{67}']), ('\u{1d421}',  &['\u{68}']), ('\u{1d422}',
-         &['\u{69}']), ('\u{1d423}',  &['\u{6a}']), ('\u{1d424}',  &['\u{6b}']), ('\u{1d425}',
-         &['\u{6c}']), ('\u{1d426}',  &['\u{72}',  '\u{6e}']), ('\u{1d427}',  &['\u{6e}']),
-        ('\u{1d428}',  &['\u{6f}']), ('\u{1d429}',  &['\u{70}']), ('\u{1d42a}',  &['\u{71}']),
-        ('\u{1d42b}',  &['\u{72}']), ('\u{1d42c}',  &['\u{73}']), ('\u{1d42d}',  &['\u{74}']),
-        ('\u{1d42e}',  &['\u{75}']), ('\u{1d42f}',  &['\u{76}']), ('\u{1d430}',  &['\u{77}']),
-        ('\u{1d431}',  &['\u{78}']), ('\u{1d432}',  &['\u{79}']), ('\u{1d433}',  &['\u{7a}']),
-        ('\u{1d434}',  &['\u{41}']), ('\u{1d435}',  &['\u{42}']), ('\u{1d436}',  &['\u{43}']),
-        ('\u{1d437}',  &['\u{44}']), ('\u{1d438}',  &['\u{45}']), ('\u{1d439}',  &['\u{46}']),
-        ('\u{1d43a}',  &['\u{47}']), ('\u{1d43b}',  &['\u{48}']), ('\u{1d43c}',  &['\u{6c}']),
-        ('\u{1d43d}',  &['\u{4a}']), ('\u{1d43e}',  &['\u{4b}']), ('\u{1d43f}',  &['\u{4c}']),
-        ('\u{1d440}',  &['\u{4d}']), ('\u{1d441}',  &['\u{4e}']), ('\u{1d442}',  &['\u{4f}']),
-        ('\u{1d443}',  &['\u{50}']), ('\u{1d444}',  &['\u{51}']), ('\u{1d445}',  &['\u{52}']),
-        ('\u{1d446}',  &['\u{53}']), ('\u{1d447}',  &['\u{54}']), ('\u{1d448}',  &['\u{55}']),
-        ('\u{1d449}',  &['\u{56}']), ('\u{1d44a}',  &['\u{57}']), ('\u{1d44b}',  &['\u{58}']),
-        ('\u{1d44c}',  &['\u{59}']), ('\u{1d44d}',  &['\u{5a}']), ('\u{1d44e}',  &['\u{61}']),
-        ('\u{1d44f}',  &['\u{62}']), ('\u{1d450}',  &['\u{63}']), ('\u{1d451}',  &['\u{64}']),
-        ('\u{1d452}',  &['\u{65}']), ('\u{1d453}',  &['\u{66}']), ('\u{1d454}',  &['\u{67}']),
-        ('\u{1d456}',  &['\u{69}']), ('\u{1d457}',  &['\u{6a}']), ('\u{1d458}',  &['\u{6b}']),
-        ('\u{1d459}',  &['\u{6c}']), ('\u{1d45a}',  &['\u{72}',  '\u{6e}']), ('\u{1d45b}',
-         &['\u{6e}']), ('\u{1d45c}',  &['\u{6f}']), ('\u{1d45d}',  &['\u{70}']), ('\u{1d45e}',
-         &['\u{71}']), ('\u{1d45f}',  &['\u{72}']), ('\u{1d460}',  &['\u{73}']), ('\u{1d461}',
-         &['\u{74}']), ('\u{1d462}',  &['\u{75}']), ('\u{1d463}',  &['\u{76}']), ('\u{1d464}',
-         &['\u{77}']), ('\u{1d465}',  &['\u{78}']), ('\u{1d466}',  &['\u{79}']), ('\u{1d467}',
-         &['\u{7a}']), ('\u{1d468}',  &['\u{41}']), ('\u{1d469}',  &['\u{42}']), ('\u{1d46a}',
-         &['\u{43}']), ('\u{1d46b}',  &['\u{44}']), ('\u{1d46c}',  &['\u{45}']), ('\u{1d46d}',
-         &['\u{46}']), ('\u{1d46e}',  &['\u{47}']), ('\u{1d46f}',  &['\u{48}']), ('\u{1d470}',
-         &['\u{6c}']), ('\u{1d471}',  &['\u{4a}']), ('\u{1d472}',  &['\u{4b}']), ('\u{1d473}',
-         &['\u{4c}']), ('\u{1d474}',  &['\u{4d}']), ('\u{1d475}',  &['\u{4e}']), ('\u{1d476}',
-         &['\u{4f}']), ('\u{1d477}',  &['\u{50}']), ('\u{1d478}',  &['\u{51}']), ('\u{1d479}',
-         &['\u{52}']), ('\u{1d47a}',  &['\u{53}']), ('\u{1d47b}',  &['\u{54}']), ('\u{1d47c}',
-         &['\u{55}']), ('\u{1d47d}',  &['\u{56}']), ('\u{1d47e}',  &['\u{57}']), ('\u{1d47f}',
-         &['\u{58}']), ('\u{1d480}',  &['\u{59}']), ('\u{1d481}',  &['\u{5a}']), ('\u{1d482}',
-         &['\u{61}']), ('\u{1d483}',  &['\u{62}']), ('\u{1d484}',  &['\u{63}']), ('\u{1d485}',
-         &['\u{64}']), ('\u{1d486}',  &['\u{65}']), ('\u{1d487}',  &['\u{66}']), ('\u{1d488}',
-         &['\u{67}']), ('\u{1d489}',  &['\u{68}']), ('\u{1d48a}',  &['\u{69}']), ('\u{1d48b}',
-         &['\u{6a}']), ('\u{1d48c}',  &['\u{6b}']), ('\u{1d48d}',  &['\u{6c}']), ('\u{1d48e}',
-         &['\u{72}',  '\u{6e}']), ('\u{1d48f}',  &['\u{6e}']), ('\u{1d490}',  &['\u{6f}']),
-        ('\u{1d491}',  &['\u{70}']), ('\u{1d492}',  &['\u{71}']), ('\u{1d493}',  &['\u{72}']),
-        ('\u{1d494}',  &['\u{73}']), ('\u{1d495}',  &['\u{74}']), ('\u{1d496}',  &['\u{75}']),
-        ('\u{1d497}',  &['\u{76}']), ('\u{1d498}',  &['\u{77}']), ('\u{1d499}',  &['\u{78}']),
-        ('\u{1d49a}',  &['\u{79}']), ('\u{1d49b}',  &['\u{7a}']), ('\u{1d49c}',  &['\u{41}']),
-        ('\u{1d49e}',  &['\u{43}']), ('\u{1d49f}',  &['\u{44}']), ('\u{1d4a2}',  &['\u{47}']),
-        ('\u{1d4a5}',  &['\u{4a}']), ('\u{1d4a6}',  &['\u{4b}']), ('\u{1d4a9}',  &['\u{4e}']),
-        ('\u{1d4aa}',  &['\u{4f}']), ('\u{1d4ab}',  &['\u{50}']), ('\u{1d4ac}',  &['\u{51}']),
-        ('\u{1d4ae}',  &['\u{53}']), ('\u{1d4af}',  &['\u{54}']), ('\u{1d4b0}',  &['\u{55}']),
-        ('\u{1d4b1}',  &['\u{56}']), ('\u{1d4b2}',  &['\u{57}']), ('\u{1d4b3}',  &['\u{58}']),
-        ('\u{1d4b4}',  &['\u{59}']), ('\u{1d4b5}',  &['\u{5a}']), ('\u{1d4b6}',  &['\u{61}']),
-        ('\u{1d4b7}',  &['\u{62}']), ('\u{1d4b8}',  &['\u{63}']), ('\u{1d4b9}',  &['\u{64}']),
-        ('\u{1d4bb}',  &['\u{66}']), ('\u{1d4bd}',  &['\u{68}']), ('\u{1d4be}',  &['\u{69}']),
-        ('\u{1d4bf}',  &['\u{6a}']), ('\u{1d4c0}',  &['\u{6b}']), ('\u{1d4c1}',  &['\u{6c}']),
-        ('\u{1d4c2}',  &['\u{72}',  '\u{6e}']), ('\u{1d4c3}',  &['\u{6e}']), ('\u{1d4c5}',
-         &['\u{70}']), ('\u{1d4c6}',  &['\u{71}']), ('\u{1d4c7}',  &['\u{72}']), ('\u{1d4c8}',
-         &['\u{73}']), ('\u{1d4c9}',  &['\u{74}']), ('\u{1d4ca}',  &['\u{75}']), ('\u{1d4cb}',
-         &['\u{76}']), ('\u{1d4cc}',  &['\u{77}']), ('\u{1d4cd}',  &['\u{78}']), ('\u{1d4ce}',
-         &['\u{79}']), ('\u{1d4cf}',  &['\u{7a}']), ('\u{1d4d0}',  &['\u{41}']), ('\u{1d4d1}',
-         &['\u{42}']), ('\u{1d4d2}',  &['\u{43}']), ('\u{1d4d3}',  &['\u{44}']), ('\u{1d4d4}',
-         &['\u{45}']), ('\u{1d4d5}',  &['\u{46}']), ('\u{1d4d6}',  &['\u{47}']), ('\u{1d4d7}',
-         &['\u{48}']), ('\u{1d4d8}',  &['\u{6c}']), ('\u{1d4d9}',  &['\u{4a}']), ('\u{1d4da}',
-         &['\u{4b}']), ('\u{1d4db}',  &['\u{4c}']), ('\u{1d4dc}',  &['\u{4d}']), ('\u{1d4dd}',
-         &['\u{4e}']), ('\u{1d4de}',  &['\u{4f}']), ('\u{1d4df}',  &['\u{50}']), ('\u{1d4e0}',
-         &['\u{51}']), ('\u{1d4e1}',  &['\u{52}']), ('\u{1d4e2}',  &['\u{53}']), ('\u{1d4e3}',
-         &['\u{54}']), ('\u{1d4e4}',  &['\u{55}']), ('\u{1d4e5}',  &['\u{56}']), ('\u{1d4e6}',
-         &['\u{57}']), ('\u{1d4e7}',  &['\u{58}']), ('\u{1d4e8}',  &['\u{59}']), ('\u{1d4e9}',
-         &['\u{5a}']), ('\u{1d4ea}',  &['\u{61}']), ('\u{1d4eb}',  &['\u{62}']), ('\u{1d4ec}',
-         &['\u{63}']), ('\u{1d4ed}',  &['\u{64}']), ('\u{1d4ee}',  &['\u{65}']), ('\u{1d4ef}',
-         &['\u{66}']), ('\u{1d4f0}',  &['\u{67}']), ('\u{1d4f1}',  &['\u{68}']), ('\u{1d4f2}',
-         &['\u{69}']), ('\u{1d4f3}',  &['\u{6a}']), ('\u{1d4f4}',  &['\u{6b}']), ('\u{1d4f5}',
-         &['\u{6c}']), ('\u{1d4f6}',  &['\u{72}',  '\u{6e}']), ('\u{1d4f7}',  &['\u{6e}']),
-        ('\u{1d4f8}',  &['\u{6f}']), ('\u{1d4f9}',  &['\u{70}']), ('\u{1d4fa}',  &['\u{71}']),
-        ('\u{1d4fb}',  &['\u{72}']), ('\u{1d4fc}',  &['\u{73}']), ('\u{1d4fd}',  &['\u{74}']),
-        ('\u{1d4fe}',  &['\u{75}']), ('\u{1d4ff}',  &['\u{76}']), ('\u{1d500}',  &['\u{77}']),
-        ('\u{1d501}',  &['\u{78}']), ('\u{1d502}',  &['\u{79}']), ('\u{1d503}',  &['\u{7a}']),
-        ('\u{1d504}',  &['\u{41}']), ('\u{1d505}',  &['\u{42}']), ('\u{1d507}',  &['\u{44}']),
-        ('\u{1d508}',  &['\u{45}']), ('\u{1d509}',  &['\u{46}']), ('\u{1d50a}',  &['\u{47}']),
-        ('\u{1d50d}',  &['\u{4a}']), ('\u{1d50e}',  &['\u{4b}']), ('\u{1d50f}',  &['\u{4c}']),
-        ('\u{1d510}',  &['\u{4d}']), ('\u{1d511}',  &['\u{4e}']), ('\u{1d512}',  &['\u{4f}']),
-        ('\u{1d513}',  &['\u{50}']), ('\u{1d514}',  &['\u{51}']), ('\u{1d516}',  &['\u{53}']),
-        ('\u{1d517}',  &['\u{54}']), ('\u{1d518}',  &['\u{55}']), ('\u{1d519}',  &['\u{56}']),
-        ('\u{1d51a}',  &['\u{57}']), ('\u{1d51b}',  &['\u{58}']), ('\u{1d51c}',  &['\u{59}']),
-        ('\u{1d51e}',  &['\u{61}']), ('\u{1d51f}',  &['\u{62}']), ('\u{1d520}',  &['\u{63}']),
-        ('\u{1d521}',  &['\u{64}']), ('\u{1d522}',  &['\u{65}']), ('\u{1d523}',  &['\u{66}']),
-        ('\u{1d524}',  &['\u{67}']), ('\u{1d525}',  &['\u{68}']), ('\u{1d526}',  &['\u{69}']),
-        ('\u{1d527}',  &['\u{6a}']), ('\u{1d528}',  &['\u{6b}']), ('\u{1d529}',  &['\u{6c}']),
-        ('\u{1d52a}',  &['\u{72}',  '\u{6e}']), ('\u{1d52b}',  &['\u{6e}']), ('\u{1d52c}',
-         &['\u{6f}']), ('\u{1d52d}',  &['\u{70}']), ('\u{1d52e}',  &['\u{71}']), ('\u{1d52f}',
-         &['\u{72}']), ('\u{1d530}',  &['\u{73}']), ('\u{1d531}',  &['\u{74}']), ('\u{1d532}',
-         &['\u{75}']), ('\u{1d533}',  &['\u{76}']), ('\u{1d534}',  &['\u{77}']), ('\u{1d535}',
-         &['\u{78}']), ('\u{1d536}',  &['\u{79}']), ('\u{1d537}',  &['\u{7a}']), ('\u{1d538}',
-         &['\u{41}']), ('\u{1d539}',  &['\u{42}']), ('\u{1d53b}',  &['\u{44}']), ('\u{1d53c}',
-         &['\u{45}']), ('\u{1d53d}',  &['\u{46}']), ('\u{1d53e}',  &['\u{47}']), ('\u{1d540}',
-         &['\u{6c}']), ('\u{1d541}',  &['\u{4a}']), ('\u{1d542}',  &['\u{4b}']), ('\u{1d543}',
-         &['\u{4c}']), ('\u{1d544}',  &['\u{4d}']), ('\u{1d546}',  &['\u{4f}']), ('\u{1d54a}',
-         &['\u{53}']), ('\u{1d54b}',  &['\u{54}']), ('\u{1d54c}',  &['\u{55}']), ('\u{1d54d}',
-         &['\u{56}']), ('\u{1d54e}',  &['\u{57}']), ('\u{1d54f}',  &['\u{58}']), ('\u{1d550}',
-         &['\u{59}']), ('\u{1d552}',  &['\u{61}']), ('\u{1d553}',  &['\u{62}']), ('\u{1d554}',
-         &['\u{63}']), ('\u{1d555}',  &['\u{64}']), ('\u{1d556}',  &['\u{65}']), ('\u{1d557}',
-         &['\u{66}']), ('\u{1d558}',  &['\u{67}']), ('\u{1d559}',  &['\u{68}']), ('\u{1d55a}',
-         &['\u{69}']), ('\u{1d55b}',  &['\u{6a}']), ('\u{1d55c}',  &['\u{6b}']), ('\u{1d55d}',
-         &['\u{6c}']), ('\u{1d55e}',  &['\u{72}',  '\u{6e}']), ('\u{1d55f}',  &['\u{6e}']),
-        ('\u{1d560}',  &['\u{6f}']), ('\u{1d561}',  &['\u{70}']), ('\u{1d562}',  &['\u{71}']),
-        ('\u{1d563}',  &['\u{72}']), ('\u{1d564}',  &['\u{73}']), ('\u{1d565}',  &['\u{74}']),
-        ('\u{1d566}',  &['\u{75}']), ('\u{1d567}',  &['\u{76}']), ('\u{1d568}',  &['\u{77}']),
-        ('\u{1d569}',  &['\u{78}']), ('\u{1d56a}',  &['\u{79}']), ('\u{1d56b}',  &['\u{7a}']),
-        ('\u{1d56c}',  &['\u{41}']), ('\u{1d56d}',  &['\u{42}']), ('\u{1d56e}',  &['\u{43}']),
-        ('\u{1d56f}',  &['\u{44}']), ('\u{1d570}',  &['\u{45}']), ('\u{1d571}',  &['\u{46}']),
-        ('\u{1d572}',  &['\u{47}']), ('\u{1d573}',  &['\u{48}']), ('\u{1d574}',  &['\u{6c}']),
-        ('\u{1d575}',  &['\u{4a}']), ('\u{1d576}',  &['\u{4b}']), ('\u{1d577}',  &['\u{4c}']),
-        ('\u{1d578}',  &['\u{4d}']), ('\u{1d579}',  &['\u{4e}']), ('\u{1d57a}',  &['\u{4f}']),
-        ('\u{1d57b}',  &['\u{50}']), ('\u{1d57c}',  &['\u{51}']), ('\u{1d57d}',  &['\u{52}']),
-        ('\u{1d57e}',  &['\u{53}']), ('\u{1d57f}',  &['\u{54}']), ('\u{1d580}',  &['\u{55}']),
-        ('\u{1d581}',  &['\u{56}']), ('\u{1d582}',  &['\u{57}']), ('\u{1d583}',  &['\u{58}']),
-        ('\u{1d584}',  &['\u{59}']), ('\u{1d585}',  &['\u{5a}']), ('\u{1d586}',  &['\u{61}']),
-        ('\u{1d587}',  &['\u{62}']), ('\u{1d588}',  &['\u{63}']), ('\u{1d589}',  &['\u{64}']),
-        ('\u{1d58a}',  &['\u{65}']), ('\u{1d58b}',  &['\u{66}']), ('\u{1d58c}',  &['\u{67}']),
-        ('\u{1d58d}',  &['\u{68}']), ('\u{1d58e}',  &['\u{69}']), ('\u{1d58f}',  &['\u{6a}']),
-        ('\u{1d590}',  &['\u{6b}']), ('\u{1d591}',  &['\u{6c}']), ('\u{1d592}',  &['\u{72}',
-         '\u{6e}']), ('\u{1d593}',  &['\u{6e}']), ('\u{1d594}',  &['\u{6f}']), ('\u{1d595}',
-         &['\u{70}']), ('\u{1d596}',  &['\u{71}']), ('\u{1d597}',  &['\u{72}']), ('\u{1d598}',
-         &['\u{73}']), ('\u{1d599}',  &['\u{74}']), ('\u{1d59a}',  &['\u{75}']), ('\u{1d59b}',
-         &['\u{76}']), ('\u{1d59c}',  &['\u{77}']), ('\u{1d59d}',  &['\u{78}']), ('\u{1d59e}',
-         &['\u{79}']), ('\u{1d59f}',  &['\u{7a}']), ('\u{1d5a0}',  &['\u{41}']), ('\u{1d5a1}',
-         &['\u{42}']), ('\u{1d5a2}',  &['\u{43}']), ('\u{1d5a3}',  &['\u{44}']), ('\u{1d5a4}',
-         &['\u{45}']), ('\u{1d5a5}',  &['\u{46}']), ('\u{1d5a6}',  &['\u{47}']), ('\u{1d5a7}',
-         &['\u{48}']), ('\u{1d5a8}',  &['\u{6c}']), ('\u{1d5a9}',  &['\u{4a}']), ('\u{1d5aa}',
-         &['\u{4b}']), ('\u{1d5ab}',  &['\u{4c}']), ('\u{1d5ac}',  &['\u{4d}']), ('\u{1d5ad}',
-         &['\u{4e}']), ('\u{1d5ae}',  &['\u{4f}']), ('\u{1d5af}',  &['\u{50}']), ('\u{1d5b0}',
-         &['\u{51}']), ('\u{1d5b1}',  &['\u{52}']), ('\u{1d5b2}',  &['\u{53}']), ('\u{1d5b3}',
-         &['\u{54}']), ('\u{1d5b4}',  &['\u{55}']), ('\u{1d5b5}',  &['\u{56}']), ('\u{1d5b6}',
-         &['\u{57}']), ('\u{1d5b7}',  &['\u{58}']), ('\u{1d5b8}',  &['\u{59}']), ('\u{1d5b9}',
-         &['\u{5a}']), ('\u{1d5ba}',  &['\u{61}']), ('\u{1d5bb}',  &['\u{62}']), ('\u{1d5bc}',
-         &['\u{63}']), ('\u{1d5bd}',  &['\u{64}']), ('\u{1d5be}',  &['\u{65}']), ('\u{1d5bf}',
-         &['\u{66}']), ('\u{1d5c0}',  &['\u{67}']), ('\u{1d5c1}',  &['\u{68}']), ('\u{1d5c2}',
-         &['\u{69}']), ('\u{1d5c3}',  &['\u{6a}']), ('\u{1d5c4}',  &['\u{6b}']), ('\u{1d5c5}',
-         &['\u{6c}']), ('\u{1d5c6}',  &['\u{72}',  '\u{6e}']), ('\u{1d5c7}',  &['\u{6e}']),
-        ('\u{1d5c8}',  &['\u{6f}']), ('\u{1d5c9}',  &['\u{70}']), ('\u{1d5ca}',  &['\u{71}']),
-        ('\u{1d5cb}',  &['\u{72}']), ('\u{1d5cc}',  &['\u{73}']), ('\u{1d5cd}',  &['\u{74}']),
-        ('\u{1d5ce}',  &['\u{75}']), ('\u{1d5cf}',  &['\u{76}']), ('\u{1d5d0}',  &['\u{77}']),
-        ('\u{1d5d1}',  &['\u{78}']), ('\u{1d5d2}',  &['\u{79}']), ('\u{1d5d3}',  &['\u{7a}']),
-        ('\u{1d5d4}',  &['\u{41}']), ('\u{1d5d5}',  &['\u{42}']), ('\u{1d5d6}',  &['\u{43}']),
-        ('\u{1d5d7}',  &['\u{44}']), ('\u{1d5d8}',  &['\u{45}']), ('\u{1d5d9}',  &['\u{46}']),
-        ('\u{1d5da}',  &['\u{47}']), ('\u{1d5db}',  &['\u{48}']), ('\u{1d5dc}',  &['\u{6c}']),
-        ('\u{1d5dd}',  &['\u{4a}']), ('\u{1d5de}',  &['\u{4b}']), ('\u{1d5df}',  &['\u{4c}']),
-        ('\u{1d5e0}',  &['\u{4d}']), ('\u{1d5e1}',  &['\u{4e}']), ('\u{1d5e2}',  &['\u{4f}']),
-        ('\u{1d5e3}',  &['\u{50}']), ('\u{1d5e4}',  &['\u{51}']), ('\u{1d5e5}',  &['\u{52}']),
-        ('\u{1d5e6}',  &['\u{53}']), ('\u{1d5e7}',  &['\u{54}']), ('\u{1d5e8}',  &['\u{55}']),
-        ('\u{1d5e9}',  &['\u{56}']), ('\u{1d5ea}',  &['\u{57}']), ('\u{1d5eb}',  &['\u{58}']),
-        ('\u{1d5ec}',  &['\u{59}']), ('\u{1d5ed}',  &['\u{5a}']), ('\u{1d5ee}',  &['\u{61}']),
-        ('\u{1d5ef}',  &['\u{62}']), ('\u{1d5f0}',  &['\u{63}']), ('\u{1d5f1}',  &['\u{64}']),
-        ('\u{1d5f2}',  &['\u{65}']), ('\u{1d5f3}',  &['\u{66}']), ('\u{1d5f4}',  &['\u{67}']),
-        ('\u{1d5f5}',  &['\u{68}']), ('\u{1d5f6}',  &['\u{69}']), ('\u{1d5f7}',  &['\u{6a}']),
-        ('\u{1d5f8}',  &['\u{6b}']), ('\u{1d5f9}',  &['\u{6c}']), ('\u{1d5fa}',  &['\u{72}',
-         '\u{6e}']), ('\u{1d5fb}',  &['\u{6e}']), ('\u{1d5fc}',  &['\u{6f}']), ('\u{1d5fd}',
-         &['\u{70}']), ('\u{1d5fe}',  &['\u{71}']), ('\u{1d5ff}',  &['\u{72}']), ('\u{1d600}',
-         &['\u{73}']), ('\u{1d601}',  &['\u{74}']), ('\u{1d602}',  &['\u{75}']), ('\u{1d603}',
-         &['\u{76}']), ('\u{1d604}',  &['\u{77}']), ('\u{1d605}',  &['\u{78}']), ('\u{1d606}',
-         &['\u{79}']), ('\u{1d607}',  &['\u{7a}']), ('\u{1d608}',  &['\u{41}']), ('\u{1d609}',
-         &['\u{42}']), ('\u{1d60a}',  &['\u{43}']), ('\u{1d60b}',  &['\u{44}']), ('\u{1d60c}',
-         &['\u{45}']), ('\u{1d60d}',  &['\u{46}']), ('\u{1d60e}',  &['\u{47}']), ('\u{1d60f}',
-         &['\u{48}']), ('\u{1d610}',  &['\u{6c}']), ('\u{1d611}',  &['\u{4a}']), ('\u{1d612}',
-         &['\u{4b}']), ('\u{1d613}',  &['\u{4c}']), ('\u{1d614}',  &['\u{4d}']), ('\u{1d615}',
-         &['\u{4e}']), ('\u{1d616}',  &['\u{4f}']), ('\u{1d617}',  &['\u{50}']), ('\u{1d618}',
-         &['\u{51}']), ('\u{1d619}',  &['\u{52}']), ('\u{1d61a}',  &['\u{53}']), ('\u{1d61b}',
-         &['\u{54}']), ('\u{1d61c}',  &['\u{55}']), ('\u{1d61d}',  &['\u{56}']), ('\u{1d61e}',
-         &['\u{57}']), ('\u{1d61f}',  &['\u{58}']), ('\u{1d620}',  &['\u{59}']), ('\u{1d621}',
-         &['\u{5a}']), ('\u{1d622}',  &['\u{61}']), ('\u{1d623}',  &['\u{62}']), ('\u{1d624}',
-         &['\u{63}']), ('\u{1d625}',  &['\u{64}']), ('\u{1d626}',  &['\u{65}']), ('\u{1d627}',
-         &['\u{66}']), ('\u{1d628}',  &['\u{67}']), ('\u{1d629}',  &['\u{68}']), ('\u{1d62a}',
-         &['\u{69}']), ('\u{1d62b}',  &['\u{6a}']), ('\u{1d62c}',  &['\u{6b}']), ('\u{1d62d}',
-         &['\u{6c}']), ('\u{1d62e}',  &['\u{72}',  '\u{6e}']), ('\u{1d62f}',  &['\u{6e}']),
-        ('\u{1d630}',  &['\u{6f}']), ('\u{1d631}',  &['\u{70}']), ('\u{1d632}',  &['\u{71}']),
-        ('\u{1d633}',  &['\u{72}']), ('\u{1d634}',  &['\u{73}']), ('\u{1d635}',  &['\u{74}']),
-        ('\u{1d636}',  &['\u{75}']), ('\u{1d637}',  &['\u{76}']), ('\u{1d638}',  &['\u{77}']),
-        ('\u{1d639}',  &['\u{78}']), ('\u{1d63a}',  &['\u{79}']), ('\u{1d63b}',  &['\u{7a}']),
-        ('\u{1d63c}',  &['\u{41}']), ('\u{1d63d}',  &['\u{42}']), ('\u{1d63e}',  &['\u{43}']),
-        ('\u{1d63f}',  &['\u{44}']), ('\u{1d640}',  &['\u{45}']), ('\u{1d641}',  &['\u{46}']),
-        ('\u{1d642}',  &['\u{47}']), ('\u{1d643}',  &['\u{48}']), ('\u{1d644}',  &['\u{6c}']),
-        ('\u{1d645}',  &['\u{4a}']), ('\u{1d646}',  &['\u{4b}']), ('\u{1d647}',  &['\u{4c}']),
-        ('\u{1d648}',  &['\u{4d}']), ('\u{1d649}',  &['\u{4e}']), ('\u{1d64a}',  &['\u{4f}']),
-        ('\u{1d64b}',  &['\u{50}']), ('\u{1d64c}',  &['\u{51}']), ('\u{1d64d}',  &['\u{52}']),
-        ('\u{1d64e}',  &['\u{53}']), ('\u{1d64f}',  &['\u{54}']), ('\u{1d650}',  &['\u{55}']),
-        ('\u{1d651}',  &['\u{56}']), ('\u{1d652}',  &['\u{57}']), ('\u{1d653}',  &['\u{58}']),
-        ('\u{1d654}',  &['\u{59}']), ('\u{1d655}',  &['\u{5a}']), ('\u{1d656}',  &['\u{61}']),
-        ('\u{1d657}',  &['\u{62}']), ('\u{1d658}',  &['\u{63}']), ('\u{1d659}',  &['\u{64}']),
-        ('\u{1d65a}',  &['\u{65}']), ('\u{1d65b}',  &['\u{66}']), ('\u{1d65c}',  &['\u{67}']),
-        ('\u{1d65d}',  &['\u{68}']), ('\u{1d65e}',  &['\u{69}']), ('\u{1d65f}',  &['\u{6a}']),
-        ('\u{1d660}',  &['\u{6b}']), ('\u{1d661}',  &['\u{6c}']), ('\u{1d662}',  &['\u{72}',
-         '\u{6e}']), ('\u{1d663}',  &['\u{6e}']), ('\u{1d664}',  &['\u{6f}']), ('\u{1d665}',
-         &['\u{70}']), ('\u{1d666}',  &['\u{71}']), ('\u{1d667}',  &['\u{72}']), ('\u{1d668}',
-         &['\u{73}']), ('\u{1d669}',  &['\u{74}']), ('\u{1d66a}',  &['\u{75}']), ('\u{1d66b}',
-         &['\u{76}']), ('\u{1d66c}',  &['\u{77}']), ('\u{1d66d}',  &['\u{78}']), ('\u{1d66e}',
-         &['\u{79}']), ('\u{1d66f}',  &['\u{7a}']), ('\u{1d670}',  &['\u{41}']), ('\u{1d671}',
-         &['\u{42}']), ('\u{1d672}',  &['\u{43}']), ('\u{1d673}',  &['\u{44}']), ('\u{1d674}',
-         &['\u{45}']), ('\u{1d675}',  &['\u{46}']), ('\u{1d676}',  &['\u{47}']), ('\u{1d677}',
-         &['\u{48}']), ('\u{1d678}',  &['\u{6c}']), ('\u{1d679}',  &['\u{4a}']), ('\u{1d67a}',
-         &['\u{4b}']), ('\u{1d67b}',  &['\u{4c}']), ('\u{1d67c}',  &['\u{4d}']), ('\u{1d67d}',
-         &['\u{4e}']), ('\u{1d67e}',  &['\u{4f}']), ('\u{1d67f}',  &['\u{50}']), ('\u{1d680}',
-         &['\u{51}']), ('\u{1d681}',  &['\u{52}']), ('\u{1d682}',  &['\u{53}']), ('\u{1d683}',
-         &['\u{54}']), ('\u{1d684}',  &['\u{55}']), ('\u{1d685}',  &['\u{56}']), ('\u{1d686}',
-         &['\u{57}']), ('\u{1d687}',  &['\u{58}']), ('\u{1d688}',  &['\u{59}']), ('\u{1d689}',
-         &['\u{5a}']), ('\u{1d68a}',  &['\u{61}']), ('\u{1d68b}',  &['\u{62}']), ('\u{1d68c}',
-         &['\u{63}']), ('\u{1d68d}',  &['\u{64}']), ('\u{1d68e}',  &['\u{65}']), ('\u{1d68f}',
-         &['\u{66}']), ('\u{1d690}',  &['\u{67}']), ('\u{1d691}',  &['\u{68}']), ('\u{1d692}',
-         &['\u{69}']), ('\u{1d693}',  &['\u{6a}']), ('\u{1d694}',  &['\u{6b}']), ('\u{1d695}',
-         &['\u{6c}']), ('\u{1d696}',  &['\u{72}',  '\u{6e}']), ('\u{1d697}',  &['\u{6e}']),
-        ('\u{1d698}',  &['\u{6f}']), ('\u{1d699}',  &['\u{70}']), ('\u{1d69a}',  &['\u{71}']),
-        ('\u{1d69b}',  &['\u{72}']), ('\u{1d69c}',  &['\u{73}']), ('\u{1d69d}',  &['\u{74}']),
-        ('\u{1d69e}',  &['\u{75}']), ('\u{1d69f}',  &['\u{76}']), ('\u{1d6a0}',  &['\u{77}']),
-        ('\u{1d6a1}',  &['\u{78}']), ('\u{1d6a2}',  &['\u{79}']), ('\u{1d6a3}',  &['\u{7a}']),
-        ('\u{1d6a4}',  &['\u{69}']), ('\u{1d6a5}',  &['\u{237}']), ('\u{1d6a8}',  &['\u{41}']),
-        ('\u{1d6a9}',  &['\u{42}']), ('\u{1d6aa}',  &['\u{393}']), ('\u{1d6ab}',  &['\u{394}']),
-        ('\u{1d6ac}',  &['\u{45}']), ('\u{1d6ad}',  &['\u{5a}']), ('\u{1d6ae}',  &['\u{48}']),
-        ('\u{1d6af}',  &['\u{4f}',  '\u{335}']), ('\u{1d6b0}',  &['\u{6c}']), ('\u{1d6b1}',
-         &['\u{4b}']), ('\u{1d6b2}',  &['\u{245}']), ('\u{1d6b3}',  &['\u{4d}']), ('\u{1d6b4}',
-         &['\u{4e}']), ('\u{1d6b5}',  &['\u{39e}']), ('\u{1d6b6}',  &['\u{4f}']), ('\u{1d6b7}',
-         &['\u{3a0}']), ('\u{1d6b8}',  &['\u{50}']), ('\u{1d6b9}',  &['\u{4f}',  '\u{335}']),
-        ('\u{1d6ba}',  &['\u{1a9}']), ('\u{1d6bb}',  &['\u{54}']), ('\u{1d6bc}',  &['\u{59}']),
-        ('\u{1d6bd}',  &['\u{3a6}']), ('\u{1d6be}',  &['\u{58}']), ('\u{1d6bf}',  &['\u{3a8}']),
-        ('\u{1d6c0}',  &['\u{3a9}']), ('\u{1d6c1}',  &['\u{2207}']), ('\u{1d6c2}',  &['\u{61}']),
-        ('\u{1d6c3}',  &['\u{df}']), ('\u{1d6c4}',  &['\u{79}']), ('\u{1d6c5}',  &['\u{1e9f}']),
-        ('\u{1d6c6}',  &['\u{a793}']), ('\u{1d6c7}',  &['\u{3b6}']), ('\u{1d6c8}',  &['\u{6e}',
-         '\u{329}']), ('\u{1d6c9}',  &['\u{4f}',  '\u{335}']), ('\u{1d6ca}',  &['\u{69}']),
-        ('\u{1d6cb}',  &['\u{138}']), ('\u{1d6cc}',  &['\u{3bb}']), ('\u{1d6cd}',  &['\u{3bc}']),
-        ('\u{1d6ce}',  &['\u{76}']), ('\u{1d6cf}',  &['\u{3be}']), ('\u{1d6d0}',  &['\u{6f}']),
-        ('\u{1d6d1}',  &['\u{3c0}']), ('\u{1d6d2}',  &['\u{70}']), ('\u{1d6d3}',  &['\u{3c2}']),
-        ('\u{1d6d4}',  &['\u{6f}']), ('\u{1d6d5}',  &['\u{1d1b}']), ('\u{1d6d6}',  &['\u{75}']),
-        ('\u{1d6d7}',  &['\u{278}']), ('\u{1d6d8}',  &['\u{3c7}']), ('\u{1d6d9}',  &['\u{3c8}']),
-        ('\u{1d6da}',  &['\u{3c9}']), ('\u{1d6db}',  &['\u{2202}']), ('\u{1d6dc}',  &['\u{a793}']),
-        ('\u{1d6dd}',  &['\u{4f}',  '\u{335}']), ('\u{1d6de}',  &['\u{138}']), ('\u{1d6df}',
-         &['\u{278}']), ('\u{1d6e0}',  &['\u{70}']), ('\u{1d6e1}',  &['\u{3c0}']), ('\u{1d6e2}',
-         &['\u{41}']), ('\u{1d6e3}',  &['\u{42}']), ('\u{1d6e4}',  &['\u{393}']), ('\u{1d6e5}',
-         &['\u{394}']), ('\u{1d6e6}',  &['\u{45}']), ('\u{1d6e7}',  &['\u{5a}']), ('\u{1d6e8}',
-         &['\u{48}']), ('\u{1d6e9}',  &['\u{4f}',  '\u{335}']), ('\u{1d6ea}',  &['\u{6c}']),
-        ('\u{1d6eb}',  &['\u{4b}']), ('\u{1d6ec}',  &['\u{245}']), ('\u{1d6ed}',  &['\u{4d}']),
-        ('\u{1d6ee}',  &['\u{4e}']), ('\u{1d6ef}',  &['\u{39e}']), ('\u{1d6f0}',  &['\u{4f}']),
-        ('\u{1d6f1}',  &['\u{3a0}']), ('\u{1d6f2}',  &['\u{50}']), ('\u{1d6f3}',  &['\u{4f}',
-         '\u{335}']), ('\u{1d6f4}',  &['\u{1a9}']), ('\u{1d6f5}',  &['\u{54}']), ('\u{1d6f6}',
-         &['\u{59}']), ('\u{1d6f7}',  &['\u{3a6}']), ('\u{1d6f8}',  &['\u{58}']), ('\u{1d6f9}',
-         &['\u{3a8}']), ('\u{1d6fa}',  &['\u{3a9}']), ('\u{1d6fb}',  &['\u{2207}']), ('\u{1d6fc}',
-         &['\u{61}']), ('\u{1d6fd}',  &['\u{df}']), ('\u{1d6fe}',  &['\u{79}']), ('\u{1d6ff}',
-         &['\u{1e9f}']), ('\u{1d700}',  &['\u{a793}']), ('\u{1d701}',  &['\u{3b6}']), ('\u{1d702}',
-         &['\u{6e}',  '\u{329}']), ('\u{1d703}',  &['\u{4f}',  '\u{335}']), ('\u{1d704}',
-         &['\u{69}']), ('\u{1d705}',  &['\u{138}']), ('\u{1d706}',  &['\u{3bb}']), ('\u{1d707}',
-         &['\u{3bc}']), ('\u{1d708}',  &['\u{76}']), ('\u{1d709}',  &['\u{3be}']), ('\u{1d70a}',
-         &['\u{6f}']), ('\u{1d70b}',  &['\u{3c0}']), ('\u{1d70c}',  &['\u{70}']), ('\u{1d70d}',
-         &['\u{3c2}']), ('\u{1d70e}',  &['\u{6f}']), ('\u{1d70f}',  &['\u{1d1b}']), ('\u{1d710}',
-         &['\u{75}']), ('\u{1d711}',  &['\u{278}']), ('\u{1d712}',  &['\u{3c7}']), ('\u{1d713}',
-         &['\u{3c8}']), ('\u{1d714}',  &['\u{3c9}']), ('\u{1d715}',  &['\u{2202}']), ('\u{1d716}',
-         &['\u{a793}']), ('\u{1d717}',  &['\u{4f}',  '\u{335}']), ('\u{1d718}',  &['\u{138}']),
-        ('\u{1d719}',  &['\u{278}']), ('\u{1d71a}',  &['\u{70}']), ('\u{1d71b}',  &['\u{3c0}']),
-        ('\u{1d71c}',  &['\u{41}']), ('\u{1d71d}',  &['\u{42}']), ('\u{1d71e}',  &['\u{393}']),
-        ('\u{1d71f}',  &['\u{394}']), ('\u{1d720}',  &['\u{45}']), ('\u{1d721}',  &['\u{5a}']),
-        ('\u{1d722}',  &['\u{48}']), ('\u{1d723}',  &['\u{4f}',  '\u{335}']), ('\u{1d724}',
-         &['\u{6c}']), ('\u{1d725}',  &['\u{4b}']), ('\u{1d726}',  &['\u{245}']), ('\u{1d727}',
-         &['\u{4d}']), ('\u{1d728}',  &['\u{4e}']), ('\u{1d729}',  &['\u{39e}']), ('\u{1d72a}',
-         &['\u{4f}']), ('\u{1d72b}',  &['\u{3a0}']), ('\u{1d72c}',  &['\u{50}']), ('\u{1d72d}',
-         &['\u{4f}',  '\u{335}']), ('\u{1d72e}',  &['\u{1a9}']), ('\u{1d72f}',  &['\u{54}']),
-        ('\u{1d730}',  &['\u{59}']), ('\u{1d731}',  &['\u{3a6}']), ('\u{1d732}',  &['\u{58}']),
-        ('\u{1d733}',  &['\u{3a8}']), ('\u{1d734}',  &['\u{3a9}']), ('\u{1d735}',  &['\u{2207}']),
-        ('\u{1d736}',  &['\u{61}']), ('\u{1d737}',  &['\u{df}']), ('\u{1d738}',  &['\u{79}']),
-        ('\u{1d739}',  &['\u{1e9f}']), ('\u{1d73a}',  &['\u{a793}']), ('\u{1d73b}',  &['\u{3b6}']),
-        ('\u{1d73c}',  &['\u{6e}',  '\u{329}']), ('\u{1d73d}',  &['\u{4f}',  '\u{335}']),
-        ('\u{1d73e}',  &['\u{69}']), ('\u{1d73f}',  &['\u{138}']), ('\u{1d740}',  &['\u{3bb}']),
-        ('\u{1d741}',  &['\u{3bc}']), ('\u{1d742}',  &['\u{76}']), ('\u{1d743}',  &['\u{3be}']),
-        ('\u{1d744}',  &['\u{6f}']), ('\u{1d745}',  &['\u{3c0}']), ('\u{1d746}',  &['\u{70}']),
-        ('\u{1d747}',  &['\u{3c2}']), ('\u{1d748}',  &['\u{6f}']), ('\u{1d749}',  &['\u{1d1b}']),
-        ('\u{1d74a}',  &['\u{75}']), ('\u{1d74b}',  &['\u{278}']), ('\u{1d74c}',  &['\u{3c7}']),
-        ('\u{1d74d}',  &['\u{3c8}']), ('\u{1d74e}',  &['\u{3c9}']), ('\u{1d74f}',  &['\u{2202}']),
-        ('\u{1d750}',  &['\u{a793}']), ('\u{1d751}',  &['\u{4f}',  '\u{335}']), ('\u{1d752}',
-         &['\u{138}']), ('\u{1d753}',  &['\u{278}']), ('\u{1d754}',  &['\u{70}']), ('\u{1d755}',
-         &['\u{3c0}']), ('\u{1d756}',  &['\u{41}']), ('\u{1d757}',  &['\u{42}']), ('\u{1d758}',
-         &['\u{393}']), ('\u{1d759}',  &['\u{394}']), ('\u{1d75a}',  &['\u{45}']), ('\u{1d75b}',
-         &['\u{5a}']), ('\u{1d75c}',  &['\u{48}']), ('\u{1d75d}',  &['\u{4f}',  '\u{335}']),
-        ('\u{1d75e}',  &['\u{6c}']), ('\u{1d75f}',  &['\u{4b}']), ('\u{1d760}',  &['\u{245}']),
-        ('\u{1d761}',  &['\u{4d}']), ('\u{1d762}',  &['\u{4e}']), ('\u{1d763}',  &['\u{39e}']),
-        ('\u{1d764}',  &['\u{4f}']), ('\u{1d765}',  &['\u{3a0}']), ('\u{1d766}',  &['\u{50}']),
-        ('\u{1d767}',  &['\u{4f}',  '\u{335}']), ('\u{1d768}',  &['\u{1a9}']), ('\u{1d769}',
-         &['\u{54}']), ('\u{1d76a}',  &['\u{59}']), ('\u{1d76b}',  &['\u{3a6}']), ('\u{1d76c}',
-         &['\u{58}']), ('\u{1d76d}',  &['\u{3a8}']), ('\u{1d76e}',  &['\u{3a9}']), ('\u{1d76f}',
-         &['\u{2207}']), ('\u{1d770}',  &['\u{61}']), ('\u{1d771}',  &['\u{df}']), ('\u{1d772}',
-         &['\u{79}']), ('\u{1d773}',  &['\u{1e9f}']), ('\u{1d774}',  &['\u{a793}']), ('\u{1d775}',
-         &['\u{3b6}']), ('\u{1d776}',  &['\u{6e}',  '\u{329}']), ('\u{1d777}',  &['\u{4f}',
-         '\u{335}']), ('\u{1d778}',  &['\u{69}']), ('\u{1d779}',  &['\u{138}']), ('\u{1d77a}',
-         &['\u{3bb}']), ('\u{1d77b}',  &['\u{3bc}']), ('\u{1d77c}',  &['\u{76}']), ('\u{1d77d}',
-         &['\u{3be}']), ('\u{1d77e}',  &['\u{6f}']), ('\u{1d77f}',  &['\u{3c0}']), ('\u{1d780}',
-         &['\u{70}']), ('\u{1d781}',  &['\u{3c2}']), ('\u{1d782}',  &['\u{6f}']), ('\u{1d783}',
-         &['\u{1d1b}']), ('\u{1d784}',  &['\u{75}']), ('\u{1d785}',  &['\u{278}']), ('\u{1d786}',
-         &['\u{3c7}']), ('\u{1d787}',  &['\u{3c8}']), ('\u{1d788}',  &['\u{3c9}']), ('\u{1d789}',
-         &['\u{2202}']), ('\u{1d78a}',  &['\u{a793}']), ('\u{1d78b}',  &['\u{4f}',  '\u{335}']),
-        ('\u{1d78c}',  &['\u{138}']), ('\u{1d78d}',  &['\u{278}']), ('\u{1d78e}',  &['\u{70}']),
-        ('\u{1d78f}',  &['\u{3c0}']), ('\u{1d790}',  &['\u{41}']), ('\u{1d791}',  &['\u{42}']),
-        ('\u{1d792}',  &['\u{393}']), ('\u{1d793}',  &['\u{394}']), ('\u{1d794}',  &['\u{45}']),
-        ('\u{1d795}',  &['\u{5a}']), ('\u{1d796}',  &['\u{48}']), ('\u{1d797}',  &['\u{4f}',
-         '\u{335}']), ('\u{1d798}',  &['\u{6c}']), ('\u{1d799}',  &['\u{4b}']), ('\u{1d79a}',
-         &['\u{245}']), ('\u{1d79b}',  &['\u{4d}']), ('\u{1d79c}',  &['\u{4e}']), ('\u{1d79d}',
-         &['\u{39e}']), ('\u{1d79e}',  &['\u{4f}']), ('\u{1d79f}',  &['\u{3a0}']), ('\u{1d7a0}',
-         &['\u{50}']), ('\u{1d7a1}',  &['\u{4f}',  '\u{335}']), ('\u{1d7a2}',  &['\u{1a9}']),
-        ('\u{1d7a3}',  &['\u{54}']), ('\u{1d7a4}',  &['\u{59}']), ('\u{1d7a5}',  &['\u{3a6}']),
-        ('\u{1d7a6}',  &['\u{58}']), ('\u{1d7a7}',  &['\u{3a8}']), ('\u{1d7a8}',  &['\u{3a9}']),
-        ('\u{1d7a9}',  &['\u{2207}']), ('\u{1d7aa}',  &['\u{61}']), ('\u{1d7ab}',  &['\u{df}']),
-        ('\u{1d7ac}',  &['\u{79}']), ('\u{1d7ad}',  &['\u{1e9f}']), ('\u{1d7ae}',  &['\u{a793}']),
-        ('\u{1d7af}',  &['\u{3b6}']), ('\u{1d7b0}',  &['\u{6e}',  '\u{329}']), ('\u{1d7b1}',
-         &['\u{4f}',  '\u{335}']), ('\u{1d7b2}',  &['\u{69}']), ('\u{1d7b3}',  &['\u{138}']),
-        ('\u{1d7b4}',  &['\u{3bb}']), ('\u{1d7b5}',  &['\u{3bc}']), ('\u{1d7b6}',  &['\u{76}']),
-        ('\u{1d7b7}',  &['\u{3be}']), ('\u{1d7b8}',  &['\u{6f}']), ('\u{1d7b9}',  &['\u{3c0}']),
-        ('\u{1d7ba}',  &['\u{70}']), ('\u{1d7bb}',  &['\u{3c2}']), ('\u{1d7bc}',  &['\u{6f}']),
-        ('\u{1d7bd}',  &['\u{1d1b}']), ('\u{1d7be}',  &['\u{75}']), ('\u{1d7bf}',  &['\u{278}']),
-        ('\u{1d7c0}',  &['\u{3c7}']), ('\u{1d7c1}',  &['\u{3c8}']), ('\u{1d7c2}',  &['\u{3c9}']),
-        ('\u{1d7c3}',  &['\u{2202}']), ('\u{1d7c4}',  &['\u{a793}']), ('\u{1d7c5}',  &['\u{4f}',
-         '\u{335}']), ('\u{1d7c6}',  &['\u{138}']), ('\u{1d7c7}',  &['\u{278}']), ('\u{1d7c8}',
-         &['\u{70}']), ('\u{1d7c9}',  &['\u{3c0}']), ('\u{1d7ca}',  &['\u{46}']), ('\u{1d7cb}',
-         &['\u{3dd}']), ('\u{1d7ce}',  &['\u{4f}']), ('\u{1d7cf}',  &['\u{6c}']), ('\u{1d7d0}',
-         &['\u{32}']), ('\u{1d7d1}',  &['\u{33}']), ('\u{1d7d2}',  &['\u{34}']), ('\u{1d7d3}',
-         &['\u{35}']), ('\u{1d7d4}',  &['\u{36}']), ('\u{1d7d5}',  &['\u{37}']), ('\u{1d7d6}',
-         &['\u{38}']), ('\u{1d7d7}',  &['\u{39}']), ('\u{1d7d8}',  &['\u{4f}']), ('\u{1d7d9}',
-         &['\u{6c}']), ('\u{1d7da}',  &['\u{32}']), ('\u{1d7db}',  &['\u{33}']), ('\u{1d7dc}',
-         &['\u{34}']), ('\u{1d7dd}',  &['\u{35}']), ('\u{1d7de}',  &['\u{36}']), ('\u{1d7df}',
-         &['\u{37}']), ('\u{1d7e0}',  &['\u{38}']), ('\u{1d7e1}',  &['\u{39}']), ('\u{1d7e2}',
-         &['\u{4f}']), ('\u{1d7e3}',  &['\u{6c}']), ('\u{1d7e4}',  &['\u{32}']), ('\u{1d7e5}',
-         &['\u{33}']), ('\u{1d7e6}',  &['\u{34}']), ('\u{1d7e7}',  &['\u{35}']), ('\u{1d7e8}',
-         &['\u{36}']), ('\u{1d7e9}',  &['\u{37}']), ('\u{1d7ea}',  &['\u{38}']), ('\u{1d7eb}',
-         &['\u{39}']), ('\u{1d7ec}',  &['\u{4f}']), ('\u{1d7ed}',  &['\u{6c}']), ('\u{1d7ee}',
-         &['\u{32}']), ('\u{1d7ef}',  &['\u{33}']), ('\u{1d7f0}',  &['\u{34}']), ('\u{1d7f1}',
-         &['\u{35}']), ('\u{1d7f2}',  &['\u{36}']), ('\u{1d7f3}',  &['\u{37}']), ('\u{1d7f4}',
-         &['\u{38}']), ('\u{1d7f5}',  &['\u{39}']), ('\u{1d7f6}',  &['\u{4f}']), ('\u{1d7f7}',
-         &['\u{6c}']), ('\u{1d7f8}',  &['\u{32}']), ('\u{1d7f9}',  &['\u{33}']), ('\u{1d7fa}',
-         &['\u{34}']), ('\u{1d7fb}',  &['\u{35}']), ('\u{1d7fc}',  &['\u{36}']), ('\u{1d7fd}',
-         &['\u{37}']), ('\u{1d7fe}',  &['\u{38}']), ('\u{1d7ff}',  &['\u{39}']), ('\u{1e6e9}',
-         &['\u{2b}']), ('\u{1e6ee}',  &['\u{1ac8}']), ('\u{1e8c7}',  &['\u{6c}']), ('\u{1e8c8}',
-         &['\u{2220}']), ('\u{1e8c9}',  &['\u{663}']), ('\u{1e8cb}',  &['\u{38}']), ('\u{1e8cc}',
-         &['\u{2202}']), ('\u{1e8cd}',  &['\u{2202}',  '\u{335}']), ('\u{1ee00}',  &['\u{6c}']),
-        ('\u{1ee01}',  &['\u{628}']), ('\u{1ee02}',  &['\u{62c}']), ('\u{1ee03}',  &['\u{62f}']),
-        ('\u{1ee05}',  &['\u{648}']), ('\u{1ee06}',  &['\u{632}']), ('\u{1ee07}',  &['\u{62d}']),
-        ('\u{1ee08}',  &['\u{637}']), ('\u{1ee09}',  &['\u{649}']), ('\u{1ee0a}',  &['\u{643}']),
-        ('\u{1ee0b}',  &['\u{644}']), ('\u{1ee0c}',  &['\u{645}']), ('\u{1ee0d}',  &['\u{646}']),
-        ('\u{1ee0e}',  &['\u{633}']), ('\u{1ee0f}',  &['\u{639}']), ('\u{1ee10}',  &['\u{641}']),
-        ('\u{1ee11}',  &['\u{635}']), ('\u{1ee12}',  &['\u{642}']), ('\u{1ee13}',  &['\u{631}']),
-        ('\u{1ee14}',  &['\u{633}',  '\u{6db}']), ('\u{1ee15}',  &['\u{62a}']), ('\u{1ee16}',
-         &['\u{649}',  '\u{6db}']), ('\u{1ee17}',  &['\u{62e}']), ('\u{1ee18}',  &['\u{630}']),
-        ('\u{1ee19}',  &['\u{636}']), ('\u{1ee1a}',  &['\u{638}']), ('\u{1ee1b}',  &['\u{63a}']),
-        ('\u{1ee1c}',  &['\u{649}']), ('\u{1ee1d}',  &['\u{649}']), ('\u{1ee1e}',  &['\u{6a1}']),
-        ('\u{1ee1f}',  &['\u{6a1}']), ('\u{1ee21}',  &['\u{628}']), ('\u{1ee22}',  &['\u{62c}']),
-        ('\u{1ee24}',  &['\u{6f}']), ('\u{1ee27}',  &['\u{62d}']), ('\u{1ee29}',  &['\u{649}']),
-        ('\u{1ee2a}',  &['\u{643}']), ('\u{1ee2b}',  &['\u{644}']), ('\u{1ee2c}',  &['\u{645}']),
-        ('\u{1ee2d}',  &['\u{646}']), ('\u{1ee2e}',  &['\u{633}']), ('\u{1ee2f}',  &['\u{639}']),
-        ('\u{1ee30}',  &['\u{641}']), ('\u{1ee31}',  &['\u{635}']), ('\u{1ee32}',  &['\u{642}']),
-        ('\u{1ee34}',  &['\u{633}',  '\u{6db}']), ('\u{1ee35}',  &['\u{62a}']), ('\u{1ee36}',
-         &['\u{649}',  '\u{6db}']), ('\u{1ee37}',  &['\u{62e}']), ('\u{1ee39}',  &['\u{636}']),
-        ('\u{1ee3b}',  &['\u{63a}']), ('\u{1ee42}',  &['\u{62c}']), ('\u{1ee47}',  &['\u{62d}']),
-        ('\u{1ee49}',  &['\u{649}']), ('\u{1ee4b}',  &['\u{644}']), ('\u{1ee4d}',  &['\u{646}']),
-        ('\u{1ee4e}',  &['\u{633}']), ('\u{1ee4f}',  &['\u{639}']), ('\u{1ee51}',  &['\u{635}']),
-        ('\u{1ee52}',  &['\u{642}']), ('\u{1ee54}',  &['\u{633}',  '\u{6db}']), ('\u{1ee57}',
-         &['\u{62e}']), ('\u{1ee59}',  &['\u{636}']), ('\u{1ee5b}',  &['\u{63a}']), ('\u{1ee5d}',
-         &['\u{649}']), ('\u{1ee5f}',  &['\u{6a1}']), ('\u{1ee61}',  &['\u{628}']), ('\u{1ee62}',
-         &['\u{62c}']), ('\u{1ee64}',  &['\u{6f}']), ('\u{1ee67}',  &['\u{62d}']), ('\u{1ee68}',
-         &['\u{637}']), ('\u{1ee69}',  &['\u{649}']), ('\u{1ee6a}',  &['\u{643}']), ('\u{1ee6c}',
-         &['\u{645}']), ('\u{1ee6d}',  &['\u{646}']), ('\u{1ee6e}',  &['\u{633}']), ('\u{1ee6f}',
-         &['\u{639}']), ('\u{1ee70}',  &['\u{641}']), ('\u{1ee71}',  &['\u{635}']), ('\u{1ee72}',
-         &['\u{642}']), ('\u{1ee74}',  &['\u{633}',  '\u{6db}']), ('\u{1ee75}',  &['\u{62a}']),
-        ('\u{1ee76}',  &['\u{649}',  '\u{6db}']), ('\u{1ee77}',  &['\u{62e}']), ('\u{1ee79}',
-         &['\u{636}']), ('\u{1ee7a}',  &['\u{638}']), ('\u{1ee7b}',  &['\u{63a}']), ('\u{1ee7c}',
-         &['\u{649}']), ('\u{1ee7e}',  &['\u{6a1}']), ('\u{1ee80}',  &['\u{6c}']), ('\u{1ee81}',
-         &['\u{628}']), ('\u{1ee82}',  &['\u{62c}']), ('\u{1ee83}',  &['\u{62f}']), ('\u{1ee84}',
-         &['\u{6f}']), ('\u{1ee85}',  &['\u{648}']), ('\u{1ee86}',  &['\u{632}']), ('\u{1ee87}',
-         &['\u{62d}']), ('\u{1ee88}',  &['\u{637}']), ('\u{1ee89}',  &['\u{649}']), ('\u{1ee8b}',
-         &['\u{644}']), ('\u{1ee8c}',  &['\u{645}']), ('\u{1ee8d}',  &['\u{646}']), ('\u{1ee8e}',
-         &['\u{633}']), ('\u{1ee8f}',  &['\u{639}']), ('\u{1ee90}',  &['\u{641}']), ('\u{1ee91}',
-         &['\u{635}']), ('\u{1ee92}',  &['\u{642}']), ('\u{1ee93}',  &['\u{631}']), ('\u{1ee94}',
-         &['\u{633}',  '\u{6db}']), ('\u{1ee95}',  &['\u{62a}']), ('\u{1ee96}',  &['\u{649}',
-         '\u{6db}']), ('\u{1ee97}',  &['\u{62e}']), ('\u{1ee98}',  &['\u{630}']), ('\u{1ee99}',
-         &['\u{636}']), ('\u{1ee9a}',  &['\u{638}']), ('\u{1ee9b}',  &['\u{63a}']), ('\u{1eea1}',
-         &['\u{628}']), ('\u{1eea2}',  &['\u{62c}']), ('\u{1eea3}',  &['\u{62f}']), ('\u{1eea5}',
-         &['\u{648}']), ('\u{1eea6}',  &['\u{632}']), ('\u{1eea7}',  &['\u{62d}']), ('\u{1eea8}',
-         &['\u{637}']), ('\u{1eea9}',  &['\u{649}']), ('\u{1eeab}',  &['\u{644}']), ('\u{1eeac}',
-         &['\u{645}']), ('\u{1eead}',  &['\u{646}']), ('\u{1eeae}',  &['\u{633}']), ('\u{1eeaf}',
-         &['\u{639}']), ('\u{1eeb0}',  &['\u{641}']), ('\u{1eeb1}',  &['\u{635}']), ('\u{1eeb2}',
-         &['\u{642}']), ('\u{1eeb3}',  &['\u{631}']), ('\u{1eeb4}',  &['\u{633}',  '\u{6db}']),
-        ('\u{1eeb5}',  &['\u{62a}']), ('\u{1eeb6}',  &['\u{649}',  '\u{6db}']), ('\u{1eeb7}',
-         &['\u{62e}']), ('\u{1eeb8}',  &['\u{630}']), ('\u{1eeb9}',  &['\u{636}']), ('\u{1eeba}',
-         &['\u{638}']), ('\u{1eebb}',  &['\u{63a}']), ('\u{1f100}',  &['\u{4f}',  '\u{2e}']),
-        ('\u{1f101}',  &['\u{4f}',  '\u{2c}']), ('\u{1f102}',  &['\u{6c}',  '\u{2c}']),
-        ('\u{1f103}',  &['\u{32}',  '\u{2c}']), ('\u{1f104}',  &['\u{33}',  '\u{2c}']),
-        ('\u{1f105}',  &['\u{34}',  '\u{2c}']), ('\u{1f106}',  &['\u{35}',  '\u{2c}']),
-        ('\u{1f107}',  &['\u{36}',  '\u{2c}']), ('\u{1f108}',  &['\u{37}',  '\u{2c}']),
-        ('\u{1f109}',  &['\u{38}',  '\u{2c}']), ('\u{1f10a}',  &['\u{39}',  '\u{2c}']),
-        ('\u{1f10f}',  &['\u{24}',  '\u{20e0}']), ('\u{1f110}',  &['\u{28}',  '\u{41}',  '\u{29}']),
-        ('\u{1f111}',  &['\u{28}',  '\u{42}',  '\u{29}']), ('\u{1f112}',  &['\u{28}',  '\u{43}',
-         '\u{29}']), ('\u{1f113}',  &['\u{28}',  '\u{44}',  '\u{29}']), ('\u{1f114}',  &['\u{28}',
-         '\u{45}',  '\u{29}']), ('\u{1f115}',  &['\u{28}',  '\u{46}',  '\u{29}']), ('\u{1f116}',
-         &['\u{28}',  '\u{47}',  '\u{29}']), ('\u{1f117}',  &['\u{28}',  '\u{48}',  '\u{29}']),
-        ('\u{1f118}',  &['\u{28}',  '\u{6c}',  '\u{29}']), ('\u{1f119}',  &['\u{28}',  '\u{4a}',
-         '\u{29}']), ('\u{1f11a}',  &['\u{28}',  '\u{4b}',  '\u{29}']), ('\u{1f11b}',  &['\u{28}',
-         '\u{4c}',  '\u{29}']), ('\u{1f11c}',  &['\u{28}',  '\u{4d}',  '\u{29}']), ('\u{1f11d}',
-         &['\u{28}',  '\u{4e}',  '\u{29}']), ('\u{1f11e}',  &['\u{28}',  '\u{4f}',  '\u{29}']),
-        ('\u{1f11f}',  &['\u{28}',  '\u{50}',  '\u{29}']), ('\u{1f120}',  &['\u{28}',  '\u{51}',
-         '\u{29}']), ('\u{1f121}',  &['\u{28}',  '\u{52}',  '\u{29}']), ('\u{1f122}',  &['\u{28}',
-         '\u{53}',  '\u{29}']), ('\u{1f123}',  &['\u{28}',  '\u{54}',  '\u{29}']), ('\u{1f124}',
-         &['\u{28}',  '\u{55}',  '\u{29}']), ('\u{1f125}',  &['\u{28}',  '\u{56}',  '\u{29}']),
-        ('\u{1f126}',  &['\u{28}',  '\u{57}',  '\u{29}']), ('\u{1f127}',  &['\u{28}',  '\u{58}',
-         '\u{29}']), ('\u{1f128}',  &['\u{28}',  '\u{59}',  '\u{29}']), ('\u{1f129}',  &['\u{28}',
-         '\u{5a}',  '\u{29}']), ('\u{1f12a}',  &['\u{28}',  '\u{53}',  '\u{29}']), ('\u{1f16d}',
-         &['\u{33c4}',  '\u{9}',  '\u{20dd}']), ('\u{1f16e}',  &['\u{43}',  '\u{20e0}']),
-        ('\u{1f240}',  &['\u{28}',  '\u{672c}',  '\u{29}']), ('\u{1f241}',  &['\u{28}',  '\u{4e09}',
-         '\u{29}']), ('\u{1f242}',  &['\u{28}',  '\u{4e8c}',  '\u{29}']), ('\u{1f243}',  &['\u{28}',
-         '\u{5b89}',  '\u{29}']), ('\u{1f244}',  &['\u{28}',  '\u{70b9}',  '\u{29}']), ('\u{1f245}',
-         &['\u{28}',  '\u{6253}',  '\u{29}']), ('\u{1f246}',  &['\u{28}',  '\u{76d7}',  '\u{29}']),
-        ('\u{1f247}',  &['\u{28}',  '\u{52dd}',  '\u{29}']), ('\u{1f248}',  &['\u{28}',  '\u{6557}',
-         '\u{29}']), ('\u{1f312}',  &['\u{263d}']), ('\u{1f318}',  &['\u{263e}']), ('\u{1f319}',
-         &['\u{263d}']), ('\u{1f333}',  &['\u{1cebc}']), ('\u{1f34e}',  &['\u{1cebd}']),
-        ('\u{1f34f}',  &['\u{1cebd}']), ('\u{1f352}',  &['\u{1cebe}']), ('\u{1f353}',
-         &['\u{1cebf}']), ('\u{1f377}',  &['\u{1ceba}']), ('\u{1f3e2}',  &['\u{1cebb}']),
-        ('\u{1f40d}',  &['\u{1ccfa}']), ('\u{1f443}',  &['\u{1ccfc}']), ('\u{1f514}',
-         &['\u{1fbfa}']), ('\u{1f700}',  &['\u{51}',  '\u{45}']), ('\u{1f701}',  &['\u{a658}']),
-        ('\u{1f702}',  &['\u{394}']), ('\u{1f704}',  &['\u{102bc}']), ('\u{1f707}',  &['\u{41}',
-         '\u{52}']), ('\u{1f708}',  &['\u{56}',  '\u{1de4}']), ('\u{1f70a}',  &['\u{2629}']),
-        ('\u{1f714}',  &['\u{4f}',  '\u{335}']), ('\u{1f728}',  &['\u{102a8}']), ('\u{1f73a}',
-         &['\u{29df}']), ('\u{1f74c}',  &['\u{43}']), ('\u{1f754}',  &['\u{16dc}']), ('\u{1f755}',
-         &['\u{22a1}']), ('\u{1f75c}',  &['\u{73}',  '\u{73}',  '\u{73}']), ('\u{1f75e}',
-         &['\u{224f}']), ('\u{1f768}',  &['\u{54}']), ('\u{1f76b}',  &['\u{4d}',  '\u{42}']),
-        ('\u{1f76c}',  &['\u{56}',  '\u{42}']), ('\u{1f771}',  &['\u{22a0}']), ('\u{1fbf0}',
-         &['\u{4f}']), ('\u{1fbf1}',  &['\u{6c}']), ('\u{1fbf2}',  &['\u{32}']), ('\u{1fbf3}',
-         &['\u{33}']), ('\u{1fbf4}',  &['\u{34}']), ('\u{1fbf5}',  &['\u{35}']), ('\u{1fbf6}',
-         &['\u{36}']), ('\u{1fbf7}',  &['\u{37}']), ('\u{1fbf8}',  &['\u{38}']), ('\u{1fbf9}',
-         &['\u{39}']), ('\u{20674}',  &['\u{51f5}']), ('\u{21533}',  &['\u{58f7}']), ('\u{21587}',
-         &['\u{591a}']), ('\u{216a7}',  &['\u{216a8}']), ('\u{21fe8}',  &['\u{276c}']),
-        ('\u{22505}',  &['\u{5f9a}']), ('\u{23d40}',  &['\u{6d85}']), ('\u{248fd}',  &['\u{73a5}']),
-        ('\u{2511a}',  &['\u{25119}']), ('\u{25ad4}',  &['\u{8d1b}']), ('\u{2659d}',
-         &['\u{265a8}']), ('\u{26d06}',  &['\u{26c36}']), ('\u{2aec5}',  &['\u{24814}']),
-        ('\u{2b73a}',  &['\u{5cc0}']), ('\u{2b73e}',  &['\u{2335f}']), ('\u{2d161}',
-         &['\u{5351}']), ('\u{2f800}',  &['\u{4e3d}']), ('\u{2f801}',  &['\u{4e38}']), ('\u{2f802}',
-         &['\u{4e41}']), ('\u{2f803}',  &['\u{20122}']), ('\u{2f804}',  &['\u{4f60}']),
-        ('\u{2f805}',  &['\u{4fae}']), ('\u{2f806}',  &['\u{4fbb}']), ('\u{2f807}',  &['\u{4f75}']),
-        ('\u{2f808}',  &['\u{507a}']), ('\u{2f809}',  &['\u{5099}']), ('\u{2f80a}',  &['\u{50e7}']),
-        ('\u{2f80b}',  &['\u{50cf}']), ('\u{2f80c}',  &['\u{349e}']), ('\u{2f80d}',
-         &['\u{2063a}']), ('\u{2f80e}',  &['\u{514d}']), ('\u{2f80f}',  &['\u{5154}']),
-        ('\u{2f810}',  &['\u{5164}']), ('\u{2f811}',  &['\u{5177}']), ('\u{2f812}',
-         &['\u{2051c}']), ('\u{2f813}',  &['\u{34b9}']), ('\u{2f814}',  &['\u{5167}']),
-        ('\u{2f815}',  &['\u{518d}']), ('\u{2f816}',  &['\u{2054b}']), ('\u{2f817}',
-         &['\u{5197}']), ('\u{2f818}',  &['\u{51a4}']), ('\u{2f819}',  &['\u{4ecc}']), ('\u{2f81a}',
-         &['\u{51ac}']), ('\u{2f81b}',  &['\u{51b5}']), ('\u{2f81c}',  &['\u{291df}']),
-        ('\u{2f81d}',  &['\u{51f5}']), ('\u{2f81e}',  &['\u{5203}']), ('\u{2f81f}',  &['\u{34df}']),
-        ('\u{2f820}',  &['\u{523b}']), ('\u{2f821}',  &['\u{5246}']), ('\u{2f822}',  &['\u{5272}']),
-        ('\u{2f823}',  &['\u{5277}']), ('\u{2f824}',  &['\u{3515}']), ('\u{2f825}',  &['\u{52c7}']),
-        ('\u{2f826}',  &['\u{52c9}']), ('\u{2f827}',  &['\u{52e4}']), ('\u{2f828}',  &['\u{52fa}']),
-        ('\u{2f829}',  &['\u{5305}']), ('\u{2f82a}',  &['\u{5306}']), ('\u{2f82b}',  &['\u{5317}']),
-        ('\u{2f82c}',  &['\u{5349}']), ('\u{2f82d}',  &['\u{5351}']), ('\u{2f82e}',  &['\u{535a}']),
-        ('\u{2f82f}',  &['\u{5373}']), ('\u{2f830}',  &['\u{537d}']), ('\u{2f831}',  &['\u{537f}']),
-        ('\u{2f832}',  &['\u{537f}']), ('\u{2f833}',  &['\u{537f}']), ('\u{2f834}',
-         &['\u{20a2c}']), ('\u{2f835}',  &['\u{7070}']), ('\u{2f836}',  &['\u{53ca}']),
-        ('\u{2f837}',  &['\u{53df}']), ('\u{2f838}',  &['\u{20b63}']), ('\u{2f839}',
-         &['\u{53eb}']), ('\u{2f83a}',  &['\u{53f1}']), ('\u{2f83b}',  &['\u{5406}']), ('\u{2f83c}',
-         &['\u{549e}']), ('\u{2f83d}',  &['\u{5438}']), ('\u{2f83e}',  &['\u{5448}']), ('\u{2f83f}',
-         &['\u{5468}']), ('\u{2f840}',  &['\u{54a2}']), ('\u{2f841}',  &['\u{54f6}']), ('\u{2f842}',
-         &['\u{5510}']), ('\u{2f843}',  &['\u{5553}']), ('\u{2f844}',  &['\u{5563}']), ('\u{2f845}',
-         &['\u{5584}']), ('\u{2f846}',  &['\u{5584}']), ('\u{2f847}',  &['\u{5599}']), ('\u{2f848}',
-         &['\u{55ab}']), ('\u{2f849}',  &['\u{55b3}']), ('\u{2f84a}',  &['\u{55c2}']), ('\u{2f84b}',
-         &['\u{5716}']), ('\u{2f84c}',  &['\u{5606}']), ('\u{2f84d}',  &['\u{5717}']), ('\u{2f84e}',
-         &['\u{5651}']), ('\u{2f84f}',  &['\u{5674}']), ('\u{2f850}',  &['\u{5207}']), ('\u{2f851}',
-         &['\u{58ee}']), ('\u{2f852}',  &['\u{57ce}']), ('\u{2f853}',  &['\u{57f4}']), ('\u{2f854}',
-         &['\u{580d}']), ('\u{2f855}',  &['\u{578b}']), ('\u{2f856}',  &['\u{5832}']), ('\u{2f857}',
-         &['\u{5831}']), ('\u{2f858}',  &['\u{58ac}']), ('\u{2f859}',  &['\u{214e4}']),
-        ('\u{2f85a}',  &['\u{58f2}']), ('\u{2f85b}',  &['\u{58f7}']), ('\u{2f85c}',  &['\u{5906}']),
-        ('\u{2f85d}',  &['\u{591a}']), ('\u{2f85e}',  &['\u{5922}']), ('\u{2f85f}',  &['\u{5962}']),
-        ('\u{2f860}',  &['\u{216a8}']), ('\u{2f861}',  &['\u{216ea}']), ('\u{2f862}',
-         &['\u{59ec}']), ('\u{2f863}',  &['\u{5a1b}']), ('\u{2f864}',  &['\u{5a27}']), ('\u{2f865}',
-         &['\u{59d8}']), ('\u{2f866}',  &['\u{5a66}']), ('\u{2f867}',  &['\u{36ee}']), ('\u{2f868}',
-         &['\u{36fc}']), ('\u{2f869}',  &['\u{5b08}']), ('\u{2f86a}',  &['\u{5b3e}']), ('\u{2f86b}',
-         &['\u{5b3e}']), ('\u{2f86c}',  &['\u{219c8}']), ('\u{2f86d}',  &['\u{5bc3}']),
-        ('\u{2f86e}',  &['\u{5bd8}']), ('\u{2f86f}',  &['\u{5be7}']), ('\u{2f870}',  &['\u{5bf3}']),
-        ('\u{2f871}',  &['\u{21b18}']), ('\u{2f872}',  &['\u{5bff}']), ('\u{2f873}',
-         &['\u{5c06}']), ('\u{2f874}',  &['\u{5f53}']), ('\u{2f875}',  &['\u{5c22}']), ('\u{2f876}',
-         &['\u{3781}']), ('\u{2f877}',  &['\u{5c60}']), ('\u{2f878}',  &['\u{5c6e}']), ('\u{2f879}',
-         &['\u{5cc0}']), ('\u{2f87a}',  &['\u{5c8d}']), ('\u{2f87b}',  &['\u{21de4}']),
-        ('\u{2f87c}',  &['\u{5d43}']), ('\u{2f87d}',  &['\u{21de6}']), ('\u{2f87e}',
-         &['\u{5d6e}']), ('\u{2f87f}',  &['\u{5d6b}']), ('\u{2f880}',  &['\u{5d7c}']), ('\u{2f881}',
-         &['\u{5de1}']), ('\u{2f882}',  &['\u{5de2}']), ('\u{2f883}',  &['\u{382f}']), ('\u{2f884}',
-         &['\u{5dfd}']), ('\u{2f885}',  &['\u{5e28}']), ('\u{2f886}',  &['\u{5e3d}']), ('\u{2f887}',
-         &['\u{5e69}']), ('\u{2f888}',  &['\u{3862}']), ('\u{2f889}',  &['\u{22183}']),
-        ('\u{2f88a}',  &['\u{387c}']), ('\u{2f88b}',  &['\u{5eb0}']), ('\u{2f88c}',  &['\u{5eb3}']),
-        ('\u{2f88d}',  &['\u{5eb6}']), ('\u{2f88e}',  &['\u{5eca}']), ('\u{2f88f}',
-         &['\u{2a392}']), ('\u{2f890}',  &['\u{5efe}']), ('\u{2f891}',  &['\u{22331}']),
-        ('\u{2f892}',  &['\u{22331}']), ('\u{2f893}',  &['\u{8201}']), ('\u{2f894}',
-         &['\u{5f22}']), ('\u{2f895}',  &['\u{5f22}']), ('\u{2f896}',  &['\u{38c7}']), ('\u{2f897}',
-         &['\u{232b8}']), ('\u{2f898}',  &['\u{261da}']), ('\u{2f899}',  &['\u{5f62}']),
-        ('\u{2f89a}',  &['\u{5f6b}']), ('\u{2f89b}',  &['\u{38e3}']), ('\u{2f89c}',  &['\u{5f9a}']),
-        ('\u{2f89d}',  &['\u{5fcd}']), ('\u{2f89e}',  &['\u{5fd7}']), ('\u{2f89f}',  &['\u{5ff9}']),
-        ('\u{2f8a0}',  &['\u{6081}']), ('\u{2f8a1}',  &['\u{393a}']), ('\u{2f8a2}',  &['\u{391c}']),
-        ('\u{2f8a3}',  &['\u{6094}']), ('\u{2f8a4}',  &['\u{226d4}']), ('\u{2f8a5}',
-         &['\u{60c7}']), ('\u{2f8a6}',  &['\u{6148}']), ('\u{2f8a7}',  &['\u{614c}']), ('\u{2f8a8}',
-         &['\u{614e}']), ('\u{2f8a9}',  &['\u{614c}']), ('\u{2f8aa}',  &['\u{617a}']), ('\u{2f8ab}',
-         &['\u{618e}']), ('\u{2f8ac}',  &['\u{61b2}']), ('\u{2f8ad}',  &['\u{61a4}']), ('\u{2f8ae}',
-         &['\u{61af}']), ('\u{2f8af}',  &['\u{61de}']), ('\u{2f8b0}',  &['\u{61f2}']), ('\u{2f8b1}',
-         &['\u{61f6}']), ('\u{2f8b2}',  &['\u{6210}']), ('\u{2f8b3}',  &['\u{621b}']), ('\u{2f8b4}',
-         &['\u{625d}']), ('\u{2f8b5}',  &['\u{62b1}']), ('\u{2f8b6}',  &['\u{62d4}']), ('\u{2f8b7}',
-         &['\u{6350}']), ('\u{2f8b8}',  &['\u{22b0c}']), ('\u{2f8b9}',  &['\u{633d}']),
-        ('\u{2f8ba}',  &['\u{62fc}']), ('\u{2f8bb}',  &['\u{6368}']), ('\u{2f8bc}',  &['\u{6383}']),
-        ('\u{2f8bd}',  &['\u{63e4}']), ('\u{2f8be}',  &['\u{22bf1}']), ('\u{2f8bf}',
-         &['\u{6422}']), ('\u{2f8c0}',  &['\u{63c5}']), ('\u{2f8c1}',  &['\u{63a9}']), ('\u{2f8c2}',
-         &['\u{3a2e}']), ('\u{2f8c3}',  &['\u{6469}']), ('\u{2f8c4}',  &['\u{647e}']), ('\u{2f8c5}',
-         &['\u{649d}']), ('\u{2f8c6}',  &['\u{6477}']), ('\u{2f8c7}',  &['\u{3a6c}']), ('\u{2f8c8}',
-         &['\u{654f}']), ('\u{2f8c9}',  &['\u{656c}']), ('\u{2f8ca}',  &['\u{2300a}']),
-        ('\u{2f8cb}',  &['\u{65e3}']), ('\u{2f8cc}',  &['\u{66f8}']), ('\u{2f8cd}',  &['\u{6649}']),
-        ('\u{2f8ce}',  &['\u{3b19}']), ('\u{2f8cf}',  &['\u{6691}']), ('\u{2f8d0}',  &['\u{3b08}']),
-        ('\u{2f8d1}',  &['\u{3ae4}']), ('\u{2f8d2}',  &['\u{5192}']), ('\u{2f8d3}',  &['\u{5195}']),
-        ('\u{2f8d4}',  &['\u{6700}']), ('\u{2f8d5}',  &['\u{669c}']), ('\u{2f8d6}',  &['\u{80ad}']),
-        ('\u{2f8d7}',  &['\u{43d9}']), ('\u{2f8d8}',  &['\u{6717}']), ('\u{2f8d9}',  &['\u{671b}']),
-        ('\u{2f8da}',  &['\u{6721}']), ('\u{2f8db}',  &['\u{675e}']), ('\u{2f8dc}',  &['\u{6753}']),
-        ('\u{2f8dd}',  &['\u{233c3}']), ('\u{2f8de}',  &['\u{3b49}']), ('\u{2f8df}',
-         &['\u{67fa}']), ('\u{2f8e0}',  &['\u{6785}']), ('\u{2f8e1}',  &['\u{6852}']), ('\u{2f8e2}',
-         &['\u{6885}']), ('\u{2f8e3}',  &['\u{2346d}']), ('\u{2f8e4}',  &['\u{688e}']),
-        ('\u{2f8e5}',  &['\u{681f}']), ('\u{2f8e6}',  &['\u{6914}']), ('\u{2f8e7}',  &['\u{3b9d}']),
-        ('\u{2f8e8}',  &['\u{6942}']), ('\u{2f8e9}',  &['\u{69a3}']), ('\u{2f8ea}',  &['\u{69ea}']),
-        ('\u{2f8eb}',  &['\u{6aa8}']), ('\u{2f8ec}',  &['\u{236a3}']), ('\u{2f8ed}',
-         &['\u{6adb}']), ('\u{2f8ee}',  &['\u{3c18}']), ('\u{2f8ef}',  &['\u{6b21}']), ('\u{2f8f0}',
-         &['\u{238a7}']), ('\u{2f8f1}',  &['\u{6b54}']), ('\u{2f8f2}',  &['\u{3c4e}']),
-        ('\u{2f8f3}',  &['\u{6b72}']), ('\u{2f8f4}',  &['\u{6b9f}']), ('\u{2f8f5}',  &['\u{6bba}']),
-        ('\u{2f8f6}',  &['\u{6bbb}']), ('\u{2f8f7}',  &['\u{23a8d}']), ('\u{2f8f8}',
-         &['\u{21d0b}']), ('\u{2f8f9}',  &['\u{23afa}']), ('\u{2f8fa}',  &['\u{6c4e}']),
-        ('\u{2f8fb}',  &['\u{23cbc}']), ('\u{2f8fc}',  &['\u{6cbf}']), ('\u{2f8fd}',
-         &['\u{6ccd}']), ('\u{2f8fe}',  &['\u{6c67}']), ('\u{2f8ff}',  &['\u{6d16}']), ('\u{2f900}',
-         &['\u{6d3e}']), ('\u{2f901}',  &['\u{6d77}']), ('\u{2f902}',  &['\u{6d41}']), ('\u{2f903}',
-         &['\u{6d69}']), ('\u{2f904}',  &['\u{6d78}']), ('\u{2f905}',  &['\u{6d85}']), ('\u{2f906}',
-         &['\u{23d1e}']), ('\u{2f907}',  &['\u{6d34}']), ('\u{2f908}',  &['\u{6e2f}']),
-        ('\u{2f909}',  &['\u{6e6e}']), ('\u{2f90a}',  &['\u{3d33}']), ('\u{2f90b}',  &['\u{6ecb}']),
-        ('\u{2f90c}',  &['\u{6ec7}']), ('\u{2f90d}',  &['\u{23ed1}']), ('\u{2f90e}',
-         &['\u{6df9}']), ('\u{2f90f}',  &['\u{6f6e}']), ('\u{2f910}',  &['\u{23f5e}']),
-        ('\u{2f911}',  &['\u{23f8e}']), ('\u{2f912}',  &['\u{6fc6}']), ('\u{2f913}',
-         &['\u{7039}']), ('\u{2f914}',  &['\u{701e}']), ('\u{2f915}',  &['\u{701b}']), ('\u{2f916}',
-         &['\u{3d96}']), ('\u{2f917}',  &['\u{704a}']), ('\u{2f918}',  &['\u{707d}']), ('\u{2f919}',
-         &['\u{7077}']), ('\u{2f91a}',  &['\u{70ad}']), ('\u{2f91b}',  &['\u{20525}']),
-        ('\u{2f91c}',  &['\u{7145}']), ('\u{2f91d}',  &['\u{24263}']), ('\u{2f91e}',
-         &['\u{719c}']), ('\u{2f91f}',  &['\u{243ab}']), ('\u{2f920}',  &['\u{7228}']),
-        ('\u{2f921}',  &['\u{7235}']), ('\u{2f922}',  &['\u{7250}']), ('\u{2f923}',
-         &['\u{24608}']), ('\u{2f924}',  &['\u{7280}']), ('\u{2f925}',  &['\u{7295}']),
-        ('\u{2f926}',  &['\u{24735}']), ('\u{2f927}',  &['\u{24814}']), ('\u{2f928}',
-         &['\u{737a}']), ('\u{2f929}',  &['\u{738b}']), ('\u{2f92a}',  &['\u{3eac}']), ('\u{2f92b}',
-         &['\u{73a5}']), ('\u{2f92c}',  &['\u{3eb8}']), ('\u{2f92d}',  &['\u{3eb8}']), ('\u{2f92e}',
-         &['\u{7447}']), ('\u{2f92f}',  &['\u{745c}']), ('\u{2f930}',  &['\u{7471}']), ('\u{2f931}',
-         &['\u{7485}']), ('\u{2f932}',  &['\u{74ca}']), ('\u{2f933}',  &['\u{3f1b}']), ('\u{2f934}',
-         &['\u{7524}']), ('\u{2f935}',  &['\u{24c36}']), ('\u{2f936}',  &['\u{753e}']),
-        ('\u{2f937}',  &['\u{24c92}']), ('\u{2f938}',  &['\u{7570}']), ('\u{2f939}',
-         &['\u{2219f}']), ('\u{2f93a}',  &['\u{7610}']), ('\u{2f93b}',  &['\u{24fa1}']),
-        ('\u{2f93c}',  &['\u{24fb8}']), ('\u{2f93d}',  &['\u{25044}']), ('\u{2f93e}',
-         &['\u{3ffc}']), ('\u{2f93f}',  &['\u{4008}']), ('\u{2f940}',  &['\u{76f4}']), ('\u{2f941}',
-         &['\u{250f3}']), ('\u{2f942}',  &['\u{250f2}']), ('\u{2f943}',  &['\u{25119}']),
-        ('\u{2f944}',  &['\u{25133}']), ('\u{2f945}',  &['\u{771e}']), ('\u{2f946}',
-         &['\u{771f}']), ('\u{2f947}',  &['\u{771f}']), ('\u{2f948}',  &['\u{774a}']), ('\u{2f949}',
-         &['\u{4039}']), ('\u{2f94a}',  &['\u{778b}']), ('\u{2f94b}',  &['\u{4046}']), ('\u{2f94c}',
-         &['\u{4096}']), ('\u{2f94d}',  &['\u{2541d}']), ('\u{2f94e}',  &['\u{784e}']),
-        ('\u{2f94f}',  &['\u{788c}']), ('\u{2f950}',  &['\u{78cc}']), ('\u{2f951}',  &['\u{40e3}']),
-        ('\u{2f952}',  &['\u{25626}']), ('\u{2f953}',  &['\u{7956}']), ('\u{2f954}',
-         &['\u{2569a}']), ('\u{2f955}',  &['\u{256c5}']), ('\u{2f956}',  &['\u{798f}']),
-        ('\u{2f957}',  &['\u{79eb}']), ('\u{2f958}',  &['\u{412f}']), ('\u{2f959}',  &['\u{7a40}']),
-        ('\u{2f95a}',  &['\u{7a4a}']), ('\u{2f95b}',  &['\u{7a4f}']), ('\u{2f95c}',
-         &['\u{2597c}']), ('\u{2f95d}',  &['\u{25aa7}']), ('\u{2f95e}',  &['\u{25aa7}']),
-        ('\u{2f95f}',  &['\u{7aee}']), ('\u{2f960}',  &['\u{4202}']), ('\u{2f961}',
-         &['\u{25bab}']), ('\u{2f962}',  &['\u{7bc6}']), ('\u{2f963}',  &['\u{7bc9}']),
-        ('\u{2f964}',  &['\u{4227}']), ('\u{2f965}',  &['\u{25c80}']), ('\u{2f966}',
-         &['\u{7cd2}']), ('\u{2f967}',  &['\u{42a0}']), ('\u{2f968}',  &['\u{7ce8}']), ('\u{2f969}',
-         &['\u{7ce3}']), ('\u{2f96a}',  &['\u{7d00}']), ('\u{2f96b}',  &['\u{25f86}']),
-        ('\u{2f96c}',  &['\u{7d63}']), ('\u{2f96d}',  &['\u{4301}']), ('\u{2f96e}',  &['\u{7dc7}']),
-        ('\u{2f96f}',  &['\u{7e02}']), ('\u{2f970}',  &['\u{7e45}']), ('\u{2f971}',  &['\u{4334}']),
-        ('\u{2f972}',  &['\u{26228}']), ('\u{2f973}',  &['\u{26247}']), ('\u{2f974}',
-         &['\u{4359}']), ('\u{2f975}',  &['\u{262d9}']), ('\u{2f976}',  &['\u{7f7a}']),
-        ('\u{2f977}',  &['\u{2633e}']), ('\u{2f978}',  &['\u{7f95}']), ('\u{2f979}',
-         &['\u{7ffa}']), ('\u{2f97a}',  &['\u{8005}']), ('\u{2f97b}',  &['\u{264da}']),
-        ('\u{2f97c}',  &['\u{26523}']), ('\u{2f97d}',  &['\u{8060}']), ('\u{2f97e}',
-         &['\u{265a8}']), ('\u{2f97f}',  &['\u{8070}']), ('\u{2f980}',  &['\u{2335f}']),
-        ('\u{2f981}',  &['\u{43d5}']), ('\u{2f982}',  &['\u{80b2}']), ('\u{2f983}',  &['\u{8103}']),
-        ('\u{2f984}',  &['\u{440b}']), ('\u{2f985}',  &['\u{813e}']), ('\u{2f986}',  &['\u{5ab5}']),
-        ('\u{2f987}',  &['\u{267a7}']), ('\u{2f988}',  &['\u{267b5}']), ('\u{2f989}',
-         &['\u{23393}']), ('\u{2f98a}',  &['\u{2339c}']), ('\u{2f98b}',  &['\u{8201}']),
-        ('\u{2f98c}',  &['\u{8204}']), ('\u{2f98d}',  &['\u{8f9e}']), ('\u{2f98e}',  &['\u{446b}']),
-        ('\u{2f98f}',  &['\u{8291}']), ('\u{2f990}',  &['\u{828b}']), ('\u{2f991}',  &['\u{829d}']),
-        ('\u{2f992}',  &['\u{52b3}']), ('\u{2f993}',  &['\u{82b1}']), ('\u{2f994}',  &['\u{82b3}']),
-        ('\u{2f995}',  &['\u{82bd}']), ('\u{2f996}',  &['\u{82e6}']), ('\u{2f997}',
-         &['\u{26b3c}']), ('\u{2f998}',  &['\u{82e5}']), ('\u{2f999}',  &['\u{831d}']),
-        ('\u{2f99a}',  &['\u{8363}']), ('\u{2f99b}',  &['\u{83ad}']), ('\u{2f99c}',  &['\u{8323}']),
-        ('\u{2f99d}',  &['\u{83bd}']), ('\u{2f99e}',  &['\u{83e7}']), ('\u{2f99f}',  &['\u{8457}']),
-        ('\u{2f9a0}',  &['\u{8353}']), ('\u{2f9a1}',  &['\u{83ca}']), ('\u{2f9a2}',  &['\u{83cc}']),
-        ('\u{2f9a3}',  &['\u{83dc}']), ('\u{2f9a4}',  &['\u{26c36}']), ('\u{2f9a5}',
-         &['\u{26d6b}']), ('\u{2f9a6}',  &['\u{26cd5}']), ('\u{2f9a7}',  &['\u{452b}']),
-        ('\u{2f9a8}',  &['\u{84f1}']), ('\u{2f9a9}',  &['\u{84f3}']), ('\u{2f9aa}',  &['\u{8516}']),
-        ('\u{2f9ab}',  &['\u{273ca}']), ('\u{2f9ac}',  &['\u{8564}']), ('\u{2f9ad}',
-         &['\u{26f2c}']), ('\u{2f9ae}',  &['\u{455d}']), ('\u{2f9af}',  &['\u{4561}']),
-        ('\u{2f9b0}',  &['\u{26fb1}']), ('\u{2f9b1}',  &['\u{270d2}']), ('\u{2f9b2}',
-         &['\u{456b}']), ('\u{2f9b3}',  &['\u{8650}']), ('\u{2f9b4}',  &['\u{865c}']), ('\u{2f9b5}',
-         &['\u{8667}']), ('\u{2f9b6}',  &['\u{8669}']), ('\u{2f9b7}',  &['\u{86a9}']), ('\u{2f9b8}',
-         &['\u{8688}']), ('\u{2f9b9}',  &['\u{870e}']), ('\u{2f9ba}',  &['\u{86e2}']), ('\u{2f9bb}',
-         &['\u{8779}']), ('\u{2f9bc}',  &['\u{8728}']), ('\u{2f9bd}',  &['\u{876b}']), ('\u{2f9be}',
-         &['\u{8786}']), ('\u{2f9bf}',  &['\u{45d7}']), ('\u{2f9c0}',  &['\u{87e1}']), ('\u{2f9c1}',
-         &['\u{8801}']), ('\u{2f9c2}',  &['\u{45f9}']), ('\u{2f9c3}',  &['\u{8860}']), ('\u{2f9c4}',
-         &['\u{8863}']), ('\u{2f9c5}',  &['\u{27667}']), ('\u{2f9c6}',  &['\u{88d7}']),
-        ('\u{2f9c7}',  &['\u{88de}']), ('\u{2f9c8}',  &['\u{4635}']), ('\u{2f9c9}',  &['\u{88fa}']),
-        ('\u{2f9ca}',  &['\u{34bb}']), ('\u{2f9cb}',  &['\u{278ae}']), ('\u{2f9cc}',
-         &['\u{27966}']), ('\u{2f9cd}',  &['\u{46be}']), ('\u{2f9ce}',  &['\u{46c7}']),
-        ('\u{2f9cf}',  &['\u{8aa0}']), ('\u{2f9d0}',  &['\u{8aed}']), ('\u{2f9d1}',  &['\u{8b8a}']),
-        ('\u{2f9d2}',  &['\u{8c55}']), ('\u{2f9d3}',  &['\u{27ca8}']), ('\u{2f9d4}',
-         &['\u{8cab}']), ('\u{2f9d5}',  &['\u{8cc1}']), ('\u{2f9d6}',  &['\u{8d1b}']), ('\u{2f9d7}',
-         &['\u{8d77}']), ('\u{2f9d8}',  &['\u{27f2f}']), ('\u{2f9d9}',  &['\u{20804}']),
-        ('\u{2f9da}',  &['\u{8dcb}']), ('\u{2f9db}',  &['\u{8dbc}']), ('\u{2f9dc}',  &['\u{8df0}']),
-        ('\u{2f9dd}',  &['\u{208de}']), ('\u{2f9de}',  &['\u{8ed4}']), ('\u{2f9df}',
-         &['\u{8f38}']), ('\u{2f9e0}',  &['\u{285d2}']), ('\u{2f9e1}',  &['\u{285ed}']),
-        ('\u{2f9e2}',  &['\u{9094}']), ('\u{2f9e3}',  &['\u{90f1}']), ('\u{2f9e4}',  &['\u{9111}']),
-        ('\u{2f9e5}',  &['\u{2872e}']), ('\u{2f9e6}',  &['\u{911b}']), ('\u{2f9e7}',
-         &['\u{9238}']), ('\u{2f9e8}',  &['\u{92d7}']), ('\u{2f9e9}',  &['\u{92d8}']), ('\u{2f9ea}',
-         &['\u{927c}']), ('\u{2f9eb}',  &['\u{93f9}']), ('\u{2f9ec}',  &['\u{9415}']), ('\u{2f9ed}',
-         &['\u{28bfa}']), ('\u{2f9ee}',  &['\u{958b}']), ('\u{2f9ef}',  &['\u{4995}']),
-        ('\u{2f9f0}',  &['\u{95b7}']), ('\u{2f9f1}',  &['\u{28d77}']), ('\u{2f9f2}',
-         &['\u{49e6}']), ('\u{2f9f3}',  &['\u{96c3}']), ('\u{2f9f4}',  &['\u{5db2}']), ('\u{2f9f5}',
-         &['\u{9723}']), ('\u{2f9f6}',  &['\u{29145}']), ('\u{2f9f7}',  &['\u{2921a}']),
-        ('\u{2f9f8}',  &['\u{4a6e}']), ('\u{2f9f9}',  &['\u{4a76}']), ('\u{2f9fa}',  &['\u{97e0}']),
-        ('\u{2f9fb}',  &['\u{2940a}']), ('\u{2f9fc}',  &['\u{4ab2}']), ('\u{2f9fd}',
-         &['\u{29496}']), ('\u{2f9fe}',  &['\u{980b}']), ('\u{2f9ff}',  &['\u{980b}']),
-        ('\u{2fa00}',  &['\u{9829}']), ('\u{2fa01}',  &['\u{295b6}']), ('\u{2fa02}',
-         &['\u{98e2}']), ('\u{2fa03}',  &['\u{4b33}']), ('\u{2fa04}',  &['\u{9929}']), ('\u{2fa05}',
-         &['\u{99a7}']), ('\u{2fa06}',  &['\u{99c2}']), ('\u{2fa07}',  &['\u{99fe}']), ('\u{2fa08}',
-         &['\u{4bce}']), ('\u{2fa09}',  &['\u{29b30}']), ('\u{2fa0a}',  &['\u{9b12}']),
-        ('\u{2fa0b}',  &['\u{9c40}']), ('\u{2fa0c}',  &['\u{9cfd}']), ('\u{2fa0d}',  &['\u{4cce}']),
-        ('\u{2fa0e}',  &['\u{4ced}']), ('\u{2fa0f}',  &['\u{9d67}']), ('\u{2fa10}',
-         &['\u{2a0ce}']), ('\u{2fa11}',  &['\u{4cf8}']), ('\u{2fa12}',  &['\u{2a105}']),
-        ('\u{2fa13}',  &['\u{2a20e}']), ('\u{2fa14}',  &['\u{2a291}']), ('\u{2fa15}',
-         &['\u{9ebb}']), ('\u{2fa16}',  &['\u{4d56}']), ('\u{2fa17}',  &['\u{9ef9}']), ('\u{2fa18}',
-         &['\u{9efe}']), ('\u{2fa19}',  &['\u{9f05}']), ('\u{2fa1a}',  &['\u{9f0f}']), ('\u{2fa1b}',
-         &['\u{9f16}']), ('\u{2fa1c}',  &['\u{9f3b}']), ('\u{2fa1d}',  &['\u{2a600}']),
-        ('\u{31e7c}',  &['\u{7dc7}'])
+    // Values are one more than the index of the prototype in PROTOTYPES, or 0
+    const CONFUSABLES: Trie<u16> = Trie {
+        latin1: &[
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 10, 0, 0, 0, 0, 5, 11, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 14, 0, 0,
+            0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 16, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            18, 19, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 21, 0, 22, 0, 0, 0, 0, 0, 23, 0
+        ],
+        index: &[
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 16, 38, 39, 40, 41, 42, 43, 44,
+            45, 46, 47, 48, 16, 49, 16, 50, 51, 16, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63,
+            64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 16, 76, 77, 78, 79, 80, 16, 81, 82, 83,
+            84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 98, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 99, 100, 16, 16, 16, 16, 101, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 102, 103, 16, 16, 104, 16, 105, 16, 16, 16, 106, 16, 107, 16, 108, 16, 16, 109, 16,
+            16, 110, 111, 16, 16, 16, 16, 112, 16, 16, 16, 16, 16, 113, 16, 16, 16, 16, 16, 114, 16,
+            115, 16, 16, 16, 116, 16, 16, 16, 117, 118, 119, 120, 16, 16, 16, 121, 122, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 123, 16, 16, 16, 16, 16, 124, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 125, 16, 16, 16, 16, 16, 16, 16, 16, 16, 126, 16, 16, 16, 16, 16, 16, 127,
+            128, 129, 16, 16, 16, 16, 16, 16, 130, 16, 131, 16, 16, 16, 16, 16, 16, 16, 132, 16, 16,
+            133, 134, 16, 16, 135, 16, 136, 137, 16, 16, 138, 16, 16, 16, 16, 16, 139, 16, 16, 140,
+            16, 16, 141, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 142, 143, 16, 144,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 145, 16, 16, 146, 147, 148, 149, 150, 16, 151, 152,
+            153, 16, 154, 155, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 156, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 157, 158, 159, 160, 161, 162, 163, 164, 165, 166, 167, 168, 169,
+            170, 16, 16, 171, 172, 16, 173, 174, 175, 176, 177, 178, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 179, 16, 16, 16, 16, 180, 16, 16, 16, 181, 16, 16, 16, 182, 16, 183, 16, 16, 184,
+            16, 185, 186, 16, 187, 188, 16, 189, 16, 16, 190, 16, 16, 16, 191, 192, 16, 193, 194,
+            16, 195, 16, 16, 16, 16, 196, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            197, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 198, 199, 200, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 201, 16, 16, 16, 202, 16, 16, 16, 16, 203, 16, 204, 16, 16, 16, 205, 206, 207, 208,
+            209, 210, 211, 212, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 213, 16, 16, 16, 214, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 215, 216, 16, 16, 16, 16, 217, 16, 218, 16, 219, 220, 221, 16,
+            222, 16, 16, 16, 223, 16, 16, 16, 16, 16, 16, 16, 16, 224, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 225, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 226,
+            227, 16, 228, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 229,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 230, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 231, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 232, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 233, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 234, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 235, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            236, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 237,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 238, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 239, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 240, 241, 242, 243, 244, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 245
+        ],
+        blocks: &[
+            0, 0, 0, 0, 1, 0, 2, 0, 0, 3, 0, 0, 4, 5, 0, 6, 0, 0, 0, 0, 7, 8, 9, 10, 11, 0, 12, 13,
+            14, 0, 15, 16, 0, 0, 17, 18, 19, 0, 20, 21, 22, 23, 24, 0, 25, 0, 0, 26, 27, 28, 29, 30,
+            31, 32, 33, 34, 35, 36, 37, 0, 38, 0, 39, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45, 46, 47,
+            48, 49, 50, 51, 52, 53, 54, 0, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68,
+            69, 70, 71, 0, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 0, 83, 84, 85, 0, 86, 87, 88,
+            0, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107,
+            0, 108, 109, 0, 110, 111, 112, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0,
+            123, 124, 125, 126, 0, 0, 0, 127, 128, 129, 130, 131, 132, 133, 134, 135, 136, 137, 0,
+            138, 139, 140, 141, 142, 143, 0, 144, 145, 146, 0, 147, 0, 0, 148, 0, 149, 150, 151,
+            152, 153, 154, 155, 156, 157, 158, 159, 160, 161, 162, 163, 164, 165, 166, 167, 168,
+            169, 170, 171, 172, 173, 0, 174, 175, 176, 177, 0, 0, 0, 0, 0, 0, 0, 178, 0, 179, 0,
+            180, 181, 182, 183, 0, 0, 0, 0, 0, 0, 0, 0, 184, 185, 0, 0, 0, 186, 187, 188, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 189, 0, 0, 190, 191, 192, 193, 194, 0, 0, 0,
+            195, 196, 197, 198, 199, 200, 201, 0, 0, 0, 0, 202, 203, 204, 205, 0, 206, 207, 208,
+            209, 210, 0, 0, 0, 0, 0, 0, 141, 0, 0, 0, 0, 211, 212, 213, 0, 214, 215, 216, 217, 218,
+            219, 220, 221, 222, 223, 0, 0, 224, 225, 226, 0, 227, 228, 229, 0, 0, 230, 231, 232,
+            233, 234, 0, 0, 235, 236, 237, 0, 238, 0, 0, 0, 184, 0, 0, 141, 0, 0, 0, 0, 235, 239, 0,
+            0, 240, 241, 242, 243, 0, 0, 244, 245, 0, 246, 247, 0, 248, 249, 250, 251, 252, 0, 253,
+            0, 254, 255, 256, 257, 258, 0, 0, 0, 259, 0, 0, 0, 260, 0, 261, 262, 263, 264, 265, 266,
+            267, 0, 0, 268, 269, 270, 0, 0, 271, 272, 273, 274, 275, 0, 276, 277, 278, 279, 0, 280,
+            281, 282, 283, 284, 285, 286, 287, 0, 0, 0, 288, 289, 290, 0, 0, 0, 0, 291, 0, 0, 292,
+            293, 294, 0, 295, 0, 296, 0, 297, 298, 299, 0, 0, 0, 0, 0, 0, 300, 0, 301, 0, 0, 302,
+            303, 0, 304, 299, 305, 0, 0, 0, 0, 306, 307, 0, 308, 0, 0, 309, 0, 0, 0, 0, 0, 0, 310,
+            311, 312, 0, 0, 0, 0, 0, 0, 0, 0, 0, 313, 314, 0, 0, 0, 0, 0, 315, 0, 316, 317, 318,
+            319, 320, 321, 299, 322, 0, 323, 324, 325, 326, 327, 328, 0, 0, 329, 330, 0, 0, 0, 0, 0,
+            331, 332, 333, 0, 334, 154, 335, 336, 337, 338, 339, 340, 341, 342, 343, 344, 345, 346,
+            347, 348, 349, 350, 351, 352, 353, 354, 355, 356, 357, 358, 359, 360, 361, 362, 363,
+            364, 365, 366, 367, 0, 0, 0, 368, 0, 0, 0, 369, 0, 0, 0, 370, 0, 0, 0, 0, 0, 371, 0, 0,
+            0, 0, 0, 0, 0, 184, 0, 0, 0, 0, 0, 0, 0, 0, 0, 372, 373, 374, 375, 376, 377, 378, 379,
+            380, 381, 382, 383, 384, 385, 386, 387, 388, 389, 390, 391, 392, 393, 394, 395, 396,
+            397, 398, 399, 400, 401, 402, 403, 404, 405, 406, 407, 0, 408, 409, 410, 411, 412, 413,
+            414, 415, 416, 417, 418, 419, 420, 421, 422, 423, 424, 0, 425, 0, 426, 427, 428, 429,
+            430, 431, 0, 0, 432, 433, 0, 434, 0, 0, 435, 0, 436, 437, 0, 438, 0, 0, 0, 439, 440,
+            441, 0, 0, 0, 0, 0, 0, 442, 443, 444, 445, 0, 0, 0, 0, 0, 446, 0, 447, 448, 449, 450,
+            451, 452, 369, 0, 0, 0, 0, 0, 0, 0, 453, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 454, 0, 0, 455,
+            0, 456, 457, 78, 458, 459, 460, 299, 0, 0, 0, 461, 137, 0, 0, 0, 0, 0, 0, 0, 0, 462, 0,
+            0, 0, 0, 0, 0, 0, 463, 0, 0, 0, 464, 465, 466, 467, 468, 469, 470, 471, 472, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 473, 0, 0, 0, 0, 0, 474, 0, 474, 0, 0, 475, 476, 0, 0, 0, 0, 477,
+            478, 479, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 480, 481, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            482, 0, 0, 0, 0, 0, 0, 0, 483, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 484, 485, 0, 486, 0, 0,
+            487, 488, 489, 490, 491, 492, 0, 493, 0, 0, 494, 0, 0, 495, 496, 497, 498, 499, 500,
+            501, 502, 0, 0, 503, 0, 0, 0, 0, 0, 504, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 505, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 506, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 333, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 507, 0, 0, 0, 0, 0, 0, 0, 508, 509, 0, 0, 0, 0, 510, 511, 512, 513,
+            514, 515, 516, 517, 518, 519, 520, 521, 522, 523, 524, 0, 0, 525, 526, 0, 0, 0, 0, 527,
+            528, 529, 530, 531, 0, 0, 532, 0, 0, 0, 0, 533, 534, 535, 536, 537, 538, 539, 540, 541,
+            542, 0, 0, 543, 544, 545, 546, 547, 0, 548, 0, 0, 0, 549, 550, 551, 552, 0, 0, 0, 0, 0,
+            0, 553, 554, 555, 556, 557, 558, 559, 560, 0, 0, 561, 562, 563, 564, 0, 0, 565, 0, 566,
+            567, 568, 0, 0, 0, 569, 570, 571, 572, 0, 573, 574, 575, 576, 0, 0, 0, 577, 578, 0, 0,
+            579, 0, 0, 580, 581, 582, 583, 584, 0, 0, 0, 585, 586, 587, 0, 0, 588, 0, 0, 0, 0, 589,
+            0, 590, 0, 0, 0, 0, 0, 0, 0, 0, 0, 591, 0, 0, 592, 593, 594, 595, 596, 597, 598, 599,
+            600, 601, 602, 603, 604, 0, 0, 605, 0, 606, 0, 0, 607, 608, 0, 0, 609, 0, 0, 0, 0, 0, 0,
+            0, 0, 610, 611, 0, 0, 612, 613, 614, 612, 0, 615, 616, 617, 618, 0, 0, 619, 0, 0, 0, 0,
+            620, 621, 0, 622, 0, 623, 624, 0, 625, 626, 627, 628, 629, 0, 0, 0, 0, 0, 0, 0, 630, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 631, 632, 0, 0, 0,
+            633, 0, 0, 0, 0, 0, 634, 635, 0, 636, 0, 637, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 638, 0, 0, 0, 0, 0, 639, 0, 0, 0, 0, 0, 0, 640, 641, 0, 0, 0, 0, 0, 642, 0, 0,
+            643, 644, 645, 0, 646, 647, 0, 0, 648, 649, 650, 651, 0, 652, 653, 654, 655, 656, 0, 0,
+            0, 0, 0, 657, 658, 0, 0, 0, 0, 0, 659, 660, 0, 0, 0, 0, 661, 0, 0, 0, 0, 662, 0, 0, 663,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 664, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 665, 666,
+            0, 0, 667, 668, 669, 670, 671, 672, 673, 674, 675, 676, 677, 678, 679, 680, 0, 681, 0,
+            0, 0, 0, 0, 0, 682, 683, 684, 685, 686, 687, 688, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 689, 690, 0, 0, 0, 0, 691, 692, 693, 694, 695, 696, 0, 0, 0, 0, 0, 0, 0, 697,
+            698, 699, 700, 701, 702, 703, 704, 705, 706, 707, 708, 709, 710, 711, 0, 712, 713, 714,
+            715, 716, 717, 718, 719, 720, 721, 722, 723, 724, 725, 726, 727, 728, 729, 730, 731,
+            732, 733, 734, 735, 736, 737, 738, 0, 0, 0, 0, 0, 739, 637, 740, 741, 0, 742, 743, 744,
+            0, 745, 0, 0, 0, 0, 0, 0, 0, 0, 0, 746, 747, 748, 0, 749, 0, 750, 0, 751, 0, 752, 0,
+            753, 0, 0, 0, 0, 754, 0, 755, 756, 757, 758, 759, 760, 761, 762, 763, 764, 765, 766, 0,
+            0, 0, 0, 0, 0, 0, 0, 767, 768, 769, 0, 0, 0, 770, 771, 772, 773, 774, 775, 776, 777,
+            778, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 779, 780, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 781, 782, 783, 784, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 785,
+            786, 787, 788, 0, 0, 0, 0, 0, 0, 789, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 790, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 791, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 792,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 793, 0, 794, 795, 0, 0, 796, 797, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 798, 0, 0, 0, 0, 0, 0, 0, 799, 0, 0, 0, 0, 0, 0, 800, 0, 0, 0, 0, 0, 0, 801,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 802, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 803, 0, 0, 0, 0, 0, 804, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 805, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 806, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 807, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 808, 0, 809, 810, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 811, 0, 0, 0, 812, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 813, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 814, 0, 0, 0, 0, 0, 0, 0, 0, 0, 815, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 816, 817, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 818, 0, 0, 0, 0, 0, 819, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 820, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 821, 0, 0,
+            0, 0, 0, 0, 822, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 823,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 824, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            825, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 826, 0, 0, 0, 0, 0,
+            827, 0, 0, 0, 0, 828, 829, 0, 0, 0, 830, 0, 831, 0, 0, 0, 832, 0, 0, 0, 833, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 834, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 835, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 836, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 837, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 838, 0, 839, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 840, 0, 0, 0, 841, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 842, 0, 0, 0, 843, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 844, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 845, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            846, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 847, 0, 0,
+            0, 0, 0, 0, 0, 0, 848, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 849, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 850, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 851,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 852, 853, 854, 855, 856, 857, 858, 0, 859, 860, 861, 862,
+            863, 864, 0, 865, 0, 0, 0, 0, 0, 0, 866, 867, 868, 0, 0, 869, 0, 870, 0, 0, 871, 872,
+            873, 0, 874, 0, 0, 875, 0, 876, 0, 877, 878, 0, 0, 0, 879, 0, 0, 880, 881, 882, 883,
+            884, 0, 885, 886, 887, 888, 889, 0, 890, 891, 892, 0, 893, 894, 0, 0, 895, 896, 897, 0,
+            0, 898, 0, 0, 0, 0, 0, 0, 0, 899, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 900, 901, 902, 903, 0, 0, 904, 0, 905, 0, 0, 0, 906, 907, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 908, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 909, 910, 911, 912,
+            913, 914, 915, 0, 916, 917, 918, 919, 920, 921, 922, 923, 924, 925, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 926, 927, 928, 929, 930, 931, 932, 933, 934, 935, 936, 937, 938,
+            939, 940, 941, 942, 943, 944, 945, 946, 947, 948, 949, 950, 951, 952, 953, 954, 955,
+            956, 957, 958, 959, 960, 961, 962, 963, 964, 965, 966, 967, 968, 969, 970, 971, 972,
+            973, 974, 975, 976, 977, 978, 979, 980, 981, 982, 983, 984, 985, 986, 987, 988, 989,
+            990, 991, 992, 993, 994, 995, 0, 0, 0, 0, 996, 0, 997, 0, 998, 999, 1000, 1001, 0, 1002,
+            1003, 1004, 1005, 1006, 1007, 1008, 1009, 1010, 1011, 1012, 1013, 1014, 1015, 0, 0, 0,
+            1016, 1017, 1018, 1019, 1020, 1021, 1022, 1023, 1024, 1025, 1026, 1027, 1028, 1029,
+            1030, 1031, 1032, 1033, 1034, 1035, 1036, 1037, 1038, 1039, 1040, 1041, 1042, 1043,
+            1044, 1045, 1046, 1047, 1048, 1049, 1050, 1051, 1052, 1053, 1054, 1055, 1056, 1057,
+            1058, 1059, 1060, 1061, 0, 0, 1062, 1063, 1064, 1065, 1066, 1067, 1068, 1069, 1070,
+            1071, 1072, 1073, 1074, 1075, 1076, 0, 0, 0, 0, 0, 1077, 1078, 0, 0, 0, 1079, 0, 0,
+            1080, 1081, 0, 1082, 0, 1083, 0, 1084, 0, 0, 1085, 1086, 1087, 1088, 1089, 1090, 1091,
+            1092, 1093, 1094, 1095, 1096, 1097, 1098, 1099, 1100, 1101, 1102, 0, 1103, 1104, 1105,
+            1106, 1107, 1108, 1109, 1110, 1111, 1112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1113, 1114, 0, 0, 1115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1116, 1117,
+            1118, 1119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1120, 1121, 1122, 1123, 1124, 1125, 1126,
+            1127, 0, 1128, 0, 0, 1129, 1130, 1131, 0, 1132, 3, 1133, 1134, 1135, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1136, 0, 0, 0, 0, 0, 1137, 0, 1138, 1139,
+            1140, 1141, 0, 1142, 1143, 1144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1145, 0, 1146, 1147,
+            1148, 1149, 1150, 1151, 0, 1152, 1153, 1154, 0, 0, 1155, 1156, 1157, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1158, 0, 0, 1159, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1160, 0, 0, 0, 0, 0, 0, 0, 0, 1161, 0, 1162, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 1163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1164, 1165,
+            0, 1166, 0, 0, 0, 0, 1167, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1168,
+            1169, 1170, 1171, 0, 0, 0, 1172, 0, 0, 0, 0, 0, 0, 0, 0, 1173, 1174, 1175, 1176, 1177,
+            1178, 1179, 0, 1180, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1181, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 1182, 0, 0, 0, 0, 0, 0, 0, 1183, 1184, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1185, 1186, 1187, 1188, 1189, 1190, 1191, 1192, 1193,
+            1194, 1195, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1196, 1197, 1198, 1199, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 1200, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1201, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 1202, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1203, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1204, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1205, 0, 0, 0, 0, 1206, 185, 1207, 0, 1208, 0, 1209, 0, 0, 0,
+            0, 0, 1210, 1211, 1212, 1213, 1214, 1215, 1216, 1217, 1218, 0, 1219, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1221,
+            1222, 1223, 1224, 1225, 1226, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1227, 0, 0, 0, 0,
+            1228, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1229, 0, 0, 1230, 1231, 1232, 1233, 1234, 1235,
+            1236, 1237, 1238, 0, 0, 0, 0, 0, 0, 0, 1239, 1240, 1241, 1242, 1243, 1244, 1245, 1246,
+            1247, 1248, 1249, 1250, 1251, 1239, 1240, 1241, 1242, 1243, 1244, 1252, 1253, 1254,
+            1248, 1255, 1256, 1251, 1239, 1240, 1241, 1242, 1243, 1244, 1257, 1258, 1259, 1260,
+            1261, 1250, 1251, 1262, 1263, 1264, 1265, 1243, 1244, 1245, 1246, 1247, 1248, 1261,
+            1250, 1251, 1239, 1240, 1241, 1242, 1243, 1244, 1245, 1246, 1247, 1248, 1261, 1250,
+            1251, 1239, 1240, 1241, 1242, 1243, 1244, 1245, 1246, 1247, 1248, 1261, 1250, 1251,
+            1239, 1240, 1241, 1242, 1243, 1244, 1266, 1267, 1268, 1269, 1270, 1271, 1272, 1273,
+            1274, 1275, 1276, 1277, 1278, 1279, 1280, 1281, 1282, 1283, 1284, 1285, 1286, 1287,
+            1288, 1289, 1290, 1291, 1292, 1293, 1294, 1295, 1267, 1268, 1269, 1270, 1271, 1272,
+            1273, 1296, 1297, 1225, 1298, 1299, 1300, 1297, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1301, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1302, 1303, 0, 0, 0, 0, 0, 0, 1304, 1305, 1306,
+            1307, 1308, 1309, 1310, 1311, 1312, 1313, 1314, 1315, 1308, 1316, 1310, 1317, 1318,
+            1319, 1306, 1320, 1321, 1319, 1306, 1320, 0, 0, 0, 0, 0, 0, 0, 0, 1322, 1323, 1324,
+            1325, 1326, 1327, 0, 0, 0, 0, 0, 0, 0, 1328, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1329, 1330,
+            0, 0, 0, 0, 0, 0, 0, 0, 1331, 1332, 0, 0, 1333, 0, 0, 1334, 1335, 0, 0, 0, 1336, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1337, 0, 0, 0, 0, 1338, 0, 0, 0, 0, 0, 0, 1339, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 1340, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1341, 1342, 1343, 0,
+            0, 1344, 0, 1345, 0, 1346, 1347, 1348, 0, 1349, 1350, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 1225, 1351, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1352, 0, 0, 0, 0, 0,
+            0, 0, 1353, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1354, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 1355, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 1356, 0, 0, 1357, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 1358, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1359, 0,
+            0, 0, 1360, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1361, 0,
+            0, 0, 0, 0, 0, 0, 0, 1362, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1363, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1364, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 1365, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1366, 0,
+            0, 0, 1367, 1368, 1369, 1370, 1371, 1372, 1373, 1374, 1375, 1376, 1377, 1378, 1379,
+            1380, 1381, 1382, 1383, 1384, 1385, 1386, 1387, 1388, 1389, 1390, 1391, 1392, 1393,
+            1394, 1395, 1396, 1397, 1398, 1399, 1400, 1401, 1402, 1403, 1404, 1405, 1406, 1407,
+            1408, 1409, 1410, 1411, 1412, 1413, 1414, 1415, 1416, 1417, 1418, 1419, 1420, 1421,
+            1422, 1423, 1424, 1425, 1426, 1427, 1428, 1429, 1430, 1431, 1432, 1433, 1434, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1435
+        ],
+        leaves: &[
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+            0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 7, 0,
+            8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 5, 11, 0, 0, 12, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 13, 14, 15, 0, 0, 0, 0, 0, 0, 16, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 18, 19, 20, 0, 0, 0, 0, 0, 21, 0, 22, 0, 0, 0, 0, 0, 23, 0, 15, 24, 0, 0, 0,
+            0, 0, 0, 0, 0, 25, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 28, 0, 29, 30, 31, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 32, 32, 33, 34, 0, 0, 0, 35, 0, 0, 36, 0, 37, 0, 0, 0, 0, 38, 0,
+            39, 40, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 42, 43, 0, 0, 0, 0, 0, 0, 0, 44, 45, 46, 47, 47,
+            48, 0, 0, 49, 0, 15, 50, 0, 51, 52, 0, 0, 0, 53, 44, 54, 0, 0, 4, 55, 56, 57, 55, 58, 0,
+            59, 37, 60, 61, 62, 0, 0, 63, 64, 65, 66, 0, 0, 0, 0, 67, 68, 69, 0, 0, 0, 0, 70, 71,
+            72, 73, 74, 0, 0, 0, 75, 76, 77, 0, 23, 4, 78, 0, 79, 80, 81, 82, 83, 84, 85, 86, 87,
+            88, 89, 90, 91, 92, 93, 94, 95, 96, 0, 0, 0, 0, 0, 0, 0, 97, 98, 99, 100, 0, 101, 102,
+            103, 0, 104, 0, 0, 0, 0, 0, 0, 0, 0, 105, 0, 0, 0, 106, 41, 74, 0, 0, 0, 0, 0, 107, 107,
+            108, 109, 110, 111, 0, 0, 0, 0, 8, 0, 112, 0, 0, 113, 0, 0, 114, 0, 115, 116, 117, 118,
+            0, 0, 0, 119, 9, 120, 0, 121, 0, 122, 0, 0, 123, 124, 0, 125, 126, 127, 0, 0, 0, 0, 128,
+            52, 0, 129, 0, 0, 130, 0, 131, 29, 29, 132, 0, 133, 134, 135, 0, 136, 0, 137, 0, 138,
+            139, 0, 0, 0, 0, 0, 140, 141, 0, 0, 0, 0, 142, 0, 0, 0, 0, 0, 0, 0, 0, 143, 0, 0, 0,
+            129, 144, 0, 145, 0, 113, 146, 0, 0, 147, 0, 0, 103, 148, 149, 150, 151, 152, 153, 154,
+            155, 0, 0, 0, 0, 0, 0, 0, 156, 0, 0, 0, 0, 0, 5, 1, 5, 5, 5, 5, 157, 0, 0, 158, 159,
+            160, 0, 160, 0, 5, 0, 5, 5, 0, 0, 0, 0, 161, 0, 0, 157, 0, 0, 0, 162, 163, 164, 165, 29,
+            166, 1, 0, 0, 0, 167, 168, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 5, 0, 1,
+            0, 161, 0, 0, 170, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 0, 0, 0, 0, 0, 0, 172, 173, 0, 0,
+            174, 175, 0, 0, 0, 176, 0, 177, 0, 0, 178, 0, 0, 0, 0, 0, 179, 180, 181, 0, 0, 0, 0,
+            180, 0, 0, 0, 0, 0, 0, 182, 183, 0, 180, 0, 0, 0, 0, 0, 0, 184, 185, 186, 176, 0, 181,
+            0, 187, 188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 189, 190, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 191, 0, 0, 0, 0, 0, 0, 0, 172, 0, 192, 0, 0, 0, 5, 193, 194, 195, 0, 0,
+            29, 196, 0, 197, 198, 199, 0, 0, 0, 0, 5, 0, 0, 200, 0, 201, 202, 0, 0, 203, 204, 205,
+            60, 4, 206, 207, 208, 209, 0, 3, 0, 210, 0, 211, 212, 213, 0, 214, 0, 121, 215, 129,
+            216, 127, 0, 37, 60, 29, 217, 0, 0, 218, 0, 219, 0, 23, 0, 219, 220, 143, 221, 0, 215,
+            60, 213, 0, 0, 221, 222, 0, 0, 0, 0, 223, 224, 0, 0, 0, 0, 0, 0, 0, 225, 226, 0, 0, 66,
+            227, 0, 0, 228, 219, 0, 0, 217, 23, 229, 230, 60, 127, 0, 231, 23, 232, 208, 0, 0, 233,
+            0, 234, 0, 0, 0, 0, 235, 236, 4, 0, 199, 0, 0, 0, 0, 0, 0, 0, 201, 47, 202, 237, 0, 203,
+            0, 74, 0, 238, 206, 207, 208, 205, 3, 239, 210, 232, 212, 213, 240, 214, 0, 0, 0, 0, 0,
+            241, 48, 0, 242, 0, 121, 228, 243, 244, 0, 245, 0, 246, 195, 0, 217, 0, 247, 248, 219,
+            222, 23, 229, 220, 129, 221, 16, 0, 0, 135, 0, 249, 250, 251, 0, 0, 252, 0, 0, 0, 0,
+            127, 77, 29, 0, 230, 0, 0, 28, 0, 253, 0, 254, 0, 135, 45, 45, 0, 0, 0, 0, 255, 256, 60,
+            138, 257, 218, 0, 0, 0, 0, 0, 0, 258, 259, 0, 0, 0, 0, 260, 261, 45, 45, 0, 0, 262, 263,
+            264, 119, 0, 0, 265, 266, 267, 268, 269, 270, 0, 0, 271, 272, 0, 0, 273, 274, 0, 0, 0,
+            0, 0, 0, 14, 19, 275, 276, 213, 129, 9, 120, 277, 0, 0, 0, 0, 0, 0, 0, 0, 278, 0, 245,
+            279, 280, 4, 0, 0, 0, 0, 281, 282, 283, 284, 283, 284, 285, 286, 287, 288, 4, 0, 0, 0,
+            0, 13, 18, 0, 0, 289, 125, 0, 0, 0, 0, 0, 0, 74, 145, 0, 0, 0, 0, 0, 0, 60, 138, 0, 0,
+            0, 0, 0, 0, 0, 290, 0, 0, 0, 0, 0, 0, 0, 0, 291, 0, 292, 293, 0, 0, 294, 127, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 295, 296, 135, 0, 0, 0, 0, 0, 297, 0, 0, 0, 0, 0, 0, 0, 0, 298, 0, 0,
+            0, 0, 0, 299, 0, 300, 301, 0, 236, 0, 0, 0, 240, 0, 3, 0, 0, 0, 0, 5, 0, 0, 5, 0, 0, 0,
+            135, 0, 295, 0, 0, 295, 0, 0, 0, 0, 0, 0, 0, 216, 0, 278, 0, 37, 0, 0, 302, 0, 0, 303,
+            0, 304, 0, 303, 143, 0, 0, 0, 52, 29, 0, 44, 219, 0, 305, 0, 161, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 185, 185, 0, 0, 0, 0, 0, 0, 306, 0, 0, 0, 307, 0, 0, 0, 0, 308, 309, 191, 0, 0,
+            0, 0, 310, 0, 0, 0, 0, 190, 190, 0, 0, 0, 0, 0, 4, 190, 190, 161, 190, 310, 0, 0, 0, 0,
+            0, 0, 0, 4, 0, 0, 218, 5, 0, 0, 0, 0, 0, 4, 0, 219, 0, 0, 0, 0, 0, 0, 78, 311, 1, 5, 1,
+            0, 0, 0, 0, 312, 313, 0, 0, 12, 0, 314, 185, 176, 177, 0, 0, 0, 0, 0, 0, 0, 0, 315, 316,
+            317, 318, 4, 0, 0, 0, 319, 0, 0, 0, 0, 0, 0, 0, 0, 320, 0, 0, 0, 0, 0, 0, 0, 0, 321, 0,
+            319, 0, 0, 0, 0, 0, 0, 0, 219, 0, 0, 322, 323, 0, 0, 185, 176, 0, 0, 191, 186, 0, 0,
+            324, 325, 172, 171, 172, 175, 310, 326, 0, 327, 328, 4, 0, 0, 0, 219, 0, 257, 207, 0, 2,
+            12, 329, 330, 322, 331, 0, 0, 315, 317, 0, 315, 316, 332, 318, 333, 334, 0, 0, 0, 319,
+            0, 0, 335, 0, 0, 0, 336, 0, 0, 337, 0, 0, 338, 0, 0, 339, 339, 0, 340, 341, 0, 0, 0, 0,
+            0, 342, 0, 0, 0, 0, 0, 343, 344, 0, 0, 0, 0, 345, 0, 0, 346, 345, 347, 347, 0, 0, 348,
+            0, 0, 0, 0, 0, 0, 349, 350, 0, 351, 0, 0, 322, 333, 0, 319, 219, 0, 0, 219, 352, 21, 0,
+            0, 353, 354, 355, 356, 0, 357, 322, 0, 358, 0, 359, 319, 322, 0, 162, 219, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 191, 174, 0, 0, 0, 190, 0, 360, 361, 328, 4, 362, 363, 364, 219, 365,
+            257, 207, 366, 0, 0, 0, 367, 368, 369, 0, 328, 328, 161, 161, 0, 0, 0, 190, 190, 370, 0,
+            0, 0, 0, 185, 0, 371, 319, 0, 0, 0, 358, 0, 0, 0, 372, 348, 0, 0, 0, 373, 374, 375, 0,
+            0, 376, 0, 0, 0, 0, 377, 335, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 378, 0, 3, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 171, 0, 190, 175, 0, 0, 0, 0, 379, 5, 5, 0, 0, 0,
+            0, 380, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 381, 0, 382, 0, 0, 383, 0, 0, 384, 385, 373,
+            0, 0, 0, 0, 386, 387, 388, 389, 390, 0, 0, 0, 391, 392, 0, 393, 394, 331, 331, 322, 395,
+            396, 397, 398, 399, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 400, 0, 0, 400, 0, 190, 379, 0, 310,
+            401, 0, 323, 400, 402, 176, 0, 0, 0, 0, 189, 403, 404, 0, 0, 0, 0, 189, 405, 174, 190,
+            161, 406, 0, 407, 0, 408, 0, 0, 0, 0, 409, 410, 0, 411, 412, 413, 414, 415, 0, 0, 0, 0,
+            0, 0, 416, 310, 0, 0, 417, 0, 0, 0, 0, 0, 172, 0, 418, 0, 419, 0, 0, 0, 0, 0, 0, 0, 0,
+            179, 184, 185, 0, 420, 421, 0, 0, 0, 0, 0, 422, 219, 366, 0, 74, 0, 0, 0, 0, 0, 0, 0, 0,
+            423, 424, 425, 426, 0, 0, 0, 0, 0, 0, 0, 113, 0, 0, 0, 174, 0, 0, 0, 0, 427, 0, 428,
+            428, 0, 0, 0, 0, 219, 0, 0, 0, 107, 0, 0, 429, 0, 0, 430, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            190, 431, 0, 0, 432, 433, 434, 435, 436, 0, 0, 0, 0, 437, 438, 0, 0, 0, 439, 440, 0, 0,
+            0, 0, 0, 0, 441, 0, 0, 442, 443, 0, 0, 0, 444, 0, 0, 445, 0, 0, 0, 446, 0, 0, 447, 0, 0,
+            0, 0, 0, 0, 448, 0, 0, 449, 0, 0, 0, 310, 0, 0, 417, 0, 420, 421, 0, 0, 0, 0, 418, 450,
+            0, 0, 451, 0, 452, 0, 0, 0, 0, 0, 0, 0, 0, 219, 429, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0,
+            453, 0, 0, 0, 0, 0, 0, 174, 190, 161, 0, 0, 454, 0, 0, 0, 0, 0, 0, 455, 0, 456, 457,
+            458, 0, 459, 460, 0, 0, 0, 0, 0, 226, 0, 0, 0, 0, 0, 461, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 310, 462, 0, 0, 0, 463, 464, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 452, 0, 0, 0, 0, 0, 0, 0,
+            0, 219, 0, 461, 74, 465, 226, 0, 0, 466, 0, 467, 0, 0, 0, 0, 0, 0, 0, 0, 174, 0, 107, 0,
+            0, 468, 0, 429, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0, 0, 0, 0, 0, 0, 0, 469, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 470, 0, 0, 0, 0, 0, 0, 0, 471, 0, 0, 0, 472, 0, 0, 0, 0, 0, 0, 0, 473, 0, 0,
+            0, 0, 0, 472, 0, 474, 0, 475, 476, 477, 190, 0, 0, 0, 0, 0, 0, 0, 0, 0, 478, 0, 0, 0, 0,
+            0, 0, 219, 479, 480, 0, 481, 482, 483, 484, 485, 0, 486, 0, 487, 0, 488, 489, 0, 490,
+            491, 0, 492, 0, 0, 0, 0, 0, 174, 0, 219, 431, 0, 0, 0, 0, 0, 0, 0, 493, 494, 0, 495, 0,
+            496, 0, 497, 0, 0, 498, 0, 0, 0, 0, 0, 0, 0, 499, 500, 0, 0, 0, 0, 0, 0, 0, 0, 501, 0,
+            502, 0, 0, 0, 0, 0, 0, 0, 0, 503, 0, 504, 0, 0, 0, 505, 506, 0, 0, 0, 0, 219, 0, 0, 174,
+            219, 431, 0, 507, 508, 509, 510, 0, 511, 493, 494, 0, 0, 512, 0, 0, 0, 0, 513, 514, 515,
+            516, 0, 0, 0, 517, 0, 0, 518, 0, 519, 0, 0, 0, 0, 0, 0, 520, 521, 522, 523, 524, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 525, 0, 526, 0, 527, 0, 0, 0, 0, 0, 0, 0, 0, 528, 0, 0, 0, 0,
+            529, 0, 0, 0, 0, 3, 530, 531, 0, 0, 0, 0, 0, 0, 532, 0, 174, 219, 431, 0, 0, 0, 0, 533,
+            480, 534, 0, 535, 0, 0, 0, 536, 0, 0, 537, 538, 0, 539, 0, 0, 535, 0, 0, 471, 0, 0, 77,
+            219, 0, 0, 540, 0, 541, 0, 0, 0, 542, 0, 0, 543, 0, 473, 0, 0, 0, 544, 0, 0, 0, 0, 545,
+            545, 0, 546, 546, 0, 0, 547, 548, 549, 0, 0, 0, 0, 0, 164, 0, 0, 0, 550, 0, 0, 0, 0,
+            551, 0, 552, 0, 0, 0, 0, 219, 0, 0, 0, 553, 554, 555, 429, 556, 557, 0, 0, 0, 0, 0, 0,
+            558, 0, 0, 535, 559, 557, 553, 560, 561, 0, 0, 0, 219, 431, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            562, 0, 0, 0, 0, 563, 564, 0, 0, 0, 0, 0, 0, 0, 0, 0, 565, 566, 0, 0, 567, 0, 0, 0, 0,
+            0, 0, 568, 0, 0, 0, 569, 0, 0, 0, 0, 570, 571, 572, 0, 0, 0, 573, 0, 0, 0, 574, 0, 0, 0,
+            0, 0, 0, 0, 575, 0, 0, 0, 576, 0, 0, 0, 0, 577, 577, 0, 578, 0, 579, 0, 0, 0, 0, 580, 0,
+            0, 0, 0, 581, 0, 0, 0, 0, 0, 582, 0, 0, 0, 583, 0, 0, 0, 0, 0, 0, 0, 191, 0, 0, 219, 0,
+            0, 0, 0, 0, 0, 0, 584, 0, 0, 0, 0, 585, 0, 0, 0, 0, 586, 587, 0, 588, 589, 590, 0, 0, 0,
+            591, 0, 0, 0, 0, 592, 593, 0, 0, 0, 0, 0, 0, 594, 595, 596, 597, 0, 191, 0, 0, 0, 0, 0,
+            0, 598, 599, 0, 0, 600, 0, 601, 602, 0, 0, 0, 0, 0, 0, 0, 0, 603, 0, 604, 0, 0, 0, 0,
+            605, 0, 0, 606, 607, 0, 0, 0, 0, 0, 0, 0, 608, 0, 0, 609, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 610, 0, 611, 0, 612, 0, 613, 0, 0, 0, 0, 0, 0, 0, 0, 614, 0, 0, 0, 0, 0, 0, 615,
+            616, 0, 617, 0, 542, 0, 229, 0, 0, 0, 618, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 219, 0,
+            619, 0, 0, 0, 620, 0, 0, 0, 0, 0, 621, 622, 0, 0, 0, 0, 0, 0, 623, 0, 0, 0, 0, 191, 0,
+            431, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 624, 0, 0, 0, 0, 0, 0, 229, 0, 0, 0, 0, 0, 0, 625, 0,
+            0, 0, 626, 627, 0, 0, 0, 0, 0, 0, 0, 0, 628, 629, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            630, 0, 0, 631, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 632, 0, 633, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 618, 542, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 129, 0, 0, 0, 145,
+            0, 0, 0, 0, 0, 634, 0, 0, 635, 0, 0, 0, 636, 0, 637, 0, 0, 638, 0, 0, 0, 0, 0, 639, 640,
+            641, 642, 643, 644, 645, 646, 647, 648, 649, 650, 651, 652, 653, 654, 655, 656, 657,
+            658, 659, 660, 661, 662, 663, 664, 665, 666, 667, 668, 669, 670, 671, 672, 673, 674,
+            675, 676, 677, 678, 646, 0, 679, 0, 680, 0, 681, 682, 683, 684, 685, 686, 687, 688, 689,
+            690, 691, 0, 692, 0, 693, 0, 694, 695, 696, 0, 0, 697, 698, 699, 0, 700, 701, 702, 703,
+            704, 0, 0, 0, 705, 0, 706, 0, 707, 0, 708, 0, 709, 710, 711, 0, 0, 712, 713, 714, 0,
+            715, 716, 717, 718, 719, 720, 721, 722, 723, 724, 725, 726, 727, 728, 729, 730, 731,
+            732, 733, 732, 734, 735, 736, 737, 738, 739, 740, 741, 742, 743, 744, 745, 746, 747,
+            748, 749, 750, 751, 752, 753, 754, 755, 756, 0, 757, 758, 759, 760, 761, 762, 763, 764,
+            765, 766, 634, 767, 768, 702, 703, 769, 770, 771, 772, 773, 774, 775, 776, 646, 777,
+            778, 653, 779, 637, 780, 781, 782, 783, 784, 785, 786, 787, 788, 639, 641, 701, 789,
+            790, 643, 704, 791, 644, 792, 793, 645, 794, 795, 796, 797, 798, 799, 800, 801, 802,
+            803, 804, 648, 805, 806, 807, 808, 809, 649, 810, 662, 811, 663, 665, 667, 668, 670,
+            812, 681, 813, 687, 814, 815, 685, 686, 697, 698, 816, 817, 818, 819, 820, 821, 822,
+            823, 824, 825, 640, 301, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 304, 0, 0, 0, 0, 240, 0, 0, 0, 0,
+            0, 0, 0, 826, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 827, 0, 0, 0, 828, 65, 212, 0, 61, 29, 0,
+            0, 192, 213, 201, 199, 203, 0, 113, 0, 192, 237, 0, 296, 0, 0, 0, 208, 0, 0, 0, 205, 0,
+            213, 60, 41, 292, 0, 278, 204, 0, 0, 0, 829, 0, 0, 0, 294, 114, 0, 830, 48, 0, 0, 65, 0,
+            296, 236, 0, 0, 0, 257, 236, 0, 0, 0, 831, 232, 0, 0, 210, 0, 0, 0, 206, 290, 0, 0, 0,
+            60, 0, 0, 228, 0, 215, 0, 130, 292, 202, 0, 0, 0, 0, 0, 0, 293, 243, 0, 0, 0, 832, 0, 0,
+            833, 0, 0, 0, 0, 0, 0, 0, 0, 834, 835, 836, 837, 838, 839, 840, 841, 842, 843, 0, 844,
+            845, 846, 847, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 200, 0, 0, 0, 848, 849, 850, 851,
+            257, 0, 207, 0, 159, 0, 0, 0, 852, 158, 0, 853, 854, 855, 856, 857, 858, 852, 859, 860,
+            861, 862, 863, 864, 865, 0, 0, 5, 0, 301, 0, 826, 0, 0, 0, 0, 0, 866, 0, 0, 867, 868,
+            869, 870, 871, 872, 866, 873, 874, 875, 876, 877, 878, 879, 0, 0, 880, 881, 882, 883, 0,
+            0, 210, 0, 290, 0, 0, 48, 884, 885, 886, 887, 888, 889, 890, 891, 892, 893, 894, 895,
+            896, 897, 898, 0, 0, 0, 899, 900, 901, 902, 0, 0, 0, 0, 199, 0, 0, 0, 0, 903, 904, 905,
+            906, 907, 908, 909, 910, 911, 912, 913, 914, 915, 916, 0, 0, 0, 0, 0, 237, 0, 0, 0, 0,
+            831, 0, 917, 918, 919, 920, 921, 922, 923, 924, 925, 926, 927, 32, 928, 929, 0, 0, 0, 0,
+            0, 66, 0, 930, 931, 932, 933, 934, 935, 0, 0, 936, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 937,
+            938, 939, 940, 941, 942, 943, 944, 945, 946, 947, 948, 949, 950, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 951, 952, 953, 954, 955, 956, 957, 958, 959, 960, 961, 962, 963, 964, 0, 0,
+            0, 0, 0, 0, 0, 0, 965, 966, 967, 968, 0, 0, 0, 0, 0, 0, 0, 969, 970, 971, 972, 973, 974,
+            975, 976, 977, 978, 979, 980, 981, 982, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 983, 984, 985,
+            986, 987, 988, 989, 990, 991, 992, 993, 994, 995, 996, 0, 0, 0, 997, 16, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 998, 999, 0, 0, 0, 1000, 1001, 0, 0, 0, 1002, 1003, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 216, 0, 0, 0, 0, 205, 16, 1004, 1005, 1006, 1007, 1008, 1009,
+            1010, 1011, 0, 65, 0, 0, 0, 0, 0, 0, 1012, 1013, 1014, 1015, 1016, 1017, 1018, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 224, 1019, 0, 1020, 0, 0, 0, 0, 1021, 201, 0, 0, 0,
+            0, 0, 0, 0, 0, 828, 0, 0, 0, 828, 0, 0, 0, 0, 829, 208, 0, 0, 0, 0, 0, 0, 202, 0, 0,
+            1022, 1023, 1024, 0, 0, 1025, 0, 0, 1026, 1027, 1028, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1029,
+            1030, 0, 0, 0, 0, 1029, 1030, 0, 0, 0, 0, 0, 0, 0, 214, 16, 1031, 1032, 1033, 1034,
+            1035, 1036, 1037, 1038, 1039, 1040, 1041, 1042, 1043, 1044, 1045, 0, 0, 7, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 158, 0, 0, 0, 0, 214, 0, 4, 1046, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0,
+            0, 0, 0, 0, 0, 206, 208, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 1047, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 200, 161, 1048, 0, 0, 0, 0, 0, 0, 1049, 1050, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1051, 0, 0,
+            0, 1052, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1053, 1054, 1055, 1056, 0, 0, 0, 0, 0, 0, 0,
+            0, 594, 0, 0, 0, 0, 0, 0, 0, 191, 1057, 1058, 0, 0, 0, 1059, 1060, 0, 0, 0, 0, 0, 0, 0,
+            0, 161, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1061, 0, 0, 0, 0, 0, 0, 0, 0, 1062, 0, 0, 0, 0, 1063,
+            0, 0, 1064, 0, 0, 1065, 0, 0, 0, 0, 0, 0, 0, 0, 1066, 0, 0, 0, 1067, 1068, 1069, 1070,
+            1071, 1072, 1073, 1074, 1075, 1076, 1077, 1078, 1079, 1080, 0, 0, 0, 0, 0, 0, 0, 168,
+            1081, 1082, 0, 0, 1083, 0, 0, 1084, 1085, 1086, 0, 0, 1087, 0, 1088, 0, 0, 1089, 0, 0,
+            1090, 0, 1091, 0, 0, 0, 0, 0, 1092, 1093, 0, 0, 0, 0, 0, 0, 1094, 0, 0, 0, 0, 0, 0, 0,
+            0, 1095, 0, 1096, 0, 0, 0, 0, 0, 0, 0, 0, 1097, 0, 0, 181, 0, 1098, 0, 0, 0, 0, 0, 0, 0,
+            0, 171, 0, 0, 0, 0, 1099, 1100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1101, 1102, 0, 0, 0, 0, 1103,
+            0, 0, 0, 1104, 0, 0, 1105, 0, 0, 0, 0, 1106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1107, 175, 0,
+            171, 1, 0, 1108, 0, 0, 1109, 1110, 1111, 0, 324, 310, 401, 0, 0, 0, 0, 0, 0, 1112, 0, 0,
+            0, 0, 0, 0, 229, 0, 0, 0, 246, 0, 0, 217, 0, 247, 0, 219, 196, 219, 0, 0, 1113, 0, 0, 0,
+            0, 0, 0, 0, 143, 0, 0, 0, 218, 135, 1114, 0, 227, 0, 244, 1115, 222, 1116, 0, 1117, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1118, 0, 0, 0, 1119, 0, 0, 0, 0, 0, 0, 0, 0, 1120, 0, 0,
+            1121, 1122, 1123, 0, 1124, 1125, 1126, 1127, 1128, 0, 1129, 0, 0, 131, 131, 1130, 1131,
+            1132, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0, 129, 0, 0, 0, 1133, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 1134, 0, 0, 1135, 0, 0, 0, 0, 0, 0, 0, 1136, 1137, 0, 0, 0, 0, 1138,
+            0, 0, 0, 0, 0, 1139, 0, 0, 0, 0, 1140, 0, 0, 0, 0, 0, 0, 1141, 0, 0, 44, 215, 0, 0, 0,
+            0, 0, 0, 1142, 0, 0, 0, 0, 0, 0, 0, 5, 29, 5, 166, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 5, 0, 0, 0, 0, 0, 0, 1143, 0, 0, 0, 0, 0, 0, 5, 5, 0, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+            7, 7, 0, 0, 0, 0, 0, 162, 162, 162, 162, 715, 715, 78, 0, 5, 5, 12, 5, 1, 1, 0, 1, 0, 0,
+            200, 0, 328, 1144, 1145, 200, 7, 7, 0, 0, 0, 0, 0, 7, 312, 313, 5, 1, 1146, 5, 1, 1146,
+            0, 158, 159, 0, 1147, 0, 10, 0, 0, 1050, 0, 162, 1050, 0, 0, 1148, 1149, 1150, 0, 0, 0,
+            0, 330, 0, 0, 0, 2, 166, 0, 0, 0, 1151, 0, 0, 161, 0, 0, 1152, 1153, 7, 1119, 0, 0, 0,
+            0, 0, 0, 0, 0, 1154, 0, 0, 0, 0, 0, 0, 0, 1155, 0, 0, 1156, 1157, 0, 0, 1158, 1159, 0,
+            1160, 235, 271, 1161, 0, 0, 0, 0, 0, 0, 0, 1162, 0, 0, 0, 0, 0, 0, 1163, 0, 0, 0, 1164,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1097, 0, 0, 0, 0, 1165, 1166, 232, 1167, 0, 1168, 1169, 294,
+            1170, 1171, 52, 205, 205, 205, 278, 28, 4, 4, 831, 4, 0, 209, 1172, 0, 0, 210, 1173, 65,
+            65, 65, 0, 0, 0, 1174, 0, 0, 204, 0, 1030, 1029, 204, 1175, 206, 0, 202, 232, 245, 245,
+            203, 224, 0, 208, 219, 1176, 1177, 1178, 1179, 29, 0, 1180, 222, 129, 237, 239, 211,
+            1181, 1182, 1183, 0, 828, 290, 245, 29, 230, 0, 0, 0, 0, 0, 0, 4, 78, 1184, 1185, 257,
+            1186, 1187, 1188, 1189, 214, 1190, 1191, 831, 232, 828, 208, 29, 1192, 1193, 1194, 218,
+            1195, 1196, 1197, 1198, 16, 1199, 1200, 4, 229, 290, 6, 0, 0, 0, 233, 196, 0, 0, 0, 0,
+            1201, 0, 0, 0, 1202, 0, 0, 0, 0, 0, 0, 0, 1203, 0, 0, 0, 0, 1204, 0, 0, 0, 1205, 1206,
+            0, 0, 0, 0, 1207, 0, 0, 0, 0, 0, 0, 0, 1208, 0, 0, 0, 1021, 0, 0, 1209, 0, 0, 833, 0, 0,
+            0, 0, 0, 0, 0, 0, 239, 0, 211, 162, 0, 1210, 1050, 1211, 330, 165, 200, 0, 0, 0, 0,
+            1212, 0, 0, 0, 0, 4, 0, 78, 0, 0, 218, 826, 301, 1213, 1214, 1215, 0, 1216, 1217, 0, 0,
+            0, 0, 0, 161, 0, 1218, 0, 0, 0, 166, 0, 0, 0, 1219, 1220, 0, 0, 0, 0, 0, 1221, 0, 1222,
+            1223, 0, 0, 0, 1224, 0, 0, 0, 0, 1225, 0, 0, 0, 0, 0, 0, 1226, 1227, 0, 0, 0, 0, 0, 0,
+            1228, 1229, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1230, 60, 0, 0, 1231, 0, 0, 0, 60, 0, 0, 0, 0, 0,
+            0, 212, 1232, 0, 0, 1233, 218, 826, 301, 1234, 200, 0, 0, 1235, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 863, 852, 1236, 1237, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1152, 1238, 0,
+            0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 203, 1239, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 1240, 0, 0, 0, 1241, 1242, 0, 0, 0, 0, 0, 0, 1243, 0, 0, 0, 0, 0, 0, 0, 1244, 1245,
+            0, 1246, 0, 0, 1247, 0, 1248, 1249, 1250, 1251, 21, 0, 0, 1252, 1253, 0, 1254, 60, 0, 0,
+            0, 0, 0, 0, 29, 23, 1255, 1256, 1257, 1258, 1259, 121, 0, 0, 0, 0, 1046, 0, 0, 0, 0,
+            717, 0, 0, 717, 0, 0, 717, 0, 0, 717, 0, 0, 0, 0, 717, 0, 0, 0, 717, 0, 0, 1260, 1261,
+            1262, 0, 0, 1263, 0, 1264, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1265, 4, 1266, 0, 0, 0,
+            1267, 0, 0, 0, 0, 0, 1268, 1269, 1270, 1271, 1272, 1273, 1274, 1275, 1276, 1277, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 1278, 1279, 1280, 1281, 1282, 1283, 1284, 1285, 1286, 1287,
+            1288, 1289, 1290, 1291, 1292, 1293, 1294, 1295, 1296, 1297, 1298, 1299, 1300, 1301,
+            1302, 1303, 1304, 1305, 1306, 1307, 1308, 1309, 1310, 1311, 1312, 1313, 1314, 1315,
+            1316, 1317, 1318, 1319, 1320, 1321, 1322, 1323, 1324, 1325, 1326, 1327, 1328, 1278,
+            1329, 1330, 1331, 1332, 1333, 1334, 1335, 1336, 1337, 1338, 1339, 1340, 1341, 1342, 0,
+            0, 1343, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1344, 0, 1345, 0, 0, 0, 1346, 0, 0, 0, 0,
+            0, 0, 1347, 0, 0, 0, 0, 0, 715, 715, 0, 1348, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1349, 0,
+            0, 0, 1350, 0, 0, 0, 0, 0, 0, 1351, 0, 0, 0, 0, 0, 0, 1050, 0, 214, 0, 0, 0, 0, 1352, 0,
+            0, 0, 0, 0, 0, 0, 1353, 0, 0, 0, 10, 0, 0, 1354, 0, 0, 0, 0, 0, 1355, 0, 0, 0, 1356, 0,
+            833, 0, 0, 0, 1357, 1358, 0, 1358, 0, 0, 1359, 0, 0, 0, 1360, 0, 0, 0, 0, 0, 1234, 0, 0,
+            1234, 165, 0, 0, 1361, 0, 1362, 0, 0, 0, 0, 0, 165, 0, 0, 1231, 0, 0, 0, 0, 0, 0, 1363,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1364, 0, 0, 1365, 0, 0, 0, 0, 0, 0, 0, 1366, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1367, 0, 0, 0, 0, 0, 0, 0, 0, 1368, 0, 0, 0, 0, 0,
+            1369, 0, 0, 0, 0, 1234, 0, 0, 0, 0, 0, 0, 1370, 1371, 0, 0, 0, 0, 0, 0, 0, 0, 0, 467, 0,
+            0, 0, 1372, 1373, 0, 0, 0, 0, 158, 159, 0, 0, 1372, 1373, 1374, 1375, 0, 0, 0, 0, 0, 0,
+            0, 1048, 162, 1376, 0, 0, 1232, 0, 0, 0, 0, 0, 1377, 1378, 0, 1050, 0, 1211, 0, 0, 0,
+            212, 0, 0, 0, 0, 0, 0, 1241, 1242, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1379, 0, 0, 0,
+            16, 16, 0, 0, 0, 0, 0, 0, 1380, 0, 1381, 0, 0, 0, 0, 0, 0, 0, 0, 1382, 1383, 0, 1153, 0,
+            0, 0, 0, 0, 0, 1384, 0, 0, 0, 0, 1385, 0, 0, 0, 0, 0, 0, 0, 0, 1361, 0, 0, 0, 0, 0,
+            1243, 1386, 0, 1387, 0, 0, 0, 0, 0, 0, 1388, 0, 0, 1389, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1390, 1211, 1391, 0, 1050, 1211, 0, 0, 0, 0, 0, 0, 1231, 1230, 1392, 1393, 1394, 1395,
+            1396, 0, 0, 0, 0, 0, 1397, 0, 0, 0, 0, 0, 0, 0, 0, 1235, 0, 0, 1227, 1205, 1398, 1399,
+            1400, 1401, 1402, 1403, 0, 1404, 1405, 0, 0, 0, 0, 16, 1406, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 1407, 1408, 1409, 0, 0, 1410, 0, 0, 0, 1411, 0, 0, 0, 0, 0, 1412, 1413, 1414,
+            0, 0, 0, 0, 0, 0, 1415, 0, 0, 0, 0, 1416, 1417, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1418,
+            0, 0, 0, 1419, 0, 1420, 0, 0, 0, 0, 0, 0, 0, 0, 1421, 0, 0, 0, 0, 0, 1422, 1423, 1424,
+            1425, 0, 0, 0, 0, 0, 0, 0, 273, 0, 269, 0, 0, 0, 0, 0, 0, 0, 0, 202, 243, 237, 244, 833,
+            0, 235, 127, 0, 223, 1426, 1427, 205, 248, 60, 138, 4, 29, 206, 217, 1428, 1115, 208,
+            247, 209, 1429, 74, 1430, 3, 219, 239, 222, 210, 23, 232, 229, 212, 220, 213, 129, 240,
+            221, 214, 1431, 255, 256, 1432, 1255, 1218, 1218, 863, 863, 1365, 1225, 0, 0, 162, 162,
+            1433, 135, 0, 0, 1163, 1434, 0, 0, 74, 145, 1050, 1050, 0, 0, 429, 429, 74, 145, 210,
+            23, 831, 1435, 228, 228, 0, 0, 0, 0, 0, 0, 0, 0, 228, 216, 0, 0, 221, 221, 0, 0, 1436,
+            0, 0, 0, 1163, 1437, 0, 0, 0, 0, 0, 0, 0, 1267, 0, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0,
+            207, 257, 203, 1209, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 1238, 211, 0, 0, 0, 0, 0,
+            4, 0, 79, 0, 0, 3, 1173, 0, 0, 0, 1231, 0, 0, 0, 214, 0, 0, 833, 0, 0, 1438, 0, 0, 0, 0,
+            1439, 0, 191, 0, 0, 1440, 1441, 1442, 0, 0, 0, 0, 0, 0, 1443, 1444, 0, 0, 1445, 0, 0, 0,
+            1410, 1446, 0, 0, 0, 0, 0, 0, 1228, 1229, 1447, 1448, 1449, 1450, 1451, 0, 1452, 0, 165,
+            200, 329, 0, 0, 1453, 0, 0, 0, 216, 0, 0, 0, 1153, 0, 1454, 832, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 1455, 1456, 0, 1457, 0, 0, 0, 1458, 0, 1459, 0, 0, 1460, 1461, 1462, 0, 1463, 1464,
+            1465, 0, 1466, 1467, 1468, 1469, 0, 1470, 0, 0, 1471, 1472, 1473, 1474, 1475, 1476,
+            1477, 0, 1478, 0, 1479, 0, 0, 1480, 0, 1481, 0, 1482, 0, 1483, 1480, 0, 0, 0, 0, 0, 0,
+            1484, 1485, 0, 0, 0, 1486, 1486, 1486, 1487, 1488, 1489, 1490, 1491, 0, 0, 1492, 1493,
+            0, 1494, 1495, 1495, 0, 1496, 1497, 1498, 1499, 1500, 1501, 0, 1496, 0, 1502, 1503,
+            1504, 1505, 1506, 1507, 0, 1508, 1509, 0, 1510, 0, 1511, 1512, 0, 0, 1513, 1514, 0,
+            1515, 1516, 1517, 1518, 1519, 1520, 0, 1521, 1522, 0, 0, 0, 0, 715, 717, 1211, 1050,
+            1523, 1524, 1525, 1526, 1527, 1528, 1529, 1530, 1531, 1532, 1533, 1534, 1535, 1536,
+            1537, 1538, 1539, 1540, 1541, 1542, 1543, 1544, 1545, 1546, 1547, 1548, 1548, 1549,
+            1549, 1550, 1551, 1552, 1553, 1554, 1555, 1556, 1557, 1558, 1462, 1559, 1560, 1561,
+            1562, 1563, 1564, 1565, 1566, 1464, 1567, 1568, 1569, 1570, 1571, 1572, 1573, 1574,
+            1575, 1576, 1577, 1578, 1579, 1580, 1581, 1582, 1583, 1584, 1585, 1586, 1587, 1588,
+            1589, 1590, 1591, 1592, 1593, 1594, 1595, 1596, 1597, 1598, 1599, 1600, 1601, 1602,
+            1603, 1604, 1605, 1606, 1607, 1608, 1609, 1610, 1611, 1612, 1613, 1614, 1615, 1616,
+            1617, 1618, 1619, 1620, 1621, 1622, 1623, 1624, 1625, 1626, 1627, 1628, 1629, 1630,
+            1631, 1632, 1633, 1634, 1635, 1636, 1637, 1638, 1639, 1640, 1641, 1642, 1643, 1644,
+            1645, 1646, 1647, 1648, 1649, 1650, 1651, 1652, 1653, 1654, 1655, 1656, 1657, 1658,
+            1659, 1660, 1661, 1662, 1663, 1664, 1665, 1666, 1667, 1668, 1669, 1670, 1671, 1672,
+            1673, 1674, 1675, 1676, 1677, 1678, 1679, 1680, 1681, 1498, 1682, 1683, 1684, 1685,
+            1686, 1687, 1688, 1689, 1690, 1691, 1692, 1693, 1694, 1695, 1696, 1507, 1697, 1698,
+            1699, 1700, 1701, 1702, 1703, 1704, 1705, 1511, 1706, 1707, 1708, 1709, 1710, 1711,
+            1712, 1713, 1714, 1715, 1716, 1717, 1718, 1719, 1720, 1721, 1722, 1723, 1724, 1725, 0,
+            0, 0, 0, 1726, 1, 0, 0, 0, 3, 0, 0, 1727, 0, 1372, 1373, 0, 0, 0, 0, 1728, 1729, 0, 0,
+            0, 0, 0, 0, 0, 0, 1730, 608, 0, 0, 0, 0, 0, 1050, 0, 0, 1727, 0, 1542, 1731, 1732, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1241, 0, 0, 191, 1733, 1734, 0, 0, 0, 832, 0, 0, 0, 1457,
+            0, 0, 0, 1563, 0, 0, 1537, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1552, 1543, 0, 0, 1525, 0,
+            0, 1050, 1530, 1735, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1548, 0, 0, 0, 0, 0, 200, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 1528, 0, 0, 766, 634, 767, 768, 702, 703, 769, 635, 770, 771,
+            772, 773, 774, 775, 776, 646, 777, 778, 636, 653, 779, 637, 780, 781, 638, 782, 783,
+            784, 785, 786, 1736, 705, 1737, 706, 1738, 707, 1739, 708, 1740, 709, 710, 711, 1741,
+            1742, 712, 713, 714, 1743, 715, 716, 717, 1744, 640, 641, 701, 789, 791, 792, 796, 800,
+            802, 648, 805, 807, 649, 650, 652, 654, 655, 659, 661, 663, 664, 665, 666, 667, 670,
+            674, 812, 687, 815, 685, 686, 698, 699, 820, 732, 733, 735, 744, 745, 747, 1745, 759, 0,
+            715, 717, 0, 1050, 1211, 0, 1455, 0, 0, 0, 1524, 1241, 0, 0, 0, 1456, 1523, 0, 0, 0, 0,
+            0, 0, 0, 1746, 1747, 1748, 1749, 1750, 1751, 1752, 1753, 1754, 1755, 1756, 1757, 1758,
+            1759, 1760, 1761, 1762, 1763, 1764, 1765, 1766, 1767, 1768, 1769, 1770, 1771, 1772,
+            1773, 1774, 1775, 1776, 0, 1777, 1778, 1779, 1780, 1781, 1782, 1783, 1784, 1785, 1786,
+            1787, 1788, 1789, 1790, 1791, 1792, 1793, 1794, 1795, 1796, 1797, 1798, 1799, 1800,
+            1801, 1802, 1803, 1804, 1805, 1806, 1807, 1808, 1809, 1810, 1811, 1812, 0, 0, 0, 0,
+            1813, 1814, 1815, 1816, 1817, 1818, 1819, 1820, 1821, 1822, 1823, 1824, 0, 0, 0, 0,
+            1825, 1826, 1827, 1828, 1829, 1830, 1831, 1832, 1833, 1834, 1835, 1836, 1837, 1838,
+            1839, 1840, 1841, 1842, 1843, 1844, 1845, 1846, 1847, 1848, 1849, 0, 0, 0, 0, 0, 0, 0,
+            1850, 1851, 1852, 1853, 1854, 1855, 1856, 1857, 1858, 1859, 1860, 1861, 1862, 1863,
+            1864, 1865, 1866, 1867, 1868, 1869, 1870, 1871, 1872, 1873, 1874, 1875, 1876, 1877,
+            1878, 1879, 1880, 0, 0, 0, 0, 1881, 0, 0, 0, 0, 0, 0, 0, 1882, 0, 0, 0, 0, 1883, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1884, 0, 0, 715, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1885,
+            0, 0, 0, 0, 0, 1886, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1211, 0, 0, 0, 0, 0, 0, 0, 0, 1050,
+            0, 0, 0, 0, 0, 0, 1887, 0, 0, 0, 1888, 0, 0, 0, 0, 0, 0, 0, 1889, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 1890, 0, 0, 0, 0, 0, 1891, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1892, 1893, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1894, 0, 0, 0, 0, 0, 0, 0, 1548, 0, 0, 0, 1895, 0,
+            0, 0, 0, 0, 0, 0, 1549, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1896, 1897, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 1898, 0, 0, 0, 0, 0, 1899, 0, 0, 0, 0, 0, 0, 0, 1900, 0, 0, 0, 0, 0, 0, 0,
+            1577, 0, 0, 0, 0, 0, 0, 0, 0, 1901, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1902, 0, 0, 0, 0, 0,
+            1903, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1904, 0, 0, 0, 0, 0, 0, 0, 1905, 0, 0, 0, 0,
+            0, 0, 0, 0, 1906, 0, 1907, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1908, 0, 1909, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1604, 0, 0, 0, 0, 0, 0, 0, 1910, 0, 0, 0, 0, 0, 0,
+            1911, 0, 0, 0, 0, 0, 0, 0, 1912, 0, 0, 0, 1913, 0, 0, 0, 0, 0, 1914, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 1915, 0, 0, 1916, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1917, 0,
+            1918, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1919, 0, 0, 0, 0, 0, 0, 0, 1920, 0, 1921,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1922, 0, 0, 0, 0, 0, 0, 1923, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 1924, 0, 0, 0, 1925, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1926, 0, 0, 0, 1927, 0, 0, 0,
+            0, 0, 0, 0, 0, 1928, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1929, 0, 0, 0, 0, 0, 0, 1930, 0,
+            0, 0, 0, 0, 0, 0, 1931, 0, 0, 0, 0, 0, 0, 0, 0, 1498, 1932, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 1933, 0, 0, 0, 0, 0, 0, 1714, 0, 0, 0, 0, 0, 0, 0, 0, 1934, 0, 0, 0, 0, 0, 0, 0, 0,
+            1935, 0, 0, 0, 0, 0, 0, 0, 1936, 0, 1937, 0, 0, 0, 0, 0, 0, 0, 0, 1938, 1939, 0, 0, 0,
+            1940, 0, 0, 0, 1941, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1942, 0, 0, 0, 1943, 1944, 1945, 0,
+            1946, 0, 0, 0, 0, 0, 202, 210, 290, 828, 212, 0, 292, 206, 0, 199, 232, 233, 204, 224,
+            1019, 208, 209, 831, 236, 65, 0, 207, 257, 205, 0, 0, 296, 214, 213, 1947, 201, 1021,
+            203, 1209, 4, 3, 301, 826, 0, 1948, 328, 12, 1144, 1949, 0, 161, 1950, 832, 0, 0, 0, 0,
+            0, 0, 328, 0, 0, 0, 0, 0, 66, 227, 0, 29, 0, 0, 0, 0, 0, 1255, 0, 0, 1951, 1952, 0, 0,
+            0, 0, 0, 0, 1231, 0, 0, 0, 0, 0, 0, 1953, 0, 0, 0, 0, 172, 0, 163, 0, 0, 0, 0, 0, 0,
+            130, 0, 0, 1954, 1212, 1230, 0, 0, 0, 0, 0, 0, 194, 0, 0, 0, 0, 0, 0, 1955, 192, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1956, 207, 0, 0, 0, 0, 239, 0, 0, 0, 257, 0, 0, 0, 113, 0, 0,
+            0, 66, 175, 171, 0, 0, 1957, 0, 0, 0, 0, 0, 0, 0, 1958, 0, 170, 0, 1959, 1960, 0, 0, 0,
+            0, 0, 0, 0, 77, 1961, 1962, 1963, 1964, 1965, 1966, 1967, 1968, 1967, 1968, 1969, 1970,
+            0, 0, 271, 0, 0, 0, 0, 0, 0, 0, 0, 0, 60, 138, 0, 0, 1954, 1212, 0, 0, 66, 0, 0, 0, 0,
+            0, 0, 1971, 0, 0, 0, 0, 0, 0, 0, 0, 74, 145, 0, 0, 429, 0, 0, 0, 0, 0, 0, 0, 0, 1972,
+            1973, 0, 1974, 0, 0, 0, 0, 0, 0, 161, 0, 0, 5, 0, 0, 200, 0, 0, 0, 0, 0, 1975, 0, 0,
+            224, 44, 1976, 1977, 0, 1978, 1979, 143, 0, 0, 0, 74, 0, 0, 0, 0, 0, 1232, 199, 214,
+            202, 215, 1432, 1255, 0, 0, 0, 0, 0, 0, 0, 146, 0, 0, 1980, 0, 1981, 0, 215, 0, 0, 0,
+            207, 1428, 1982, 0, 0, 0, 0, 168, 0, 0, 0, 0, 0, 715, 1983, 0, 0, 0, 0, 0, 0, 0, 1984,
+            1985, 1986, 1987, 771, 1988, 792, 1989, 772, 773, 1990, 798, 774, 1991, 801, 803, 1992,
+            805, 1993, 1994, 811, 1995, 1996, 1997, 1998, 1999, 2000, 2001, 2002, 0, 0, 0, 0, 0,
+            2003, 0, 0, 0, 0, 0, 0, 0, 0, 2004, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2005, 0, 0, 0, 0, 0,
+            0, 0, 0, 362, 0, 0, 0, 2006, 0, 0, 2007, 0, 0, 0, 245, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0,
+            219, 22, 2008, 0, 2009, 2010, 0, 0, 0, 0, 244, 244, 0, 0, 0, 0, 1213, 143, 0, 0, 0, 143,
+            1431, 0, 1431, 0, 0, 0, 0, 129, 0, 0, 0, 0, 0, 2011, 0, 2012, 2013, 0, 0, 0, 0, 2014,
+            2015, 220, 0, 2016, 29, 0, 0, 0, 0, 2017, 2018, 2019, 0, 2020, 0, 2021, 244, 0, 135, 0,
+            0, 0, 247, 0, 0, 0, 248, 0, 0, 138, 0, 293, 0, 0, 1114, 0, 0, 0, 0, 0, 0, 0, 127, 1131,
+            0, 0, 251, 0, 0, 2015, 0, 0, 0, 0, 0, 0, 218, 77, 0, 0, 0, 1435, 229, 0, 0, 1116, 0, 0,
+            0, 217, 0, 0, 0, 0, 138, 0, 0, 0, 0, 2022, 2023, 2024, 2025, 2026, 2027, 2028, 2029,
+            2030, 2031, 2032, 2033, 2034, 2035, 2036, 2037, 2038, 2039, 2040, 2041, 2042, 2043,
+            2044, 0, 0, 0, 0, 2045, 2046, 635, 2047, 1985, 1986, 2048, 1987, 2049, 2050, 1988, 2051,
+            2052, 2053, 2054, 2055, 2056, 2057, 647, 2058, 2059, 2060, 2061, 2062, 652, 2063, 2064,
+            636, 655, 659, 660, 669, 2065, 2066, 2067, 2068, 674, 675, 677, 646, 2069, 2070, 2071,
+            2072, 2073, 2074, 638, 2075, 2076, 0, 0, 0, 0, 2077, 2078, 1673, 2079, 2080, 2081, 2082,
+            1724, 1724, 2083, 1681, 2084, 2085, 2086, 2087, 2088, 2089, 2090, 2091, 2092, 2093,
+            2094, 2095, 2096, 2097, 2098, 2099, 2100, 2101, 2102, 2103, 2104, 2105, 2106, 2107,
+            2108, 2109, 2110, 2111, 2112, 2113, 2114, 2115, 2116, 1930, 2117, 2118, 2119, 2120,
+            2121, 2122, 2123, 1639, 2124, 2125, 2126, 2127, 2128, 2129, 2130, 2131, 2132, 2133,
+            2134, 1709, 2135, 2136, 2137, 2138, 2139, 2140, 2141, 2142, 2143, 2144, 2145, 2146,
+            2147, 2148, 2149, 2150, 2151, 2152, 2153, 2154, 2155, 2156, 2157, 2158, 2159, 2160,
+            2161, 2093, 2162, 2163, 2164, 2165, 2166, 2167, 2168, 2169, 2170, 2171, 2172, 2173,
+            2174, 2175, 2176, 2177, 2178, 2179, 2180, 2181, 1675, 2182, 2183, 2184, 2185, 2186,
+            2187, 2188, 2189, 2190, 2191, 2192, 2193, 2194, 2195, 2196, 1554, 2197, 2198, 2199,
+            2200, 2201, 2202, 2203, 2204, 1537, 2205, 2206, 2207, 2208, 2209, 2210, 2211, 2212,
+            2213, 2214, 2215, 2216, 2217, 2218, 2219, 2220, 2221, 2222, 2223, 2224, 2225, 2226,
+            2180, 2227, 2228, 2229, 2230, 2231, 2232, 2233, 2234, 2164, 2235, 2236, 2237, 2238,
+            2239, 2240, 2241, 2242, 2243, 2244, 2245, 2246, 2247, 1932, 2248, 2249, 2250, 2251,
+            2252, 2253, 2093, 2254, 2255, 2256, 2257, 1723, 2258, 2259, 2260, 2261, 2262, 2263,
+            2264, 2265, 2266, 2267, 2268, 2269, 2270, 2271, 2272, 2273, 2274, 2275, 2276, 2277,
+            2278, 2279, 2166, 2280, 2281, 2282, 2283, 2284, 2285, 2286, 2287, 2288, 2289, 2290,
+            2291, 2292, 1680, 2293, 2294, 2295, 2296, 2297, 2298, 2299, 2300, 2301, 2302, 2303,
+            2304, 2305, 1631, 2306, 2307, 2308, 2309, 2310, 2311, 2312, 2313, 2314, 2315, 2316,
+            2317, 2318, 2319, 2320, 2321, 1658, 2322, 1661, 2323, 1460, 2324, 0, 0, 2325, 0, 2326,
+            0, 0, 2327, 2328, 2329, 2330, 2331, 2332, 2333, 2334, 2335, 1638, 0, 2336, 0, 2337, 0,
+            0, 2338, 2339, 0, 0, 0, 2340, 2341, 2342, 2343, 1930, 1932, 2344, 2345, 2346, 2347,
+            2348, 2349, 2350, 2351, 2352, 2353, 2354, 2355, 1560, 2356, 2357, 2358, 2359, 2360,
+            2361, 2362, 2363, 2364, 2365, 2366, 2367, 1477, 2368, 2369, 2370, 2371, 2372, 2373,
+            2374, 2375, 2376, 2377, 2378, 2379, 2380, 2216, 2381, 2382, 2383, 2384, 2385, 1486,
+            1486, 2386, 2387, 2388, 2389, 2390, 2391, 2392, 1495, 2338, 2393, 2394, 2395, 2396,
+            2397, 2398, 0, 0, 2399, 2400, 2401, 2402, 2403, 2404, 2405, 2406, 2350, 2407, 2408,
+            2409, 2325, 2410, 2411, 2412, 2413, 2414, 2415, 2416, 2417, 2418, 2419, 2420, 2421,
+            2358, 2422, 2359, 2423, 2424, 2425, 2426, 2427, 2326, 2114, 2428, 2429, 1592, 2181,
+            2263, 2430, 2431, 2366, 2432, 2367, 2433, 2434, 2435, 2328, 2436, 2437, 2438, 2439,
+            2440, 2329, 2441, 2442, 2443, 2444, 2445, 2446, 2380, 2447, 2448, 2216, 2449, 2384,
+            2450, 2451, 2452, 2453, 2454, 2388, 2455, 2337, 2456, 2389, 2162, 2457, 2390, 2458,
+            2392, 2459, 2460, 2461, 2462, 2463, 2393, 2334, 2464, 2394, 2465, 2395, 2466, 1724,
+            2467, 2468, 2469, 2470, 2471, 1934, 2472, 2473, 2474, 2475, 2476, 0, 0, 0, 0, 0, 0,
+            2477, 2478, 2479, 2480, 2481, 0, 2482, 0, 0, 0, 0, 2483, 2484, 2485, 2486, 2487, 2488,
+            1176, 1179, 2489, 2490, 2491, 2492, 2493, 2494, 1218, 0, 2495, 0, 2496, 0, 2497, 2497,
+            0, 0, 0, 0, 0, 0, 0, 0, 2498, 0, 0, 0, 0, 0, 0, 0, 2495, 0, 0, 0, 0, 0, 2499, 2500,
+            2500, 359, 359, 359, 359, 319, 319, 319, 319, 2501, 2501, 2501, 2501, 334, 334, 334,
+            334, 2502, 2502, 2502, 2502, 333, 333, 333, 333, 345, 345, 345, 345, 2503, 2503, 2503,
+            2503, 2504, 2504, 2504, 2504, 2505, 2505, 2505, 2505, 2506, 2506, 2506, 2506, 2507,
+            2507, 2507, 2507, 2508, 2508, 2509, 2509, 339, 339, 337, 337, 342, 342, 340, 340, 347,
+            347, 347, 347, 388, 388, 388, 388, 2510, 2510, 2510, 2510, 2511, 2511, 2511, 2511, 322,
+            322, 333, 333, 333, 333, 352, 352, 219, 219, 219, 219, 219, 219, 219, 219, 322, 322,
+            2512, 2512, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 348, 348, 348, 354, 354, 353, 353, 355, 355,
+            332, 357, 357, 2513, 2513, 356, 356, 359, 359, 359, 359, 322, 322, 2514, 2514, 2515,
+            2515, 2516, 2516, 2517, 2517, 2518, 2518, 2519, 2519, 2520, 2520, 2520, 2521, 2521,
+            2521, 322, 322, 322, 322, 2522, 2523, 2524, 2521, 2521, 2525, 2526, 2527, 2528, 2529,
+            2529, 2530, 2531, 2532, 2533, 2534, 2534, 2535, 2536, 2537, 2537, 2538, 2539, 2540,
+            2541, 2542, 2543, 2544, 2545, 2546, 2547, 2548, 2549, 2550, 2551, 2552, 2553, 2554,
+            2555, 2556, 2557, 2558, 2559, 2560, 2561, 2562, 2563, 2564, 2565, 2566, 2566, 2567,
+            2568, 2569, 2569, 2570, 2571, 2572, 2573, 2574, 2575, 2576, 2576, 2577, 2578, 2579,
+            2580, 2581, 2581, 2582, 2583, 2584, 2585, 2586, 2586, 2527, 2587, 2588, 2589, 2590,
+            2590, 2591, 2592, 2593, 2593, 2594, 2595, 2596, 2597, 2598, 2598, 2599, 2600, 2601,
+            2602, 2603, 2604, 2605, 2606, 2607, 2608, 2609, 2524, 2610, 2521, 2521, 2611, 2612,
+            2528, 2613, 2529, 2529, 2614, 2615, 2533, 2616, 2534, 2534, 2617, 2618, 2536, 2619,
+            2537, 2537, 2566, 2566, 2569, 2569, 2570, 2574, 2575, 2576, 2576, 2580, 2581, 2581,
+            2620, 2585, 2621, 2622, 2589, 2623, 2590, 2590, 2601, 2624, 2625, 2597, 2626, 2598,
+            2598, 2522, 2523, 2627, 2524, 2515, 2525, 2526, 2527, 2528, 2628, 2530, 2531, 2532,
+            2533, 2629, 2536, 2538, 2539, 2540, 2541, 2542, 2544, 2545, 2546, 2547, 2548, 2549,
+            2630, 2550, 2551, 2552, 2553, 2554, 2555, 2557, 2558, 2559, 2560, 2561, 2562, 2563,
+            2564, 2565, 2567, 2568, 2571, 2572, 2573, 2574, 2575, 2577, 2578, 2579, 2580, 2631,
+            2582, 2583, 2584, 2585, 2527, 2587, 2588, 2589, 2632, 2591, 2592, 2633, 2594, 2595,
+            2596, 2597, 2634, 2524, 2515, 2528, 2628, 2533, 2629, 2536, 2635, 2548, 2636, 2637,
+            2638, 2574, 2575, 2580, 2589, 2632, 2597, 2634, 2639, 2640, 2641, 2642, 2642, 2643,
+            2643, 2644, 2644, 2645, 2645, 2646, 2646, 2647, 2647, 2648, 2648, 2649, 2649, 2650,
+            2650, 2651, 2651, 2652, 2653, 2654, 2637, 2655, 2656, 2657, 2658, 2642, 2642, 2643,
+            2643, 2644, 2644, 2645, 2645, 2646, 2646, 2647, 2647, 2648, 2648, 2649, 2649, 2650,
+            2650, 2651, 2651, 2652, 2653, 2654, 2637, 2655, 2656, 2657, 2658, 2652, 2653, 2654,
+            2637, 2636, 2638, 2556, 2545, 2546, 2547, 2652, 2653, 2654, 2556, 2557, 2659, 2659,
+            1372, 1373, 2660, 2661, 2661, 2662, 2663, 2664, 2665, 2666, 2667, 2667, 2668, 2668,
+            2669, 2670, 2671, 2672, 2672, 2673, 2674, 2674, 2675, 2675, 2676, 2677, 2677, 2678,
+            2679, 2679, 2680, 2680, 2681, 2682, 2682, 2683, 2683, 2684, 2685, 2686, 2687, 2687,
+            2688, 2689, 2690, 2690, 2691, 2691, 2692, 2693, 2694, 2695, 2695, 2696, 2696, 2697,
+            2697, 2698, 2698, 2699, 2700, 2701, 2702, 2703, 2704, 2705, 0, 0, 2706, 2707, 2708,
+            2709, 2710, 2711, 2711, 2712, 2713, 2713, 2714, 2714, 2715, 2716, 2716, 2717, 2717,
+            2718, 2718, 2719, 2720, 2719, 2721, 2722, 2723, 2681, 2724, 2725, 2726, 2727, 2728,
+            2729, 2730, 2710, 2692, 2694, 2688, 2731, 2732, 2733, 2734, 2735, 2734, 2732, 2720,
+            2736, 2737, 2738, 2739, 2735, 2686, 2676, 2721, 2712, 2740, 2741, 2742, 2743, 2744,
+            2745, 2746, 2747, 2748, 2740, 2749, 2750, 1164, 0, 0, 0, 0, 1152, 0, 0, 0, 0, 0, 0, 161,
+            1348, 0, 0, 2751, 2752, 2753, 2754, 2755, 2756, 2757, 0, 0, 0, 0, 0, 0, 10, 10, 10, 10,
+            380, 380, 380, 162, 0, 0, 0, 0, 0, 0, 0, 1211, 0, 0, 0, 0, 0, 0, 0, 2758, 2759, 2759,
+            315, 315, 316, 316, 317, 317, 318, 318, 318, 318, 4, 4, 2760, 2760, 2760, 2760, 21, 21,
+            334, 334, 334, 334, 319, 319, 319, 319, 2761, 2761, 2761, 2761, 2762, 2762, 2762, 2762,
+            2763, 2763, 2763, 2763, 2764, 2764, 2765, 2765, 2766, 2766, 2767, 2767, 2768, 2768,
+            2768, 2768, 320, 320, 320, 320, 2769, 2769, 2769, 2769, 2770, 2770, 2770, 2770, 2771,
+            2771, 2771, 2771, 2772, 2772, 2772, 2772, 314, 314, 314, 314, 2773, 2773, 2773, 2773,
+            346, 346, 346, 346, 2774, 2774, 2774, 2774, 347, 347, 347, 347, 2775, 2775, 2775, 2775,
+            2776, 2776, 2776, 2776, 2777, 2777, 2777, 2777, 219, 219, 219, 219, 389, 389, 322, 322,
+            322, 322, 322, 322, 2778, 2778, 2779, 2779, 2780, 2780, 2781, 2781, 0, 0, 0, 0, 79, 1,
+            0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 715, 0, 0, 0, 0, 161, 0, 0, 0, 0, 0, 0, 201, 202, 232, 0,
+            203, 0, 0, 205, 4, 199, 206, 0, 208, 209, 3, 210, 0, 0, 236, 212, 0, 0, 0, 214, 213,
+            204, 1372, 1211, 1373, 2782, 0, 5, 121, 0, 229, 0, 245, 0, 52, 278, 29, 230, 0, 4, 0, 0,
+            219, 23, 0, 0, 77, 0, 0, 218, 0, 16, 129, 0, 0, 1348, 0, 2783, 0, 0, 0, 0, 0, 0, 200, 0,
+            0, 0, 0, 0, 10, 0, 0, 0, 0, 4, 0, 0, 0, 0, 2784, 0, 0, 0, 200, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 2785, 0, 0, 0, 0, 0, 0, 0, 2786, 2787, 2788, 2789, 0, 0, 0, 0, 0, 0, 1163,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 202, 0, 0, 833, 203, 224, 0, 0, 4, 0, 0, 207, 0, 0, 214, 0,
+            3, 0, 1234, 210, 236, 212, 0, 0, 0, 1048, 0, 0, 0, 0, 201, 202, 232, 833, 0, 224, 0, 0,
+            0, 0, 0, 3, 0, 2790, 0, 0, 208, 212, 213, 240, 214, 255, 1030, 0, 2791, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 205, 0, 2764, 0, 0, 389, 0, 0, 0, 2771, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 2769, 0, 0, 204, 0, 0, 0, 202, 232, 0, 0, 0, 0, 0, 0, 208, 2790, 0, 0, 212, 0,
+            214, 0, 0, 107, 0, 0, 0, 0, 330, 4, 0, 214, 0, 0, 0, 0, 0, 0, 2792, 0, 2793, 0, 0, 0, 0,
+            0, 294, 0, 0, 3, 0, 0, 0, 0, 1182, 0, 0, 0, 232, 0, 0, 0, 0, 0, 831, 0, 0, 0, 2794, 236,
+            0, 0, 233, 0, 194, 0, 0, 0, 127, 1978, 0, 219, 0, 0, 0, 0, 0, 0, 0, 0, 229, 0, 2795, 0,
+            0, 2796, 1435, 0, 0, 0, 0, 77, 0, 0, 196, 0, 195, 0, 0, 2797, 0, 0, 0, 0, 0, 0, 0, 207,
+            0, 0, 0, 65, 0, 0, 0, 0, 0, 0, 0, 2798, 0, 0, 0, 0, 0, 3, 1231, 231, 0, 0, 0, 0, 0, 0,
+            0, 0, 2799, 301, 0, 2800, 255, 2801, 0, 0, 0, 0, 0, 1115, 0, 0, 1428, 0, 0, 0, 0, 0, 0,
+            219, 2802, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 143, 0, 0, 256, 0, 0, 0, 0, 0, 0, 0, 0, 0, 209,
+            0, 0, 3, 0, 206, 0, 0, 0, 232, 257, 0, 0, 0, 0, 0, 0, 0, 224, 831, 214, 0, 0, 310, 0, 0,
+            0, 0, 0, 328, 0, 0, 0, 0, 0, 0, 2803, 0, 0, 2804, 0, 2805, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            2777, 2501, 1246, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 467, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            467, 0, 0, 310, 2806, 0, 0, 0, 0, 0, 0, 0, 2807, 2808, 0, 2809, 0, 191, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 2810, 0, 0, 0, 0, 0, 2811, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2812, 0, 0, 0,
+            0, 0, 2813, 0, 0, 2814, 0, 2815, 0, 0, 0, 0, 2816, 0, 0, 0, 0, 0, 2817, 0, 2818, 0,
+            2819, 0, 2820, 2821, 0, 2822, 0, 2823, 2824, 2825, 2826, 2827, 2828, 2829, 0, 0, 0,
+            2830, 2831, 2832, 2833, 430, 0, 2834, 2835, 0, 2836, 417, 0, 0, 0, 0, 0, 0, 0, 2837, 0,
+            0, 2838, 2839, 2840, 174, 0, 431, 2841, 310, 2842, 2843, 0, 0, 219, 2844, 2845, 0, 0, 0,
+            2846, 0, 2847, 2847, 2848, 2849, 2850, 2851, 0, 0, 0, 0, 2852, 0, 0, 0, 0, 0, 6, 0, 0,
+            0, 0, 0, 218, 0, 0, 0, 135, 0, 0, 0, 135, 135, 257, 0, 224, 831, 213, 0, 203, 0, 2853,
+            204, 0, 0, 429, 0, 203, 830, 0, 0, 831, 0, 0, 3, 0, 1234, 301, 0, 0, 76, 212, 0, 0, 0,
+            218, 77, 224, 29, 1114, 0, 2801, 0, 219, 0, 74, 0, 429, 0, 127, 0, 0, 0, 0, 0, 0, 228,
+            429, 219, 143, 0, 0, 0, 129, 0, 0, 0, 3, 0, 0, 6, 366, 204, 296, 0, 0, 232, 0, 0, 214,
+            0, 0, 296, 0, 0, 232, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2854, 2855, 2856, 2857, 2858, 0,
+            2859, 2860, 2861, 0, 0, 0, 0, 0, 2862, 2863, 2864, 2865, 2866, 0, 0, 0, 0, 0, 0, 0,
+            2806, 0, 0, 0, 0, 0, 172, 0, 0, 0, 2867, 0, 0, 0, 0, 0, 0, 0, 2868, 0, 0, 0, 0, 0, 0,
+            161, 4, 0, 0, 0, 0, 0, 2869, 0, 0, 0, 0, 0, 0, 0, 0, 1364, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 239, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 222, 0, 0, 0, 0, 0, 0, 0, 251, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 237, 257, 0, 212, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 831, 0, 0, 0,
+            833, 0, 2870, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1182, 0, 4, 0, 0, 0, 0, 294, 0, 0, 0, 0, 0, 0,
+            0, 65, 0, 0, 0, 0, 236, 74, 0, 207, 0, 159, 201, 0, 301, 213, 0, 0, 0, 0, 0, 5, 5, 0, 0,
+            0, 0, 0, 0, 0, 1528, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 201, 202, 232, 828, 203, 224, 292,
+            205, 4, 199, 206, 831, 208, 209, 3, 210, 1173, 65, 236, 212, 301, 257, 296, 214, 213,
+            204, 3, 4, 66, 74, 830, 76, 228, 2801, 107, 429, 0, 2871, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 1153, 0, 0, 0, 0, 1374, 0, 0, 0, 0, 0, 0, 0, 0, 328, 0, 0, 0, 0, 2872, 0, 0, 0, 74,
+            0, 0, 0, 0, 194, 0, 257, 0, 1211, 0, 0, 2801, 224, 1359, 1182, 65, 1021, 0, 0, 60, 2873,
+            1232, 0, 0, 0, 0, 294, 829, 0, 0, 0, 0, 0, 0, 0, 831, 1182, 0, 0, 0, 0, 1020, 0, 0, 0,
+            0, 0, 158, 159, 2874, 2875, 1050, 1211, 0, 0, 0, 2876, 0, 0, 0, 0, 0, 826, 0, 0, 201,
+            202, 232, 828, 203, 224, 292, 205, 4, 199, 206, 831, 208, 209, 3, 210, 1173, 65, 236,
+            212, 301, 257, 296, 214, 213, 204, 121, 48, 229, 290, 245, 44, 52, 278, 29, 230, 2877,
+            4, 6, 303, 219, 23, 295, 244, 77, 2878, 143, 218, 135, 16, 129, 1114, 201, 202, 232,
+            828, 203, 224, 292, 205, 4, 199, 206, 831, 208, 209, 3, 210, 1173, 65, 236, 212, 301,
+            257, 296, 214, 213, 204, 121, 48, 229, 290, 245, 44, 52, 0, 29, 230, 2877, 4, 6, 303,
+            219, 23, 295, 244, 77, 2878, 143, 218, 135, 16, 129, 1114, 135, 16, 129, 1114, 201, 0,
+            232, 828, 0, 0, 292, 0, 0, 199, 206, 0, 0, 209, 3, 210, 1173, 0, 236, 212, 229, 290, 0,
+            44, 0, 278, 29, 230, 2877, 4, 6, 303, 0, 23, 295, 244, 135, 16, 129, 1114, 201, 202, 0,
+            828, 203, 224, 292, 0, 0, 199, 206, 831, 208, 209, 3, 210, 1173, 0, 236, 212, 301, 257,
+            296, 214, 213, 0, 121, 48, 229, 290, 245, 44, 52, 278, 29, 230, 201, 202, 0, 828, 203,
+            224, 292, 0, 4, 199, 206, 831, 208, 0, 3, 0, 0, 0, 236, 212, 301, 257, 296, 214, 213, 0,
+            121, 48, 229, 290, 245, 44, 135, 16, 129, 1114, 29, 302, 0, 0, 201, 202, 237, 833, 203,
+            204, 205, 60, 4, 206, 207, 208, 209, 1365, 3, 239, 210, 60, 211, 212, 213, 240, 214,
+            255, 1030, 2853, 121, 215, 129, 216, 127, 2879, 37, 60, 29, 217, 1428, 11, 218, 2880,
+            219, 222, 23, 223, 219, 220, 143, 221, 1431, 256, 1255, 2881, 127, 60, 217, 221, 23,
+            222, 201, 202, 237, 833, 203, 204, 205, 60, 4, 206, 207, 208, 209, 1365, 3, 239, 210,
+            60, 211, 212, 213, 240, 214, 255, 1030, 2853, 121, 215, 129, 216, 127, 2879, 37, 60, 29,
+            217, 1428, 11, 218, 2880, 219, 222, 23, 223, 219, 220, 143, 221, 1431, 256, 1255, 2881,
+            127, 60, 217, 221, 23, 222, 201, 202, 237, 833, 203, 204, 205, 60, 4, 206, 207, 208,
+            209, 1365, 3, 239, 210, 60, 211, 212, 213, 240, 214, 255, 1030, 2853, 121, 215, 129,
+            216, 127, 2879, 37, 60, 29, 217, 1428, 11, 218, 2880, 219, 222, 23, 223, 219, 220, 143,
+            221, 1431, 256, 1255, 2881, 127, 60, 217, 221, 23, 222, 201, 202, 237, 833, 203, 204,
+            205, 60, 4, 206, 207, 208, 209, 1365, 3, 239, 210, 60, 211, 212, 213, 240, 214, 255,
+            1030, 2853, 121, 215, 129, 216, 127, 2879, 37, 60, 29, 217, 1428, 11, 218, 2880, 219,
+            222, 23, 223, 219, 220, 143, 221, 1431, 256, 1255, 2881, 127, 60, 217, 221, 23, 222, 23,
+            222, 224, 2882, 0, 0, 3, 4, 66, 74, 830, 76, 228, 2801, 107, 429, 107, 429, 3, 4, 66,
+            74, 830, 76, 228, 2801, 107, 429, 3, 4, 66, 74, 830, 76, 228, 2801, 107, 429, 3, 4, 0,
+            1048, 0, 0, 0, 0, 2883, 0, 0, 0, 0, 0, 0, 0, 0, 4, 2884, 363, 0, 107, 2881, 20, 0, 0, 4,
+            2760, 2761, 2764, 0, 389, 2767, 2762, 2771, 322, 347, 2775, 2776, 2777, 2768, 314, 346,
+            2769, 2774, 2766, 320, 334, 319, 2763, 2765, 2770, 2772, 2773, 322, 322, 331, 331, 0,
+            2760, 2761, 0, 219, 0, 0, 2762, 0, 322, 347, 2775, 2776, 2777, 2768, 314, 346, 2769,
+            2774, 0, 320, 334, 319, 2763, 0, 2770, 0, 2773, 0, 0, 0, 0, 0, 0, 2761, 0, 0, 0, 0,
+            2762, 0, 322, 0, 2775, 0, 2777, 2768, 314, 0, 2769, 2774, 0, 320, 0, 0, 2763, 0, 2770,
+            0, 2773, 0, 322, 0, 331, 2771, 322, 347, 0, 2776, 2777, 2768, 314, 0, 2770, 2772, 2773,
+            322, 0, 331, 0, 4, 2760, 2761, 2764, 219, 389, 2767, 2762, 2771, 322, 0, 2775, 2776,
+            2777, 2768, 314, 2765, 2770, 2772, 2773, 0, 0, 0, 0, 0, 2760, 2761, 2764, 0, 389, 2767,
+            2762, 2885, 2886, 2887, 2888, 2889, 2890, 2891, 2892, 2893, 2894, 2895, 0, 0, 0, 0,
+            2896, 2897, 2898, 2899, 2900, 2901, 2902, 2903, 2904, 1278, 2905, 2906, 2907, 2908,
+            2909, 2910, 2911, 2912, 2913, 2914, 2915, 2916, 2917, 2918, 2919, 2920, 2921, 2914, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 2922, 2923, 0, 2924, 1779, 1778, 2925, 2926, 2927, 2928,
+            2929, 2930, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2931, 0, 0, 0, 0, 0, 1266, 2931, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 2932, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2933, 2933, 0, 0, 2934, 2935, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 2936, 0, 0, 2937, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2938, 0, 0, 0,
+            0, 0, 2939, 0, 0, 0, 0, 0, 0, 0, 0, 2940, 0, 0, 0, 2941, 2870, 833, 0, 1359, 0, 0, 2942,
+            2943, 0, 2944, 0, 0, 0, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 1230, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            2945, 0, 0, 0, 0, 0, 0, 0, 0, 0, 232, 0, 0, 0, 0, 0, 0, 0, 1234, 2946, 0, 0, 0, 0, 0, 0,
+            2947, 0, 1367, 0, 212, 0, 0, 2948, 2949, 0, 0, 0, 0, 2950, 0, 0, 0, 0, 0, 0, 107, 429,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1535, 0, 0, 0, 0, 0, 0, 2951, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 2952, 0, 0, 0, 0, 0, 0, 0, 2953, 1241, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2954,
+            0, 0, 2955, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2956, 0, 0, 0, 0, 2957, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 2958, 0, 0, 0, 0, 0, 0, 0, 0, 2959, 0, 0, 0, 0, 0, 0, 0, 0, 2960, 0, 0, 0,
+            0, 0, 0, 2961, 0, 0, 0, 0, 2962, 0, 0, 0, 2963, 0, 0, 2349, 0, 0, 0, 0, 0, 0, 2964,
+            2965, 2966, 2967, 2968, 2344, 2969, 1889, 2970, 2971, 2345, 2972, 2973, 2974, 2346,
+            2975, 2976, 2977, 2978, 2979, 2980, 2981, 2982, 2983, 2984, 2985, 2986, 2400, 2987,
+            1535, 2988, 2989, 2990, 2991, 2992, 2993, 2994, 2405, 2347, 2348, 2406, 2995, 2996,
+            2168, 2997, 2349, 2998, 2999, 3000, 3001, 3001, 3001, 3002, 3003, 3004, 3005, 3006,
+            3007, 3008, 3009, 3010, 3011, 3012, 3013, 3014, 3015, 3016, 1894, 3017, 3018, 3018,
+            2408, 3019, 3020, 3021, 3022, 2351, 3023, 3024, 3025, 2314, 3026, 3027, 3028, 3029,
+            3030, 3031, 3032, 3033, 3034, 3035, 2951, 3036, 2952, 3037, 3038, 2953, 3039, 3040,
+            3041, 3042, 3043, 3044, 3045, 3046, 3047, 3048, 3048, 3049, 3050, 3051, 2164, 3052,
+            3053, 3054, 3055, 3056, 1462, 3057, 3058, 1560, 2962, 3059, 3060, 3061, 3062, 3063,
+            3064, 3065, 3066, 3067, 3068, 3069, 3070, 3071, 3072, 3073, 3074, 3075, 3076, 3077,
+            3078, 2113, 3079, 1569, 3080, 3080, 3081, 3082, 3082, 3083, 3084, 3085, 3086, 3087,
+            3088, 2954, 3089, 3090, 3091, 3092, 3093, 3094, 2356, 3095, 3096, 3097, 3098, 2420,
+            3098, 3099, 2358, 3100, 3101, 3102, 3103, 2359, 2086, 3104, 3105, 3106, 3107, 3108,
+            3109, 3110, 3111, 3112, 3113, 3114, 3115, 3116, 3117, 3118, 3119, 3120, 3121, 3122,
+            3123, 3124, 3125, 2360, 3126, 3127, 3128, 3129, 3130, 3131, 2362, 3132, 3133, 3134,
+            3135, 3136, 3137, 3138, 3139, 2114, 2428, 3140, 3141, 3142, 3143, 3144, 3145, 3146,
+            3147, 2363, 3148, 3149, 3150, 3151, 2470, 3152, 3153, 3154, 3155, 3156, 3157, 3158,
+            3159, 3160, 3161, 3162, 3163, 3164, 2181, 3165, 3166, 3167, 3168, 3169, 3170, 3171,
+            3172, 3173, 3174, 3175, 2364, 2263, 3176, 3177, 2955, 3178, 3179, 3180, 3181, 3182,
+            2431, 3183, 3184, 3185, 3186, 3187, 3188, 3189, 3190, 2432, 3191, 3192, 3193, 3194,
+            3195, 3196, 3197, 3198, 3199, 3200, 3201, 3202, 2434, 3203, 3204, 3205, 3206, 3207,
+            2961, 3208, 3209, 3210, 2956, 3211, 3211, 3212, 3213, 2436, 3214, 3215, 3216, 3217,
+            3218, 3219, 3220, 2167, 3221, 3222, 3223, 3224, 3225, 3226, 3227, 2442, 3228, 3229,
+            2957, 3230, 3231, 3232, 3232, 2443, 1934, 3233, 3234, 3235, 3236, 3237, 2130, 2445,
+            3238, 3239, 2374, 3240, 3241, 2333, 3242, 3243, 2378, 3244, 3245, 3246, 3247, 3247,
+            3248, 3249, 3250, 3251, 3252, 3253, 3254, 3255, 3256, 3257, 3258, 3259, 3260, 3261,
+            3262, 3263, 3264, 3265, 3266, 3267, 3268, 3269, 3270, 3271, 3272, 3273, 3274, 2384,
+            3275, 3276, 3277, 2959, 3278, 2963, 3279, 3280, 3281, 3282, 3283, 3284, 3285, 3286,
+            3287, 3288, 3081, 3289, 3290, 3291, 3292, 3293, 3294, 3295, 3296, 3297, 3298, 3299,
+            3300, 2184, 3301, 3302, 3303, 3304, 3305, 3306, 2386, 3307, 3308, 3309, 3310, 2960,
+            3311, 3312, 3313, 3314, 3315, 3316, 3317, 3318, 3319, 3320, 3321, 3322, 3323, 3324,
+            3325, 2125, 3326, 3327, 3328, 3329, 3330, 3331, 2452, 3332, 3333, 3334, 3335, 3336,
+            3337, 3338, 3339, 1659, 3340, 3341, 3342, 3343, 3344, 3345, 1884, 3346, 3347, 3348,
+            3349, 2457, 2458, 1666, 3350, 3351, 3352, 2958, 3353, 3354, 3355, 3356, 3357, 3358,
+            3359, 3360, 2459, 3361, 3362, 3363, 3364, 3365, 3366, 3367, 3368, 3369, 3370, 3371,
+            3372, 3373, 3374, 3375, 3376, 3377, 3378, 3379, 3380, 3381, 3382, 3383, 3384, 3385,
+            3386, 3387, 3388, 3389, 3390, 2465, 2465, 3391, 3392, 3393, 3394, 3395, 3396, 3397,
+            3398, 3399, 3400, 2466, 3401, 3402, 3403, 3404, 3405, 3406, 3407, 3408, 3409, 3410,
+            1711, 3411, 1715, 3412, 3413, 3414, 3415, 1720, 3416, 0, 0, 0, 0, 0, 0, 3263, 0, 0, 0
+        ],
+        block_bits: 4,
+        leaf_bits: 3,
+    };
+
+    const PROTOTYPES: &[&[char]] = &[
+        &['\u{27}',  '\u{27}'], &['\u{ba}',  '\u{2f}',  '\u{2080}'], &['\u{4f}'], &['\u{6c}'],
+        &['\u{27}'], &['\u{72}',  '\u{6e}'], &['\u{20}'], &['\u{63}',  '\u{338}'], &['\u{59}',
+         '\u{335}'], &['\u{2c9}'], &['\u{3bc}'], &['\u{2c}'], &['\u{41}',  '\u{45}'], &['\u{43}',
+         '\u{326}'], &['\u{44}',  '\u{335}'], &['\u{78}'], &['\u{4f}',  '\u{338}'], &['\u{61}',
+         '\u{65}'], &['\u{63}',  '\u{326}'], &['\u{2202}',  '\u{335}'], &['\u{629}'], &['\u{6f}',
+         '\u{338}'], &['\u{70}'], &['\u{64}',  '\u{335}'], &['\u{114}'], &['\u{115}'], &['\u{48}',
+         '\u{335}'], &['\u{68}',  '\u{335}'], &['\u{69}'], &['\u{6c}',  '\u{4a}'], &['\u{69}',
+         '\u{6a}'], &['\u{6c}',  '\u{b7}'], &['\u{4c}',  '\u{338}'], &['\u{6c}',  '\u{338}'],
+        &['\u{272}'], &['\u{27}',  '\u{6e}'], &['\u{6e}',  '\u{329}'], &['\u{d6}'], &['\u{4f}',
+         '\u{45}'], &['\u{6f}',  '\u{65}'], &['\u{1ab}'], &['\u{54}',  '\u{335}'], &['\u{74}',
+         '\u{335}'], &['\u{66}'], &['\u{62}',  '\u{335}'], &['\u{27}',  '\u{42}'], &['\u{62}',
+         '\u{304}'], &['\u{62}'], &['\u{43}',  '\u{27}'], &['\u{27}',  '\u{44}'], &['\u{64}',
+         '\u{304}'], &['\u{67}'], &['\u{46}',  '\u{326}'], &['\u{47}',  '\u{27}'], &['\u{6c}',
+         '\u{335}'], &['\u{4b}',  '\u{27}'], &['\u{6b}',  '\u{314}'], &['\u{3bb}',  '\u{338}'],
+        &['\u{4e}',  '\u{326}'], &['\u{4f}',  '\u{335}'], &['\u{4f}',  '\u{27}'], &['\u{6f}',
+         '\u{27}'], &['\u{27}',  '\u{50}'], &['\u{70}',  '\u{314}'], &['\u{52}'], &['\u{32}'],
+        &['\u{27}',  '\u{54}'], &['\u{74}',  '\u{314}'], &['\u{54}',  '\u{328}'], &['\u{27}',
+         '\u{59}'], &['\u{79}',  '\u{314}'], &['\u{5a}',  '\u{335}'], &['\u{7a}',  '\u{335}'],
+        &['\u{33}'], &['\u{32}',  '\u{335}'], &['\u{35}'], &['\u{73}'], &['\u{6c}',  '\u{6c}'],
+        &['\u{21}'], &['\u{44}',  '\u{17d}'], &['\u{44}',  '\u{17e}'], &['\u{64}',  '\u{17e}'],
+        &['\u{4c}',  '\u{4a}'], &['\u{4c}',  '\u{6a}'], &['\u{6c}',  '\u{6a}'], &['\u{4e}',
+         '\u{4a}'], &['\u{4e}',  '\u{6a}'], &['\u{6e}',  '\u{6a}'], &['\u{102}'], &['\u{103}'],
+        &['\u{12c}'], &['\u{12d}'], &['\u{14e}'], &['\u{14f}'], &['\u{16c}'], &['\u{16d}'],
+        &['\u{47}',  '\u{335}'], &['\u{67}',  '\u{335}'], &['\u{11e}'], &['\u{11f}'], &['\u{44}',
+         '\u{5a}'], &['\u{44}',  '\u{7a}'], &['\u{64}',  '\u{7a}'], &['\u{123}'], &['\u{4f}',
+         '\u{338}',  '\u{301}'], &['\u{162}'], &['\u{38}'], &['\u{5a}',  '\u{326}'], &['\u{7a}',
+         '\u{326}'], &['\u{c5}'], &['\u{e5}'], &['\u{54}',  '\u{338}'], &['\u{3f}'], &['\u{55}',
+         '\u{335}'], &['\u{45}',  '\u{338}'], &['\u{65}',  '\u{338}'], &['\u{4a}',  '\u{335}'],
+        &['\u{6a}',  '\u{335}'], &['\u{72}',  '\u{335}'], &['\u{79}',  '\u{335}'], &['\u{61}'],
+        &['\u{62}',  '\u{314}'], &['\u{64}',  '\u{328}'], &['\u{64}',  '\u{314}'], &['\u{1dd}'],
+        &['\u{1dd}',  '\u{2de}'], &['\u{a793}'], &['\u{67}',  '\u{314}'], &['\u{79}'], &['\u{68}',
+         '\u{314}'], &['\u{69}',  '\u{335}'], &['\u{6c}',  '\u{334}'], &['\u{6c}',  '\u{328}'],
+        &['\u{6c}',  '\u{21d}'], &['\u{77}'], &['\u{72}',  '\u{6e}',  '\u{326}'], &['\u{6e}',
+         '\u{328}'], &['\u{6f}',  '\u{335}'], &['\u{6f}',  '\u{1d07}'], &['\u{72}',  '\u{329}'],
+        &['\u{72}',  '\u{328}'], &['\u{73}',  '\u{328}'], &['\u{75}'], &['\u{7a}',  '\u{328}'],
+        &['\u{21d}'], &['\u{a7ce}'], &['\u{71}',  '\u{314}'], &['\u{64}',  '\u{21d}'], &['\u{64}',
+         '\u{291}'], &['\u{74}',  '\u{73}'], &['\u{74}',  '\u{283}'], &['\u{74}',  '\u{255}'],
+        &['\u{66}',  '\u{6e}',  '\u{329}'], &['\u{6c}',  '\u{73}'], &['\u{6c}',  '\u{7a}'],
+        &['\u{18f4}'], &['\u{559}'], &['\u{3c}'], &['\u{3e}'], &['\u{5e}'], &['\u{3a}'],
+        &['\u{2d}'], &['\u{2c7}'], &['\u{971}'], &['\u{b0}'], &['\u{7e}'], &['\u{18f3}'],
+        &['\u{18f5}'], &['\u{2c1}'], &['\u{2ea}'], &['\u{304}'], &['\u{306}'], &['\u{670}'],
+        &['\u{306}',  '\u{307}'], &['\u{302}'], &['\u{313}'], &['\u{650}'], &['\u{1ae9}'],
+        &['\u{331}'], &['\u{326}'], &['\u{328}'], &['\u{335}'], &['\u{338}'], &['\u{300}'],
+        &['\u{301}'], &['\u{303}'], &['\u{333}'], &['\u{10efa}'], &['\u{350}'], &['\u{307}'],
+        &['\u{30a}'], &['\u{2c75}'], &['\u{2cf}'], &['\u{418}'], &['\u{1d0e}'], &['\u{254}'],
+        &['\u{a73f}'], &['\u{3b}'], &['\u{4a}'], &['\u{b7}'], &['\u{41}'], &['\u{42}'], &['\u{45}'],
+        &['\u{5a}'], &['\u{48}'], &['\u{4b}'], &['\u{245}'], &['\u{4d}'], &['\u{4e}'], &['\u{50}'],
+        &['\u{1a9}'], &['\u{54}'], &['\u{59}'], &['\u{58}'], &['\u{df}'], &['\u{1e9f}'],
+        &['\u{138}'], &['\u{76}'], &['\u{6f}'], &['\u{1d1b}'], &['\u{278}'], &['\u{3c0}'],
+        &['\u{3c2}'], &['\u{46}'], &['\u{427}'], &['\u{447}'], &['\u{1a8}'], &['\u{36}'],
+        &['\u{63}'], &['\u{6a}'], &['\u{de}'], &['\u{43}'], &['\u{186}'], &['\u{a73e}'],
+        &['\u{a792}'], &['\u{53}'], &['\u{393}'], &['\u{40d}'], &['\u{3a0}'], &['\u{3a6}'],
+        &['\u{62}',  '\u{6c}'], &['\u{6c}',  '\u{4f}'], &['\u{299}'], &['\u{72}'], &['\u{65}'],
+        &['\u{25c}'], &['\u{28d}'], &['\u{29c}'], &['\u{2c9}',  '\u{62}'], &['\u{185}',  '\u{69}'],
+        &['\u{185}'], &['\u{1d19}'], &['\u{439}'], &['\u{75}',  '\u{329}'], &['\u{3a8}'],
+        &['\u{3c8}'], &['\u{56}'], &['\u{460}',  '\u{486}',  '\u{487}'], &['\u{77}',  '\u{486}',
+         '\u{487}'], &['\u{40d}',  '\u{326}'], &['\u{439}',  '\u{326}'], &['\u{393}',  '\u{27}'],
+        &['\u{72}',  '\u{27}'], &['\u{393}',  '\u{335}'], &['\u{416}',  '\u{329}'], &['\u{436}',
+         '\u{329}'], &['\u{33}',  '\u{326}'], &['\u{25c}',  '\u{326}'], &['\u{4b}',  '\u{329}'],
+        &['\u{138}',  '\u{329}'], &['\u{4b}',  '\u{335}'], &['\u{138}',  '\u{335}'], &['\u{48}',
+         '\u{329}'], &['\u{29c}',  '\u{329}'], &['\u{54}',  '\u{329}'], &['\u{1d1b}',  '\u{329}'],
+        &['\u{58}',  '\u{329}'], &['\u{68}'], &['\u{4bc}',  '\u{328}'], &['\u{65}',  '\u{328}'],
+        &['\u{245}',  '\u{326}'], &['\u{43b}',  '\u{326}'], &['\u{48}',  '\u{326}'], &['\u{29c}',
+         '\u{326}'], &['\u{4b6}'], &['\u{4b7}'], &['\u{4d}',  '\u{326}'], &['\u{28d}',  '\u{326}'],
+        &['\u{18f}'], &['\u{64}'], &['\u{1f6}'], &['\u{47}'], &['\u{262}'], &['\u{190}'],
+        &['\u{71}'], &['\u{57}'], &['\u{12ae}'], &['\u{1206}'], &['\u{1323}'], &['\u{1261}'],
+        &['\u{55}'], &['\u{237}'], &['\u{6e}'], &['\u{270}'], &['\u{565}',  '\u{69}'], &['\u{59a}'],
+        &['\u{599}'], &['\u{596}'], &['\u{598}'], &['\u{323}'], &['\u{6c}',  '\u{27}'], &['\u{ba}',
+         '\u{2f}',  '\u{2080}',  '\u{2080}'], &['\u{ba}',  '\u{2f}',  '\u{2080}',  '\u{2080}',
+         '\u{2080}'], &['\u{639}'], &['\u{6c}',  '\u{674}'], &['\u{648}',  '\u{674}'], &['\u{6c}',
+         '\u{655}'], &['\u{649}',  '\u{674}'], &['\u{649}',  '\u{6db}'], &['\u{633}',  '\u{6db}'],
+        &['\u{649}',  '\u{302}'], &['\u{649}'], &['\u{30b}'], &['\u{329}'], &['\u{312}'],
+        &['\u{314}'], &['\u{655}'], &['\u{2e}'], &['\u{60c}'], &['\u{2a}'], &['\u{6a1}'],
+        &['\u{648}',  '\u{313}',  '\u{674}'], &['\u{649}',  '\u{615}'], &['\u{62a}'], &['\u{62d}',
+         '\u{654}'], &['\u{62d}',  '\u{6db}'], &['\u{62f}',  '\u{615}'], &['\u{68a}',  '\u{615}'],
+        &['\u{62f}',  '\u{6db}'], &['\u{631}',  '\u{615}'], &['\u{631}',  '\u{306}'], &['\u{631}',
+         '\u{6db}'], &['\u{635}',  '\u{6db}'], &['\u{637}',  '\u{6db}'], &['\u{6a1}',  '\u{6db}'],
+        &['\u{641}'], &['\u{643}'], &['\u{643}',  '\u{6db}'], &['\u{6af}',  '\u{6db}'], &['\u{644}',
+         '\u{306}'], &['\u{644}',  '\u{6db}'], &['\u{6c0}'], &['\u{648}',  '\u{306}'], &['\u{648}',
+         '\u{313}'], &['\u{648}',  '\u{670}'], &['\u{648}',  '\u{302}'], &['\u{648}',  '\u{6db}'],
+        &['\u{649}',  '\u{306}'], &['\u{67b}'], &['\u{62f}',  '\u{302}'], &['\u{631}',  '\u{302}'],
+        &['\u{662}'], &['\u{663}'], &['\u{664}'], &['\u{666}'], &['\u{669}'], &['\u{621}',
+         '\u{10efa}'], &['\u{645}',  '\u{10efa}'], &['\u{6f}',  '\u{302}'], &['\u{73c}'],
+        &['\u{628}',  '\u{6db}'], &['\u{6ac}'], &['\u{754}'], &['\u{646}',  '\u{615}'], &['\u{646}',
+         '\u{306}'], &['\u{631}',  '\u{654}'], &['\u{697}',  '\u{615}'], &['\u{633}',  '\u{302}'],
+        &['\u{308}'], &['\u{5f}'], &['\u{649}',  '\u{30a}'], &['\u{628}',  '\u{654}'], &['\u{6a2}',
+         '\u{6db}'], &['\u{645}',  '\u{6db}'], &['\u{649}',  '\u{654}'], &['\u{62f}',  '\u{324}',
+         '\u{323}'], &['\u{635}',  '\u{324}',  '\u{323}'], &['\u{6af}'], &['\u{648}'], &['\u{632}',
+         '\u{302}'], &['\u{628}',  '\u{6e2}'], &['\u{649}',  '\u{6db}',  '\u{6e2}'], &['\u{631}',
+         '\u{306}',  '\u{307}'], &['\u{649}',  '\u{306}',  '\u{307}'], &['\u{649}',  '\u{6db}',
+         '\u{306}'], &['\u{62a}',  '\u{306}'], &['\u{649}',  '\u{615}',  '\u{306}'], &['\u{686}',
+         '\u{306}'], &['\u{643}',  '\u{306}'], &['\u{64c}'], &['\u{324}'], &['\u{64d}'],
+        &['\u{354}'], &['\u{355}'], &['\u{352}'], &['\u{905}',  '\u{946}'], &['\u{905}',
+         '\u{93e}'], &['\u{930}',  '\u{94d}',  '\u{907}'], &['\u{90f}',  '\u{306}'], &['\u{90f}',
+         '\u{946}'], &['\u{90f}',  '\u{11b64}'], &['\u{905}',  '\u{93e}',  '\u{306}'], &['\u{905}',
+         '\u{93e}',  '\u{946}'], &['\u{905}',  '\u{93e}',  '\u{11b64}'], &['\u{905}',  '\u{93e}',
+         '\u{948}'], &['\u{93e}',  '\u{93a}'], &['\u{9bf}'], &['\u{11b64}'], &['\u{93e}',
+         '\u{306}'], &['\u{11b62}'], &['\u{11b63}'], &['\u{964}',  '\u{964}'], &['\u{905}',
+         '\u{306}'], &['\u{905}',  '\u{93a}'], &['\u{905}',  '\u{93e}',  '\u{93a}'], &['\u{905}',
+         '\u{94f}'], &['\u{985}',  '\u{9be}'], &['\u{98b}',  '\u{9c3}'], &['\u{39}'], &['\u{9b0}'],
+        &['\u{983}'], &['\u{a05}',  '\u{a3e}'], &['\u{92a}',  '\u{94d}',  '\u{91f}',  '\u{9bf}'],
+        &['\u{92a}',  '\u{94d}',  '\u{91f}',  '\u{a40}'], &['\u{a73}',  '\u{11b62}'], &['\u{a73}',
+         '\u{11b63}'], &['\u{92a}',  '\u{94d}',  '\u{91f}',  '\u{11b64}'], &['\u{a05}',  '\u{948}'],
+        &['\u{a05}',  '\u{a4c}'], &['\u{935}'], &['\u{924}',  '\u{94d}',  '\u{924}'], &['\u{91f}'],
+        &['\u{920}'], &['\u{909}'], &['\u{92a}'], &['\u{922}'], &['\u{92d}'], &['\u{939}'],
+        &['\u{92e}'], &['\u{948}'], &['\u{946}'], &['\u{94d}'], &['\u{92a}',  '\u{94d}',
+         '\u{91f}'], &['\u{a85}',  '\u{abe}'], &['\u{a85}',  '\u{ac5}'], &['\u{a85}',  '\u{ac7}'],
+        &['\u{a85}',  '\u{ac8}'], &['\u{a85}',  '\u{abe}',  '\u{ac5}'], &['\u{a85}',  '\u{abe}',
+         '\u{ac7}'], &['\u{a85}',  '\u{abe}',  '\u{ac8}'], &['\u{968}'], &['\u{93d}'], &['\u{941}'],
+        &['\u{942}'], &['\u{96a}'], &['\u{96e}'], &['\u{970}'], &['\u{b05}',  '\u{b3e}'],
+        &['\u{b89}',  '\u{bb3}'], &['\u{b92}',  '\u{bb3}'], &['\u{b90}'], &['\u{b88}'],
+        &['\u{bb6}'], &['\u{ba9}'], &['\u{bc6}',  '\u{b88}'], &['\u{bc7}',  '\u{b88}'], &['\u{bc6}',
+         '\u{bb3}'], &['\u{bb3}'], &['\u{b95}'], &['\u{b89}'], &['\u{b9a}'], &['\u{b88}',
+         '\u{bc1}'], &['\u{b9a}',  '\u{bc1}'], &['\u{b8e}'], &['\u{b85}'], &['\u{baf}'],
+        &['\u{b9a}',  '\u{bc2}'], &['\u{bae}',  '\u{bc0}'], &['\u{bf3}'], &['\u{b8e}',  '\u{bb5}'],
+        &['\u{bb7}'], &['\u{ba8}',  '\u{bc0}'], &['\u{c12}',  '\u{c55}'], &['\u{c12}',  '\u{c4c}'],
+        &['\u{c96}',  '\u{323}'], &['\u{c30}',  '\u{5bc}'], &['\u{c21}',  '\u{323}'], &['\u{c27}',
+         '\u{5bc}'], &['\u{c2c}',  '\u{323}'], &['\u{c35}',  '\u{c41}'], &['\u{c35}',  '\u{323}'],
+        &['\u{c35}',  '\u{c3e}'], &['\u{c41}',  '\u{c3e}'], &['\u{c43}',  '\u{c3e}'], &['\u{c0b}',
+         '\u{c3e}'], &['\u{c0c}',  '\u{c3e}'], &['\u{c05}'], &['\u{c06}'], &['\u{c07}'],
+        &['\u{c10}'], &['\u{c12}'], &['\u{c17}'], &['\u{c1c}'], &['\u{c1d}'], &['\u{c1e}'],
+        &['\u{c1f}'], &['\u{c23}'], &['\u{c26}'], &['\u{c28}'], &['\u{c2f}'], &['\u{c30}'],
+        &['\u{c31}'], &['\u{c32}'], &['\u{c33}'], &['\u{c3f}'], &['\u{c41}'], &['\u{c43}'],
+        &['\u{c5c}'], &['\u{c8c}',  '\u{cbe}'], &['\u{c67}'], &['\u{c68}'], &['\u{c6f}'],
+        &['\u{d07}',  '\u{d57}'], &['\u{b89}',  '\u{d57}'], &['\u{d28}',  '\u{d41}'], &['\u{bc6}',
+         '\u{d0e}'], &['\u{d12}',  '\u{d3e}'], &['\u{d12}',  '\u{d57}'], &['\u{bb5}'], &['\u{ba3}'],
+        &['\u{bae}'], &['\u{d30}'], &['\u{bb4}'], &['\u{b9f}',  '\u{bbf}'], &['\u{bbf}'],
+        &['\u{d41}'], &['\u{bc6}'], &['\u{bc7}'], &['\u{bc6}',  '\u{bc6}'], &['\u{d28}',  '\u{d4d}',
+         '\u{d2e}'], &['\u{6f}',  '\u{d30}',  '\u{6f}'], &['\u{d1e}'], &['\u{d30}',  '\u{d4d}'],
+        &['\u{d26}',  '\u{d4d}',  '\u{d30}'], &['\u{d28}',  '\u{d4d}',  '\u{d28}'], &['\u{d35}',
+         '\u{d4d}',  '\u{d30}'], &['\u{d28}',  '\u{d4d}'], &['\u{d39}',  '\u{d4d}',  '\u{d2e}'],
+        &['\u{ba3}',  '\u{d4d}'], &['\u{d32}',  '\u{d4d}'], &['\u{d33}',  '\u{d4d}'], &['\u{dc3}',
+         '\u{dd8}'], &['\u{d91}',  '\u{dca}'], &['\u{d91}',  '\u{dd9}'], &['\u{d91}'], &['\u{d9b}'],
+        &['\u{d94}'], &['\u{da0}'], &['\u{db7}'], &['\u{de8}',  '\u{dcf}'], &['\u{da2}'],
+        &['\u{daf}'], &['\u{de8}',  '\u{dd3}'], &['\u{e02}'], &['\u{e0a}'], &['\u{e0e}'],
+        &['\u{e04}'], &['\u{e11}'], &['\u{e06}'], &['\u{e20}'], &['\u{30a}',  '\u{e32}'],
+        &['\u{e40}',  '\u{e40}'], &['\u{e32}'], &['\u{e08}'], &['\u{e22}'], &['\u{e1a}'],
+        &['\u{e1b}'], &['\u{e1d}'], &['\u{e1e}'], &['\u{e1f}'], &['\u{30a}',  '\u{eb2}'],
+        &['\u{e38}'], &['\u{e39}'], &['\u{e48}'], &['\u{e49}'], &['\u{e4a}'], &['\u{e4b}'],
+        &['\u{eab}',  '\u{e99}'], &['\u{eab}',  '\u{ea1}'], &['\u{f68}',  '\u{f7c}',  '\u{f7e}'],
+        &['\u{f60}',  '\u{f74}',  '\u{f82}',  '\u{f7f}'], &['\u{f60}',  '\u{f74}',  '\u{f82}',
+         '\u{f14}'], &['\u{f0b}'], &['\u{f0d}',  '\u{f0d}'], &['\u{f1a}',  '\u{f1a}'], &['\u{f1d}',
+         '\u{f1d}'], &['\u{f1a}',  '\u{f1d}'], &['\u{325}'], &['\u{f62}'], &['\u{fb2}',  '\u{f71}',
+         '\u{f80}'], &['\u{fb3}',  '\u{f71}',  '\u{f80}'], &['\u{f7a}',  '\u{f7a}'], &['\u{f7c}',
+         '\u{f7c}'], &['\u{f1d}',  '\u{f1a}'], &['\u{5350}'], &['\u{534d}'], &['\u{d30}',
+         '\u{102c}'], &['\u{6f}',  '\u{102c}'], &['\u{1015}',  '\u{102c}'], &['\u{d30}',
+         '\u{102c}',  '\u{1039}',  '\u{d30}',  '\u{102c}'], &['\u{101e}',  '\u{103c}'],
+        &['\u{101e}',  '\u{103c}',  '\u{b47}',  '\u{102c}',  '\u{103a}'], &['\u{b47}'],
+        &['\u{104a}',  '\u{104a}'], &['\u{101b}',  '\u{103e}'], &['\u{1041}'], &['\u{1015}',
+         '\u{103e}'], &['\u{1015}',  '\u{102c}',  '\u{103e}'], &['\u{1003}',  '\u{103e}'],
+        &['\u{107d}',  '\u{103e}'], &['\u{d30}',  '\u{103e}'], &['\u{1083}',  '\u{30a}'],
+        &['\u{a786}'], &['\u{1100}',  '\u{1100}'], &['\u{1103}',  '\u{1103}'], &['\u{1107}',
+         '\u{1107}'], &['\u{1109}',  '\u{1109}'], &['\u{110c}',  '\u{110c}'], &['\u{1102}',
+         '\u{1100}'], &['\u{1102}',  '\u{1102}'], &['\u{1102}',  '\u{1103}'], &['\u{1102}',
+         '\u{1107}'], &['\u{1103}',  '\u{1100}'], &['\u{1105}',  '\u{1102}'], &['\u{1105}',
+         '\u{1105}'], &['\u{1105}',  '\u{1112}'], &['\u{1105}',  '\u{110b}'], &['\u{1106}',
+         '\u{1107}'], &['\u{1106}',  '\u{110b}'], &['\u{1107}',  '\u{1100}'], &['\u{1107}',
+         '\u{1102}'], &['\u{1107}',  '\u{1103}'], &['\u{1107}',  '\u{1109}'], &['\u{1107}',
+         '\u{1109}',  '\u{1100}'], &['\u{1107}',  '\u{1109}',  '\u{1103}'], &['\u{1107}',
+         '\u{1109}',  '\u{1107}'], &['\u{1107}',  '\u{1109}',  '\u{1109}'], &['\u{1107}',
+         '\u{1109}',  '\u{110c}'], &['\u{1107}',  '\u{110c}'], &['\u{1107}',  '\u{110e}'],
+        &['\u{1107}',  '\u{1110}'], &['\u{1107}',  '\u{1111}'], &['\u{1107}',  '\u{110b}'],
+        &['\u{1107}',  '\u{1107}',  '\u{110b}'], &['\u{1109}',  '\u{1100}'], &['\u{1109}',
+         '\u{1102}'], &['\u{1109}',  '\u{1103}'], &['\u{1109}',  '\u{1105}'], &['\u{1109}',
+         '\u{1106}'], &['\u{1109}',  '\u{1107}'], &['\u{1109}',  '\u{1107}',  '\u{1100}'],
+        &['\u{1109}',  '\u{1109}',  '\u{1109}'], &['\u{1109}',  '\u{110b}'], &['\u{1109}',
+         '\u{110c}'], &['\u{1109}',  '\u{110e}'], &['\u{1109}',  '\u{110f}'], &['\u{1109}',
+         '\u{1110}'], &['\u{1109}',  '\u{1111}'], &['\u{113c}',  '\u{113c}'], &['\u{113e}',
+         '\u{113e}'], &['\u{110b}',  '\u{1100}'], &['\u{110b}',  '\u{1103}'], &['\u{110b}',
+         '\u{1106}'], &['\u{110b}',  '\u{1107}'], &['\u{110b}',  '\u{1109}'], &['\u{110b}',
+         '\u{1140}'], &['\u{110b}',  '\u{110b}'], &['\u{110b}',  '\u{110c}'], &['\u{110b}',
+         '\u{110e}'], &['\u{110b}',  '\u{1110}'], &['\u{110b}',  '\u{1111}'], &['\u{110c}',
+         '\u{110b}'], &['\u{114e}',  '\u{114e}'], &['\u{1150}',  '\u{1150}'], &['\u{110e}',
+         '\u{110f}'], &['\u{110e}',  '\u{1112}'], &['\u{1111}',  '\u{1107}'], &['\u{1111}',
+         '\u{110b}'], &['\u{1112}',  '\u{1112}'], &['\u{1100}',  '\u{1103}'], &['\u{1102}',
+         '\u{1109}'], &['\u{1102}',  '\u{110c}'], &['\u{1102}',  '\u{1112}'], &['\u{1103}',
+         '\u{1105}'], &['\u{1161}',  '\u{4e28}'], &['\u{1163}',  '\u{4e28}'], &['\u{1165}',
+         '\u{4e28}'], &['\u{1167}',  '\u{4e28}'], &['\u{1169}',  '\u{1161}'], &['\u{1169}',
+         '\u{1161}',  '\u{4e28}'], &['\u{1169}',  '\u{4e28}'], &['\u{116e}',  '\u{1165}'],
+        &['\u{116e}',  '\u{1165}',  '\u{4e28}'], &['\u{116e}',  '\u{4e28}'], &['\u{30fc}'],
+        &['\u{30fc}',  '\u{4e28}'], &['\u{4e28}'], &['\u{1161}',  '\u{1169}'], &['\u{1161}',
+         '\u{116e}'], &['\u{1163}',  '\u{1169}'], &['\u{1163}',  '\u{116d}'], &['\u{1165}',
+         '\u{1169}'], &['\u{1165}',  '\u{116e}'], &['\u{1165}',  '\u{30fc}'], &['\u{1167}',
+         '\u{1169}'], &['\u{1167}',  '\u{116e}'], &['\u{1169}',  '\u{1165}'], &['\u{1169}',
+         '\u{1165}',  '\u{4e28}'], &['\u{1169}',  '\u{1167}',  '\u{4e28}'], &['\u{1169}',
+         '\u{1169}'], &['\u{1169}',  '\u{116e}'], &['\u{116d}',  '\u{1163}'], &['\u{116d}',
+         '\u{1163}',  '\u{4e28}'], &['\u{116d}',  '\u{1169}'], &['\u{116d}',  '\u{4e28}'],
+        &['\u{116e}',  '\u{1161}'], &['\u{116e}',  '\u{1161}',  '\u{4e28}'], &['\u{116e}',
+         '\u{1165}',  '\u{30fc}'], &['\u{116e}',  '\u{1167}',  '\u{4e28}'], &['\u{116e}',
+         '\u{116e}'], &['\u{1172}',  '\u{1161}'], &['\u{1172}',  '\u{1165}'], &['\u{1172}',
+         '\u{1165}',  '\u{4e28}'], &['\u{1172}',  '\u{1167}'], &['\u{1172}',  '\u{1167}',
+         '\u{4e28}'], &['\u{1172}',  '\u{116e}'], &['\u{1172}',  '\u{4e28}'], &['\u{30fc}',
+         '\u{116e}'], &['\u{30fc}',  '\u{30fc}'], &['\u{30fc}',  '\u{4e28}',  '\u{116e}'],
+        &['\u{4e28}',  '\u{1161}'], &['\u{4e28}',  '\u{1163}'], &['\u{4e28}',  '\u{1169}'],
+        &['\u{4e28}',  '\u{116e}'], &['\u{4e28}',  '\u{30fc}'], &['\u{4e28}',  '\u{119e}'],
+        &['\u{119e}',  '\u{1165}'], &['\u{119e}',  '\u{116e}'], &['\u{119e}',  '\u{4e28}'],
+        &['\u{119e}',  '\u{119e}'], &['\u{1161}',  '\u{30fc}'], &['\u{1163}',  '\u{116e}'],
+        &['\u{1167}',  '\u{1163}'], &['\u{1169}',  '\u{1163}'], &['\u{1169}',  '\u{1163}',
+         '\u{4e28}'], &['\u{1100}'], &['\u{1100}',  '\u{1109}'], &['\u{1102}'], &['\u{1103}'],
+        &['\u{1105}'], &['\u{1105}',  '\u{1100}'], &['\u{1105}',  '\u{1106}'], &['\u{1105}',
+         '\u{1107}'], &['\u{1105}',  '\u{1109}'], &['\u{1105}',  '\u{1110}'], &['\u{1105}',
+         '\u{1111}'], &['\u{1106}'], &['\u{1107}'], &['\u{1109}'], &['\u{110b}'], &['\u{110c}'],
+        &['\u{110e}'], &['\u{110f}'], &['\u{1110}'], &['\u{1111}'], &['\u{1112}'], &['\u{1100}',
+         '\u{1105}'], &['\u{1100}',  '\u{1109}',  '\u{1100}'], &['\u{1102}',  '\u{1140}'],
+        &['\u{1102}',  '\u{1110}'], &['\u{1105}',  '\u{1100}',  '\u{1109}'], &['\u{1105}',
+         '\u{1103}'], &['\u{1105}',  '\u{1103}',  '\u{1112}'], &['\u{1105}',  '\u{1106}',
+         '\u{1100}'], &['\u{1105}',  '\u{1106}',  '\u{1109}'], &['\u{1105}',  '\u{1107}',
+         '\u{1109}'], &['\u{1105}',  '\u{1107}',  '\u{1112}'], &['\u{1105}',  '\u{1107}',
+         '\u{110b}'], &['\u{1105}',  '\u{1109}',  '\u{1109}'], &['\u{1105}',  '\u{1140}'],
+        &['\u{1105}',  '\u{110f}'], &['\u{1105}',  '\u{1159}'], &['\u{1106}',  '\u{1100}'],
+        &['\u{1106}',  '\u{1105}'], &['\u{1106}',  '\u{1109}'], &['\u{1106}',  '\u{1109}',
+         '\u{1109}'], &['\u{1106}',  '\u{1140}'], &['\u{1106}',  '\u{110e}'], &['\u{1106}',
+         '\u{1112}'], &['\u{1107}',  '\u{1105}'], &['\u{1107}',  '\u{1112}'], &['\u{1140}'],
+        &['\u{110b}',  '\u{1100}',  '\u{1100}'], &['\u{110b}',  '\u{110f}'], &['\u{114c}'],
+        &['\u{1112}',  '\u{1102}'], &['\u{1112}',  '\u{1105}'], &['\u{1112}',  '\u{1106}'],
+        &['\u{1112}',  '\u{1107}'], &['\u{1159}'], &['\u{1100}',  '\u{1102}'], &['\u{1100}',
+         '\u{1107}'], &['\u{1100}',  '\u{110e}'], &['\u{1100}',  '\u{110f}'], &['\u{1100}',
+         '\u{1112}'], &['\u{548}'], &['\u{571}'], &['\u{44}'], &['\u{460}'], &['\u{34}'],
+        &['\u{4c}'], &['\u{3d}'], &['\u{394}'], &['\u{b7}',  '\u{1401}'], &['\u{1401}',  '\u{b7}'],
+        &['\u{b7}',  '\u{394}'], &['\u{394}',  '\u{b7}'], &['\u{b7}',  '\u{1404}'], &['\u{1404}',
+         '\u{b7}'], &['\u{b7}',  '\u{1405}'], &['\u{1405}',  '\u{b7}'], &['\u{b7}',  '\u{1406}'],
+        &['\u{1406}',  '\u{b7}'], &['\u{b7}',  '\u{140a}'], &['\u{140a}',  '\u{b7}'], &['\u{b7}',
+         '\u{140b}'], &['\u{140b}',  '\u{b7}'], &['\u{1401}',  '\u{1420}'], &['\u{394}',
+         '\u{1420}'], &['\u{1405}',  '\u{1420}'], &['\u{140a}',  '\u{1420}'], &['\u{b7}',
+         '\u{3e}'], &['\u{b7}',  '\u{56}'], &['\u{56}',  '\u{b7}'], &['\u{b7}',  '\u{245}'],
+        &['\u{245}',  '\u{b7}'], &['\u{b7}',  '\u{1432}'], &['\u{1432}',  '\u{b7}'], &['\u{3e}',
+         '\u{b7}'], &['\u{b7}',  '\u{1434}'], &['\u{1434}',  '\u{b7}'], &['\u{b7}',  '\u{3c}'],
+        &['\u{3c}',  '\u{b7}'], &['\u{b7}',  '\u{1439}'], &['\u{1439}',  '\u{b7}'], &['\u{b7}',
+         '\u{1450}'], &['\u{b7}',  '\u{55}'], &['\u{55}',  '\u{b7}'], &['\u{b7}',  '\u{548}'],
+        &['\u{548}',  '\u{b7}'], &['\u{b7}',  '\u{144f}'], &['\u{144f}',  '\u{b7}'], &['\u{1450}',
+         '\u{b7}'], &['\u{b7}',  '\u{1451}'], &['\u{1451}',  '\u{b7}'], &['\u{b7}',  '\u{1455}'],
+        &['\u{1455}',  '\u{b7}'], &['\u{b7}',  '\u{1456}'], &['\u{1456}',  '\u{b7}'], &['\u{55}',
+         '\u{27}'], &['\u{548}',  '\u{27}'], &['\u{1450}',  '\u{27}'], &['\u{1455}',  '\u{27}'],
+        &['\u{62}',  '\u{307}'], &['\u{b7}',  '\u{146b}'], &['\u{146b}',  '\u{b7}'], &['\u{b7}',
+         '\u{50}'], &['\u{70}',  '\u{b7}'], &['\u{b7}',  '\u{146e}'], &['\u{146e}',  '\u{b7}'],
+        &['\u{b7}',  '\u{64}'], &['\u{64}',  '\u{b7}'], &['\u{b7}',  '\u{1470}'], &['\u{1470}',
+         '\u{b7}'], &['\u{b7}',  '\u{62}'], &['\u{62}',  '\u{b7}'], &['\u{b7}',  '\u{62}',
+         '\u{307}'], &['\u{62}',  '\u{307}',  '\u{b7}'], &['\u{146b}',  '\u{27}'], &['\u{50}',
+         '\u{27}'], &['\u{64}',  '\u{27}'], &['\u{62}',  '\u{27}'], &['\u{b7}',  '\u{1489}'],
+        &['\u{1489}',  '\u{b7}'], &['\u{b7}',  '\u{148b}'], &['\u{148b}',  '\u{b7}'], &['\u{b7}',
+         '\u{148c}'], &['\u{148c}',  '\u{b7}'], &['\u{b7}',  '\u{4a}'], &['\u{4a}',  '\u{b7}'],
+        &['\u{b7}',  '\u{148e}'], &['\u{148e}',  '\u{b7}'], &['\u{b7}',  '\u{1490}'], &['\u{1490}',
+         '\u{b7}'], &['\u{b7}',  '\u{1491}'], &['\u{1491}',  '\u{b7}'], &['\u{b7}',  '\u{14a3}'],
+        &['\u{14a3}',  '\u{b7}'], &['\u{b7}',  '\u{393}'], &['\u{393}',  '\u{b7}'], &['\u{b7}',
+         '\u{14a6}'], &['\u{14a6}',  '\u{b7}'], &['\u{b7}',  '\u{14a7}'], &['\u{14a7}',  '\u{b7}'],
+        &['\u{b7}',  '\u{14a8}'], &['\u{14a8}',  '\u{b7}'], &['\u{b7}',  '\u{4c}'], &['\u{b7}',
+         '\u{14ab}'], &['\u{14ab}',  '\u{b7}'], &['\u{b7}',  '\u{14c0}'], &['\u{14c0}',  '\u{b7}'],
+        &['\u{b7}',  '\u{14c7}'], &['\u{14c7}',  '\u{b7}'], &['\u{b7}',  '\u{14c8}'], &['\u{14c8}',
+         '\u{b7}'], &['\u{1421}'], &['\u{b7}',  '\u{14d3}'], &['\u{14d3}',  '\u{b7}'], &['\u{b7}',
+         '\u{14d5}'], &['\u{14d5}',  '\u{b7}'], &['\u{b7}',  '\u{14d6}'], &['\u{14d6}',  '\u{b7}'],
+        &['\u{b7}',  '\u{14d7}'], &['\u{14d7}',  '\u{b7}'], &['\u{b7}',  '\u{14d8}'], &['\u{14d8}',
+         '\u{b7}'], &['\u{b7}',  '\u{14da}'], &['\u{14da}',  '\u{b7}'], &['\u{b7}',  '\u{14db}'],
+        &['\u{14db}',  '\u{b7}'], &['\u{b7}',  '\u{14ed}'], &['\u{14ed}',  '\u{b7}'], &['\u{b7}',
+         '\u{14ef}'], &['\u{14ef}',  '\u{b7}'], &['\u{b7}',  '\u{14f0}'], &['\u{14f0}',  '\u{b7}'],
+        &['\u{b7}',  '\u{14f1}'], &['\u{14f1}',  '\u{b7}'], &['\u{b7}',  '\u{14f2}'], &['\u{14f2}',
+         '\u{b7}'], &['\u{b7}',  '\u{14f4}'], &['\u{14f4}',  '\u{b7}'], &['\u{b7}',  '\u{14f5}'],
+        &['\u{14f5}',  '\u{b7}'], &['\u{150b}',  '\u{3c}'], &['\u{150b}',  '\u{1455}'],
+        &['\u{150b}',  '\u{62}'], &['\u{150b}',  '\u{1490}'], &['\u{b7}',  '\u{1510}'],
+        &['\u{1510}',  '\u{b7}'], &['\u{b7}',  '\u{1511}'], &['\u{1511}',  '\u{b7}'], &['\u{b7}',
+         '\u{1512}'], &['\u{1512}',  '\u{b7}'], &['\u{b7}',  '\u{1513}'], &['\u{1513}',  '\u{b7}'],
+        &['\u{b7}',  '\u{1514}'], &['\u{1514}',  '\u{b7}'], &['\u{b7}',  '\u{1515}'], &['\u{1515}',
+         '\u{b7}'], &['\u{b7}',  '\u{1516}'], &['\u{1516}',  '\u{b7}'], &['\u{b7}',  '\u{34}'],
+        &['\u{34}',  '\u{b7}'], &['\u{b7}',  '\u{1528}'], &['\u{1528}',  '\u{b7}'], &['\u{b7}',
+         '\u{1529}'], &['\u{1529}',  '\u{b7}'], &['\u{b7}',  '\u{152a}'], &['\u{152a}',  '\u{b7}'],
+        &['\u{b7}',  '\u{152b}'], &['\u{152b}',  '\u{b7}'], &['\u{b7}',  '\u{152d}'], &['\u{152d}',
+         '\u{b7}'], &['\u{b7}',  '\u{152e}'], &['\u{152e}',  '\u{b7}'], &['\u{1429}'], &['\u{b7}',
+         '\u{154c}'], &['\u{154c}',  '\u{b7}'], &['\u{b7}',  '\u{155a}'], &['\u{155a}',  '\u{b7}'],
+        &['\u{b7}',  '\u{1567}'], &['\u{1567}',  '\u{b7}'], &['\u{1550}',  '\u{146c}'],
+        &['\u{1550}',  '\u{50}'], &['\u{1550}',  '\u{146e}'], &['\u{1550}',  '\u{64}'],
+        &['\u{1550}',  '\u{1470}'], &['\u{1550}',  '\u{62}'], &['\u{1550}',  '\u{62}',  '\u{307}'],
+        &['\u{1550}',  '\u{1483}'], &['\u{1595}',  '\u{148a}'], &['\u{1595}',  '\u{148b}'],
+        &['\u{1595}',  '\u{148c}'], &['\u{1595}',  '\u{4a}'], &['\u{1595}',  '\u{148e}'],
+        &['\u{1595}',  '\u{1490}'], &['\u{1595}',  '\u{1491}'], &['\u{2132}'], &['\u{a7fb}'],
+        &['\u{2c6f}'], &['\u{1490}'], &['\u{1489}'], &['\u{14d3}'], &['\u{14da}'], &['\u{1543}'],
+        &['\u{1546}'], &['\u{154a}'], &['\u{1b1}'], &['\u{3a9}'], &['\u{1550}',  '\u{146b}'],
+        &['\u{1595}',  '\u{1489}'], &['\u{1596}',  '\u{148b}'], &['\u{1596}',  '\u{148c}'],
+        &['\u{1596}',  '\u{4a}'], &['\u{1596}',  '\u{148e}'], &['\u{1596}',  '\u{1490}'],
+        &['\u{1596}',  '\u{1491}'], &['\u{15a7}',  '\u{b7}'], &['\u{15a8}',  '\u{b7}'],
+        &['\u{15a9}',  '\u{b7}'], &['\u{15aa}',  '\u{b7}'], &['\u{15ab}',  '\u{b7}'], &['\u{15ac}',
+         '\u{b7}'], &['\u{15ad}',  '\u{b7}'], &['\u{16bd}'], &['\u{16bc}'], &['\u{2b}'],
+        &['\u{1715}'], &['\u{2f}'], &['\u{178a}'], &['\u{17a2}'], &['\u{e34}'], &['\u{e35}'],
+        &['\u{e36}'], &['\u{e37}'], &['\u{e2f}'], &['\u{e5a}'], &['\u{e4f}'], &['\u{e5b}'],
+        &['\u{1835}'], &['\u{185c}'], &['\u{b7}',  '\u{18b1}'], &['\u{b7}',  '\u{18b4}'],
+        &['\u{b7}',  '\u{18b8}'], &['\u{b7}',  '\u{18c0}'], &['\u{b7}',  '\u{14c2}'], &['\u{14c2}',
+         '\u{b7}'], &['\u{b7}',  '\u{14c3}'], &['\u{14c3}',  '\u{b7}'], &['\u{b7}',  '\u{14c4}'],
+        &['\u{14c4}',  '\u{b7}'], &['\u{b7}',  '\u{14c5}'], &['\u{14c5}',  '\u{b7}'], &['\u{b7}',
+         '\u{1543}'], &['\u{b7}',  '\u{1546}'], &['\u{b7}',  '\u{1547}'], &['\u{b7}',  '\u{1548}'],
+        &['\u{b7}',  '\u{1549}'], &['\u{b7}',  '\u{154b}'], &['\u{18df}',  '\u{141e}'],
+        &['\u{141e}',  '\u{18df}'], &['\u{1543}',  '\u{b7}'], &['\u{155e}',  '\u{b7}'],
+        &['\u{1566}',  '\u{b7}'], &['\u{156b}',  '\u{b7}'], &['\u{1586}',  '\u{b7}'], &['\u{1597}',
+         '\u{b7}'], &['\u{460}',  '\u{b7}'], &['\u{15f4}',  '\u{b7}'], &['\u{161b}',  '\u{b7}'],
+        &['\u{199e}'], &['\u{19b1}'], &['\u{1a45}'], &['\u{1aa8}',  '\u{1aa8}'], &['\u{1aaa}',
+         '\u{1aa8}'], &['\u{6db}'], &['\u{1ac6}'], &['\u{1ae5}'], &['\u{304}',  '\u{304}'],
+        &['\u{1b0d}'], &['\u{1b11}'], &['\u{1b28}'], &['\u{1b50}'], &['\u{1b5e}',  '\u{1b5e}'],
+        &['\u{1c3b}',  '\u{1c3b}'], &['\u{1c7e}',  '\u{1c7e}'], &['\u{32b}'], &['\u{32e}'],
+        &['\u{32d}'], &['\u{30e}'], &['\u{316}'], &['\u{1dd}',  '\u{6f}'], &['\u{7a}'],
+        &['\u{28c}'], &['\u{1d18}'], &['\u{43b}'], &['\u{18d6}'], &['\u{ba}'], &['\u{75}',
+         '\u{65}'], &['\u{66}',  '\u{334}'], &['\u{72}',  '\u{6e}',  '\u{334}'], &['\u{6e}',
+         '\u{334}'], &['\u{72}',  '\u{334}'], &['\u{27e}',  '\u{334}'], &['\u{73}',  '\u{334}'],
+        &['\u{74}',  '\u{334}'], &['\u{7a}',  '\u{334}'], &['\u{1d34}'], &['\u{70}',  '\u{335}'],
+        &['\u{75}',  '\u{335}'], &['\u{28a}',  '\u{335}'], &['\u{24b}'], &['\u{1d4b}'],
+        &['\u{1d4d}'], &['\u{18d4}'], &['\u{1646}'], &['\u{1ada}'], &['\u{2dec}'], &['\u{ab51}'],
+        &['\u{1ea3}'], &['\u{1ff4}'], &['\u{13ef}'], &['\u{2e}',  '\u{2e}'], &['\u{2e}',  '\u{2e}',
+         '\u{2e}'], &['\u{27}',  '\u{27}',  '\u{27}'], &['\u{21}',  '\u{21}'], &['\u{3f}',
+         '\u{3f}'], &['\u{3f}',  '\u{21}'], &['\u{21}',  '\u{3f}'], &['\u{27}',  '\u{27}',
+         '\u{27}',  '\u{27}'], &['\u{2d57}'], &['\u{2d42}'], &['\u{a770}'], &['\u{43}',
+         '\u{20eb}'], &['\u{a3}'], &['\u{72}',  '\u{6e}',  '\u{338}'], &['\u{52}',  '\u{73}'],
+        &['\u{57}',  '\u{335}'], &['\u{64}',  '\u{335}',  '\u{331}'], &['\u{54}',  '\u{20eb}'],
+        &['\u{6c}',  '\u{74}'], &['\u{554}'], &['\u{631}',  '\u{649}',  '\u{6c}',  '\u{644}'],
+        &['\u{61}',  '\u{2f}',  '\u{63}'], &['\u{61}',  '\u{2f}',  '\u{73}'], &['\u{b0}',
+         '\u{43}'], &['\u{63}',  '\u{2f}',  '\u{6f}'], &['\u{63}',  '\u{2f}',  '\u{75}'],
+        &['\u{42d}'], &['\u{b0}',  '\u{46}'], &['\u{4e}',  '\u{6f}'], &['\u{51}'], &['\u{54}',
+         '\u{45}',  '\u{4c}'], &['\u{27f}'], &['\u{5d0}'], &['\u{5d1}'], &['\u{5d2}'], &['\u{5d3}'],
+        &['\u{46}',  '\u{41}',  '\u{58}'], &['\u{a4e8}'], &['\u{a4f6}'], &['\u{16f00}'], &['\u{6c}',
+         '\u{6c}',  '\u{6c}'], &['\u{6c}',  '\u{56}'], &['\u{56}',  '\u{6c}'], &['\u{56}',
+         '\u{6c}',  '\u{6c}'], &['\u{56}',  '\u{6c}',  '\u{6c}',  '\u{6c}'], &['\u{6c}',  '\u{58}'],
+        &['\u{58}',  '\u{6c}'], &['\u{58}',  '\u{6c}',  '\u{6c}'], &['\u{69}',  '\u{69}'],
+        &['\u{69}',  '\u{69}',  '\u{69}'], &['\u{69}',  '\u{76}'], &['\u{76}',  '\u{69}'],
+        &['\u{76}',  '\u{69}',  '\u{69}'], &['\u{76}',  '\u{69}',  '\u{69}',  '\u{69}'], &['\u{69}',
+         '\u{78}'], &['\u{78}',  '\u{69}'], &['\u{78}',  '\u{69}',  '\u{69}'], &['\u{16cf}'],
+        &['\u{16e8}'], &['\u{21b2}'], &['\u{1f10e}'], &['\u{16da}'], &['\u{16d0}'], &['\u{1f8d0}'],
+        &['\u{1f8d1}'], &['\u{18e}'], &['\u{2b}',  '\u{307}'], &['\u{5c}'], &['\u{6f}',  '\u{6f}'],
+        &['\u{283}'], &['\u{283}',  '\u{283}'], &['\u{283}',  '\u{283}',  '\u{283}'], &['\u{222e}',
+         '\u{222e}'], &['\u{222e}',  '\u{222e}',  '\u{222e}'], &['\u{2d}',  '\u{307}'], &['\u{3d}',
+         '\u{307}'], &['\u{3d}',  '\u{307}',  '\u{323}'], &['\u{3d}',  '\u{30a}'], &['\u{3d}',
+         '\u{302}'], &['\u{3d}',  '\u{306}'], &['\u{3d}',  '\u{36b}'], &['\u{2261}'], &['\u{3c}',
+         '\u{3c}'], &['\u{3e}',  '\u{3e}'], &['\u{1455}'], &['\u{1450}'], &['\u{102a8}'],
+        &['\u{298}'], &['\u{a4d5}'], &['\u{2227}'], &['\u{16dc}'], &['\u{16de}'], &['\u{3c}',
+         '\u{3c}',  '\u{3c}'], &['\u{3e}',  '\u{3e}',  '\u{3e}'], &['\u{b7}',  '\u{b7}',  '\u{b7}'],
+        &['\u{2205}'], &['\u{2324}'], &['\u{276c}'], &['\u{276d}'], &['\u{303c}'], &['\u{394}',
+         '\u{332}'], &['\u{16dc}',  '\u{332}'], &['\u{b0}',  '\u{332}'], &['\u{229b}'], &['\u{54}',
+         '\u{308}'], &['\u{2207}',  '\u{308}'], &['\u{22c6}',  '\u{308}'], &['\u{b0}',  '\u{308}'],
+        &['\u{7e}',  '\u{308}'], &['\u{1435}'], &['\u{2207}',  '\u{334}'], &['\u{3c9}'], &['\u{61}',
+         '\u{332}'], &['\u{a793}',  '\u{332}'], &['\u{69}',  '\u{332}'], &['\u{3c9}',  '\u{332}'],
+        &['\u{2355}'], &['\u{234e}'], &['\u{234b}'], &['\u{236d}'], &['\u{2081}',  '\u{2080}'],
+        &['\u{23fb}'], &['\u{263e}'], &['\u{5c}',  '\u{5c}'], &['\u{2780}'], &['\u{2781}'],
+        &['\u{2782}'], &['\u{2783}'], &['\u{2784}'], &['\u{2785}'], &['\u{2786}'], &['\u{2787}'],
+        &['\u{2788}'], &['\u{2789}'], &['\u{28}',  '\u{6c}',  '\u{29}'], &['\u{28}',  '\u{32}',
+         '\u{29}'], &['\u{28}',  '\u{33}',  '\u{29}'], &['\u{28}',  '\u{34}',  '\u{29}'],
+        &['\u{28}',  '\u{35}',  '\u{29}'], &['\u{28}',  '\u{36}',  '\u{29}'], &['\u{28}',  '\u{37}',
+         '\u{29}'], &['\u{28}',  '\u{38}',  '\u{29}'], &['\u{28}',  '\u{39}',  '\u{29}'],
+        &['\u{28}',  '\u{6c}',  '\u{4f}',  '\u{29}'], &['\u{28}',  '\u{6c}',  '\u{6c}',  '\u{29}'],
+        &['\u{28}',  '\u{6c}',  '\u{32}',  '\u{29}'], &['\u{28}',  '\u{6c}',  '\u{33}',  '\u{29}'],
+        &['\u{28}',  '\u{6c}',  '\u{34}',  '\u{29}'], &['\u{28}',  '\u{6c}',  '\u{35}',  '\u{29}'],
+        &['\u{28}',  '\u{6c}',  '\u{36}',  '\u{29}'], &['\u{28}',  '\u{6c}',  '\u{37}',  '\u{29}'],
+        &['\u{28}',  '\u{6c}',  '\u{38}',  '\u{29}'], &['\u{28}',  '\u{6c}',  '\u{39}',  '\u{29}'],
+        &['\u{28}',  '\u{32}',  '\u{4f}',  '\u{29}'], &['\u{6c}',  '\u{2e}'], &['\u{32}',
+         '\u{2e}'], &['\u{33}',  '\u{2e}'], &['\u{34}',  '\u{2e}'], &['\u{35}',  '\u{2e}'],
+        &['\u{36}',  '\u{2e}'], &['\u{37}',  '\u{2e}'], &['\u{38}',  '\u{2e}'], &['\u{39}',
+         '\u{2e}'], &['\u{6c}',  '\u{4f}',  '\u{2e}'], &['\u{6c}',  '\u{6c}',  '\u{2e}'],
+        &['\u{6c}',  '\u{32}',  '\u{2e}'], &['\u{6c}',  '\u{33}',  '\u{2e}'], &['\u{6c}',  '\u{34}',
+         '\u{2e}'], &['\u{6c}',  '\u{35}',  '\u{2e}'], &['\u{6c}',  '\u{36}',  '\u{2e}'],
+        &['\u{6c}',  '\u{37}',  '\u{2e}'], &['\u{6c}',  '\u{38}',  '\u{2e}'], &['\u{6c}',  '\u{39}',
+         '\u{2e}'], &['\u{32}',  '\u{4f}',  '\u{2e}'], &['\u{28}',  '\u{61}',  '\u{29}'],
+        &['\u{28}',  '\u{62}',  '\u{29}'], &['\u{28}',  '\u{63}',  '\u{29}'], &['\u{28}',  '\u{64}',
+         '\u{29}'], &['\u{28}',  '\u{65}',  '\u{29}'], &['\u{28}',  '\u{66}',  '\u{29}'],
+        &['\u{28}',  '\u{67}',  '\u{29}'], &['\u{28}',  '\u{68}',  '\u{29}'], &['\u{28}',  '\u{69}',
+         '\u{29}'], &['\u{28}',  '\u{6a}',  '\u{29}'], &['\u{28}',  '\u{6b}',  '\u{29}'],
+        &['\u{28}',  '\u{72}',  '\u{6e}',  '\u{29}'], &['\u{28}',  '\u{6e}',  '\u{29}'], &['\u{28}',
+         '\u{6f}',  '\u{29}'], &['\u{28}',  '\u{70}',  '\u{29}'], &['\u{28}',  '\u{71}',  '\u{29}'],
+        &['\u{28}',  '\u{72}',  '\u{29}'], &['\u{28}',  '\u{73}',  '\u{29}'], &['\u{28}',  '\u{74}',
+         '\u{29}'], &['\u{28}',  '\u{75}',  '\u{29}'], &['\u{28}',  '\u{76}',  '\u{29}'],
+        &['\u{28}',  '\u{77}',  '\u{29}'], &['\u{28}',  '\u{78}',  '\u{29}'], &['\u{28}',  '\u{79}',
+         '\u{29}'], &['\u{28}',  '\u{7a}',  '\u{29}'], &['\u{a9}'], &['\u{2117}'], &['\u{ae}'],
+        &['\u{24be}'], &['\u{1f10d}'], &['\u{2502}'], &['\u{250c}'], &['\u{251c}'], &['\u{1c2}'],
+        &['\u{220e}'], &['\u{258c}'], &['\u{2596}'], &['\u{2598}'], &['\u{23e5}'], &['\u{22b3}'],
+        &['\u{25b6}'], &['\u{102bc}'], &['\u{22b2}'], &['\u{233e}'], &['\u{2312}'], &['\u{25a1}'],
+        &['\u{1099e}'], &['\u{39e}'], &['\u{2388}'], &['\u{224f}'], &['\u{1fa55}'], &['\u{1fa57}'],
+        &['\u{1d158}',  '\u{1d165}'], &['\u{1d158}',  '\u{1d165}',  '\u{1d16e}'], &['\u{28}'],
+        &['\u{29}'], &['\u{7b}'], &['\u{7d}'], &['\u{f7}'], &['\u{5c}',  '\u{1455}'], &['\u{1450}',
+         '\u{2f}'], &['\u{1cee0}'], &['\u{16d0}',  '\u{16da}'], &['\u{21c3}',  '\u{21c2}'],
+        &['\u{16d0}',  '\u{21c2}'], &['\u{21c3}',  '\u{16da}'], &['\u{2349}'], &['\u{1cef0}'],
+        &['\u{2342}'], &['\u{233b}'], &['\u{102c0}'], &['\u{299a}'], &['\u{3a}',  '\u{2192}'],
+        &['\u{2f}',  '\u{304}'], &['\u{2297}'], &['\u{228d}'], &['\u{228e}'], &['\u{2293}'],
+        &['\u{2294}'], &['\u{283}',  '\u{283}',  '\u{283}',  '\u{283}'], &['\u{2b}',  '\u{30a}'],
+        &['\u{2b}',  '\u{302}'], &['\u{2b}',  '\u{303}'], &['\u{2b}',  '\u{323}'], &['\u{2b}',
+         '\u{330}'], &['\u{2b}',  '\u{2082}'], &['\u{2d}',  '\u{313}'], &['\u{2d}',  '\u{323}'],
+        &['\u{78}',  '\u{307}'], &['\u{2319}'], &['\u{2a1f}'], &['\u{2210}'], &['\u{7e}',
+         '\u{307}'], &['\u{3d}',  '\u{20f0}'], &['\u{3a}',  '\u{3a}',  '\u{3d}'], &['\u{3d}',
+         '\u{3d}'], &['\u{3d}',  '\u{3d}',  '\u{3d}'], &['\u{3e}',  '\u{3c}'], &['\u{15d5}'],
+        &['\u{15d2}'], &['\u{1450}',  '\u{1455}'], &['\u{2f}',  '\u{2f}',  '\u{2f}'], &['\u{2f}',
+         '\u{2f}'], &['\u{3d}',  '\u{1ab2}'], &['\u{219e}'], &['\u{219f}'], &['\u{21a0}'],
+        &['\u{21a1}'], &['\u{2c6b}'], &['\u{2c6c}'], &['\u{3bb}'], &['\u{274}'], &['\u{293}'],
+        &['\u{3c7}'], &['\u{a64c}'], &['\u{428}'], &['\u{3fc}'], &['\u{29f}'], &['\u{3d7}'],
+        &['\u{2627}'], &['\u{16ef}'], &['\u{1ddf}'], &['\u{368}'], &['\u{1adb}'], &['\u{36f}'],
+        &['\u{363}'], &['\u{364}'], &['\u{2d}',  '\u{308}'], &['\u{7e}',  '\u{323}'], &['\u{28}',
+         '\u{28}'], &['\u{29}',  '\u{29}'], &['\u{2235}'], &['\u{2234}'], &['\u{2237}'],
+        &['\u{61f}'], &['\u{61b}'], &['\u{b6}'], &['\u{4e5b}'], &['\u{4e5a}'], &['\u{4ebb}'],
+        &['\u{5202}'], &['\u{353e}'], &['\u{5140}'], &['\u{5c23}'], &['\u{5c22}'], &['\u{5df3}'],
+        &['\u{5e7a}'], &['\u{5f51}'], &['\u{5fc4}'], &['\u{38fa}'], &['\u{624c}'], &['\u{6535}'],
+        &['\u{65e1}'], &['\u{6b7a}'], &['\u{6bcd}'], &['\u{6c11}'], &['\u{6c35}'], &['\u{6c3a}'],
+        &['\u{706c}'], &['\u{722b}'], &['\u{4e2c}'], &['\u{72ad}'], &['\u{7f52}'], &['\u{793b}'],
+        &['\u{7cf9}'], &['\u{7f53}'], &['\u{8002}'], &['\u{8080}'], &['\u{8279}'], &['\u{864e}'],
+        &['\u{8864}'], &['\u{8980}'], &['\u{897f}'], &['\u{89c1}'], &['\u{8ba0}'], &['\u{8d1d}'],
+        &['\u{8f66}'], &['\u{8fb6}'], &['\u{961d}'], &['\u{9485}'], &['\u{1110}',  '\u{30fc}',
+         '\u{1102}',  '\u{110c}'], &['\u{9578}'], &['\u{957f}'], &['\u{95e8}'], &['\u{9752}'],
+        &['\u{97e6}'], &['\u{9875}'], &['\u{98ce}'], &['\u{98de}'], &['\u{98df}'], &['\u{98e0}'],
+        &['\u{9963}'], &['\u{9a6c}'], &['\u{9b3c}'], &['\u{9c7c}'], &['\u{9ea6}'], &['\u{9ec4}'],
+        &['\u{6589}'], &['\u{9f50}'], &['\u{6b6f}'], &['\u{9f7f}'], &['\u{7adc}'], &['\u{9f99}'],
+        &['\u{4e80}'], &['\u{9f9f}'], &['\u{4e59}'], &['\u{4e85}'], &['\u{4e8c}'], &['\u{4ea0}'],
+        &['\u{4eba}'], &['\u{513f}'], &['\u{5165}'], &['\u{516b}'], &['\u{5182}'], &['\u{5196}'],
+        &['\u{51ab}'], &['\u{51e0}'], &['\u{51f5}'], &['\u{5200}'], &['\u{529b}'], &['\u{52f9}'],
+        &['\u{5315}'], &['\u{531a}'], &['\u{5338}'], &['\u{5341}'], &['\u{535c}'], &['\u{5369}'],
+        &['\u{5382}'], &['\u{53b6}'], &['\u{53c8}'], &['\u{53e3}'], &['\u{571f}'], &['\u{5902}'],
+        &['\u{590a}'], &['\u{5915}'], &['\u{5927}'], &['\u{5973}'], &['\u{5b50}'], &['\u{5b80}'],
+        &['\u{5bf8}'], &['\u{5c0f}'], &['\u{5c38}'], &['\u{5c6e}'], &['\u{5c71}'], &['\u{5ddb}'],
+        &['\u{5de5}'], &['\u{5df1}'], &['\u{5dfe}'], &['\u{5e72}'], &['\u{5e7f}'], &['\u{5ef4}'],
+        &['\u{5efe}'], &['\u{5f0b}'], &['\u{5f13}'], &['\u{5f50}'], &['\u{5f61}'], &['\u{5f73}'],
+        &['\u{5fc3}'], &['\u{6208}'], &['\u{6236}'], &['\u{624b}'], &['\u{652f}'], &['\u{6534}'],
+        &['\u{6587}'], &['\u{6597}'], &['\u{65a4}'], &['\u{65b9}'], &['\u{65e0}'], &['\u{65e5}'],
+        &['\u{66f0}'], &['\u{6708}'], &['\u{6728}'], &['\u{6b20}'], &['\u{6b62}'], &['\u{6b79}'],
+        &['\u{6bb3}'], &['\u{6bcb}'], &['\u{6bd4}'], &['\u{6bdb}'], &['\u{6c0f}'], &['\u{6c14}'],
+        &['\u{6c34}'], &['\u{706b}'], &['\u{722a}'], &['\u{7236}'], &['\u{723b}'], &['\u{1102}',
+         '\u{116e}',  '\u{4e28}'], &['\u{7247}'], &['\u{7259}'], &['\u{725b}'], &['\u{72ac}'],
+        &['\u{7384}'], &['\u{7389}'], &['\u{74dc}'], &['\u{74e6}'], &['\u{7518}'], &['\u{751f}'],
+        &['\u{7528}'], &['\u{7530}'], &['\u{758b}'], &['\u{7592}'], &['\u{7676}'], &['\u{767d}'],
+        &['\u{76ae}'], &['\u{76bf}'], &['\u{76ee}'], &['\u{77db}'], &['\u{77e2}'], &['\u{77f3}'],
+        &['\u{793a}'], &['\u{79b8}'], &['\u{79be}'], &['\u{7a74}'], &['\u{7acb}'], &['\u{7af9}'],
+        &['\u{7c73}'], &['\u{7cf8}'], &['\u{7f36}'], &['\u{7f51}'], &['\u{7f8a}'], &['\u{7fbd}'],
+        &['\u{8001}'], &['\u{800c}'], &['\u{8012}'], &['\u{8033}'], &['\u{807f}'], &['\u{8089}'],
+        &['\u{81e3}'], &['\u{81ea}'], &['\u{81f3}'], &['\u{81fc}'], &['\u{820c}'], &['\u{821b}'],
+        &['\u{821f}'], &['\u{826e}'], &['\u{8272}'], &['\u{8278}'], &['\u{864d}'], &['\u{866b}'],
+        &['\u{8840}'], &['\u{884c}'], &['\u{8863}'], &['\u{897e}'], &['\u{898b}'], &['\u{89d2}'],
+        &['\u{8a00}'], &['\u{8c37}'], &['\u{8c46}'], &['\u{8c55}'], &['\u{8c78}'], &['\u{8c9d}'],
+        &['\u{8d64}'], &['\u{8d70}'], &['\u{8db3}'], &['\u{8eab}'], &['\u{8eca}'], &['\u{8f9b}'],
+        &['\u{8fb0}'], &['\u{8fb5}'], &['\u{9091}'], &['\u{9149}'], &['\u{91c6}'], &['\u{91cc}'],
+        &['\u{91d1}'], &['\u{9580}'], &['\u{961c}'], &['\u{96b6}'], &['\u{96b9}'], &['\u{96e8}'],
+        &['\u{9751}'], &['\u{975e}'], &['\u{9762}'], &['\u{9769}'], &['\u{97cb}'], &['\u{97ed}'],
+        &['\u{97f3}'], &['\u{9801}'], &['\u{98a8}'], &['\u{98db}'], &['\u{9996}'], &['\u{9999}'],
+        &['\u{99ac}'], &['\u{9aa8}'], &['\u{9ad8}'], &['\u{9adf}'], &['\u{9b25}'], &['\u{9b2f}'],
+        &['\u{9b32}'], &['\u{9b5a}'], &['\u{9ce5}'], &['\u{9e75}'], &['\u{9e7f}'], &['\u{9ea5}'],
+        &['\u{9ebb}'], &['\u{9ec3}'], &['\u{9ecd}'], &['\u{9ed1}'], &['\u{9ef9}'], &['\u{9efd}'],
+        &['\u{9f0e}'], &['\u{9f13}'], &['\u{9f20}'], &['\u{9f3b}'], &['\u{9f4a}'], &['\u{9f52}'],
+        &['\u{9f8d}'], &['\u{9f9c}'], &['\u{9fa0}'], &['\u{2f3}'], &['\u{20b8}'], &['\u{27e6}'],
+        &['\u{27e7}'], &['\u{309}'], &['\u{5344}'], &['\u{5345}'], &['\u{ff9e}'], &['\u{ff9f}'],
+        &['\u{3078}'], &['\u{1161}'], &['\u{1163}'], &['\u{1165}'], &['\u{1167}'], &['\u{1169}'],
+        &['\u{116d}'], &['\u{116e}'], &['\u{1172}'], &['\u{1160}'], &['\u{119e}'], &['\u{28}',
+         '\u{1100}',  '\u{29}'], &['\u{28}',  '\u{1102}',  '\u{29}'], &['\u{28}',  '\u{1103}',
+         '\u{29}'], &['\u{28}',  '\u{1105}',  '\u{29}'], &['\u{28}',  '\u{1106}',  '\u{29}'],
+        &['\u{28}',  '\u{1107}',  '\u{29}'], &['\u{28}',  '\u{1109}',  '\u{29}'], &['\u{28}',
+         '\u{110b}',  '\u{29}'], &['\u{28}',  '\u{110c}',  '\u{29}'], &['\u{28}',  '\u{110e}',
+         '\u{29}'], &['\u{28}',  '\u{110f}',  '\u{29}'], &['\u{28}',  '\u{1110}',  '\u{29}'],
+        &['\u{28}',  '\u{1111}',  '\u{29}'], &['\u{28}',  '\u{1112}',  '\u{29}'], &['\u{28}',
+         '\u{ac00}',  '\u{29}'], &['\u{28}',  '\u{b098}',  '\u{29}'], &['\u{28}',  '\u{b2e4}',
+         '\u{29}'], &['\u{28}',  '\u{b77c}',  '\u{29}'], &['\u{28}',  '\u{b9c8}',  '\u{29}'],
+        &['\u{28}',  '\u{bc14}',  '\u{29}'], &['\u{28}',  '\u{c0ac}',  '\u{29}'], &['\u{28}',
+         '\u{c544}',  '\u{29}'], &['\u{28}',  '\u{c790}',  '\u{29}'], &['\u{28}',  '\u{cc28}',
+         '\u{29}'], &['\u{28}',  '\u{ce74}',  '\u{29}'], &['\u{28}',  '\u{d0c0}',  '\u{29}'],
+        &['\u{28}',  '\u{d30c}',  '\u{29}'], &['\u{28}',  '\u{d558}',  '\u{29}'], &['\u{28}',
+         '\u{c8fc}',  '\u{29}'], &['\u{28}',  '\u{c624}',  '\u{c804}',  '\u{29}'], &['\u{28}',
+         '\u{c624}',  '\u{d6c4}',  '\u{29}'], &['\u{28}',  '\u{30fc}',  '\u{29}'], &['\u{28}',
+         '\u{4e8c}',  '\u{29}'], &['\u{28}',  '\u{4e09}',  '\u{29}'], &['\u{28}',  '\u{56db}',
+         '\u{29}'], &['\u{28}',  '\u{4e94}',  '\u{29}'], &['\u{28}',  '\u{516d}',  '\u{29}'],
+        &['\u{28}',  '\u{4e03}',  '\u{29}'], &['\u{28}',  '\u{516b}',  '\u{29}'], &['\u{28}',
+         '\u{4e5d}',  '\u{29}'], &['\u{28}',  '\u{5341}',  '\u{29}'], &['\u{28}',  '\u{6708}',
+         '\u{29}'], &['\u{28}',  '\u{706b}',  '\u{29}'], &['\u{28}',  '\u{6c34}',  '\u{29}'],
+        &['\u{28}',  '\u{6728}',  '\u{29}'], &['\u{28}',  '\u{91d1}',  '\u{29}'], &['\u{28}',
+         '\u{571f}',  '\u{29}'], &['\u{28}',  '\u{65e5}',  '\u{29}'], &['\u{28}',  '\u{682a}',
+         '\u{29}'], &['\u{28}',  '\u{6709}',  '\u{29}'], &['\u{28}',  '\u{793e}',  '\u{29}'],
+        &['\u{28}',  '\u{540d}',  '\u{29}'], &['\u{28}',  '\u{7279}',  '\u{29}'], &['\u{28}',
+         '\u{8ca1}',  '\u{29}'], &['\u{28}',  '\u{795d}',  '\u{29}'], &['\u{28}',  '\u{52b4}',
+         '\u{29}'], &['\u{28}',  '\u{4ee3}',  '\u{29}'], &['\u{28}',  '\u{547c}',  '\u{29}'],
+        &['\u{28}',  '\u{5b66}',  '\u{29}'], &['\u{28}',  '\u{76e3}',  '\u{29}'], &['\u{28}',
+         '\u{4f01}',  '\u{29}'], &['\u{28}',  '\u{8cc7}',  '\u{29}'], &['\u{28}',  '\u{5354}',
+         '\u{29}'], &['\u{28}',  '\u{796d}',  '\u{29}'], &['\u{28}',  '\u{4f11}',  '\u{29}'],
+        &['\u{28}',  '\u{81ea}',  '\u{29}'], &['\u{28}',  '\u{81f3}',  '\u{29}'], &['\u{6c}',
+         '\u{6708}'], &['\u{32}',  '\u{6708}'], &['\u{33}',  '\u{6708}'], &['\u{34}',  '\u{6708}'],
+        &['\u{35}',  '\u{6708}'], &['\u{36}',  '\u{6708}'], &['\u{37}',  '\u{6708}'], &['\u{38}',
+         '\u{6708}'], &['\u{39}',  '\u{6708}'], &['\u{6c}',  '\u{4f}',  '\u{6708}'], &['\u{6c}',
+         '\u{6c}',  '\u{6708}'], &['\u{6c}',  '\u{32}',  '\u{6708}'], &['\u{4f}',  '\u{70b9}'],
+        &['\u{6c}',  '\u{70b9}'], &['\u{32}',  '\u{70b9}'], &['\u{33}',  '\u{70b9}'], &['\u{34}',
+         '\u{70b9}'], &['\u{35}',  '\u{70b9}'], &['\u{36}',  '\u{70b9}'], &['\u{37}',  '\u{70b9}'],
+        &['\u{38}',  '\u{70b9}'], &['\u{39}',  '\u{70b9}'], &['\u{6c}',  '\u{4f}',  '\u{70b9}'],
+        &['\u{6c}',  '\u{6c}',  '\u{70b9}'], &['\u{6c}',  '\u{32}',  '\u{70b9}'], &['\u{6c}',
+         '\u{33}',  '\u{70b9}'], &['\u{6c}',  '\u{34}',  '\u{70b9}'], &['\u{6c}',  '\u{35}',
+         '\u{70b9}'], &['\u{6c}',  '\u{36}',  '\u{70b9}'], &['\u{6c}',  '\u{37}',  '\u{70b9}'],
+        &['\u{6c}',  '\u{38}',  '\u{70b9}'], &['\u{6c}',  '\u{39}',  '\u{70b9}'], &['\u{32}',
+         '\u{4f}',  '\u{70b9}'], &['\u{32}',  '\u{6c}',  '\u{70b9}'], &['\u{32}',  '\u{32}',
+         '\u{70b9}'], &['\u{32}',  '\u{33}',  '\u{70b9}'], &['\u{32}',  '\u{34}',  '\u{70b9}'],
+        &['\u{6c}',  '\u{65e5}'], &['\u{32}',  '\u{65e5}'], &['\u{33}',  '\u{65e5}'], &['\u{34}',
+         '\u{65e5}'], &['\u{35}',  '\u{65e5}'], &['\u{36}',  '\u{65e5}'], &['\u{37}',  '\u{65e5}'],
+        &['\u{38}',  '\u{65e5}'], &['\u{39}',  '\u{65e5}'], &['\u{6c}',  '\u{4f}',  '\u{65e5}'],
+        &['\u{6c}',  '\u{6c}',  '\u{65e5}'], &['\u{6c}',  '\u{32}',  '\u{65e5}'], &['\u{6c}',
+         '\u{33}',  '\u{65e5}'], &['\u{6c}',  '\u{34}',  '\u{65e5}'], &['\u{6c}',  '\u{35}',
+         '\u{65e5}'], &['\u{6c}',  '\u{36}',  '\u{65e5}'], &['\u{6c}',  '\u{37}',  '\u{65e5}'],
+        &['\u{6c}',  '\u{38}',  '\u{65e5}'], &['\u{6c}',  '\u{39}',  '\u{65e5}'], &['\u{32}',
+         '\u{4f}',  '\u{65e5}'], &['\u{32}',  '\u{6c}',  '\u{65e5}'], &['\u{32}',  '\u{32}',
+         '\u{65e5}'], &['\u{32}',  '\u{33}',  '\u{65e5}'], &['\u{32}',  '\u{34}',  '\u{65e5}'],
+        &['\u{32}',  '\u{35}',  '\u{65e5}'], &['\u{32}',  '\u{36}',  '\u{65e5}'], &['\u{32}',
+         '\u{37}',  '\u{65e5}'], &['\u{32}',  '\u{38}',  '\u{65e5}'], &['\u{32}',  '\u{39}',
+         '\u{65e5}'], &['\u{33}',  '\u{4f}',  '\u{65e5}'], &['\u{33}',  '\u{6c}',  '\u{65e5}'],
+        &['\u{363d}'], &['\u{3588}'], &['\u{3b3b}'], &['\u{278ae}'], &['\u{110c}',  '\u{1169}'],
+        &['\u{1109}',  '\u{1109}',  '\u{30fc}'], &['\u{1b122}'], &['\u{1109}',  '\u{30fc}',
+         '\u{1100}'], &['\u{4f75}'], &['\u{5024}'], &['\u{16ff3}'], &['\u{1106}',  '\u{1161}'],
+        &['\u{1109}',  '\u{30fc}',  '\u{1106}'], &['\u{5553}'], &['\u{5861}'], &['\u{58ab}'],
+        &['\u{5aaf}'], &['\u{5e21}'], &['\u{3b3a}'], &['\u{2b73f}'], &['\u{3a41}'], &['\u{403f}'],
+        &['\u{665a}'], &['\u{3ada}'], &['\u{4443}'], &['\u{676e}'], &['\u{3ba3}'], &['\u{699d}'],
+        &['\u{6e88}'], &['\u{7814}'], &['\u{7d55}'], &['\u{670c}'], &['\u{6710}'], &['\u{670f}'],
+        &['\u{3b35}'], &['\u{6713}'], &['\u{6718}'], &['\u{80fc}'], &['\u{6723}'], &['\u{848d}'],
+        &['\u{8637}'], &['\u{46b6}'], &['\u{8a2e}'], &['\u{8b86}'], &['\u{8c5c}'], &['\u{8d7f}'],
+        &['\u{8de5}'], &['\u{8e97}'], &['\u{8eff}'], &['\u{90ce}'], &['\u{93ad}'], &['\u{96b7}'],
+        &['\u{9e42}'], &['\u{4039}'], &['\u{a2cd}'], &['\u{a0c0}'], &['\u{a04a}'], &['\u{a458}'],
+        &['\u{a132}'], &['\u{a050}'], &['\u{a3c2}'], &['\u{a3bf}'], &['\u{a2b1}'], &['\u{a259}'],
+        &['\u{a3ab}'], &['\u{a3b5}'], &['\u{1660}'], &['\u{15e1}'], &['\u{2e}',  '\u{2c}'],
+        &['\u{2d}',  '\u{2e}'], &['\u{42a}',  '\u{6c}'], &['\u{2c9}',  '\u{62}',  '\u{69}'],
+        &['\u{20e9}'], &['\u{4f}',  '\u{4f}'], &['\u{16b9}'], &['\u{2a1}'], &['\u{a6f3}',
+         '\u{a6f3}'], &['\u{2eb}'], &['\u{54}',  '\u{33}'], &['\u{74}',  '\u{21d}'], &['\u{41}',
+         '\u{41}'], &['\u{61}',  '\u{61}'], &['\u{41}',  '\u{4f}'], &['\u{61}',  '\u{6f}'],
+        &['\u{41}',  '\u{55}'], &['\u{61}',  '\u{75}'], &['\u{41}',  '\u{56}'], &['\u{61}',
+         '\u{76}'], &['\u{41}',  '\u{59}'], &['\u{61}',  '\u{79}'], &['\u{77}',  '\u{326}'],
+        &['\u{74}',  '\u{66}'], &['\u{26}'], &['\u{a779}'], &['\u{a727}'], &['\u{10412}'],
+        &['\u{1043a}'], &['\u{29a}'], &['\u{a4e4}'], &['\u{a7d3}'], &['\u{a7d5}'], &['\u{245}',
+         '\u{338}'], &['\u{964}'], &['\u{1103}',  '\u{1106}'], &['\u{1103}',  '\u{1107}'],
+        &['\u{1103}',  '\u{1109}'], &['\u{1103}',  '\u{110c}'], &['\u{1105}',  '\u{1100}',
+         '\u{1100}'], &['\u{1105}',  '\u{1103}',  '\u{1103}'], &['\u{1105}',  '\u{1107}',
+         '\u{1107}'], &['\u{1105}',  '\u{110c}'], &['\u{1106}',  '\u{1103}'], &['\u{1107}',
+         '\u{1109}',  '\u{1110}'], &['\u{1107}',  '\u{110f}'], &['\u{1109}',  '\u{1109}',
+         '\u{1107}'], &['\u{110b}',  '\u{1105}'], &['\u{110b}',  '\u{1112}'], &['\u{110c}',
+         '\u{110c}',  '\u{1112}'], &['\u{1110}',  '\u{1110}'], &['\u{1111}',  '\u{1112}'],
+        &['\u{1112}',  '\u{1109}'], &['\u{1159}',  '\u{1159}'], &['\u{2c3f}'], &['\u{a99d}'],
+        &['\u{a9d0}'], &['\u{aa01}'], &['\u{aa23}'], &['\u{254}',  '\u{338}'], &['\u{1dd}',
+         '\u{6f}',  '\u{338}'], &['\u{1dd}',  '\u{6f}',  '\u{335}'], &['\u{459}'], &['\u{254}',
+         '\u{65}'], &['\u{75}',  '\u{6f}'], &['\u{1d05}'], &['\u{280}'], &['\u{6f}',  '\u{31b}'],
+        &['\u{1d00}'], &['\u{1d0a}'], &['\u{1d07}'], &['\u{242}'], &['\u{2c76}'], &['\u{1169}',
+         '\u{1167}'], &['\u{1169}',  '\u{1169}',  '\u{4e28}'], &['\u{116d}',  '\u{1161}'],
+        &['\u{116d}',  '\u{1161}',  '\u{4e28}'], &['\u{116d}',  '\u{1165}'], &['\u{116e}',
+         '\u{1167}'], &['\u{116e}',  '\u{4e28}',  '\u{4e28}'], &['\u{1172}',  '\u{1161}',
+         '\u{4e28}'], &['\u{1172}',  '\u{1169}'], &['\u{30fc}',  '\u{1161}'], &['\u{30fc}',
+         '\u{1165}'], &['\u{30fc}',  '\u{1165}',  '\u{4e28}'], &['\u{30fc}',  '\u{1169}'],
+        &['\u{4e28}',  '\u{1163}',  '\u{1169}'], &['\u{4e28}',  '\u{1163}',  '\u{4e28}'],
+        &['\u{4e28}',  '\u{1167}'], &['\u{4e28}',  '\u{1167}',  '\u{4e28}'], &['\u{4e28}',
+         '\u{1169}',  '\u{4e28}'], &['\u{4e28}',  '\u{116d}'], &['\u{4e28}',  '\u{1172}'],
+        &['\u{4e28}',  '\u{4e28}'], &['\u{119e}',  '\u{1161}'], &['\u{119e}',  '\u{1165}',
+         '\u{4e28}'], &['\u{1102}',  '\u{1105}'], &['\u{1102}',  '\u{110e}'], &['\u{1103}',
+         '\u{1103}',  '\u{1107}'], &['\u{1103}',  '\u{1109}',  '\u{1100}'], &['\u{1103}',
+         '\u{110e}'], &['\u{1103}',  '\u{1110}'], &['\u{1105}',  '\u{1100}',  '\u{1112}'],
+        &['\u{1105}',  '\u{1105}',  '\u{110f}'], &['\u{1105}',  '\u{1106}',  '\u{1112}'],
+        &['\u{1105}',  '\u{1107}',  '\u{1103}'], &['\u{1105}',  '\u{1107}',  '\u{1111}'],
+        &['\u{1105}',  '\u{114c}'], &['\u{1105}',  '\u{1159}',  '\u{1112}'], &['\u{1106}',
+         '\u{1102}'], &['\u{1106}',  '\u{1102}',  '\u{1102}'], &['\u{1106}',  '\u{1106}'],
+        &['\u{1106}',  '\u{1107}',  '\u{1109}'], &['\u{1106}',  '\u{110c}'], &['\u{1107}',
+         '\u{1105}',  '\u{1111}'], &['\u{1107}',  '\u{1106}'], &['\u{1109}',  '\u{1107}',
+         '\u{110b}'], &['\u{1109}',  '\u{1109}',  '\u{1100}'], &['\u{1109}',  '\u{1109}',
+         '\u{1103}'], &['\u{1109}',  '\u{1140}'], &['\u{1140}',  '\u{1107}'], &['\u{1140}',
+         '\u{1107}',  '\u{110b}'], &['\u{114c}',  '\u{1106}'], &['\u{114c}',  '\u{1112}'],
+        &['\u{110c}',  '\u{1107}'], &['\u{110c}',  '\u{1107}',  '\u{1107}'], &['\u{1111}',
+         '\u{1109}'], &['\u{1111}',  '\u{1110}'], &['\u{8c48}'], &['\u{66f4}'], &['\u{8cc8}'],
+        &['\u{6ed1}'], &['\u{4e32}'], &['\u{53e5}'], &['\u{5951}'], &['\u{5587}'], &['\u{5948}'],
+        &['\u{61f6}'], &['\u{7669}'], &['\u{7f85}'], &['\u{863f}'], &['\u{87ba}'], &['\u{88f8}'],
+        &['\u{908f}'], &['\u{6a02}'], &['\u{6d1b}'], &['\u{70d9}'], &['\u{73de}'], &['\u{843d}'],
+        &['\u{916a}'], &['\u{99f1}'], &['\u{4e82}'], &['\u{5375}'], &['\u{6b04}'], &['\u{721b}'],
+        &['\u{862d}'], &['\u{9e1e}'], &['\u{5d50}'], &['\u{6feb}'], &['\u{85cd}'], &['\u{8964}'],
+        &['\u{62c9}'], &['\u{81d8}'], &['\u{881f}'], &['\u{5eca}'], &['\u{6717}'], &['\u{6d6a}'],
+        &['\u{72fc}'], &['\u{4f86}'], &['\u{51b7}'], &['\u{52de}'], &['\u{64c4}'], &['\u{6ad3}'],
+        &['\u{7210}'], &['\u{76e7}'], &['\u{8606}'], &['\u{865c}'], &['\u{8def}'], &['\u{9732}'],
+        &['\u{9b6f}'], &['\u{9dfa}'], &['\u{788c}'], &['\u{797f}'], &['\u{7da0}'], &['\u{83c9}'],
+        &['\u{9304}'], &['\u{8ad6}'], &['\u{58df}'], &['\u{5f04}'], &['\u{7c60}'], &['\u{807e}'],
+        &['\u{7262}'], &['\u{78ca}'], &['\u{8cc2}'], &['\u{96f7}'], &['\u{58d8}'], &['\u{5c62}'],
+        &['\u{6a13}'], &['\u{6dda}'], &['\u{6f0f}'], &['\u{7d2f}'], &['\u{7e37}'], &['\u{964b}'],
+        &['\u{52d2}'], &['\u{808b}'], &['\u{51dc}'], &['\u{51cc}'], &['\u{7a1c}'], &['\u{7dbe}'],
+        &['\u{83f1}'], &['\u{9675}'], &['\u{8b80}'], &['\u{62cf}'], &['\u{8afe}'], &['\u{4e39}'],
+        &['\u{5be7}'], &['\u{6012}'], &['\u{7387}'], &['\u{7570}'], &['\u{5317}'], &['\u{78fb}'],
+        &['\u{4fbf}'], &['\u{5fa9}'], &['\u{4e0d}'], &['\u{6ccc}'], &['\u{6578}'], &['\u{7d22}'],
+        &['\u{53c3}'], &['\u{585e}'], &['\u{7701}'], &['\u{8449}'], &['\u{8aaa}'], &['\u{6bba}'],
+        &['\u{6c88}'], &['\u{62fe}'], &['\u{82e5}'], &['\u{63a0}'], &['\u{7565}'], &['\u{4eae}'],
+        &['\u{5169}'], &['\u{51c9}'], &['\u{6881}'], &['\u{7ce7}'], &['\u{826f}'], &['\u{8ad2}'],
+        &['\u{91cf}'], &['\u{52f5}'], &['\u{5442}'], &['\u{5eec}'], &['\u{65c5}'], &['\u{6ffe}'],
+        &['\u{792a}'], &['\u{95ad}'], &['\u{9a6a}'], &['\u{9e97}'], &['\u{9ece}'], &['\u{66c6}'],
+        &['\u{6b77}'], &['\u{8f62}'], &['\u{5e74}'], &['\u{6190}'], &['\u{6200}'], &['\u{649a}'],
+        &['\u{6f23}'], &['\u{7149}'], &['\u{7489}'], &['\u{79ca}'], &['\u{7df4}'], &['\u{806f}'],
+        &['\u{8f26}'], &['\u{84ee}'], &['\u{9023}'], &['\u{934a}'], &['\u{5217}'], &['\u{52a3}'],
+        &['\u{54bd}'], &['\u{70c8}'], &['\u{88c2}'], &['\u{5ec9}'], &['\u{5ff5}'], &['\u{637b}'],
+        &['\u{6bae}'], &['\u{7c3e}'], &['\u{7375}'], &['\u{4ee4}'], &['\u{56f9}'], &['\u{5dba}'],
+        &['\u{601c}'], &['\u{73b2}'], &['\u{7469}'], &['\u{7f9a}'], &['\u{8046}'], &['\u{9234}'],
+        &['\u{96f6}'], &['\u{9748}'], &['\u{9818}'], &['\u{4f8b}'], &['\u{79ae}'], &['\u{91b4}'],
+        &['\u{60e1}'], &['\u{4e86}'], &['\u{50da}'], &['\u{5bee}'], &['\u{5c3f}'], &['\u{6599}'],
+        &['\u{71ce}'], &['\u{7642}'], &['\u{84fc}'], &['\u{907c}'], &['\u{6688}'], &['\u{962e}'],
+        &['\u{5289}'], &['\u{677b}'], &['\u{67f3}'], &['\u{6d41}'], &['\u{6e9c}'], &['\u{7409}'],
+        &['\u{7559}'], &['\u{786b}'], &['\u{7d10}'], &['\u{985e}'], &['\u{516d}'], &['\u{622e}'],
+        &['\u{9678}'], &['\u{502b}'], &['\u{5d19}'], &['\u{6dea}'], &['\u{8f2a}'], &['\u{5f8b}'],
+        &['\u{6144}'], &['\u{6817}'], &['\u{9686}'], &['\u{5229}'], &['\u{540f}'], &['\u{5c65}'],
+        &['\u{6613}'], &['\u{674e}'], &['\u{68a8}'], &['\u{6ce5}'], &['\u{7406}'], &['\u{75e2}'],
+        &['\u{7f79}'], &['\u{88cf}'], &['\u{88e1}'], &['\u{96e2}'], &['\u{533f}'], &['\u{6eba}'],
+        &['\u{541d}'], &['\u{71d0}'], &['\u{7498}'], &['\u{85fa}'], &['\u{96a3}'], &['\u{9c57}'],
+        &['\u{9e9f}'], &['\u{6797}'], &['\u{6dcb}'], &['\u{81e8}'], &['\u{7b20}'], &['\u{7c92}'],
+        &['\u{72c0}'], &['\u{7099}'], &['\u{8b58}'], &['\u{4ec0}'], &['\u{8336}'], &['\u{523a}'],
+        &['\u{5207}'], &['\u{5ea6}'], &['\u{62d3}'], &['\u{7cd6}'], &['\u{5b85}'], &['\u{6d1e}'],
+        &['\u{66b4}'], &['\u{8f3b}'], &['\u{964d}'], &['\u{5ed3}'], &['\u{55c0}'], &['\u{585a}'],
+        &['\u{6674}'], &['\u{51de}'], &['\u{732a}'], &['\u{76ca}'], &['\u{793c}'], &['\u{795e}'],
+        &['\u{7965}'], &['\u{798f}'], &['\u{9756}'], &['\u{7cbe}'], &['\u{8612}'], &['\u{8af8}'],
+        &['\u{9038}'], &['\u{90fd}'], &['\u{98ef}'], &['\u{98fc}'], &['\u{9928}'], &['\u{9db4}'],
+        &['\u{4fae}'], &['\u{50e7}'], &['\u{514d}'], &['\u{52c9}'], &['\u{52e4}'], &['\u{5351}'],
+        &['\u{559d}'], &['\u{5606}'], &['\u{5668}'], &['\u{5840}'], &['\u{58a8}'], &['\u{5c64}'],
+        &['\u{6094}'], &['\u{6168}'], &['\u{618e}'], &['\u{61f2}'], &['\u{654f}'], &['\u{65e2}'],
+        &['\u{6691}'], &['\u{6885}'], &['\u{6d77}'], &['\u{6e1a}'], &['\u{6f22}'], &['\u{716e}'],
+        &['\u{7422}'], &['\u{7891}'], &['\u{793e}'], &['\u{7949}'], &['\u{7948}'], &['\u{7950}'],
+        &['\u{7956}'], &['\u{795d}'], &['\u{798d}'], &['\u{798e}'], &['\u{7a40}'], &['\u{7a81}'],
+        &['\u{7bc0}'], &['\u{7e09}'], &['\u{7e41}'], &['\u{7f72}'], &['\u{8005}'], &['\u{81ed}'],
+        &['\u{8457}'], &['\u{8910}'], &['\u{8996}'], &['\u{8b01}'], &['\u{8b39}'], &['\u{8cd3}'],
+        &['\u{8d08}'], &['\u{96e3}'], &['\u{97ff}'], &['\u{983b}'], &['\u{6075}'], &['\u{242ee}'],
+        &['\u{8218}'], &['\u{4e26}'], &['\u{51b5}'], &['\u{5168}'], &['\u{4f80}'], &['\u{5145}'],
+        &['\u{5180}'], &['\u{52c7}'], &['\u{52fa}'], &['\u{5555}'], &['\u{5599}'], &['\u{55e2}'],
+        &['\u{58b3}'], &['\u{5944}'], &['\u{5954}'], &['\u{5a62}'], &['\u{5b28}'], &['\u{5ed2}'],
+        &['\u{5ed9}'], &['\u{5f69}'], &['\u{5fad}'], &['\u{60d8}'], &['\u{614e}'], &['\u{6108}'],
+        &['\u{6160}'], &['\u{6234}'], &['\u{63c4}'], &['\u{641c}'], &['\u{6452}'], &['\u{6556}'],
+        &['\u{671b}'], &['\u{6756}'], &['\u{6edb}'], &['\u{6ecb}'], &['\u{701e}'], &['\u{77a7}'],
+        &['\u{7235}'], &['\u{72af}'], &['\u{7471}'], &['\u{7506}'], &['\u{753b}'], &['\u{761d}'],
+        &['\u{761f}'], &['\u{76db}'], &['\u{76f4}'], &['\u{774a}'], &['\u{7740}'], &['\u{78cc}'],
+        &['\u{7ab1}'], &['\u{7c7b}'], &['\u{7d5b}'], &['\u{7f3e}'], &['\u{8352}'], &['\u{83ef}'],
+        &['\u{8779}'], &['\u{8941}'], &['\u{8986}'], &['\u{8abf}'], &['\u{8acb}'], &['\u{8aed}'],
+        &['\u{8b8a}'], &['\u{8f38}'], &['\u{9072}'], &['\u{9199}'], &['\u{9276}'], &['\u{967c}'],
+        &['\u{97db}'], &['\u{980b}'], &['\u{9b12}'], &['\u{2284a}'], &['\u{22844}'], &['\u{233d5}'],
+        &['\u{3b9d}'], &['\u{4018}'], &['\u{25249}'], &['\u{25cd0}'], &['\u{27ed3}'], &['\u{9f43}'],
+        &['\u{9f8e}'], &['\u{66}',  '\u{66}'], &['\u{66}',  '\u{69}'], &['\u{66}',  '\u{6c}'],
+        &['\u{66}',  '\u{66}',  '\u{69}'], &['\u{66}',  '\u{66}',  '\u{6c}'], &['\u{73}',
+         '\u{74}'], &['\u{574}',  '\u{576}'], &['\u{574}',  '\u{565}'], &['\u{574}',  '\u{56b}'],
+        &['\u{57e}',  '\u{576}'], &['\u{574}',  '\u{56d}'], &['\u{5e2}'], &['\u{5d4}'],
+        &['\u{5db}'], &['\u{5dc}'], &['\u{5dd}'], &['\u{5e8}'], &['\u{5ea}'], &['\u{fb2a}'],
+        &['\u{fb2c}'], &['\u{fb2e}'], &['\u{fb1d}'], &['\u{5d0}',  '\u{5dc}'], &['\u{671}'],
+        &['\u{680}'], &['\u{67f}'], &['\u{6a6}'], &['\u{684}'], &['\u{683}'], &['\u{686}'],
+        &['\u{687}'], &['\u{68d}'], &['\u{68c}'], &['\u{6b3}'], &['\u{6b1}'], &['\u{6d3}'],
+        &['\u{6c5}'], &['\u{649}',  '\u{674}',  '\u{6c}'], &['\u{649}',  '\u{674}',  '\u{6f}'],
+        &['\u{649}',  '\u{674}',  '\u{648}'], &['\u{649}',  '\u{674}',  '\u{648}',  '\u{313}'],
+        &['\u{649}',  '\u{674}',  '\u{648}',  '\u{306}'], &['\u{649}',  '\u{674}',  '\u{648}',
+         '\u{670}'], &['\u{649}',  '\u{674}',  '\u{67b}'], &['\u{649}',  '\u{674}',  '\u{649}'],
+        &['\u{649}',  '\u{674}',  '\u{62c}'], &['\u{649}',  '\u{674}',  '\u{62d}'], &['\u{649}',
+         '\u{674}',  '\u{645}'], &['\u{628}',  '\u{62c}'], &['\u{628}',  '\u{62d}'], &['\u{628}',
+         '\u{62e}'], &['\u{628}',  '\u{645}'], &['\u{628}',  '\u{649}'], &['\u{62a}',  '\u{62c}'],
+        &['\u{62a}',  '\u{62d}'], &['\u{62a}',  '\u{62e}'], &['\u{62a}',  '\u{645}'], &['\u{62a}',
+         '\u{649}'], &['\u{649}',  '\u{6db}',  '\u{62c}'], &['\u{649}',  '\u{6db}',  '\u{645}'],
+        &['\u{649}',  '\u{6db}',  '\u{649}'], &['\u{62c}',  '\u{62d}'], &['\u{62c}',  '\u{645}'],
+        &['\u{62d}',  '\u{62c}'], &['\u{62d}',  '\u{645}'], &['\u{62e}',  '\u{62c}'], &['\u{62e}',
+         '\u{62d}'], &['\u{62e}',  '\u{645}'], &['\u{633}',  '\u{62c}'], &['\u{633}',  '\u{62d}'],
+        &['\u{633}',  '\u{62e}'], &['\u{633}',  '\u{645}'], &['\u{635}',  '\u{62d}'], &['\u{635}',
+         '\u{645}'], &['\u{636}',  '\u{62c}'], &['\u{636}',  '\u{62d}'], &['\u{636}',  '\u{62e}'],
+        &['\u{636}',  '\u{645}'], &['\u{637}',  '\u{62d}'], &['\u{637}',  '\u{645}'], &['\u{638}',
+         '\u{645}'], &['\u{639}',  '\u{62c}'], &['\u{639}',  '\u{645}'], &['\u{63a}',  '\u{62c}'],
+        &['\u{63a}',  '\u{645}'], &['\u{641}',  '\u{62c}'], &['\u{641}',  '\u{62d}'], &['\u{641}',
+         '\u{62e}'], &['\u{641}',  '\u{645}'], &['\u{641}',  '\u{649}'], &['\u{642}',  '\u{62d}'],
+        &['\u{642}',  '\u{645}'], &['\u{642}',  '\u{649}'], &['\u{643}',  '\u{6c}'], &['\u{643}',
+         '\u{62c}'], &['\u{643}',  '\u{62d}'], &['\u{643}',  '\u{62e}'], &['\u{643}',  '\u{644}'],
+        &['\u{643}',  '\u{645}'], &['\u{643}',  '\u{649}'], &['\u{644}',  '\u{62c}'], &['\u{644}',
+         '\u{62d}'], &['\u{644}',  '\u{62e}'], &['\u{644}',  '\u{645}'], &['\u{644}',  '\u{649}'],
+        &['\u{645}',  '\u{62c}'], &['\u{645}',  '\u{62d}'], &['\u{645}',  '\u{62e}'], &['\u{645}',
+         '\u{645}'], &['\u{645}',  '\u{649}'], &['\u{646}',  '\u{62d}'], &['\u{646}',  '\u{62e}'],
+        &['\u{646}',  '\u{645}'], &['\u{646}',  '\u{649}'], &['\u{6f}',  '\u{62c}'], &['\u{6f}',
+         '\u{645}'], &['\u{6f}',  '\u{649}'], &['\u{649}',  '\u{62c}'], &['\u{649}',  '\u{62d}'],
+        &['\u{649}',  '\u{62e}'], &['\u{649}',  '\u{645}'], &['\u{649}',  '\u{649}'], &['\u{630}',
+         '\u{670}'], &['\u{631}',  '\u{670}'], &['\u{649}',  '\u{670}'], &['\u{fe72}',  '\u{651}'],
+        &['\u{fe74}',  '\u{651}'], &['\u{fe76}',  '\u{651}'], &['\u{fe78}',  '\u{651}'],
+        &['\u{fe7a}',  '\u{651}'], &['\u{fe7c}',  '\u{670}'], &['\u{649}',  '\u{674}',  '\u{631}'],
+        &['\u{649}',  '\u{674}',  '\u{632}'], &['\u{649}',  '\u{674}',  '\u{646}'], &['\u{628}',
+         '\u{631}'], &['\u{628}',  '\u{632}'], &['\u{628}',  '\u{646}'], &['\u{62a}',  '\u{631}'],
+        &['\u{62a}',  '\u{632}'], &['\u{62a}',  '\u{646}'], &['\u{649}',  '\u{6db}',  '\u{631}'],
+        &['\u{649}',  '\u{6db}',  '\u{632}'], &['\u{649}',  '\u{6db}',  '\u{646}'], &['\u{645}',
+         '\u{6c}'], &['\u{646}',  '\u{631}'], &['\u{646}',  '\u{632}'], &['\u{646}',  '\u{646}'],
+        &['\u{649}',  '\u{631}'], &['\u{649}',  '\u{632}'], &['\u{649}',  '\u{646}'], &['\u{649}',
+         '\u{674}',  '\u{62e}'], &['\u{628}',  '\u{6f}'], &['\u{62a}',  '\u{6f}'], &['\u{635}',
+         '\u{62e}'], &['\u{644}',  '\u{6f}'], &['\u{646}',  '\u{6f}'], &['\u{6f}',  '\u{670}'],
+        &['\u{649}',  '\u{6f}'], &['\u{649}',  '\u{6db}',  '\u{6f}'], &['\u{633}',  '\u{6f}'],
+        &['\u{633}',  '\u{6db}',  '\u{645}'], &['\u{633}',  '\u{6db}',  '\u{6f}'], &['\u{fe77}',
+         '\u{651}'], &['\u{fe79}',  '\u{651}'], &['\u{fe7b}',  '\u{651}'], &['\u{637}',  '\u{649}'],
+        &['\u{639}',  '\u{649}'], &['\u{63a}',  '\u{649}'], &['\u{633}',  '\u{649}'], &['\u{633}',
+         '\u{6db}',  '\u{649}'], &['\u{62d}',  '\u{649}'], &['\u{62c}',  '\u{649}'], &['\u{62e}',
+         '\u{649}'], &['\u{635}',  '\u{649}'], &['\u{636}',  '\u{649}'], &['\u{633}',  '\u{6db}',
+         '\u{62c}'], &['\u{633}',  '\u{6db}',  '\u{62d}'], &['\u{633}',  '\u{6db}',  '\u{62e}'],
+        &['\u{633}',  '\u{6db}',  '\u{631}'], &['\u{633}',  '\u{631}'], &['\u{635}',  '\u{631}'],
+        &['\u{636}',  '\u{631}'], &['\u{6c}',  '\u{30b}'], &['\u{62a}',  '\u{62c}',  '\u{645}'],
+        &['\u{62a}',  '\u{62d}',  '\u{62c}'], &['\u{62a}',  '\u{62d}',  '\u{645}'], &['\u{62a}',
+         '\u{62e}',  '\u{645}'], &['\u{62a}',  '\u{645}',  '\u{62c}'], &['\u{62a}',  '\u{645}',
+         '\u{62d}'], &['\u{62a}',  '\u{645}',  '\u{62e}'], &['\u{62c}',  '\u{645}',  '\u{62d}'],
+        &['\u{62d}',  '\u{645}',  '\u{649}'], &['\u{633}',  '\u{62d}',  '\u{62c}'], &['\u{633}',
+         '\u{62c}',  '\u{62d}'], &['\u{633}',  '\u{62c}',  '\u{649}'], &['\u{633}',  '\u{645}',
+         '\u{62d}'], &['\u{633}',  '\u{645}',  '\u{62c}'], &['\u{633}',  '\u{645}',  '\u{645}'],
+        &['\u{635}',  '\u{62d}',  '\u{62d}'], &['\u{635}',  '\u{645}',  '\u{645}'], &['\u{633}',
+         '\u{6db}',  '\u{62d}',  '\u{645}'], &['\u{633}',  '\u{6db}',  '\u{62c}',  '\u{649}'],
+        &['\u{633}',  '\u{6db}',  '\u{645}',  '\u{62e}'], &['\u{633}',  '\u{6db}',  '\u{645}',
+         '\u{645}'], &['\u{636}',  '\u{62d}',  '\u{649}'], &['\u{636}',  '\u{62e}',  '\u{645}'],
+        &['\u{637}',  '\u{645}',  '\u{62d}'], &['\u{637}',  '\u{645}',  '\u{645}'], &['\u{637}',
+         '\u{645}',  '\u{649}'], &['\u{639}',  '\u{62c}',  '\u{645}'], &['\u{639}',  '\u{645}',
+         '\u{645}'], &['\u{639}',  '\u{645}',  '\u{649}'], &['\u{63a}',  '\u{645}',  '\u{645}'],
+        &['\u{63a}',  '\u{645}',  '\u{649}'], &['\u{641}',  '\u{62e}',  '\u{645}'], &['\u{642}',
+         '\u{645}',  '\u{62d}'], &['\u{642}',  '\u{645}',  '\u{645}'], &['\u{644}',  '\u{62d}',
+         '\u{645}'], &['\u{644}',  '\u{62d}',  '\u{649}'], &['\u{644}',  '\u{62c}',  '\u{62c}'],
+        &['\u{644}',  '\u{62e}',  '\u{645}'], &['\u{644}',  '\u{645}',  '\u{62d}'], &['\u{645}',
+         '\u{62d}',  '\u{62c}'], &['\u{645}',  '\u{62d}',  '\u{645}'], &['\u{645}',  '\u{62d}',
+         '\u{649}'], &['\u{645}',  '\u{62c}',  '\u{62d}'], &['\u{645}',  '\u{62c}',  '\u{645}'],
+        &['\u{645}',  '\u{62e}',  '\u{62c}'], &['\u{645}',  '\u{62e}',  '\u{645}'], &['\u{645}',
+         '\u{62c}',  '\u{62e}'], &['\u{6f}',  '\u{645}',  '\u{62c}'], &['\u{6f}',  '\u{645}',
+         '\u{645}'], &['\u{646}',  '\u{62d}',  '\u{645}'], &['\u{646}',  '\u{62d}',  '\u{649}'],
+        &['\u{646}',  '\u{62c}',  '\u{645}'], &['\u{646}',  '\u{62c}',  '\u{649}'], &['\u{646}',
+         '\u{645}',  '\u{649}'], &['\u{649}',  '\u{645}',  '\u{645}'], &['\u{628}',  '\u{62e}',
+         '\u{649}'], &['\u{62a}',  '\u{62c}',  '\u{649}'], &['\u{62a}',  '\u{62e}',  '\u{649}'],
+        &['\u{62a}',  '\u{645}',  '\u{649}'], &['\u{62c}',  '\u{645}',  '\u{649}'], &['\u{62c}',
+         '\u{62d}',  '\u{649}'], &['\u{633}',  '\u{62e}',  '\u{649}'], &['\u{635}',  '\u{62d}',
+         '\u{649}'], &['\u{633}',  '\u{6db}',  '\u{62d}',  '\u{649}'], &['\u{644}',  '\u{62c}',
+         '\u{649}'], &['\u{644}',  '\u{645}',  '\u{649}'], &['\u{649}',  '\u{62d}',  '\u{649}'],
+        &['\u{649}',  '\u{62c}',  '\u{649}'], &['\u{649}',  '\u{645}',  '\u{649}'], &['\u{645}',
+         '\u{645}',  '\u{649}'], &['\u{642}',  '\u{645}',  '\u{649}'], &['\u{643}',  '\u{645}',
+         '\u{649}'], &['\u{646}',  '\u{62c}',  '\u{62d}'], &['\u{645}',  '\u{62e}',  '\u{649}'],
+        &['\u{644}',  '\u{62c}',  '\u{645}'], &['\u{643}',  '\u{645}',  '\u{645}'], &['\u{62d}',
+         '\u{62c}',  '\u{649}'], &['\u{645}',  '\u{62c}',  '\u{649}'], &['\u{641}',  '\u{645}',
+         '\u{649}'], &['\u{628}',  '\u{62d}',  '\u{649}'], &['\u{635}',  '\u{644}',  '\u{649}'],
+        &['\u{642}',  '\u{644}',  '\u{649}'], &['\u{6c}',  '\u{644}',  '\u{644}',  '\u{651}',
+         '\u{670}',  '\u{6f}'], &['\u{6c}',  '\u{643}',  '\u{628}',  '\u{631}'], &['\u{645}',
+         '\u{62d}',  '\u{645}',  '\u{62f}'], &['\u{635}',  '\u{644}',  '\u{639}',  '\u{645}'],
+        &['\u{631}',  '\u{633}',  '\u{648}',  '\u{644}'], &['\u{639}',  '\u{644}',  '\u{649}',
+         '\u{6f}'], &['\u{648}',  '\u{633}',  '\u{644}',  '\u{645}'], &['\u{635}',  '\u{644}',
+         '\u{649}',  '\u{20}',  '\u{6c}',  '\u{644}',  '\u{644}',  '\u{6f}',  '\u{20}',  '\u{639}',
+         '\u{644}',  '\u{649}',  '\u{6f}',  '\u{20}',  '\u{648}',  '\u{633}',  '\u{644}',
+         '\u{645}'], &['\u{62c}',  '\u{644}',  '\u{20}',  '\u{62c}',  '\u{644}',  '\u{6c}',
+         '\u{644}',  '\u{6f}'], &['\u{2307}'], &['\u{23dc}'], &['\u{23dd}'], &['\u{23de}'],
+        &['\u{23df}'], &['\u{23e0}'], &['\u{23e1}'], &['\u{621}'], &['\u{622}'], &['\u{628}'],
+        &['\u{62c}'], &['\u{62d}'], &['\u{62e}'], &['\u{62f}'], &['\u{630}'], &['\u{631}'],
+        &['\u{632}'], &['\u{633}'], &['\u{635}'], &['\u{636}'], &['\u{637}'], &['\u{638}'],
+        &['\u{63a}'], &['\u{642}'], &['\u{644}'], &['\u{645}'], &['\u{646}'], &['\u{644}',
+         '\u{622}'], &['\u{644}',  '\u{6c}',  '\u{674}'], &['\u{644}',  '\u{6c}',  '\u{655}'],
+        &['\u{644}',  '\u{6c}'], &['\u{fe3f}'], &['\u{301c}'], &['\u{25aa}'], &['\u{4e}',
+         '\u{30a}'], &['\u{58}',  '\u{335}'], &['\u{56}',  '\u{335}'], &['\u{6c}',  '\u{335}',
+         '\u{6c}',  '\u{335}',  '\u{53}',  '\u{335}'], &['\u{6c}',  '\u{335}',  '\u{6c}',
+         '\u{335}'], &['\u{3d8}'], &['\u{2d40}'], &['\u{10382}'], &['\u{10393}'], &['\u{2c70}'],
+        &['\u{277}'], &['\u{25e}'], &['\u{10486}'], &['\u{4c3}'], &['\u{40b}'], &['\u{16e6}'],
+        &['\u{37}'], &['\u{a669}'], &['\u{10a56}',  '\u{10a56}'], &['\u{10ca5}'], &['\u{10c82}'],
+        &['\u{93a}'], &['\u{a8fc}'], &['\u{a8fb}'], &['\u{2248}'], &['\u{11434}',  '\u{11442}',
+         '\u{11412}'], &['\u{11434}',  '\u{11442}',  '\u{11418}'], &['\u{11434}',  '\u{11442}',
+         '\u{11423}'], &['\u{11434}',  '\u{11442}',  '\u{11429}'], &['\u{11434}',  '\u{11442}',
+         '\u{1142c}'], &['\u{11434}',  '\u{11442}',  '\u{1142e}'], &['\u{1144b}',  '\u{1144b}'],
+        &['\u{998}'], &['\u{99a}'], &['\u{99c}'], &['\u{99e}'], &['\u{99f}'], &['\u{9a1}'],
+        &['\u{9b2}'], &['\u{9a4}'], &['\u{9a5}'], &['\u{9a6}'], &['\u{9a7}'], &['\u{9a8}'],
+        &['\u{9aa}'], &['\u{9ae}'], &['\u{9af}'], &['\u{9ac}'], &['\u{9a3}'], &['\u{9b7}'],
+        &['\u{9b8}'], &['\u{9be}'], &['\u{9c7}'], &['\u{9cb}'], &['\u{9d7}'], &['\u{9cc}'],
+        &['\u{9cd}'], &['\u{9bd}'], &['\u{77}',  '\u{307}'], &['\u{9e7}'], &['\u{9e8}'],
+        &['\u{9ec}'], &['\u{11582}'], &['\u{11583}'], &['\u{11584}'], &['\u{115b2}'],
+        &['\u{115b3}'], &['\u{11641}',  '\u{11641}'], &['\u{2207}'], &['\u{11ae5}',  '\u{11aef}'],
+        &['\u{11ae5}',  '\u{11af0}'], &['\u{11ae5}',  '\u{11ae5}'], &['\u{11ae5}',  '\u{11ae5}',
+         '\u{11aef}'], &['\u{11ae5}',  '\u{11ae5}',  '\u{11af0}'], &['\u{11aeb}',  '\u{11aef}'],
+        &['\u{11aeb}',  '\u{11aeb}'], &['\u{11aeb}',  '\u{11aeb}',  '\u{11aef}'], &['\u{11af3}',
+         '\u{11aef}'], &['\u{11af3}',  '\u{11af0}'], &['\u{11af3}',  '\u{11af3}'], &['\u{11af3}',
+         '\u{11af3}',  '\u{11aef}'], &['\u{11af3}',  '\u{11af3}',  '\u{11af0}'], &['\u{11c41}',
+         '\u{11c41}'], &['\u{11caa}'], &['\u{1039a}'], &['\u{a658}'], &['\u{1f6f8}'], &['\u{4fe}'],
+        &['\u{2144}'], &['\u{228f}'], &['\u{2290}'], &['\u{16cb}'], &['\u{6b}'], &['\u{74}'],
+        &['\u{3b6}'], &['\u{3be}'], &['\u{2202}'], &['\u{3dd}'], &['\u{1ac8}'], &['\u{2220}'],
+        &['\u{4f}',  '\u{2e}'], &['\u{4f}',  '\u{2c}'], &['\u{6c}',  '\u{2c}'], &['\u{32}',
+         '\u{2c}'], &['\u{33}',  '\u{2c}'], &['\u{34}',  '\u{2c}'], &['\u{35}',  '\u{2c}'],
+        &['\u{36}',  '\u{2c}'], &['\u{37}',  '\u{2c}'], &['\u{38}',  '\u{2c}'], &['\u{39}',
+         '\u{2c}'], &['\u{24}',  '\u{20e0}'], &['\u{28}',  '\u{41}',  '\u{29}'], &['\u{28}',
+         '\u{42}',  '\u{29}'], &['\u{28}',  '\u{43}',  '\u{29}'], &['\u{28}',  '\u{44}',  '\u{29}'],
+        &['\u{28}',  '\u{45}',  '\u{29}'], &['\u{28}',  '\u{46}',  '\u{29}'], &['\u{28}',  '\u{47}',
+         '\u{29}'], &['\u{28}',  '\u{48}',  '\u{29}'], &['\u{28}',  '\u{4a}',  '\u{29}'],
+        &['\u{28}',  '\u{4b}',  '\u{29}'], &['\u{28}',  '\u{4c}',  '\u{29}'], &['\u{28}',  '\u{4d}',
+         '\u{29}'], &['\u{28}',  '\u{4e}',  '\u{29}'], &['\u{28}',  '\u{4f}',  '\u{29}'],
+        &['\u{28}',  '\u{50}',  '\u{29}'], &['\u{28}',  '\u{51}',  '\u{29}'], &['\u{28}',  '\u{52}',
+         '\u{29}'], &['\u{28}',  '\u{53}',  '\u{29}'], &['\u{28}',  '\u{54}',  '\u{29}'],
+        &['\u{28}',  '\u{55}',  '\u{29}'], &['\u{28}',  '\u{56}',  '\u{29}'], &['\u{28}',  '\u{57}',
+         '\u{29}'], &['\u{28}',  '\u{58}',  '\u{29}'], &['\u{28}',  '\u{59}',  '\u{29}'],
+        &['\u{28}',  '\u{5a}',  '\u{29}'], &['\u{33c4}',  '\u{9}',  '\u{20dd}'], &['\u{43}',
+         '\u{20e0}'], &['\u{28}',  '\u{672c}',  '\u{29}'], &['\u{28}',  '\u{5b89}',  '\u{29}'],
+        &['\u{28}',  '\u{70b9}',  '\u{29}'], &['\u{28}',  '\u{6253}',  '\u{29}'], &['\u{28}',
+         '\u{76d7}',  '\u{29}'], &['\u{28}',  '\u{52dd}',  '\u{29}'], &['\u{28}',  '\u{6557}',
+         '\u{29}'], &['\u{263d}'], &['\u{1cebc}'], &['\u{1cebd}'], &['\u{1cebe}'], &['\u{1cebf}'],
+        &['\u{1ceba}'], &['\u{1cebb}'], &['\u{1ccfa}'], &['\u{1ccfc}'], &['\u{1fbfa}'], &['\u{51}',
+         '\u{45}'], &['\u{41}',  '\u{52}'], &['\u{56}',  '\u{1de4}'], &['\u{2629}'], &['\u{29df}'],
+        &['\u{22a1}'], &['\u{73}',  '\u{73}',  '\u{73}'], &['\u{4d}',  '\u{42}'], &['\u{56}',
+         '\u{42}'], &['\u{22a0}'], &['\u{58f7}'], &['\u{591a}'], &['\u{216a8}'], &['\u{5f9a}'],
+        &['\u{6d85}'], &['\u{73a5}'], &['\u{25119}'], &['\u{8d1b}'], &['\u{265a8}'], &['\u{26c36}'],
+        &['\u{24814}'], &['\u{5cc0}'], &['\u{2335f}'], &['\u{4e3d}'], &['\u{4e38}'], &['\u{4e41}'],
+        &['\u{20122}'], &['\u{4f60}'], &['\u{4fbb}'], &['\u{507a}'], &['\u{5099}'], &['\u{50cf}'],
+        &['\u{349e}'], &['\u{2063a}'], &['\u{5154}'], &['\u{5164}'], &['\u{5177}'], &['\u{2051c}'],
+        &['\u{34b9}'], &['\u{5167}'], &['\u{518d}'], &['\u{2054b}'], &['\u{5197}'], &['\u{51a4}'],
+        &['\u{4ecc}'], &['\u{51ac}'], &['\u{291df}'], &['\u{5203}'], &['\u{34df}'], &['\u{523b}'],
+        &['\u{5246}'], &['\u{5272}'], &['\u{5277}'], &['\u{3515}'], &['\u{5305}'], &['\u{5306}'],
+        &['\u{5349}'], &['\u{535a}'], &['\u{5373}'], &['\u{537d}'], &['\u{537f}'], &['\u{20a2c}'],
+        &['\u{7070}'], &['\u{53ca}'], &['\u{53df}'], &['\u{20b63}'], &['\u{53eb}'], &['\u{53f1}'],
+        &['\u{5406}'], &['\u{549e}'], &['\u{5438}'], &['\u{5448}'], &['\u{5468}'], &['\u{54a2}'],
+        &['\u{54f6}'], &['\u{5510}'], &['\u{5563}'], &['\u{5584}'], &['\u{55ab}'], &['\u{55b3}'],
+        &['\u{55c2}'], &['\u{5716}'], &['\u{5717}'], &['\u{5651}'], &['\u{5674}'], &['\u{58ee}'],
+        &['\u{57ce}'], &['\u{57f4}'], &['\u{580d}'], &['\u{578b}'], &['\u{5832}'], &['\u{5831}'],
+        &['\u{58ac}'], &['\u{214e4}'], &['\u{58f2}'], &['\u{5906}'], &['\u{5922}'], &['\u{5962}'],
+        &['\u{216ea}'], &['\u{59ec}'], &['\u{5a1b}'], &['\u{5a27}'], &['\u{59d8}'], &['\u{5a66}'],
+        &['\u{36ee}'], &['\u{36fc}'], &['\u{5b08}'], &['\u{5b3e}'], &['\u{219c8}'], &['\u{5bc3}'],
+        &['\u{5bd8}'], &['\u{5bf3}'], &['\u{21b18}'], &['\u{5bff}'], &['\u{5c06}'], &['\u{5f53}'],
+        &['\u{3781}'], &['\u{5c60}'], &['\u{5c8d}'], &['\u{21de4}'], &['\u{5d43}'], &['\u{21de6}'],
+        &['\u{5d6e}'], &['\u{5d6b}'], &['\u{5d7c}'], &['\u{5de1}'], &['\u{5de2}'], &['\u{382f}'],
+        &['\u{5dfd}'], &['\u{5e28}'], &['\u{5e3d}'], &['\u{5e69}'], &['\u{3862}'], &['\u{22183}'],
+        &['\u{387c}'], &['\u{5eb0}'], &['\u{5eb3}'], &['\u{5eb6}'], &['\u{2a392}'], &['\u{22331}'],
+        &['\u{8201}'], &['\u{5f22}'], &['\u{38c7}'], &['\u{232b8}'], &['\u{261da}'], &['\u{5f62}'],
+        &['\u{5f6b}'], &['\u{38e3}'], &['\u{5fcd}'], &['\u{5fd7}'], &['\u{5ff9}'], &['\u{6081}'],
+        &['\u{393a}'], &['\u{391c}'], &['\u{226d4}'], &['\u{60c7}'], &['\u{6148}'], &['\u{614c}'],
+        &['\u{617a}'], &['\u{61b2}'], &['\u{61a4}'], &['\u{61af}'], &['\u{61de}'], &['\u{6210}'],
+        &['\u{621b}'], &['\u{625d}'], &['\u{62b1}'], &['\u{62d4}'], &['\u{6350}'], &['\u{22b0c}'],
+        &['\u{633d}'], &['\u{62fc}'], &['\u{6368}'], &['\u{6383}'], &['\u{63e4}'], &['\u{22bf1}'],
+        &['\u{6422}'], &['\u{63c5}'], &['\u{63a9}'], &['\u{3a2e}'], &['\u{6469}'], &['\u{647e}'],
+        &['\u{649d}'], &['\u{6477}'], &['\u{3a6c}'], &['\u{656c}'], &['\u{2300a}'], &['\u{65e3}'],
+        &['\u{66f8}'], &['\u{6649}'], &['\u{3b19}'], &['\u{3b08}'], &['\u{3ae4}'], &['\u{5192}'],
+        &['\u{5195}'], &['\u{6700}'], &['\u{669c}'], &['\u{80ad}'], &['\u{43d9}'], &['\u{6721}'],
+        &['\u{675e}'], &['\u{6753}'], &['\u{233c3}'], &['\u{3b49}'], &['\u{67fa}'], &['\u{6785}'],
+        &['\u{6852}'], &['\u{2346d}'], &['\u{688e}'], &['\u{681f}'], &['\u{6914}'], &['\u{6942}'],
+        &['\u{69a3}'], &['\u{69ea}'], &['\u{6aa8}'], &['\u{236a3}'], &['\u{6adb}'], &['\u{3c18}'],
+        &['\u{6b21}'], &['\u{238a7}'], &['\u{6b54}'], &['\u{3c4e}'], &['\u{6b72}'], &['\u{6b9f}'],
+        &['\u{6bbb}'], &['\u{23a8d}'], &['\u{21d0b}'], &['\u{23afa}'], &['\u{6c4e}'],
+        &['\u{23cbc}'], &['\u{6cbf}'], &['\u{6ccd}'], &['\u{6c67}'], &['\u{6d16}'], &['\u{6d3e}'],
+        &['\u{6d69}'], &['\u{6d78}'], &['\u{23d1e}'], &['\u{6d34}'], &['\u{6e2f}'], &['\u{6e6e}'],
+        &['\u{3d33}'], &['\u{6ec7}'], &['\u{23ed1}'], &['\u{6df9}'], &['\u{6f6e}'], &['\u{23f5e}'],
+        &['\u{23f8e}'], &['\u{6fc6}'], &['\u{7039}'], &['\u{701b}'], &['\u{3d96}'], &['\u{704a}'],
+        &['\u{707d}'], &['\u{7077}'], &['\u{70ad}'], &['\u{20525}'], &['\u{7145}'], &['\u{24263}'],
+        &['\u{719c}'], &['\u{243ab}'], &['\u{7228}'], &['\u{7250}'], &['\u{24608}'], &['\u{7280}'],
+        &['\u{7295}'], &['\u{24735}'], &['\u{737a}'], &['\u{738b}'], &['\u{3eac}'], &['\u{3eb8}'],
+        &['\u{7447}'], &['\u{745c}'], &['\u{7485}'], &['\u{74ca}'], &['\u{3f1b}'], &['\u{7524}'],
+        &['\u{24c36}'], &['\u{753e}'], &['\u{24c92}'], &['\u{2219f}'], &['\u{7610}'],
+        &['\u{24fa1}'], &['\u{24fb8}'], &['\u{25044}'], &['\u{3ffc}'], &['\u{4008}'],
+        &['\u{250f3}'], &['\u{250f2}'], &['\u{25133}'], &['\u{771e}'], &['\u{771f}'], &['\u{778b}'],
+        &['\u{4046}'], &['\u{4096}'], &['\u{2541d}'], &['\u{784e}'], &['\u{40e3}'], &['\u{25626}'],
+        &['\u{2569a}'], &['\u{256c5}'], &['\u{79eb}'], &['\u{412f}'], &['\u{7a4a}'], &['\u{7a4f}'],
+        &['\u{2597c}'], &['\u{25aa7}'], &['\u{7aee}'], &['\u{4202}'], &['\u{25bab}'], &['\u{7bc6}'],
+        &['\u{7bc9}'], &['\u{4227}'], &['\u{25c80}'], &['\u{7cd2}'], &['\u{42a0}'], &['\u{7ce8}'],
+        &['\u{7ce3}'], &['\u{7d00}'], &['\u{25f86}'], &['\u{7d63}'], &['\u{4301}'], &['\u{7dc7}'],
+        &['\u{7e02}'], &['\u{7e45}'], &['\u{4334}'], &['\u{26228}'], &['\u{26247}'], &['\u{4359}'],
+        &['\u{262d9}'], &['\u{7f7a}'], &['\u{2633e}'], &['\u{7f95}'], &['\u{7ffa}'], &['\u{264da}'],
+        &['\u{26523}'], &['\u{8060}'], &['\u{8070}'], &['\u{43d5}'], &['\u{80b2}'], &['\u{8103}'],
+        &['\u{440b}'], &['\u{813e}'], &['\u{5ab5}'], &['\u{267a7}'], &['\u{267b5}'], &['\u{23393}'],
+        &['\u{2339c}'], &['\u{8204}'], &['\u{8f9e}'], &['\u{446b}'], &['\u{8291}'], &['\u{828b}'],
+        &['\u{829d}'], &['\u{52b3}'], &['\u{82b1}'], &['\u{82b3}'], &['\u{82bd}'], &['\u{82e6}'],
+        &['\u{26b3c}'], &['\u{831d}'], &['\u{8363}'], &['\u{83ad}'], &['\u{8323}'], &['\u{83bd}'],
+        &['\u{83e7}'], &['\u{8353}'], &['\u{83ca}'], &['\u{83cc}'], &['\u{83dc}'], &['\u{26d6b}'],
+        &['\u{26cd5}'], &['\u{452b}'], &['\u{84f1}'], &['\u{84f3}'], &['\u{8516}'], &['\u{273ca}'],
+        &['\u{8564}'], &['\u{26f2c}'], &['\u{455d}'], &['\u{4561}'], &['\u{26fb1}'], &['\u{270d2}'],
+        &['\u{456b}'], &['\u{8650}'], &['\u{8667}'], &['\u{8669}'], &['\u{86a9}'], &['\u{8688}'],
+        &['\u{870e}'], &['\u{86e2}'], &['\u{8728}'], &['\u{876b}'], &['\u{8786}'], &['\u{45d7}'],
+        &['\u{87e1}'], &['\u{8801}'], &['\u{45f9}'], &['\u{8860}'], &['\u{27667}'], &['\u{88d7}'],
+        &['\u{88de}'], &['\u{4635}'], &['\u{88fa}'], &['\u{34bb}'], &['\u{27966}'], &['\u{46be}'],
+        &['\u{46c7}'], &['\u{8aa0}'], &['\u{27ca8}'], &['\u{8cab}'], &['\u{8cc1}'], &['\u{8d77}'],
+        &['\u{27f2f}'], &['\u{20804}'], &['\u{8dcb}'], &['\u{8dbc}'], &['\u{8df0}'], &['\u{208de}'],
+        &['\u{8ed4}'], &['\u{285d2}'], &['\u{285ed}'], &['\u{9094}'], &['\u{90f1}'], &['\u{9111}'],
+        &['\u{2872e}'], &['\u{911b}'], &['\u{9238}'], &['\u{92d7}'], &['\u{92d8}'], &['\u{927c}'],
+        &['\u{93f9}'], &['\u{9415}'], &['\u{28bfa}'], &['\u{958b}'], &['\u{4995}'], &['\u{95b7}'],
+        &['\u{28d77}'], &['\u{49e6}'], &['\u{96c3}'], &['\u{5db2}'], &['\u{9723}'], &['\u{29145}'],
+        &['\u{2921a}'], &['\u{4a6e}'], &['\u{4a76}'], &['\u{97e0}'], &['\u{2940a}'], &['\u{4ab2}'],
+        &['\u{29496}'], &['\u{9829}'], &['\u{295b6}'], &['\u{98e2}'], &['\u{4b33}'], &['\u{9929}'],
+        &['\u{99a7}'], &['\u{99c2}'], &['\u{99fe}'], &['\u{4bce}'], &['\u{29b30}'], &['\u{9c40}'],
+        &['\u{9cfd}'], &['\u{4cce}'], &['\u{4ced}'], &['\u{9d67}'], &['\u{2a0ce}'], &['\u{4cf8}'],
+        &['\u{2a105}'], &['\u{2a20e}'], &['\u{2a291}'], &['\u{4d56}'], &['\u{9efe}'], &['\u{9f05}'],
+        &['\u{9f0f}'], &['\u{9f16}'], &['\u{2a600}']
     ];
 
 }
 
+#[cfg(feature = "confusables")]
+pub mod confusable_sequences {
+
+    #[inline]
+    pub fn confusable_sequence_target(source: &[char]) -> Option<&'static [char]> {
+        CONFUSABLE_SEQUENCES
+            .binary_search_by_key(&source, |&(k, _)| k)
+            .ok()
+            .map(|idx| CONFUSABLE_SEQUENCES[idx].1)
+    }
+
+    pub const MAX_SEQUENCE_LEN: usize = 2;
+
+    // Confusable sequence table:
+    const CONFUSABLE_SEQUENCES: &[(&[char], &[char])] = &[
+        (&['\u{56}',  '\u{56}'],  &['\u{57}']), (&['\u{63}',  '\u{6c}'],  &['\u{64}']), (&['\u{76}',
+         '\u{76}'],  &['\u{77}'])
+    ];
+
+}
+
+#[cfg(feature = "confusables")]
+pub mod intentional_confusables {
+
+    #[inline]
+    pub fn intentional_confusables(c: char) -> &'static [char] {
+        super::util::bsearch_value_table(c, INTENTIONAL_CONFUSABLES).unwrap_or(&[])
+    }
+
+    // Intentional confusables table:
+    const INTENTIONAL_CONFUSABLES: &[(char, &[char])] = &[
+        
+    ];
+
+}
+
+#[cfg(feature = "mixed-script")]
 pub mod potential_mixed_script_confusable {
+    use super::util::Trie;
+
     #[inline]
     pub fn potential_mixed_script_confusable(c: char) -> bool {
-        match c as usize {
-            _ => super::util::bsearch_table(c, CONFUSABLES)
-        }
+        super::util::trie_bitset_contains(c, &CONFUSABLES)
     }
-    const CONFUSABLES: &'static [char] = &[
-        '\u{41}', '\u{42}', '\u{43}', '\u{45}', '\u{48}', '\u{49}', '\u{4a}', '\u{4b}', '\u{4d}',
-        '\u{4e}', '\u{4f}', '\u{50}', '\u{53}', '\u{54}', '\u{55}', '\u{56}', '\u{58}', '\u{59}',
-        '\u{5a}', '\u{61}', '\u{62}', '\u{63}', '\u{65}', '\u{66}', '\u{67}', '\u{68}', '\u{69}',
-        '\u{6a}', '\u{6c}', '\u{6e}', '\u{6f}', '\u{70}', '\u{71}', '\u{72}', '\u{73}', '\u{75}',
-        '\u{76}', '\u{77}', '\u{78}', '\u{79}', '\u{c6}', '\u{c7}', '\u{df}', '\u{e6}', '\u{e7}',
-        '\u{f6}', '\u{fe}', '\u{127}', '\u{131}', '\u{14b}', '\u{18f}', '\u{192}', '\u{196}',
-        '\u{1b7}', '\u{1dd}', '\u{24d}', '\u{259}', '\u{25b}', '\u{263}', '\u{269}', '\u{28b}',
-        '\u{292}', '\u{391}', '\u{392}', '\u{393}', '\u{395}', '\u{396}', '\u{397}', '\u{398}',
-        '\u{399}', '\u{39a}', '\u{39b}', '\u{39c}', '\u{39d}', '\u{39f}', '\u{3a0}', '\u{3a1}',
-        '\u{3a4}', '\u{3a5}', '\u{3a6}', '\u{3a7}', '\u{3b1}', '\u{3b2}', '\u{3b3}', '\u{3b4}',
-        '\u{3b5}', '\u{3b7}', '\u{3b8}', '\u{3b9}', '\u{3ba}', '\u{3bd}', '\u{3bf}', '\u{3c0}',
-        '\u{3c1}', '\u{3c3}', '\u{3c4}', '\u{3c5}', '\u{3c6}', '\u{405}', '\u{406}', '\u{408}',
-        '\u{410}', '\u{411}', '\u{412}', '\u{413}', '\u{415}', '\u{417}', '\u{41a}', '\u{41b}',
-        '\u{41c}', '\u{41d}', '\u{41e}', '\u{41f}', '\u{420}', '\u{421}', '\u{422}', '\u{423}',
-        '\u{424}', '\u{425}', '\u{42b}', '\u{42c}', '\u{42e}', '\u{430}', '\u{431}', '\u{433}',
-        '\u{435}', '\u{43a}', '\u{43e}', '\u{43f}', '\u{440}', '\u{441}', '\u{442}', '\u{443}',
-        '\u{444}', '\u{445}', '\u{447}', '\u{448}', '\u{454}', '\u{455}', '\u{456}', '\u{458}',
-        '\u{45b}', '\u{490}', '\u{491}', '\u{493}', '\u{498}', '\u{4aa}', '\u{4ab}', '\u{4ae}',
-        '\u{4af}', '\u{4bb}', '\u{4bd}', '\u{4bf}', '\u{4c0}', '\u{4cf}', '\u{4d4}', '\u{4d5}',
-        '\u{4d8}', '\u{4d9}', '\u{4e0}', '\u{4e1}', '\u{4e8}', '\u{53b}', '\u{544}', '\u{548}',
-        '\u{54a}', '\u{54c}', '\u{54d}', '\u{54f}', '\u{553}', '\u{555}', '\u{561}', '\u{563}',
-        '\u{566}', '\u{56e}', '\u{570}', '\u{571}', '\u{572}', '\u{578}', '\u{57a}', '\u{57c}',
-        '\u{57d}', '\u{581}', '\u{582}', '\u{584}', '\u{585}', '\u{5d5}', '\u{5d8}', '\u{5d9}',
-        '\u{5df}', '\u{5e1}', '\u{5f3}', '\u{5f4}', '\u{625}', '\u{627}', '\u{629}', '\u{647}',
-        '\u{660}', '\u{661}', '\u{665}', '\u{667}', '\u{668}', '\u{669}', '\u{6be}', '\u{6c1}',
-        '\u{6c3}', '\u{6d5}', '\u{6f0}', '\u{6f1}', '\u{6f5}', '\u{6f7}', '\u{6f8}', '\u{6f9}',
-        '\u{6ff}', '\u{901}', '\u{902}', '\u{903}', '\u{909}', '\u{91f}', '\u{920}', '\u{922}',
-        '\u{92a}', '\u{92d}', '\u{92e}', '\u{935}', '\u{939}', '\u{93c}', '\u{93f}', '\u{941}',
-        '\u{942}', '\u{945}', '\u{946}', '\u{947}', '\u{948}', '\u{94d}', '\u{956}', '\u{957}',
-        '\u{966}', '\u{967}', '\u{968}', '\u{969}', '\u{96a}', '\u{96e}', '\u{981}', '\u{983}',
-        '\u{9bc}', '\u{9bf}', '\u{9e6}', '\u{9ea}', '\u{9ed}', '\u{a02}', '\u{a15}', '\u{a1c}',
-        '\u{a1f}', '\u{a20}', '\u{a24}', '\u{a27}', '\u{a2b}', '\u{a2e}', '\u{a35}', '\u{a38}',
-        '\u{a3c}', '\u{a3f}', '\u{a41}', '\u{a42}', '\u{a47}', '\u{a48}', '\u{a4b}', '\u{a4d}',
-        '\u{a82}', '\u{a83}', '\u{aaa}', '\u{ab0}', '\u{abc}', '\u{ac1}', '\u{ac2}', '\u{acd}',
-        '\u{ae6}', '\u{ae8}', '\u{ae9}', '\u{aea}', '\u{aeb}', '\u{aee}', '\u{b01}', '\u{b03}',
-        '\u{b20}', '\u{b3c}', '\u{b47}', '\u{b89}', '\u{b90}', '\u{b9c}', '\u{ba3}', '\u{bae}',
-        '\u{bb4}', '\u{bb5}', '\u{bb6}', '\u{bb8}', '\u{bbf}', '\u{bc6}', '\u{bc7}', '\u{bcd}',
-        '\u{c02}', '\u{c03}', '\u{c05}', '\u{c06}', '\u{c07}', '\u{c10}', '\u{c12}', '\u{c13}',
-        '\u{c14}', '\u{c16}', '\u{c17}', '\u{c1c}', '\u{c1d}', '\u{c1e}', '\u{c1f}', '\u{c23}',
-        '\u{c26}', '\u{c28}', '\u{c2f}', '\u{c30}', '\u{c32}', '\u{c33}', '\u{c3f}', '\u{c41}',
-        '\u{c43}', '\u{c82}', '\u{c83}', '\u{c85}', '\u{c86}', '\u{c87}', '\u{c90}', '\u{c92}',
-        '\u{c93}', '\u{c94}', '\u{c97}', '\u{c9c}', '\u{c9d}', '\u{c9e}', '\u{c9f}', '\u{ca3}',
-        '\u{ca6}', '\u{ca8}', '\u{caf}', '\u{cb0}', '\u{cb2}', '\u{cb3}', '\u{cbf}', '\u{cc1}',
-        '\u{cc3}', '\u{ce6}', '\u{d02}', '\u{d03}', '\u{d09}', '\u{d16}', '\u{d1c}', '\u{d1f}',
-        '\u{d20}', '\u{d23}', '\u{d25}', '\u{d30}', '\u{d31}', '\u{d34}', '\u{d36}', '\u{d3f}',
-        '\u{d40}', '\u{d46}', '\u{d47}', '\u{d48}', '\u{d82}', '\u{d83}', '\u{e08}', '\u{e1a}',
-        '\u{e1b}', '\u{e1d}', '\u{e1e}', '\u{e1f}', '\u{e22}', '\u{e34}', '\u{e35}', '\u{e36}',
-        '\u{e37}', '\u{e38}', '\u{e39}', '\u{e48}', '\u{e49}', '\u{e4a}', '\u{e4b}', '\u{e4d}',
-        '\u{e50}', '\u{e88}', '\u{e8d}', '\u{e9a}', '\u{e9b}', '\u{e9d}', '\u{e9e}', '\u{e9f}',
-        '\u{eb8}', '\u{eb9}', '\u{ec8}', '\u{ec9}', '\u{eca}', '\u{ecb}', '\u{ecd}', '\u{ed0}',
-        '\u{1002}', '\u{1004}', '\u{1010}', '\u{101d}', '\u{1031}', '\u{1036}', '\u{1038}',
-        '\u{1040}', '\u{105a}', '\u{10d7}', '\u{10d8}', '\u{10e7}', '\u{1200}', '\u{1206}',
-        '\u{1223}', '\u{1240}', '\u{1260}', '\u{1261}', '\u{1294}', '\u{12ae}', '\u{12d0}',
-        '\u{1323}', '\u{17b7}', '\u{17b8}', '\u{17b9}', '\u{17ba}', '\u{17c6}', '\u{17cb}',
-        '\u{17e0}', '\u{1e9e}', '\u{3007}', '\u{3078}', '\u{30a4}', '\u{30a8}', '\u{30ab}',
-        '\u{30bf}', '\u{30c8}', '\u{30cb}', '\u{30ce}', '\u{30cf}', '\u{30d8}', '\u{30ed}',
-        '\u{4e00}', '\u{4e3f}', '\u{4e8c}', '\u{4ebb}', '\u{516b}', '\u{529b}', '\u{535c}',
-        '\u{53e3}', '\u{56d7}', '\u{5915}', '\u{5de5}'
+
+    const CONFUSABLES: Trie<u64> = Trie {
+        latin1: &[
+            0x0, 0x3efd7ee0779ef2e, 0x0, 0x404000c0800000c0
+        ],
+        index: &[
+            0, 1, 2, 3, 4, 5, 6, 7, 7, 8, 9, 10, 11, 12, 13, 7, 14, 7, 15, 16, 7, 7, 7, 17, 7, 7, 7,
+            7, 7, 7, 18, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 19, 7, 7, 7, 7, 7, 7, 7,
+            7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 20, 7, 7, 21, 22, 23,
+            7, 7, 24, 7, 7, 25, 7, 7, 7, 26
+        ],
+        blocks: &[
+            0, 1, 0, 2, 3, 4, 5, 6, 0, 7, 8, 0, 0, 0, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+            21, 22, 0, 0, 0, 0, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39,
+            40, 41, 0, 42, 43, 44, 43, 45, 46, 0, 47, 48, 49, 50, 51, 52, 0, 0, 0, 0, 0, 53, 54, 0,
+            0, 55, 0, 32, 56, 57, 58, 59, 0, 60, 0, 0, 61, 0, 0, 0, 0, 62, 0, 0, 63, 0, 52, 0, 0, 0,
+            64, 65, 0, 0, 0, 0, 0, 0, 66
+        ],
+        leaves: &[
+            0x0, 0x3efd7ee0779ef2e, 0x404000c0800000c0, 0x2008000000000, 0x800, 0x80000000448000,
+            0x20000000, 0x2080a002000, 0x40800, 0xa7be00f3bfee0000, 0x7b, 0xc42b583ffcaf0160,
+            0x97001bf, 0xa800cc00010b0000, 0x10303308001, 0x800000000000000, 0x3507404a0028b510,
+            0x36, 0x18000283200000, 0x2a000000000, 0x3a300000080, 0x4000000000000000,
+            0x83a300000020000a, 0x922064058000020e, 0x47c000c021e6, 0x900000000000000a,
+            0x244000000000, 0x9120489190200004, 0x2986, 0x100104000000000c, 0x4f4000002006,
+            0x100000010000000a, 0x80, 0x8170400810010200, 0x20c0, 0x800d8148f0dd00ec, 0xa,
+            0x800d8148f09d00ec, 0x400000000a, 0x805300299040020c, 0x1c1, 0xc, 0x3f00004ec000100,
+            0x12f00, 0x3000000ec002100, 0x142000020010014, 0x4000001, 0x8001800000, 0x800000041,
+            0x300000001, 0x400000100000, 0x10000, 0x800000000, 0x780000000000000, 0x100000840,
+            0x40000000, 0x100000000000000, 0x8000091000000000, 0x20000100c900, 0x8000000000000001,
+            0x800000000001000, 0x80000000000, 0x8000000, 0x10000000, 0x800000, 0x200000,
+            0x2000000000
+        ],
+        block_bits: 2,
+        leaf_bits: 0,
+    };
+
+}
+
+#[cfg(feature = "confusables")]
+pub mod case_folding {
+
+    #[inline]
+    pub fn case_fold(c: char) -> Option<&'static [char]> {
+        super::util::bsearch_value_table(c, CASE_FOLDING)
+    }
+
+    // Full case folding table:
+    const CASE_FOLDING: &[(char, &[char])] = &[
+        ('\u{41}',  &['\u{61}']), ('\u{42}',  &['\u{62}']), ('\u{43}',  &['\u{63}']), ('\u{44}',
+         &['\u{64}']), ('\u{45}',  &['\u{65}']), ('\u{46}',  &['\u{66}']), ('\u{47}',  &['\u{67}']),
+        ('\u{48}',  &['\u{68}']), ('\u{49}',  &['\u{69}']), ('\u{4a}',  &['\u{6a}']), ('\u{4b}',
+         &['\u{6b}']), ('\u{4c}',  &['\u{6c}']), ('\u{4d}',  &['\u{6d}']), ('\u{4e}',  &['\u{6e}']),
+        ('\u{4f}',  &['\u{6f}']), ('\u{50}',  &['\u{70}']), ('\u{51}',  &['\u{71}']), ('\u{52}',
+         &['\u{72}']), ('\u{53}',  &['\u{73}']), ('\u{54}',  &['\u{74}']), ('\u{55}',  &['\u{75}']),
+        ('\u{56}',  &['\u{76}']), ('\u{57}',  &['\u{77}']), ('\u{58}',  &['\u{78}']), ('\u{59}',
+         &['\u{79}']), ('\u{5a}',  &['\u{7a}']), ('\u{b5}',  &['\u{3bc}']), ('\u{c0}',
+         &['\u{e0}']), ('\u{c1}',  &['\u{e1}']), ('\u{c2}',  &['\u{e2}']), ('\u{c3}',  &['\u{e3}']),
+        ('\u{c4}',  &['\u{e4}']), ('\u{c5}',  &['\u{e5}']), ('\u{c6}',  &['\u{e6}']), ('\u{c7}',
+         &['\u{e7}']), ('\u{c8}',  &['\u{e8}']), ('\u{c9}',  &['\u{e9}']), ('\u{ca}',  &['\u{ea}']),
+        ('\u{cb}',  &['\u{eb}']), ('\u{cc}',  &['\u{ec}']), ('\u{cd}',  &['\u{ed}']), ('\u{ce}',
+         &['\u{ee}']), ('\u{cf}',  &['\u{ef}']), ('\u{d0}',  &['\u{f0}']), ('\u{d1}',  &['\u{f1}']),
+        ('\u{d2}',  &['\u{f2}']), ('\u{d3}',  &['\u{f3}']), ('\u{d4}',  &['\u{f4}']), ('\u{d5}',
+         &['\u{f5}']), ('\u{d6}',  &['\u{f6}']), ('\u{d8}',  &['\u{f8}']), ('\u{d9}',  &['\u{f9}']),
+        ('\u{da}',  &['\u{fa}']), ('\u{db}',  &['\u{fb}']), ('\u{dc}',  &['\u{fc}']), ('\u{dd}',
+         &['\u{fd}']), ('\u{de}',  &['\u{fe}']), ('\u{df}',  &['\u{73}',  '\u{73}']), ('\u{100}',
+         &['\u{101}']), ('\u{102}',  &['\u{103}']), ('\u{104}',  &['\u{105}']), ('\u{106}',
+         &['\u{107}']), ('\u{108}',  &['\u{109}']), ('\u{10a}',  &['\u{10b}']), ('\u{10c}',
+         &['\u{10d}']), ('\u{10e}',  &['\u{10f}']), ('\u{110}',  &['\u{111}']), ('\u{112}',
+         &['\u{113}']), ('\u{114}',  &['\u{115}']), ('\u{116}',  &['\u{117}']), ('\u{118}',
+         &['\u{119}']), ('\u{11a}',  &['\u{11b}']), ('\u{11c}',  &['\u{11d}']), ('\u{11e}',
+         &['\u{11f}']), ('\u{120}',  &['\u{121}']), ('\u{122}',  &['\u{123}']), ('\u{124}',
+         &['\u{125}']), ('\u{126}',  &['\u{127}']), ('\u{128}',  &['\u{129}']), ('\u{12a}',
+         &['\u{12b}']), ('\u{12c}',  &['\u{12d}']), ('\u{12e}',  &['\u{12f}']), ('\u{130}',
+         &['\u{69}',  '\u{307}']), ('\u{132}',  &['\u{133}']), ('\u{134}',  &['\u{135}']),
+        ('\u{136}',  &['\u{137}']), ('\u{139}',  &['\u{13a}']), ('\u{13b}',  &['\u{13c}']),
+        ('\u{13d}',  &['\u{13e}']), ('\u{13f}',  &['\u{140}']), ('\u{141}',  &['\u{142}']),
+        ('\u{143}',  &['\u{144}']), ('\u{145}',  &['\u{146}']), ('\u{147}',  &['\u{148}']),
+        ('\u{149}',  &['\u{2bc}',  '\u{6e}']), ('\u{14a}',  &['\u{14b}']), ('\u{14c}',
+         &['\u{14d}']), ('\u{14e}',  &['\u{14f}']), ('\u{150}',  &['\u{151}']), ('\u{152}',
+         &['\u{153}']), ('\u{154}',  &['\u{155}']), ('\u{156}',  &['\u{157}']), ('\u{158}',
+         &['\u{159}']), ('\u{15a}',  &['\u{15b}']), ('\u{15c}',  &['\u{15d}']), ('\u{15e}',
+         &['\u{15f}']), ('\u{160}',  &['\u{161}']), ('\u{162}',  &['\u{163}']), ('\u{164}',
+         &['\u{165}']), ('\u{166}',  &['\u{167}']), ('\u{168}',  &['\u{169}']), ('\u{16a}',
+         &['\u{16b}']), ('\u{16c}',  &['\u{16d}']), ('\u{16e}',  &['\u{16f}']), ('\u{170}',
+         &['\u{171}']), ('\u{172}',  &['\u{173}']), ('\u{174}',  &['\u{175}']), ('\u{176}',
+         &['\u{177}']), ('\u{178}',  &['\u{ff}']), ('\u{179}',  &['\u{17a}']), ('\u{17b}',
+         &['\u{17c}']), ('\u{17d}',  &['\u{17e}']), ('\u{17f}',  &['\u{73}']), ('\u{181}',
+         &['\u{253}']), ('\u{182}',  &['\u{183}']), ('\u{184}',  &['\u{185}']), ('\u{186}',
+         &['\u{254}']), ('\u{187}',  &['\u{188}']), ('\u{189}',  &['\u{256}']), ('\u{18a}',
+         &['\u{257}']), ('\u{18b}',  &['\u{18c}']), ('\u{18e}',  &['\u{1dd}']), ('\u{18f}',
+         &['\u{259}']), ('\u{190}',  &['\u{25b}']), ('\u{191}',  &['\u{192}']), ('\u{193}',
+         &['\u{260}']), ('\u{194}',  &['\u{263}']), ('\u{196}',  &['\u{269}']), ('\u{197}',
+         &['\u{268}']), ('\u{198}',  &['\u{199}']), ('\u{19c}',  &['\u{26f}']), ('\u{19d}',
+         &['\u{272}']), ('\u{19f}',  &['\u{275}']), ('\u{1a0}',  &['\u{1a1}']), ('\u{1a2}',
+         &['\u{1a3}']), ('\u{1a4}',  &['\u{1a5}']), ('\u{1a6}',  &['\u{280}']), ('\u{1a7}',
+         &['\u{1a8}']), ('\u{1a9}',  &['\u{283}']), ('\u{1ac}',  &['\u{1ad}']), ('\u{1ae}',
+         &['\u{288}']), ('\u{1af}',  &['\u{1b0}']), ('\u{1b1}',  &['\u{28a}']), ('\u{1b2}',
+         &['\u{28b}']), ('\u{1b3}',  &['\u{1b4}']), ('\u{1b5}',  &['\u{1b6}']), ('\u{1b7}',
+         &['\u{292}']), ('\u{1b8}',  &['\u{1b9}']), ('\u{1bc}',  &['\u{1bd}']), ('\u{1c4}',
+         &['\u{1c6}']), ('\u{1c5}',  &['\u{1c6}']), ('\u{1c7}',  &['\u{1c9}']), ('\u{1c8}',
+         &['\u{1c9}']), ('\u{1ca}',  &['\u{1cc}']), ('\u{1cb}',  &['\u{1cc}']), ('\u{1cd}',
+         &['\u{1ce}']), ('\u{1cf}',  &['\u{1d0}']), ('\u{1d1}',  &['\u{1d2}']), ('\u{1d3}',
+         &['\u{1d4}']), ('\u{1d5}',  &['\u{1d6}']), ('\u{1d7}',  &['\u{1d8}']), ('\u{1d9}',
+         &['\u{1da}']), ('\u{1db}',  &['\u{1dc}']), ('\u{1de}',  &['\u{1df}']), ('\u{1e0}',
+         &['\u{1e1}']), ('\u{1e2}',  &['\u{1e3}']), ('\u{1e4}',  &['\u{1e5}']), ('\u{1e6}',
+         &['\u{1e7}']), ('\u{1e8}',  &['\u{1e9}']), ('\u{1ea}',  &['\u{1eb}']), ('\u{1ec}',
+         &['\u{1ed}']), ('\u{1ee}',  &['\u{1ef}']), ('\u{1f0}',  &['\u{6a}',  '\u{30c}']),
+        ('\u{1f1}',  &['\u{1f3}']), ('\u{1f2}',  &['\u{1f3}']), ('\u{1f4}',  &['\u{1f5}']),
+        ('\u{1f6}',  &['\u{195}']), ('\u{1f7}',  &['\u{1bf}']), ('\u{1f8}',  &['\u{1f9}']),
+        ('\u{1fa}',  &['\u{1fb}']), ('\u{1fc}',  &['\u{1fd}']), ('\u{1fe}',  &['\u{1ff}']),
+        ('\u{200}',  &['\u{201}']), ('\u{202}',  &['\u{203}']), ('\u{204}',  &['\u{205}']),
+        ('\u{206}',  &['\u{207}']), ('\u{208}',  &['\u{209}']), ('\u{20a}',  &['\u{20b}']),
+        ('\u{20c}',  &['\u{20d}']), ('\u{20e}',  &['\u{20f}']), ('\u{210}',  &['\u{211}']),
+        ('\u{212}',  &['\u{213}']), ('\u{214}',  &['\u{215}']), ('\u{216}',  &['\u{217}']),
+        ('\u{218}',  &['\u{219}']), ('\u{21a}',  &['\u{21b}']), ('\u{21c}',  &['\u{21d}']),
+        ('\u{21e}',  &['\u{21f}']), ('\u{220}',  &['\u{19e}']), ('\u{222}',  &['\u{223}']),
+        ('\u{224}',  &['\u{225}']), ('\u{226}',  &['\u{227}']), ('\u{228}',  &['\u{229}']),
+        ('\u{22a}',  &['\u{22b}']), ('\u{22c}',  &['\u{22d}']), ('\u{22e}',  &['\u{22f}']),
+        ('\u{230}',  &['\u{231}']), ('\u{232}',  &['\u{233}']), ('\u{23a}',  &['\u{2c65}']),
+        ('\u{23b}',  &['\u{23c}']), ('\u{23d}',  &['\u{19a}']), ('\u{23e}',  &['\u{2c66}']),
+        ('\u{241}',  &['\u{242}']), ('\u{243}',  &['\u{180}']), ('\u{244}',  &['\u{289}']),
+        ('\u{245}',  &['\u{28c}']), ('\u{246}',  &['\u{247}']), ('\u{248}',  &['\u{249}']),
+        ('\u{24a}',  &['\u{24b}']), ('\u{24c}',  &['\u{24d}']), ('\u{24e}',  &['\u{24f}']),
+        ('\u{345}',  &['\u{3b9}']), ('\u{370}',  &['\u{371}']), ('\u{372}',  &['\u{373}']),
+        ('\u{376}',  &['\u{377}']), ('\u{37f}',  &['\u{3f3}']), ('\u{386}',  &['\u{3ac}']),
+        ('\u{388}',  &['\u{3ad}']), ('\u{389}',  &['\u{3ae}']), ('\u{38a}',  &['\u{3af}']),
+        ('\u{38c}',  &['\u{3cc}']), ('\u{38e}',  &['\u{3cd}']), ('\u{38f}',  &['\u{3ce}']),
+        ('\u{390}',  &['\u{3b9}',  '\u{308}',  '\u{301}']), ('\u{391}',  &['\u{3b1}']), ('\u{392}',
+         &['\u{3b2}']), ('\u{393}',  &['\u{3b3}']), ('\u{394}',  &['\u{3b4}']), ('\u{395}',
+         &['\u{3b5}']), ('\u{396}',  &['\u{3b6}']), ('\u{397}',  &['\u{3b7}']), ('\u{398}',
+         &['\u{3b8}']), ('\u{399}',  &['\u{3b9}']), ('\u{39a}',  &['\u{3ba}']), ('\u{39b}',
+         &['\u{3bb}']), ('\u{39c}',  &['\u{3bc}']), ('\u{39d}',  &['\u{3bd}']), ('\u{39e}',
+         &['\u{3be}']), ('\u{39f}',  &['\u{3bf}']), ('\u{3a0}',  &['\u{3c0}']), ('\u{3a1}',
+         &['\u{3c1}']), ('\u{3a3}',  &['\u{3c3}']), ('\u{3a4}',  &['\u{3c4}']), ('\u{3a5}',
+         &['\u{3c5}']), ('\u{3a6}',  &['\u{3c6}']), ('\u{3a7}',  &['\u{3c7}']), ('\u{3a8}',
+         &['\u{3c8}']), ('\u{3a9}',  &['\u{3c9}']), ('\u{3aa}',  &['\u{3ca}']), ('\u{3ab}',
+         &['\u{3cb}']), ('\u{3b0}',  &['\u{3c5}',  '\u{308}',  '\u{301}']), ('\u{3c2}',
+         &['\u{3c3}']), ('\u{3cf}',  &['\u{3d7}']), ('\u{3d0}',  &['\u{3b2}']), ('\u{3d1}',
+         &['\u{3b8}']), ('\u{3d5}',  &['\u{3c6}']), ('\u{3d6}',  &['\u{3c0}']), ('\u{3d8}',
+         &['\u{3d9}']), ('\u{3da}',  &['\u{3db}']), ('\u{3dc}',  &['\u{3dd}']), ('\u{3de}',
+         &['\u{3df}']), ('\u{3e0}',  &['\u{3e1}']), ('\u{3e2}',  &['\u{3e3}']), ('\u{3e4}',
+         &['\u{3e5}']), ('\u{3e6}',  &['\u{3e7}']), ('\u{3e8}',  &['\u{3e9}']), ('\u{3ea}',
+         &['\u{3eb}']), ('\u{3ec}',  &['\u{3ed}']), ('\u{3ee}',  &['\u{3ef}']), ('\u{3f0}',
+         &['\u{3ba}']), ('\u{3f1}',  &['\u{3c1}']), ('\u{3f4}',  &['\u{3b8}']), ('\u{3f5}',
+         &['\u{3b5}']), ('\u{3f7}',  &['\u{3f8}']), ('\u{3f9}',  &['\u{3f2}']), ('\u{3fa}',
+         &['\u{3fb}']), ('\u{3fd}',  &['\u{37b}']), ('\u{3fe}',  &['\u{37c}']), ('\u{3ff}',
+         &['\u{37d}']), ('\u{400}',  &['\u{450}']), ('\u{401}',  &['\u{451}']), ('\u{402}',
+         &['\u{452}']), ('\u{403}',  &['\u{453}']), ('\u{404}',  &['\u{454}']), ('\u{405}',
+         &['\u{455}']), ('\u{406}',  &['\u{456}']), ('\u{407}',  &['\u{457}']), ('\u{408}',
+         &['\u{458}']), ('\u{409}',  &['\u{459}']), ('\u{40a}',  &['\u{45a}']), ('\u{40b}',
+         &['\u{45b}']), ('\u{40c}',  &['\u{45c}']), ('\u{40d}',  &['\u{45d}']), ('\u{40e}',
+         &['\u{45e}']), ('\u{40f}',  &['\u{45f}']), ('\u{410}',  &['\u{430}']), ('\u{411}',
+         &['\u{431}']), ('\u{412}',  &['\u{432}']), ('\u{413}',  &['\u{433}']), ('\u{414}',
+         &['\u{434}']), ('\u{415}',  &['\u{435}']), ('\u{416}',  &['\u{436}']), ('\u{417}',
+         &['\u{437}']), ('\u{418}',  &['\u{438}']), ('\u{419}',  &['\u{439}']), ('\u{41a}',
+         &['\u{43a}']), ('\u{41b}',  &['\u{43b}']), ('\u{41c}',  &['\u{43c}']), ('\u{41d}',
+         &['\u{43d}']), ('\u{41e}',  &['\u{43e}']), ('\u{41f}',  &['\u{43f}']), ('\u{420}',
+         &['\u{440}']), ('\u{421}',  &['\u{441}']), ('\u{422}',  &['\u{442}']), ('\u{423}',
+         &['\u{443}']), ('\u{424}',  &['\u{444}']), ('\u{425}',  &['\u{445}']), ('\u{426}',
+         &['\u{446}']), ('\u{427}',  &['\u{447}']), ('\u{428}',  &['\u{448}']), ('\u{429}',
+         &['\u{449}']), ('\u{42a}',  &['\u{44a}']), ('\u{42b}',  &['\u{44b}']), ('\u{42c}',
+         &['\u{44c}']), ('\u{42d}',  &['\u{44d}']), ('\u{42e}',  &['\u{44e}']), ('\u{42f}',
+         &['\u{44f}']), ('\u{460}',  &['\u{461}']), ('\u{462}',  &['\u{463}']), ('\u{464}',
+         &['\u{465}']), ('\u{466}',  &['\u{467}']), ('\u{468}',  &['\u{469}']), ('\u{46a}',
+         &['\u{46b}']), ('\u{46c}',  &['\u{46d}']), ('\u{46e}',  &['\u{46f}']), ('\u{470}',
+         &['\u{471}']), ('\u{472}',  &['\u{473}']), ('\u{474}',  &['\u{475}']), ('\u{476}',
+         &['\u{477}']), ('\u{478}',  &['\u{479}']), ('\u{47a}',  &['\u{47b}']), ('\u{47c}',
+         &['\u{47d}']), ('\u{47e}',  &['\u{47f}']), ('\u{480}',  &['\u{481}']), ('\u{48a}',
+         &['\u{48b}']), ('\u{48c}',  &['\u{48d}']), ('\u{48e}',  &['\u{48f}']), ('\u{490}',
+         &['\u{491}']), ('\u{492}',  &['\u{493}']), ('\u{494}',  &['\u{495}']), ('\u{496}',
+         &['\u{497}']), ('\u{498}',  &['\u{499}']), ('\u{49a}',  &['\u{49b}']), ('\u{49c}',
+         &['\u{49d}']), ('\u{49e}',  &['\u{49f}']), ('\u{4a0}',  &['\u{4a1}']), ('\u{4a2}',
+         &['\u{4a3}']), ('\u{4a4}',  &['\u{4a5}']), ('\u{4a6}',  &['\u{4a7}']), ('\u{4a8}',
+         &['\u{4a9}']), ('\u{4aa}',  &['\u{4ab}']), ('\u{4ac}',  &['\u{4ad}']), ('\u{4ae}',
+         &['\u{4af}']), ('\u{4b0}',  &['\u{4b1}']), ('\u{4b2}',  &['\u{4b3}']), ('\u{4b4}',
+         &['\u{4b5}']), ('\u{4b6}',  &['\u{4b7}']), ('\u{4b8}',  &['\u{4b9}']), ('\u{4ba}',
+         &['\u{4bb}']), ('\u{4bc}',  &['\u{4bd}']), ('\u{4be}',  &['\u{4bf}']), ('\u{4c0}',
+         &['\u{4cf}']), ('\u{4c1}',  &['\u{4c2}']), ('\u{4c3}',  &['\u{4c4}']), ('\u{4c5}',
+         &['\u{4c6}']), ('\u{4c7}',  &['\u{4c8}']), ('\u{4c9}',  &['\u{4ca}']), ('\u{4cb}',
+         &['\u{4cc}']), ('\u{4cd}',  &['\u{4ce}']), ('\u{4d0}',  &['\u{4d1}']), ('\u{4d2}',
+         &['\u{4d3}']), ('\u{4d4}',  &['\u{4d5}']), ('\u{4d6}',  &['\u{4d7}']), ('\u{4d8}',
+         &['\u{4d9}']), ('\u{4da}',  &['\u{4db}']), ('\u{4dc}',  &['\u{4dd}']), ('\u{4de}',
+         &['\u{4df}']), ('\u{4e0}',  &['\u{4e1}']), ('\u{4e2}',  &['\u{4e3}']), ('\u{4e4}',
+         &['\u{4e5}']), ('\u{4e6}',  &['\u{4e7}']), ('\u{4e8}',  &['\u{4e9}']), ('\u{4ea}',
+         &['\u{4eb}']), ('\u{4ec}',  &['\u{4ed}']), ('\u{4ee}',  &['\u{4ef}']), ('\u{4f0}',
+         &['\u{4f1}']), ('\u{4f2}',  &['\u{4f3}']), ('\u{4f4}',  &['\u{4f5}']), ('\u{4f6}',
+         &['\u{4f7}']), ('\u{4f8}',  &['\u{4f9}']), ('\u{4fa}',  &['\u{4fb}']), ('\u{4fc}',
+         &['\u{4fd}']), ('\u{4fe}',  &['\u{4ff}']), ('\u{500}',  &['\u{501}']), ('\u{502}',
+         &['\u{503}']), ('\u{504}',  &['\u{505}']), ('\u{506}',  &['\u{507}']), ('\u{508}',
+         &['\u{509}']), ('\u{50a}',  &['\u{50b}']), ('\u{50c}',  &['\u{50d}']), ('\u{50e}',
+         &['\u{50f}']), ('\u{510}',  &['\u{511}']), ('\u{512}',  &['\u{513}']), ('\u{514}',
+         &['\u{515}']), ('\u{516}',  &['\u{517}']), ('\u{518}',  &['\u{519}']), ('\u{51a}',
+         &['\u{51b}']), ('\u{51c}',  &['\u{51d}']), ('\u{51e}',  &['\u{51f}']), ('\u{520}',
+         &['\u{521}']), ('\u{522}',  &['\u{523}']), ('\u{524}',  &['\u{525}']), ('\u{526}',
+         &['\u{527}']), ('\u{528}',  &['\u{529}']), ('\u{52a}',  &['\u{52b}']), ('\u{52c}',
+         &['\u{52d}']), ('\u{52e}',  &['\u{52f}']), ('\u{531}',  &['\u{561}']), ('\u{532}',
+         &['\u{562}']), ('\u{533}',  &['\u{563}']), ('\u{534}',  &['\u{564}']), ('\u{535}',
+         &['\u{565}']), ('\u{536}',  &['\u{566}']), ('\u{537}',  &['\u{567}']), ('\u{538}',
+         &['\u{568}']), ('\u{539}',  &['\u{569}']), ('\u{53a}',  &['\u{56a}']), ('\u{53b}',
+         &['\u{56b}']), ('\u{53c}',  &['\u{56c}']), ('\u{53d}',  &['\u{56d}']), ('\u{53e}',
+         &['\u{56e}']), ('\u{53f}',  &['\u{56f}']), ('\u{540}',  &['\u{570}']), ('\u{541}',
+         &['\u{571}']), ('\u{542}',  &['\u{572}']), ('\u{543}',  &['\u{573}']), ('\u{544}',
+         &['\u{574}']), ('\u{545}',  &['\u{575}']), ('\u{546}',  &['\u{576}']), ('\u{547}',
+         &['\u{577}']), ('\u{548}',  &['\u{578}']), ('\u{549}',  &['\u{579}']), ('\u{54a}',
+         &['\u{57a}']), ('\u{54b}',  &['\u{57b}']), ('\u{54c}',  &['\u{57c}']), ('\u{54d}',
+         &['\u{57d}']), ('\u{54e}',  &['\u{57e}']), ('\u{54f}',  &['\u{57f}']), ('\u{550}',
+         &['\u{580}']), ('\u{551}',  &['\u{581}']), ('\u{552}',  &['\u{582}']), ('\u{553}',
+         &['\u{583}']), ('\u{554}',  &['\u{584}']), ('\u{555}',  &['\u{585}']), ('\u{556}',
+         &['\u{586}']), ('\u{587}',  &['\u{565}',  '\u{582}']), ('\u{10a0}',  &['\u{2d00}']),
+        ('\u{10a1}',  &['\u{2d01}']), ('\u{10a2}',  &['\u{2d02}']), ('\u{10a3}',  &['\u{2d03}']),
+        ('\u{10a4}',  &['\u{2d04}']), ('\u{10a5}',  &['\u{2d05}']), ('\u{10a6}',  &['\u{2d06}']),
+        ('\u{10a7}',  &['\u{2d07}']), ('\u{10a8}',  &['\u{2d08}']), ('\u{10a9}',  &['\u{2d09}']),
+        ('\u{10aa}',  &['\u{2d0a}']), ('\u{10ab}',  &['\u{2d0b}']), ('\u{10ac}',  &['\u{2d0c}']),
+        ('\u{10ad}',  &['\u{2d0d}']), ('\u{10ae}',  &['\u{2d0e}']), ('\u{10af}',  &['\u{2d0f}']),
+        ('\u{10b0}',  &['\u{2d10}']), ('\u{10b1}',  &['\u{2d11}']), ('\u{10b2}',  &['\u{2d12}']),
+        ('\u{10b3}',  &['\u{2d13}']), ('\u{10b4}',  &['\u{2d14}']), ('\u{10b5}',  &['\u{2d15}']),
+        ('\u{10b6}',  &['\u{2d16}']), ('\u{10b7}',  &['\u{2d17}']), ('\u{10b8}',  &['\u{2d18}']),
+        ('\u{10b9}',  &['\u{2d19}']), ('\u{10ba}',  &['\u{2d1a}']), ('\u{10bb}',  &['\u{2d1b}']),
+        ('\u{10bc}',  &['\u{2d1c}']), ('\u{10bd}',  &['\u{2d1d}']), ('\u{10be}',  &['\u{2d1e}']),
+        ('\u{10bf}',  &['\u{2d1f}']), ('\u{10c0}',  &['\u{2d20}']), ('\u{10c1}',  &['\u{2d21}']),
+        ('\u{10c2}',  &['\u{2d22}']), ('\u{10c3}',  &['\u{2d23}']), ('\u{10c4}',  &['\u{2d24}']),
+        ('\u{10c5}',  &['\u{2d25}']), ('\u{10c7}',  &['\u{2d27}']), ('\u{10cd}',  &['\u{2d2d}']),
+        ('\u{13f8}',  &['\u{13f0}']), ('\u{13f9}',  &['\u{13f1}']), ('\u{13fa}',  &['\u{13f2}']),
+        ('\u{13fb}',  &['\u{13f3}']), ('\u{13fc}',  &['\u{13f4}']), ('\u{13fd}',  &['\u{13f5}']),
+        ('\u{1c80}',  &['\u{432}']), ('\u{1c81}',  &['\u{434}']), ('\u{1c82}',  &['\u{43e}']),
+        ('\u{1c83}',  &['\u{441}']), ('\u{1c84}',  &['\u{442}']), ('\u{1c85}',  &['\u{442}']),
+        ('\u{1c86}',  &['\u{44a}']), ('\u{1c87}',  &['\u{463}']), ('\u{1c88}',  &['\u{a64b}']),
+        ('\u{1c89}',  &['\u{1c8a}']), ('\u{1c90}',  &['\u{10d0}']), ('\u{1c91}',  &['\u{10d1}']),
+        ('\u{1c92}',  &['\u{10d2}']), ('\u{1c93}',  &['\u{10d3}']), ('\u{1c94}',  &['\u{10d4}']),
+        ('\u{1c95}',  &['\u{10d5}']), ('\u{1c96}',  &['\u{10d6}']), ('\u{1c97}',  &['\u{10d7}']),
+        ('\u{1c98}',  &['\u{10d8}']), ('\u{1c99}',  &['\u{10d9}']), ('\u{1c9a}',  &['\u{10da}']),
+        ('\u{1c9b}',  &['\u{10db}']), ('\u{1c9c}',  &['\u{10dc}']), ('\u{1c9d}',  &['\u{10dd}']),
+        ('\u{1c9e}',  &['\u{10de}']), ('\u{1c9f}',  &['\u{10df}']), ('\u{1ca0}',  &['\u{10e0}']),
+        ('\u{1ca1}',  &['\u{10e1}']), ('\u{1ca2}',  &['\u{10e2}']), ('\u{1ca3}',  &['\u{10e3}']),
+        ('\u{1ca4}',  &['\u{10e4}']), ('\u{1ca5}',  &['\u{10e5}']), ('\u{1ca6}',  &['\u{10e6}']),
+        ('\u{1ca7}',  &['\u{10e7}']), ('\u{1ca8}',  &['\u{10e8}']), ('\u{1ca9}',  &['\u{10e9}']),
+        ('\u{1caa}',  &['\u{10ea}']), ('\u{1cab}',  &['\u{10eb}']), ('\u{1cac}',  &['\u{10ec}']),
+        ('\u{1cad}',  &['\u{10ed}']), ('\u{1cae}',  &['\u{10ee}']), ('\u{1caf}',  &['\u{10ef}']),
+        ('\u{1cb0}',  &['\u{10f0}']), ('\u{1cb1}',  &['\u{10f1}']), ('\u{1cb2}',  &['\u{10f2}']),
+        ('\u{1cb3}',  &['\u{10f3}']), ('\u{1cb4}',  &['\u{10f4}']), ('\u{1cb5}',  &['\u{10f5}']),
+        ('\u{1cb6}',  &['\u{10f6}']), ('\u{1cb7}',  &['\u{10f7}']), ('\u{1cb8}',  &['\u{10f8}']),
+        ('\u{1cb9}',  &['\u{10f9}']), ('\u{1cba}',  &['\u{10fa}']), ('\u{1cbd}',  &['\u{10fd}']),
+        ('\u{1cbe}',  &['\u{10fe}']), ('\u{1cbf}',  &['\u{10ff}']), ('\u{1e00}',  &['\u{1e01}']),
+        ('\u{1e02}',  &['\u{1e03}']), ('\u{1e04}',  &['\u{1e05}']), ('\u{1e06}',  &['\u{1e07}']),
+        ('\u{1e08}',  &['\u{1e09}']), ('\u{1e0a}',  &['\u{1e0b}']), ('\u{1e0c}',  &['\u{1e0d}']),
+        ('\u{1e0e}',  &['\u{1e0f}']), ('\u{1e10}',  &['\u{1e11}']), ('\u{1e12}',  &['\u{1e13}']),
+        ('\u{1e14}',  &['\u{1e15}']), ('\u{1e16}',  &['\u{1e17}']), ('\u{1e18}',  &['\u{1e19}']),
+        ('\u{1e1a}',  &['\u{1e1b}']), ('\u{1e1c}',  &['\u{1e1d}']), ('\u{1e1e}',  &['\u{1e1f}']),
+        ('\u{1e20}',  &['\u{1e21}']), ('\u{1e22}',  &['\u{1e23}']), ('\u{1e24}',  &['\u{1e25}']),
+        ('\u{1e26}',  &['\u{1e27}']), ('\u{1e28}',  &['\u{1e29}']), ('\u{1e2a}',  &['\u{1e2b}']),
+        ('\u{1e2c}',  &['\u{1e2d}']), ('\u{1e2e}',  &['\u{1e2f}']), ('\u{1e30}',  &['\u{1e31}']),
+        ('\u{1e32}',  &['\u{1e33}']), ('\u{1e34}',  &['\u{1e35}']), ('\u{1e36}',  &['\u{1e37}']),
+        ('\u{1e38}',  &['\u{1e39}']), ('\u{1e3a}',  &['\u{1e3b}']), ('\u{1e3c}',  &['\u{1e3d}']),
+        ('\u{1e3e}',  &['\u{1e3f}']), ('\u{1e40}',  &['\u{1e41}']), ('\u{1e42}',  &['\u{1e43}']),
+        ('\u{1e44}',  &['\u{1e45}']), ('\u{1e46}',  &['\u{1e47}']), ('\u{1e48}',  &['\u{1e49}']),
+        ('\u{1e4a}',  &['\u{1e4b}']), ('\u{1e4c}',  &['\u{1e4d}']), ('\u{1e4e}',  &['\u{1e4f}']),
+        ('\u{1e50}',  &['\u{1e51}']), ('\u{1e52}',  &['\u{1e53}']), ('\u{1e54}',  &['\u{1e55}']),
+        ('\u{1e56}',  &['\u{1e57}']), ('\u{1e58}',  &['\u{1e59}']), ('\u{1e5a}',  &['\u{1e5b}']),
+        ('\u{1e5c}',  &['\u{1e5d}']), ('\u{1e5e}',  &['\u{1e5f}']), ('\u{1e60}',  &['\u{1e61}']),
+        ('\u{1e62}',  &['\u{1e63}']), ('\u{1e64}',  &['\u{1e65}']), ('\u{1e66}',  &['\u{1e67}']),
+        ('\u{1e68}',  &['\u{1e69}']), ('\u{1e6a}',  &['\u{1e6b}']), ('\u{1e6c}',  &['\u{1e6d}']),
+        ('\u{1e6e}',  &['\u{1e6f}']), ('\u{1e70}',  &['\u{1e71}']), ('\u{1e72}',  &['\u{1e73}']),
+        ('\u{1e74}',  &['\u{1e75}']), ('\u{1e76}',  &['\u{1e77}']), ('\u{1e78}',  &['\u{1e79}']),
+        ('\u{1e7a}',  &['\u{1e7b}']), ('\u{1e7c}',  &['\u{1e7d}']), ('\u{1e7e}',  &['\u{1e7f}']),
+        ('\u{1e80}',  &['\u{1e81}']), ('\u{1e82}',  &['\u{1e83}']), ('\u{1e84}',  &['\u{1e85}']),
+        ('\u{1e86}',  &['\u{1e87}']), ('\u{1e88}',  &['\u{1e89}']), ('\u{1e8a}',  &['\u{1e8b}']),
+        ('\u{1e8c}',  &['\u{1e8d}']), ('\u{1e8e}',  &['\u{1e8f}']), ('\u{1e90}',  &['\u{1e91}']),
+        ('\u{1e92}',  &['\u{1e93}']), ('\u{1e94}',  &['\u{1e95}']), ('\u{1e96}',  &['\u{68}',
+         '\u{331}']), ('\u{1e97}',  &['\u{74}',  '\u{308}']), ('\u{1e98}',  &['\u{77}',
+         '\u{30a}']), ('\u{1e99}',  &['\u{79}',  '\u{30a}']), ('\u{1e9a}',  &['\u{61}',
+         '\u{2be}']), ('\u{1e9b}',  &['\u{1e61}']), ('\u{1e9e}',  &['\u{73}',  '\u{73}']),
+        ('\u{1ea0}',  &['\u{1ea1}']), ('\u{1ea2}',  &['\u{1ea3}']), ('\u{1ea4}',  &['\u{1ea5}']),
+        ('\u{1ea6}',  &['\u{1ea7}']), ('\u{1ea8}',  &['\u{1ea9}']), ('\u{1eaa}',  &['\u{1eab}']),
+        ('\u{1eac}',  &['\u{1ead}']), ('\u{1eae}',  &['\u{1eaf}']), ('\u{1eb0}',  &['\u{1eb1}']),
+        ('\u{1eb2}',  &['\u{1eb3}']), ('\u{1eb4}',  &['\u{1eb5}']), ('\u{1eb6}',  &['\u{1eb7}']),
+        ('\u{1eb8}',  &['\u{1eb9}']), ('\u{1eba}',  &['\u{1ebb}']), ('\u{1ebc}',  &['\u{1ebd}']),
+        ('\u{1ebe}',  &['\u{1ebf}']), ('\u{1ec0}',  &['\u{1ec1}']), ('\u{1ec2}',  &['\u{1ec3}']),
+        ('\u{1ec4}',  &['\u{1ec5}']), ('\u{1ec6}',  &['\u{1ec7}']), ('\u{1ec8}',  &['\u{1ec9}']),
+        ('\u{1eca}',  &['\u{1ecb}']), ('\u{1ecc}',  &['\u{1ecd}']), ('\u{1ece}',  &['\u{1ecf}']),
+        ('\u{1ed0}',  &['\u{1ed1}']), ('\u{1ed2}',  &['\u{1ed3}']), ('\u{1ed4}',  &['\u{1ed5}']),
+        ('\u{1ed6}',  &['\u{1ed7}']), ('\u{1ed8}',  &['\u{1ed9}']), ('\u{1eda}',  &['\u{1edb}']),
+        ('\u{1edc}',  &['\u{1edd}']), ('\u{1ede}',  &['\u{1edf}']), ('\u{1ee0}',  &['\u{1ee1}']),
+        ('\u{1ee2}',  &['\u{1ee3}']), ('\u{1ee4}',  &['\u{1ee5}']), ('\u{1ee6}',  &['\u{1ee7}']),
+        ('\u{1ee8}',  &['\u{1ee9}']), ('\u{1eea}',  &['\u{1eeb}']), ('\u{1eec}',  &['\u{1eed}']),
+        ('\u{1eee}',  &['\u{1eef}']), ('\u{1ef0}',  &['\u{1ef1}']), ('\u{1ef2}',  &['\u{1ef3}']),
+        ('\u{1ef4}',  &['\u{1ef5}']), ('\u{1ef6}',  &['\u{1ef7}']), ('\u{1ef8}',  &['\u{1ef9}']),
+        ('\u{1efa}',  &['\u{1efb}']), ('\u{1efc}',  &['\u{1efd}']), ('\u{1efe}',  &['\u{1eff}']),
+        ('\u{1f08}',  &['\u{1f00}']), ('\u{1f09}',  &['\u{1f01}']), ('\u{1f0a}',  &['\u{1f02}']),
+        ('\u{1f0b}',  &['\u{1f03}']), ('\u{1f0c}',  &['\u{1f04}']), ('\u{1f0d}',  &['\u{1f05}']),
+        ('\u{1f0e}',  &['\u{1f06}']), ('\u{1f0f}',  &['\u{1f07}']), ('\u{1f18}',  &['\u{1f10}']),
+        ('\u{1f19}',  &['\u{1f11}']), ('\u{1f1a}',  &['\u{1f12}']), ('\u{1f1b}',  &['\u{1f13}']),
+        ('\u{1f1c}',  &['\u{1f14}']), ('\u{1f1d}',  &['\u{1f15}']), ('\u{1f28}',  &['\u{1f20}']),
+        ('\u{1f29}',  &['\u{1f21}']), ('\u{1f2a}',  &['\u{1f22}']), ('\u{1f2b}',  &['\u{1f23}']),
+        ('\u{1f2c}',  &['\u{1f24}']), ('\u{1f2d}',  &['\u{1f25}']), ('\u{1f2e}',  &['\u{1f26}']),
+        ('\u{1f2f}',  &['\u{1f27}']), ('\u{1f38}',  &['\u{1f30}']), ('\u{1f39}',  &['\u{1f31}']),
+        ('\u{1f3a}',  &['\u{1f32}']), ('\u{1f3b}',  &['\u{1f33}']), ('\u{1f3c}',  &['\u{1f34}']),
+        ('\u{1f3d}',  &['\u{1f35}']), ('\u{1f3e}',  &['\u{1f36}']), ('\u{1f3f}',  &['\u{1f37}']),
+        ('\u{1f48}',  &['\u{1f40}']), ('\u{1f49}',  &['\u{1f41}']), ('\u{1f4a}',  &['\u{1f42}']),
+        ('\u{1f4b}',  &['\u{1f43}']), ('\u{1f4c}',  &['\u{1f44}']), ('\u{1f4d}',  &['\u{1f45}']),
+        ('\u{1f50}',  &['\u{3c5}',  '\u{313}']), ('\u{1f52}',  &['\u{3c5}',  '\u{313}',
+         '\u{300}']), ('\u{1f54}',  &['\u{3c5}',  '\u{313}',  '\u{301}']), ('\u{1f56}',
+         &['\u{3c5}',  '\u{313}',  '\u{342}']), ('\u{1f59}',  &['\u{1f51}']), ('\u{1f5b}',
+         &['\u{1f53}']), ('\u{1f5d}',  &['\u{1f55}']), ('\u{1f5f}',  &['\u{1f57}']), ('\u{1f68}',
+         &['\u{1f60}']), ('\u{1f69}',  &['\u{1f61}']), ('\u{1f6a}',  &['\u{1f62}']), ('\u{1f6b}',
+         &['\u{1f63}']), ('\u{1f6c}',  &['\u{1f64}']), ('\u{1f6d}',  &['\u{1f65}']), ('\u{1f6e}',
+         &['\u{1f66}']), ('\u{1f6f}',  &['\u{1f67}']), ('\u{1f80}',  &['\u{1f00}',  '\u{3b9}']),
+        ('\u{1f81}',  &['\u{1f01}',  '\u{3b9}']), ('\u{1f82}',  &['\u{1f02}',  '\u{3b9}']),
+        ('\u{1f83}',  &['\u{1f03}',  '\u{3b9}']), ('\u{1f84}',  &['\u{1f04}',  '\u{3b9}']),
+        ('\u{1f85}',  &['\u{1f05}',  '\u{3b9}']), ('\u{1f86}',  &['\u{1f06}',  '\u{3b9}']),
+        ('\u{1f87}',  &['\u{1f07}',  '\u{3b9}']), ('\u{1f88}',  &['\u{1f00}',  '\u{3b9}']),
+        ('\u{1f89}',  &['\u{1f01}',  '\u{3b9}']), ('\u{1f8a}',  &['\u{1f02}',  '\u{3b9}']),
+        ('\u{1f8b}',  &['\u{1f03}',  '\u{3b9}']), ('\u{1f8c}',  &['\u{1f04}',  '\u{3b9}']),
+        ('\u{1f8d}',  &['\u{1f05}',  '\u{3b9}']), ('\u{1f8e}',  &['\u{1f06}',  '\u{3b9}']),
+        ('\u{1f8f}',  &['\u{1f07}',  '\u{3b9}']), ('\u{1f90}',  &['\u{1f20}',  '\u{3b9}']),
+        ('\u{1f91}',  &['\u{1f21}',  '\u{3b9}']), ('\u{1f92}',  &['\u{1f22}',  '\u{3b9}']),
+        ('\u{1f93}',  &['\u{1f23}',  '\u{3b9}']), ('\u{1f94}',  &['\u{1f24}',  '\u{3b9}']),
+        ('\u{1f95}',  &['\u{1f25}',  '\u{3b9}']), ('\u{1f96}',  &['\u{1f26}',  '\u{3b9}']),
+        ('\u{1f97}',  &['\u{1f27}',  '\u{3b9}']), ('\u{1f98}',  &['\u{1f20}',  '\u{3b9}']),
+        ('\u{1f99}',  &['\u{1f21}',  '\u{3b9}']), ('\u{1f9a}',  &['\u{1f22}',  '\u{3b9}']),
+        ('\u{1f9b}',  &['\u{1f23}',  '\u{3b9}']), ('\u{1f9c}',  &['\u{1f24}',  '\u{3b9}']),
+        ('\u{1f9d}',  &['\u{1f25}',  '\u{3b9}']), ('\u{1f9e}',  &['\u{1f26}',  '\u{3b9}']),
+        ('\u{1f9f}',  &['\u{1f27}',  '\u{3b9}']), ('\u{1fa0}',  &['\u{1f60}',  '\u{3b9}']),
+        ('\u{1fa1}',  &['\u{1f61}',  '\u{3b9}']), ('\u{1fa2}',  &['\u{1f62}',  '\u{3b9}']),
+        ('\u{1fa3}',  &['\u{1f63}',  '\u{3b9}']), ('\u{1fa4}',  &['\u{1f64}',  '\u{3b9}']),
+        ('\u{1fa5}',  &['\u{1f65}',  '\u{3b9}']), ('\u{1fa6}',  &['\u{1f66}',  '\u{3b9}']),
+        ('\u{1fa7}',  &['\u{1f67}',  '\u{3b9}']), ('\u{1fa8}',  &['\u{1f60}',  '\u{3b9}']),
+        ('\u{1fa9}',  &['\u{1f61}',  '\u{3b9}']), ('\u{1faa}',  &['\u{1f62}',  '\u{3b9}']),
+        ('\u{1fab}',  &['\u{1f63}',  '\u{3b9}']), ('\u{1fac}',  &['\u{1f64}',  '\u{3b9}']),
+        ('\u{1fad}',  &['\u{1f65}',  '\u{3b9}']), ('\u{1fae}',  &['\u{1f66}',  '\u{3b9}']),
+        ('\u{1faf}',  &['\u{1f67}',  '\u{3b9}']), ('\u{1fb2}',  &['\u{1f70}',  '\u{3b9}']),
+        ('\u{1fb3}',  &['\u{3b1}',  '\u{3b9}']), ('\u{1fb4}',  &['\u{3ac}',  '\u{3b9}']),
+        ('\u{1fb6}',  &['\u{3b1}',  '\u{342}']), ('\u{1fb7}',  &['\u{3b1}',  '\u{342}',
+         '\u{3b9}']), ('\u{1fb8}',  &['\u{1fb0}']), ('\u{1fb9}',  &['\u{1fb1}']), ('\u{1fba}',
+         &['\u{1f70}']), ('\u{1fbb}',  &['\u{1f71}']), ('\u{1fbc}',  &['\u{3b1}',  '\u{3b9}']),
+        ('\u{1fbe}',  &['\u{3b9}']), ('\u{1fc2}',  &['\u{1f74}',  '\u{3b9}']), ('\u{1fc3}',
+         &['\u{3b7}',  '\u{3b9}']), ('\u{1fc4}',  &['\u{3ae}',  '\u{3b9}']), ('\u{1fc6}',
+         &['\u{3b7}',  '\u{342}']), ('\u{1fc7}',  &['\u{3b7}',  '\u{342}',  '\u{3b9}']),
+        ('\u{1fc8}',  &['\u{1f72}']), ('\u{1fc9}',  &['\u{1f73}']), ('\u{1fca}',  &['\u{1f74}']),
+        ('\u{1fcb}',  &['\u{1f75}']), ('\u{1fcc}',  &['\u{3b7}',  '\u{3b9}']), ('\u{1fd2}',
+         &['\u{3b9}',  '\u{308}',  '\u{300}']), ('\u{1fd3}',  &['\u{3b9}',  '\u{308}',  '\u{301}']),
+        ('\u{1fd6}',  &['\u{3b9}',  '\u{342}']), ('\u{1fd7}',  &['\u{3b9}',  '\u{308}',
+         '\u{342}']), ('\u{1fd8}',  &['\u{1fd0}']), ('\u{1fd9}',  &['\u{1fd1}']), ('\u{1fda}',
+         &['\u{1f76}']), ('\u{1fdb}',  &['\u{1f77}']), ('\u{1fe2}',  &['\u{3c5}',  '\u{308}',
+         '\u{300}']), ('\u{1fe3}',  &['\u{3c5}',  '\u{308}',  '\u{301}']), ('\u{1fe4}',
+         &['\u{3c1}',  '\u{313}']), ('\u{1fe6}',  &['\u{3c5}',  '\u{342}']), ('\u{1fe7}',
+         &['\u{3c5}',  '\u{308}',  '\u{342}']), ('\u{1fe8}',  &['\u{1fe0}']), ('\u{1fe9}',
+         &['\u{1fe1}']), ('\u{1fea}',  &['\u{1f7a}']), ('\u{1feb}',  &['\u{1f7b}']), ('\u{1fec}',
+         &['\u{1fe5}']), ('\u{1ff2}',  &['\u{1f7c}',  '\u{3b9}']), ('\u{1ff3}',  &['\u{3c9}',
+         '\u{3b9}']), ('\u{1ff4}',  &['\u{3ce}',  '\u{3b9}']), ('\u{1ff6}',  &['\u{3c9}',
+         '\u{342}']), ('\u{1ff7}',  &['\u{3c9}',  '\u{342}',  '\u{3b9}']), ('\u{1ff8}',
+         &['\u{1f78}']), ('\u{1ff9}',  &['\u{1f79}']), ('\u{1ffa}',  &['\u{1f7c}']), ('\u{1ffb}',
+         &['\u{1f7d}']), ('\u{1ffc}',  &['\u{3c9}',  '\u{3b9}']), ('\u{2126}',  &['\u{3c9}']),
+        ('\u{212a}',  &['\u{6b}']), ('\u{212b}',  &['\u{e5}']), ('\u{2132}',  &['\u{214e}']),
+        ('\u{2160}',  &['\u{2170}']), ('\u{2161}',  &['\u{2171}']), ('\u{2162}',  &['\u{2172}']),
+        ('\u{2163}',  &['\u{2173}']), ('\u{2164}',  &['\u{2174}']), ('\u{2165}',  &['\u{2175}']),
+        ('\u{2166}',  &['\u{2176}']), ('\u{2167}',  &['\u{2177}']), ('\u{2168}',  &['\u{2178}']),
+        ('\u{2169}',  &['\u{2179}']), ('\u{216a}',  &['\u{217a}']), ('\u{216b}',  &['\u{217b}']),
+        ('\u{216c}',  &['\u{217c}']), ('\u{216d}',  &['\u{217d}']), ('\u{216e}',  &['\u{217e}']),
+        ('\u{216f}',  &['\u{217f}']), ('\u{2183}',  &['\u{2184}']), ('\u{24b6}',  &['\u{24d0}']),
+        ('\u{24b7}',  &['\u{24d1}']), ('\u{24b8}',  &['\u{24d2}']), ('\u{24b9}',  &['\u{24d3}']),
+        ('\u{24ba}',  &['\u{24d4}']), ('\u{24bb}',  &['\u{24d5}']), ('\u{24bc}',  &['\u{24d6}']),
+        ('\u{24bd}',  &['\u{24d7}']), ('\u{24be}',  &['\u{24d8}']), ('\u{24bf}',  &['\u{24d9}']),
+        ('\u{24c0}',  &['\u{24da}']), ('\u{24c1}',  &['\u{24db}']), ('\u{24c2}',  &['\u{24dc}']),
+        ('\u{24c3}',  &['\u{24dd}']), ('\u{24c4}',  &['\u{24de}']), ('\u{24c5}',  &['\u{24df}']),
+        ('\u{24c6}',  &['\u{24e0}']), ('\u{24c7}',  &['\u{24e1}']), ('\u{24c8}',  &['\u{24e2}']),
+        ('\u{24c9}',  &['\u{24e3}']), ('\u{24ca}',  &['\u{24e4}']), ('\u{24cb}',  &['\u{24e5}']),
+        ('\u{24cc}',  &['\u{24e6}']), ('\u{24cd}',  &['\u{24e7}']), ('\u{24ce}',  &['\u{24e8}']),
+        ('\u{24cf}',  &['\u{24e9}']), ('\u{2c00}',  &['\u{2c30}']), ('\u{2c01}',  &['\u{2c31}']),
+        ('\u{2c02}',  &['\u{2c32}']), ('\u{2c03}',  &['\u{2c33}']), ('\u{2c04}',  &['\u{2c34}']),
+        ('\u{2c05}',  &['\u{2c35}']), ('\u{2c06}',  &['\u{2c36}']), ('\u{2c07}',  &['\u{2c37}']),
+        ('\u{2c08}',  &['\u{2c38}']), ('\u{2c09}',  &['\u{2c39}']), ('\u{2c0a}',  &['\u{2c3a}']),
+        ('\u{2c0b}',  &['\u{2c3b}']), ('\u{2c0c}',  &['\u{2c3c}']), ('\u{2c0d}',  &['\u{2c3d}']),
+        ('\u{2c0e}',  &['\u{2c3e}']), ('\u{2c0f}',  &['\u{2c3f}']), ('\u{2c10}',  &['\u{2c40}']),
+        ('\u{2c11}',  &['\u{2c41}']), ('\u{2c12}',  &['\u{2c42}']), ('\u{2c13}',  &['\u{2c43}']),
+        ('\u{2c14}',  &['\u{2c44}']), ('\u{2c15}',  &['\u{2c45}']), ('\u{2c16}',  &['\u{2c46}']),
+        ('\u{2c17}',  &['\u{2c47}']), ('\u{2c18}',  &['\u{2c48}']), ('\u{2c19}',  &['\u{2c49}']),
+        ('\u{2c1a}',  &['\u{2c4a}']), ('\u{2c1b}',  &['\u{2c4b}']), ('\u{2c1c}',  &['\u{2c4c}']),
+        ('\u{2c1d}',  &['\u{2c4d}']), ('\u{2c1e}',  &['\u{2c4e}']), ('\u{2c1f}',  &['\u{2c4f}']),
+        ('\u{2c20}',  &['\u{2c50}']), ('\u{2c21}',  &['\u{2c51}']), ('\u{2c22}',  &['\u{2c52}']),
+        ('\u{2c23}',  &['\u{2c53}']), ('\u{2c24}',  &['\u{2c54}']), ('\u{2c25}',  &['\u{2c55}']),
+        ('\u{2c26}',  &['\u{2c56}']), ('\u{2c27}',  &['\u{2c57}']), ('\u{2c28}',  &['\u{2c58}']),
+        ('\u{2c29}',  &['\u{2c59}']), ('\u{2c2a}',  &['\u{2c5a}']), ('\u{2c2b}',  &['\u{2c5b}']),
+        ('\u{2c2c}',  &['\u{2c5c}']), ('\u{2c2d}',  &['\u{2c5d}']), ('\u{2c2e}',  &['\u{2c5e}']),
+        ('\u{2c2f}',  &['\u{2c5f}']), ('\u{2c60}',  &['\u{2c61}']), ('\u{2c62}',  &['\u{26b}']),
+        ('\u{2c63}',  &['\u{1d7d}']), ('\u{2c64}',  &['\u{27d}']), ('\u{2c67}',  &['\u{2c68}']),
+        ('\u{2c69}',  &['\u{2c6a}']), ('\u{2c6b}',  &['\u{2c6c}']), ('\u{2c6d}',  &['\u{251}']),
+        ('\u{2c6e}',  &['\u{271}']), ('\u{2c6f}',  &['\u{250}']), ('\u{2c70}',  &['\u{252}']),
+        ('\u{2c72}',  &['\u{2c73}']), ('\u{2c75}',  &['\u{2c76}']), ('\u{2c7e}',  &['\u{23f}']),
+        ('\u{2c7f}',  &['\u{240}']), ('\u{2c80}',  &['\u{2c81}']), ('\u{2c82}',  &['\u{2c83}']),
+        ('\u{2c84}',  &['\u{2c85}']), ('\u{2c86}',  &['\u{2c87}']), ('\u{2c88}',  &['\u{2c89}']),
+        ('\u{2c8a}',  &['\u{2c8b}']), ('\u{2c8c}',  &['\u{2c8d}']), ('\u{2c8e}',  &['\u{2c8f}']),
+        ('\u{2c90}',  &['\u{2c91}']), ('\u{2c92}',  &['\u{2c93}']), ('\u{2c94}',  &['\u{2c95}']),
+        ('\u{2c96}',  &['\u{2c97}']), ('\u{2c98}',  &['\u{2c99}']), ('\u{2c9a}',  &['\u{2c9b}']),
+        ('\u{2c9c}',  &['\u{2c9d}']), ('\u{2c9e}',  &['\u{2c9f}']), ('\u{2ca0}',  &['\u{2ca1}']),
+        ('\u{2ca2}',  &['\u{2ca3}']), ('\u{2ca4}',  &['\u{2ca5}']), ('\u{2ca6}',  &['\u{2ca7}']),
+        ('\u{2ca8}',  &['\u{2ca9}']), ('\u{2caa}',  &['\u{2cab}']), ('\u{2cac}',  &['\u{2cad}']),
+        ('\u{2cae}',  &['\u{2caf}']), ('\u{2cb0}',  &['\u{2cb1}']), ('\u{2cb2}',  &['\u{2cb3}']),
+        ('\u{2cb4}',  &['\u{2cb5}']), ('\u{2cb6}',  &['\u{2cb7}']), ('\u{2cb8}',  &['\u{2cb9}']),
+        ('\u{2cba}',  &['\u{2cbb}']), ('\u{2cbc}',  &['\u{2cbd}']), ('\u{2cbe}',  &['\u{2cbf}']),
+        ('\u{2cc0}',  &['\u{2cc1}']), ('\u{2cc2}',  &['\u{2cc3}']), ('\u{2cc4}',  &['\u{2cc5}']),
+        ('\u{2cc6}',  &['\u{2cc7}']), ('\u{2cc8}',  &['\u{2cc9}']), ('\u{2cca}',  &['\u{2ccb}']),
+        ('\u{2ccc}',  &['\u{2ccd}']), ('\u{2cce}',  &['\u{2ccf}']), ('\u{2cd0}',  &['\u{2cd1}']),
+        ('\u{2cd2}',  &['\u{2cd3}']), ('\u{2cd4}',  &['\u{2cd5}']), ('\u{2cd6}',  &['\u{2cd7}']),
+        ('\u{2cd8}',  &['\u{2cd9}']), ('\u{2cda}',  &['\u{2cdb}']), ('\u{2cdc}',  &['\u{2cdd}']),
+        ('\u{2cde}',  &['\u{2cdf}']), ('\u{2ce0}',  &['\u{2ce1}']), ('\u{2ce2}',  &['\u{2ce3}']),
+        ('\u{2ceb}',  &['\u{2cec}']), ('\u{2ced}',  &['\u{2cee}']), ('\u{2cf2}',  &['\u{2cf3}']),
+        ('\u{a640}',  &['\u{a641}']), ('\u{a642}',  &['\u{a643}']), ('\u{a644}',  &['\u{a645}']),
+        ('\u{a646}',  &['\u{a647}']), ('\u{a648}',  &['\u{a649}']), ('\u{a64a}',  &['\u{a64b}']),
+        ('\u{a64c}',  &['\u{a64d}']), ('\u{a64e}',  &['\u{a64f}']), ('\u{a650}',  &['\u{a651}']),
+        ('\u{a652}',  &['\u{a653}']), ('\u{a654}',  &['\u{a655}']), ('\u{a656}',  &['\u{a657}']),
+        ('\u{a658}',  &['\u{a659}']), ('\u{a65a}',  &['\u{a65b}']), ('\u{a65c}',  &['\u{a65d}']),
+        ('\u{a65e}',  &['\u{a65f}']), ('\u{a660}',  &['\u{a661}']), ('\u{a662}',  &['\u{a663}']),
+        ('\u{a664}',  &['\u{a665}']), ('\u{a666}',  &['\u{a667}']), ('\u{a668}',  &['\u{a669}']),
+        ('\u{a66a}',  &['\u{a66b}']), ('\u{a66c}',  &['\u{a66d}']), ('\u{a680}',  &['\u{a681}']),
+        ('\u{a682}',  &['\u{a683}']), ('\u{a684}',  &['\u{a685}']), ('\u{a686}',  &['\u{a687}']),
+        ('\u{a688}',  &['\u{a689}']), ('\u{a68a}',  &['\u{a68b}']), ('\u{a68c}',  &['\u{a68d}']),
+        ('\u{a68e}',  &['\u{a68f}']), ('\u{a690}',  &['\u{a691}']), ('\u{a692}',  &['\u{a693}']),
+        ('\u{a694}',  &['\u{a695}']), ('\u{a696}',  &['\u{a697}']), ('\u{a698}',  &['\u{a699}']),
+        ('\u{a69a}',  &['\u{a69b}']), ('\u{a722}',  &['\u{a723}']), ('\u{a724}',  &['\u{a725}']),
+        ('\u{a726}',  &['\u{a727}']), ('\u{a728}',  &['\u{a729}']), ('\u{a72a}',  &['\u{a72b}']),
+        ('\u{a72c}',  &['\u{a72d}']), ('\u{a72e}',  &['\u{a72f}']), ('\u{a732}',  &['\u{a733}']),
+        ('\u{a734}',  &['\u{a735}']), ('\u{a736}',  &['\u{a737}']), ('\u{a738}',  &['\u{a739}']),
+        ('\u{a73a}',  &['\u{a73b}']), ('\u{a73c}',  &['\u{a73d}']), ('\u{a73e}',  &['\u{a73f}']),
+        ('\u{a740}',  &['\u{a741}']), ('\u{a742}',  &['\u{a743}']), ('\u{a744}',  &['\u{a745}']),
+        ('\u{a746}',  &['\u{a747}']), ('\u{a748}',  &['\u{a749}']), ('\u{a74a}',  &['\u{a74b}']),
+        ('\u{a74c}',  &['\u{a74d}']), ('\u{a74e}',  &['\u{a74f}']), ('\u{a750}',  &['\u{a751}']),
+        ('\u{a752}',  &['\u{a753}']), ('\u{a754}',  &['\u{a755}']), ('\u{a756}',  &['\u{a757}']),
+        ('\u{a758}',  &['\u{a759}']), ('\u{a75a}',  &['\u{a75b}']), ('\u{a75c}',  &['\u{a75d}']),
+        ('\u{a75e}',  &['\u{a75f}']), ('\u{a760}',  &['\u{a761}']), ('\u{a762}',  &['\u{a763}']),
+        ('\u{a764}',  &['\u{a765}']), ('\u{a766}',  &['\u{a767}']), ('\u{a768}',  &['\u{a769}']),
+        ('\u{a76a}',  &['\u{a76b}']), ('\u{a76c}',  &['\u{a76d}']), ('\u{a76e}',  &['\u{a76f}']),
+        ('\u{a779}',  &['\u{a77a}']), ('\u{a77b}',  &['\u{a77c}']), ('\u{a77d}',  &['\u{1d79}']),
+        ('\u{a77e}',  &['\u{a77f}']), ('\u{a780}',  &['\u{a781}']), ('\u{a782}',  &['\u{a783}']),
+        ('\u{a784}',  &['\u{a785}']), ('\u{a786}',  &['\u{a787}']), ('\u{a78b}',  &['\u{a78c}']),
+        ('\u{a78d}',  &['\u{265}']), ('\u{a790}',  &['\u{a791}']), ('\u{a792}',  &['\u{a793}']),
+        ('\u{a796}',  &['\u{a797}']), ('\u{a798}',  &['\u{a799}']), ('\u{a79a}',  &['\u{a79b}']),
+        ('\u{a79c}',  &['\u{a79d}']), ('\u{a79e}',  &['\u{a79f}']), ('\u{a7a0}',  &['\u{a7a1}']),
+        ('\u{a7a2}',  &['\u{a7a3}']), ('\u{a7a4}',  &['\u{a7a5}']), ('\u{a7a6}',  &['\u{a7a7}']),
+        ('\u{a7a8}',  &['\u{a7a9}']), ('\u{a7aa}',  &['\u{266}']), ('\u{a7ab}',  &['\u{25c}']),
+        ('\u{a7ac}',  &['\u{261}']), ('\u{a7ad}',  &['\u{26c}']), ('\u{a7ae}',  &['\u{26a}']),
+        ('\u{a7b0}',  &['\u{29e}']), ('\u{a7b1}',  &['\u{287}']), ('\u{a7b2}',  &['\u{29d}']),
+        ('\u{a7b3}',  &['\u{ab53}']), ('\u{a7b4}',  &['\u{a7b5}']), ('\u{a7b6}',  &['\u{a7b7}']),
+        ('\u{a7b8}',  &['\u{a7b9}']), ('\u{a7ba}',  &['\u{a7bb}']), ('\u{a7bc}',  &['\u{a7bd}']),
+        ('\u{a7be}',  &['\u{a7bf}']), ('\u{a7c0}',  &['\u{a7c1}']), ('\u{a7c2}',  &['\u{a7c3}']),
+        ('\u{a7c4}',  &['\u{a794}']), ('\u{a7c5}',  &['\u{282}']), ('\u{a7c6}',  &['\u{1d8e}']),
+        ('\u{a7c7}',  &['\u{a7c8}']), ('\u{a7c9}',  &['\u{a7ca}']), ('\u{a7cb}',  &['\u{264}']),
+        ('\u{a7cc}',  &['\u{a7cd}']), ('\u{a7ce}',  &['\u{a7cf}']), ('\u{a7d0}',  &['\u{a7d1}']),
+        ('\u{a7d2}',  &['\u{a7d3}']), ('\u{a7d4}',  &['\u{a7d5}']), ('\u{a7d6}',  &['\u{a7d7}']),
+        ('\u{a7d8}',  &['\u{a7d9}']), ('\u{a7da}',  &['\u{a7db}']), ('\u{a7dc}',  &['\u{19b}']),
+        ('\u{a7f5}',  &['\u{a7f6}']), ('\u{ab70}',  &['\u{13a0}']), ('\u{ab71}',  &['\u{13a1}']),
+        ('\u{ab72}',  &['\u{13a2}']), ('\u{ab73}',  &['\u{13a3}']), ('\u{ab74}',  &['\u{13a4}']),
+        ('\u{ab75}',  &['\u{13a5}']), ('\u{ab76}',  &['\u{13a6}']), ('\u{ab77}',  &['\u{13a7}']),
+        ('\u{ab78}',  &['\u{13a8}']), ('\u{ab79}',  &['\u{13a9}']), ('\u{ab7a}',  &['\u{13aa}']),
+        ('\u{ab7b}',  &['\u{13ab}']), ('\u{ab7c}',  &['\u{13ac}']), ('\u{ab7d}',  &['\u{13ad}']),
+        ('\u{ab7e}',  &['\u{13ae}']), ('\u{ab7f}',  &['\u{13af}']), ('\u{ab80}',  &['\u{13b0}']),
+        ('\u{ab81}',  &['\u{13b1}']), ('\u{ab82}',  &['\u{13b2}']), ('\u{ab83}',  &['\u{13b3}']),
+        ('\u{ab84}',  &['\u{13b4}']), ('\u{ab85}',  &['\u{13b5}']), ('\u{ab86}',  &['\u{13b6}']),
+        ('\u{ab87}',  &['\u{13b7}']), ('\u{ab88}',  &['\u{13b8}']), ('\u{ab89}',  &['\u{13b9}']),
+        ('\u{ab8a}',  &['\u{13ba}']), ('\u{ab8b}',  &['\u{13bb}']), ('\u{ab8c}',  &['\u{13bc}']),
+        ('\u{ab8d}',  &['\u{13bd}']), ('\u{ab8e}',  &['\u{13be}']), ('\u{ab8f}',  &['\u{13bf}']),
+        ('\u{ab90}',  &['\u{13c0}']), ('\u{ab91}',  &['\u{13c1}']), ('\u{ab92}',  &['\u{13c2}']),
+        ('\u{ab93}',  &['\u{13c3}']), ('\u{ab94}',  &['\u{13c4}']), ('\u{ab95}',  &['\u{13c5}']),
+        ('\u{ab96}',  &['\u{13c6}']), ('\u{ab97}',  &['\u{13c7}']), ('\u{ab98}',  &['\u{13c8}']),
+        ('\u{ab99}',  &['\u{13c9}']), ('\u{ab9a}',  &['\u{13ca}']), ('\u{ab9b}',  &['\u{13cb}']),
+        ('\u{ab9c}',  &['\u{13cc}']), ('\u{ab9d}',  &['\u{13cd}']), ('\u{ab9e}',  &['\u{13ce}']),
+        ('\u{ab9f}',  &['\u{13cf}']), ('\u{aba0}',  &['\u{13d0}']), ('\u{aba1}',  &['\u{13d1}']),
+        ('\u{aba2}',  &['\u{13d2}']), ('\u{aba3}',  &['\u{13d3}']), ('\u{aba4}',  &['\u{13d4}']),
+        ('\u{aba5}',  &['\u{13d5}']), ('\u{aba6}',  &['\u{13d6}']), ('\u{aba7}',  &['\u{13d7}']),
+        ('\u{aba8}',  &['\u{13d8}']), ('\u{aba9}',  &['\u{13d9}']), ('\u{abaa}',  &['\u{13da}']),
+        ('\u{abab}',  &['\u{13db}']), ('\u{abac}',  &['\u{13dc}']), ('\u{abad}',  &['\u{13dd}']),
+        ('\u{abae}',  &['\u{13de}']), ('\u{abaf}',  &['\u{13df}']), ('\u{abb0}',  &['\u{13e0}']),
+        ('\u{abb1}',  &['\u{13e1}']), ('\u{abb2}',  &['\u{13e2}']), ('\u{abb3}',  &['\u{13e3}']),
+        ('\u{abb4}',  &['\u{13e4}']), ('\u{abb5}',  &['\u{13e5}']), ('\u{abb6}',  &['\u{13e6}']),
+        ('\u{abb7}',  &['\u{13e7}']), ('\u{abb8}',  &['\u{13e8}']), ('\u{abb9}',  &['\u{13e9}']),
+        ('\u{abba}',  &['\u{13ea}']), ('\u{abbb}',  &['\u{13eb}']), ('\u{abbc}',  &['\u{13ec}']),
+        ('\u{abbd}',  &['\u{13ed}']), ('\u{abbe}',  &['\u{13ee}']), ('\u{abbf}',  &['\u{13ef}']),
+        ('\u{fb00}',  &['\u{66}',  '\u{66}']), ('\u{fb01}',  &['\u{66}',  '\u{69}']), ('\u{fb02}',
+         &['\u{66}',  '\u{6c}']), ('\u{fb03}',  &['\u{66}',  '\u{66}',  '\u{69}']), ('\u{fb04}',
+         &['\u{66}',  '\u{66}',  '\u{6c}']), ('\u{fb05}',  &['\u{73}',  '\u{74}']), ('\u{fb06}',
+         &['\u{73}',  '\u{74}']), ('\u{fb13}',  &['\u{574}',  '\u{576}']), ('\u{fb14}',
+         &['\u{574}',  '\u{565}']), ('\u{fb15}',  &['\u{574}',  '\u{56b}']), ('\u{fb16}',
+         &['\u{57e}',  '\u{576}']), ('\u{fb17}',  &['\u{574}',  '\u{56d}']), ('\u{ff21}',
+         &['\u{ff41}']), ('\u{ff22}',  &['\u{ff42}']), ('\u{ff23}',  &['\u{ff43}']), ('\u{ff24}',
+         &['\u{ff44}']), ('\u{ff25}',  &['\u{ff45}']), ('\u{ff26}',  &['\u{ff46}']), ('\u{ff27}',
+         &['\u{ff47}']), ('\u{ff28}',  &['\u{ff48}']), ('\u{ff29}',  &['\u{ff49}']), ('\u{ff2a}',
+         &['\u{ff4a}']), ('\u{ff2b}',  &['\u{ff4b}']), ('\u{ff2c}',  &['\u{ff4c}']), ('\u{ff2d}',
+         &['\u{ff4d}']), ('\u{ff2e}',  &['\u{ff4e}']), ('\u{ff2f}',  &['\u{ff4f}']), ('\u{ff30}',
+         &['\u{ff50}']), ('\u{ff31}',  &['\u{ff51}']), ('\u{ff32}',  &['\u{ff52}']), ('\u{ff33}',
+         &['\u{ff53}']), ('\u{ff34}',  &['\u{ff54}']), ('\u{ff35}',  &['\u{ff55}']), ('\u{ff36}',
+         &['\u{ff56}']), ('\u{ff37}',  &['\u{ff57}']), ('\u{ff38}',  &['\u{ff58}']), ('\u{ff39}',
+         &['\u{ff59}']), ('\u{ff3a}',  &['\u{ff5a}']), ('\u{10400}',  &['\u{10428}']), ('\u{10401}',
+         &['\u{10429}']), ('\u{10402}',  &['\u{1042a}']), ('\u{10403}',  &['\u{1042b}']),
+        ('\u{10404}',  &['\u{1042c}']), ('\u{10405}',  &['\u{1042d}']), ('\u{10406}',
+         &['\u{1042e}']), ('\u{10407}',  &['\u{1042f}']), ('\u{10408}',  &['\u{10430}']),
+        ('\u{10409}',  &['\u{10431}']), ('\u{1040a}',  &['\u{10432}']), ('\u{1040b}',
+         &['\u{10433}']), ('\u{1040c}',  &['\u{10434}']), ('\u{1040d}',  &['\u{10435}']),
+        ('\u{1040e}',  &['\u{10436}']), ('\u{1040f}',  &['\u{10437}']), ('\u{10410}',
+         &['\u{10438}']), ('\u{10411}',  &['\u{10439}']), ('\u{10412}',  &['\u{1043a}']),
+        ('\u{10413}',  &['\u{1043b}']), ('\u{10414}',  &['\u{1043c}']), ('\u{10415}',
+         &['\u{1043d}']), ('\u{10416}',  &['\u{1043e}']), ('\u{10417}',  &['\u{1043f}']),
+        ('\u{10418}',  &['\u{10440}']), ('\u{10419}',  &['\u{10441}']), ('\u{1041a}',
+         &['\u{10442}']), ('\u{1041b}',  &['\u{10443}']), ('\u{1041c}',  &['\u{10444}']),
+        ('\u{1041d}',  &['\u{10445}']), ('\u{1041e}',  &['\u{10446}']), ('\u{1041f}',
+         &['\u{10447}']), ('\u{10420}',  &['\u{10448}']), ('\u{10421}',  &['\u{10449}']),
+        ('\u{10422}',  &['\u{1044a}']), ('\u{10423}',  &['\u{1044b}']), ('\u{10424}',
+         &['\u{1044c}']), ('\u{10425}',  &['\u{1044d}']), ('\u{10426}',  &['\u{1044e}']),
+        ('\u{10427}',  &['\u{1044f}']), ('\u{104b0}',  &['\u{104d8}']), ('\u{104b1}',
+         &['\u{104d9}']), ('\u{104b2}',  &['\u{104da}']), ('\u{104b3}',  &['\u{104db}']),
+        ('\u{104b4}',  &['\u{104dc}']), ('\u{104b5}',  &['\u{104dd}']), ('\u{104b6}',
+         &['\u{104de}']), ('\u{104b7}',  &['\u{104df}']), ('\u{104b8}',  &['\u{104e0}']),
+        ('\u{104b9}',  &['\u{104e1}']), ('\u{104ba}',  &['\u{104e2}']), ('\u{104bb}',
+         &['\u{104e3}']), ('\u{104bc}',  &['\u{104e4}']), ('\u{104bd}',  &['\u{104e5}']),
+        ('\u{104be}',  &['\u{104e6}']), ('\u{104bf}',  &['\u{104e7}']), ('\u{104c0}',
+         &['\u{104e8}']), ('\u{104c1}',  &['\u{104e9}']), ('\u{104c2}',  &['\u{104ea}']),
+        ('\u{104c3}',  &['\u{104eb}']), ('\u{104c4}',  &['\u{104ec}']), ('\u{104c5}',
+         &['\u{104ed}']), ('\u{104c6}',  &['\u{104ee}']), ('\u{104c7}',  &['\u{104ef}']),
+        ('\u{104c8}',  &['\u{104f0}']), ('\u{104c9}',  &['\u{104f1}']), ('\u{104ca}',
+         &['\u{104f2}']), ('\u{104cb}',  &['\u{104f3}']), ('\u{104cc}',  &['\u{104f4}']),
+        ('\u{104cd}',  &['\u{104f5}']), ('\u{104ce}',  &['\u{104f6}']), ('\u{104cf}',
+         &['\u{104f7}']), ('\u{104d0}',  &['\u{104f8}']), ('\u{104d1}',  &['\u{104f9}']),
+        ('\u{104d2}',  &['\u{104fa}']), ('\u{104d3}',  &['\u{104fb}']), ('\u{10570}',
+         &['\u{10597}']), ('\u{10571}',  &['\u{10598}']), ('\u{10572}',  &['\u{10599}']),
+        ('\u{10573}',  &['\u{1059a}']), ('\u{10574}',  &['\u{1059b}']), ('\u{10575}',
+         &['\u{1059c}']), ('\u{10576}',  &['\u{1059d}']), ('\u{10577}',  &['\u{1059e}']),
+        ('\u{10578}',  &['\u{1059f}']), ('\u{10579}',  &['\u{105a0}']), ('\u{1057a}',
+         &['\u{105a1}']), ('\u{1057c}',  &['\u{105a3}']), ('\u{1057d}',  &['\u{105a4}']),
+        ('\u{1057e}',  &['\u{105a5}']), ('\u{1057f}',  &['\u{105a6}']), ('\u{10580}',
+         &['\u{105a7}']), ('\u{10581}',  &['\u{105a8}']), ('\u{10582}',  &['\u{105a9}']),
+        ('\u{10583}',  &['\u{105aa}']), ('\u{10584}',  &['\u{105ab}']), ('\u{10585}',
+         &['\u{105ac}']), ('\u{10586}',  &['\u{105ad}']), ('\u{10587}',  &['\u{105ae}']),
+        ('\u{10588}',  &['\u{105af}']), ('\u{10589}',  &['\u{105b0}']), ('\u{1058a}',
+         &['\u{105b1}']), ('\u{1058c}',  &['\u{105b3}']), ('\u{1058d}',  &['\u{105b4}']),
+        ('\u{1058e}',  &['\u{105b5}']), ('\u{1058f}',  &['\u{105b6}']), ('\u{10590}',
+         &['\u{105b7}']), ('\u{10591}',  &['\u{105b8}']), ('\u{10592}',  &['\u{105b9}']),
+        ('\u{10594}',  &['\u{105bb}']), ('\u{10595}',  &['\u{105bc}']), ('\u{10c80}',
+         &['\u{10cc0}']), ('\u{10c81}',  &['\u{10cc1}']), ('\u{10c82}',  &['\u{10cc2}']),
+        ('\u{10c83}',  &['\u{10cc3}']), ('\u{10c84}',  &['\u{10cc4}']), ('\u{10c85}',
+         &['\u{10cc5}']), ('\u{10c86}',  &['\u{10cc6}']), ('\u{10c87}',  &['\u{10cc7}']),
+        ('\u{10c88}',  &['\u{10cc8}']), ('\u{10c89}',  &['\u{10cc9}']), ('\u{10c8a}',
+         &['\u{10cca}']), ('\u{10c8b}',  &['\u{10ccb}']), ('\u{10c8c}',  &['\u{10ccc}']),
+        ('\u{10c8d}',  &['\u{10ccd}']), ('\u{10c8e}',  &['\u{10cce}']), ('\u{10c8f}',
+         &['\u{10ccf}']), ('\u{10c90}',  &['\u{10cd0}']), ('\u{10c91}',  &['\u{10cd1}']),
+        ('\u{10c92}',  &['\u{10cd2}']), ('\u{10c93}',  &['\u{10cd3}']), ('\u{10c94}',
+         &['\u{10cd4}']), ('\u{10c95}',  &['\u{10cd5}']), ('\u{10c96}',  &['\u{10cd6}']),
+        ('\u{10c97}',  &['\u{10cd7}']), ('\u{10c98}',  &['\u{10cd8}']), ('\u{10c99}',
+         &['\u{10cd9}']), ('\u{10c9a}',  &['\u{10cda}']), ('\u{10c9b}',  &['\u{10cdb}']),
+        ('\u{10c9c}',  &['\u{10cdc}']), ('\u{10c9d}',  &['\u{10cdd}']), ('\u{10c9e}',
+         &['\u{10cde}']), ('\u{10c9f}',  &['\u{10cdf}']), ('\u{10ca0}',  &['\u{10ce0}']),
+        ('\u{10ca1}',  &['\u{10ce1}']), ('\u{10ca2}',  &['\u{10ce2}']), ('\u{10ca3}',
+         &['\u{10ce3}']), ('\u{10ca4}',  &['\u{10ce4}']), ('\u{10ca5}',  &['\u{10ce5}']),
+        ('\u{10ca6}',  &['\u{10ce6}']), ('\u{10ca7}',  &['\u{10ce7}']), ('\u{10ca8}',
+         &['\u{10ce8}']), ('\u{10ca9}',  &['\u{10ce9}']), ('\u{10caa}',  &['\u{10cea}']),
+        ('\u{10cab}',  &['\u{10ceb}']), ('\u{10cac}',  &['\u{10cec}']), ('\u{10cad}',
+         &['\u{10ced}']), ('\u{10cae}',  &['\u{10cee}']), ('\u{10caf}',  &['\u{10cef}']),
+        ('\u{10cb0}',  &['\u{10cf0}']), ('\u{10cb1}',  &['\u{10cf1}']), ('\u{10cb2}',
+         &['\u{10cf2}']), ('\u{10d50}',  &['\u{10d70}']), ('\u{10d51}',  &['\u{10d71}']),
+        ('\u{10d52}',  &['\u{10d72}']), ('\u{10d53}',  &['\u{10d73}']), ('\u{10d54}',
+         &['\u{10d74}']), ('\u{10d55}',  &['\u{10d75}']), ('\u{10d56}',  &['\u{10d76}']),
+        ('\u{10d57}',  &['\u{10d77}']), ('\u{10d58}',  &['\u{10d78}']), ('\u{10d59}',
+         &['\u{10d79}']), ('\u{10d5a}',  &['\u{10d7a}']), ('\u{10d5b}',  &['\u{10d7b}']),
+        ('\u{10d5c}',  &['\u{10d7c}']), ('\u{10d5d}',  &['\u{10d7d}']), ('\u{10d5e}',
+         &['\u{10d7e}']), ('\u{10d5f}',  &['\u{10d7f}']), ('\u{10d60}',  &['\u{10d80}']),
+        ('\u{10d61}',  &['\u{10d81}']), ('\u{10d62}',  &['\u{10d82}']), ('\u{10d63}',
+         &['\u{10d83}']), ('\u{10d64}',  &['\u{10d84}']), ('\u{10d65}',  &['\u{10d85}']),
+        ('\u{118a0}',  &['\u{118c0}']), ('\u{118a1}',  &['\u{118c1}']), ('\u{118a2}',
+         &['\u{118c2}']), ('\u{118a3}',  &['\u{118c3}']), ('\u{118a4}',  &['\u{118c4}']),
+        ('\u{118a5}',  &['\u{118c5}']), ('\u{118a6}',  &['\u{118c6}']), ('\u{118a7}',
+         &['\u{118c7}']), ('\u{118a8}',  &['\u{118c8}']), ('\u{118a9}',  &['\u{118c9}']),
+        ('\u{118aa}',  &['\u{118ca}']), ('\u{118ab}',  &['\u{118cb}']), ('\u{118ac}',
+         &['\u{118cc}']), ('\u{118ad}',  &['\u{118cd}']), ('\u{118ae}',  &['\u{118ce}']),
+        ('\u{118af}',  &['\u{118cf}']), ('\u{118b0}',  &['\u{118d0}']), ('\u{118b1}',
+         &['\u{118d1}']), ('\u{118b2}',  &['\u{118d2}']), ('\u{118b3}',  &['\u{118d3}']),
+        ('\u{118b4}',  &['\u{118d4}']), ('\u{118b5}',  &['\u{118d5}']), ('\u{118b6}',
+         &['\u{118d6}']), ('\u{118b7}',  &['\u{118d7}']), ('\u{118b8}',  &['\u{118d8}']),
+        ('\u{118b9}',  &['\u{118d9}']), ('\u{118ba}',  &['\u{118da}']), ('\u{118bb}',
+         &['\u{118db}']), ('\u{118bc}',  &['\u{118dc}']), ('\u{118bd}',  &['\u{118dd}']),
+        ('\u{118be}',  &['\u{118de}']), ('\u{118bf}',  &['\u{118df}']), ('\u{16e40}',
+         &['\u{16e60}']), ('\u{16e41}',  &['\u{16e61}']), ('\u{16e42}',  &['\u{16e62}']),
+        ('\u{16e43}',  &['\u{16e63}']), ('\u{16e44}',  &['\u{16e64}']), ('\u{16e45}',
+         &['\u{16e65}']), ('\u{16e46}',  &['\u{16e66}']), ('\u{16e47}',  &['\u{16e67}']),
+        ('\u{16e48}',  &['\u{16e68}']), ('\u{16e49}',  &['\u{16e69}']), ('\u{16e4a}',
+         &['\u{16e6a}']), ('\u{16e4b}',  &['\u{16e6b}']), ('\u{16e4c}',  &['\u{16e6c}']),
+        ('\u{16e4d}',  &['\u{16e6d}']), ('\u{16e4e}',  &['\u{16e6e}']), ('\u{16e4f}',
+         &['\u{16e6f}']), ('\u{16e50}',  &['\u{16e70}']), ('\u{16e51}',  &['\u{16e71}']),
+        ('\u{16e52}',  &['\u{16e72}']), ('\u{16e53}',  &['\u{16e73}']), ('\u{16e54}',
+         &['\u{16e74}']), ('\u{16e55}',  &['\u{16e75}']), ('\u{16e56}',  &['\u{16e76}']),
+        ('\u{16e57}',  &['\u{16e77}']), ('\u{16e58}',  &['\u{16e78}']), ('\u{16e59}',
+         &['\u{16e79}']), ('\u{16e5a}',  &['\u{16e7a}']), ('\u{16e5b}',  &['\u{16e7b}']),
+        ('\u{16e5c}',  &['\u{16e7c}']), ('\u{16e5d}',  &['\u{16e7d}']), ('\u{16e5e}',
+         &['\u{16e7e}']), ('\u{16e5f}',  &['\u{16e7f}']), ('\u{16ea0}',  &['\u{16ebb}']),
+        ('\u{16ea1}',  &['\u{16ebc}']), ('\u{16ea2}',  &['\u{16ebd}']), ('\u{16ea3}',
+         &['\u{16ebe}']), ('\u{16ea4}',  &['\u{16ebf}']), ('\u{16ea5}',  &['\u{16ec0}']),
+        ('\u{16ea6}',  &['\u{16ec1}']), ('\u{16ea7}',  &['\u{16ec2}']), ('\u{16ea8}',
+         &['\u{16ec3}']), ('\u{16ea9}',  &['\u{16ec4}']), ('\u{16eaa}',  &['\u{16ec5}']),
+        ('\u{16eab}',  &['\u{16ec6}']), ('\u{16eac}',  &['\u{16ec7}']), ('\u{16ead}',
+         &['\u{16ec8}']), ('\u{16eae}',  &['\u{16ec9}']), ('\u{16eaf}',  &['\u{16eca}']),
+        ('\u{16eb0}',  &['\u{16ecb}']), ('\u{16eb1}',  &['\u{16ecc}']), ('\u{16eb2}',
+         &['\u{16ecd}']), ('\u{16eb3}',  &['\u{16ece}']), ('\u{16eb4}',  &['\u{16ecf}']),
+        ('\u{16eb5}',  &['\u{16ed0}']), ('\u{16eb6}',  &['\u{16ed1}']), ('\u{16eb7}',
+         &['\u{16ed2}']), ('\u{16eb8}',  &['\u{16ed3}']), ('\u{1e900}',  &['\u{1e922}']),
+        ('\u{1e901}',  &['\u{1e923}']), ('\u{1e902}',  &['\u{1e924}']), ('\u{1e903}',
+         &['\u{1e925}']), ('\u{1e904}',  &['\u{1e926}']), ('\u{1e905}',  &['\u{1e927}']),
+        ('\u{1e906}',  &['\u{1e928}']), ('\u{1e907}',  &['\u{1e929}']), ('\u{1e908}',
+         &['\u{1e92a}']), ('\u{1e909}',  &['\u{1e92b}']), ('\u{1e90a}',  &['\u{1e92c}']),
+        ('\u{1e90b}',  &['\u{1e92d}']), ('\u{1e90c}',  &['\u{1e92e}']), ('\u{1e90d}',
+         &['\u{1e92f}']), ('\u{1e90e}',  &['\u{1e930}']), ('\u{1e90f}',  &['\u{1e931}']),
+        ('\u{1e910}',  &['\u{1e932}']), ('\u{1e911}',  &['\u{1e933}']), ('\u{1e912}',
+         &['\u{1e934}']), ('\u{1e913}',  &['\u{1e935}']), ('\u{1e914}',  &['\u{1e936}']),
+        ('\u{1e915}',  &['\u{1e937}']), ('\u{1e916}',  &['\u{1e938}']), ('\u{1e917}',
+         &['\u{1e939}']), ('\u{1e918}',  &['\u{1e93a}']), ('\u{1e919}',  &['\u{1e93b}']),
+        ('\u{1e91a}',  &['\u{1e93c}']), ('\u{1e91b}',  &['\u{1e93d}']), ('\u{1e91c}',
+         &['\u{1e93e}']), ('\u{1e91d}',  &['\u{1e93f}']), ('\u{1e91e}',  &['\u{1e940}']),
+        ('\u{1e91f}',  &['\u{1e941}']), ('\u{1e920}',  &['\u{1e942}']), ('\u{1e921}',
+         &['\u{1e943}'])
     ];
 
 }
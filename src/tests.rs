@@ -8,6 +8,44 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+#![allow(clippy::bool_assert_comparison)]
+
+#[cfg(feature = "confusables")]
+use crate::provider::{BakedData, DataProvider};
+
+/// A [`DataProvider`] that differs from the compiled-in data in a few known ways
+///
+/// Only ASCII letters and digits are allowed in identifiers, "w" has the
+/// prototype "vv" and "ℓ" has none, and the only intentional confusables are
+/// Latin "A" with Greek "Α" and Cyrillic "А".
+#[cfg(feature = "confusables")]
+struct Fixture;
+
+#[cfg(feature = "confusables")]
+impl DataProvider for Fixture {
+    #[cfg(feature = "identifier")]
+    fn identifier_allowed(&self, c: char) -> bool {
+        c.is_ascii_alphanumeric()
+    }
+
+    fn confusable_prototype(&self, c: char) -> Option<&[char]> {
+        match c {
+            'w' => Some(&['v', 'v']),
+            'ℓ' => None,
+            _ => BakedData.confusable_prototype(c),
+        }
+    }
+
+    fn intentional_confusables(&self, c: char) -> &[char] {
+        match c {
+            'A' => &['Α', 'А'],
+            'Α' | 'А' => &['A'],
+            _ => &[],
+        }
+    }
+}
+
+#[cfg(feature = "identifier")]
 #[test]
 fn test_general_security_profile_identifier_allowed() {
     use crate::GeneralSecurityProfile;
@@ -27,6 +65,7 @@ fn test_general_security_profile_identifier_allowed() {
     assert_eq!('Ⅰ'.identifier_allowed(), false);
 }
 
+#[cfg(feature = "mixed-script")]
 #[test]
 fn test_mixed_script() {
     use crate::MixedScript;
@@ -59,6 +98,7 @@ fn test_mixed_script() {
     assert_eq!("夏の幻ㄒㄧㄤ".resolve_script_set().is_all(), false);
 }
 
+#[cfg(feature = "confusables")]
 #[test]
 fn test_confusable_detection() {
     use crate::skeleton;
@@ -71,6 +111,45 @@ fn test_confusable_detection() {
     assert_eq!(&skeleton("ﶛﶛ").collect::<String>(), "نمىنمى");
 }
 
+#[cfg(feature = "confusables")]
+#[test]
+fn test_confusable_detection_casefold() {
+    use crate::{skeleton, skeleton_casefold};
+    use std::string::String;
+    assert_eq!(&skeleton_casefold("").collect::<String>(), "");
+    assert_ne!(
+        skeleton("PayPaI").collect::<String>(),
+        skeleton("paypal").collect::<String>()
+    );
+    assert_eq!(
+        skeleton_casefold("PayPal").collect::<String>(),
+        skeleton_casefold("paypal").collect::<String>()
+    );
+    assert_eq!(
+        skeleton_casefold("PayPaI").collect::<String>(),
+        skeleton_casefold("paypal").collect::<String>()
+    );
+    assert_eq!(
+        skeleton_casefold("PayPaǀ").collect::<String>(),
+        skeleton_casefold("paypal").collect::<String>()
+    );
+    assert_eq!(
+        skeleton_casefold("STRAẞE").collect::<String>(),
+        skeleton_casefold("strasse").collect::<String>()
+    );
+    assert_eq!(
+        skeleton_casefold("PAYPAL").collect::<String>(),
+        skeleton_casefold("paypal").collect::<String>()
+    );
+    // GREEK SMALL LETTER BETA is confusable with LATIN SMALL LETTER SHARP S
+    assert_eq!(
+        skeleton_casefold("β").collect::<String>(),
+        skeleton_casefold("SS").collect::<String>()
+    );
+    assert_eq!(&skeleton_casefold("ｓ؜Ｓ").collect::<String>(), "ss");
+}
+
+#[cfg(feature = "mixed-script")]
 #[test]
 fn test_potential_mixed_script_detection() {
     use crate::is_potential_mixed_script_confusable_char;
@@ -79,6 +158,7 @@ fn test_potential_mixed_script_detection() {
     assert!(!is_potential_mixed_script_confusable_char('D'));
 }
 
+#[cfg(feature = "mixed-script")]
 #[test]
 fn test_augmented_script_set_fmt_debug() {
     use crate::mixed_script::AugmentedScriptSet;
@@ -116,6 +196,7 @@ fn test_augmented_script_set_fmt_debug() {
     }
 }
 
+#[cfg(feature = "mixed-script")]
 #[test]
 fn test_augmented_script_set_fmt_display() {
     use crate::mixed_script::AugmentedScriptSet;
@@ -152,3 +233,1234 @@ fn test_augmented_script_set_fmt_display() {
         assert_eq!(format!("{}", ss), output);
     }
 }
+
+#[cfg(all(
+    feature = "alloc",
+    feature = "identifier",
+    feature = "confusables",
+    feature = "mixed-script"
+))]
+#[test]
+fn test_domain_labels() {
+    use crate::domain::{check_domain, decode_label, labels, LabelError};
+    use crate::findings::FindingKind;
+    use crate::RestrictionLevel;
+    use std::vec::Vec;
+
+    assert_eq!(
+        labels("www.example。com．").collect::<Vec<_>>(),
+        vec!["www", "example", "com"]
+    );
+    assert_eq!(labels("a｡b").collect::<Vec<_>>(), vec!["a", "b"]);
+    assert_eq!(labels("a..b").collect::<Vec<_>>(), vec!["a", "", "b"]);
+
+    assert_eq!(decode_label("example").unwrap(), "example");
+    assert_eq!(decode_label("xn--bcher-kva").unwrap(), "bücher");
+    assert_eq!(decode_label("XN--mnchen-3ya").unwrap(), "münchen");
+    assert_eq!(decode_label("xn--r8jz45g").unwrap(), "例え");
+    assert_eq!(decode_label("xn--80ak6aa92e").unwrap(), "аррӏе");
+    assert_eq!(
+        decode_label("xn--bcher-kv!"),
+        Err(LabelError::InvalidPunycode)
+    );
+    assert_eq!(
+        decode_label("xn--99999999"),
+        Err(LabelError::InvalidPunycode)
+    );
+    assert_eq!(decode_label("xn--example-"), Err(LabelError::AsciiPunycode));
+    assert_eq!(decode_label(""), Err(LabelError::EmptyLabel));
+
+    let verdicts = check_domain("xn--bcher-kva.example.com");
+    assert_eq!(verdicts.len(), 3);
+    assert!(verdicts.iter().all(|v| v.safe));
+    assert_eq!(
+        verdicts[0].restriction_level,
+        RestrictionLevel::SingleScript
+    );
+
+    // Cyrillic "аррӏе", which has the same skeleton as "apple"
+    let verdict = &check_domain("xn--80ak6aa92e.com")[0];
+    assert_eq!(verdict.findings.len(), 1);
+    assert_eq!(verdict.findings[0].kind, FindingKind::WholeScriptConfusable);
+    assert!(!verdict.safe);
+
+    // Latin "pypal" with a Cyrillic "а"
+    let verdict = &check_domain("xn--pypal-4ve.com")[0];
+    assert_eq!(verdict.unicode.as_deref(), Ok("pаypal"));
+    assert!(verdict
+        .findings
+        .iter()
+        .any(|f| f.kind == FindingKind::MixedScriptConfusable));
+    assert!(!verdict.safe);
+
+    // Latin "sony" with Han "日本" mixes scripts, but nothing in it is confusable
+    let verdict = &check_domain("xn--sony-j79hm9d.jp")[0];
+    assert_eq!(verdict.unicode.as_deref(), Ok("sony日本"));
+    assert_eq!(
+        verdict.restriction_level,
+        RestrictionLevel::HighlyRestrictive
+    );
+    assert!(verdict.findings.is_empty());
+    assert!(verdict.safe);
+
+    let verdict = &check_domain("xn--example-.com")[0];
+    assert_eq!(verdict.unicode, Err(LabelError::AsciiPunycode));
+    assert!(!verdict.safe);
+
+    let verdicts = check_domain("a..b");
+    assert_eq!(verdicts.len(), 3);
+    assert_eq!(verdicts[1].unicode, Err(LabelError::EmptyLabel));
+    assert!(!verdicts[1].safe);
+    assert!(verdicts[0].safe && verdicts[2].safe);
+}
+
+#[cfg(all(
+    feature = "alloc",
+    feature = "identifier",
+    feature = "confusables",
+    feature = "mixed-script"
+))]
+#[test]
+fn test_email() {
+    use crate::email::{check_email, check_local_part, EmailError};
+    use crate::findings::{Finding, FindingKind};
+    use crate::RestrictionLevel;
+    use std::vec::Vec;
+
+    let kinds = |findings: Vec<Finding>| {
+        findings
+            .into_iter()
+            .map(|f| (f.kind, f.range))
+            .collect::<Vec<_>>()
+    };
+
+    let report = check_email("john.doe+news@example.com").unwrap();
+    assert_eq!(report.local_part, "john.doe+news");
+    assert_eq!(report.domain, "example.com");
+    assert!(report.is_safe());
+
+    assert!(check_email("пётр@xn--bcher-kva.de").unwrap().is_safe());
+    assert!(!check_email("\"a@b\"@example.com").unwrap().is_safe());
+    assert_eq!(check_email("example.com"), Err(EmailError::MissingAt));
+    assert_eq!(check_email("john@"), Err(EmailError::EmptyDomain));
+
+    let report = check_email("john@xn--80ak6aa92e.com").unwrap();
+    assert!(report.local_part_findings.is_empty());
+    assert!(!report.is_safe());
+
+    assert_eq!(
+        kinds(check_local_part("")),
+        vec![(FindingKind::InvalidSyntax, 0..0)]
+    );
+    assert_eq!(
+        kinds(check_local_part(".a..b.")),
+        vec![
+            (FindingKind::InvalidSyntax, 0..1),
+            (FindingKind::InvalidSyntax, 3..4),
+            (FindingKind::InvalidSyntax, 5..6),
+        ]
+    );
+    assert_eq!(
+        kinds(check_local_part("a...")),
+        vec![
+            (FindingKind::InvalidSyntax, 2..3),
+            (FindingKind::InvalidSyntax, 3..4),
+        ]
+    );
+    let findings = check_local_part("a b");
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].kind, FindingKind::RestrictedChar);
+    assert_eq!(findings[0].range, 1..2);
+    assert_eq!(findings[0].chars, vec![' ']);
+    assert_eq!(check_local_part("ｊｏｈｎ")[0].kind, FindingKind::NotNfkc);
+    // Latin mixed with Cyrillic
+    assert_eq!(
+        kinds(check_local_part("pаypal")),
+        vec![(
+            FindingKind::RestrictionLevel(RestrictionLevel::MinimallyRestrictive),
+            0..7
+        )]
+    );
+}
+
+#[cfg(all(
+    feature = "alloc",
+    feature = "identifier",
+    feature = "confusables",
+    feature = "mixed-script"
+))]
+#[test]
+fn test_canonicalize_identifier() {
+    use crate::canonicalization::{canonicalize_identifier, CanonicalizationOptions};
+    use crate::RestrictionLevel;
+
+    let options = CanonicalizationOptions::default();
+    let canonical = canonicalize_identifier("Straße", &options);
+    assert_eq!(canonical.normalized, "strasse");
+    assert_eq!(canonical.restriction_level, RestrictionLevel::ASCIIOnly);
+    assert!(canonical.accepted);
+
+    // Fullwidth letters, a soft hyphen and a zero width joiner
+    let canonical = canonicalize_identifier("ＰａｙＰａｌ\u{ad}\u{200d}", &options);
+    assert_eq!(canonical.normalized, "paypal");
+    assert_eq!(canonical.skeleton, "paypal");
+
+    let canonical = canonicalize_identifier("ΣΊΣΥΦΟΣ", &options);
+    assert_eq!(canonical.normalized, "σίσυφοσ");
+    assert_eq!(canonical.restriction_level, RestrictionLevel::SingleScript);
+
+    let options = CanonicalizationOptions::new()
+        .casefold(false)
+        .remove_default_ignorables(false)
+        .max_restriction_level(RestrictionLevel::ASCIIOnly);
+    let canonical = canonicalize_identifier("Ｐａｙ\u{ad}", &options);
+    assert_eq!(canonical.normalized, "Pay\u{ad}");
+    assert_eq!(canonical.skeleton, "Pay");
+    assert_eq!(canonical.restriction_level, RestrictionLevel::Unrestricted);
+    assert!(!canonical.accepted);
+
+    // Latin mixed with Cyrillic
+    let canonical = canonicalize_identifier("pаypal", &CanonicalizationOptions::default());
+    assert_eq!(
+        canonical.restriction_level,
+        RestrictionLevel::MinimallyRestrictive
+    );
+    assert!(!canonical.accepted);
+}
+
+#[cfg(all(feature = "alloc", feature = "confusables"))]
+#[test]
+fn test_confusable_similarity() {
+    use crate::confusable_similarity::{
+        skeleton_distance, skeleton_distance_weighted, ProtectedNames, SkeletonWeights,
+    };
+
+    assert_eq!(skeleton_distance("", ""), 0);
+    assert_eq!(skeleton_distance("rnicrosoft", "microsoft"), 0);
+    assert_eq!(skeleton_distance("rnicrosofts", "microsoft"), 1);
+    assert_eq!(skeleton_distance("microsoft", ""), 10);
+    assert_eq!(skeleton_distance("paypal", "pауpаl"), 0);
+    assert_eq!(skeleton_distance("kitten", "sitting"), 3);
+
+    struct CheapVowels;
+    impl SkeletonWeights for CheapVowels {
+        fn substitution(&self, from: char, to: char) -> u32 {
+            if "aeiou".contains(from) && "aeiou".contains(to) {
+                0
+            } else {
+                2
+            }
+        }
+    }
+    assert_eq!(
+        skeleton_distance_weighted("paypel", "paypal", &CheapVowels),
+        0
+    );
+    assert_eq!(
+        skeleton_distance_weighted("paypat", "paypal", &CheapVowels),
+        2
+    );
+    assert_eq!(
+        skeleton_distance_weighted("paypa", "paypal", &CheapVowels),
+        1
+    );
+
+    let protected = ProtectedNames::new(vec!["google", "microsoft", "paypal", "apple"]);
+    let matches = protected.closest("rnicrosofts", 2);
+    assert_eq!(matches.len(), 2);
+    assert_eq!(matches[0].name, "microsoft");
+    assert_eq!(matches[0].distance, 1);
+    assert!(matches[1].distance > 1);
+    let matches = protected.closest("аpple", 10);
+    assert_eq!(matches.len(), 4);
+    assert_eq!((matches[0].name, matches[0].distance), ("apple", 0));
+    assert!(protected.closest("apple", 0).is_empty());
+}
+
+#[cfg(all(
+    feature = "alloc",
+    feature = "identifier",
+    feature = "confusables",
+    feature = "mixed-script"
+))]
+#[test]
+fn test_identifier_lints() {
+    use crate::general_security_profile::IdentifierType;
+    use crate::identifier_lints::{Diagnostic, Identifier, IdentifierLints};
+    use crate::mixed_script::AugmentedScriptSet;
+
+    let lints = IdentifierLints::new();
+    let ascii = vec![Identifier::new("foo", 0), Identifier::new("f00", 1)];
+    assert!(lints.check(ascii).is_empty());
+
+    // Greek 'ο' is only used as a confusable of Latin 'o', while 'λ' verifies
+    // the use of Greek in the second set.
+    let identifiers = vec![
+        Identifier::new("ο", 3),
+        Identifier::new("foo", 1),
+        Identifier::new("fοο", 2),
+        Identifier::new("ο", 5),
+    ];
+    let diagnostics = lints.check(identifiers.clone());
+    assert_eq!(
+        diagnostics,
+        vec![
+            Diagnostic::NonAsciiIdent {
+                name: "fοο",
+                span: 2
+            },
+            Diagnostic::NonAsciiIdent {
+                name: "ο", span: 3
+            },
+            Diagnostic::ConfusableIdents {
+                name: "fοο",
+                span: 2,
+                existing_name: "foo",
+                existing_span: 1,
+            },
+            Diagnostic::MixedScriptConfusables {
+                span: 2,
+                script_set: AugmentedScriptSet::for_char('ο'),
+                chars: vec!['ο'],
+            },
+        ]
+    );
+    let mut verified = identifiers;
+    verified.push(Identifier::new("λ", 4));
+    let diagnostics = lints
+        .non_ascii_idents(false)
+        .confusable_idents(false)
+        .check(verified);
+    assert!(diagnostics.is_empty());
+
+    let diagnostics = IdentifierLints::new()
+        .non_ascii_idents(false)
+        .check(vec![Identifier::new("µsⅠ", ())]);
+    assert_eq!(
+        diagnostics,
+        vec![Diagnostic::UncommonCodepoints {
+            name: "µsⅠ",
+            span: (),
+            identifier_type: Some(IdentifierType::Not_NFKC),
+            chars: vec!['µ', 'Ⅰ'],
+        },]
+    );
+}
+
+#[cfg(all(
+    feature = "identifier",
+    feature = "confusables",
+    feature = "mixed-script"
+))]
+#[test]
+fn test_inspect() {
+    use crate::general_security_profile::IdentifierType;
+    use crate::inspect;
+
+    let info = inspect('а');
+    assert!(info.identifier_allowed);
+    assert_eq!(info.identifier_type, Some(IdentifierType::Recommended));
+    assert_eq!(info.confusable_prototype, Some(&['a'][..]));
+    assert!(info.potential_mixed_script_confusable);
+    assert!(!info.default_ignorable);
+
+    let info = inspect('\u{200B}');
+    assert!(info.default_ignorable);
+    assert!(!info.identifier_allowed);
+
+    assert_eq!(inspect('a').confusable_prototype, None);
+}
+
+#[cfg(all(
+    feature = "alloc",
+    feature = "identifier",
+    feature = "confusables",
+    feature = "mixed-script"
+))]
+#[test]
+fn test_security_checker() {
+    use crate::findings::{Finding, FindingKind, SecurityChecker, Severity};
+    use crate::mixed_script::AugmentedScriptSet;
+    use crate::RestrictionLevel;
+    use std::vec::Vec;
+
+    assert!(SecurityChecker::new().check("paypal").is_empty());
+
+    let findings = SecurityChecker::new().check("pаypаl\u{200B}");
+    let kinds: Vec<FindingKind> = findings.iter().map(|f| f.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            FindingKind::RestrictedChar,
+            FindingKind::DefaultIgnorable,
+            FindingKind::MixedScript,
+            FindingKind::RestrictionLevel(RestrictionLevel::Unrestricted),
+            FindingKind::MixedScriptConfusable,
+        ]
+    );
+    assert_eq!(findings[1].range, 8..11);
+    assert_eq!(
+        findings[2].scripts,
+        vec![
+            AugmentedScriptSet::for_char('p'),
+            AugmentedScriptSet::for_char('а')
+        ]
+    );
+    assert_eq!(
+        findings[4],
+        Finding {
+            kind: FindingKind::MixedScriptConfusable,
+            severity: Severity::Error,
+            range: 1..7,
+            chars: vec!['а', 'а'],
+            scripts: vec![AugmentedScriptSet::for_char('а')],
+        }
+    );
+
+    let findings = SecurityChecker::new()
+        .mixed_script(false)
+        .max_restriction_level(None)
+        .check("рорех");
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].kind, FindingKind::WholeScriptConfusable);
+    assert_eq!(findings[0].range, 0..10);
+
+    let findings = SecurityChecker::new()
+        .restricted_chars(false)
+        .max_restriction_level(None)
+        .confusables(false)
+        .check("a\u{202E}b");
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].kind, FindingKind::BidiControl);
+    assert_eq!(findings[0].severity, Severity::Error);
+    assert_eq!(findings[0].range, 1..4);
+}
+
+#[cfg(all(feature = "identifier", feature = "mixed-script"))]
+#[test]
+fn test_restriction_level_detector() {
+    use crate::restriction_level::{RestrictionLevel, RestrictionLevelDetector};
+    use crate::RestrictionLevelDetection;
+
+    let samples = [
+        "",
+        "abc",
+        "Σ",
+        "ΣΑΣ",
+        "日本語カタカナ",
+        "aｱ",
+        "Σx",
+        "µ",
+        "a\u{200B}b",
+    ];
+    for sample in samples {
+        let expected = sample.detect_restriction_level();
+        let mut detector = RestrictionLevelDetector::new();
+        for (idx, ch) in sample.chars().enumerate() {
+            if idx % 2 == 0 {
+                detector.push(ch);
+            } else {
+                detector.push_str(ch.encode_utf8(&mut [0; 4]));
+            }
+        }
+        assert_eq!(detector.finish(), expected, "{}", sample);
+        assert_eq!(sample.chars().detect_restriction_level(), expected);
+        let chars: std::vec::Vec<char> = sample.chars().collect();
+        assert_eq!(chars[..].detect_restriction_level(), expected);
+    }
+    let detector: RestrictionLevelDetector = "Σ".chars().chain("x".chars()).collect();
+    assert_eq!(detector.finish(), RestrictionLevel::MinimallyRestrictive);
+}
+
+#[cfg(all(
+    feature = "identifier",
+    feature = "confusables",
+    feature = "mixed-script"
+))]
+#[test]
+fn test_input_adapters() {
+    use crate::input::{CharIter, Utf16};
+    use crate::restriction_level::RestrictionLevel;
+    use crate::{skeleton, skeleton_chars, MixedScript, RestrictionLevelDetection};
+    use std::vec::Vec;
+
+    for sample in ["", "abc", "Σ", "Σx", "pаypal", "日本語カタカナ", "µ"] {
+        let chars: Vec<char> = sample.chars().collect();
+        let units: Vec<u16> = sample.encode_utf16().collect();
+        let utf16 = Utf16::new(&units);
+        assert!(!utf16.has_unpaired_surrogates());
+
+        let single_script = sample.is_single_script();
+        assert_eq!(sample.chars().is_single_script(), single_script);
+        assert_eq!(chars[..].is_single_script(), single_script);
+        assert_eq!(
+            CharIter(chars.iter().copied()).is_single_script(),
+            single_script
+        );
+        assert_eq!(utf16.is_single_script(), single_script);
+
+        let level = sample.detect_restriction_level();
+        assert_eq!(
+            CharIter(chars.iter().copied()).detect_restriction_level(),
+            level
+        );
+        assert_eq!(utf16.detect_restriction_level(), level);
+
+        assert!(skeleton_chars(utf16.chars()).eq(skeleton(sample)));
+    }
+
+    let unpaired = [0x61, 0xD800, 0x62];
+    let utf16 = Utf16::new(&unpaired);
+    assert!(utf16.has_unpaired_surrogates());
+    assert!(utf16.chars().eq("a\u{FFFD}b".chars()));
+    assert!(utf16.is_single_script());
+    assert_eq!(
+        utf16.detect_restriction_level(),
+        RestrictionLevel::Unrestricted
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_unicode_data() {
+    use crate::confusable_detection::skeleton_with;
+    use crate::data::{ParseError, UnicodeData};
+    use crate::general_security_profile::IdentifierType;
+    use crate::provider::{BakedData, DataProvider};
+    use crate::restriction_level::detect_restriction_level_with;
+    use crate::RestrictionLevel;
+    use std::string::String;
+    use std::vec::Vec;
+
+    let mut data = UnicodeData::new();
+    assert_eq!(skeleton_with("ｓ", &data).collect::<String>(), "s");
+    assert!(data.identifier_allowed('a'));
+
+    data.load_confusables(b"# comment\n\n0041 ;\t0061 ;\tMA\t# A -> a\n")
+        .unwrap();
+    assert_eq!(skeleton_with("AＡ", &data).collect::<String>(), "aＡ");
+    data.load_identifier_status("\u{feff}0061..007A ; Allowed # a..z\n".as_bytes())
+        .unwrap();
+    assert!(data.identifier_allowed('b'));
+    assert!(!data.identifier_allowed('B'));
+    assert_eq!(
+        detect_restriction_level_with("aB", &data),
+        RestrictionLevel::Unrestricted
+    );
+    data.load_identifier_type(b"0061 ; Technical Uncommon_Use # a\n")
+        .unwrap();
+    assert_eq!(data.identifier_type('a'), Some(IdentifierType::Technical));
+    assert_eq!(data.identifier_type('b'), None);
+
+    assert_eq!(
+        data.load_confusables(b"0041 ;\t0061 ;\tMA\n0041 0042 ;\t0061 ;\tMA\n"),
+        Err(ParseError {
+            file: "confusables.txt",
+            line: 2
+        })
+    );
+    assert!(data
+        .load_identifier_type(b"0061 ; Not_A_Type # a\n")
+        .is_err());
+
+    let dir = std::env::temp_dir().join(format!("unicode-security-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("confusables.txt"), "0042 ;\t0062 ;\tMA\n").unwrap();
+    let loaded = UnicodeData::from_dir(&dir);
+    std::fs::remove_dir_all(&dir).unwrap();
+    let loaded = loaded.unwrap();
+    assert_eq!(loaded.confusable_prototype('B'), Some(&['b'][..]));
+    assert_eq!(loaded.confusable_prototype('I'), None);
+    assert!(loaded.identifier_allowed('B'));
+
+    // Mixed-script confusables are derived from the loaded confusables
+    let mut data = UnicodeData::new();
+    assert!(data.potential_mixed_script_confusable('о'));
+    data.load_confusables("0430 ;\t0061 ;\tMA\t# а → a\n".as_bytes())
+        .unwrap();
+    assert!(data.potential_mixed_script_confusable('а'));
+    assert!(data.potential_mixed_script_confusable('a'));
+    assert!(!data.potential_mixed_script_confusable('о'));
+    // Only confusables allowed in identifiers count
+    data.load_identifier_status(b"0061 ; Allowed\n").unwrap();
+    assert!(!data.potential_mixed_script_confusable('a'));
+
+    // Deriving them from the compiled-in confusables gives the compiled-in table
+    let mut confusables = String::new();
+    for c in (0..=0x10FFFF).filter_map(char::from_u32) {
+        if let Some(prototype) = BakedData.confusable_prototype(c) {
+            let prototype: Vec<String> = prototype
+                .iter()
+                .map(|&p| format!("{:04X}", p as u32))
+                .collect();
+            confusables += &format!("{:04X} ;\t{} ;\tMA\n", c as u32, prototype.join(" "));
+        }
+    }
+    let mut data = UnicodeData::new();
+    data.load_confusables(confusables.as_bytes()).unwrap();
+    for c in (0..=0x10FFFF).filter_map(char::from_u32) {
+        assert_eq!(
+            data.potential_mixed_script_confusable(c),
+            BakedData.potential_mixed_script_confusable(c),
+            "{:?}",
+            c
+        );
+    }
+}
+
+#[cfg(all(
+    feature = "alloc",
+    feature = "identifier",
+    feature = "confusables",
+    feature = "mixed-script"
+))]
+#[test]
+fn test_data_provider() {
+    use crate::batch::{check_all_with, skeleton_collisions, skeleton_collisions_with};
+    use crate::canonicalization::{canonicalize_identifier_with, CanonicalizationOptions};
+    use crate::confusable_detection::{
+        skeleton_casefold_with, skeleton_extended_with, skeleton_with, SkeletonBuilder,
+    };
+    use crate::confusable_profile::ConfusableProfile;
+    use crate::confusable_similarity::{skeleton_distance, skeleton_distance_with, ProtectedNames};
+    use crate::domain::DomainChecker;
+    use crate::email::{check_email, check_email_with};
+    use crate::findings::{FindingKind, SecurityChecker};
+    use crate::identifier_lints::{Diagnostic, Identifier, IdentifierLints};
+    use crate::inspect::inspect_with;
+    use crate::mixed_script::{
+        is_potential_mixed_script_confusable_char_with, is_single_script_with,
+    };
+    use crate::provider::BakedData;
+    use crate::restriction_level::{detect_restriction_level_with, RestrictionLevel};
+    use crate::{skeleton, skeleton_casefold, skeleton_extended};
+    use std::string::String;
+    use std::vec::Vec;
+
+    for sample in ["", "paypal", "PayPaI", "ｓ؜ｓ", "ﶛ", "STRAẞE"] {
+        assert!(skeleton_with(sample, &BakedData).eq(skeleton(sample)));
+        assert!(skeleton_casefold_with(sample, &BakedData).eq(skeleton_casefold(sample)));
+    }
+    assert_eq!(skeleton_with("wolf", &Fixture).collect::<String>(), "vvolf");
+    assert_eq!(
+        skeleton_casefold_with("WOLF", &Fixture).collect::<String>(),
+        skeleton_casefold_with("vvolf", &Fixture).collect::<String>()
+    );
+
+    assert_eq!(
+        detect_restriction_level_with("café", &Fixture),
+        RestrictionLevel::Unrestricted
+    );
+    assert_eq!(
+        detect_restriction_level_with("café", &BakedData),
+        RestrictionLevel::SingleScript
+    );
+    let kinds: Vec<FindingKind> = SecurityChecker::new()
+        .check_with("café", &Fixture)
+        .iter()
+        .map(|f| f.kind)
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            FindingKind::RestrictedChar,
+            FindingKind::RestrictionLevel(RestrictionLevel::Unrestricted)
+        ]
+    );
+
+    assert!(skeleton_extended_with("cIoud", &BakedData).eq(skeleton_extended("cIoud")));
+    assert!(skeleton_extended_with("wolf", &Fixture).eq(skeleton_extended("vvolf")));
+    assert!(!is_single_script_with("pаypal", &Fixture));
+    assert!(is_potential_mixed_script_confusable_char_with(
+        'а', &Fixture
+    ));
+
+    let verdict = DomainChecker::new().check_label_with("café", &Fixture);
+    assert_eq!(verdict.restriction_level, RestrictionLevel::Unrestricted);
+    assert!(!verdict.safe);
+    assert!(DomainChecker::new().check_label("café").safe);
+    assert!(!check_email_with("café@example.com", &Fixture)
+        .unwrap()
+        .is_safe());
+    assert!(check_email("café@example.com").unwrap().is_safe());
+
+    let canonical = canonicalize_identifier_with("Wolf", &CanonicalizationOptions::new(), &Fixture);
+    assert_eq!(canonical.normalized, "wolf");
+    assert_eq!(canonical.skeleton, "vvolf");
+    assert_eq!(
+        inspect_with('w', &Fixture).confusable_prototype,
+        Some(&['v', 'v'][..])
+    );
+    assert!(!inspect_with('é', &Fixture).identifier_allowed);
+
+    let results = check_all_with(&["wolf", "café"], &Fixture);
+    assert_eq!(results[0].skeleton, "vvolf");
+    assert_eq!(results[1].restriction_level, RestrictionLevel::Unrestricted);
+    assert_eq!(
+        skeleton_collisions_with(&["wolf", "vvolf"], &Fixture).len(),
+        1
+    );
+    assert!(skeleton_collisions(&["wolf", "vvolf"]).is_empty());
+
+    assert_eq!(skeleton_distance_with("wolf", "vvolf", &Fixture), 0);
+    assert_eq!(skeleton_distance("wolf", "vvolf"), 2);
+    let names = ProtectedNames::new_with(["wolf", "fox"], &Fixture);
+    assert_eq!(names.closest_with("vvolf", 1, &Fixture)[0].distance, 0);
+    let profile = ConfusableProfile::new("test", SkeletonBuilder::new());
+    assert_eq!(profile.skeleton_with("wolf", &Fixture), "vvolf");
+    assert!(profile.is_confusable_with("wolf", "vvolf", &Fixture));
+    assert!(!profile.is_confusable("wolf", "vvolf"));
+    assert_eq!(
+        profile.collisions_with(["wolf", "fox", "vvolf"], &Fixture),
+        [["wolf", "vvolf"]]
+    );
+
+    let diagnostics = IdentifierLints::new().check_with([Identifier::new("café", 0)], &Fixture);
+    assert!(diagnostics
+        .iter()
+        .any(|d| matches!(d, Diagnostic::UncommonCodepoints { .. })));
+}
+
+#[cfg(feature = "confusables")]
+#[test]
+fn test_intentional_confusables() {
+    use crate::confusable_detection::{
+        intentional_confusables_with, is_intentional_confusable_with,
+    };
+    use crate::{intentional_confusables, is_intentional_confusable, skeleton};
+
+    // Latin, Greek and Cyrillic capital A, and small o
+    for &(a, b) in &[('A', 'Α'), ('A', 'А'), ('o', 'ο'), ('o', 'о')] {
+        assert!(is_intentional_confusable(a, b), "{:?} {:?}", a, b);
+        assert!(is_intentional_confusable(b, a), "{:?} {:?}", b, a);
+        assert!(intentional_confusables(a).any(|c| c == b));
+        assert!(skeleton(a.encode_utf8(&mut [0; 4])).eq(skeleton(b.encode_utf8(&mut [0; 4]))));
+    }
+    assert!(!is_intentional_confusable('A', 'A'));
+    assert!(!is_intentional_confusable('A', 'B'));
+
+    assert!(is_intentional_confusable_with('A', 'А', &Fixture));
+    assert!(is_intentional_confusable_with('Α', 'A', &Fixture));
+    assert!(!is_intentional_confusable_with('Α', 'А', &Fixture));
+    assert!(!is_intentional_confusable_with('A', 'B', &Fixture));
+    assert!(intentional_confusables_with('A', &Fixture).eq(['Α', 'А']));
+    assert!(intentional_confusables_with('B', &Fixture).eq([]));
+
+    #[cfg(feature = "std")]
+    {
+        use crate::data::UnicodeData;
+
+        let mut data = UnicodeData::new();
+        data.load_intentional(
+            "# intentional.txt\n\
+             0041 ;\t0391 #  ( A ~ Α ) LATIN CAPITAL LETTER A ~ GREEK CAPITAL LETTER ALPHA\n\
+             0041 ;\t0410 #  ( A ~ А ) LATIN CAPITAL LETTER A ~ CYRILLIC CAPITAL LETTER A\n"
+                .as_bytes(),
+        )
+        .unwrap();
+        assert!(intentional_confusables_with('A', &data).eq(['Α', 'А']));
+        assert!(intentional_confusables_with('А', &data).eq(['A']));
+        assert!(data.load_intentional(b"0041 ; X\n").is_err());
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "confusables"))]
+#[test]
+fn test_skeleton_builder() {
+    use crate::{skeleton, SkeletonBuilder};
+    use std::string::String;
+
+    let standard = SkeletonBuilder::new();
+    for sample in ["", "paypal", "PayPaI", "ｓ؜ｓ", "ﶛ", "STRAẞE", "g00gle"] {
+        assert_eq!(
+            standard.skeleton(sample),
+            skeleton(sample).collect::<String>()
+        );
+    }
+
+    let builder = SkeletonBuilder::new()
+        .add_mapping("vv", "w")
+        .add_mapping("c\u{200B}l", "d")
+        .add_mapping("cll", "dl")
+        .suppress_mapping("0");
+    assert_eq!(builder.skeleton("vvolf"), builder.skeleton("wolf"));
+    assert_ne!(standard.skeleton("vvolf"), standard.skeleton("wolf"));
+    // Sources are matched after removing default ignorables
+    assert_eq!(builder.skeleton("c\u{200B}loud"), builder.skeleton("doud"));
+    assert_eq!(builder.skeleton("cloud"), builder.skeleton("doud"));
+    // The longest source wins
+    assert_eq!(builder.skeleton("clll"), builder.skeleton("dll"));
+    // Targets are mapped to their prototypes
+    let rn = SkeletonBuilder::new().add_mapping("nn", "m");
+    assert_eq!(rn.skeleton("nn"), "rn");
+    assert_eq!(rn.skeleton("nn"), rn.skeleton("m"));
+    // Suppressed prototypes are kept as they are
+    assert_eq!(builder.skeleton("g00gle"), "g00gle");
+    assert_eq!(standard.skeleton("g00gle"), "gOOgle");
+
+    // The last change of a source wins
+    let suppressed = builder.clone().suppress_mapping("vv");
+    assert_ne!(suppressed.skeleton("vvolf"), suppressed.skeleton("wolf"));
+    let restored = suppressed.add_mapping("vv", "w");
+    assert_eq!(restored.skeleton("vvolf"), restored.skeleton("wolf"));
+    assert_eq!(restored, builder);
+    assert_eq!(SkeletonBuilder::new().add_mapping("", "x"), standard);
+
+    assert_eq!(standard.skeleton("ℓ"), "l");
+    assert_eq!(standard.skeleton_with("ℓ", &Fixture), "ℓ");
+    // The targets of added mappings are mapped with the data of the provider too
+    assert_eq!(builder.skeleton_with("wℓ", &Fixture), "vvℓ");
+    assert_eq!(builder.skeleton_with("vvℓ", &Fixture), "vvℓ");
+}
+
+#[cfg(feature = "confusables")]
+#[test]
+fn test_skeleton_extended() {
+    use crate::confusable_detection::{skeleton_extended, skeleton_extended_chars};
+    use crate::skeleton;
+
+    for sample in ["", "paypal", "PayPaI", "ｓ؜ｓ", "ﶛ", "STRAẞE", "g00gle", "m"] {
+        assert!(skeleton_extended(sample).eq(skeleton(sample)));
+    }
+    assert!(skeleton_extended("cloud").eq("doud".chars()));
+    assert!(skeleton_extended("cIoud").eq(skeleton_extended("doud")));
+    assert!(skeleton_extended("vvolf").eq(skeleton_extended("wolf")));
+    assert!(skeleton_extended("VVOLF").eq(skeleton_extended("WOLF")));
+    assert!(skeleton_extended("vvv").eq("wv".chars()));
+    assert!(skeleton_extended("c").eq("c".chars()));
+    assert!(skeleton_extended("ccl").eq("cd".chars()));
+    // Sequences are matched after removing default ignorables
+    assert!(skeleton_extended("c\u{200B}l").eq("d".chars()));
+    assert!(skeleton_extended_chars(['v', 'v']).eq(['w']));
+    // The standard skeleton is unchanged
+    assert!(skeleton("cloud").eq("cloud".chars()));
+
+    #[cfg(feature = "alloc")]
+    {
+        use crate::SkeletonBuilder;
+        use std::string::String;
+
+        let builder = SkeletonBuilder::new().confusable_sequences(true);
+        assert_eq!(builder.skeleton("cIoud vvolf"), "doud wolf");
+        assert_eq!(
+            builder.skeleton("g00gle cloud"),
+            skeleton_extended("g00gle cloud").collect::<String>()
+        );
+        let builder = builder.suppress_mapping("cl").add_mapping("rn", "m");
+        assert_eq!(builder.skeleton("cloud vvolf"), "cloud wolf");
+        assert_eq!(builder.skeleton("rncl"), "rncl");
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "confusables"))]
+#[test]
+fn test_confusable_profile() {
+    use crate::confusable_profile::{ConfusableProfile, ProfileParseError, BUILTIN_PROFILES};
+    use crate::SkeletonBuilder;
+    use std::vec;
+
+    for name in BUILTIN_PROFILES {
+        assert_eq!(ConfusableProfile::builtin(name).unwrap().name(), *name);
+    }
+    assert_eq!(ConfusableProfile::builtin("comic-sans"), None);
+
+    let mono = ConfusableProfile::dejavu_sans_mono();
+    assert_eq!(mono.name(), "dejavu-sans-mono");
+    assert!(!mono.is_confusable("l1st", "list"));
+    assert!(!mono.is_confusable("Iist", "list"));
+    assert!(!mono.is_confusable("g00gle", "google"));
+    assert!(!mono.is_confusable("modern", "rnodern"));
+    assert!(mono.is_confusable("a│b", "a|b"));
+    assert!(mono.is_confusable("a∣b", "a|b"));
+    // The profile overrides the standard prototype l of DIVIDES
+    assert!(!mono.is_confusable("a∣b", "alb"));
+    assert!(!mono.is_confusable("a|b", "alb"));
+    // Mappings of confusables.txt not mentioned by the profile still apply
+    assert!(mono.is_confusable("pаypal", "paypal"));
+
+    let profile = ConfusableProfile::parse(
+        "custom",
+        "# comment\n\
+         \n\
+         add ; 0076 0076 ; 0077  # vv → w\n\
+         remove ; 0030\n",
+    )
+    .unwrap();
+    assert_eq!(
+        profile,
+        ConfusableProfile::new(
+            "custom",
+            SkeletonBuilder::new()
+                .add_mapping("vv", "w")
+                .suppress_mapping("0")
+        )
+    );
+    assert_eq!(profile.skeleton("vv0"), "w0");
+    assert_eq!(
+        profile.collisions(["wolf", "g0", "paypal", "vvolf", "gO", "pаypal", "vvoIf"]),
+        vec![vec!["wolf", "vvolf", "vvoIf"], vec!["paypal", "pаypal"]]
+    );
+
+    for (contents, line) in [
+        ("add ; 0076\n", 1),
+        ("\nremove ; 0030 ; 004F\n", 2),
+        ("remove ; \n", 1),
+        ("replace ; 0030\n", 1),
+        ("add ; 0076 ; 11FFFF\n", 1),
+    ] {
+        assert_eq!(
+            ConfusableProfile::parse("broken", contents),
+            Err(ProfileParseError { line })
+        );
+    }
+}
+
+#[cfg(all(
+    feature = "alloc",
+    feature = "identifier",
+    feature = "confusables",
+    feature = "mixed-script"
+))]
+#[test]
+fn test_batch() {
+    use crate::batch::{check_all, check_iter, skeleton_collisions, SkeletonCollision};
+    use crate::{skeleton, MixedScript, RestrictionLevelDetection};
+    use std::string::{String, ToString};
+    use std::vec::Vec;
+
+    let names = [
+        "paypal",
+        "rn",
+        "pаypal",
+        "Σίσυφος",
+        "m",
+        "paypal",
+        "",
+        "rn",
+        "x",
+    ];
+    let results = check_all(&names);
+    assert_eq!(results.len(), names.len());
+    for (name, result) in names.iter().zip(&results) {
+        assert_eq!(result.restriction_level, name.detect_restriction_level());
+        assert_eq!(result.single_script, name.is_single_script());
+        assert_eq!(result.skeleton, skeleton(name).collect::<String>());
+    }
+    let owned: Vec<String> = names.iter().map(|name| name.to_string()).collect();
+    assert_eq!(check_all(&owned), results);
+    assert_eq!(check_iter(names).collect::<Vec<_>>(), results);
+    assert!(check_all::<&str>(&[]).is_empty());
+
+    assert_eq!(
+        skeleton_collisions(&names),
+        [
+            SkeletonCollision {
+                skeleton: "paypal".into(),
+                indices: vec![0, 2, 5],
+            },
+            SkeletonCollision {
+                skeleton: "rn".into(),
+                indices: vec![1, 4, 7],
+            },
+        ]
+    );
+    // Duplicates alone are not collisions
+    assert!(skeleton_collisions(&["x", "x", "y"]).is_empty());
+    assert!(skeleton_collisions::<&str>(&[]).is_empty());
+}
+
+#[cfg(feature = "ffi")]
+#[test]
+#[allow(unsafe_code)]
+fn test_ffi() {
+    use crate::ffi::*;
+    use core::ptr;
+
+    assert!(unicode_security_identifier_allowed('A' as u32));
+    assert!(!unicode_security_identifier_allowed(0xD800));
+    assert_eq!(
+        unicode_security_identifier_type('\t' as u32),
+        UnicodeSecurityIdentifierType::NotXid
+    );
+    assert_eq!(
+        unicode_security_identifier_type(0x110000),
+        UnicodeSecurityIdentifierType::None
+    );
+
+    let s = "pаyPaI"; // with a Cyrillic 'а'
+    let mut level = UnicodeSecurityRestrictionLevel::AsciiOnly;
+    let mut single_script = true;
+    let mut buf = [0u8; 16];
+    let mut written = 0;
+    unsafe {
+        assert_eq!(
+            unicode_security_restriction_level(s.as_ptr(), s.len(), &mut level),
+            UnicodeSecurityStatus::Ok
+        );
+        assert_eq!(level, UnicodeSecurityRestrictionLevel::MinimallyRestrictive);
+        assert_eq!(
+            unicode_security_is_single_script(s.as_ptr(), s.len(), &mut single_script),
+            UnicodeSecurityStatus::Ok
+        );
+        assert!(!single_script);
+        assert_eq!(
+            unicode_security_skeleton(
+                s.as_ptr(),
+                s.len(),
+                buf.as_mut_ptr(),
+                buf.len(),
+                &mut written
+            ),
+            UnicodeSecurityStatus::Ok
+        );
+        assert_eq!(&buf[..written], b"payPal");
+        assert_eq!(
+            unicode_security_skeleton(s.as_ptr(), s.len(), ptr::null_mut(), 0, &mut written),
+            UnicodeSecurityStatus::BufferTooSmall
+        );
+        assert_eq!(written, 6);
+        let s = "字";
+        assert_eq!(
+            unicode_security_resolve_script_set(
+                s.as_ptr(),
+                s.len(),
+                buf.as_mut_ptr(),
+                buf.len(),
+                &mut written
+            ),
+            UnicodeSecurityStatus::BufferTooSmall
+        );
+        assert_eq!(written, "Hanb Jpan Kore Hani".len());
+        assert_eq!(
+            unicode_security_restriction_level(b"\xff".as_ptr(), 1, &mut level),
+            UnicodeSecurityStatus::InvalidUtf8
+        );
+        assert_eq!(
+            unicode_security_is_single_script(ptr::null(), 1, &mut single_script),
+            UnicodeSecurityStatus::NullPointer
+        );
+    }
+}
+
+#[cfg(all(
+    feature = "serde",
+    feature = "identifier",
+    feature = "confusables",
+    feature = "mixed-script"
+))]
+#[test]
+fn test_serde() {
+    use crate::findings::SecurityChecker;
+    use crate::general_security_profile::IdentifierType;
+    use crate::mixed_script::AugmentedScriptSet;
+    use crate::report::SecurityReport;
+    use crate::RestrictionLevel;
+    use serde_json::{json, to_value};
+
+    assert_eq!(
+        to_value(RestrictionLevel::HighlyRestrictive).unwrap(),
+        json!("Highly Restrictive")
+    );
+    assert_eq!(
+        to_value(RestrictionLevel::ASCIIOnly).unwrap(),
+        json!("ASCII-Only")
+    );
+    assert_eq!(
+        to_value(IdentifierType::Not_NFKC).unwrap(),
+        json!("Not_NFKC")
+    );
+    assert_eq!(
+        to_value(AugmentedScriptSet::from('字')).unwrap(),
+        json!(["Hanb", "Jpan", "Kore", "Hani"])
+    );
+    assert_eq!(
+        to_value(AugmentedScriptSet::from("123")).unwrap(),
+        json!(["Zyyy"])
+    );
+    assert_eq!(to_value(AugmentedScriptSet::from("aа")).unwrap(), json!([]));
+
+    // With a Cyrillic 'а' and a Roman numeral one
+    let report = SecurityReport::new("pаyⅠ");
+    assert_eq!(
+        to_value(&report).unwrap(),
+        json!({
+            "input": "pаyⅠ",
+            "identifier": {
+                "restricted_chars": [{
+                    "offset": 4,
+                    "character": "Ⅰ",
+                    "code_point": 0x2160,
+                    "identifier_type": "Not_NFKC",
+                }],
+            },
+            "mixed_script": {
+                "single_script": false,
+                "resolved_script_set": [],
+            },
+            "restriction_level": { "level": "Unrestricted" },
+            "skeleton": { "skeleton": "payl" },
+        })
+    );
+
+    let findings = SecurityChecker::new()
+        .restricted_chars(false)
+        .mixed_script(false)
+        .confusables(false)
+        .check("a\u{200B}Σ");
+    assert_eq!(
+        to_value(&findings).unwrap(),
+        json!([
+            {
+                "kind": "default-ignorable",
+                "severity": "warning",
+                "range": { "start": 1, "end": 4 },
+                "chars": ["\u{200B}"],
+                "scripts": [["Zyyy"]],
+            },
+            {
+                "kind": { "restriction-level": "Unrestricted" },
+                "severity": "warning",
+                "range": { "start": 0, "end": 6 },
+                "chars": [],
+                "scripts": [["Latn"], ["Grek"]],
+            },
+        ])
+    );
+}
+
+#[cfg(all(
+    feature = "graphemes",
+    feature = "alloc",
+    feature = "identifier",
+    feature = "confusables",
+    feature = "mixed-script"
+))]
+#[test]
+fn test_graphemes() {
+    use crate::graphemes::{graphemes, GraphemeChecker, GraphemeFindingKind::*};
+    use crate::RestrictionLevel;
+    use std::vec::Vec;
+    use unicode_script::Script;
+
+    let clusters: Vec<_> = graphemes("ne\u{301}한").collect();
+    assert_eq!(clusters.len(), 3);
+    assert_eq!(clusters[1].range, 1..4);
+    assert_eq!(clusters[1].text, "e\u{301}");
+    assert_eq!(clusters[1].base, 'e');
+    assert_eq!((clusters[2].index, clusters[2].script), (2, Script::Hangul));
+
+    let kinds = |s: &str| -> Vec<_> {
+        GraphemeChecker::new()
+            .check(s)
+            .into_iter()
+            .map(|f| (f.kind, f.index))
+            .collect()
+    };
+    assert_eq!(kinds("paypal"), []);
+    assert_eq!(kinds("café"), []);
+    // Each script change is reported at the cluster it happens in
+    assert_eq!(
+        kinds("pаypаl"), // with two Cyrillic 'а's
+        [
+            (MixedScript, 1),
+            (RestrictionLevel(RestrictionLevel::MinimallyRestrictive), 1),
+            (MixedScript, 2),
+            (MixedScript, 4),
+            (MixedScript, 5),
+        ]
+    );
+    // A Latin letter with a combining Cyrillic titlo
+    let findings = GraphemeChecker::new()
+        .max_restriction_level(None)
+        .check("xa\u{483}");
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].kind, MixedScriptCluster);
+    assert_eq!(findings[0].range, 1..4);
+    assert_eq!((findings[0].base, findings[0].script), ('a', Script::Latin));
+    // Marks are compared after NFD, so a precomposed 'é' hides a second acute
+    assert_eq!(kinds("e\u{301}\u{301}"), [(RepeatedMark, 0)]);
+    assert_eq!(kinds("xé\u{301}"), [(RepeatedMark, 1)]);
+    assert_eq!(kinds("a\u{300}\u{301}\u{302}\u{303}"), []);
+    assert_eq!(
+        kinds("a\u{300}\u{301}\u{302}\u{303}\u{304}"),
+        [(TooManyMarks, 0)]
+    );
+    assert_eq!(
+        GraphemeChecker::new()
+            .hidden_marks(false)
+            .check("e\u{301}\u{301}"),
+        []
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_hangul() {
+    use crate::hangul::{check_hangul, HangulFindingKind::*};
+    use std::vec::Vec;
+
+    let findings = |s: &str| -> Vec<_> {
+        check_hangul(s)
+            .into_iter()
+            .map(|f| (f.kind, f.range))
+            .collect()
+    };
+    assert_eq!(findings("한국어"), []);
+    assert_eq!(findings("hangul"), []);
+    // Compatibility jamo alone are ordinary text
+    assert_eq!(findings("ㅎㅎ"), []);
+    // ᄒ ᅡ ᆫ and 하 ᆫ both render as 한
+    assert_eq!(
+        findings("\u{1112}\u{1161}\u{11AB}국"),
+        [(DecomposedSyllable('한'), 0..9)]
+    );
+    assert_eq!(findings("하\u{11AB}"), [(DecomposedSyllable('한'), 0..6)]);
+    assert_eq!(
+        findings("\u{1100}\u{1161}\u{1100}\u{1161}"),
+        [
+            (DecomposedSyllable('가'), 0..6),
+            (DecomposedSyllable('가'), 6..12)
+        ]
+    );
+    // An LVT syllable takes no further trailing consonant, and old jamo do not compose
+    assert_eq!(findings("한\u{11AB}"), [(NonComposingJamo, 3..6)]);
+    assert_eq!(
+        findings("\u{1113}\u{1161}"),
+        [(NonComposingJamo, 0..3), (NonComposingJamo, 3..6)]
+    );
+    // A doubled leading consonant, of which only the second composes
+    assert_eq!(
+        findings("\u{1100}\u{1100}\u{1161}"),
+        [(NonComposingJamo, 0..3), (DecomposedSyllable('가'), 3..9)]
+    );
+    // Lone vowels, trailing and leading consonants
+    assert_eq!(findings("\u{1161}"), [(NonComposingJamo, 0..3)]);
+    assert_eq!(findings("\u{11AB}"), [(NonComposingJamo, 0..3)]);
+    assert_eq!(findings("\u{1100}국"), [(NonComposingJamo, 0..3)]);
+    assert_eq!(findings("가\u{1100}"), [(NonComposingJamo, 3..6)]);
+    // Jamo of Hangul Jamo Extended-A and Extended-B never compose
+    assert_eq!(
+        findings("\u{A960}\u{1161}"),
+        [(NonComposingJamo, 0..3), (NonComposingJamo, 3..6)]
+    );
+    assert_eq!(
+        findings("\u{1100}\u{D7B0}"),
+        [(NonComposingJamo, 0..3), (NonComposingJamo, 3..6)]
+    );
+    assert_eq!(findings("가\u{D7CB}"), [(NonComposingJamo, 3..6)]);
+    // Compatibility and halfwidth jamo next to syllables
+    assert_eq!(
+        findings("하ㄴ\u{FFA4}"),
+        [(CompatibilityJamo, 3..6), (CompatibilityJamo, 6..9)]
+    );
+    assert_eq!(
+        findings("\u{3164}한\u{115F}\u{1160}\u{FFA0}"),
+        [
+            (Filler, 0..3),
+            (Filler, 6..9),
+            (Filler, 9..12),
+            (Filler, 12..15)
+        ]
+    );
+    assert_eq!(findings("ㄱ\u{3164}"), [(Filler, 3..6)]);
+}